@@ -781,6 +781,10 @@ BEGIN
     IF NOT EXISTS (SELECT 1 FROM information_schema.columns WHERE table_name = 'subcontractors' AND column_name = 'secondary_trades') THEN
         ALTER TABLE subcontractors ADD COLUMN secondary_trades JSONB DEFAULT '[]';
     END IF;
+    
+    IF NOT EXISTS (SELECT 1 FROM information_schema.columns WHERE table_name = 'subcontractors' AND column_name = 'updated_at') THEN
+        ALTER TABLE subcontractors ADD COLUMN updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW();
+    END IF;
 END $$;
 
 -- Add constraint for verification_status
@@ -981,3 +985,577 @@ COMMENT ON COLUMN profiles.is_admin IS 'Admin flag - grants access to admin pane
 COMMENT ON COLUMN subcontractors.verification_status IS 'Profile verification status: pending, verified, rejected';
 COMMENT ON COLUMN tenders.reserve_price IS 'Minimum acceptable bid amount (visible to bidders)';
 COMMENT ON COLUMN tenders.visibility IS 'Tender visibility: public (marketplace) or invited_only';
+
+-- ============================================================================
+-- Stream D: Activity Log & Platform Hardening
+-- ============================================================================
+
+-- Activity log for sensitive non-admin actions (contract signing, awards, etc.)
+CREATE TABLE IF NOT EXISTS activity_log (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    actor_id UUID NOT NULL REFERENCES profiles(id),
+    project_id UUID REFERENCES projects(id) ON DELETE CASCADE,
+    action VARCHAR(100) NOT NULL,
+    target_type VARCHAR(50) NOT NULL,
+    target_id UUID,
+    details JSONB DEFAULT '{}',
+    ip_address VARCHAR(45),
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_activity_log_project ON activity_log(project_id, created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_activity_log_actor ON activity_log(actor_id, created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_activity_log_target ON activity_log(target_type, target_id);
+
+COMMENT ON TABLE activity_log IS 'Audit trail for sensitive user actions, shown as a project timeline';
+
+-- Hire message notifications respect a dedicated preference (defaults on)
+UPDATE user_settings
+SET notification_settings = notification_settings || '{"message_alerts": true}'::jsonb
+WHERE NOT (notification_settings ? 'message_alerts');
+
+-- Processing jobs cover more than document ingestion (e.g. AI trade scope extraction)
+ALTER TABLE processing_jobs ADD COLUMN IF NOT EXISTS job_type VARCHAR(50) DEFAULT 'document_ingestion' NOT NULL;
+ALTER TABLE processing_jobs ADD COLUMN IF NOT EXISTS input JSONB DEFAULT '{}';
+
+CREATE INDEX IF NOT EXISTS ix_processing_jobs_type_status ON processing_jobs(job_type, status);
+
+COMMENT ON COLUMN processing_jobs.job_type IS 'Job kind: document_ingestion, trade_scope_extraction';
+COMMENT ON COLUMN processing_jobs.input IS 'Job parameters passed to the AI service (e.g. requested trades)';
+
+-- Content hashes let processing reuse a completed job when the file is unchanged
+ALTER TABLE documents ADD COLUMN IF NOT EXISTS content_hash VARCHAR(64);
+ALTER TABLE processing_jobs ADD COLUMN IF NOT EXISTS content_hash VARCHAR(64);
+
+CREATE INDEX IF NOT EXISTS ix_processing_jobs_reuse ON processing_jobs(document_id, job_type, content_hash) WHERE status = 'completed';
+
+COMMENT ON COLUMN documents.content_hash IS 'SHA-256 of the uploaded file (hex)';
+COMMENT ON COLUMN processing_jobs.content_hash IS 'Document content hash the job ran against';
+
+-- Worker claiming: AI workers claim queued jobs and heartbeat while running
+ALTER TABLE processing_jobs ADD COLUMN IF NOT EXISTS claimed_by VARCHAR(255);
+ALTER TABLE processing_jobs ADD COLUMN IF NOT EXISTS claimed_at TIMESTAMP WITH TIME ZONE;
+ALTER TABLE processing_jobs ADD COLUMN IF NOT EXISTS heartbeat_at TIMESTAMP WITH TIME ZONE;
+
+CREATE INDEX IF NOT EXISTS ix_processing_jobs_queued ON processing_jobs(created_at) WHERE status = 'queued';
+
+COMMENT ON COLUMN processing_jobs.claimed_by IS 'Worker id that claimed the job via /internal/jobs/claim';
+COMMENT ON COLUMN processing_jobs.heartbeat_at IS 'Last heartbeat from the claiming worker';
+
+-- Last progress report from the worker; used by the stalled-job reaper
+ALTER TABLE processing_jobs ADD COLUMN IF NOT EXISTS last_progress_at TIMESTAMP WITH TIME ZONE;
+
+CREATE INDEX IF NOT EXISTS ix_processing_jobs_running ON processing_jobs(last_progress_at) WHERE status = 'running';
+
+-- Document versioning: a revision links to the document it supersedes
+ALTER TABLE documents ADD COLUMN IF NOT EXISTS supersedes_document_id UUID REFERENCES documents(id) ON DELETE SET NULL;
+
+CREATE UNIQUE INDEX IF NOT EXISTS ux_documents_supersedes ON documents(supersedes_document_id) WHERE supersedes_document_id IS NOT NULL;
+
+-- Which document version produced each job and extracted record
+ALTER TABLE processing_jobs ADD COLUMN IF NOT EXISTS document_version INTEGER;
+ALTER TABLE extracted_materials ADD COLUMN IF NOT EXISTS document_version INTEGER;
+ALTER TABLE extracted_rooms ADD COLUMN IF NOT EXISTS document_version INTEGER;
+ALTER TABLE extracted_trade_scopes ADD COLUMN IF NOT EXISTS document_version INTEGER;
+
+COMMENT ON COLUMN documents.supersedes_document_id IS 'Previous version this document revises (the previous row is marked superseded)';
+COMMENT ON COLUMN processing_jobs.document_version IS 'Document version the job processed';
+COMMENT ON COLUMN extracted_trade_scopes.document_version IS 'Document version the scope was extracted from';
+COMMENT ON COLUMN extracted_materials.document_version IS 'Document version the material was extracted from';
+COMMENT ON COLUMN extracted_rooms.document_version IS 'Document version the room was extracted from';
+
+-- Extracted materials and rooms are written by the AI service; they take the
+-- version of the job that produced them, or else of their document
+CREATE OR REPLACE FUNCTION set_extracted_document_version() RETURNS TRIGGER AS $$
+BEGIN
+    IF NEW.document_version IS NULL THEN
+        NEW.document_version := COALESCE(
+            (SELECT document_version FROM processing_jobs WHERE id = NEW.job_id),
+            (SELECT version FROM documents WHERE id = NEW.document_id)
+        );
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS trg_extracted_materials_document_version ON extracted_materials;
+CREATE TRIGGER trg_extracted_materials_document_version
+    BEFORE INSERT ON extracted_materials
+    FOR EACH ROW EXECUTE FUNCTION set_extracted_document_version();
+
+DROP TRIGGER IF EXISTS trg_extracted_rooms_document_version ON extracted_rooms;
+CREATE TRIGGER trg_extracted_rooms_document_version
+    BEFORE INSERT ON extracted_rooms
+    FOR EACH ROW EXECUTE FUNCTION set_extracted_document_version();
+
+-- Profile avatars and company logos (object storage keys; served from STORAGE_PUBLIC_URL)
+ALTER TABLE profiles ADD COLUMN IF NOT EXISTS avatar_key TEXT;
+ALTER TABLE profiles ADD COLUMN IF NOT EXISTS avatar_thumb_key TEXT;
+ALTER TABLE subcontractors ADD COLUMN IF NOT EXISTS logo_key TEXT;
+ALTER TABLE subcontractors ADD COLUMN IF NOT EXISTS logo_thumb_key TEXT;
+
+COMMENT ON COLUMN profiles.avatar_thumb_key IS 'Resized thumbnail variant of avatar_key';
+COMMENT ON COLUMN subcontractors.logo_thumb_key IS 'Resized thumbnail variant of logo_key';
+
+-- Geographic search: coordinates geocoded from free-text locations
+CREATE EXTENSION IF NOT EXISTS cube;
+CREATE EXTENSION IF NOT EXISTS earthdistance;
+
+ALTER TABLE subcontractors ADD COLUMN IF NOT EXISTS latitude DOUBLE PRECISION;
+ALTER TABLE subcontractors ADD COLUMN IF NOT EXISTS longitude DOUBLE PRECISION;
+ALTER TABLE tenders ADD COLUMN IF NOT EXISTS latitude DOUBLE PRECISION;
+ALTER TABLE tenders ADD COLUMN IF NOT EXISTS longitude DOUBLE PRECISION;
+
+CREATE INDEX IF NOT EXISTS ix_subcontractors_earth ON subcontractors USING gist (ll_to_earth(latitude, longitude))
+    WHERE latitude IS NOT NULL AND longitude IS NOT NULL;
+CREATE INDEX IF NOT EXISTS ix_tenders_earth ON tenders USING gist (ll_to_earth(latitude, longitude))
+    WHERE latitude IS NOT NULL AND longitude IS NOT NULL;
+
+-- Keep subcontractor rating aggregates in sync with reviews.
+-- NUMERIC ROUND() rounds half away from zero, i.e. half-up for ratings.
+CREATE OR REPLACE FUNCTION refresh_subcontractor_rating(sub_id UUID) RETURNS VOID AS $$
+    UPDATE subcontractors s
+    SET rating = COALESCE(agg.avg_rating, 0),
+        review_count = agg.review_count
+    FROM (
+        SELECT ROUND(AVG(rating), 1) AS avg_rating, COUNT(*)::INTEGER AS review_count
+        FROM subcontractor_reviews
+        WHERE subcontractor_id = sub_id
+    ) agg
+    WHERE s.id = sub_id;
+$$ LANGUAGE sql;
+
+CREATE OR REPLACE FUNCTION subcontractor_reviews_refresh_rating() RETURNS TRIGGER AS $$
+BEGIN
+    IF TG_OP <> 'INSERT' AND OLD.subcontractor_id IS NOT NULL THEN
+        PERFORM refresh_subcontractor_rating(OLD.subcontractor_id);
+    END IF;
+    IF TG_OP <> 'DELETE' AND NEW.subcontractor_id IS NOT NULL
+        AND (TG_OP = 'INSERT' OR NEW.subcontractor_id IS DISTINCT FROM OLD.subcontractor_id
+             OR NEW.rating IS DISTINCT FROM OLD.rating) THEN
+        PERFORM refresh_subcontractor_rating(NEW.subcontractor_id);
+    END IF;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS trg_subcontractor_reviews_refresh_rating ON subcontractor_reviews;
+CREATE TRIGGER trg_subcontractor_reviews_refresh_rating
+    AFTER INSERT OR UPDATE OR DELETE ON subcontractor_reviews
+    FOR EACH ROW EXECUTE FUNCTION subcontractor_reviews_refresh_rating();
+
+-- Contract signature integrity: who signed, and a hash of the terms they signed
+ALTER TABLE contracts ADD COLUMN IF NOT EXISTS gc_signed_by UUID REFERENCES profiles(id);
+ALTER TABLE contracts ADD COLUMN IF NOT EXISTS sub_signed_by UUID REFERENCES profiles(id);
+ALTER TABLE contracts ADD COLUMN IF NOT EXISTS gc_content_hash VARCHAR(64);
+ALTER TABLE contracts ADD COLUMN IF NOT EXISTS sub_content_hash VARCHAR(64);
+
+COMMENT ON COLUMN contracts.gc_content_hash IS 'SHA-256 of title, content, sections and amount when the GC signed';
+COMMENT ON COLUMN contracts.sub_content_hash IS 'SHA-256 of title, content, sections and amount when the subcontractor signed';
+
+-- Project documents shared with a tender (only these are visible to bidders)
+CREATE TABLE IF NOT EXISTS tender_documents (
+    tender_id UUID NOT NULL REFERENCES tenders(id) ON DELETE CASCADE,
+    document_id UUID NOT NULL REFERENCES documents(id) ON DELETE CASCADE,
+    shared_by UUID REFERENCES profiles(id) ON DELETE SET NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+    PRIMARY KEY (tender_id, document_id)
+);
+
+CREATE TABLE IF NOT EXISTS tender_document_downloads (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    tender_id UUID NOT NULL REFERENCES tenders(id) ON DELETE CASCADE,
+    document_id UUID NOT NULL REFERENCES documents(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES profiles(id) ON DELETE CASCADE,
+    ip_address VARCHAR(45),
+    downloaded_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS ix_tender_documents_document_id ON tender_documents(document_id);
+CREATE INDEX IF NOT EXISTS ix_tender_document_downloads_tender_id ON tender_document_downloads(tender_id, downloaded_at DESC);
+
+COMMENT ON TABLE tender_documents IS 'Project documents a GC has shared with bidders on a tender';
+COMMENT ON TABLE tender_document_downloads IS 'Download log for shared tender documents';
+
+-- Money in JSONB used to be integer cents; it is now a dollar string such as
+-- "1250.00". Only numeric values are converted, so re-running is a no-op.
+UPDATE subcontractors s
+SET insurance = (
+    SELECT jsonb_object_agg(
+        e.key,
+        CASE WHEN e.key IN ('general_liability', 'workers_comp', 'auto_liability') AND jsonb_typeof(e.value) = 'number'
+             THEN to_jsonb(((e.value::text)::numeric / 100)::numeric(15, 2)::text)
+             ELSE e.value END)
+    FROM jsonb_each(s.insurance) e)
+WHERE jsonb_typeof(s.insurance) = 'object'
+  AND EXISTS (
+      SELECT 1 FROM jsonb_each(s.insurance) e
+      WHERE e.key IN ('general_liability', 'workers_comp', 'auto_liability') AND jsonb_typeof(e.value) = 'number');
+
+UPDATE bids b
+SET breakdown = (
+    SELECT jsonb_agg(
+        i.item
+        || CASE WHEN jsonb_typeof(i.item->'unit_price') = 'number'
+                THEN jsonb_build_object('unit_price', ((i.item->>'unit_price')::numeric / 100)::numeric(15, 2)::text)
+                ELSE '{}'::jsonb END
+        || CASE WHEN jsonb_typeof(i.item->'total') = 'number'
+                THEN jsonb_build_object('total', ((i.item->>'total')::numeric / 100)::numeric(15, 2)::text)
+                ELSE '{}'::jsonb END
+        ORDER BY i.ord)
+    FROM jsonb_array_elements(b.breakdown) WITH ORDINALITY AS i(item, ord))
+WHERE jsonb_typeof(b.breakdown) = 'array'
+  AND EXISTS (
+      SELECT 1 FROM jsonb_array_elements(b.breakdown) item
+      WHERE jsonb_typeof(item->'unit_price') = 'number' OR jsonb_typeof(item->'total') = 'number');
+
+UPDATE subcontractors s
+SET recent_projects = (
+    SELECT jsonb_agg(
+        CASE WHEN jsonb_typeof(i.item->'value') = 'number'
+             THEN i.item || jsonb_build_object('value', ((i.item->>'value')::numeric / 100)::numeric(15, 2)::text)
+             ELSE i.item END
+        ORDER BY i.ord)
+    FROM jsonb_array_elements(s.recent_projects) WITH ORDINALITY AS i(item, ord))
+WHERE jsonb_typeof(s.recent_projects) = 'array'
+  AND EXISTS (
+      SELECT 1 FROM jsonb_array_elements(s.recent_projects) item
+      WHERE jsonb_typeof(item->'value') = 'number');
+
+-- Canonical trade keys (see GET /trades) stored alongside free-text trades
+ALTER TABLE extracted_materials ADD COLUMN IF NOT EXISTS normalized_trade VARCHAR(50);
+ALTER TABLE extracted_trade_scopes ADD COLUMN IF NOT EXISTS normalized_trade VARCHAR(50);
+
+CREATE INDEX IF NOT EXISTS idx_extracted_materials_normalized_trade ON extracted_materials(project_id, normalized_trade);
+CREATE INDEX IF NOT EXISTS idx_extracted_trade_scopes_normalized_trade ON extracted_trade_scopes(project_id, normalized_trade);
+
+COMMENT ON COLUMN extracted_materials.normalized_trade IS 'Canonical trade key derived from trade_category or csi_division';
+COMMENT ON COLUMN extracted_trade_scopes.normalized_trade IS 'Canonical trade key derived from trade or csi_division';
+
+-- Counter-offers: state of the subcontractor's latest counter (sub_counter_amount)
+ALTER TABLE hire_requests ADD COLUMN IF NOT EXISTS counter_offer_status VARCHAR(20);
+
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_constraint WHERE conname = 'hire_requests_counter_offer_status_check') THEN
+        ALTER TABLE hire_requests ADD CONSTRAINT hire_requests_counter_offer_status_check
+            CHECK (counter_offer_status IN ('pending', 'accepted', 'rejected'));
+    END IF;
+END $$;
+
+UPDATE hire_requests SET counter_offer_status = 'pending'
+WHERE sub_counter_amount IS NOT NULL AND counter_offer_status IS NULL;
+
+COMMENT ON COLUMN hire_requests.counter_offer_status IS 'pending, accepted or rejected; accepting copies sub_counter_amount into proposed_amount';
+
+-- Team rows created when a contract is fully signed are keyed by hire request
+CREATE INDEX IF NOT EXISTS ix_project_team_hire_request_id ON project_team(hire_request_id);
+
+-- Team member reviews: the GC's review of a completed member sets their performance rating
+ALTER TABLE project_team ADD COLUMN IF NOT EXISTS review_id UUID REFERENCES subcontractor_reviews(id) ON DELETE SET NULL;
+
+COMMENT ON COLUMN project_team.review_id IS 'Review written via POST /projects/:id/team/:member_id/review; performance_rating mirrors its rating';
+
+-- Admin-managed platform settings (JSONB values keyed by name)
+CREATE TABLE IF NOT EXISTS system_settings (
+    key VARCHAR(100) PRIMARY KEY,
+    value JSONB NOT NULL,
+    updated_by UUID REFERENCES profiles(id) ON DELETE SET NULL,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL
+);
+
+INSERT INTO system_settings (key, value)
+VALUES ('verification_requirements', '{"required_fields": ["insurance", "license_info", "contact_email"]}')
+ON CONFLICT (key) DO NOTHING;
+
+COMMENT ON TABLE system_settings IS 'Platform settings edited by admins; verification_requirements lists fields required before requesting verification';
+
+-- Evidence uploaded with verification requests (files live outside the public media root)
+CREATE TABLE IF NOT EXISTS verification_documents (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    subcontractor_id UUID NOT NULL REFERENCES subcontractors(id) ON DELETE CASCADE,
+    document_type VARCHAR(20) NOT NULL CHECK (document_type IN ('insurance', 'license', 'other')),
+    file_name VARCHAR(255) NOT NULL,
+    file_path VARCHAR(500) NOT NULL,
+    file_size BIGINT NOT NULL,
+    mime_type VARCHAR(100),
+    expiry_date DATE,
+    uploaded_by UUID REFERENCES profiles(id) ON DELETE SET NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS ix_verification_documents_subcontractor_id ON verification_documents(subcontractor_id);
+
+COMMENT ON TABLE verification_documents IS 'Insurance certificates, licenses and other proof attached to subcontractor verification requests';
+
+-- Insurance expiry: structured copy of insurance->>'expiry_date' for filtering and lapse tracking
+ALTER TABLE subcontractors ADD COLUMN IF NOT EXISTS insurance_expiry DATE;
+ALTER TABLE subcontractors ADD COLUMN IF NOT EXISTS insurance_reminder_sent_for DATE;
+ALTER TABLE subcontractors ADD COLUMN IF NOT EXISTS insurance_lapse_flagged_for DATE;
+
+UPDATE subcontractors
+SET insurance_expiry = (insurance->>'expiry_date')::date
+WHERE insurance_expiry IS NULL
+AND insurance->>'expiry_date' ~ '^\d{4}-\d{2}-\d{2}$';
+
+CREATE INDEX IF NOT EXISTS ix_subcontractors_insurance_expiry ON subcontractors(insurance_expiry) WHERE insurance_expiry IS NOT NULL;
+
+COMMENT ON COLUMN subcontractors.insurance_expiry IS 'Parsed from insurance.expiry_date on profile update; drives the has_insurance filter and the insurance monitor';
+COMMENT ON COLUMN subcontractors.insurance_reminder_sent_for IS 'Expiry date the sub was last reminded about, so each expiry is announced once';
+COMMENT ON COLUMN subcontractors.insurance_lapse_flagged_for IS 'Expiry date whose lapse last sent a verified sub back to admin review';
+
+-- Tender scope documents: AI-generated scope text kept as versions, with GC edits saved as revisions
+CREATE TABLE IF NOT EXISTS tender_scope_docs (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    tender_id UUID NOT NULL REFERENCES tenders(id) ON DELETE CASCADE,
+    version INTEGER NOT NULL,
+    source VARCHAR(20) NOT NULL CHECK (source IN ('generated', 'edited')),
+    document JSONB,
+    markdown TEXT NOT NULL,
+    trade_scope_ids UUID[] NOT NULL DEFAULT '{}',
+    sources_updated_at TIMESTAMP WITH TIME ZONE,
+    created_by UUID REFERENCES profiles(id) ON DELETE SET NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+    UNIQUE (tender_id, version)
+);
+
+COMMENT ON TABLE tender_scope_docs IS 'Versioned tender scope documents; the highest version is current';
+COMMENT ON COLUMN tender_scope_docs.trade_scope_ids IS 'extracted_trade_scopes the document was generated from; edits inherit them';
+COMMENT ON COLUMN tender_scope_docs.sources_updated_at IS 'Latest updated_at among the source trade scopes at generation; newer scopes mark the document stale';
+
+-- Document page counts: read from the PDF at upload; sizes processing progress and caps AI cost
+ALTER TABLE documents ADD COLUMN IF NOT EXISTS page_count INTEGER;
+
+COMMENT ON COLUMN documents.page_count IS 'Pages counted at upload (NULL when the PDF could not be read); sets items_total on per-page processing steps';
+
+-- Milestone confidence: lets milestones join the other extracted types in confidence filters and the review queue
+ALTER TABLE project_milestones ADD COLUMN IF NOT EXISTS confidence DECIMAL(3, 2) DEFAULT 0.8 CHECK (confidence >= 0 AND confidence <= 1);
+UPDATE project_milestones SET confidence = 1.0 WHERE is_ai_generated = false AND confidence = 0.8;
+
+CREATE INDEX IF NOT EXISTS ix_extracted_materials_review ON extracted_materials(project_id, confidence) WHERE is_verified IS NOT TRUE;
+CREATE INDEX IF NOT EXISTS ix_extracted_rooms_review ON extracted_rooms(project_id, confidence) WHERE is_verified IS NOT TRUE;
+CREATE INDEX IF NOT EXISTS ix_project_milestones_review ON project_milestones(project_id, confidence) WHERE is_verified IS NOT TRUE;
+CREATE INDEX IF NOT EXISTS ix_extracted_trade_scopes_review ON extracted_trade_scopes(project_id, confidence) WHERE is_verified IS NOT TRUE;
+
+COMMENT ON COLUMN project_milestones.confidence IS 'AI confidence (0-1); manually created milestones are 1.0';
+
+-- File deletion queue: files of deleted documents, enqueued in the delete transaction and removed by the API or its sweeper
+CREATE TABLE IF NOT EXISTS file_deletions (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    file_path TEXT NOT NULL,
+    reason VARCHAR(50) NOT NULL,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    last_attempt_at TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS ix_file_deletions_created_at ON file_deletions(created_at);
+
+COMMENT ON TABLE file_deletions IS 'Files still to be removed from disk; a row disappears once its file is gone';
+
+-- Settings schema version: rows are upgraded with the defaults of keys added after they were written
+ALTER TABLE user_settings ADD COLUMN IF NOT EXISTS schema_version INTEGER NOT NULL DEFAULT 1;
+
+COMMENT ON COLUMN user_settings.schema_version IS 'Settings layout the row was written under (SETTINGS_SCHEMA_VERSION in the API)';
+
+-- AI usage: one row per AI service call, for cost attribution and quotas.
+-- No foreign keys so usage outlives deleted projects and accounts.
+CREATE TABLE IF NOT EXISTS ai_usage (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    user_id UUID NOT NULL,
+    project_id UUID,
+    operation VARCHAR(50) NOT NULL CHECK (operation IN ('summary', 'trade_scopes', 'tender_scope_doc', 'qna')),
+    model VARCHAR(100),
+    prompt_tokens INTEGER,
+    completion_tokens INTEGER,
+    total_tokens INTEGER,
+    duration_ms INTEGER NOT NULL,
+    success BOOLEAN NOT NULL,
+    error_message TEXT,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_ai_usage_project ON ai_usage(project_id, created_at);
+CREATE INDEX IF NOT EXISTS idx_ai_usage_user ON ai_usage(user_id, created_at);
+CREATE INDEX IF NOT EXISTS idx_ai_usage_created ON ai_usage(created_at);
+
+COMMENT ON TABLE ai_usage IS 'AI service calls with duration, outcome and reported token counts';
+COMMENT ON COLUMN ai_usage.total_tokens IS 'As reported by the AI service; NULL when it reported none';
+
+-- Bid attachments: proposals and credentials a subcontractor attaches to their bid.
+-- Deleting a row (directly, or by cascade when the bid, tender or project goes)
+-- queues its file in file_deletions.
+CREATE TABLE IF NOT EXISTS bid_attachments (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    bid_id UUID NOT NULL REFERENCES bids(id) ON DELETE CASCADE,
+    file_name VARCHAR(255) NOT NULL,
+    file_path VARCHAR(500) NOT NULL,
+    file_size BIGINT NOT NULL,
+    mime_type VARCHAR(100),
+    uploaded_by UUID REFERENCES profiles(id) ON DELETE SET NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS ix_bid_attachments_bid_id ON bid_attachments(bid_id);
+
+CREATE OR REPLACE FUNCTION bid_attachments_queue_file_deletion() RETURNS TRIGGER AS $$
+BEGIN
+    INSERT INTO file_deletions (file_path, reason) VALUES (OLD.file_path, 'bid_attachment_deleted');
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS trg_bid_attachments_queue_file_deletion ON bid_attachments;
+CREATE TRIGGER trg_bid_attachments_queue_file_deletion
+    AFTER DELETE ON bid_attachments
+    FOR EACH ROW EXECUTE FUNCTION bid_attachments_queue_file_deletion();
+
+COMMENT ON TABLE bid_attachments IS 'Files attached to a bid; visible to the bidder and the tender owner';
+
+-- Tender clarification thread: questions from subs, answered by the GC for every bidder
+CREATE TABLE IF NOT EXISTS tender_questions (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    tender_id UUID NOT NULL REFERENCES tenders(id) ON DELETE CASCADE,
+    asked_by UUID REFERENCES profiles(id) ON DELETE SET NULL,
+    subcontractor_id UUID REFERENCES subcontractors(id) ON DELETE SET NULL,
+    question TEXT NOT NULL,
+    answer TEXT,
+    answered_by UUID REFERENCES profiles(id) ON DELETE SET NULL,
+    answered_at TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS ix_tender_questions_tender_id ON tender_questions(tender_id, created_at);
+
+COMMENT ON TABLE tender_questions IS 'Bid clarification Q&A; askers are shown only to the tender owner';
+
+-- External subcontractor duplicate detection: trigram similarity of normalized
+-- company names, plus exact contact email / phone matches
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+CREATE OR REPLACE FUNCTION normalize_company_name(name TEXT) RETURNS TEXT AS $$
+    SELECT btrim(regexp_replace(
+        regexp_replace(
+            regexp_replace(lower(COALESCE(name, '')), '[^a-z0-9 ]', ' ', 'g'),
+            '\m(the|inc|incorporated|llc|ltd|limited|co|corp|corporation|company)\M', ' ', 'g'),
+        '\s+', ' ', 'g'))
+$$ LANGUAGE sql IMMUTABLE;
+
+CREATE OR REPLACE FUNCTION normalize_phone(phone TEXT) RETURNS TEXT AS $$
+    SELECT regexp_replace(COALESCE(phone, ''), '\D', '', 'g')
+$$ LANGUAGE sql IMMUTABLE;
+
+COMMENT ON FUNCTION normalize_company_name(TEXT) IS 'Lowercased company name without punctuation or legal suffixes (Inc, LLC, ...), for duplicate matching';
+COMMENT ON FUNCTION normalize_phone(TEXT) IS 'Digits of a phone number, for duplicate matching';
+
+-- External subcontractors linked to the platform profile the company later created
+ALTER TABLE external_subcontractors ADD COLUMN IF NOT EXISTS linked_subcontractor_id UUID REFERENCES subcontractors(id) ON DELETE SET NULL;
+ALTER TABLE external_subcontractors ADD COLUMN IF NOT EXISTS linked_at TIMESTAMP WITH TIME ZONE;
+
+CREATE INDEX IF NOT EXISTS ix_external_subcontractors_linked ON external_subcontractors(linked_subcontractor_id)
+    WHERE linked_subcontractor_id IS NOT NULL;
+
+COMMENT ON COLUMN external_subcontractors.linked_subcontractor_id IS 'Platform profile this record was linked to; its hire requests, reviews and team memberships were moved there';
+
+-- Cross-project RFI inbox: filter by assignee and due date
+CREATE INDEX IF NOT EXISTS ix_rfis_assignee_id ON rfis(assignee_id);
+CREATE INDEX IF NOT EXISTS ix_rfis_due_date ON rfis(due_date);
+CREATE INDEX IF NOT EXISTS ix_tasks_due_date ON tasks(due_date);
+
+-- RFI lifecycle: the response that resolved the RFI
+ALTER TABLE rfis ADD COLUMN IF NOT EXISTS accepted_response_id UUID REFERENCES rfi_responses(id) ON DELETE SET NULL;
+ALTER TABLE rfis ADD COLUMN IF NOT EXISTS accepted_by UUID REFERENCES profiles(id) ON DELETE SET NULL;
+ALTER TABLE rfis ADD COLUMN IF NOT EXISTS accepted_at TIMESTAMP WITH TIME ZONE;
+
+COMMENT ON COLUMN rfis.accepted_response_id IS 'Response accepted as the answer; cleared when the RFI is reopened';
+
+-- Watchers: users following an RFI or tender they don't own
+CREATE TABLE IF NOT EXISTS resource_watchers (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    resource_type VARCHAR(20) NOT NULL CHECK (resource_type IN ('rfi', 'tender')),
+    resource_id UUID NOT NULL,
+    user_id UUID NOT NULL REFERENCES profiles(id) ON DELETE CASCADE,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+    UNIQUE (resource_type, resource_id, user_id)
+);
+
+CREATE INDEX IF NOT EXISTS ix_resource_watchers_user_id ON resource_watchers(user_id);
+
+-- resource_id has no foreign key, so drop watchers with their resource
+CREATE OR REPLACE FUNCTION delete_resource_watchers() RETURNS TRIGGER AS $$
+BEGIN
+    DELETE FROM resource_watchers WHERE resource_type = TG_ARGV[0] AND resource_id = OLD.id;
+    RETURN OLD;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS trg_rfis_delete_watchers ON rfis;
+CREATE TRIGGER trg_rfis_delete_watchers
+    AFTER DELETE ON rfis
+    FOR EACH ROW EXECUTE FUNCTION delete_resource_watchers('rfi');
+
+DROP TRIGGER IF EXISTS trg_tenders_delete_watchers ON tenders;
+CREATE TRIGGER trg_tenders_delete_watchers
+    AFTER DELETE ON tenders
+    FOR EACH ROW EXECUTE FUNCTION delete_resource_watchers('tender');
+
+COMMENT ON TABLE resource_watchers IS 'Subscriptions to RFI and tender activity; resource_type says which table resource_id points at';
+
+-- Auth sessions: one row per refresh token family. Each refresh rotates the
+-- family's current token; rotated-out and revoked tokens go on a revocation
+-- list in Redis, and presenting a rotated-out token revokes the family.
+CREATE TABLE IF NOT EXISTS auth_sessions (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    user_id UUID NOT NULL REFERENCES profiles(id) ON DELETE CASCADE,
+    refresh_token_hash VARCHAR(64) NOT NULL UNIQUE,
+    auth_session_id UUID,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+    last_refreshed_at TIMESTAMP WITH TIME ZONE,
+    revoked_at TIMESTAMP WITH TIME ZONE,
+    revoked_reason VARCHAR(20) CHECK (revoked_reason IN ('sign_out', 'user', 'reuse_detected'))
+);
+
+CREATE INDEX IF NOT EXISTS ix_auth_sessions_user_active ON auth_sessions(user_id) WHERE revoked_at IS NULL;
+CREATE INDEX IF NOT EXISTS ix_auth_sessions_auth_session_id ON auth_sessions(auth_session_id);
+
+COMMENT ON TABLE auth_sessions IS 'Signed-in sessions, one per refresh token family';
+COMMENT ON COLUMN auth_sessions.refresh_token_hash IS 'SHA-256 (hex) of the family''s current refresh token';
+COMMENT ON COLUMN auth_sessions.auth_session_id IS 'Supabase session_id claim of the session''s access tokens';
+
+-- Auth sessions: the device and address each session was last used from
+ALTER TABLE auth_sessions ADD COLUMN IF NOT EXISTS user_agent VARCHAR(500);
+ALTER TABLE auth_sessions ADD COLUMN IF NOT EXISTS ip_address VARCHAR(45);
+
+COMMENT ON COLUMN auth_sessions.user_agent IS 'User-Agent of the sign-in or latest refresh';
+COMMENT ON COLUMN auth_sessions.ip_address IS 'Client IP of the sign-in or latest refresh';
+
+-- Extraction provenance: where on its source page the AI found each item
+ALTER TABLE extracted_materials ADD COLUMN IF NOT EXISTS source_bbox JSONB;
+ALTER TABLE extracted_rooms ADD COLUMN IF NOT EXISTS source_bbox JSONB;
+
+COMMENT ON COLUMN extracted_materials.source_bbox IS 'Region of source_page the material was read from: {x, y, width, height} as fractions of the page from its top-left corner';
+COMMENT ON COLUMN extracted_rooms.source_bbox IS 'Region of source_page the room was read from: {x, y, width, height} as fractions of the page from its top-left corner';
+
+-- Feature flags: off unless turned on globally or for a user. A per-user row
+-- overrides the global setting either way.
+CREATE TABLE IF NOT EXISTS feature_flags (
+    key VARCHAR(100) PRIMARY KEY,
+    description TEXT,
+    enabled BOOLEAN NOT NULL DEFAULT FALSE,
+    updated_by UUID REFERENCES profiles(id) ON DELETE SET NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS feature_flag_users (
+    flag_key VARCHAR(100) NOT NULL REFERENCES feature_flags(key) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES profiles(id) ON DELETE CASCADE,
+    enabled BOOLEAN NOT NULL,
+    updated_by UUID REFERENCES profiles(id) ON DELETE SET NULL,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+    PRIMARY KEY (flag_key, user_id)
+);
+
+COMMENT ON TABLE feature_flags IS 'Feature flags for gradual rollouts; enabled is the setting for everyone';
+COMMENT ON TABLE feature_flag_users IS 'Per-user feature flag overrides, turning a flag on or off for one user';
@@ -221,6 +221,7 @@ class ExtractionPipeline:
                             trade_category=mat.get("trade_category"),
                             csi_division=mat.get("csi_division"),
                             source_page=mat.get("source_page", page),
+                            source_bbox=mat.get("source_bbox"),
                             confidence=mat.get("confidence", 0.5),
                         )
                         all_materials.append(item)
@@ -56,6 +56,15 @@ class ScopeItem(BaseModel):
     details: str | None = Field(default=None, description="Additional details")
 
 
+class SourceBoundingBox(BaseModel):
+    """Region of a page an item was read from, as fractions of the page from its top-left corner."""
+
+    x: float = Field(ge=0.0, le=1.0)
+    y: float = Field(ge=0.0, le=1.0)
+    width: float = Field(ge=0.0, le=1.0)
+    height: float = Field(ge=0.0, le=1.0)
+
+
 # ============================================================================
 # Material Extraction
 # ============================================================================
@@ -74,6 +83,9 @@ class ExtractedMaterialItem(BaseModel):
     trade_category: str | None = Field(default=None, description="Trade category")
     csi_division: str | None = Field(default=None, description="CSI division code")
     source_page: int | None = Field(default=None, ge=1, description="Source page number")
+    source_bbox: SourceBoundingBox | None = Field(
+        default=None, description="Where on the source page the material was found, if known"
+    )
     confidence: float = Field(default=0.5, ge=0.0, le=1.0, description="Extraction confidence")
 
 
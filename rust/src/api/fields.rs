@@ -0,0 +1,79 @@
+//! Sparse fieldsets for list endpoints
+//!
+//! `?fields=id,name,trade` trims every list item to the named top-level
+//! fields. Each endpoint declares the field names it accepts; unknown names
+//! are rejected like unknown sort keys. `id` is always returned so clients
+//! can still link items to their detail views.
+
+use axum::response::{IntoResponse, Response};
+use serde::{Deserialize, Serialize};
+use utoipa::IntoParams;
+
+use crate::api::pagination::{PageRequest, Paginated};
+use crate::error::ApiError;
+
+/// Top-level field names a list item serializes
+pub type SelectableFields = &'static [&'static str];
+
+/// Field selection query parameter
+#[derive(Debug, Clone, Deserialize, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct FieldsQuery {
+    /// Comma-separated fields to return for each item (default: all fields)
+    pub fields: Option<String>,
+}
+
+/// A validated field selection; `None` keeps every field
+#[derive(Debug, Clone, Default)]
+pub struct FieldSet(Option<Vec<&'static str>>);
+
+impl FieldSet {
+    /// Resolve the `fields` query value against `allowed`
+    pub fn parse(allowed: SelectableFields, fields: Option<&str>) -> Result<Self, ApiError> {
+        let Some(raw) = fields.map(str::trim).filter(|f| !f.is_empty()) else {
+            return Ok(Self(None));
+        };
+
+        let mut selected = vec!["id"];
+        for name in raw.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+            let field = allowed.iter().find(|f| **f == name).ok_or_else(|| {
+                ApiError::bad_request(format!(
+                    "Unknown field '{}'; expected any of: {}",
+                    name,
+                    allowed.join(", ")
+                ))
+            })?;
+            if !selected.contains(field) {
+                selected.push(field);
+            }
+        }
+
+        Ok(Self(Some(selected)))
+    }
+
+    /// A page of `data`, trimmed to the selected fields
+    pub fn paginated<T: Serialize>(
+        &self,
+        data: Vec<T>,
+        page: &PageRequest,
+        total_items: u64,
+    ) -> Result<Response, ApiError> {
+        let Some(selected) = &self.0 else {
+            return Ok(Paginated::new(data, page, total_items).into_response());
+        };
+
+        let data = data
+            .into_iter()
+            .map(|item| match serde_json::to_value(item) {
+                Ok(serde_json::Value::Object(mut map)) => {
+                    map.retain(|key, _| selected.contains(&key.as_str()));
+                    Ok(serde_json::Value::Object(map))
+                }
+                Ok(other) => Ok(other),
+                Err(e) => Err(ApiError::internal(format!("Failed to serialize response: {}", e))),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Paginated::new(data, page, total_items).into_response())
+    }
+}
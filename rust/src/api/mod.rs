@@ -2,10 +2,17 @@
 //!
 //! These types will be used when implementing full database logic.
 
+pub mod fields;
+pub mod openapi;
 pub mod pagination;
 pub mod response;
+pub mod sort;
 
 #[allow(unused_imports)]
-pub use pagination::{Paginated, PaginationMeta, PaginationParams};
+pub use pagination::{
+    Cursor, CursorPage, CursorParams, Paginated, PaginationMeta, PaginationParams,
+};
 #[allow(unused_imports)]
 pub use response::{ApiResponse, Created, DataResponse, MessageResponse, NoContent};
+#[allow(unused_imports)]
+pub use sort::{SortOrder, SortSpec};
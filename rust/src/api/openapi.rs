@@ -0,0 +1,386 @@
+//! OpenAPI document
+//!
+//! Paths and schemas come from `#[utoipa::path]` and `ToSchema` annotations
+//! on the handlers and DTOs; this module collects them into one document.
+//! Route groups are added here as their handlers are annotated.
+
+#![allow(dead_code)]
+
+use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::openapi::{RefOr, Response, ResponseBuilder};
+use utoipa::{Modify, OpenApi, ToSchema};
+
+use crate::api::pagination::PaginationMeta;
+use crate::api::response::{CreatedId, MessageResponse};
+use crate::domain::extraction::*;
+use crate::domain::hiring::*;
+use crate::domain::marketplace::*;
+use crate::domain::money::Money;
+use crate::domain::rating::Rating;
+use crate::domain::subcontractors::RecentProject;
+use crate::error::{ErrorCode, ErrorResponse};
+use crate::routes::{extraction, hiring, marketplace};
+
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "BlueprintX API",
+        description = "Successful responses use the envelopes in `api::response`: \
+            `{ data }` for single resources, `{ data, pagination }` for pages, \
+            `201 { data }` for creates, `{ message }` for mutations \
+            and `204` for deletes. Errors are `ErrorResponse`, whose `code` \
+            (`ErrorCode`) is stable for clients to branch on."
+    ),
+    servers((url = "/api")),
+    security(("bearer" = [])),
+    modifiers(&CommonResponses),
+    paths(
+        // Marketplace
+        marketplace::list_marketplace_subcontractors,
+        marketplace::get_marketplace_subcontractor,
+        marketplace::get_subcontractor_portfolio,
+        marketplace::get_my_marketplace_profile,
+        marketplace::update_my_marketplace_profile,
+        marketplace::upload_my_marketplace_logo,
+        marketplace::request_verification,
+        marketplace::get_my_portfolio,
+        marketplace::create_portfolio_project,
+        marketplace::update_portfolio_project,
+        marketplace::delete_portfolio_project,
+        marketplace::upload_portfolio_image,
+        marketplace::delete_portfolio_image,
+        marketplace::recommend_subs_for_tender,
+        marketplace::invite_subcontractor_to_tender,
+        marketplace::list_saved_searches,
+        marketplace::create_saved_search,
+        marketplace::delete_saved_search,
+        marketplace::list_marketplace_tenders,
+        marketplace::get_marketplace_tender,
+        marketplace::submit_bid,
+        marketplace::update_bid,
+        marketplace::withdraw_bid,
+        marketplace::restore_bid,
+        marketplace::list_my_bids,
+        // Hiring
+        hiring::list_external_subcontractors,
+        hiring::create_external_subcontractor,
+        hiring::list_external_subcontractor_duplicates,
+        hiring::list_platform_matches,
+        hiring::link_external_subcontractor,
+        hiring::get_external_subcontractor,
+        hiring::update_external_subcontractor,
+        hiring::delete_external_subcontractor,
+        hiring::list_hire_requests,
+        hiring::create_hire_request,
+        hiring::get_hire_request,
+        hiring::update_hire_request,
+        hiring::update_hire_request_status,
+        hiring::submit_counter_offer,
+        hiring::accept_counter_offer,
+        hiring::reject_counter_offer,
+        hiring::list_hire_messages,
+        hiring::mark_hire_messages_read,
+        hiring::send_hire_message,
+        hiring::list_contract_templates,
+        hiring::create_contract,
+        hiring::get_contract,
+        hiring::sign_contract,
+        hiring::verify_contract_signatures,
+        hiring::get_contract_payments,
+        hiring::mark_milestone_paid,
+        hiring::list_team_members,
+        hiring::add_team_member,
+        hiring::update_team_member,
+        hiring::remove_team_member,
+        hiring::review_team_member,
+        // Extraction
+        extraction::get_extraction_summary,
+        extraction::get_review_queue,
+        extraction::list_materials,
+        extraction::create_material,
+        extraction::update_material,
+        extraction::patch_material,
+        extraction::delete_material,
+        extraction::verify_material,
+        extraction::bulk_assign_material_trade,
+        extraction::get_material_source,
+        extraction::list_rooms,
+        extraction::create_room,
+        extraction::update_room,
+        extraction::patch_room,
+        extraction::delete_room,
+        extraction::list_milestones,
+        extraction::create_milestone,
+        extraction::update_milestone,
+        extraction::patch_milestone,
+        extraction::delete_milestone,
+        extraction::list_trade_scopes,
+        extraction::create_trade_scope,
+        extraction::update_trade_scope,
+        extraction::patch_trade_scope,
+        extraction::delete_trade_scope,
+    ),
+    components(schemas(
+        // Shared
+        ErrorResponse,
+        ErrorCode,
+        MessageResponse,
+        CreatedId,
+        PaginationMeta,
+        Money,
+        Rating,
+        ImageUpload,
+        // Marketplace
+        VerificationStatus,
+        AvailabilityStatus,
+        Certification,
+        InsuranceInfo,
+        LicenseInfo,
+        RecentProject,
+        SubcontractorProfile,
+        SubcontractorListItem,
+        ProfileCompleteness,
+        RecommendedSubcontractor,
+        InviteSubcontractorRequest,
+        UpdateMarketplaceProfileRequest,
+        PortfolioProjectRequest,
+        PortfolioProjectResponse,
+        CreateSavedSearchRequest,
+        SavedSearchResponse,
+        TenderVisibility,
+        MarketplaceTender,
+        MarketplaceBidSummary,
+        SubmitBidRequest,
+        BidLineItem,
+        MarketplaceBidResponse,
+        // Hiring
+        HireRequestStatus,
+        RateType,
+        ExternalSubcontractorResponse,
+        CreateExternalSubcontractorInput,
+        UpdateExternalSubcontractorInput,
+        DuplicateReason,
+        ExternalSubcontractorSummary,
+        ExternalSubcontractorMatch,
+        DuplicateExternalSubcontractorError,
+        ExternalSubcontractorDuplicatePair,
+        PlatformSubcontractorMatch,
+        LinkExternalSubcontractorInput,
+        ExternalSubcontractorLinkResult,
+        HireRequestSubcontractor,
+        HireRequestResponse,
+        CreateHireRequestInput,
+        UpdateHireRequestInput,
+        HireRequestStatusInput,
+        CounterOfferStatus,
+        CounterOfferInput,
+        ContractStatus,
+        PaymentMilestone,
+        SignatureVerification,
+        ContractSignatureVerification,
+        MarkMilestonePaidInput,
+        ContractPaymentSummary,
+        ContractSection,
+        ContractTemplateResponse,
+        TemplateVariable,
+        ContractResponse,
+        CreateContractInput,
+        SignContractInput,
+        MessageType,
+        HireMessageResponse,
+        MarkMessagesReadInput,
+        SendMessageInput,
+        TeamMemberStatus,
+        TeamMemberResponse,
+        AddTeamMemberInput,
+        UpdateTeamMemberInput,
+        TeamMemberReview,
+        TeamMemberReviewInput,
+        // Extraction
+        ExtractedMaterialResponse,
+        MaterialInput,
+        MaterialPatch,
+        MaterialAssignFilter,
+        BulkAssignTradeRequest,
+        BulkAssignTradeResponse,
+        SourceBoundingBox,
+        SourceDocument,
+        MaterialSourceResponse,
+        RoomFinishes,
+        ExtractedRoomResponse,
+        RoomInput,
+        RoomPatch,
+        MilestoneStatus,
+        MilestoneResponse,
+        MilestoneInput,
+        MilestonePatch,
+        ScopeItem,
+        TradeScopeResponse,
+        TradeScopeInput,
+        TradeScopePatch,
+        ExtractionSummary,
+        VerifyItemRequest,
+        ExtractionSort,
+        ExtractionItemType,
+        ReviewQueueItem,
+        // Envelopes
+        SubcontractorProfileData,
+        SubcontractorListPage,
+        PortfolioProjectData,
+        PortfolioProjectListData,
+        RecommendedSubcontractorListData,
+        SavedSearchData,
+        SavedSearchListData,
+        MarketplaceTenderData,
+        MarketplaceTenderPage,
+        MarketplaceBidPage,
+        ExternalSubcontractorData,
+        ExternalSubcontractorPage,
+        ExternalSubcontractorDuplicatePairListData,
+        PlatformSubcontractorMatchListData,
+        ExternalSubcontractorLinkResultData,
+        HireRequestData,
+        HireRequestPage,
+        HireMessageCursorPage,
+        ContractTemplateListData,
+        ContractData,
+        ContractSignatureVerificationData,
+        ContractPaymentSummaryData,
+        TeamMemberListData,
+        TeamMemberReviewData,
+        ExtractionSummaryData,
+        ExtractedMaterialData,
+        ExtractedMaterialPage,
+        MaterialSourceData,
+        BulkAssignTradeData,
+        ExtractedRoomData,
+        ExtractedRoomPage,
+        MilestoneData,
+        MilestonePage,
+        TradeScopeData,
+        TradeScopePage,
+        ReviewQueuePage,
+        CreatedIdData,
+    )),
+    tags(
+        (name = "marketplace", description = "Subcontractor marketplace, portfolios, saved searches and bidding"),
+        (name = "hiring", description = "External subcontractors, hire requests, contracts and project teams"),
+        (name = "extraction", description = "AI-extracted materials, rooms, milestones and trade scopes"),
+    )
+)]
+pub struct ApiDoc;
+
+/// `{ "data": ... }`, as returned by `DataResponse` and `Created`
+#[derive(ToSchema)]
+#[aliases(
+    SubcontractorProfileData = DataEnvelope<SubcontractorProfile>,
+    PortfolioProjectData = DataEnvelope<PortfolioProjectResponse>,
+    PortfolioProjectListData = DataEnvelope<Vec<PortfolioProjectResponse>>,
+    RecommendedSubcontractorListData = DataEnvelope<Vec<RecommendedSubcontractor>>,
+    SavedSearchData = DataEnvelope<SavedSearchResponse>,
+    SavedSearchListData = DataEnvelope<Vec<SavedSearchResponse>>,
+    MarketplaceTenderData = DataEnvelope<MarketplaceTender>,
+    ExternalSubcontractorData = DataEnvelope<ExternalSubcontractorResponse>,
+    ExternalSubcontractorDuplicatePairListData = DataEnvelope<Vec<ExternalSubcontractorDuplicatePair>>,
+    PlatformSubcontractorMatchListData = DataEnvelope<Vec<PlatformSubcontractorMatch>>,
+    ExternalSubcontractorLinkResultData = DataEnvelope<ExternalSubcontractorLinkResult>,
+    HireRequestData = DataEnvelope<HireRequestResponse>,
+    ContractTemplateListData = DataEnvelope<Vec<ContractTemplateResponse>>,
+    ContractData = DataEnvelope<ContractResponse>,
+    ContractSignatureVerificationData = DataEnvelope<ContractSignatureVerification>,
+    ContractPaymentSummaryData = DataEnvelope<ContractPaymentSummary>,
+    TeamMemberListData = DataEnvelope<Vec<TeamMemberResponse>>,
+    TeamMemberReviewData = DataEnvelope<TeamMemberReview>,
+    ExtractionSummaryData = DataEnvelope<ExtractionSummary>,
+    ExtractedMaterialData = DataEnvelope<ExtractedMaterialResponse>,
+    MaterialSourceData = DataEnvelope<MaterialSourceResponse>,
+    BulkAssignTradeData = DataEnvelope<BulkAssignTradeResponse>,
+    ExtractedRoomData = DataEnvelope<ExtractedRoomResponse>,
+    MilestoneData = DataEnvelope<MilestoneResponse>,
+    TradeScopeData = DataEnvelope<TradeScopeResponse>,
+    CreatedIdData = DataEnvelope<CreatedId>,
+)]
+pub struct DataEnvelope<T> {
+    pub data: T,
+}
+
+/// `{ "data": [...], "pagination": ... }`, as returned by `Paginated`
+#[derive(ToSchema)]
+#[aliases(
+    SubcontractorListPage = PageEnvelope<SubcontractorListItem>,
+    MarketplaceTenderPage = PageEnvelope<MarketplaceTender>,
+    MarketplaceBidPage = PageEnvelope<MarketplaceBidResponse>,
+    ExternalSubcontractorPage = PageEnvelope<ExternalSubcontractorResponse>,
+    HireRequestPage = PageEnvelope<HireRequestResponse>,
+    ExtractedMaterialPage = PageEnvelope<ExtractedMaterialResponse>,
+    ExtractedRoomPage = PageEnvelope<ExtractedRoomResponse>,
+    MilestonePage = PageEnvelope<MilestoneResponse>,
+    TradeScopePage = PageEnvelope<TradeScopeResponse>,
+    ReviewQueuePage = PageEnvelope<ReviewQueueItem>,
+)]
+pub struct PageEnvelope<T> {
+    pub data: Vec<T>,
+    pub pagination: PaginationMeta,
+}
+
+/// `{ "data": [...], "has_more", "next_cursor" }`, as returned by `CursorPage`
+#[derive(ToSchema)]
+#[aliases(HireMessageCursorPage = CursorEnvelope<HireMessageResponse>)]
+pub struct CursorEnvelope<T> {
+    pub data: Vec<T>,
+    pub has_more: bool,
+    pub next_cursor: Option<String>,
+}
+
+/// Multipart image upload
+#[derive(ToSchema)]
+pub struct ImageUpload {
+    /// JPEG, PNG or WebP image
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
+}
+
+/// Adds the bearer scheme and the 401/500 error responses every
+/// authenticated operation can return.
+struct CommonResponses;
+
+impl Modify for CommonResponses {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        if let Some(components) = openapi.components.as_mut() {
+            components.add_security_scheme(
+                "bearer",
+                SecurityScheme::Http(
+                    HttpBuilder::new()
+                        .scheme(HttpAuthScheme::Bearer)
+                        .bearer_format("JWT")
+                        .build(),
+                ),
+            );
+        }
+
+        for item in openapi.paths.paths.values_mut() {
+            for operation in item.operations.values_mut() {
+                let responses = &mut operation.responses.responses;
+                responses
+                    .entry("401".to_string())
+                    .or_insert_with(|| error_response("Missing or invalid access token"));
+                responses
+                    .entry("500".to_string())
+                    .or_insert_with(|| error_response("Internal server error"));
+            }
+        }
+    }
+}
+
+fn error_response(description: &str) -> RefOr<Response> {
+    ResponseBuilder::new()
+        .description(description)
+        .content(
+            "application/json",
+            utoipa::openapi::ContentBuilder::new()
+                .schema(utoipa::openapi::Ref::from_schema_name("ErrorResponse"))
+                .build(),
+        )
+        .build()
+        .into()
+}
@@ -6,45 +6,166 @@ use axum::{
     response::{IntoResponse, Response},
     Json,
 };
-use serde::{Deserialize, Serialize};
+use serde::{de, Deserialize, Deserializer, Serialize};
+use sqlx::{postgres::PgRow, FromRow, Row};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::error::ApiError;
 
 /// Pagination query parameters
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Deserialize, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct PaginationParams {
     /// Page number (1-indexed)
-    pub page: Option<u32>,
+    #[param(minimum = 1)]
+    #[serde(default, deserialize_with = "page_number")]
+    pub page: Option<i64>,
 
     /// Items per page
-    pub per_page: Option<u32>,
+    #[param(minimum = 1)]
+    #[serde(default, deserialize_with = "page_number")]
+    pub per_page: Option<i64>,
 }
 
-impl PaginationParams {
-    /// Maximum allowed items per page
-    pub const MAX_PER_PAGE: u32 = 100;
+/// List endpoints `#[serde(flatten)]` these params next to their filters,
+/// and flattened query values reach serde as strings, so accept both forms
+fn page_number<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<i64>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Number {
+        Int(i64),
+        Text(String),
+    }
 
-    /// Returns the clamped per_page value
-    pub fn per_page(&self) -> u32 {
-        self.per_page.unwrap_or(20).min(Self::MAX_PER_PAGE).max(1)
+    match Option::<Number>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(Number::Int(n)) => Ok(Some(n)),
+        Some(Number::Text(s)) => s.trim().parse().map(Some).map_err(|_| {
+            de::Error::invalid_value(de::Unexpected::Str(&s), &"a page number")
+        }),
     }
+}
+
+/// Highest page number accepted; deeper pages should narrow the filter instead
+pub const MAX_PAGE: u32 = 10_000;
+
+/// Page size limits for one kind of list
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageLimits {
+    pub default_per_page: u32,
+    pub max_per_page: u32,
+}
 
-    /// Returns the page (1-indexed, minimum 1)
-    pub fn page(&self) -> u32 {
-        self.page.unwrap_or(1).max(1)
+impl PageLimits {
+    pub const fn new(default_per_page: u32, max_per_page: u32) -> Self {
+        Self {
+            default_per_page,
+            max_per_page,
+        }
     }
+}
 
-    /// Calculate SQL OFFSET
-    pub fn offset(&self) -> u32 {
-        (self.page() - 1) * self.per_page()
+/// A validated page of a list: what to fetch and how to describe it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageRequest {
+    pub page: u32,
+    pub per_page: u32,
+}
+
+impl PageRequest {
+    /// SQL LIMIT
+    pub fn limit(&self) -> i64 {
+        self.per_page as i64
     }
 
-    /// Calculate SQL LIMIT
-    pub fn limit(&self) -> u32 {
-        self.per_page()
+    /// SQL OFFSET
+    pub fn offset(&self) -> i64 {
+        (self.page as i64 - 1) * self.per_page as i64
+    }
+
+    /// Pagination metadata for a list of `total_items`
+    pub fn meta(&self, total_items: u64) -> PaginationMeta {
+        let total_pages = match self.per_page {
+            0 => 0,
+            per_page => total_items.div_ceil(per_page as u64) as u32,
+        };
+
+        PaginationMeta {
+            page: self.page,
+            per_page: self.per_page,
+            total_items,
+            total_pages,
+            has_next: self.page < total_pages,
+            has_prev: self.page > 1,
+        }
+    }
+}
+
+/// Select list entry that adds the list's total to every row of a page, so
+/// one query returns both. Read rows as [`Counted`] and the total with
+/// [`PageRequest::counted_total`].
+pub const TOTAL_COUNT_COLUMN: &str = "COUNT(*) OVER() AS total_count";
+
+/// A page row fetched alongside [`TOTAL_COUNT_COLUMN`]
+#[derive(Debug)]
+pub struct Counted<T> {
+    pub row: T,
+    pub total_count: i64,
+}
+
+impl<'r, T: FromRow<'r, PgRow>> FromRow<'r, PgRow> for Counted<T> {
+    fn from_row(row: &'r PgRow) -> Result<Self, sqlx::Error> {
+        Ok(Self {
+            row: T::from_row(row)?,
+            total_count: row.try_get("total_count")?,
+        })
     }
 }
 
+impl PageRequest {
+    /// The list's total from a page fetched with [`TOTAL_COUNT_COLUMN`].
+    ///
+    /// An empty page past the end carries no count; that gives `None`, and the
+    /// caller falls back to a separate `COUNT(*)`. The window count can also
+    /// make the planner read every match rather than stop at the page, so
+    /// lists where that hurts keep the two-query form.
+    pub fn counted_total<T>(&self, rows: &[Counted<T>]) -> Option<u64> {
+        match rows.first() {
+            Some(first) => Some(first.total_count.max(0) as u64),
+            None if self.page == 1 => Some(0),
+            None => None,
+        }
+    }
+}
+
+/// Resolve query params against a resource's limits.
+///
+/// A missing `per_page` uses the default and a large one is capped at the
+/// maximum. Zero or negative values and pages past `MAX_PAGE` are rejected
+/// rather than coerced.
+pub fn resolve_pagination(params: &PaginationParams, limits: PageLimits) -> Result<PageRequest, ApiError> {
+    let per_page = match params.per_page {
+        Some(per_page) if per_page < 1 => {
+            return Err(ApiError::bad_request("per_page must be at least 1"));
+        }
+        Some(per_page) => per_page.min(limits.max_per_page as i64) as u32,
+        None => limits.default_per_page,
+    };
+
+    let page = match params.page {
+        Some(page) if page < 1 => return Err(ApiError::bad_request("page must be at least 1")),
+        Some(page) if page > MAX_PAGE as i64 => {
+            return Err(ApiError::bad_request(format!("page must be at most {}", MAX_PAGE)));
+        }
+        Some(page) => page as u32,
+        None => 1,
+    };
+
+    Ok(PageRequest { page, per_page })
+}
+
 /// Pagination metadata
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct PaginationMeta {
     pub page: u32,
     pub per_page: u32,
@@ -54,41 +175,220 @@ pub struct PaginationMeta {
     pub has_prev: bool,
 }
 
-impl PaginationMeta {
-    pub fn new(params: &PaginationParams, total_items: u64) -> Self {
-        let per_page = params.per_page();
-        let page = params.page();
-        let total_pages = ((total_items as f64) / (per_page as f64)).ceil() as u32;
+/// Paginated response wrapper
+#[derive(Debug, Serialize)]
+pub struct Paginated<T: Serialize> {
+    pub data: Vec<T>,
+    pub pagination: PaginationMeta,
+}
 
+impl<T: Serialize> Paginated<T> {
+    pub fn new(data: Vec<T>, page: &PageRequest, total_items: u64) -> Self {
         Self {
-            page,
-            per_page,
-            total_items,
-            total_pages,
-            has_next: page < total_pages,
-            has_prev: page > 1,
+            data,
+            pagination: page.meta(total_items),
         }
     }
 }
 
-/// Paginated response wrapper
+impl<T: Serialize> IntoResponse for Paginated<T> {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
+
+/// Cursor pagination query parameters (newest-first feeds)
+#[derive(Debug, Clone, Deserialize, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CursorParams {
+    /// Opaque cursor from a previous page's `next_cursor`; returns older items
+    pub before: Option<String>,
+
+    /// Items per page
+    pub limit: Option<u32>,
+}
+
+impl CursorParams {
+    /// Maximum allowed items per page
+    pub const MAX_LIMIT: u32 = 100;
+
+    /// Returns the clamped limit value
+    pub fn limit(&self) -> u32 {
+        self.limit.unwrap_or(50).clamp(1, Self::MAX_LIMIT)
+    }
+
+    /// Decode the `before` cursor, if any
+    pub fn before(&self) -> Result<Option<Cursor>, crate::error::ApiError> {
+        self.before
+            .as_deref()
+            .map(|c| {
+                Cursor::decode(c)
+                    .ok_or_else(|| crate::error::ApiError::bad_request("Invalid cursor"))
+            })
+            .transpose()
+    }
+}
+
+/// Position in a `(created_at, id)` ordered feed.
+///
+/// Encoded as `<unix micros>_<uuid>` so it is URL-safe and stable across pages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cursor {
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub id: uuid::Uuid,
+}
+
+impl Cursor {
+    pub fn new(created_at: chrono::DateTime<chrono::Utc>, id: uuid::Uuid) -> Self {
+        Self { created_at, id }
+    }
+
+    pub fn encode(&self) -> String {
+        format!("{}_{}", self.created_at.timestamp_micros(), self.id)
+    }
+
+    pub fn decode(s: &str) -> Option<Self> {
+        let (micros, id) = s.split_once('_')?;
+        Some(Self {
+            created_at: chrono::DateTime::from_timestamp_micros(micros.parse().ok()?)?,
+            id: id.parse().ok()?,
+        })
+    }
+}
+
+/// Cursor-paginated response wrapper
 #[derive(Debug, Serialize)]
-pub struct Paginated<T: Serialize> {
+pub struct CursorPage<T: Serialize> {
     pub data: Vec<T>,
-    pub pagination: PaginationMeta,
+    pub has_more: bool,
+    pub next_cursor: Option<String>,
 }
 
-impl<T: Serialize> Paginated<T> {
-    pub fn new(data: Vec<T>, params: &PaginationParams, total_items: u64) -> Self {
+impl<T: Serialize> CursorPage<T> {
+    /// Build a page from rows fetched with `LIMIT limit + 1`; the extra row only
+    /// signals that more items exist and is dropped.
+    pub fn from_rows(mut data: Vec<T>, limit: u32, cursor_of: impl Fn(&T) -> Cursor) -> Self {
+        let has_more = data.len() > limit as usize;
+        data.truncate(limit as usize);
+        let next_cursor = if has_more {
+            data.last().map(|last| cursor_of(last).encode())
+        } else {
+            None
+        };
+
         Self {
             data,
-            pagination: PaginationMeta::new(params, total_items),
+            has_more,
+            next_cursor,
         }
     }
 }
 
-impl<T: Serialize> IntoResponse for Paginated<T> {
+impl<T: Serialize> IntoResponse for CursorPage<T> {
     fn into_response(self) -> Response {
         Json(self).into_response()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use axum::extract::Query;
+    use axum::http::Uri;
+
+    #[derive(Debug, Deserialize)]
+    struct ListParams {
+        #[serde(flatten)]
+        pagination: PaginationParams,
+        status: Option<String>,
+    }
+
+    #[test]
+    fn page_numbers_parse_when_flattened() {
+        let uri: Uri = "/items?page=2&per_page=25&status=open".parse().unwrap();
+        let Query(params) = Query::<ListParams>::try_from_uri(&uri).unwrap();
+        assert_eq!(params.pagination.page, Some(2));
+        assert_eq!(params.pagination.per_page, Some(25));
+
+        let uri: Uri = "/items?status=open".parse().unwrap();
+        let Query(params) = Query::<ListParams>::try_from_uri(&uri).unwrap();
+        assert_eq!(params.pagination.page, None);
+    }
+
+    #[test]
+    fn page_numbers_parse_on_their_own() {
+        let uri: Uri = "/items?page=3".parse().unwrap();
+        let Query(params) = Query::<PaginationParams>::try_from_uri(&uri).unwrap();
+        assert_eq!(params.page, Some(3));
+    }
+
+    #[test]
+    fn malformed_page_number_is_rejected() {
+        let uri: Uri = "/items?page=two".parse().unwrap();
+        assert!(Query::<ListParams>::try_from_uri(&uri).is_err());
+    }
+
+    /// Fastest `EXPLAIN ANALYZE` execution time of `sql` over a few runs, in ms
+    async fn execution_ms(conn: &mut sqlx::PgConnection, sql: &str) -> f64 {
+        let mut best = f64::MAX;
+        for _ in 0..3 {
+            let plan: serde_json::Value = sqlx::query_scalar(&format!("EXPLAIN (ANALYZE, FORMAT JSON) {}", sql))
+                .fetch_one(&mut *conn)
+                .await
+                .unwrap();
+            best = best.min(plan[0]["Execution Time"].as_f64().unwrap());
+        }
+        best
+    }
+
+    /// The first page of a large materials list, with and without the window
+    /// count. The window makes the page query read every match instead of
+    /// stopping once the page is sorted, which costs more than a separate
+    /// `COUNT(*)`; this is why the materials list keeps the two-query form.
+    #[tokio::test]
+    async fn window_count_is_slower_than_a_separate_count_on_large_material_lists() {
+        let Some(db) = crate::test_support::db().await else { return };
+        let owner_id = crate::test_support::user(&db, "gc").await;
+        let project_id = crate::test_support::project(&db, owner_id).await;
+
+        // Seeded rows roll back with the transaction
+        let mut tx = db.begin().await.unwrap();
+        sqlx::query(
+            r#"
+            INSERT INTO extracted_materials (project_id, name, trade_category, room, confidence)
+            SELECT $1, 'Material ' || g, (ARRAY['electrical', 'plumbing', 'hvac', 'framing'])[g % 4 + 1],
+                   'Room ' || g % 50, 0.9
+            FROM generate_series(1, 50000) g
+            "#,
+        )
+        .bind(project_id)
+        .execute(&mut *tx)
+        .await
+        .unwrap();
+        sqlx::query("ANALYZE extracted_materials").execute(&mut *tx).await.unwrap();
+
+        let page = |count: &str| {
+            format!(
+                "SELECT id, name, trade_category, room, confidence{} FROM extracted_materials \
+                 WHERE project_id = '{}' ORDER BY trade_category, name LIMIT 50",
+                count, project_id
+            )
+        };
+        let windowed = execution_ms(&mut tx, &page(&format!(", {}", TOTAL_COUNT_COLUMN))).await;
+        let page_only = execution_ms(&mut tx, &page("")).await;
+        let count = execution_ms(
+            &mut tx,
+            &format!("SELECT COUNT(*) FROM extracted_materials WHERE project_id = '{}'", project_id),
+        )
+        .await;
+
+        assert!(
+            windowed > page_only + count,
+            "window count {:.1}ms, page {:.1}ms + count {:.1}ms",
+            windowed,
+            page_only,
+            count
+        );
+    }
+}
@@ -1,4 +1,10 @@
 //! Standard API response types
+//!
+//! Every successful response uses one of these shapes:
+//! - `DataResponse` / `Paginated`: `{ "data": ... }` (plus `pagination`)
+//! - `Created`: `201` with `{ "data": ... }`, usually just the new `id`
+//! - `MessageResponse`: `{ "message": ... }` for mutations with nothing to return
+//! - `NoContent`: `204` for deletes
 
 #![allow(dead_code)]
 
@@ -8,6 +14,8 @@ use axum::{
     Json,
 };
 use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
 
 /// Generic API response wrapper
 #[derive(Debug, Serialize)]
@@ -55,7 +63,7 @@ impl<T: Serialize> IntoResponse for DataResponse<T> {
 }
 
 /// Simple message response
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct MessageResponse {
     pub message: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -84,16 +92,29 @@ impl IntoResponse for MessageResponse {
     }
 }
 
-/// Helper for creating responses with status codes
+/// Created resource: `201 Created` with the body in the standard `data` envelope
 pub struct Created<T: Serialize>(pub T);
 
+impl Created<CreatedId> {
+    /// Created response carrying only the new resource's id
+    pub fn id(id: Uuid) -> Self {
+        Self(CreatedId { id })
+    }
+}
+
 impl<T: Serialize> IntoResponse for Created<T> {
     fn into_response(self) -> Response {
-        (StatusCode::CREATED, Json(self.0)).into_response()
+        (StatusCode::CREATED, DataResponse::new(self.0)).into_response()
     }
 }
 
-/// Helper for no content responses
+/// Id of a newly created resource
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CreatedId {
+    pub id: Uuid,
+}
+
+/// Helper for no content responses (deletes)
 pub struct NoContent;
 
 impl IntoResponse for NoContent {
@@ -103,4 +124,4 @@ impl IntoResponse for NoContent {
 }
 
 // Re-export pagination types for convenience
-pub use super::pagination::{Paginated, PaginationMeta};
+pub use super::pagination::Paginated;
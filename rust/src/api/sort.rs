@@ -0,0 +1,85 @@
+//! Sorting for list endpoints
+//!
+//! Each sortable endpoint declares the `sort_by` keys it accepts and the SQL
+//! expression each one orders by. Only those vetted expressions ever reach an
+//! `ORDER BY`; unknown keys are rejected rather than silently replaced.
+
+use std::fmt;
+
+use crate::error::ApiError;
+
+/// Sort direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+impl SortOrder {
+    fn as_sql(self) -> &'static str {
+        match self {
+            SortOrder::Asc => "ASC",
+            SortOrder::Desc => "DESC",
+        }
+    }
+}
+
+/// Client sort keys mapped to the SQL expressions they order by.
+/// The first entry is the default.
+pub type SortFields = &'static [(&'static str, &'static str)];
+
+/// A validated sort, rendered as `<expression> <ASC|DESC>`
+#[derive(Debug, Clone, Copy)]
+pub struct SortSpec {
+    column: &'static str,
+    order: SortOrder,
+}
+
+impl SortSpec {
+    /// Resolve `sort_by` / `sort_order` query values against `fields`.
+    ///
+    /// Missing values fall back to the first field and `default_order`;
+    /// unrecognised values are a 400.
+    pub fn parse(
+        fields: SortFields,
+        default_order: SortOrder,
+        sort_by: Option<&str>,
+        sort_order: Option<&str>,
+    ) -> Result<Self, ApiError> {
+        let column = match sort_by {
+            None => fields.first().map(|(_, column)| *column).unwrap_or("1"),
+            Some(key) => fields
+                .iter()
+                .find(|(k, _)| *k == key)
+                .map(|(_, column)| *column)
+                .ok_or_else(|| {
+                    let allowed: Vec<&str> = fields.iter().map(|(k, _)| *k).collect();
+                    ApiError::bad_request(format!(
+                        "Unknown sort_by '{}'; expected one of: {}",
+                        key,
+                        allowed.join(", ")
+                    ))
+                })?,
+        };
+
+        let order = match sort_order {
+            None => default_order,
+            Some(o) if o.eq_ignore_ascii_case("asc") => SortOrder::Asc,
+            Some(o) if o.eq_ignore_ascii_case("desc") => SortOrder::Desc,
+            Some(o) => {
+                return Err(ApiError::bad_request(format!(
+                    "Unknown sort_order '{}'; expected asc or desc",
+                    o
+                )))
+            }
+        };
+
+        Ok(Self { column, order })
+    }
+}
+
+impl fmt::Display for SortSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.column, self.order.as_sql())
+    }
+}
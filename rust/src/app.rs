@@ -1,17 +1,27 @@
-use axum::{http::HeaderValue, Router};
+use axum::{
+    body::Body,
+    extract::MatchedPath,
+    http::{HeaderValue, Request},
+    response::Response,
+    Router,
+};
 use sqlx::PgPool;
 use std::sync::Arc;
+use std::time::Duration;
 use tower_http::{
     cors::{AllowHeaders, AllowMethods, CorsLayer},
-    trace::{DefaultMakeSpan, DefaultOnRequest, DefaultOnResponse, TraceLayer},
+    services::ServeDir,
+    trace::{DefaultOnRequest, TraceLayer},
 };
-use tracing::Level;
+use tracing::{field::Empty, Level, Span};
 
 use crate::auth::JwksCache;
 use crate::config::Settings;
-use crate::middleware::request_id_layer;
+use crate::middleware::request_id::{scope_request_id, RequestIdExt};
+use crate::middleware::{body_limit_layers, json_payload_too_large, maintenance_guard, request_id_layer};
 use crate::routes;
-use crate::services::{AiClient, RedisCache};
+use crate::services::feature_flags::FeatureFlags;
+use crate::services::{AiClient, Geocoder, ObjectStorage, RedisCache, SupabaseAuth, UrlSigner};
 
 /// Shared application state
 #[derive(Clone)]
@@ -24,6 +34,16 @@ pub struct AppState {
     /// Shared HTTP client for external API calls (Supabase, etc.)
     /// Reusing a single client avoids expensive per-request allocations
     pub http_client: reqwest::Client,
+    /// Media object storage for avatars and logos
+    pub storage: ObjectStorage,
+    /// Geocoder for marketplace locations
+    pub geocoder: Geocoder,
+    /// Ends sessions on Supabase when they are revoked here
+    pub supabase_auth: SupabaseAuth,
+    /// Signer for time-limited download links
+    pub url_signer: UrlSigner,
+    /// Feature flags, resolved into each request's `AuthContext`
+    pub feature_flags: FeatureFlags,
 }
 
 impl AppState {
@@ -34,7 +54,13 @@ impl AppState {
         cache: RedisCache,
         ai_client: AiClient,
         http_client: reqwest::Client,
+        storage: ObjectStorage,
     ) -> Arc<Self> {
+        let geocoder = Geocoder::new(http_client.clone(), &settings.geocoding_url);
+        let supabase_auth = SupabaseAuth::new(http_client.clone(), &settings);
+        let url_signer = UrlSigner::new(&settings.download_url_secret, settings.download_url_ttl_seconds);
+        let feature_flags = FeatureFlags::new(db.clone());
+
         Arc::new(Self {
             db,
             settings,
@@ -42,6 +68,11 @@ impl AppState {
             cache,
             ai_client,
             http_client,
+            storage,
+            geocoder,
+            supabase_auth,
+            url_signer,
+            feature_flags,
         })
     }
 }
@@ -51,19 +82,52 @@ pub fn create_app(state: Arc<AppState>) -> Router {
     // Build CORS layer
     let cors = build_cors_layer(&state.settings);
 
-    // Build trace layer (use DEBUG for spans to reduce overhead at INFO level)
+    // Build trace layer. Every event logged while handling a request carries
+    // the span's request_id, route and (once authenticated) user_id; status
+    // and latency are recorded when the response is ready, and requests over
+    // the slow threshold are logged as warnings.
+    let slow_request = Duration::from_millis(state.settings.slow_request_threshold_ms);
     let trace_layer = TraceLayer::new_for_http()
-        .make_span_with(DefaultMakeSpan::new().level(Level::DEBUG))
+        .make_span_with(request_span)
         .on_request(DefaultOnRequest::new().level(Level::DEBUG))
-        .on_response(DefaultOnResponse::new().level(Level::DEBUG));
+        .on_response(move |response: &Response, latency: Duration, span: &Span| {
+            span.record("status", response.status().as_u16());
+            span.record("latency_ms", latency.as_millis() as u64);
+            if latency >= slow_request {
+                tracing::warn!(
+                    threshold_ms = slow_request.as_millis() as u64,
+                    "Slow request"
+                );
+            } else {
+                tracing::debug!("finished processing request");
+            }
+        });
 
     // Request ID layers
     let (set_request_id, propagate_request_id) = request_id_layer();
 
+    // Body size limits (upload routes raise the default for themselves)
+    let (default_body_limit, body_hard_cap) = body_limit_layers(
+        state.settings.max_request_body_bytes,
+        routes::max_body_limit(&state.settings),
+    );
+
+    // Serve stored media when it is exposed under a local path
+    let media_path = state.settings.storage_public_url.trim_end_matches('/');
+
     // Build router (routes at root level, no /api prefix)
-    Router::new()
-        .merge(routes::api_router())
+    let mut router = Router::new().merge(routes::api_router(&state.settings));
+    if media_path.len() > 1 && media_path.starts_with('/') {
+        router = router.nest_service(media_path, ServeDir::new(state.storage.root()));
+    }
+
+    router
         // Middleware stack (applied bottom-up)
+        .layer(axum::middleware::from_fn_with_state(state.clone(), maintenance_guard))
+        .layer(default_body_limit)
+        .layer(body_hard_cap)
+        .layer(axum::middleware::map_response(json_payload_too_large))
+        .layer(axum::middleware::from_fn(scope_request_id))
         .layer(propagate_request_id)
         .layer(trace_layer)
         .layer(set_request_id)
@@ -71,6 +135,25 @@ pub fn create_app(state: Arc<AppState>) -> Router {
         .with_state(state)
 }
 
+/// Per-request span. `user_id` is filled in by the auth extractors.
+fn request_span(request: &Request<Body>) -> Span {
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(MatchedPath::as_str)
+        .unwrap_or_else(|| request.uri().path());
+
+    tracing::info_span!(
+        "request",
+        method = %request.method(),
+        route,
+        request_id = request.headers().request_id().unwrap_or_default(),
+        user_id = Empty,
+        status = Empty,
+        latency_ms = Empty,
+    )
+}
+
 fn build_cors_layer(settings: &Settings) -> CorsLayer {
     let origins: Vec<HeaderValue> = settings
         .cors_allow_origins
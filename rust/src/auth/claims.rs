@@ -26,6 +26,10 @@ pub struct Claims {
     #[serde(default)]
     pub email: Option<String>,
 
+    /// Supabase auth session the token was issued for - optional
+    #[serde(default)]
+    pub session_id: Option<String>,
+
     /// User role - optional
     #[serde(default)]
     pub role: Option<String>,
@@ -46,4 +50,9 @@ pub struct AppMetadata {
 
     #[serde(default)]
     pub providers: Option<Vec<String>>,
+
+    /// "gc" or "sub", when set on the account by the backend. Unlike
+    /// `user_metadata`, users cannot edit app metadata themselves.
+    #[serde(default)]
+    pub user_type: Option<String>,
 }
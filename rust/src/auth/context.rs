@@ -1,4 +1,6 @@
 use super::Claims;
+use crate::domain::auth::UserType;
+use crate::services::feature_flags::FlagSet;
 use uuid::Uuid;
 
 /// Authenticated user context extracted from JWT
@@ -25,6 +27,9 @@ pub struct AuthContext {
 
     /// JWT claims
     claims: Claims,
+
+    /// Feature flags enabled for the user
+    flags: FlagSet,
 }
 
 impl AuthContext {
@@ -39,9 +44,16 @@ impl AuthContext {
             audience: claims.aud.clone(),
             token: token.to_string(),
             claims: claims.clone(),
+            flags: FlagSet::default(),
         })
     }
 
+    /// Attach the user's resolved feature flags
+    pub fn with_flags(mut self, flags: FlagSet) -> Self {
+        self.flags = flags;
+        self
+    }
+
     #[allow(dead_code)]
     #[deprecated(note = "Use from_claims_with_token instead")]
     pub fn from_claims(claims: &Claims) -> Result<Self, &'static str> {
@@ -57,4 +69,24 @@ impl AuthContext {
     pub fn claims(&self) -> &Claims {
         &self.claims
     }
+
+    /// Whether feature flag `key` is on for this user
+    #[allow(dead_code)]
+    pub fn has_flag(&self, key: &str) -> bool {
+        self.flags.contains(key)
+    }
+
+    /// Feature flags enabled for this user
+    pub fn flags(&self) -> &FlagSet {
+        &self.flags
+    }
+
+    /// User type from the token's app metadata, if the token carries one
+    pub fn user_type(&self) -> Option<UserType> {
+        self.claims
+            .app_metadata
+            .as_ref()
+            .and_then(|m| m.user_type.as_deref())
+            .and_then(UserType::parse)
+    }
 }
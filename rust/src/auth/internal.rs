@@ -0,0 +1,66 @@
+//! Authentication for service-to-service routes.
+//!
+//! The AI service calls back into `/internal/*` routes with the shared
+//! `X-Internal-Token` it already uses for outbound requests.
+
+use axum::{
+    async_trait,
+    extract::FromRequestParts,
+    http::{request::Parts, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use std::sync::Arc;
+
+use crate::app::AppState;
+use crate::error::{ErrorCode, ErrorResponse};
+
+/// Header carrying the shared internal service token
+pub const X_INTERNAL_TOKEN: &str = "x-internal-token";
+
+/// Extractor that requires a valid internal service token
+#[derive(Debug, Clone, Copy)]
+pub struct RequireInternalToken;
+
+#[derive(Debug)]
+pub struct InternalAuthError;
+
+impl IntoResponse for InternalAuthError {
+    fn into_response(self) -> Response {
+        let body = ErrorResponse {
+            code: ErrorCode::Unauthorized,
+            message: "Invalid internal token".to_string(),
+            request_id: None,
+        };
+
+        (StatusCode::UNAUTHORIZED, Json(body)).into_response()
+    }
+}
+
+#[async_trait]
+impl FromRequestParts<Arc<AppState>> for RequireInternalToken {
+    type Rejection = InternalAuthError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &Arc<AppState>,
+    ) -> Result<Self, Self::Rejection> {
+        let token = parts
+            .headers
+            .get(X_INTERNAL_TOKEN)
+            .and_then(|v| v.to_str().ok())
+            .ok_or(InternalAuthError)?;
+
+        if !constant_time_eq(token.as_bytes(), state.settings.ai_service_token.as_bytes()) {
+            tracing::warn!("Internal route called with invalid token");
+            return Err(InternalAuthError);
+        }
+
+        Ok(RequireInternalToken)
+    }
+}
+
+/// Compare secrets without short-circuiting on the first mismatched byte
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
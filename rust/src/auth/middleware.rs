@@ -1,7 +1,11 @@
 use axum::{
     async_trait,
     extract::FromRequestParts,
-    http::{header::AUTHORIZATION, request::Parts, StatusCode},
+    http::{
+        header::{AUTHORIZATION, COOKIE},
+        request::Parts,
+        HeaderMap, StatusCode,
+    },
     response::{IntoResponse, Response},
     Json,
 };
@@ -9,11 +13,16 @@ use std::sync::Arc;
 
 use super::AuthContext;
 use crate::app::AppState;
-use crate::error::ErrorResponse;
+use crate::error::{ErrorCode, ErrorResponse};
 
 /// Extractor that requires authentication
 /// Use this in route handlers to require a valid JWT
 ///
+/// The token is read from the `Authorization: Bearer` header. When cookie
+/// auth is enabled (`AUTH_COOKIE_ENABLED`), a request without that header
+/// may instead carry the token in the `AUTH_COOKIE_NAME` cookie; the header
+/// always wins when both are present.
+///
 /// Example:
 /// ```ignore
 /// async fn protected_route(auth: RequireAuth) -> impl IntoResponse {
@@ -48,7 +57,7 @@ impl IntoResponse for AuthError {
         };
 
         let body = ErrorResponse {
-            code: "UNAUTHORIZED".to_string(),
+            code: ErrorCode::Unauthorized,
             message: message.to_string(),
             request_id: None,
         };
@@ -65,18 +74,19 @@ impl FromRequestParts<Arc<AppState>> for RequireAuth {
         parts: &mut Parts,
         state: &Arc<AppState>,
     ) -> Result<Self, Self::Rejection> {
-        // Extract Authorization header
-        let auth_header = parts
-            .headers
-            .get(AUTHORIZATION)
-            .ok_or(AuthError::MissingToken)?
-            .to_str()
-            .map_err(|_| AuthError::InvalidFormat)?;
-
-        // Parse Bearer token
-        let token = auth_header
-            .strip_prefix("Bearer ")
-            .ok_or(AuthError::InvalidFormat)?;
+        // Prefer the Authorization header; fall back to the auth cookie
+        let token = match parts.headers.get(AUTHORIZATION) {
+            Some(auth_header) => auth_header
+                .to_str()
+                .map_err(|_| AuthError::InvalidFormat)?
+                .strip_prefix("Bearer ")
+                .ok_or(AuthError::InvalidFormat)?,
+            None if state.settings.auth_cookie_enabled => {
+                cookie_value(&parts.headers, &state.settings.auth_cookie_name)
+                    .ok_or(AuthError::MissingToken)?
+            }
+            None => return Err(AuthError::MissingToken),
+        };
 
         if token.is_empty() {
             return Err(AuthError::MissingToken);
@@ -94,7 +104,23 @@ impl FromRequestParts<Arc<AppState>> for RequireAuth {
             tracing::warn!(error = %e, "Failed to build auth context");
             AuthError::InvalidToken(e.to_string())
         })?;
+        let flags = state.feature_flags.for_user(context.user_id);
+        let context = context.with_flags(flags);
+
+        tracing::Span::current().record("user_id", tracing::field::display(context.user_id));
 
         Ok(RequireAuth(context))
     }
 }
+
+/// Value of the cookie `name` from the request's `Cookie` headers
+fn cookie_value<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers
+        .get_all(COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(';'))
+        .filter_map(|pair| pair.trim().split_once('='))
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| value.trim_matches('"'))
+}
@@ -1,9 +1,13 @@
 pub mod claims;
 pub mod context;
+pub mod internal;
 pub mod jwks;
 pub mod middleware;
+pub mod role;
 
 pub use claims::Claims;
 pub use context::AuthContext;
+pub use internal::RequireInternalToken;
 pub use jwks::JwksCache;
 pub use middleware::RequireAuth;
+pub use role::{RequireGc, RequireSub};
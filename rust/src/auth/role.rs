@@ -0,0 +1,115 @@
+use axum::{
+    async_trait,
+    extract::FromRequestParts,
+    http::request::Parts,
+    response::{IntoResponse, Response},
+};
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use super::RequireAuth;
+use crate::app::AppState;
+use crate::domain::auth::UserType;
+use crate::error::{map_db_err, ApiError};
+
+/// A user type a route can require
+pub trait RequiredUserType: Send + Sync + 'static {
+    const USER_TYPE: UserType;
+    const REJECTION: &'static str;
+}
+
+/// General contractor accounts
+#[derive(Debug, Clone)]
+pub struct Gc;
+
+impl RequiredUserType for Gc {
+    const USER_TYPE: UserType = UserType::Gc;
+    const REJECTION: &'static str = "This action is only available to general contractors";
+}
+
+/// Subcontractor accounts
+#[derive(Debug, Clone)]
+pub struct Sub;
+
+impl RequiredUserType for Sub {
+    const USER_TYPE: UserType = UserType::Sub;
+    const REJECTION: &'static str = "This action is only available to subcontractors";
+}
+
+/// Extractor that requires an authenticated user of type `R`
+///
+/// The user type comes from the token's `app_metadata.user_type` claim, and
+/// from `profiles.user_type` for tokens issued without it.
+///
+/// Example:
+/// ```ignore
+/// async fn sub_only_route(auth: RequireSub) -> impl IntoResponse {
+///     format!("Hello, subcontractor {}", auth.user_id)
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RequireRole<R: RequiredUserType> {
+    pub auth: RequireAuth,
+    _role: PhantomData<R>,
+}
+
+/// Requires a general contractor
+pub type RequireGc = RequireRole<Gc>;
+
+/// Requires a subcontractor
+pub type RequireSub = RequireRole<Sub>;
+
+impl<R: RequiredUserType> std::ops::Deref for RequireRole<R> {
+    type Target = RequireAuth;
+
+    fn deref(&self) -> &Self::Target {
+        &self.auth
+    }
+}
+
+#[cfg(test)]
+impl<R: RequiredUserType> RequireRole<R> {
+    /// Wrap `auth` without checking its user type
+    pub fn assume(auth: RequireAuth) -> Self {
+        Self {
+            auth,
+            _role: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<R: RequiredUserType> FromRequestParts<Arc<AppState>> for RequireRole<R> {
+    type Rejection = Response;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &Arc<AppState>,
+    ) -> Result<Self, Self::Rejection> {
+        let auth = RequireAuth::from_request_parts(parts, state)
+            .await
+            .map_err(IntoResponse::into_response)?;
+
+        let user_type = match auth.user_type() {
+            Some(user_type) => Some(user_type),
+            None => {
+                let stored: Option<String> =
+                    sqlx::query_scalar("SELECT user_type FROM profiles WHERE id = $1")
+                        .bind(auth.user_id)
+                        .fetch_optional(&state.db)
+                        .await
+                        .map_err(|e| map_db_err("require_role")(e).into_response())?;
+                stored.as_deref().and_then(UserType::parse)
+            }
+        };
+
+        if user_type != Some(R::USER_TYPE) {
+            return Err(ApiError::forbidden(R::REJECTION).into_response());
+        }
+
+        Ok(Self {
+            auth,
+            _role: PhantomData,
+        })
+    }
+}
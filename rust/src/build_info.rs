@@ -0,0 +1,32 @@
+//! What is running: crate version, commit and build time (embedded by
+//! `build.rs`) and the deployment environment. Nothing here is secret, so it
+//! is served without authentication.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::config::Environment;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BuildInfo {
+    pub version: &'static str,
+    pub git_sha: &'static str,
+    pub built_at: Option<DateTime<Utc>>,
+    pub env: &'static str,
+}
+
+impl BuildInfo {
+    pub fn new(env: &Environment) -> Self {
+        let built_at = env!("BUILD_TIMESTAMP")
+            .parse::<i64>()
+            .ok()
+            .and_then(|secs| DateTime::from_timestamp(secs, 0));
+
+        Self {
+            version: env!("CARGO_PKG_VERSION"),
+            git_sha: env!("BUILD_GIT_SHA"),
+            built_at,
+            env: env.as_str(),
+        }
+    }
+}
@@ -1,5 +1,9 @@
 use anyhow::{Context, Result};
 use std::env;
+use std::time::Duration;
+
+use crate::api::pagination::PageLimits;
+use crate::domain::ai::AiOperation;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Environment {
@@ -17,7 +21,14 @@ impl Environment {
         }
     }
 
-    #[allow(dead_code)]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Dev => "dev",
+            Self::Staging => "staging",
+            Self::Prod => "prod",
+        }
+    }
+
     pub fn is_dev(&self) -> bool {
         matches!(self, Self::Dev)
     }
@@ -28,33 +39,427 @@ impl Environment {
     }
 }
 
+/// Log output format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    /// One JSON object per line, for log aggregation
+    Json,
+    /// Multi-line human-readable output
+    Pretty,
+    /// Single-line human-readable output
+    Compact,
+}
+
+impl LogFormat {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "json" => Some(Self::Json),
+            "pretty" => Some(Self::Pretty),
+            "compact" => Some(Self::Compact),
+            _ => None,
+        }
+    }
+}
+
+/// Per-operation AI request timeouts. Operations without an override use `default`.
+#[derive(Debug, Clone, Copy)]
+pub struct AiTimeouts {
+    pub default: Duration,
+    pub summary: Duration,
+    pub trade_scopes: Duration,
+    pub tender_scope_doc: Duration,
+    pub qna: Duration,
+}
+
+impl AiTimeouts {
+    fn from_env(default_seconds: u64) -> Self {
+        let op = |key: &str| {
+            Duration::from_secs(
+                env::var(key)
+                    .ok()
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(default_seconds),
+            )
+        };
+
+        Self {
+            default: Duration::from_secs(default_seconds),
+            summary: op("AI_TIMEOUT_SUMMARY_SECONDS"),
+            trade_scopes: op("AI_TIMEOUT_TRADE_SCOPES_SECONDS"),
+            tender_scope_doc: op("AI_TIMEOUT_TENDER_SCOPE_DOC_SECONDS"),
+            qna: op("AI_TIMEOUT_QNA_SECONDS"),
+        }
+    }
+}
+
+/// Circuit breaker thresholds for an upstream service. A threshold of 0
+/// disables the breaker.
+#[derive(Debug, Clone, Copy)]
+pub struct CircuitBreakerSettings {
+    /// Consecutive failures that open the circuit
+    pub failure_threshold: u32,
+    /// How long the circuit stays open before a probe is let through
+    pub cooldown_seconds: u64,
+}
+
+impl CircuitBreakerSettings {
+    fn from_env(prefix: &str) -> Self {
+        let parse = |key: &str, default: u64| {
+            env::var(format!("{}_{}", prefix, key))
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(default)
+        };
+
+        Self {
+            failure_threshold: parse("CIRCUIT_FAILURE_THRESHOLD", 5) as u32,
+            cooldown_seconds: parse("CIRCUIT_COOLDOWN_SECONDS", 30).max(1),
+        }
+    }
+}
+
+/// What maintenance mode leaves open. Paths are matched by prefix against
+/// the request path as routed here (e.g. `/auth/`).
+#[derive(Debug, Clone)]
+pub struct MaintenanceSettings {
+    /// Path prefixes whose mutating requests are still served
+    pub allowed_paths: Vec<String>,
+    /// `Retry-After` used when the admin toggling maintenance mode gives none
+    pub retry_after_seconds: u64,
+}
+
+impl MaintenanceSettings {
+    fn from_env() -> Self {
+        let allowed_paths = env::var("MAINTENANCE_ALLOWED_PATHS")
+            .unwrap_or_else(|_| "/auth/".to_string())
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect();
+
+        Self {
+            allowed_paths,
+            retry_after_seconds: env::var("MAINTENANCE_RETRY_AFTER_SECONDS")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(300),
+        }
+    }
+}
+
+/// Per-operation AI model selection, sent to the AI service with each
+/// request. `None` leaves the choice to the AI service's own default.
+#[derive(Debug, Clone, Default)]
+pub struct AiModels {
+    pub summary: Option<String>,
+    pub trade_scopes: Option<String>,
+    pub tender_scope_doc: Option<String>,
+    pub qna: Option<String>,
+}
+
+impl AiModels {
+    fn from_env() -> Self {
+        let var = |key: &str| env::var(key).ok().filter(|s| !s.trim().is_empty());
+        let default = var("AI_MODEL");
+        let op = |key: &str| var(key).or_else(|| default.clone());
+
+        Self {
+            summary: op("AI_MODEL_SUMMARY"),
+            trade_scopes: op("AI_MODEL_TRADE_SCOPES"),
+            tender_scope_doc: op("AI_MODEL_TENDER_SCOPE_DOC"),
+            qna: op("AI_MODEL_QNA"),
+        }
+    }
+
+    /// Model configured for `operation`
+    pub fn for_operation(&self, operation: AiOperation) -> Option<&str> {
+        match operation {
+            AiOperation::Summary => self.summary.as_deref(),
+            AiOperation::TradeScopes => self.trade_scopes.as_deref(),
+            AiOperation::TenderScopeDoc => self.tender_scope_doc.as_deref(),
+            AiOperation::Qna => self.qna.as_deref(),
+        }
+    }
+}
+
+/// AI call quotas, counted per call before it is dispatched. 0 = unlimited.
+#[derive(Debug, Clone, Copy)]
+pub struct AiQuotaLimits {
+    /// Calls per user per UTC day
+    pub user_daily: u32,
+    /// Calls per admin per UTC day; admins skip project limits
+    pub admin_daily: u32,
+    /// Successful calls per project, ever
+    pub project_total: u32,
+}
+
+impl AiQuotaLimits {
+    fn from_env() -> Self {
+        let limit = |key: &str, default: u32| {
+            env::var(key)
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(default)
+        };
+
+        Self {
+            user_daily: limit("AI_QUOTA_USER_DAILY", 200),
+            admin_daily: limit("AI_QUOTA_ADMIN_DAILY", 0),
+            project_total: limit("AI_QUOTA_PROJECT_TOTAL", 0),
+        }
+    }
+}
+
+/// Throttling of the unauthenticated auth endpoints (sign-up, sign-in,
+/// token refresh). 0 disables a limit.
+#[derive(Debug, Clone, Copy)]
+pub struct AuthRateLimits {
+    /// Requests per client IP per endpoint in each window
+    pub ip_per_window: u32,
+    /// Length of the per-IP window
+    pub window_seconds: u64,
+    /// Failed sign-ins for one email before it is locked
+    pub max_failed_sign_ins: u32,
+    /// How long failed sign-ins are remembered
+    pub failure_window_seconds: u64,
+    /// First lockout; each further lockout within a day doubles it
+    pub lockout_seconds: u64,
+    /// Longest lockout
+    pub max_lockout_seconds: u64,
+}
+
+impl AuthRateLimits {
+    fn from_env() -> Self {
+        fn parse<T: std::str::FromStr>(key: &str, default: T) -> T {
+            env::var(key)
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(default)
+        }
+
+        Self {
+            ip_per_window: parse("AUTH_RATE_LIMIT_IP", 20),
+            window_seconds: parse("AUTH_RATE_LIMIT_WINDOW_SECONDS", 60).max(1),
+            max_failed_sign_ins: parse("AUTH_MAX_FAILED_SIGN_INS", 5),
+            failure_window_seconds: parse("AUTH_FAILURE_WINDOW_SECONDS", 900).max(1),
+            lockout_seconds: parse("AUTH_LOCKOUT_SECONDS", 300).max(1),
+            max_lockout_seconds: parse("AUTH_MAX_LOCKOUT_SECONDS", 3600).max(1),
+        }
+    }
+}
+
+/// Redis cache TTLs per data category (see `services::cache::CacheNamespace`)
+#[derive(Debug, Clone, Copy)]
+pub struct CacheTtls {
+    /// Anything cached without a category
+    pub default_seconds: u64,
+    /// AI results (plan summaries, trade scopes, Q&A); expensive to recompute
+    pub ai_seconds: u64,
+    /// Paginated lists and directory pages; change with every write
+    pub listing_seconds: u64,
+    /// Reference data (standard trades, contract templates)
+    pub reference_seconds: u64,
+}
+
+impl CacheTtls {
+    fn from_env() -> Self {
+        let seconds = |key: &str, default: u64| {
+            env::var(key)
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(default)
+                .max(1)
+        };
+
+        Self {
+            default_seconds: seconds("REDIS_CACHE_TTL_SECONDS", 3600),
+            ai_seconds: seconds("CACHE_TTL_AI_SECONDS", 7 * 24 * 3600),
+            listing_seconds: seconds("CACHE_TTL_LISTING_SECONDS", 120),
+            reference_seconds: seconds("CACHE_TTL_REFERENCE_SECONDS", 24 * 3600),
+        }
+    }
+}
+
+/// Weight of each field in a subcontractor's profile completeness score.
+/// The score is the populated weight over the total weight.
+#[derive(Debug, Clone, Copy)]
+pub struct CompletenessWeights {
+    pub headline: u32,
+    pub description: u32,
+    pub insurance: u32,
+    pub license: u32,
+    pub certifications: u32,
+    pub portfolio: u32,
+    pub service_areas: u32,
+}
+
+impl CompletenessWeights {
+    fn from_env() -> Self {
+        let weight = |key: &str, default: u32| {
+            env::var(key)
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(default)
+        };
+
+        Self {
+            headline: weight("PROFILE_WEIGHT_HEADLINE", 10),
+            description: weight("PROFILE_WEIGHT_DESCRIPTION", 15),
+            insurance: weight("PROFILE_WEIGHT_INSURANCE", 20),
+            license: weight("PROFILE_WEIGHT_LICENSE", 20),
+            certifications: weight("PROFILE_WEIGHT_CERTIFICATIONS", 10),
+            portfolio: weight("PROFILE_WEIGHT_PORTFOLIO", 15),
+            service_areas: weight("PROFILE_WEIGHT_SERVICE_AREAS", 10),
+        }
+    }
+}
+
+/// Page size defaults and caps per kind of list. Resources without an
+/// override use `default`.
+#[derive(Debug, Clone, Copy)]
+pub struct PaginationConfig {
+    pub default: PageLimits,
+    pub marketplace: PageLimits,
+    pub extraction: PageLimits,
+    pub admin: PageLimits,
+    pub audit_log: PageLimits,
+}
+
+impl PaginationConfig {
+    fn from_env() -> Self {
+        let limits = |prefix: &str, default_per_page: u32, max_per_page: u32| {
+            let var = |suffix: &str| {
+                env::var(format!("{}_{}", prefix, suffix))
+                    .ok()
+                    .and_then(|s| s.parse::<u32>().ok())
+            };
+            let max = var("MAX_PER_PAGE").unwrap_or(max_per_page).max(1);
+            let default = var("DEFAULT_PER_PAGE").unwrap_or(default_per_page).clamp(1, max);
+            PageLimits::new(default, max)
+        };
+
+        let default = limits("PAGINATION", 20, 100);
+        Self {
+            default,
+            marketplace: limits("PAGINATION_MARKETPLACE", default.default_per_page, default.max_per_page),
+            extraction: limits("PAGINATION_EXTRACTION", 50, default.max_per_page),
+            admin: limits("PAGINATION_ADMIN", default.default_per_page, default.max_per_page),
+            audit_log: limits("PAGINATION_AUDIT_LOG", 50, default.max_per_page),
+        }
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct Settings {
     pub env: Environment,
     pub server_addr: String,
 
+    // Request body limits: the default for JSON and form bodies, and the larger
+    // limit for document uploads
+    pub max_request_body_bytes: usize,
+    pub max_upload_bytes: usize,
+
+    // List endpoints: default and maximum `per_page` per kind of list
+    pub pagination: PaginationConfig,
+
+    // Documents with more pages than this are rejected at upload to cap AI cost
+    pub max_document_pages: u32,
+
+    // Logging: output format and `EnvFilter` directives
+    pub log_format: LogFormat,
+    pub log_level: String,
+
+    // Requests and queries slower than these are logged as warnings
+    pub slow_request_threshold_ms: u64,
+    pub slow_query_threshold_ms: u64,
+
     // Database
     pub database_url: String,
+    /// Pool size; the admin dashboard alone runs five queries concurrently
     pub database_max_connections: u32,
 
     // Redis
     pub redis_url: String,
-    pub redis_cache_ttl_seconds: u64,
+    pub cache_ttls: CacheTtls,
 
     // CORS
     pub cors_allow_origins: Vec<String>,
 
+    // Proxies whose X-Forwarded-For / X-Real-IP headers are trusted
+    pub trusted_proxies: Vec<ipnet::IpNet>,
+
     // Supabase Auth
     pub supabase_jwt_jwks_url: String,
     pub supabase_jwt_issuer: String,
     pub supabase_jwt_audience: String,
     pub jwks_cache_ttl_seconds: u64,
 
+    // Cookie auth: read the access token from this cookie when no
+    // Authorization header is sent. Only enable it with a SameSite cookie and
+    // CORS_ALLOW_ORIGINS limited to trusted origins, since browsers attach
+    // cookies to cross-site requests on their own (CSRF).
+    pub auth_cookie_enabled: bool,
+    pub auth_cookie_name: String,
+
+    // Auth endpoint throttling (per IP, and lockout after failed sign-ins)
+    pub auth_rate_limits: AuthRateLimits,
+    // How long rotated-out and revoked refresh tokens stay on the revocation list
+    pub refresh_token_revocation_ttl_seconds: u64,
+
     // AI Service
     pub ai_service_url: String,
     pub ai_service_token: String,
     pub ai_service_timeout_seconds: u64,
+    pub ai_timeouts: AiTimeouts,
+    pub ai_models: AiModels,
+    pub ai_quotas: AiQuotaLimits,
+    pub ai_circuit_breaker: CircuitBreakerSettings,
+    // Base URL the AI service uses to reach this API's internal routes
+    pub internal_api_url: String,
+
+    // Processing jobs
+    pub job_stale_threshold_seconds: u64,
+    pub job_reaper_interval_seconds: u64,
+
+    // Tenders: how often open tenders past their bid due date are closed
+    pub tender_closer_interval_seconds: u64,
+
+    // Marketplace profiles: completeness weighting, and the score a profile
+    // needs before it can be submitted for verification
+    pub completeness_weights: CompletenessWeights,
+    pub verification_min_completeness: u8,
+
+    // Hire requests: how often requests past their response deadline are expired
+    pub hire_request_expirer_interval_seconds: u64,
+
+    // Insurance: how often expiring and lapsed subcontractor insurance is checked
+    pub insurance_monitor_interval_seconds: u64,
+
+    // Deleted documents: how often files whose deletion failed are retried
+    pub file_deletion_interval_seconds: u64,
+
+    // Maintenance mode: paths still writable, and the default Retry-After
+    pub maintenance: MaintenanceSettings,
+
+    // Feature flags: how often each instance reloads them
+    pub feature_flag_refresh_seconds: u64,
+
+    // Media object storage (avatars, logos) and the URL prefix it is served from
+    pub storage_root: String,
+    pub storage_public_url: String,
+
+    // API docs: whether /openapi.json and /docs are served, and the directory
+    // holding the Swagger UI assets /docs loads
+    pub api_docs_enabled: bool,
+    pub swagger_ui_dir: String,
+
+    // Nominatim-compatible geocoding service (empty = disabled)
+    pub geocoding_url: String,
+
+    // Signed download links (empty secret = random per-process key)
+    pub download_url_secret: String,
+    pub download_url_ttl_seconds: u64,
 
     // Supabase API (for auth proxy)
     pub supabase_url: String,
@@ -67,6 +472,49 @@ impl Settings {
         let env = Environment::from_str(&env::var("ENV").unwrap_or_else(|_| "dev".to_string()));
         let server_addr = env::var("SERVER_ADDR").unwrap_or_else(|_| "0.0.0.0:8080".to_string());
 
+        // Request body limits
+        let max_request_body_bytes = env::var("MAX_REQUEST_BODY_BYTES")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(1024 * 1024); // 1 MiB
+        let max_upload_bytes = env::var("MAX_UPLOAD_BYTES")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(50 * 1024 * 1024); // 50 MiB
+        let pagination = PaginationConfig::from_env();
+        let max_document_pages = env::var("MAX_DOCUMENT_PAGES")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(500);
+
+        // Logging (defaults: JSON in production, pretty elsewhere)
+        let log_format = match env::var("RUST_LOG_FORMAT") {
+            Ok(s) if !s.trim().is_empty() => LogFormat::parse(s.trim()).with_context(|| {
+                format!("Invalid RUST_LOG_FORMAT '{}'; expected json, pretty or compact", s)
+            })?,
+            _ if env.is_prod() => LogFormat::Json,
+            _ => LogFormat::Pretty,
+        };
+        let log_level = env::var("RUST_LOG")
+            .ok()
+            .filter(|s| !s.trim().is_empty())
+            .unwrap_or_else(|| {
+                match env {
+                    Environment::Dev => "blueprintx_backend=debug,tower_http=debug,info",
+                    Environment::Staging => "blueprintx_backend=debug,tower_http=info,info",
+                    Environment::Prod => "blueprintx_backend=info,tower_http=info,warn",
+                }
+                .to_string()
+            });
+        let slow_request_threshold_ms = env::var("SLOW_REQUEST_THRESHOLD_MS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(1000);
+        let slow_query_threshold_ms = env::var("SLOW_QUERY_THRESHOLD_MS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(250);
+
         // Database
         let database_url = env::var("DATABASE_URL").context("DATABASE_URL must be set")?;
         let database_max_connections = env::var("DATABASE_MAX_CONNECTIONS")
@@ -77,10 +525,7 @@ impl Settings {
         // Redis
         let redis_url =
             env::var("REDIS_URL").unwrap_or_else(|_| "redis://redis:6379/0".to_string());
-        let redis_cache_ttl_seconds = env::var("REDIS_CACHE_TTL_SECONDS")
-            .ok()
-            .and_then(|s| s.parse().ok())
-            .unwrap_or(3600); // 1 hour default
+        let cache_ttls = CacheTtls::from_env();
 
         // CORS
         let cors_allow_origins = env::var("CORS_ALLOW_ORIGINS")
@@ -90,6 +535,19 @@ impl Settings {
             .filter(|s| !s.is_empty())
             .collect();
 
+        // Trusted proxies (comma-separated IPs or CIDRs; empty = trust no forwarding headers)
+        let trusted_proxies = env::var("TRUSTED_PROXIES")
+            .unwrap_or_default()
+            .split(',')
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+            .map(|s| {
+                s.parse::<ipnet::IpNet>()
+                    .or_else(|_| s.parse::<std::net::IpAddr>().map(ipnet::IpNet::from))
+                    .with_context(|| format!("Invalid TRUSTED_PROXIES entry: {}", s))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
         // Supabase Auth
         let supabase_jwt_jwks_url =
             env::var("SUPABASE_JWT_JWKS_URL").context("SUPABASE_JWT_JWKS_URL must be set")?;
@@ -102,6 +560,18 @@ impl Settings {
             .and_then(|s| s.parse().ok())
             .unwrap_or(1800); // 30 minutes default
 
+        // Cookie auth
+        let auth_cookie_enabled = env::var("AUTH_COOKIE_ENABLED")
+            .map(|s| matches!(s.to_lowercase().as_str(), "1" | "true" | "yes"))
+            .unwrap_or(false);
+        let auth_cookie_name =
+            env::var("AUTH_COOKIE_NAME").unwrap_or_else(|_| "bx_access_token".to_string());
+        let auth_rate_limits = AuthRateLimits::from_env();
+        let refresh_token_revocation_ttl_seconds = env::var("REFRESH_TOKEN_REVOCATION_TTL_SECONDS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(30 * 24 * 3600); // 30 days
+
         // AI Service
         let ai_service_url =
             env::var("AI_SERVICE_URL").unwrap_or_else(|_| "http://ai-service:8000".to_string());
@@ -111,6 +581,86 @@ impl Settings {
             .ok()
             .and_then(|s| s.parse().ok())
             .unwrap_or(120); // 2 minutes default for LLM calls
+        let ai_timeouts = AiTimeouts::from_env(ai_service_timeout_seconds);
+        let ai_models = AiModels::from_env();
+        let ai_quotas = AiQuotaLimits::from_env();
+        let ai_circuit_breaker = CircuitBreakerSettings::from_env("AI");
+        let internal_api_url =
+            env::var("INTERNAL_API_URL").unwrap_or_else(|_| "http://rust-api:8080".to_string());
+
+        // Processing jobs
+        let job_stale_threshold_seconds = env::var("JOB_STALE_THRESHOLD_SECONDS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(600); // 10 minutes without progress
+        let job_reaper_interval_seconds = env::var("JOB_REAPER_INTERVAL_SECONDS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(60);
+
+        // Tenders
+        let tender_closer_interval_seconds = env::var("TENDER_CLOSER_INTERVAL_SECONDS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(60);
+
+        // Marketplace profiles
+        let completeness_weights = CompletenessWeights::from_env();
+        let verification_min_completeness = env::var("VERIFICATION_MIN_COMPLETENESS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(70);
+
+        // Hire requests
+        let hire_request_expirer_interval_seconds = env::var("HIRE_REQUEST_EXPIRER_INTERVAL_SECONDS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(300);
+
+        // Insurance monitor
+        let insurance_monitor_interval_seconds = env::var("INSURANCE_MONITOR_INTERVAL_SECONDS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(3600);
+
+        // File deletion sweeper
+        let file_deletion_interval_seconds = env::var("FILE_DELETION_INTERVAL_SECONDS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(300);
+
+        // Maintenance mode
+        let maintenance = MaintenanceSettings::from_env();
+
+        // Feature flag reload
+        let feature_flag_refresh_seconds = env::var("FEATURE_FLAG_REFRESH_SECONDS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(30u64)
+            .max(1);
+
+        // Media storage
+        let storage_root =
+            env::var("STORAGE_ROOT").unwrap_or_else(|_| "./uploads/media".to_string());
+        let storage_public_url =
+            env::var("STORAGE_PUBLIC_URL").unwrap_or_else(|_| "/media".to_string());
+
+        // API docs (on by default in dev only)
+        let api_docs_enabled = env::var("API_DOCS_ENABLED")
+            .map(|s| matches!(s.to_lowercase().as_str(), "1" | "true" | "yes"))
+            .unwrap_or(env.is_dev());
+        let swagger_ui_dir =
+            env::var("SWAGGER_UI_DIR").unwrap_or_else(|_| "./swagger-ui".to_string());
+
+        // Geocoding
+        let geocoding_url = env::var("GEOCODING_URL").unwrap_or_default();
+
+        // Signed download links
+        let download_url_secret = env::var("DOWNLOAD_URL_SECRET").unwrap_or_default();
+        let download_url_ttl_seconds = env::var("DOWNLOAD_URL_TTL_SECONDS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(900); // 15 minutes
 
         // Supabase API (for auth proxy)
         let supabase_url = env::var("SUPABASE_URL").context("SUPABASE_URL must be set")?;
@@ -122,18 +672,53 @@ impl Settings {
         Ok(Settings {
             env,
             server_addr,
+            max_request_body_bytes,
+            max_upload_bytes,
+            pagination,
+            max_document_pages,
+            log_format,
+            log_level,
+            slow_request_threshold_ms,
+            slow_query_threshold_ms,
             database_url,
             database_max_connections,
             redis_url,
-            redis_cache_ttl_seconds,
+            cache_ttls,
             cors_allow_origins,
+            trusted_proxies,
             supabase_jwt_jwks_url,
             supabase_jwt_issuer,
             supabase_jwt_audience,
             jwks_cache_ttl_seconds,
+            auth_cookie_enabled,
+            auth_cookie_name,
+            auth_rate_limits,
+            refresh_token_revocation_ttl_seconds,
             ai_service_url,
             ai_service_token,
             ai_service_timeout_seconds,
+            ai_timeouts,
+            ai_models,
+            ai_quotas,
+            ai_circuit_breaker,
+            internal_api_url,
+            job_stale_threshold_seconds,
+            job_reaper_interval_seconds,
+            tender_closer_interval_seconds,
+            completeness_weights,
+            verification_min_completeness,
+            hire_request_expirer_interval_seconds,
+            insurance_monitor_interval_seconds,
+            file_deletion_interval_seconds,
+            maintenance,
+            feature_flag_refresh_seconds,
+            storage_root,
+            storage_public_url,
+            api_docs_enabled,
+            swagger_ui_dir,
+            geocoding_url,
+            download_url_secret,
+            download_url_ttl_seconds,
             supabase_url,
             supabase_anon_key,
             supabase_service_role_key,
@@ -5,7 +5,7 @@
 use anyhow::{Context, Result};
 use sqlx::{
     postgres::{PgConnectOptions, PgPoolOptions},
-    PgPool,
+    ConnectOptions, PgPool,
 };
 use std::str::FromStr;
 use std::time::Duration;
@@ -16,7 +16,12 @@ use crate::config::{Environment, Settings};
 pub async fn create_pool(settings: &Settings) -> Result<PgPool> {
     let connect_options = PgConnectOptions::from_str(&settings.database_url)
         .context("Invalid DATABASE_URL")?
-        .application_name("blueprintx-backend");
+        .application_name("blueprintx-backend")
+        // Slow statements are logged with their SQL and elapsed time
+        .log_slow_statements(
+            log::LevelFilter::Warn,
+            Duration::from_millis(settings.slow_query_threshold_ms),
+        );
 
     // Optimize pool settings based on environment
     let (min_connections, acquire_timeout, idle_timeout) = match settings.env {
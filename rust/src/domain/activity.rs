@@ -0,0 +1,41 @@
+//! Activity log domain types
+//!
+//! Audit trail for sensitive non-admin actions (contract signing, bid awards,
+//! hire request transitions, verification requests, project exports).
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Activity action types for the user activity log
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivityAction {
+    ContractSigned,
+    PaymentMilestonePaid,
+    BidAwarded,
+    HireRequestStatusChanged,
+    VerificationRequested,
+    ProjectExported,
+}
+
+impl std::fmt::Display for ActivityAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = serde_json::to_string(self).unwrap_or_default();
+        write!(f, "{}", s.trim_matches('"'))
+    }
+}
+
+/// Response DTO for an activity log entry
+#[derive(Debug, Clone, Serialize)]
+pub struct ActivityLogResponse {
+    pub id: Uuid,
+    pub actor_id: Uuid,
+    pub actor_name: Option<String>,
+    pub project_id: Option<Uuid>,
+    pub action: String,
+    pub target_type: String,
+    pub target_id: Option<Uuid>,
+    pub details: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+}
@@ -2,11 +2,13 @@
 //!
 //! Types for admin panel operations including verification and audit logging.
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use uuid::Uuid;
 
+use super::marketplace::VerificationDocumentResponse;
+
 /// Admin action types for audit logging
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -20,6 +22,12 @@ pub enum AdminAction {
     DeleteContent,
     UpdateSystemSetting,
     ViewSensitiveData,
+    ExportAuditLog,
+    InvalidateCache,
+    CreateContractTemplate,
+    UpdateContractTemplate,
+    DeleteContractTemplate,
+    UpdateFeatureFlag,
 }
 
 impl std::fmt::Display for AdminAction {
@@ -38,9 +46,13 @@ pub enum AuditTargetType {
     Tender,
     Bid,
     Contract,
+    HireRequest,
     Review,
     Project,
     SystemSetting,
+    AuditLog,
+    ContractTemplate,
+    FeatureFlag,
 }
 
 impl std::fmt::Display for AuditTargetType {
@@ -93,10 +105,14 @@ pub struct PendingVerification {
     pub employee_count: Option<String>,
     pub certifications: Vec<serde_json::Value>,
     pub insurance: Option<serde_json::Value>,
+    /// In the past for verified subs the insurance monitor sent back to review
+    pub insurance_expiry: Option<NaiveDate>,
     pub license_info: Option<serde_json::Value>,
     pub created_at: DateTime<Utc>,
     pub profile_email: Option<String>,
     pub profile_name: Option<String>,
+    /// Uploaded evidence, with view links for the requesting admin
+    pub documents: Vec<VerificationDocumentResponse>,
 }
 
 /// Request to approve verification
@@ -114,6 +130,73 @@ pub struct RejectVerificationRequest {
     pub notes: Option<String>,
 }
 
+/// Single rejection in a bulk verification request
+#[derive(Debug, Clone, Deserialize)]
+pub struct BulkRejectItem {
+    pub id: Uuid,
+    pub reason: String,
+}
+
+/// Request to approve/reject many verifications at once
+#[derive(Debug, Clone, Deserialize)]
+pub struct BulkVerificationRequest {
+    #[serde(default)]
+    pub approve: Vec<Uuid>,
+    #[serde(default)]
+    pub reject: Vec<BulkRejectItem>,
+    #[serde(default)]
+    pub notes: Option<String>,
+}
+
+/// Outcome of a single item in a bulk verification request
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkItemOutcome {
+    Approved,
+    Rejected,
+    Skipped,
+    NotFound,
+    Invalid,
+}
+
+/// Per-id result of a bulk verification request
+#[derive(Debug, Clone, Serialize)]
+pub struct BulkVerificationResult {
+    pub id: Uuid,
+    pub outcome: BulkItemOutcome,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// Profile fields an admin can make mandatory for verification requests
+pub const VERIFICATION_FIELDS: &[&str] = &[
+    "insurance",
+    "license_info",
+    "contact_email",
+    "contact_phone",
+    "headline",
+    "company_description",
+    "certifications",
+    "service_areas",
+];
+
+/// Fields a subcontractor profile must fill in before requesting verification
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerificationRequirements {
+    pub required_fields: Vec<String>,
+}
+
+impl Default for VerificationRequirements {
+    fn default() -> Self {
+        Self {
+            required_fields: ["insurance", "license_info", "contact_email"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+        }
+    }
+}
+
 /// Admin dashboard stats
 #[derive(Debug, Clone, Serialize)]
 pub struct AdminDashboardStats {
@@ -143,12 +226,21 @@ pub struct AuditLogQuery {
     pub target_type: Option<String>,
     #[serde(default)]
     pub target_id: Option<Uuid>,
-    #[serde(default)]
+    #[serde(default, alias = "from")]
     pub from_date: Option<DateTime<Utc>>,
-    #[serde(default)]
+    #[serde(default, alias = "to")]
     pub to_date: Option<DateTime<Utc>>,
 }
 
+/// Export format for the audit log
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditExportFormat {
+    #[default]
+    Csv,
+    Json,
+}
+
 /// Query params for pending verifications
 #[derive(Debug, Clone, Deserialize, Default)]
 pub struct VerificationQuery {
@@ -159,3 +251,79 @@ pub struct VerificationQuery {
     #[serde(default)]
     pub sort_order: Option<String>,
 }
+
+/// Query params for admin user listing. Taken as its own `Query` extractor
+/// rather than flattened next to the pagination params, where `is_admin`
+/// would arrive as a string and be rejected.
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct AdminUserQuery {
+    #[serde(default)]
+    pub search: Option<String>, // matches email, company, first/last name
+    #[serde(default)]
+    pub user_type: Option<String>,
+    #[serde(default)]
+    pub is_admin: Option<bool>,
+}
+
+/// User row shown in the admin user list
+#[derive(Debug, Clone, Serialize)]
+pub struct AdminUserResponse {
+    pub id: Uuid,
+    pub email: String,
+    pub user_type: String,
+    pub company_name: Option<String>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub is_admin: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Maintenance mode as last set by an admin
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MaintenanceMode {
+    pub enabled: bool,
+    /// Shown to clients whose writes are refused
+    pub message: Option<String>,
+    /// Sent as `Retry-After` on refused writes
+    pub retry_after_seconds: u64,
+    pub updated_by: Option<Uuid>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// Turn maintenance mode on or off
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateMaintenanceModeRequest {
+    pub enabled: bool,
+    #[serde(default)]
+    pub message: Option<String>,
+    /// Defaults to `MAINTENANCE_RETRY_AFTER_SECONDS`
+    #[serde(default)]
+    pub retry_after_seconds: Option<u64>,
+}
+
+/// A feature flag and the users it is overridden for
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct FeatureFlagResponse {
+    pub key: String,
+    pub description: Option<String>,
+    /// On for everyone without an override
+    pub enabled: bool,
+    pub users_enabled: i64,
+    pub users_disabled: i64,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Turn a feature flag on or off for everyone, creating it if needed
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateFeatureFlagRequest {
+    pub enabled: bool,
+    /// Left unchanged when omitted
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+/// Turn a feature flag on or off for one user, whatever its global setting
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateUserFeatureFlagRequest {
+    pub enabled: bool,
+}
@@ -68,6 +68,9 @@ pub struct QnAResponse {
     pub citations: Vec<String>,
     pub confidence: f64,
     pub followups: Vec<String>,
+    /// Model that answered; reported by the AI service or the one requested
+    #[serde(default)]
+    pub model: Option<String>,
 }
 
 /// Vision OCR result from a drawing page.
@@ -103,6 +106,9 @@ pub struct PlanSummaryRequest {
 pub struct PlanSummaryResponse {
     pub project_id: String,
     pub summary: PlanSummary,
+    /// Model that produced the summary, when known
+    #[serde(default)]
+    pub model: Option<String>,
     pub cached: bool,
 }
 
@@ -119,6 +125,9 @@ pub struct TradeScopesRequest {
 pub struct TradeScopesResponse {
     pub project_id: String,
     pub scopes: TradeScopesOutput,
+    /// Model that extracted the scopes, when known
+    #[serde(default)]
+    pub model: Option<String>,
     pub cached: bool,
 }
 
@@ -139,6 +148,8 @@ pub struct TenderScopeDocResponse {
     pub project_id: String,
     pub trade: String,
     pub document: TenderScopeDoc,
+    /// Model that generated the document, when known
+    pub model: Option<String>,
 }
 
 /// Request for Q&A.
@@ -151,6 +162,130 @@ pub struct QnARequest {
     pub document_text: Option<String>,
 }
 
+/// AI service operation, each with its own timeout and model
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AiOperation {
+    Summary,
+    TradeScopes,
+    TenderScopeDoc,
+    Qna,
+}
+
+impl AiOperation {
+    /// Name stored in `ai_usage.operation`
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Summary => "summary",
+            Self::TradeScopes => "trade_scopes",
+            Self::TenderScopeDoc => "tender_scope_doc",
+            Self::Qna => "qna",
+        }
+    }
+}
+
+/// Token counts reported by the AI service for one call; absent counts
+/// stay `None` rather than being guessed.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct AiTokenUsage {
+    #[serde(default)]
+    pub prompt_tokens: Option<i32>,
+    #[serde(default)]
+    pub completion_tokens: Option<i32>,
+    #[serde(default)]
+    pub total_tokens: Option<i32>,
+}
+
+/// Date range for AI usage reports (both ends optional)
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct AiUsageQuery {
+    pub since: Option<chrono::DateTime<chrono::Utc>>,
+    pub until: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Aggregated AI usage
+#[derive(Debug, Clone, Default, Serialize, sqlx::FromRow)]
+pub struct AiUsageTotals {
+    pub calls: i64,
+    pub failed_calls: i64,
+    pub prompt_tokens: i64,
+    pub completion_tokens: i64,
+    pub total_tokens: i64,
+    pub duration_ms: i64,
+}
+
+/// AI usage for one operation, model, user or project
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct AiUsageBreakdown {
+    /// The operation, model, user id or project id the row aggregates
+    pub key: Option<String>,
+    #[serde(flatten)]
+    #[sqlx(flatten)]
+    pub totals: AiUsageTotals,
+}
+
+/// AI usage of one project
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectAiUsage {
+    pub project_id: uuid::Uuid,
+    pub totals: AiUsageTotals,
+    pub by_operation: Vec<AiUsageBreakdown>,
+    pub by_user: Vec<AiUsageBreakdown>,
+}
+
+/// Platform-wide AI usage for admins
+#[derive(Debug, Clone, Serialize)]
+pub struct AdminAiUsage {
+    pub totals: AiUsageTotals,
+    pub by_operation: Vec<AiUsageBreakdown>,
+    pub by_model: Vec<AiUsageBreakdown>,
+    /// Heaviest projects by tokens, then calls
+    pub top_projects: Vec<AiUsageBreakdown>,
+    /// Heaviest users by tokens, then calls
+    pub top_users: Vec<AiUsageBreakdown>,
+}
+
+/// The caller's AI allowance for today
+#[derive(Debug, Clone, Serialize)]
+pub struct AiQuotaStatus {
+    /// Calls allowed per UTC day; `None` when unlimited
+    pub daily_limit: Option<u32>,
+    pub used_today: u32,
+    /// `None` when unlimited
+    pub remaining_today: Option<u32>,
+    /// When the daily count resets (next UTC midnight)
+    pub resets_at: chrono::DateTime<chrono::Utc>,
+    pub is_admin: bool,
+}
+
+/// AI operation whose cached results can be invalidated on their own.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AiCacheOperation {
+    Summary,
+    TradeScopes,
+    Qna,
+}
+
+impl AiCacheOperation {
+    /// Cache key segment used by `cache::keys` for this operation.
+    pub fn key_segment(self) -> &'static str {
+        match self {
+            Self::Summary => "summary",
+            Self::TradeScopes => "scopes",
+            Self::Qna => "qna",
+        }
+    }
+}
+
+/// Query for AI cache invalidation.
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct InvalidateAiCacheQuery {
+    /// Only invalidate this operation; all AI caches for the project when omitted.
+    #[serde(default)]
+    pub operation: Option<AiCacheOperation>,
+}
+
 /// Standard trades list response.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StandardTradesResponse {
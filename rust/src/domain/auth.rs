@@ -5,18 +5,33 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 /// User type enum
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum UserType {
+    #[default]
     Gc,  // General Contractor
     Sub, // Subcontractor
 }
 
-impl Default for UserType {
-    fn default() -> Self {
-        Self::Gc
+impl UserType {
+    /// Value stored in `profiles.user_type` and carried in token claims
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Gc => "gc",
+            Self::Sub => "sub",
+        }
+    }
+
+    /// Parse a stored or claimed user type; unknown values are `None`
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "gc" => Some(Self::Gc),
+            "sub" => Some(Self::Sub),
+            _ => None,
+        }
     }
 }
 
@@ -44,6 +59,35 @@ pub struct RefreshTokenRequest {
     pub refresh_token: String,
 }
 
+/// Sign out request; the refresh token identifies the session to revoke when
+/// the access token doesn't
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SignOutRequest {
+    #[serde(default)]
+    pub refresh_token: Option<String>,
+}
+
+/// A signed-in session (refresh token family)
+#[derive(Debug, Clone, Serialize)]
+pub struct AuthSessionResponse {
+    pub id: Uuid,
+    /// User agent of the sign-in or latest refresh
+    pub user_agent: Option<String>,
+    /// Client IP of the sign-in or latest refresh
+    pub ip_address: Option<String>,
+    pub created_at: DateTime<Utc>,
+    /// Sign-in or latest refresh
+    pub last_seen_at: DateTime<Utc>,
+    /// Whether this is the session making the request
+    pub current: bool,
+}
+
+/// Result of signing out other sessions
+#[derive(Debug, Clone, Serialize)]
+pub struct RevokedSessions {
+    pub revoked: u64,
+}
+
 /// User info from Supabase
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct User {
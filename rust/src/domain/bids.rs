@@ -2,10 +2,13 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::money::Money;
+
 /// Bid status
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum BidStatus {
+    #[default]
     Draft,
     Submitted,
     UnderReview,
@@ -15,12 +18,6 @@ pub enum BidStatus {
     Withdrawn,
 }
 
-impl Default for BidStatus {
-    fn default() -> Self {
-        Self::Draft
-    }
-}
-
 /// Bid entity
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Bid {
@@ -31,7 +28,8 @@ pub struct Bid {
     pub contact_name: Option<String>,
     pub contact_email: Option<String>,
     pub contact_phone: Option<String>,
-    pub bid_amount: i64, // cents
+    /// Bid total in dollars
+    pub bid_amount: Money,
     pub status: BidStatus,
     pub notes: Option<String>,
     pub submitted_at: Option<DateTime<Utc>>,
@@ -49,7 +47,8 @@ pub struct CreateBidRequest {
     pub contact_email: Option<String>,
     #[serde(default)]
     pub contact_phone: Option<String>,
-    pub bid_amount: i64,
+    /// Bid total in dollars
+    pub bid_amount: Money,
     #[serde(default)]
     pub notes: Option<String>,
 }
@@ -66,7 +65,8 @@ pub struct UpdateBidRequest {
     #[serde(default)]
     pub contact_phone: Option<String>,
     #[serde(default)]
-    pub bid_amount: Option<i64>,
+    /// Bid total in dollars
+    pub bid_amount: Option<Money>,
     #[serde(default)]
     pub status: Option<BidStatus>,
     #[serde(default)]
@@ -82,12 +82,15 @@ pub struct BidResponse {
     pub contact_name: Option<String>,
     pub contact_email: Option<String>,
     pub contact_phone: Option<String>,
-    pub bid_amount: i64,
+    /// Bid total in dollars
+    pub bid_amount: Money,
     pub status: BidStatus,
     pub notes: Option<String>,
     pub submitted_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Proposals and credentials the bidder attached
+    pub attachments: Vec<BidAttachmentResponse>,
 }
 
 impl From<Bid> for BidResponse {
@@ -105,16 +108,31 @@ impl From<Bid> for BidResponse {
             submitted_at: b.submitted_at,
             created_at: b.created_at,
             updated_at: b.updated_at,
+            attachments: Vec::new(),
         }
     }
 }
 
+/// File attached to a bid (formal proposal, credentials)
+#[derive(Debug, Clone, Serialize)]
+pub struct BidAttachmentResponse {
+    pub id: Uuid,
+    pub file_name: String,
+    pub file_size: i64,
+    pub mime_type: Option<String>,
+    /// Signed link to download the file, for the bidder and the tender owner
+    pub download_url: String,
+    pub download_expires_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
 /// Bid summary for leveling
 #[derive(Debug, Clone, Serialize)]
 pub struct BidSummary {
     pub id: Uuid,
     pub company_name: String,
-    pub bid_amount: i64,
+    /// Bid total in dollars
+    pub bid_amount: Money,
     pub status: BidStatus,
     pub submitted_at: Option<DateTime<Utc>>,
 }
@@ -130,3 +148,17 @@ impl From<Bid> for BidSummary {
         }
     }
 }
+
+/// Query params for listing a tender's bids
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct BidListQuery {
+    #[serde(default)]
+    pub status: Option<BidStatus>,
+    #[serde(default)]
+    pub sort_by: Option<String>, // amount, timeline, submitted_at
+    #[serde(default)]
+    pub sort_order: Option<String>, // asc, desc
+    /// Withdrawn bids are hidden unless requested (or filtered by status)
+    #[serde(default)]
+    pub include_withdrawn: bool,
+}
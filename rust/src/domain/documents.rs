@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 /// Document type enum
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum DocumentType {
     Plan,
@@ -13,31 +13,21 @@ pub enum DocumentType {
     ChangeOrder,
     Submittal,
     Rfi,
+    #[default]
     Other,
 }
 
-impl Default for DocumentType {
-    fn default() -> Self {
-        Self::Other
-    }
-}
-
 /// Document version status
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum DocumentStatus {
+    #[default]
     Draft,
     Active,
     Superseded,
     Archived,
 }
 
-impl Default for DocumentStatus {
-    fn default() -> Self {
-        Self::Draft
-    }
-}
-
 /// Document entity
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Document {
@@ -65,6 +55,9 @@ pub struct CreateDocumentRequest {
     pub description: Option<String>,
     #[serde(default)]
     pub document_type: DocumentType,
+    /// Existing document this one revises; it is marked superseded
+    #[serde(default)]
+    pub supersedes_document_id: Option<Uuid>,
 }
 
 /// Response DTO for document
@@ -77,12 +70,45 @@ pub struct DocumentResponse {
     pub document_type: DocumentType,
     pub file_size: i64,
     pub mime_type: String,
+    /// Pages counted at upload; `None` when the PDF could not be read
+    pub page_count: Option<i32>,
     pub version: i32,
     pub status: DocumentStatus,
+    pub supersedes_document_id: Option<Uuid>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+/// Query params for listing documents
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct DocumentListQuery {
+    /// Include older versions that have been superseded by a revision
+    #[serde(default)]
+    pub include_superseded: bool,
+}
+
+/// Query params for deleting a document
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct DeleteDocumentQuery {
+    /// Cancel active processing jobs instead of refusing the delete
+    #[serde(default)]
+    pub force: bool,
+}
+
+/// What deleting a document removed
+#[derive(Debug, Clone, Serialize)]
+pub struct DocumentDeletionSummary {
+    pub document_id: Uuid,
+    /// Active processing jobs cancelled because of `force`
+    pub cancelled_jobs: u64,
+    pub deleted_materials: u64,
+    pub deleted_rooms: u64,
+    pub deleted_trade_scopes: u64,
+    pub deleted_embeddings: u64,
+    /// The file was queued for removal from storage
+    pub file_deletion_queued: bool,
+}
+
 impl From<Document> for DocumentResponse {
     fn from(d: Document) -> Self {
         Self {
@@ -93,8 +119,10 @@ impl From<Document> for DocumentResponse {
             document_type: d.document_type,
             file_size: d.file_size,
             mime_type: d.mime_type,
+            page_count: None,
             version: d.version,
             status: d.status,
+            supersedes_document_id: None,
             created_at: d.created_at,
             updated_at: d.updated_at,
         }
@@ -4,28 +4,37 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
+use super::money::Money;
+
 // ============================================================================
 // Extracted Materials
 // ============================================================================
 
 /// Extracted material from blueprints
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ExtractedMaterialResponse {
     pub id: Uuid,
     pub project_id: Uuid,
     pub document_id: Option<Uuid>,
+    /// Version of the source document this was extracted from
+    pub document_version: Option<i32>,
     pub name: String,
     pub description: Option<String>,
     pub quantity: Option<f64>,
     pub unit: Option<String>,
-    pub unit_cost: Option<f64>,
-    pub total_cost: Option<f64>,
+    /// Cost per unit in dollars
+    pub unit_cost: Option<Money>,
+    /// `quantity * unit_cost` in dollars
+    pub total_cost: Option<Money>,
     pub location: Option<String>,
     pub room: Option<String>,
     pub specification: Option<String>,
     pub trade_category: Option<String>,
+    /// Canonical trade key (see `GET /api/trades`)
+    pub normalized_trade: Option<String>,
     pub csi_division: Option<String>,
     pub source_page: Option<i32>,
     pub confidence: f64,
@@ -35,14 +44,32 @@ pub struct ExtractedMaterialResponse {
     pub updated_at: DateTime<Utc>,
 }
 
-/// Create/update material request
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Create or replace (PUT) material request; omitted optional fields are cleared
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct MaterialInput {
     pub name: String,
     pub description: Option<String>,
     pub quantity: Option<f64>,
     pub unit: Option<String>,
-    pub unit_cost: Option<f64>,
+    /// Cost per unit in dollars
+    pub unit_cost: Option<Money>,
+    pub location: Option<String>,
+    pub room: Option<String>,
+    pub specification: Option<String>,
+    pub trade_category: Option<String>,
+    pub csi_division: Option<String>,
+    pub source_page: Option<i32>,
+}
+
+/// Partial (PATCH) material update; omitted or null fields are left unchanged
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct MaterialPatch {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub quantity: Option<f64>,
+    pub unit: Option<String>,
+    /// Cost per unit in dollars
+    pub unit_cost: Option<Money>,
     pub location: Option<String>,
     pub room: Option<String>,
     pub specification: Option<String>,
@@ -52,7 +79,8 @@ pub struct MaterialInput {
 }
 
 /// Material filter query
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct MaterialQuery {
     pub trade_category: Option<String>,
     pub room: Option<String>,
@@ -60,12 +88,78 @@ pub struct MaterialQuery {
     pub search: Option<String>,
 }
 
+/// Which materials a bulk trade assignment applies to, instead of listing ids.
+/// Criteria combine with AND; text matches ignore case.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct MaterialAssignFilter {
+    pub room: Option<String>,
+    /// Materials currently in this trade category
+    pub trade_category: Option<String>,
+    /// Materials with no trade category yet
+    #[serde(default)]
+    pub uncategorized: bool,
+}
+
+/// Bulk trade assignment request: give either `ids` or `filter`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BulkAssignTradeRequest {
+    #[serde(default)]
+    pub ids: Vec<Uuid>,
+    pub filter: Option<MaterialAssignFilter>,
+    /// Trade key, name, alias or CSI division (see `GET /api/trades`)
+    pub trade_category: String,
+    /// Left unchanged when omitted
+    pub csi_division: Option<String>,
+}
+
+/// Bulk trade assignment result
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BulkAssignTradeResponse {
+    pub updated: u64,
+    /// Canonical display name the materials were given
+    pub trade_category: String,
+    pub normalized_trade: String,
+}
+
+/// Region of a page an item was read from, as fractions (0-1) of the page's
+/// width and height measured from its top-left corner
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SourceBoundingBox {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+/// The document a material was extracted from
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SourceDocument {
+    pub id: Uuid,
+    pub name: String,
+    pub mime_type: Option<String>,
+    pub page_count: Option<i32>,
+    pub version: Option<i32>,
+    pub status: Option<String>,
+}
+
+/// Where a material came from, for jumping from a line item to the drawing
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MaterialSourceResponse {
+    pub material_id: Uuid,
+    /// `None` if the material was entered by hand or its document was deleted
+    pub document: Option<SourceDocument>,
+    /// 1-based page number
+    pub source_page: Option<i32>,
+    /// Present when the AI reported where on the page it found the material
+    pub bounding_box: Option<SourceBoundingBox>,
+}
+
 // ============================================================================
 // Extracted Rooms
 // ============================================================================
 
 /// Room finishes (stored as JSON)
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ToSchema)]
 pub struct RoomFinishes {
     pub floor: Option<String>,
     pub walls: Option<String>,
@@ -75,11 +169,13 @@ pub struct RoomFinishes {
 }
 
 /// Extracted room/space from blueprints
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ExtractedRoomResponse {
     pub id: Uuid,
     pub project_id: Uuid,
     pub document_id: Option<Uuid>,
+    /// Version of the source document this was extracted from
+    pub document_version: Option<i32>,
     pub room_name: String,
     pub room_number: Option<String>,
     pub room_type: Option<String>,
@@ -98,8 +194,8 @@ pub struct ExtractedRoomResponse {
     pub updated_at: DateTime<Utc>,
 }
 
-/// Create/update room request
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Create or replace (PUT) room request; omitted optional fields are cleared
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct RoomInput {
     pub room_name: String,
     pub room_number: Option<String>,
@@ -114,8 +210,25 @@ pub struct RoomInput {
     pub source_page: Option<i32>,
 }
 
+/// Partial (PATCH) room update; omitted or null fields are left unchanged
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct RoomPatch {
+    pub room_name: Option<String>,
+    pub room_number: Option<String>,
+    pub room_type: Option<String>,
+    pub floor: Option<String>,
+    pub area_sqft: Option<f64>,
+    pub ceiling_height: Option<f64>,
+    pub perimeter_ft: Option<f64>,
+    pub finishes: Option<RoomFinishes>,
+    pub fixtures: Option<Vec<String>>,
+    pub notes: Option<String>,
+    pub source_page: Option<i32>,
+}
+
 /// Room filter query
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct RoomQuery {
     pub floor: Option<String>,
     pub room_type: Option<String>,
@@ -128,7 +241,7 @@ pub struct RoomQuery {
 // ============================================================================
 
 /// Milestone status
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum MilestoneStatus {
     Pending,
@@ -151,7 +264,7 @@ impl std::fmt::Display for MilestoneStatus {
 }
 
 /// Project milestone response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct MilestoneResponse {
     pub id: Uuid,
     pub project_id: Uuid,
@@ -170,14 +283,16 @@ pub struct MilestoneResponse {
     pub status: String,
     pub progress: f64,
     pub is_ai_generated: bool,
+    pub confidence: f64,
     pub is_verified: bool,
     pub verified_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
-/// Create/update milestone request
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Create or replace (PUT) milestone request; omitted optional fields are
+/// cleared or reset to their create defaults
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct MilestoneInput {
     pub name: String,
     pub description: Option<String>,
@@ -193,8 +308,26 @@ pub struct MilestoneInput {
     pub progress: Option<f64>,
 }
 
+/// Partial (PATCH) milestone update; omitted or null fields are left unchanged
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct MilestonePatch {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub phase: Option<String>,
+    pub phase_order: Option<i32>,
+    pub estimated_duration_days: Option<i32>,
+    pub estimated_start_date: Option<DateTime<Utc>>,
+    pub estimated_end_date: Option<DateTime<Utc>>,
+    pub dependencies: Option<Vec<String>>,
+    pub trades_involved: Option<Vec<String>>,
+    pub deliverables: Option<Vec<String>>,
+    pub status: Option<String>,
+    pub progress: Option<f64>,
+}
+
 /// Milestone filter query
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct MilestoneQuery {
     pub phase: Option<String>,
     pub status: Option<String>,
@@ -206,20 +339,24 @@ pub struct MilestoneQuery {
 // ============================================================================
 
 /// Trade scope item (inclusion/exclusion)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ScopeItem {
     pub item: String,
     pub details: Option<String>,
 }
 
 /// Extracted trade scope response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct TradeScopeResponse {
     pub id: Uuid,
     pub project_id: Uuid,
     pub document_id: Option<Uuid>,
+    /// Version of the source document this scope was extracted from
+    pub document_version: Option<i32>,
     pub trade: String,
     pub trade_display_name: Option<String>,
+    /// Canonical trade key (see `GET /api/trades`)
+    pub normalized_trade: Option<String>,
     pub csi_division: Option<String>,
     pub inclusions: Vec<ScopeItem>,
     pub exclusions: Vec<ScopeItem>,
@@ -227,7 +364,8 @@ pub struct TradeScopeResponse {
     pub spec_sections: Vec<String>,
     pub rfi_needed: Vec<String>,
     pub assumptions: Vec<String>,
-    pub estimated_value: Option<f64>,
+    /// Estimated scope value in dollars
+    pub estimated_value: Option<Money>,
     pub confidence: f64,
     pub is_verified: bool,
     pub verified_at: Option<DateTime<Utc>>,
@@ -235,8 +373,9 @@ pub struct TradeScopeResponse {
     pub updated_at: DateTime<Utc>,
 }
 
-/// Create/update trade scope request
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Create or replace (PUT) trade scope request; omitted optional fields are
+/// cleared
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct TradeScopeInput {
     pub trade: String,
     pub trade_display_name: Option<String>,
@@ -247,11 +386,29 @@ pub struct TradeScopeInput {
     pub spec_sections: Option<Vec<String>>,
     pub rfi_needed: Option<Vec<String>>,
     pub assumptions: Option<Vec<String>>,
-    pub estimated_value: Option<f64>,
+    /// Estimated scope value in dollars
+    pub estimated_value: Option<Money>,
+}
+
+/// Partial (PATCH) trade scope update; omitted or null fields are left unchanged
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct TradeScopePatch {
+    pub trade: Option<String>,
+    pub trade_display_name: Option<String>,
+    pub csi_division: Option<String>,
+    pub inclusions: Option<Vec<ScopeItem>>,
+    pub exclusions: Option<Vec<ScopeItem>>,
+    pub required_sheets: Option<Vec<String>>,
+    pub spec_sections: Option<Vec<String>>,
+    pub rfi_needed: Option<Vec<String>>,
+    pub assumptions: Option<Vec<String>>,
+    /// Estimated scope value in dollars
+    pub estimated_value: Option<Money>,
 }
 
 /// Trade scope filter query
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct TradeScopeQuery {
     pub trade: Option<String>,
     pub is_verified: Option<bool>,
@@ -262,7 +419,7 @@ pub struct TradeScopeQuery {
 // ============================================================================
 
 /// Summary of all extracted data for a project
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ExtractionSummary {
     pub project_id: Uuid,
     pub materials_count: i64,
@@ -278,14 +435,94 @@ pub struct ExtractionSummary {
     pub processing_status: Option<String>,
 }
 
+// ============================================================================
+// Review Prioritization
+// ============================================================================
+
+/// Ordering for extraction lists
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExtractionSort {
+    /// Each list's natural order (trade, floor, phase, ...)
+    #[default]
+    Default,
+    /// Unverified items first, least confident first
+    ConfidenceAsc,
+}
+
+/// Confidence filter and ordering shared by the extraction lists.
+///
+/// Handlers take this as its own `Query` extractor instead of flattening it
+/// into their params: serde hands flattened query values over as strings,
+/// which the `f64` bounds reject.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ConfidenceQuery {
+    /// Only items with at least this confidence (0-1)
+    pub min_confidence: Option<f64>,
+    /// Only items with at most this confidence (0-1)
+    pub max_confidence: Option<f64>,
+    #[param(inline)]
+    pub sort: Option<ExtractionSort>,
+}
+
+impl ConfidenceQuery {
+    /// Check both bounds lie in 0-1 and do not cross
+    pub fn validate(&self) -> Result<(), String> {
+        for (name, value) in [("min_confidence", self.min_confidence), ("max_confidence", self.max_confidence)] {
+            if value.is_some_and(|v| !(0.0..=1.0).contains(&v)) {
+                return Err(format!("{} must be between 0 and 1", name));
+            }
+        }
+        if let (Some(min), Some(max)) = (self.min_confidence, self.max_confidence) {
+            if min > max {
+                return Err("min_confidence must not exceed max_confidence".to_string());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Kind of extracted item in the review queue
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExtractionItemType {
+    Material,
+    Room,
+    Milestone,
+    TradeScope,
+}
+
+/// An unverified extracted item awaiting review
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReviewQueueItem {
+    pub item_type: ExtractionItemType,
+    pub id: Uuid,
+    /// Material, room, milestone or trade name
+    pub label: String,
+    pub confidence: f64,
+    pub document_id: Option<Uuid>,
+    pub source_page: Option<i32>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Review queue filter query; taken as its own `Query` extractor, like
+/// [`ConfidenceQuery`]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ReviewQueueQuery {
+    /// Only items with at most this confidence (0-1)
+    pub max_confidence: Option<f64>,
+}
+
 /// Verify item request (for materials, rooms, milestones, trade scopes)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct VerifyItemRequest {
     pub is_verified: bool,
 }
 
 /// Bulk verify request
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct BulkVerifyRequest {
     pub ids: Vec<Uuid>,
     pub is_verified: bool,
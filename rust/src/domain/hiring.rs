@@ -4,14 +4,19 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
+use super::money::Money;
+use super::patch::Patch;
+use super::rating::Rating;
+
 // ============================================================================
 // Hire Request Status
 // ============================================================================
 
 /// Hire request status
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum HireRequestStatus {
     Draft,
@@ -47,8 +52,23 @@ impl std::fmt::Display for HireRequestStatus {
     }
 }
 
+impl HireRequestStatus {
+    /// Statuses still awaiting the subcontractor, which lapse to `Expired`
+    /// once the response deadline passes
+    pub const EXPIRABLE: [HireRequestStatus; 3] = [
+        HireRequestStatus::Sent,
+        HireRequestStatus::Viewed,
+        HireRequestStatus::Interested,
+    ];
+
+    /// Whether a request in stored status `status` can expire
+    pub fn is_expirable(status: &str) -> bool {
+        Self::EXPIRABLE.iter().any(|s| s.to_string() == status)
+    }
+}
+
 /// Rate type for hire requests
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum RateType {
     Fixed,
@@ -77,7 +97,7 @@ impl std::fmt::Display for RateType {
 // ============================================================================
 
 /// External subcontractor response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ExternalSubcontractorResponse {
     pub id: Uuid,
     pub added_by: Uuid,
@@ -92,15 +112,17 @@ pub struct ExternalSubcontractorResponse {
     pub license_number: Option<String>,
     pub insurance_info: Option<String>,
     pub notes: Option<String>,
-    pub rating: f64,
+    pub rating: Rating,
     pub projects_together: i32,
     pub is_preferred: bool,
+    /// Platform profile this company was linked to after joining
+    pub linked_subcontractor_id: Option<Uuid>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
 /// Create external subcontractor request
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct CreateExternalSubcontractorInput {
     pub company_name: String,
     pub contact_name: Option<String>,
@@ -117,25 +139,136 @@ pub struct CreateExternalSubcontractorInput {
 }
 
 /// Update external subcontractor request
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// `Patch` fields: omit to keep, `null` to clear, a value to set
+/// (see `domain::patch`). Other fields ignore `null`.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct UpdateExternalSubcontractorInput {
     pub company_name: Option<String>,
-    pub contact_name: Option<String>,
-    pub contact_email: Option<String>,
-    pub contact_phone: Option<String>,
+    #[serde(default, skip_serializing_if = "Patch::is_undefined")]
+    #[schema(value_type = Option<String>)]
+    pub contact_name: Patch<String>,
+    #[serde(default, skip_serializing_if = "Patch::is_undefined")]
+    #[schema(value_type = Option<String>)]
+    pub contact_email: Patch<String>,
+    #[serde(default, skip_serializing_if = "Patch::is_undefined")]
+    #[schema(value_type = Option<String>)]
+    pub contact_phone: Patch<String>,
     pub trade: Option<String>,
     pub secondary_trades: Option<Vec<String>>,
-    pub location: Option<String>,
-    pub address: Option<String>,
-    pub license_number: Option<String>,
-    pub insurance_info: Option<String>,
-    pub notes: Option<String>,
-    pub rating: Option<f64>,
+    #[serde(default, skip_serializing_if = "Patch::is_undefined")]
+    #[schema(value_type = Option<String>)]
+    pub location: Patch<String>,
+    #[serde(default, skip_serializing_if = "Patch::is_undefined")]
+    #[schema(value_type = Option<String>)]
+    pub address: Patch<String>,
+    #[serde(default, skip_serializing_if = "Patch::is_undefined")]
+    #[schema(value_type = Option<String>)]
+    pub license_number: Patch<String>,
+    #[serde(default, skip_serializing_if = "Patch::is_undefined")]
+    #[schema(value_type = Option<String>)]
+    pub insurance_info: Patch<String>,
+    #[serde(default, skip_serializing_if = "Patch::is_undefined")]
+    #[schema(value_type = Option<String>)]
+    pub notes: Patch<String>,
+    pub rating: Option<Rating>,
     pub is_preferred: Option<bool>,
 }
 
+/// Query flags for creating an external subcontractor
+#[derive(Debug, Clone, Serialize, Deserialize, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CreateExternalSubcontractorQuery {
+    /// Save even when the company looks like one already in the book
+    #[serde(default)]
+    pub allow_duplicate: bool,
+}
+
+/// Why two external subcontractors look like the same company
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DuplicateReason {
+    /// Similar company name in the same trade
+    SimilarName,
+    SameEmail,
+    SamePhone,
+}
+
+/// Identifying fields of an external subcontractor in a duplicate match
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ExternalSubcontractorSummary {
+    pub id: Uuid,
+    pub company_name: String,
+    pub trade: String,
+    pub contact_email: Option<String>,
+    pub contact_phone: Option<String>,
+}
+
+/// An existing external subcontractor that looks like the one being created
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ExternalSubcontractorMatch {
+    #[serde(flatten)]
+    pub subcontractor: ExternalSubcontractorSummary,
+    /// Trigram similarity of the normalized company names, 0 to 1
+    pub name_similarity: f32,
+    pub reasons: Vec<DuplicateReason>,
+}
+
+/// `409` body when a new external subcontractor looks like a duplicate
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DuplicateExternalSubcontractorError {
+    pub code: String,
+    pub message: String,
+    pub matches: Vec<ExternalSubcontractorMatch>,
+}
+
+/// Two of a user's external subcontractors that look like the same company
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ExternalSubcontractorDuplicatePair {
+    pub first: ExternalSubcontractorSummary,
+    pub second: ExternalSubcontractorSummary,
+    pub name_similarity: f32,
+    pub reasons: Vec<DuplicateReason>,
+}
+
+/// A registered platform subcontractor that looks like an external subcontractor
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PlatformSubcontractorMatch {
+    pub subcontractor_id: Uuid,
+    pub name: String,
+    pub trade: String,
+    pub location: Option<String>,
+    pub verified: bool,
+    /// Trigram similarity of the normalized company names, 0 to 1
+    pub name_similarity: f32,
+    pub reasons: Vec<DuplicateReason>,
+}
+
+/// Request to link an external subcontractor to a platform profile
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ToSchema)]
+pub struct LinkExternalSubcontractorInput {
+    /// Move the history; without it the response only previews what would move
+    #[serde(default)]
+    pub confirm: bool,
+}
+
+/// History moved (or, unconfirmed, that would move) to the platform profile
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ExternalSubcontractorLinkResult {
+    pub external_sub_id: Uuid,
+    pub subcontractor_id: Uuid,
+    /// The link was made; `false` for a preview
+    pub linked: bool,
+    pub hire_requests: i64,
+    pub reviews: i64,
+    pub team_memberships: i64,
+    /// Why the two look like the same company; empty when nothing matches
+    pub reasons: Vec<DuplicateReason>,
+}
+
 /// External subcontractor filter query
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct ExternalSubcontractorQuery {
     pub trade: Option<String>,
     pub is_preferred: Option<bool>,
@@ -147,7 +280,7 @@ pub struct ExternalSubcontractorQuery {
 // ============================================================================
 
 /// Subcontractor info in hire request (either platform or external)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct HireRequestSubcontractor {
     pub id: Uuid,
     pub is_external: bool,
@@ -157,12 +290,12 @@ pub struct HireRequestSubcontractor {
     pub contact_phone: Option<String>,
     pub trade: String,
     pub location: Option<String>,
-    pub rating: Option<f64>,
+    pub rating: Option<Rating>,
     pub verified: bool,
 }
 
 /// Hire request response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct HireRequestResponse {
     pub id: Uuid,
     pub project_id: Uuid,
@@ -176,15 +309,24 @@ pub struct HireRequestResponse {
     pub title: String,
     pub message: Option<String>,
     pub scope_description: Option<String>,
-    pub proposed_amount: Option<f64>,
+    /// Proposed price in dollars
+    pub proposed_amount: Option<Money>,
     pub rate_type: Option<String>,
     pub unit_description: Option<String>,
     pub estimated_hours: Option<i32>,
     pub estimated_start_date: Option<DateTime<Utc>>,
     pub estimated_end_date: Option<DateTime<Utc>>,
     pub response_deadline: Option<DateTime<Utc>>,
+    /// Whole days left until `response_deadline`; negative once it has passed
+    pub days_until_deadline: Option<i64>,
     pub sub_response: Option<String>,
-    pub sub_counter_amount: Option<f64>,
+    /// Subcontractor's counter-offer in dollars
+    pub sub_counter_amount: Option<Money>,
+    /// Where the latest counter-offer stands (`pending`, `accepted`, `rejected`)
+    pub counter_offer_status: Option<String>,
+    /// Live price in dollars: the latest accepted counter-offer, otherwise the
+    /// proposed amount
+    pub current_amount: Option<Money>,
     pub unread_messages: i32,
     pub contract_id: Option<Uuid>,
     pub viewed_at: Option<DateTime<Utc>>,
@@ -195,7 +337,7 @@ pub struct HireRequestResponse {
 }
 
 /// Create hire request input
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct CreateHireRequestInput {
     pub project_id: Uuid,
     pub tender_id: Option<Uuid>,
@@ -205,7 +347,8 @@ pub struct CreateHireRequestInput {
     pub title: String,
     pub message: Option<String>,
     pub scope_description: Option<String>,
-    pub proposed_amount: Option<f64>,
+    /// Proposed price in dollars
+    pub proposed_amount: Option<Money>,
     pub rate_type: Option<String>,
     pub unit_description: Option<String>,
     pub estimated_hours: Option<i32>,
@@ -216,30 +359,95 @@ pub struct CreateHireRequestInput {
 }
 
 /// Update hire request input
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// `Patch` fields: omit to keep, `null` to clear, a value to set
+/// (see `domain::patch`). Other fields ignore `null`.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct UpdateHireRequestInput {
     pub title: Option<String>,
-    pub message: Option<String>,
-    pub scope_description: Option<String>,
-    pub proposed_amount: Option<f64>,
+    #[serde(default, skip_serializing_if = "Patch::is_undefined")]
+    #[schema(value_type = Option<String>)]
+    pub message: Patch<String>,
+    #[serde(default, skip_serializing_if = "Patch::is_undefined")]
+    #[schema(value_type = Option<String>)]
+    pub scope_description: Patch<String>,
+    /// Proposed price in dollars
+    #[serde(default, skip_serializing_if = "Patch::is_undefined")]
+    #[schema(value_type = Option<Money>)]
+    pub proposed_amount: Patch<Money>,
     pub rate_type: Option<String>,
-    pub unit_description: Option<String>,
-    pub estimated_hours: Option<i32>,
-    pub estimated_start_date: Option<DateTime<Utc>>,
-    pub estimated_end_date: Option<DateTime<Utc>>,
-    pub response_deadline: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Patch::is_undefined")]
+    #[schema(value_type = Option<String>)]
+    pub unit_description: Patch<String>,
+    #[serde(default, skip_serializing_if = "Patch::is_undefined")]
+    #[schema(value_type = Option<i32>)]
+    pub estimated_hours: Patch<i32>,
+    #[serde(default, skip_serializing_if = "Patch::is_undefined")]
+    #[schema(value_type = Option<DateTime<Utc>>)]
+    pub estimated_start_date: Patch<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Patch::is_undefined")]
+    #[schema(value_type = Option<DateTime<Utc>>)]
+    pub estimated_end_date: Patch<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Patch::is_undefined")]
+    #[schema(value_type = Option<DateTime<Utc>>)]
+    pub response_deadline: Patch<DateTime<Utc>>,
 }
 
 /// Hire request status transition input
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct HireRequestStatusInput {
     pub status: String,
     pub response: Option<String>,
-    pub counter_amount: Option<f64>,
+    /// Counter-offer in dollars
+    pub counter_amount: Option<Money>,
+}
+
+/// Counter-offer lifecycle
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CounterOfferStatus {
+    Pending,
+    Accepted,
+    Rejected,
+    /// Replaced by a newer counter-offer before the GC answered
+    Superseded,
+}
+
+impl std::fmt::Display for CounterOfferStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CounterOfferStatus::Pending => write!(f, "pending"),
+            CounterOfferStatus::Accepted => write!(f, "accepted"),
+            CounterOfferStatus::Rejected => write!(f, "rejected"),
+            CounterOfferStatus::Superseded => write!(f, "superseded"),
+        }
+    }
+}
+
+/// Subcontractor counter-offer input
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CounterOfferInput {
+    /// Counter-offer in dollars
+    pub amount: Money,
+    /// Conditions attached to the counter-offer
+    pub terms: Option<String>,
+    /// Message shown in the thread; a summary of the amount if omitted
+    pub message: Option<String>,
+}
+
+/// Metadata carried by a `counter_offer` hire message
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CounterOfferMetadata {
+    /// Counter-offer in dollars
+    pub amount: Money,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub terms: Option<String>,
+    pub status: CounterOfferStatus,
 }
 
 /// Hire request filter query
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct HireRequestQuery {
     pub project_id: Option<Uuid>,
     pub status: Option<String>,
@@ -253,7 +461,7 @@ pub struct HireRequestQuery {
 // ============================================================================
 
 /// Contract status
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ContractStatus {
     Draft,
@@ -284,17 +492,85 @@ impl std::fmt::Display for ContractStatus {
 }
 
 /// Payment milestone in contract
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct PaymentMilestone {
     pub name: String,
-    pub amount: f64,
+    /// Milestone amount in dollars
+    pub amount: Money,
     pub due_upon: String,
     pub is_paid: bool,
     pub paid_at: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reference_number: Option<String>,
+}
+
+/// A stored contract signature checked against the current content
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SignatureVerification {
+    pub signed_by: Option<Uuid>,
+    pub signed_at: DateTime<Utc>,
+    pub signed_ip: Option<String>,
+    /// Content hash recorded when this party signed
+    pub content_hash: Option<String>,
+    pub hash_matches: bool,
+}
+
+/// Contract signature verification response
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ContractSignatureVerification {
+    pub contract_id: Uuid,
+    pub status: String,
+    /// Hash of the contract content as it is now
+    pub content_hash: String,
+    pub gc_signature: Option<SignatureVerification>,
+    pub sub_signature: Option<SignatureVerification>,
+    /// True when every recorded signature matches the current content
+    pub valid: bool,
+}
+
+/// Mark payment milestone as paid input
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct MarkMilestonePaidInput {
+    pub reference_number: Option<String>,
+}
+
+/// Contract payment summary response
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ContractPaymentSummary {
+    pub contract_id: Uuid,
+    pub status: String,
+    /// Contract amount in dollars
+    pub total_amount: Money,
+    /// Sum of paid milestones in dollars
+    pub paid_amount: Money,
+    /// Unpaid balance in dollars, never negative
+    pub outstanding_amount: Money,
+    pub milestones_paid: usize,
+    pub milestones_total: usize,
+    pub milestones: Vec<PaymentMilestone>,
+}
+
+impl ContractPaymentSummary {
+    /// Contract statuses in which milestones can be marked paid
+    pub const PAYABLE_STATUSES: &'static [&'static str] = &["fully_signed", "active"];
+
+    pub fn new(contract_id: Uuid, status: String, total_amount: Money, milestones: Vec<PaymentMilestone>) -> Self {
+        let paid_amount: Money = milestones.iter().filter(|m| m.is_paid).map(|m| m.amount).sum();
+        Self {
+            contract_id,
+            status,
+            total_amount,
+            paid_amount,
+            outstanding_amount: (total_amount - paid_amount).max(Money::ZERO),
+            milestones_paid: milestones.iter().filter(|m| m.is_paid).count(),
+            milestones_total: milestones.len(),
+            milestones,
+        }
+    }
 }
 
 /// Contract section
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ContractSection {
     pub key: String,
     pub title: String,
@@ -303,7 +579,7 @@ pub struct ContractSection {
 }
 
 /// Contract template response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ContractTemplateResponse {
     pub id: Uuid,
     pub name: String,
@@ -317,8 +593,33 @@ pub struct ContractTemplateResponse {
     pub created_at: DateTime<Utc>,
 }
 
+/// Create contract template request (admin)
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CreateContractTemplateInput {
+    pub name: String,
+    pub description: Option<String>,
+    /// standard, time_materials, fixed_price, unit_price or master_service
+    pub template_type: Option<String>,
+    pub content: String,
+    pub sections: Option<Vec<ContractSection>>,
+    pub variables: Option<Vec<TemplateVariable>>,
+    pub is_active: Option<bool>,
+}
+
+/// Update contract template request (admin)
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UpdateContractTemplateInput {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub template_type: Option<String>,
+    pub content: Option<String>,
+    pub sections: Option<Vec<ContractSection>>,
+    pub variables: Option<Vec<TemplateVariable>>,
+    pub is_active: Option<bool>,
+}
+
 /// Template variable definition
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct TemplateVariable {
     pub key: String,
     pub label: String,
@@ -329,7 +630,7 @@ pub struct TemplateVariable {
 }
 
 /// Contract response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ContractResponse {
     pub id: Uuid,
     pub hire_request_id: Uuid,
@@ -342,7 +643,8 @@ pub struct ContractResponse {
     pub content: String,
     pub sections: Vec<ContractSection>,
     pub terms_summary: Option<String>,
-    pub amount: f64,
+    /// Contract amount in dollars
+    pub amount: Money,
     pub payment_schedule: Vec<PaymentMilestone>,
     pub start_date: Option<DateTime<Utc>>,
     pub end_date: Option<DateTime<Utc>>,
@@ -359,7 +661,7 @@ pub struct ContractResponse {
 }
 
 /// Create contract input
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct CreateContractInput {
     pub hire_request_id: Uuid,
     pub template_id: Option<Uuid>,
@@ -367,7 +669,8 @@ pub struct CreateContractInput {
     pub content: Option<String>,
     pub sections: Option<Vec<ContractSection>>,
     pub terms_summary: Option<String>,
-    pub amount: f64,
+    /// Contract amount in dollars
+    pub amount: Money,
     pub payment_schedule: Option<Vec<PaymentMilestone>>,
     pub start_date: Option<DateTime<Utc>>,
     pub end_date: Option<DateTime<Utc>>,
@@ -376,13 +679,14 @@ pub struct CreateContractInput {
 }
 
 /// Update contract input
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct UpdateContractInput {
     pub title: Option<String>,
     pub content: Option<String>,
     pub sections: Option<Vec<ContractSection>>,
     pub terms_summary: Option<String>,
-    pub amount: Option<f64>,
+    /// Contract amount in dollars
+    pub amount: Option<Money>,
     pub payment_schedule: Option<Vec<PaymentMilestone>>,
     pub start_date: Option<DateTime<Utc>>,
     pub end_date: Option<DateTime<Utc>>,
@@ -390,14 +694,15 @@ pub struct UpdateContractInput {
 }
 
 /// Sign contract input
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct SignContractInput {
     pub signature: String,
     pub agreed_to_terms: bool,
 }
 
 /// Contract filter query
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct ContractQuery {
     pub project_id: Option<Uuid>,
     pub status: Option<String>,
@@ -409,7 +714,7 @@ pub struct ContractQuery {
 // ============================================================================
 
 /// Message type in hire request negotiation
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum MessageType {
     Text,
@@ -434,7 +739,7 @@ impl std::fmt::Display for MessageType {
 }
 
 /// Hire message response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct HireMessageResponse {
     pub id: Uuid,
     pub hire_request_id: Uuid,
@@ -449,8 +754,16 @@ pub struct HireMessageResponse {
     pub created_at: DateTime<Utc>,
 }
 
+/// Read receipt input for hire messages
+#[derive(Debug, Clone, Default, Deserialize, ToSchema)]
+pub struct MarkMessagesReadInput {
+    /// Mark messages up to and including this one; all unread if omitted
+    #[serde(default)]
+    pub up_to: Option<Uuid>,
+}
+
 /// Send message input
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct SendMessageInput {
     pub message: String,
     pub message_type: Option<String>,
@@ -462,7 +775,7 @@ pub struct SendMessageInput {
 // ============================================================================
 
 /// Team member status
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum TeamMemberStatus {
     Pending,
@@ -485,7 +798,7 @@ impl std::fmt::Display for TeamMemberStatus {
 }
 
 /// Project team member response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct TeamMemberResponse {
     pub id: Uuid,
     pub project_id: Uuid,
@@ -497,9 +810,12 @@ pub struct TeamMemberResponse {
     pub responsibilities: Option<String>,
     pub start_date: Option<DateTime<Utc>>,
     pub end_date: Option<DateTime<Utc>>,
-    pub hourly_rate: Option<f64>,
+    /// Hourly rate in dollars
+    pub hourly_rate: Option<Money>,
     pub status: String,
-    pub performance_rating: Option<f64>,
+    pub performance_rating: Option<Rating>,
+    /// The GC's review once the member's work is completed
+    pub review: Option<TeamMemberReview>,
     pub notes: Option<String>,
     pub joined_at: DateTime<Utc>,
     pub created_at: DateTime<Utc>,
@@ -507,7 +823,7 @@ pub struct TeamMemberResponse {
 }
 
 /// Add team member input
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct AddTeamMemberInput {
     pub subcontractor_id: Option<Uuid>,
     pub external_sub_id: Option<Uuid>,
@@ -518,21 +834,38 @@ pub struct AddTeamMemberInput {
     pub responsibilities: Option<String>,
     pub start_date: Option<DateTime<Utc>>,
     pub end_date: Option<DateTime<Utc>>,
-    pub hourly_rate: Option<f64>,
+    /// Hourly rate in dollars
+    pub hourly_rate: Option<Money>,
     pub notes: Option<String>,
 }
 
 /// Update team member input
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// `Patch` fields: omit to keep, `null` to clear, a value to set
+/// (see `domain::patch`). Other fields ignore `null`.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct UpdateTeamMemberInput {
-    pub role: Option<String>,
-    pub responsibilities: Option<String>,
-    pub start_date: Option<DateTime<Utc>>,
-    pub end_date: Option<DateTime<Utc>>,
-    pub hourly_rate: Option<f64>,
+    #[serde(default, skip_serializing_if = "Patch::is_undefined")]
+    #[schema(value_type = Option<String>)]
+    pub role: Patch<String>,
+    #[serde(default, skip_serializing_if = "Patch::is_undefined")]
+    #[schema(value_type = Option<String>)]
+    pub responsibilities: Patch<String>,
+    #[serde(default, skip_serializing_if = "Patch::is_undefined")]
+    #[schema(value_type = Option<DateTime<Utc>>)]
+    pub start_date: Patch<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Patch::is_undefined")]
+    #[schema(value_type = Option<DateTime<Utc>>)]
+    pub end_date: Patch<DateTime<Utc>>,
+    /// Hourly rate in dollars
+    #[serde(default, skip_serializing_if = "Patch::is_undefined")]
+    #[schema(value_type = Option<Money>)]
+    pub hourly_rate: Patch<Money>,
     pub status: Option<String>,
-    pub performance_rating: Option<f64>,
-    pub notes: Option<String>,
+    pub performance_rating: Option<Rating>,
+    #[serde(default, skip_serializing_if = "Patch::is_undefined")]
+    #[schema(value_type = Option<String>)]
+    pub notes: Patch<String>,
 }
 
 // ============================================================================
@@ -540,7 +873,7 @@ pub struct UpdateTeamMemberInput {
 // ============================================================================
 
 /// Subcontractor review response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct SubcontractorReviewResponse {
     pub id: Uuid,
     pub subcontractor_id: Option<Uuid>,
@@ -549,11 +882,11 @@ pub struct SubcontractorReviewResponse {
     pub reviewer_name: String,
     pub project_id: Option<Uuid>,
     pub project_name: Option<String>,
-    pub rating: f64,
-    pub quality_rating: Option<f64>,
-    pub communication_rating: Option<f64>,
-    pub timeliness_rating: Option<f64>,
-    pub value_rating: Option<f64>,
+    pub rating: Rating,
+    pub quality_rating: Option<Rating>,
+    pub communication_rating: Option<Rating>,
+    pub timeliness_rating: Option<Rating>,
+    pub value_rating: Option<Rating>,
     pub title: Option<String>,
     pub comment: Option<String>,
     pub would_hire_again: Option<bool>,
@@ -561,18 +894,42 @@ pub struct SubcontractorReviewResponse {
     pub created_at: DateTime<Utc>,
 }
 
+/// Review left for a completed team member
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TeamMemberReview {
+    pub id: Uuid,
+    pub rating: Rating,
+    pub title: Option<String>,
+    pub comment: Option<String>,
+    pub would_hire_again: Option<bool>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Review a completed team member; `rating` becomes their performance rating
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TeamMemberReviewInput {
+    pub rating: Rating,
+    pub quality_rating: Option<Rating>,
+    pub communication_rating: Option<Rating>,
+    pub timeliness_rating: Option<Rating>,
+    pub value_rating: Option<Rating>,
+    pub title: Option<String>,
+    pub comment: Option<String>,
+    pub would_hire_again: Option<bool>,
+}
+
 /// Create review input
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct CreateReviewInput {
     pub subcontractor_id: Option<Uuid>,
     pub external_sub_id: Option<Uuid>,
     pub project_id: Option<Uuid>,
     pub contract_id: Option<Uuid>,
-    pub rating: f64,
-    pub quality_rating: Option<f64>,
-    pub communication_rating: Option<f64>,
-    pub timeliness_rating: Option<f64>,
-    pub value_rating: Option<f64>,
+    pub rating: Rating,
+    pub quality_rating: Option<Rating>,
+    pub communication_rating: Option<Rating>,
+    pub timeliness_rating: Option<Rating>,
+    pub value_rating: Option<Rating>,
     pub title: Option<String>,
     pub comment: Option<String>,
     pub would_hire_again: Option<bool>,
@@ -32,6 +32,23 @@ impl std::fmt::Display for JobStatus {
     }
 }
 
+/// Kind of work a processing job performs
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ProcessingJobType {
+    DocumentIngestion,
+    TradeScopeExtraction,
+}
+
+impl std::fmt::Display for ProcessingJobType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProcessingJobType::DocumentIngestion => write!(f, "document_ingestion"),
+            ProcessingJobType::TradeScopeExtraction => write!(f, "trade_scope_extraction"),
+        }
+    }
+}
+
 /// Processing step status
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, sqlx::Type)]
 #[serde(rename_all = "snake_case")]
@@ -93,12 +110,28 @@ impl std::fmt::Display for StepKey {
     }
 }
 
+impl StepKey {
+    /// Work items for this step given the document's page count: steps that
+    /// walk the document page by page report progress per page, others 0.
+    pub fn items_total(&self, page_count: Option<i32>) -> i32 {
+        match self {
+            StepKey::ExtractPages
+            | StepKey::OcrPages
+            | StepKey::ExtractTradeScopes
+            | StepKey::ExtractMaterials
+            | StepKey::ExtractRooms => page_count.unwrap_or(0),
+            _ => 0,
+        }
+    }
+}
+
 /// Processing job response
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProcessingJobResponse {
     pub id: Uuid,
     pub document_id: Uuid,
     pub project_id: Uuid,
+    pub job_type: String,
     pub status: String,
     pub current_step: Option<String>,
     pub progress: f64,
@@ -139,6 +172,79 @@ pub struct StartProcessingRequest {
     pub auto_start: Option<bool>,
 }
 
+/// Query parameters for starting a job
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct StartJobQuery {
+    /// Run even if a completed job exists for identical document content
+    #[serde(default)]
+    pub force: bool,
+}
+
+/// Response for job start endpoints
+#[derive(Debug, Clone, Serialize)]
+pub struct StartJobResponse {
+    #[serde(flatten)]
+    pub job: ProcessingJobResponse,
+    /// True when an earlier completed job was returned and no new work was queued
+    pub reused: bool,
+}
+
+/// Start AI trade scope extraction request
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StartTradeScopeExtractionRequest {
+    /// Limit extraction to these trades (all standard trades if omitted)
+    #[serde(default)]
+    pub trades: Option<Vec<String>>,
+    /// Pre-extracted document text; the AI service loads the document otherwise
+    #[serde(default)]
+    pub document_text: Option<String>,
+}
+
+/// Progress report posted by the AI service to the internal callback route
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobProgressCallback {
+    /// Step being reported on; omit for job-level updates
+    pub step_key: Option<String>,
+    pub status: Option<StepStatus>,
+    pub progress: Option<f64>,
+    pub message: Option<String>,
+    pub items_processed: Option<i32>,
+    pub items_total: Option<i32>,
+    /// Failure reason; fails the step (if given) and the job
+    pub error: Option<String>,
+    /// Final job output; completes the job when present
+    pub output: Option<serde_json::Value>,
+}
+
+/// Worker request to claim the next queued job
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClaimJobRequest {
+    pub worker_id: String,
+    /// Only claim these job types (any type if omitted)
+    #[serde(default)]
+    pub job_types: Option<Vec<ProcessingJobType>>,
+}
+
+/// Job handed to a worker by a successful claim
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClaimedJobResponse {
+    pub job_id: Uuid,
+    pub job_type: String,
+    pub project_id: Uuid,
+    pub document_id: Uuid,
+    /// Storage key (path) of the document file to process
+    pub storage_key: Option<String>,
+    pub content_hash: Option<String>,
+    pub input: serde_json::Value,
+    pub steps: Vec<ProcessingStepResponse>,
+}
+
+/// Worker heartbeat for a claimed job
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobHeartbeatRequest {
+    pub worker_id: String,
+}
+
 /// Job control action
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -235,3 +341,12 @@ pub fn default_ingestion_steps() -> Vec<(StepKey, &'static str, i32)> {
         (StepKey::Finalize, "Finalizing", 12),
     ]
 }
+
+/// Processing steps for AI trade scope extraction
+pub fn trade_scope_extraction_steps() -> Vec<(StepKey, &'static str, i32)> {
+    vec![
+        (StepKey::Validate, "Loading Document", 1),
+        (StepKey::ExtractTradeScopes, "Extracting Trade Scopes", 2),
+        (StepKey::Finalize, "Saving Results", 3),
+    ]
+}
@@ -5,12 +5,15 @@
 use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
+use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
+use super::money::Money;
+use super::rating::Rating;
 use super::subcontractors::RecentProject;
 
 /// Verification status for subcontractors
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum VerificationStatus {
     #[default]
@@ -39,8 +42,58 @@ impl std::fmt::Display for VerificationStatus {
     }
 }
 
+/// What a verification document proves
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum VerificationDocumentType {
+    Insurance,
+    License,
+    Other,
+}
+
+impl VerificationDocumentType {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "insurance" => Some(Self::Insurance),
+            "license" => Some(Self::License),
+            "other" => Some(Self::Other),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for VerificationDocumentType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Insurance => write!(f, "insurance"),
+            Self::License => write!(f, "license"),
+            Self::Other => write!(f, "other"),
+        }
+    }
+}
+
+/// Evidence (insurance certificate, license) attached to a verification request
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct VerificationDocumentResponse {
+    pub id: Uuid,
+    pub document_type: String,
+    pub file_name: String,
+    pub file_size: i64,
+    pub mime_type: Option<String>,
+    pub expiry_date: Option<NaiveDate>,
+    /// The expiry date has passed
+    pub expired: bool,
+    /// Signed link to view the file, for the uploader and admins
+    pub view_url: String,
+    pub view_expires_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    /// Set on upload when the document is already out of date
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
+}
+
 /// Availability status for subcontractors
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum AvailabilityStatus {
     #[default]
@@ -70,7 +123,7 @@ impl std::fmt::Display for AvailabilityStatus {
 }
 
 /// Certification info
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ToSchema)]
 pub struct Certification {
     pub name: String,
     pub issuer: Option<String>,
@@ -81,18 +134,35 @@ pub struct Certification {
 }
 
 /// Insurance info
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ToSchema)]
 pub struct InsuranceInfo {
-    pub general_liability: Option<i64>, // Coverage amount in cents
-    pub workers_comp: Option<i64>,
-    pub auto_liability: Option<i64>,
+    /// Coverage amounts in dollars
+    pub general_liability: Option<Money>,
+    pub workers_comp: Option<Money>,
+    pub auto_liability: Option<Money>,
+    /// Policy expiry date (YYYY-MM-DD)
     pub expiry_date: Option<String>,
     pub carrier: Option<String>,
     pub verified: bool,
 }
 
+impl InsuranceInfo {
+    /// Days before expiry at which insurance counts as expiring soon
+    pub const EXPIRY_WARNING_DAYS: i64 = 30;
+
+    /// Parsed `expiry_date`; `Err` when it is set but not YYYY-MM-DD
+    pub fn expiry(&self) -> Result<Option<NaiveDate>, String> {
+        match self.expiry_date.as_deref().map(str::trim) {
+            None | Some("") => Ok(None),
+            Some(date) => NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                .map(Some)
+                .map_err(|_| "insurance.expiry_date must be YYYY-MM-DD".to_string()),
+        }
+    }
+}
+
 /// License info
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ToSchema)]
 pub struct LicenseInfo {
     pub number: Option<String>,
     pub state: Option<String>,
@@ -102,7 +172,7 @@ pub struct LicenseInfo {
 }
 
 /// Enhanced subcontractor profile for marketplace
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct SubcontractorProfile {
     pub id: Uuid,
     pub profile_id: Option<Uuid>,
@@ -111,14 +181,17 @@ pub struct SubcontractorProfile {
     pub secondary_trades: Vec<String>,
     pub headline: Option<String>,
     pub company_description: Option<String>,
-    pub rating: f64,
+    pub rating: Rating,
     pub review_count: i32,
     pub location: Option<String>,
     pub contact_email: Option<String>,
     pub contact_phone: Option<String>,
     pub website: Option<String>,
+    pub logo_url: Option<String>,
+    pub logo_thumbnail_url: Option<String>,
     pub projects_completed: i32,
-    pub average_bid_value: Option<i64>,
+    /// Average bid in dollars
+    pub average_bid_value: Option<Money>,
     pub response_time: Option<String>,
     pub response_time_hours: Option<i32>,
     pub verified: bool,
@@ -127,19 +200,125 @@ pub struct SubcontractorProfile {
     pub service_areas: Vec<String>,
     pub certifications: Vec<Certification>,
     pub insurance: Option<InsuranceInfo>,
+    /// Insurance expiry date has passed
+    pub insurance_expired: bool,
+    /// Insurance expires within `InsuranceInfo::EXPIRY_WARNING_DAYS` days
+    pub insurance_expiring_soon: bool,
     pub license_info: Option<LicenseInfo>,
     pub year_established: Option<i32>,
     pub employee_count: Option<String>,
-    pub min_project_value: Option<i64>,
-    pub max_project_value: Option<i64>,
+    /// Smallest project taken on, in dollars
+    pub min_project_value: Option<Money>,
+    /// Largest project taken on, in dollars
+    pub max_project_value: Option<Money>,
     pub availability_status: String,
     pub recent_projects: Vec<RecentProject>,
     pub portfolio_count: i32,
+    /// Review averages per criterion; null until a review rates that criterion
+    pub avg_quality: Option<Rating>,
+    pub avg_communication: Option<Rating>,
+    pub avg_timeliness: Option<Rating>,
+    pub avg_value: Option<Rating>,
+    /// Share of reviews answering "would hire again" that said yes (0-100)
+    pub would_hire_again_pct: Option<f64>,
+    /// Distance from the `near` search point, when both have coordinates
+    pub distance_km: Option<f64>,
+    /// How complete the profile is; only returned on the caller's own profile
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completeness: Option<ProfileCompleteness>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Directory card for subcontractor list views; the detail endpoint returns
+/// the full `SubcontractorProfile`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SubcontractorListItem {
+    pub id: Uuid,
+    pub name: String,
+    pub trade: String,
+    pub secondary_trades: Vec<String>,
+    pub headline: Option<String>,
+    pub location: Option<String>,
+    pub rating: Rating,
+    pub review_count: i32,
+    pub logo_thumbnail_url: Option<String>,
+    pub projects_completed: i32,
+    pub response_time_hours: Option<i32>,
+    pub verified: bool,
+    pub availability_status: String,
+    /// Insurance expiry date has passed
+    pub insurance_expired: bool,
+    /// Smallest project taken on, in dollars
+    pub min_project_value: Option<Money>,
+    /// Largest project taken on, in dollars
+    pub max_project_value: Option<Money>,
+    /// Distance from the `near` search point, when both have coordinates
+    pub distance_km: Option<f64>,
     pub created_at: DateTime<Utc>,
 }
 
+impl SubcontractorListItem {
+    /// Fields list endpoints accept in `?fields=`
+    pub const FIELDS: &'static [&'static str] = &[
+        "id", "name", "trade", "secondary_trades", "headline", "location", "rating",
+        "review_count", "logo_thumbnail_url", "projects_completed", "response_time_hours",
+        "verified", "availability_status", "insurance_expired", "min_project_value",
+        "max_project_value", "distance_km", "created_at",
+    ];
+}
+
+/// Weighted share of key profile fields that are filled in
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ProfileCompleteness {
+    /// 0-100
+    pub percent: u8,
+    /// Fields still to fill in: `headline`, `description`, `insurance`,
+    /// `license`, `certifications`, `portfolio`, `service_areas`
+    pub missing: Vec<String>,
+}
+
+/// Geographic search filter: `?near=lat,lng&radius_km=`
+#[derive(Debug, Clone, Deserialize, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct GeoQuery {
+    #[serde(default)]
+    pub near: Option<String>,
+    #[serde(default)]
+    pub radius_km: Option<f64>,
+}
+
+impl GeoQuery {
+    pub const DEFAULT_RADIUS_KM: f64 = 50.0;
+    pub const MAX_RADIUS_KM: f64 = 500.0;
+
+    /// Parse `near` into `(latitude, longitude)`; `Err` describes invalid input
+    pub fn point(&self) -> Result<Option<(f64, f64)>, String> {
+        let Some(near) = self.near.as_deref() else {
+            return Ok(None);
+        };
+        let (lat, lng) = near
+            .split_once(',')
+            .ok_or_else(|| "near must be formatted as lat,lng".to_string())?;
+        let lat: f64 = lat.trim().parse().map_err(|_| "Invalid latitude in near".to_string())?;
+        let lng: f64 = lng.trim().parse().map_err(|_| "Invalid longitude in near".to_string())?;
+        if !(-90.0..=90.0).contains(&lat) || !(-180.0..=180.0).contains(&lng) {
+            return Err("near coordinates are out of range".to_string());
+        }
+        Ok(Some((lat, lng)))
+    }
+
+    /// Search radius in meters
+    pub fn radius_meters(&self) -> f64 {
+        self.radius_km
+            .unwrap_or(Self::DEFAULT_RADIUS_KM)
+            .clamp(0.1, Self::MAX_RADIUS_KM)
+            * 1000.0
+    }
+}
+
 /// Query params for marketplace search
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Deserialize, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct MarketplaceSubcontractorQuery {
     #[serde(default)]
     pub search: Option<String>,
@@ -155,10 +334,13 @@ pub struct MarketplaceSubcontractorQuery {
     pub min_rating: Option<f64>,
     #[serde(default)]
     pub availability: Option<String>,
+    /// Only subcontractors whose range reaches this value, in dollars
     #[serde(default)]
-    pub min_project_value: Option<i64>,
+    pub min_project_value: Option<Money>,
+    /// Only subcontractors whose range starts at or below this value, in dollars
     #[serde(default)]
-    pub max_project_value: Option<i64>,
+    pub max_project_value: Option<Money>,
+    /// Only subcontractors with insurance on file that has not expired
     #[serde(default)]
     pub has_insurance: Option<bool>,
     #[serde(default)]
@@ -167,8 +349,40 @@ pub struct MarketplaceSubcontractorQuery {
     pub sort_order: Option<String>, // asc, desc
 }
 
+/// Query params for tender subcontractor recommendations
+#[derive(Debug, Clone, Deserialize, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct RecommendedSubsQuery {
+    #[serde(default)]
+    pub limit: Option<u32>,
+}
+
+impl RecommendedSubsQuery {
+    /// Maximum number of recommendations returned
+    pub const MAX_LIMIT: u32 = 50;
+
+    pub fn limit(&self) -> u32 {
+        self.limit.unwrap_or(10).clamp(1, Self::MAX_LIMIT)
+    }
+}
+
+/// A subcontractor suggested for a tender, ranked by match score (0-100)
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RecommendedSubcontractor {
+    #[serde(flatten)]
+    pub subcontractor: SubcontractorProfile,
+    pub match_score: u32,
+    pub match_reasons: Vec<String>,
+}
+
+/// Request to invite a marketplace subcontractor to bid on a tender
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct InviteSubcontractorRequest {
+    pub subcontractor_id: Uuid,
+}
+
 /// Request to update marketplace profile (for subs)
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, ToSchema)]
 pub struct UpdateMarketplaceProfileRequest {
     #[serde(default)]
     pub name: Option<String>,
@@ -202,14 +416,24 @@ pub struct UpdateMarketplaceProfileRequest {
     pub year_established: Option<i32>,
     #[serde(default)]
     pub employee_count: Option<String>,
+    /// Smallest project taken on, in dollars
     #[serde(default)]
-    pub min_project_value: Option<i64>,
+    pub min_project_value: Option<Money>,
+    /// Largest project taken on, in dollars
     #[serde(default)]
-    pub max_project_value: Option<i64>,
+    pub max_project_value: Option<Money>,
     #[serde(default)]
     pub availability_status: Option<String>,
+    /// Explicit coordinates; otherwise a changed `location` is geocoded
+    #[serde(default)]
+    pub latitude: Option<f64>,
+    #[serde(default)]
+    pub longitude: Option<f64>,
 }
 
+/// Maximum number of images per portfolio project
+pub const MAX_PORTFOLIO_IMAGES: usize = 20;
+
 /// Portfolio project entity
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct PortfolioProject {
@@ -221,7 +445,8 @@ pub struct PortfolioProject {
     pub trade_category: Option<String>,
     pub location: Option<String>,
     pub completion_date: Option<NaiveDate>,
-    pub project_value: Option<i64>,
+    /// Project value in dollars
+    pub project_value: Option<Money>,
     pub client_name: Option<String>,
     pub client_testimonial: Option<String>,
     pub images: sqlx::types::Json<Vec<String>>,
@@ -231,7 +456,7 @@ pub struct PortfolioProject {
 }
 
 /// Request to create/update portfolio project
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, ToSchema)]
 pub struct PortfolioProjectRequest {
     pub title: String,
     #[serde(default)]
@@ -244,12 +469,15 @@ pub struct PortfolioProjectRequest {
     pub location: Option<String>,
     #[serde(default)]
     pub completion_date: Option<NaiveDate>,
+    /// Project value in dollars
     #[serde(default)]
-    pub project_value: Option<i64>,
+    pub project_value: Option<Money>,
     #[serde(default)]
     pub client_name: Option<String>,
     #[serde(default)]
     pub client_testimonial: Option<String>,
+    /// External image URLs or URLs of this project's uploads (for reordering);
+    /// new images are added via `POST .../portfolio/:id/images`
     #[serde(default)]
     pub images: Option<Vec<String>>,
     #[serde(default)]
@@ -259,7 +487,7 @@ pub struct PortfolioProjectRequest {
 }
 
 /// Response DTO for portfolio project
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct PortfolioProjectResponse {
     pub id: Uuid,
     pub title: String,
@@ -268,7 +496,8 @@ pub struct PortfolioProjectResponse {
     pub trade_category: Option<String>,
     pub location: Option<String>,
     pub completion_date: Option<NaiveDate>,
-    pub project_value: Option<i64>,
+    /// Project value in dollars
+    pub project_value: Option<Money>,
     pub client_name: Option<String>,
     pub client_testimonial: Option<String>,
     pub images: Vec<String>,
@@ -298,6 +527,16 @@ impl From<PortfolioProject> for PortfolioProjectResponse {
     }
 }
 
+/// Query for removing a portfolio image, by position or by URL
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PortfolioImageDeleteQuery {
+    #[serde(default)]
+    pub index: Option<usize>,
+    #[serde(default)]
+    pub url: Option<String>,
+}
+
 /// Saved search entity
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct SavedSearch {
@@ -313,7 +552,7 @@ pub struct SavedSearch {
 }
 
 /// Request to create saved search
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, ToSchema)]
 pub struct CreateSavedSearchRequest {
     pub name: String,
     #[serde(default = "default_search_type")]
@@ -328,7 +567,7 @@ fn default_search_type() -> String {
 }
 
 /// Response DTO for saved search
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct SavedSearchResponse {
     pub id: Uuid,
     pub name: String,
@@ -354,7 +593,7 @@ impl From<SavedSearch> for SavedSearchResponse {
 }
 
 /// Tender visibility enum
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum TenderVisibility {
     #[default]
@@ -381,7 +620,7 @@ impl std::fmt::Display for TenderVisibility {
 }
 
 /// Enhanced tender for marketplace
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct MarketplaceTender {
     pub id: Uuid,
     pub project_id: Uuid,
@@ -395,27 +634,43 @@ pub struct MarketplaceTender {
     pub status: String,
     pub visibility: String,
     pub bid_due_date: Option<DateTime<Utc>>,
-    pub estimated_value: Option<i64>,
-    pub reserve_price: Option<i64>,
+    /// Estimated package value in dollars
+    pub estimated_value: Option<Money>,
+    /// Reserve price in dollars
+    pub reserve_price: Option<Money>,
     pub requirements: serde_json::Value,
     pub bids_received: i32,
     pub priority: Option<String>,
+    /// Distance from the `near` search point, when both have coordinates
+    pub distance_km: Option<f64>,
     pub created_at: DateTime<Utc>,
     // For authenticated sub users
     pub my_bid: Option<MarketplaceBidSummary>,
 }
 
+impl MarketplaceTender {
+    /// Fields list endpoints accept in `?fields=`
+    pub const FIELDS: &'static [&'static str] = &[
+        "id", "project_id", "project_name", "gc_company_name", "name", "description",
+        "trade_category", "scope_of_work", "location", "status", "visibility", "bid_due_date",
+        "estimated_value", "reserve_price", "requirements", "bids_received", "priority",
+        "distance_km", "created_at", "my_bid",
+    ];
+}
+
 /// Summary of user's bid on a tender
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct MarketplaceBidSummary {
     pub id: Uuid,
-    pub bid_amount: i64,
+    /// Bid total in dollars
+    pub bid_amount: Money,
     pub status: String,
     pub submitted_at: Option<DateTime<Utc>>,
 }
 
 /// Query params for marketplace tenders
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Deserialize, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct MarketplaceTenderQuery {
     #[serde(default)]
     pub search: Option<String>,
@@ -425,10 +680,12 @@ pub struct MarketplaceTenderQuery {
     pub trades: Option<Vec<String>>,
     #[serde(default)]
     pub location: Option<String>,
+    /// Minimum estimated value in dollars
     #[serde(default)]
-    pub min_value: Option<i64>,
+    pub min_value: Option<Money>,
+    /// Maximum estimated value in dollars
     #[serde(default)]
-    pub max_value: Option<i64>,
+    pub max_value: Option<Money>,
     #[serde(default)]
     pub due_within_days: Option<i32>,
     #[serde(default)]
@@ -438,9 +695,10 @@ pub struct MarketplaceTenderQuery {
 }
 
 /// Enhanced bid request for marketplace
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, ToSchema)]
 pub struct SubmitBidRequest {
-    pub bid_amount: i64,
+    /// Bid total in dollars
+    pub bid_amount: Money,
     #[serde(default)]
     pub breakdown: Option<Vec<BidLineItem>>,
     #[serde(default)]
@@ -454,17 +712,19 @@ pub struct SubmitBidRequest {
 }
 
 /// Bid line item for breakdown
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct BidLineItem {
     pub description: String,
     pub quantity: Option<f64>,
     pub unit: Option<String>,
-    pub unit_price: Option<i64>,
-    pub total: i64,
+    /// Unit price in dollars
+    pub unit_price: Option<Money>,
+    /// Line total in dollars
+    pub total: Money,
 }
 
 /// Enhanced bid response for marketplace
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct MarketplaceBidResponse {
     pub id: Uuid,
     pub tender_id: Uuid,
@@ -472,7 +732,8 @@ pub struct MarketplaceBidResponse {
     pub project_name: Option<String>,
     pub subcontractor_id: Option<Uuid>,
     pub company_name: String,
-    pub bid_amount: i64,
+    /// Bid total in dollars
+    pub bid_amount: Money,
     pub breakdown: Vec<BidLineItem>,
     pub proposed_timeline_days: Option<i32>,
     pub proposed_start_date: Option<NaiveDate>,
@@ -5,6 +5,7 @@
 
 #![allow(dead_code)]
 
+pub mod activity;
 pub mod admin;
 pub mod ai;
 pub mod auth;
@@ -14,14 +15,18 @@ pub mod extraction;
 pub mod hiring;
 pub mod jobs;
 pub mod marketplace;
+pub mod money;
 pub mod notifications;
+pub mod patch;
 pub mod profiles;
 pub mod projects;
+pub mod rating;
 pub mod rfis;
 pub mod settings;
 pub mod subcontractors;
 pub mod tasks;
 pub mod tenders;
+pub mod trades;
 
 // Re-export commonly used types
 #[allow(unused_imports)]
@@ -0,0 +1,158 @@
+//! Money value type
+//!
+//! Amounts are held as whole cents so arithmetic is exact. The database
+//! stores dollars in `DECIMAL(15, 2)` columns and the API exchanges dollars as
+//! decimal strings (`"1250.00"`); `Money` converts at both boundaries so no
+//! handler has to scale by 100 by hand.
+
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueRef, Postgres};
+use std::fmt;
+use std::iter::Sum;
+use std::str::FromStr;
+use std::ops::{Add, AddAssign, Sub};
+use utoipa::ToSchema;
+
+/// Largest amount a `DECIMAL(15, 2)` column holds, in cents
+const MAX_CENTS: i64 = 999_999_999_999_999;
+
+/// A USD amount in cents.
+///
+/// Serializes as a dollar string with two decimal places. Deserializes from
+/// a dollar string or number (`"1250.5"`, `1250.5`), rounded half-up to the
+/// cent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, ToSchema)]
+#[schema(value_type = String, format = "decimal", example = "1250.00")]
+pub struct Money(i64);
+
+impl Money {
+    pub const ZERO: Money = Money(0);
+
+    pub const fn from_cents(cents: i64) -> Self {
+        Self(cents)
+    }
+
+    pub const fn cents(self) -> i64 {
+        self.0
+    }
+
+    /// Dollar value for binding into a `DECIMAL(15, 2)` column.
+    pub fn to_decimal(self) -> Decimal {
+        Decimal::new(self.0, 2)
+    }
+
+    /// Dollar value as a float, for display text only (never for arithmetic).
+    pub fn to_f64(self) -> f64 {
+        self.to_decimal().to_f64().unwrap_or_default()
+    }
+
+    /// This amount times `factor` (e.g. a quantity), rounded to the cent.
+    pub fn scaled(self, factor: f64) -> Money {
+        // Shortest decimal form, so a quantity of 0.1 multiplies as exactly 0.1
+        Decimal::from_str(&factor.to_string())
+            .ok()
+            .and_then(|f| self.to_decimal().checked_mul(f))
+            .map(Self::from)
+            .unwrap_or_default()
+    }
+
+    pub fn is_negative(self) -> bool {
+        self.0 < 0
+    }
+
+    fn try_from_dollars(dollars: Decimal) -> Result<Self, String> {
+        let cents = dollars.round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero) * Decimal::ONE_HUNDRED;
+        cents
+            .to_i64()
+            .filter(|c| c.abs() <= MAX_CENTS)
+            .map(Self)
+            .ok_or_else(|| format!("Amount {} is out of range", dollars))
+    }
+}
+
+/// Dollars (e.g. a `DECIMAL(15, 2)` column) to cents, saturating on overflow.
+impl From<Decimal> for Money {
+    fn from(dollars: Decimal) -> Self {
+        Self::try_from_dollars(dollars).unwrap_or(if dollars.is_sign_negative() {
+            Self(-MAX_CENTS)
+        } else {
+            Self(MAX_CENTS)
+        })
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_decimal())
+    }
+}
+
+impl Serialize for Money {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Money {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let dollars = <Decimal as Deserialize>::deserialize(deserializer)?;
+        Self::try_from_dollars(dollars).map_err(serde::de::Error::custom)
+    }
+}
+
+impl Add for Money {
+    type Output = Money;
+
+    fn add(self, rhs: Money) -> Money {
+        Money(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for Money {
+    fn add_assign(&mut self, rhs: Money) {
+        self.0 += rhs.0;
+    }
+}
+
+impl Sub for Money {
+    type Output = Money;
+
+    fn sub(self, rhs: Money) -> Money {
+        Money(self.0 - rhs.0)
+    }
+}
+
+impl Sum for Money {
+    fn sum<I: Iterator<Item = Money>>(iter: I) -> Money {
+        iter.fold(Money::ZERO, Add::add)
+    }
+}
+
+// Database boundary: `Money` binds and decodes as the dollar `NUMERIC` value.
+
+impl sqlx::Type<Postgres> for Money {
+    fn type_info() -> PgTypeInfo {
+        <Decimal as sqlx::Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <Decimal as sqlx::Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl sqlx::Encode<'_, Postgres> for Money {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        <Decimal as sqlx::Encode<Postgres>>::encode_by_ref(&self.to_decimal(), buf)
+    }
+}
+
+impl<'r> sqlx::Decode<'r, Postgres> for Money {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        let dollars = <Decimal as sqlx::Decode<Postgres>>::decode(value)?;
+        Ok(Self::from(dollars))
+    }
+}
@@ -2,13 +2,15 @@
 //!
 //! In-app notification system for real-time user alerts.
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use uuid::Uuid;
 
+use super::money::Money;
+
 /// Notification type enum
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum NotificationType {
     // Bid-related
@@ -17,6 +19,7 @@ pub enum NotificationType {
     BidRejected,
     BidShortlisted,
     BidWithdrawn,
+    BidRestored,
 
     // Hire request related
     HireRequestReceived,
@@ -28,6 +31,7 @@ pub enum NotificationType {
     ContractSent,
     ContractSigned,
     ContractFullySigned,
+    PaymentMilestonePaid,
 
     // Review related
     ReviewReceived,
@@ -37,14 +41,30 @@ pub enum NotificationType {
     ProfileVerified,
     ProfileRejected,
     ProfileViewed,
+    AdminRoleChanged,
+    InsuranceExpiring,
+    InsuranceLapsed,
 
     // Message related
     NewMessage,
 
     // Tender related
     TenderPublished,
+    TenderInvitation,
     TenderClosingSoon,
     TenderClosed,
+    TenderQuestionAsked,
+    TenderQuestionAnswered,
+    TenderBidPlaced,
+    TenderStatusChanged,
+
+    // RFI related
+    RfiResponseAdded,
+    RfiResponseAccepted,
+    RfiStatusChanged,
+
+    // Processing jobs
+    JobFailed,
 
     // System
     System,
@@ -63,6 +83,292 @@ impl From<String> for NotificationType {
     }
 }
 
+/// Structured `data` payload for each notification type.
+///
+/// This is the contract clients can rely on: every variant's fields are stored
+/// in `notifications.data` (plus a `link`), and the variant determines the
+/// notification's `type`. Adding a notification type means adding a variant
+/// here, so the compiler flags every place that needs to handle it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "notification_type", content = "data", rename_all = "snake_case")]
+pub enum NotificationData {
+    BidReceived {
+        tender_id: Uuid,
+        bid_id: Option<Uuid>,
+        tender_title: String,
+        subcontractor_name: String,
+        bid_amount: Money,
+    },
+    BidAwarded {
+        tender_id: Uuid,
+        tender_title: String,
+        project_name: String,
+    },
+    BidRejected {
+        tender_id: Uuid,
+        tender_title: String,
+    },
+    BidShortlisted {
+        tender_id: Uuid,
+        tender_title: String,
+    },
+    BidWithdrawn {
+        tender_id: Uuid,
+        tender_title: String,
+        subcontractor_name: String,
+    },
+    BidRestored {
+        tender_id: Uuid,
+        bid_id: Uuid,
+        tender_title: String,
+        subcontractor_name: String,
+    },
+    HireRequestReceived {
+        hire_request_id: Uuid,
+        gc_company_name: String,
+        project_name: String,
+        trade: String,
+    },
+    HireRequestAccepted {
+        hire_request_id: Uuid,
+        subcontractor_name: String,
+        project_name: String,
+    },
+    HireRequestDeclined {
+        hire_request_id: Uuid,
+        subcontractor_name: String,
+        project_name: String,
+    },
+    HireRequestExpired {
+        hire_request_id: Uuid,
+        project_name: Option<String>,
+    },
+    ContractSent {
+        contract_id: Uuid,
+        gc_company_name: String,
+        project_name: String,
+    },
+    ContractSigned {
+        contract_id: Uuid,
+        signer_name: String,
+        project_name: String,
+    },
+    ContractFullySigned {
+        contract_id: Uuid,
+        signer_name: String,
+        project_name: String,
+    },
+    PaymentMilestonePaid {
+        contract_id: Uuid,
+        milestone_name: String,
+        amount: Money,
+        outstanding_amount: Money,
+    },
+    ReviewReceived {
+        review_id: Uuid,
+        reviewer_name: String,
+        rating: f64,
+    },
+    ReviewResponseReceived {
+        review_id: Uuid,
+    },
+    ProfileVerified {},
+    ProfileRejected {
+        reason: String,
+    },
+    ProfileViewed {
+        viewer_company_name: Option<String>,
+    },
+    AdminRoleChanged {
+        is_admin: bool,
+    },
+    InsuranceExpiring {
+        expiry_date: NaiveDate,
+        days_remaining: i64,
+    },
+    InsuranceLapsed {
+        expiry_date: NaiveDate,
+    },
+    NewMessage {
+        hire_request_id: Uuid,
+        sender_name: String,
+        snippet: String,
+        message_count: i64,
+    },
+    TenderPublished {
+        tender_id: Uuid,
+        tender_title: String,
+    },
+    TenderInvitation {
+        tender_id: Uuid,
+        tender_title: String,
+    },
+    TenderClosingSoon {
+        tender_id: Uuid,
+        tender_title: String,
+        hours_remaining: i32,
+    },
+    TenderClosed {
+        tender_id: Uuid,
+        tender_title: String,
+    },
+    TenderQuestionAsked {
+        tender_id: Uuid,
+        question_id: Uuid,
+        tender_title: String,
+    },
+    TenderQuestionAnswered {
+        tender_id: Uuid,
+        question_id: Uuid,
+        tender_title: String,
+    },
+    /// Sent to tender watchers; carries no bid details
+    TenderBidPlaced {
+        tender_id: Uuid,
+        tender_title: String,
+    },
+    TenderStatusChanged {
+        tender_id: Uuid,
+        tender_title: String,
+        status: String,
+    },
+    RfiResponseAdded {
+        project_id: Uuid,
+        rfi_id: Uuid,
+        response_id: Uuid,
+        rfi_title: String,
+        author_name: String,
+    },
+    RfiResponseAccepted {
+        project_id: Uuid,
+        rfi_id: Uuid,
+        response_id: Uuid,
+        rfi_title: String,
+    },
+    RfiStatusChanged {
+        project_id: Uuid,
+        rfi_id: Uuid,
+        rfi_title: String,
+        status: String,
+    },
+    JobFailed {
+        job_id: Uuid,
+        project_id: Uuid,
+    },
+    System {},
+}
+
+impl NotificationData {
+    /// Notification type this payload belongs to
+    pub fn notification_type(&self) -> NotificationType {
+        match self {
+            Self::BidReceived { .. } => NotificationType::BidReceived,
+            Self::BidAwarded { .. } => NotificationType::BidAwarded,
+            Self::BidRejected { .. } => NotificationType::BidRejected,
+            Self::BidShortlisted { .. } => NotificationType::BidShortlisted,
+            Self::BidWithdrawn { .. } => NotificationType::BidWithdrawn,
+            Self::BidRestored { .. } => NotificationType::BidRestored,
+            Self::HireRequestReceived { .. } => NotificationType::HireRequestReceived,
+            Self::HireRequestAccepted { .. } => NotificationType::HireRequestAccepted,
+            Self::HireRequestDeclined { .. } => NotificationType::HireRequestDeclined,
+            Self::HireRequestExpired { .. } => NotificationType::HireRequestExpired,
+            Self::ContractSent { .. } => NotificationType::ContractSent,
+            Self::ContractSigned { .. } => NotificationType::ContractSigned,
+            Self::ContractFullySigned { .. } => NotificationType::ContractFullySigned,
+            Self::PaymentMilestonePaid { .. } => NotificationType::PaymentMilestonePaid,
+            Self::ReviewReceived { .. } => NotificationType::ReviewReceived,
+            Self::ReviewResponseReceived { .. } => NotificationType::ReviewResponseReceived,
+            Self::ProfileVerified {} => NotificationType::ProfileVerified,
+            Self::ProfileRejected { .. } => NotificationType::ProfileRejected,
+            Self::ProfileViewed { .. } => NotificationType::ProfileViewed,
+            Self::AdminRoleChanged { .. } => NotificationType::AdminRoleChanged,
+            Self::InsuranceExpiring { .. } => NotificationType::InsuranceExpiring,
+            Self::InsuranceLapsed { .. } => NotificationType::InsuranceLapsed,
+            Self::NewMessage { .. } => NotificationType::NewMessage,
+            Self::TenderPublished { .. } => NotificationType::TenderPublished,
+            Self::TenderInvitation { .. } => NotificationType::TenderInvitation,
+            Self::TenderClosingSoon { .. } => NotificationType::TenderClosingSoon,
+            Self::TenderClosed { .. } => NotificationType::TenderClosed,
+            Self::TenderQuestionAsked { .. } => NotificationType::TenderQuestionAsked,
+            Self::TenderQuestionAnswered { .. } => NotificationType::TenderQuestionAnswered,
+            Self::TenderBidPlaced { .. } => NotificationType::TenderBidPlaced,
+            Self::TenderStatusChanged { .. } => NotificationType::TenderStatusChanged,
+            Self::RfiResponseAdded { .. } => NotificationType::RfiResponseAdded,
+            Self::RfiResponseAccepted { .. } => NotificationType::RfiResponseAccepted,
+            Self::RfiStatusChanged { .. } => NotificationType::RfiStatusChanged,
+            Self::JobFailed { .. } => NotificationType::JobFailed,
+            Self::System {} => NotificationType::System,
+        }
+    }
+
+    /// In-app route the frontend should open for this notification
+    pub fn link(&self) -> Option<String> {
+        match self {
+            Self::BidReceived { tender_id, .. }
+            | Self::BidWithdrawn { tender_id, .. }
+            | Self::BidRestored { tender_id, .. }
+            | Self::TenderQuestionAsked { tender_id, .. } => {
+                Some(format!("/tenders/{}", tender_id))
+            }
+            Self::BidAwarded { tender_id, .. }
+            | Self::BidRejected { tender_id, .. }
+            | Self::BidShortlisted { tender_id, .. }
+            | Self::TenderPublished { tender_id, .. }
+            | Self::TenderInvitation { tender_id, .. }
+            | Self::TenderClosingSoon { tender_id, .. }
+            | Self::TenderClosed { tender_id, .. }
+            | Self::TenderQuestionAnswered { tender_id, .. }
+            | Self::TenderBidPlaced { tender_id, .. }
+            | Self::TenderStatusChanged { tender_id, .. } => Some(format!("/marketplace/tenders/{}", tender_id)),
+            Self::HireRequestReceived { hire_request_id, .. }
+            | Self::HireRequestAccepted { hire_request_id, .. }
+            | Self::HireRequestDeclined { hire_request_id, .. }
+            | Self::HireRequestExpired { hire_request_id, .. }
+            | Self::NewMessage { hire_request_id, .. } => Some(format!("/hiring/{}", hire_request_id)),
+            Self::ContractSent { contract_id, .. }
+            | Self::ContractSigned { contract_id, .. }
+            | Self::ContractFullySigned { contract_id, .. }
+            | Self::PaymentMilestonePaid { contract_id, .. } => Some(format!("/contracts/{}", contract_id)),
+            Self::ReviewReceived { .. }
+            | Self::ReviewResponseReceived { .. }
+            | Self::ProfileVerified {}
+            | Self::ProfileRejected { .. }
+            | Self::ProfileViewed { .. }
+            | Self::InsuranceExpiring { .. }
+            | Self::InsuranceLapsed { .. } => Some("/marketplace/profile".to_string()),
+            Self::AdminRoleChanged { is_admin } => is_admin.then(|| "/admin".to_string()),
+            Self::RfiResponseAdded { project_id, rfi_id, .. }
+            | Self::RfiResponseAccepted { project_id, rfi_id, .. }
+            | Self::RfiStatusChanged { project_id, rfi_id, .. } => {
+                Some(format!("/projects/{}/rfis/{}", project_id, rfi_id))
+            }
+            Self::JobFailed { job_id, project_id } => Some(format!("/projects/{}/jobs/{}", project_id, job_id)),
+            Self::System {} => None,
+        }
+    }
+
+    /// JSON stored in `notifications.data`: the payload fields plus `link`
+    pub fn to_stored(&self) -> serde_json::Value {
+        let mut data = serde_json::to_value(self)
+            .ok()
+            .and_then(|mut v| v.get_mut("data").map(serde_json::Value::take))
+            .unwrap_or_else(|| serde_json::json!({}));
+        if let (Some(map), Some(link)) = (data.as_object_mut(), self.link()) {
+            map.insert("link".to_string(), serde_json::Value::String(link));
+        }
+        data
+    }
+
+    /// Parse a stored row back into its typed payload, if it matches the contract
+    pub fn from_stored(notification_type: &str, data: &serde_json::Value) -> Option<Self> {
+        serde_json::from_value(serde_json::json!({
+            "notification_type": notification_type,
+            "data": data,
+        }))
+        .ok()
+    }
+}
+
 /// Notification entity
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct Notification {
@@ -97,13 +403,52 @@ pub struct NotificationQuery {
     pub notification_type: Option<String>,
 }
 
+/// Query params for marking notifications read by filter
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct MarkReadFilter {
+    #[serde(default, rename = "type")]
+    pub notification_type: Option<String>,
+    /// Only notifications created before this timestamp
+    #[serde(default)]
+    pub before: Option<DateTime<Utc>>,
+}
+
+/// Query params for bulk-deleting notifications. Only read notifications are
+/// deleted unless `include_unread` is set.
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct DeleteNotificationsFilter {
+    #[serde(default, rename = "type")]
+    pub notification_type: Option<String>,
+    /// Only notifications created before this timestamp
+    #[serde(default)]
+    pub before: Option<DateTime<Utc>>,
+    /// Match on read state; `false` (unread only) needs `include_unread`
+    #[serde(default)]
+    pub read: Option<bool>,
+    /// Allow unread notifications to be deleted
+    #[serde(default)]
+    pub include_unread: bool,
+}
+
+/// Result of a batch mark-read
+#[derive(Debug, Clone, Serialize)]
+pub struct MarkReadResponse {
+    pub marked_count: u64,
+    /// Unread notifications remaining, for updating the badge
+    pub unread_count: i64,
+}
+
 /// Response DTO for notification
+///
+/// `data` follows the [`NotificationData`] contract for `notification_type`;
+/// `link` is the in-app route to open when the notification is clicked.
 #[derive(Debug, Clone, Serialize)]
 pub struct NotificationResponse {
     pub id: Uuid,
-    pub notification_type: String,
+    pub notification_type: NotificationType,
     pub title: String,
     pub message: Option<String>,
+    pub link: Option<String>,
     pub data: serde_json::Value,
     pub is_read: bool,
     pub read_at: Option<DateTime<Utc>>,
@@ -112,12 +457,24 @@ pub struct NotificationResponse {
 
 impl From<Notification> for NotificationResponse {
     fn from(n: Notification) -> Self {
+        let stored = n.data.0;
+        // Rows written before the typed contract may not parse; fall back to
+        // the raw data and any link it carries
+        let (data, link) = match NotificationData::from_stored(&n.notification_type, &stored) {
+            Some(typed) => (typed.to_stored(), typed.link()),
+            None => {
+                let link = stored.get("link").and_then(|l| l.as_str()).map(str::to_string);
+                (stored, link)
+            }
+        };
+
         Self {
             id: n.id,
-            notification_type: n.notification_type,
+            notification_type: NotificationType::from(n.notification_type),
             title: n.title,
             message: n.message,
-            data: n.data.0,
+            link,
+            data,
             is_read: n.is_read,
             read_at: n.read_at,
             created_at: n.created_at,
@@ -125,6 +482,54 @@ impl From<Notification> for NotificationResponse {
     }
 }
 
+/// Event on a user's live notification stream
+/// (`GET /api/notifications/stream`). The SSE event name is the `event` tag;
+/// `unread_delta` is the change to the unread count.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum NotificationStreamEvent {
+    /// Unread count when the stream opens
+    UnreadCount { count: i64 },
+    /// A notification was created
+    Created {
+        notification: NotificationResponse,
+        unread_delta: i64,
+    },
+    /// A notification was rewritten in place, e.g. a message notification
+    /// folding in another message; replaces the client's copy by id
+    Updated {
+        notification: NotificationResponse,
+        unread_delta: i64,
+    },
+    /// Notifications were marked read; `notification_ids` is `None` when a
+    /// filter or "read all" matched them
+    Read {
+        notification_ids: Option<Vec<Uuid>>,
+        unread_delta: i64,
+    },
+    /// Notifications were deleted
+    Deleted {
+        notification_ids: Vec<Uuid>,
+        unread_delta: i64,
+    },
+    /// Sent periodically so clients and proxies keep the stream open
+    Heartbeat { timestamp: DateTime<Utc> },
+}
+
+impl NotificationStreamEvent {
+    /// SSE event name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::UnreadCount { .. } => "unread_count",
+            Self::Created { .. } => "created",
+            Self::Updated { .. } => "updated",
+            Self::Read { .. } => "read",
+            Self::Deleted { .. } => "deleted",
+            Self::Heartbeat { .. } => "heartbeat",
+        }
+    }
+}
+
 /// Unread count response
 #[derive(Debug, Clone, Serialize)]
 pub struct UnreadCountResponse {
@@ -137,3 +542,11 @@ pub struct MarkReadRequest {
     #[serde(default)]
     pub notification_ids: Option<Vec<Uuid>>,
 }
+
+/// The caller's subscription to an RFI or tender after watching or unwatching
+#[derive(Debug, Clone, Serialize)]
+pub struct WatchStatus {
+    pub watching: bool,
+    /// Everyone watching the resource, the caller included
+    pub watchers: i64,
+}
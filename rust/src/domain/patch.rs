@@ -0,0 +1,82 @@
+//! Tri-state field for partial updates
+//!
+//! Update endpoints mostly treat a missing or `null` field as "leave it
+//! unchanged" (`field = COALESCE($1, field)`), which leaves no way to clear a
+//! nullable column. Fields typed `Patch<T>` tell the three cases apart:
+//!
+//! | JSON body               | `Patch`        | Effect           |
+//! |-------------------------|----------------|------------------|
+//! | field omitted           | `Undefined`    | unchanged        |
+//! | `"field": null`         | `Null`         | cleared to NULL  |
+//! | `"field": <value>`      | `Value(value)` | set to the value |
+//!
+//! Such fields must be declared `#[serde(default)]` so an omitted field
+//! deserializes as `Undefined`. In SQL, bind `is_set()` and `value()` and
+//! write `field = CASE WHEN $n THEN $m ELSE field END`.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A field in an update body: omitted, explicitly `null`, or a value
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Patch<T> {
+    /// Absent from the body; leave the stored value alone
+    #[default]
+    Undefined,
+    /// Sent as `null`; clear the stored value
+    Null,
+    /// Sent with a value; store it
+    Value(T),
+}
+
+impl<T> Patch<T> {
+    /// Whether the body mentioned the field at all
+    pub fn is_set(&self) -> bool {
+        !matches!(self, Patch::Undefined)
+    }
+
+    pub fn is_undefined(&self) -> bool {
+        matches!(self, Patch::Undefined)
+    }
+
+    /// The new value: `None` when cleared or left unchanged
+    pub fn value(&self) -> Option<&T> {
+        match self {
+            Patch::Value(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Patch<U> {
+        match self {
+            Patch::Undefined => Patch::Undefined,
+            Patch::Null => Patch::Null,
+            Patch::Value(v) => Patch::Value(f(v)),
+        }
+    }
+}
+
+impl<T: Copy> Patch<T> {
+    /// Owned copy of the new value, for binding `Copy` types
+    pub fn copied(&self) -> Option<T> {
+        self.value().copied()
+    }
+}
+
+impl<T> From<Option<T>> for Patch<T> {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Patch::Null, Patch::Value)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Patch<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Only called when the field is present; `#[serde(default)]` covers absence
+        Option::<T>::deserialize(deserializer).map(Patch::from)
+    }
+}
+
+impl<T: Serialize> Serialize for Patch<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value().serialize(serializer)
+    }
+}
@@ -57,6 +57,8 @@ pub struct ProfileResponse {
     pub title: Option<String>,
     pub bio: Option<String>,
     pub location: Option<String>,
+    pub avatar_url: Option<String>,
+    pub avatar_thumbnail_url: Option<String>,
     pub updated_at: DateTime<Utc>,
 }
 
@@ -73,6 +75,8 @@ impl From<Profile> for ProfileResponse {
             title: p.title,
             bio: p.bio,
             location: p.location,
+            avatar_url: None,
+            avatar_thumbnail_url: None,
             updated_at: p.updated_at,
         }
     }
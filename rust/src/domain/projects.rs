@@ -1,11 +1,15 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use uuid::Uuid;
 
+use super::money::Money;
+
 /// Project status enum
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum ProjectStatus {
+    #[default]
     Draft,
     Active,
     Bidding,
@@ -15,12 +19,6 @@ pub enum ProjectStatus {
     Cancelled,
 }
 
-impl Default for ProjectStatus {
-    fn default() -> Self {
-        Self::Draft
-    }
-}
-
 /// Project entity
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Project {
@@ -33,7 +31,8 @@ pub struct Project {
     pub state: Option<String>,
     pub zip_code: Option<String>,
     pub status: ProjectStatus,
-    pub estimated_value: Option<i64>, // cents
+    /// Estimated project value in dollars
+    pub estimated_value: Option<Money>,
     pub bid_due_date: Option<DateTime<Utc>>,
     pub start_date: Option<DateTime<Utc>>,
     pub end_date: Option<DateTime<Utc>>,
@@ -55,8 +54,9 @@ pub struct CreateProjectRequest {
     pub state: Option<String>,
     #[serde(default)]
     pub zip_code: Option<String>,
+    /// Estimated project value in dollars
     #[serde(default)]
-    pub estimated_value: Option<i64>,
+    pub estimated_value: Option<Money>,
     #[serde(default)]
     pub bid_due_date: Option<DateTime<Utc>>,
     #[serde(default)]
@@ -82,8 +82,9 @@ pub struct UpdateProjectRequest {
     pub zip_code: Option<String>,
     #[serde(default)]
     pub status: Option<ProjectStatus>,
+    /// Estimated project value in dollars
     #[serde(default)]
-    pub estimated_value: Option<i64>,
+    pub estimated_value: Option<Money>,
     #[serde(default)]
     pub bid_due_date: Option<DateTime<Utc>>,
     #[serde(default)]
@@ -103,7 +104,8 @@ pub struct ProjectResponse {
     pub state: Option<String>,
     pub zip_code: Option<String>,
     pub status: ProjectStatus,
-    pub estimated_value: Option<i64>,
+    /// Estimated project value in dollars
+    pub estimated_value: Option<Money>,
     pub bid_due_date: Option<DateTime<Utc>>,
     pub start_date: Option<DateTime<Utc>>,
     pub end_date: Option<DateTime<Utc>>,
@@ -131,3 +133,90 @@ impl From<Project> for ProjectResponse {
         }
     }
 }
+
+/// Per-project dashboard statistics
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectStats {
+    pub project_id: Uuid,
+    pub documents: i64,
+    pub tenders: StatusCounts,
+    /// Bids received across all of the project's tenders
+    pub bids_received: i64,
+    pub rfis: RfiStats,
+    pub tasks: StatusCounts,
+    /// Team members that are not terminated
+    pub team_members: i64,
+    pub extraction: ExtractionStats,
+}
+
+/// Counts grouped by status
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StatusCounts {
+    pub total: i64,
+    pub by_status: BTreeMap<String, i64>,
+}
+
+impl StatusCounts {
+    pub fn add(&mut self, status: String, count: i64) {
+        self.total += count;
+        *self.by_status.entry(status).or_default() += count;
+    }
+}
+
+/// RFI counts for a project
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RfiStats {
+    pub total: i64,
+    pub open: i64,
+    /// Open RFIs past their due date
+    pub overdue: i64,
+}
+
+/// Extracted item count and how much of it has been verified
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerifiedCount {
+    pub total: i64,
+    pub verified: i64,
+    /// Verified share of `total`, 0-100 (0 when there are no items)
+    pub verified_pct: f64,
+}
+
+impl VerifiedCount {
+    pub fn new(total: i64, verified: i64) -> Self {
+        let verified_pct = if total > 0 {
+            (verified as f64 * 1000.0 / total as f64).round() / 10.0
+        } else {
+            0.0
+        };
+        Self {
+            total,
+            verified,
+            verified_pct,
+        }
+    }
+}
+
+/// Extraction totals for a project
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtractionStats {
+    pub materials: VerifiedCount,
+    pub rooms: VerifiedCount,
+    pub milestones: VerifiedCount,
+    pub trade_scopes: VerifiedCount,
+    /// Sum of material `total_cost` in dollars
+    pub materials_total_cost: Money,
+    /// Sum of trade scope `estimated_value` in dollars
+    pub trade_scopes_estimated_value: Money,
+}
+
+/// Query for `GET /api/projects/:project_id/export`
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProjectExportQuery {
+    /// Bundle the uploaded document files; `false` exports data only
+    #[serde(default = "default_include_documents")]
+    pub include_documents: bool,
+}
+
+fn default_include_documents() -> bool {
+    true
+}
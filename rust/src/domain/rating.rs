@@ -0,0 +1,132 @@
+//! Rating value type
+//!
+//! Ratings are stored as `DECIMAL(2, 1)` and shown on a 0-5 scale with one
+//! decimal place. `Rating` applies that policy wherever a rating is built so
+//! aggregates never leak values like `4.333333333`.
+
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use utoipa::ToSchema;
+
+/// A 0-5 rating rounded half-up to one decimal place
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize, ToSchema)]
+#[serde(try_from = "f64", into = "f64")]
+#[schema(value_type = f64, example = 4.5)]
+pub struct Rating(Decimal);
+
+impl Rating {
+    pub const MIN: f64 = 0.0;
+    pub const MAX: f64 = 5.0;
+
+    /// Build a rating from a user-supplied value, rejecting anything outside 0-5.
+    pub fn new(value: f64) -> Result<Self, String> {
+        if !value.is_finite() || !(Self::MIN..=Self::MAX).contains(&value) {
+            return Err(format!(
+                "Rating must be between {} and {}",
+                Self::MIN,
+                Self::MAX
+            ));
+        }
+        Ok(Self::clamped(value))
+    }
+
+    /// Build a rating from a computed value, clamping it into 0-5.
+    pub fn clamped(value: f64) -> Self {
+        if !value.is_finite() {
+            return Self::default();
+        }
+        // Go through the shortest decimal representation so that e.g. 4.35
+        // rounds to 4.4 rather than 4.3 from its binary approximation.
+        let decimal = Decimal::from_str(&value.clamp(Self::MIN, Self::MAX).to_string()).unwrap_or_default();
+        Self::from(decimal)
+    }
+
+    /// Average of `ratings` rounded half-up, or `None` when there are none.
+    pub fn average<I>(ratings: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Rating>,
+    {
+        let (sum, count) = ratings
+            .into_iter()
+            .fold((Decimal::ZERO, 0u32), |(sum, count), r| (sum + r.0, count + 1));
+        (count > 0).then(|| Self::from(sum / Decimal::from(count)))
+    }
+
+    pub fn value(self) -> f64 {
+        self.0.to_f64().unwrap_or_default()
+    }
+
+    /// Value to bind into a `DECIMAL(2, 1)` column.
+    pub fn to_decimal(self) -> Decimal {
+        self.0
+    }
+}
+
+impl From<Decimal> for Rating {
+    fn from(value: Decimal) -> Self {
+        let clamped = value.clamp(Decimal::ZERO, Decimal::from(5));
+        Self(clamped.round_dp_with_strategy(1, RoundingStrategy::MidpointAwayFromZero))
+    }
+}
+
+impl TryFrom<f64> for Rating {
+    type Error = String;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<Rating> for f64 {
+    fn from(rating: Rating) -> Self {
+        rating.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ratings(values: &[f64]) -> Vec<Rating> {
+        values.iter().map(|&v| Rating::new(v).unwrap()).collect()
+    }
+
+    #[test]
+    fn bounds_are_accepted() {
+        assert_eq!(Rating::new(0.0).unwrap().value(), 0.0);
+        assert_eq!(Rating::new(5.0).unwrap().value(), 5.0);
+    }
+
+    #[test]
+    fn out_of_range_is_rejected() {
+        for value in [-0.1, 5.1, 10.0, f64::NAN, f64::INFINITY] {
+            assert!(Rating::new(value).is_err(), "{} accepted", value);
+        }
+        assert!(serde_json::from_str::<Rating>("6").is_err());
+        assert_eq!(serde_json::from_str::<Rating>("4.5").unwrap().value(), 4.5);
+    }
+
+    #[test]
+    fn computed_values_are_clamped() {
+        assert_eq!(Rating::clamped(-2.0).value(), 0.0);
+        assert_eq!(Rating::clamped(7.5).value(), 5.0);
+        assert_eq!(Rating::clamped(f64::NAN).value(), 0.0);
+    }
+
+    #[test]
+    fn values_round_half_up_to_one_place() {
+        assert_eq!(Rating::new(4.35).unwrap().value(), 4.4);
+        assert_eq!(Rating::new(4.25).unwrap().value(), 4.3);
+        assert_eq!(Rating::new(4.24).unwrap().value(), 4.2);
+    }
+
+    #[test]
+    fn average_is_rounded() {
+        assert_eq!(Rating::average(ratings(&[4.0, 4.0, 5.0])).unwrap().value(), 4.3);
+        assert_eq!(Rating::average(ratings(&[4.0, 5.0])).unwrap().value(), 4.5);
+        assert_eq!(Rating::average(ratings(&[3.0, 4.0, 4.0, 4.5])).unwrap().value(), 3.9);
+        assert_eq!(Rating::average(Vec::new()), None);
+    }
+}
@@ -7,38 +7,50 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 /// RFI status enum
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum RFIStatus {
+    #[default]
     Open,
     Answered,
     Closed,
 }
 
-impl Default for RFIStatus {
-    fn default() -> Self {
-        Self::Open
-    }
-}
-
 /// RFI priority enum
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum RFIPriority {
     Low,
+    #[default]
     Medium,
     High,
     Urgent,
 }
 
-impl Default for RFIPriority {
-    fn default() -> Self {
-        Self::Medium
+impl RFIStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RFIStatus::Open => "open",
+            RFIStatus::Answered => "answered",
+            RFIStatus::Closed => "closed",
+        }
+    }
+}
+
+impl RFIPriority {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RFIPriority::Low => "low",
+            RFIPriority::Medium => "medium",
+            RFIPriority::High => "high",
+            RFIPriority::Urgent => "urgent",
+        }
     }
 }
 
 /// RFI entity
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(clippy::upper_case_acronyms)]
 pub struct RFI {
     pub id: Uuid,
     pub project_id: Uuid,
@@ -55,6 +67,9 @@ pub struct RFI {
     pub due_date: Option<DateTime<Utc>>,
     pub responses_count: i32,
     pub attachments_count: i32,
+    /// The response that resolved the RFI, once accepted
+    pub accepted_response_id: Option<Uuid>,
+    pub accepted_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -67,6 +82,8 @@ pub struct RFIResponseEntity {
     pub content: String,
     pub author: String, // Display name
     pub author_id: Uuid,
+    /// Whether this is the RFI's accepted answer
+    pub is_accepted: bool,
     pub created_at: DateTime<Utc>,
 }
 
@@ -104,12 +121,57 @@ pub struct UpdateRFIRequest {
     pub due_date: Option<DateTime<Utc>>,
 }
 
+/// Filters for the cross-project rfi list (`GET /api/rfis`)
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RFIListQuery {
+    #[serde(default)]
+    pub status: Option<RFIStatus>,
+    #[serde(default)]
+    pub priority: Option<RFIPriority>,
+    #[serde(default)]
+    pub project_id: Option<Uuid>,
+    #[serde(default)]
+    pub assignee_id: Option<Uuid>,
+    /// Due on or after this instant
+    #[serde(default)]
+    pub due_from: Option<DateTime<Utc>>,
+    /// Due on or before this instant
+    #[serde(default)]
+    pub due_to: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub sort_by: Option<String>, // created_at, updated_at, due_date, priority, number
+    #[serde(default)]
+    pub sort_order: Option<String>, // asc, desc
+}
+
+impl RFIListQuery {
+    /// No filters and the default order
+    pub fn is_unfiltered(&self) -> bool {
+        self.status.is_none()
+            && self.priority.is_none()
+            && self.project_id.is_none()
+            && self.assignee_id.is_none()
+            && self.due_from.is_none()
+            && self.due_to.is_none()
+            && self.sort_by.is_none()
+            && self.sort_order.is_none()
+    }
+}
+
 /// Request DTO for creating an RFI response
 #[derive(Debug, Clone, Deserialize)]
 pub struct CreateRFIResponseRequest {
     pub content: String,
 }
 
+/// Request DTO for accepting an RFI response
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AcceptRFIResponseRequest {
+    /// Close the RFI rather than leaving it `answered`
+    #[serde(default)]
+    pub close: bool,
+}
+
 /// Response DTO for RFI
 #[derive(Debug, Clone, Serialize)]
 pub struct RFIResponse {
@@ -128,6 +190,9 @@ pub struct RFIResponse {
     pub due_date: Option<DateTime<Utc>>,
     pub responses_count: i32,
     pub attachments_count: i32,
+    /// The response that resolved the RFI, once accepted
+    pub accepted_response_id: Option<Uuid>,
+    pub accepted_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -150,6 +215,8 @@ impl From<RFI> for RFIResponse {
             due_date: r.due_date,
             responses_count: r.responses_count,
             attachments_count: r.attachments_count,
+            accepted_response_id: r.accepted_response_id,
+            accepted_at: r.accepted_at,
             created_at: r.created_at,
             updated_at: r.updated_at,
         }
@@ -164,6 +231,8 @@ pub struct RFIResponseDTO {
     pub content: String,
     pub author: String,
     pub author_id: Uuid,
+    /// Whether this is the RFI's accepted answer
+    pub is_accepted: bool,
     pub created_at: DateTime<Utc>,
 }
 
@@ -175,6 +244,7 @@ impl From<RFIResponseEntity> for RFIResponseDTO {
             content: r.content,
             author: r.author,
             author_id: r.author_id,
+            is_accepted: r.is_accepted,
             created_at: r.created_at,
         }
     }
@@ -1,30 +1,44 @@
 //! User settings domain types
 //!
 //! User preferences and notification settings.
+//!
+//! Settings are stored as JSON stamped with the `schema_version` they were
+//! written under. Keys missing from older rows take the defaults below when
+//! read; bump `SETTINGS_SCHEMA_VERSION` whenever a key is added so stored
+//! rows are rewritten with its default on next read.
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+/// Version of the settings layout this build reads and writes
+pub const SETTINGS_SCHEMA_VERSION: i32 = 1;
+
 /// Notification settings
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct NotificationSettings {
-    #[serde(default = "default_true")]
     pub email_notifications: bool,
-    #[serde(default = "default_true")]
     pub push_notifications: bool,
-    #[serde(default = "default_true")]
     pub bid_updates: bool,
-    #[serde(default = "default_true")]
     pub rfi_alerts: bool,
-    #[serde(default = "default_true")]
     pub task_reminders: bool,
-    #[serde(default)]
+    pub message_alerts: bool,
     pub weekly_reports: bool,
 }
 
-fn default_true() -> bool {
-    true
+impl Default for NotificationSettings {
+    fn default() -> Self {
+        Self {
+            email_notifications: true,
+            push_notifications: true,
+            bid_updates: true,
+            rfi_alerts: true,
+            task_reminders: true,
+            message_alerts: true,
+            weekly_reports: false,
+        }
+    }
 }
 
 /// User settings entity
@@ -35,16 +49,40 @@ pub struct UserSettings {
     pub updated_at: DateTime<Utc>,
 }
 
+/// Changed notification settings; omitted keys keep their current value and
+/// unknown keys are rejected
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NotificationSettingsUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_notifications: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub push_notifications: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bid_updates: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rfi_alerts: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task_reminders: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_alerts: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weekly_reports: Option<bool>,
+}
+
 /// Request DTO for updating user settings
 #[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct UpdateUserSettingsRequest {
-    pub notification_settings: NotificationSettings,
+    #[serde(default)]
+    pub notification_settings: NotificationSettingsUpdate,
 }
 
 /// Response DTO for user settings
 #[derive(Debug, Clone, Serialize)]
 pub struct UserSettingsResponse {
     pub user_id: Uuid,
+    pub schema_version: i32,
     pub notification_settings: NotificationSettings,
     pub updated_at: DateTime<Utc>,
 }
@@ -53,6 +91,7 @@ impl From<UserSettings> for UserSettingsResponse {
     fn from(s: UserSettings) -> Self {
         Self {
             user_id: s.user_id,
+            schema_version: SETTINGS_SCHEMA_VERSION,
             notification_settings: s.notification_settings,
             updated_at: s.updated_at,
         }
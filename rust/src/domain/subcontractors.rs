@@ -4,13 +4,18 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
+use super::money::Money;
+use super::rating::Rating;
+
 /// Recent project for subcontractor
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct RecentProject {
     pub name: String,
-    pub value: i64,        // In cents
+    /// Project value in dollars
+    pub value: Money,
     pub completed: String, // Date string
 }
 
@@ -20,14 +25,15 @@ pub struct Subcontractor {
     pub id: Uuid,
     pub name: String,
     pub trade: String,
-    pub rating: f64, // 0-5 scale
+    pub rating: Rating, // 0-5 scale
     pub review_count: i32,
     pub location: String,
     pub description: Option<String>,
     pub contact_email: Option<String>,
     pub contact_phone: Option<String>,
     pub projects_completed: i32,
-    pub average_bid_value: Option<i64>,
+    /// Average bid in dollars
+    pub average_bid_value: Option<Money>,
     pub response_time: Option<String>,
     pub verified: bool,
     pub specialties: Vec<String>,
@@ -54,14 +60,15 @@ pub struct SubcontractorResponse {
     pub id: Uuid,
     pub name: String,
     pub trade: String,
-    pub rating: f64,
+    pub rating: Rating,
     pub review_count: i32,
     pub location: String,
     pub description: Option<String>,
     pub contact_email: Option<String>,
     pub contact_phone: Option<String>,
     pub projects_completed: i32,
-    pub average_bid_value: Option<i64>,
+    /// Average bid in dollars
+    pub average_bid_value: Option<Money>,
     pub response_time: Option<String>,
     pub verified: bool,
     pub specialties: Vec<String>,
@@ -7,33 +7,44 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 /// Task status enum
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum TaskStatus {
+    #[default]
     Todo,
     InProgress,
     Completed,
 }
 
-impl Default for TaskStatus {
-    fn default() -> Self {
-        Self::Todo
-    }
-}
-
 /// Task priority enum
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum TaskPriority {
     Low,
+    #[default]
     Medium,
     High,
     Urgent,
 }
 
-impl Default for TaskPriority {
-    fn default() -> Self {
-        Self::Medium
+impl TaskStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaskStatus::Todo => "todo",
+            TaskStatus::InProgress => "in_progress",
+            TaskStatus::Completed => "completed",
+        }
+    }
+}
+
+impl TaskPriority {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaskPriority::Low => "low",
+            TaskPriority::Medium => "medium",
+            TaskPriority::High => "high",
+            TaskPriority::Urgent => "urgent",
+        }
     }
 }
 
@@ -94,6 +105,43 @@ pub struct UpdateTaskRequest {
     pub progress: Option<i32>,
 }
 
+/// Filters for the cross-project task list (`GET /api/tasks`)
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct TaskListQuery {
+    #[serde(default)]
+    pub status: Option<TaskStatus>,
+    #[serde(default)]
+    pub priority: Option<TaskPriority>,
+    #[serde(default)]
+    pub project_id: Option<Uuid>,
+    #[serde(default)]
+    pub assignee_id: Option<Uuid>,
+    /// Due on or after this instant
+    #[serde(default)]
+    pub due_from: Option<DateTime<Utc>>,
+    /// Due on or before this instant
+    #[serde(default)]
+    pub due_to: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub sort_by: Option<String>, // created_at, updated_at, due_date, priority, progress
+    #[serde(default)]
+    pub sort_order: Option<String>, // asc, desc
+}
+
+impl TaskListQuery {
+    /// No filters and the default order
+    pub fn is_unfiltered(&self) -> bool {
+        self.status.is_none()
+            && self.priority.is_none()
+            && self.project_id.is_none()
+            && self.assignee_id.is_none()
+            && self.due_from.is_none()
+            && self.due_to.is_none()
+            && self.sort_by.is_none()
+            && self.sort_order.is_none()
+    }
+}
+
 /// Response DTO for task
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TaskResponse {
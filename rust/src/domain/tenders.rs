@@ -2,8 +2,11 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::ai::TenderScopeDoc;
+use super::money::Money;
+
 /// Trade category for tender packages
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum TradeCategory {
     GeneralConditions,
@@ -25,19 +28,15 @@ pub enum TradeCategory {
     Plumbing,
     Hvac,
     FireProtection,
+    #[default]
     Other,
 }
 
-impl Default for TradeCategory {
-    fn default() -> Self {
-        Self::Other
-    }
-}
-
 /// Tender status
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum TenderStatus {
+    #[default]
     Draft,
     Published,
     Closed,
@@ -45,12 +44,6 @@ pub enum TenderStatus {
     Cancelled,
 }
 
-impl Default for TenderStatus {
-    fn default() -> Self {
-        Self::Draft
-    }
-}
-
 /// Tender package entity
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Tender {
@@ -63,7 +56,8 @@ pub struct Tender {
     pub scope_of_work: Option<String>,
     pub status: TenderStatus,
     pub bid_due_date: Option<DateTime<Utc>>,
-    pub estimated_value: Option<i64>,
+    /// Estimated package value in dollars
+    pub estimated_value: Option<Money>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -80,8 +74,9 @@ pub struct CreateTenderRequest {
     pub scope_of_work: Option<String>,
     #[serde(default)]
     pub bid_due_date: Option<DateTime<Utc>>,
+    /// Estimated package value in dollars
     #[serde(default)]
-    pub estimated_value: Option<i64>,
+    pub estimated_value: Option<Money>,
 }
 
 /// Request DTO for updating a tender
@@ -99,8 +94,13 @@ pub struct UpdateTenderRequest {
     pub status: Option<TenderStatus>,
     #[serde(default)]
     pub bid_due_date: Option<DateTime<Utc>>,
+    /// Estimated package value in dollars
+    #[serde(default)]
+    pub estimated_value: Option<Money>,
+    /// Confirms changing the scope or trade of a tender that is already open
+    /// to bids; bidders are notified of the change
     #[serde(default)]
-    pub estimated_value: Option<i64>,
+    pub confirm_republish: bool,
 }
 
 /// Response DTO for tender
@@ -114,7 +114,8 @@ pub struct TenderResponse {
     pub scope_of_work: Option<String>,
     pub status: TenderStatus,
     pub bid_due_date: Option<DateTime<Utc>>,
-    pub estimated_value: Option<i64>,
+    /// Estimated package value in dollars
+    pub estimated_value: Option<Money>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -136,3 +137,154 @@ impl From<Tender> for TenderResponse {
         }
     }
 }
+
+/// Request DTO for sharing project documents with a tender
+#[derive(Debug, Clone, Deserialize)]
+pub struct ShareTenderDocumentsRequest {
+    pub document_ids: Vec<Uuid>,
+}
+
+/// Project document shared with a tender
+#[derive(Debug, Clone, Serialize)]
+pub struct TenderDocumentResponse {
+    pub document_id: Uuid,
+    pub name: String,
+    pub document_type: Option<String>,
+    pub file_size: Option<i64>,
+    pub mime_type: Option<String>,
+    pub version: i32,
+    pub shared_at: DateTime<Utc>,
+    /// Signed link; `None` for documents without an uploaded file
+    pub download_url: Option<String>,
+    pub download_expires_at: Option<DateTime<Utc>>,
+}
+
+/// A logged download of a shared tender document
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct TenderDocumentDownload {
+    pub id: Uuid,
+    pub document_id: Uuid,
+    pub document_name: String,
+    pub user_id: Uuid,
+    pub user_name: Option<String>,
+    pub company_name: Option<String>,
+    pub ip_address: Option<String>,
+    pub downloaded_at: DateTime<Utc>,
+}
+
+/// Request to ask a question on a tender's clarification thread
+#[derive(Debug, Clone, Deserialize)]
+pub struct AskTenderQuestionRequest {
+    pub question: String,
+}
+
+/// The GC's answer to a tender question; answering again replaces it
+#[derive(Debug, Clone, Deserialize)]
+pub struct AnswerTenderQuestionRequest {
+    pub answer: String,
+}
+
+/// Subcontractor who asked a tender question, shown only to the GC
+#[derive(Debug, Clone, Serialize)]
+pub struct TenderQuestionAsker {
+    pub subcontractor_id: Option<Uuid>,
+    pub name: Option<String>,
+}
+
+/// A question on a tender's clarification thread
+#[derive(Debug, Clone, Serialize)]
+pub struct TenderQuestionResponse {
+    pub id: Uuid,
+    pub tender_id: Uuid,
+    pub question: String,
+    pub answer: Option<String>,
+    pub answered_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    /// The caller asked this question
+    pub asked_by_me: bool,
+    /// Who asked; `None` for everyone but the tender owner
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub asker: Option<TenderQuestionAsker>,
+}
+
+/// Query params carried by a signed download link
+#[derive(Debug, Clone, Deserialize)]
+pub struct SignedDownloadQuery {
+    pub user: Uuid,
+    pub expires: i64,
+    pub signature: String,
+}
+
+/// How a tender scope document version was produced
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ScopeDocSource {
+    /// Generated by the AI service from the project's trade scopes
+    Generated,
+    /// Revision saved by the GC
+    Edited,
+}
+
+impl std::fmt::Display for ScopeDocSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Generated => write!(f, "generated"),
+            Self::Edited => write!(f, "edited"),
+        }
+    }
+}
+
+/// A stored version of a tender's scope document
+#[derive(Debug, Clone, Serialize)]
+pub struct TenderScopeDocVersion {
+    pub id: Uuid,
+    pub tender_id: Uuid,
+    pub version: i32,
+    pub latest_version: i32,
+    pub source: String,
+    /// Structured sections from the last generation; edits only change `markdown`
+    pub document: Option<TenderScopeDoc>,
+    pub markdown: String,
+    /// Trade scopes the document was generated from
+    pub trade_scope_ids: Vec<Uuid>,
+    /// The trade scopes changed since generation; regenerate to pick them up
+    pub stale: bool,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Query params for `GET .../scope-doc`
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct ScopeDocQuery {
+    /// Version to return; the latest when omitted
+    #[serde(default)]
+    pub version: Option<i32>,
+}
+
+/// Request DTO for (re)generating a tender's scope document
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct GenerateScopeDocRequest {
+    #[serde(default)]
+    pub project_context: Option<String>,
+}
+
+/// Request DTO for saving an edited scope document
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateScopeDocRequest {
+    pub markdown: String,
+}
+
+/// Download format for tender and bid exports
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum TenderExportFormat {
+    #[default]
+    Csv,
+}
+
+/// Query params for `GET .../bids/export` and `GET .../tenders/export`
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct TenderExportQuery {
+    #[serde(default)]
+    pub format: TenderExportFormat,
+}
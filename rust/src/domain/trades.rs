@@ -0,0 +1,210 @@
+//! Canonical trade catalog
+//!
+//! Trades appear as free text across materials (`trade_category`), tenders
+//! (`trade_category`), trade scopes (`trade`), and subcontractors (`trade`).
+//! The catalog gives each trade a stable key, a display name, and the CSI
+//! MasterFormat divisions it covers, and [`normalize_trade`] maps loose
+//! strings onto those keys.
+
+use serde::Serialize;
+
+/// A canonical trade
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Trade {
+    /// Stable key stored as `normalized_trade` (matches `TradeCategory`)
+    pub key: &'static str,
+    pub display_name: &'static str,
+    /// Two-digit CSI MasterFormat divisions
+    pub csi_divisions: &'static [&'static str],
+    /// Other names this trade commonly goes by
+    pub aliases: &'static [&'static str],
+}
+
+/// All canonical trades, in CSI division order
+pub const TRADES: &[Trade] = &[
+    Trade {
+        key: "general_conditions",
+        display_name: "General Conditions",
+        csi_divisions: &["01"],
+        aliases: &["general requirements", "general contractor", "gc"],
+    },
+    Trade {
+        key: "sitework_excavation",
+        display_name: "Sitework & Excavation",
+        csi_divisions: &["02", "31", "32", "33"],
+        aliases: &[
+            "sitework", "site work", "excavation", "earthwork", "grading", "demolition",
+            "paving", "landscaping", "utilities", "existing conditions",
+        ],
+    },
+    Trade {
+        key: "concrete",
+        display_name: "Concrete",
+        csi_divisions: &["03"],
+        aliases: &["cast in place concrete", "foundations", "rebar", "flatwork"],
+    },
+    Trade {
+        key: "masonry",
+        display_name: "Masonry",
+        csi_divisions: &["04"],
+        aliases: &["brick", "block", "cmu", "stone"],
+    },
+    Trade {
+        key: "metals",
+        display_name: "Metals",
+        csi_divisions: &["05"],
+        aliases: &["steel", "structural steel", "metal fabrication", "misc metals", "welding"],
+    },
+    Trade {
+        key: "wood_plastics",
+        display_name: "Wood, Plastics & Composites",
+        csi_divisions: &["06"],
+        aliases: &["carpentry", "framing", "rough carpentry", "finish carpentry", "millwork", "casework"],
+    },
+    Trade {
+        key: "thermal_moisture",
+        display_name: "Thermal & Moisture Protection",
+        csi_divisions: &["07"],
+        aliases: &["roofing", "insulation", "waterproofing", "siding", "sealants"],
+    },
+    Trade {
+        key: "doors_windows",
+        display_name: "Doors & Windows",
+        csi_divisions: &["08"],
+        aliases: &["openings", "doors", "windows", "glazing", "glass", "storefront", "hardware"],
+    },
+    Trade {
+        key: "finishes",
+        display_name: "Finishes",
+        csi_divisions: &["09"],
+        aliases: &["drywall", "painting", "flooring", "tile", "carpet", "acoustical ceilings", "plaster"],
+    },
+    Trade {
+        key: "specialties",
+        display_name: "Specialties",
+        csi_divisions: &["10"],
+        aliases: &["signage", "toilet accessories", "toilet partitions", "lockers"],
+    },
+    Trade {
+        key: "equipment",
+        display_name: "Equipment",
+        csi_divisions: &["11"],
+        aliases: &["appliances", "kitchen equipment", "food service equipment"],
+    },
+    Trade {
+        key: "furnishings",
+        display_name: "Furnishings",
+        csi_divisions: &["12"],
+        aliases: &["furniture", "window treatments", "countertops"],
+    },
+    Trade {
+        key: "special_construction",
+        display_name: "Special Construction",
+        csi_divisions: &["13"],
+        aliases: &["pre engineered structures", "pools"],
+    },
+    Trade {
+        key: "conveying_systems",
+        display_name: "Conveying Systems",
+        csi_divisions: &["14"],
+        aliases: &["conveying equipment", "elevators", "elevator", "escalators", "lifts"],
+    },
+    Trade {
+        key: "fire_protection",
+        display_name: "Fire Protection",
+        csi_divisions: &["21"],
+        aliases: &["fire suppression", "fire sprinklers", "sprinklers", "sprinkler"],
+    },
+    Trade {
+        key: "plumbing",
+        display_name: "Plumbing",
+        csi_divisions: &["22"],
+        aliases: &["plumber", "piping"],
+    },
+    Trade {
+        key: "hvac",
+        display_name: "HVAC",
+        csi_divisions: &["23"],
+        aliases: &["heating ventilation and air conditioning", "heating and cooling", "air conditioning", "ductwork", "sheet metal"],
+    },
+    Trade {
+        key: "mechanical",
+        display_name: "Mechanical",
+        csi_divisions: &["21", "22", "23", "25"],
+        aliases: &["mep", "integrated automation", "controls"],
+    },
+    Trade {
+        key: "electrical",
+        display_name: "Electrical",
+        csi_divisions: &["26", "27", "28"],
+        aliases: &["electrician", "low voltage", "communications", "electronic safety and security", "fire alarm", "lighting"],
+    },
+    Trade {
+        key: "other",
+        display_name: "Other",
+        csi_divisions: &[],
+        aliases: &[],
+    },
+];
+
+/// Look up a trade by its canonical key.
+pub fn find_trade(key: &str) -> Option<&'static Trade> {
+    TRADES.iter().find(|t| t.key == key)
+}
+
+/// Map a free-text trade (key, display name, alias, or CSI division such as
+/// "09" or "Division 9") to its canonical trade.
+pub fn normalize_trade(raw: &str) -> Option<&'static Trade> {
+    let words = normalize_words(raw);
+    if words.is_empty() {
+        return None;
+    }
+
+    if let Some(trade) = trade_for_csi_division(&words) {
+        return Some(trade);
+    }
+
+    let key = words.replace(' ', "_");
+    TRADES.iter().find(|t| {
+        t.key == key
+            || normalize_words(t.display_name) == words
+            || t.aliases.contains(&words.as_str())
+    })
+}
+
+/// Canonical key for a free-text trade, falling back to its CSI division.
+pub fn normalized_trade_key(trade: Option<&str>, csi_division: Option<&str>) -> Option<&'static str> {
+    trade
+        .and_then(normalize_trade)
+        .or_else(|| csi_division.and_then(normalize_trade))
+        .map(|t| t.key)
+}
+
+/// The most specific trade for a CSI division ("09", "division 9", "09 29 00").
+fn trade_for_csi_division(words: &str) -> Option<&'static Trade> {
+    let digits = words
+        .strip_prefix("division ")
+        .or_else(|| words.strip_prefix("div "))
+        .unwrap_or(words);
+    let first = digits.split(' ').next()?;
+    if first.is_empty() || first.len() > 2 || !first.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let division = format!("{:0>2}", first);
+
+    // Single-division trades win over umbrella trades (e.g. plumbing over mechanical)
+    TRADES
+        .iter()
+        .filter(|t| t.csi_divisions.contains(&division.as_str()))
+        .min_by_key(|t| t.csi_divisions.len())
+}
+
+/// Lowercase, `&` spelled out, and any punctuation collapsed to single spaces.
+fn normalize_words(raw: &str) -> String {
+    raw.to_lowercase()
+        .replace('&', " and ")
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
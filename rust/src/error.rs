@@ -1,16 +1,21 @@
 //! Unified API error handling
 //!
-//! Provides consistent error responses across all endpoints.
+//! Provides consistent error responses across all endpoints. Every error body
+//! carries an `ErrorCode`, a stable string clients can branch on; messages
+//! are for people and may change.
 
 #![allow(dead_code)]
 
 use axum::{
-    http::StatusCode,
+    http::{header, HeaderValue, StatusCode},
     response::{IntoResponse, Response},
     Json,
 };
 use serde::Serialize;
 use thiserror::Error;
+use utoipa::ToSchema;
+
+use crate::middleware::request_id::current_request_id;
 
 #[derive(Debug, Error)]
 pub enum ApiError {
@@ -29,6 +34,28 @@ pub enum ApiError {
     #[error("Conflict: {0}")]
     Conflict(String),
 
+    #[error("Unprocessable entity: {0}")]
+    UnprocessableEntity(String),
+
+    #[error("Payload too large: {0}")]
+    PayloadTooLarge(String),
+
+    #[error("Unsupported media type: {0}")]
+    UnsupportedMediaType(String),
+
+    #[error("Gateway timeout: {0}")]
+    GatewayTimeout(String),
+
+    #[error("Service unavailable: {0}")]
+    ServiceUnavailable(String),
+
+    #[error("Too many requests: {message}")]
+    TooManyRequests {
+        message: String,
+        /// Seconds until the client may retry, sent as `Retry-After`
+        retry_after: Option<u64>,
+    },
+
     #[error("Internal server error")]
     Internal(#[from] anyhow::Error),
 
@@ -36,9 +63,64 @@ pub enum ApiError {
     Database(#[from] sqlx::Error),
 }
 
-#[derive(Serialize)]
+/// Machine-stable error codes, one per failure class
+///
+/// | Code | Status | Meaning |
+/// |------|--------|---------|
+/// | `UNAUTHORIZED` | 401 | Missing, invalid or expired credentials |
+/// | `FORBIDDEN` | 403 | Authenticated but not allowed |
+/// | `NOT_FOUND` | 404 | The resource does not exist or is not visible |
+/// | `VALIDATION_FAILED` | 400, 422 | The request is malformed or fails validation |
+/// | `CONFLICT` | 409 | The request conflicts with the resource's current state |
+/// | `PAYLOAD_TOO_LARGE` | 413 | The request body is over the limit |
+/// | `UNSUPPORTED_MEDIA_TYPE` | 415 | The upload is not an accepted type |
+/// | `RATE_LIMITED` | 429 | A rate limit or quota is used up; see `Retry-After` |
+/// | `UPSTREAM_UNAVAILABLE` | 503 | A service we depend on is down |
+/// | `UPSTREAM_TIMEOUT` | 504 | A service we depend on took too long |
+/// | `INTERNAL_ERROR` | 500 | Anything else; details are only logged |
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ErrorCode {
+    Unauthorized,
+    Forbidden,
+    NotFound,
+    ValidationFailed,
+    Conflict,
+    PayloadTooLarge,
+    UnsupportedMediaType,
+    RateLimited,
+    UpstreamUnavailable,
+    UpstreamTimeout,
+    InternalError,
+}
+
+impl ErrorCode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Unauthorized => "UNAUTHORIZED",
+            Self::Forbidden => "FORBIDDEN",
+            Self::NotFound => "NOT_FOUND",
+            Self::ValidationFailed => "VALIDATION_FAILED",
+            Self::Conflict => "CONFLICT",
+            Self::PayloadTooLarge => "PAYLOAD_TOO_LARGE",
+            Self::UnsupportedMediaType => "UNSUPPORTED_MEDIA_TYPE",
+            Self::RateLimited => "RATE_LIMITED",
+            Self::UpstreamUnavailable => "UPSTREAM_UNAVAILABLE",
+            Self::UpstreamTimeout => "UPSTREAM_TIMEOUT",
+            Self::InternalError => "INTERNAL_ERROR",
+        }
+    }
+}
+
+impl std::fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Serialize, ToSchema)]
 pub struct ErrorResponse {
-    pub code: String,
+    pub code: ErrorCode,
     pub message: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub request_id: Option<String>,
@@ -75,6 +157,39 @@ impl ApiError {
         Self::Conflict(message.into())
     }
 
+    /// Create an unprocessable entity error (well-formed request that fails validation)
+    pub fn unprocessable(message: impl Into<String>) -> Self {
+        Self::UnprocessableEntity(message.into())
+    }
+
+    /// Create a payload too large error (request body over the limit)
+    pub fn payload_too_large(message: impl Into<String>) -> Self {
+        Self::PayloadTooLarge(message.into())
+    }
+
+    /// Create an unsupported media type error (upload content is not an accepted type)
+    pub fn unsupported_media_type(message: impl Into<String>) -> Self {
+        Self::UnsupportedMediaType(message.into())
+    }
+
+    /// Create a gateway timeout error (upstream service took too long)
+    pub fn gateway_timeout(message: impl Into<String>) -> Self {
+        Self::GatewayTimeout(message.into())
+    }
+
+    /// Create a service unavailable error (an upstream service is down)
+    pub fn service_unavailable(message: impl Into<String>) -> Self {
+        Self::ServiceUnavailable(message.into())
+    }
+
+    /// Create a too many requests error (a rate limit or quota is exhausted)
+    pub fn too_many_requests(message: impl Into<String>, retry_after: Option<u64>) -> Self {
+        Self::TooManyRequests {
+            message: message.into(),
+            retry_after,
+        }
+    }
+
     fn status_code(&self) -> StatusCode {
         match self {
             Self::Unauthorized(_) => StatusCode::UNAUTHORIZED,
@@ -82,19 +197,31 @@ impl ApiError {
             Self::NotFound(_) => StatusCode::NOT_FOUND,
             Self::BadRequest(_) => StatusCode::BAD_REQUEST,
             Self::Conflict(_) => StatusCode::CONFLICT,
+            Self::UnprocessableEntity(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::PayloadTooLarge(_) => StatusCode::PAYLOAD_TOO_LARGE,
+            Self::UnsupportedMediaType(_) => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            Self::GatewayTimeout(_) => StatusCode::GATEWAY_TIMEOUT,
+            Self::ServiceUnavailable(_) => StatusCode::SERVICE_UNAVAILABLE,
+            Self::TooManyRequests { .. } => StatusCode::TOO_MANY_REQUESTS,
             Self::Internal(_) | Self::Database(_) => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
 
-    fn error_code(&self) -> &'static str {
+    /// Stable code for the error's failure class
+    pub fn code(&self) -> ErrorCode {
         match self {
-            Self::Unauthorized(_) => "UNAUTHORIZED",
-            Self::Forbidden(_) => "FORBIDDEN",
-            Self::NotFound(_) => "NOT_FOUND",
-            Self::BadRequest(_) => "BAD_REQUEST",
-            Self::Conflict(_) => "CONFLICT",
-            Self::Internal(_) => "INTERNAL_ERROR",
-            Self::Database(_) => "DATABASE_ERROR",
+            Self::Unauthorized(_) => ErrorCode::Unauthorized,
+            Self::Forbidden(_) => ErrorCode::Forbidden,
+            Self::NotFound(_) => ErrorCode::NotFound,
+            Self::BadRequest(_) | Self::UnprocessableEntity(_) => ErrorCode::ValidationFailed,
+            Self::Conflict(_) => ErrorCode::Conflict,
+            Self::PayloadTooLarge(_) => ErrorCode::PayloadTooLarge,
+            Self::UnsupportedMediaType(_) => ErrorCode::UnsupportedMediaType,
+            Self::GatewayTimeout(_) => ErrorCode::UpstreamTimeout,
+            Self::ServiceUnavailable(_) => ErrorCode::UpstreamUnavailable,
+            Self::TooManyRequests { .. } => ErrorCode::RateLimited,
+            // Database failures share the generic code; what failed is only logged
+            Self::Internal(_) | Self::Database(_) => ErrorCode::InternalError,
         }
     }
 
@@ -105,6 +232,12 @@ impl ApiError {
             Self::NotFound(msg) => msg.clone(),
             Self::BadRequest(msg) => msg.clone(),
             Self::Conflict(msg) => msg.clone(),
+            Self::UnprocessableEntity(msg) => msg.clone(),
+            Self::PayloadTooLarge(msg) => msg.clone(),
+            Self::UnsupportedMediaType(msg) => msg.clone(),
+            Self::GatewayTimeout(msg) => msg.clone(),
+            Self::ServiceUnavailable(msg) => msg.clone(),
+            Self::TooManyRequests { message, .. } => message.clone(),
             // Don't leak internal error details
             Self::Internal(_) | Self::Database(_) => "An internal error occurred".to_string(),
         }
@@ -128,13 +261,156 @@ impl IntoResponse for ApiError {
 
         let status = self.status_code();
         let body = ErrorResponse {
-            code: self.error_code().to_string(),
+            code: self.code(),
             message: self.public_message(),
-            request_id: None, // Will be populated by middleware if available
+            request_id: current_request_id(),
         };
 
-        (status, Json(body)).into_response()
+        let mut response = (status, Json(body)).into_response();
+        if let Self::TooManyRequests { retry_after: Some(seconds), .. } = &self {
+            response.headers_mut().insert(header::RETRY_AFTER, HeaderValue::from(*seconds));
+        }
+        response
     }
 }
 
 pub type ApiResult<T> = Result<T, ApiError>;
+
+/// Map a database error for `map_err`.
+///
+/// Unique violations become 409 and foreign key violations 422, with a
+/// message naming the resource and fields involved (never the constraint or
+/// the values). Anything else is a generic 500: `context` (what was being
+/// done) and the call site go to the log with the error, and the client only
+/// sees `INTERNAL_ERROR` and the request ID.
+#[track_caller]
+pub fn map_db_err(context: &'static str) -> impl Fn(sqlx::Error) -> ApiError + Copy {
+    let location = std::panic::Location::caller();
+    move |e| {
+        if let Some(err) = constraint_violation(&e) {
+            tracing::debug!(error = %e, context, %location, "Constraint violation");
+            return err;
+        }
+        ApiError::Internal(anyhow::Error::new(e).context(format!("{} ({})", context, location)))
+    }
+}
+
+/// Client error for a unique (23505) or foreign key (23503) violation
+fn constraint_violation(e: &sqlx::Error) -> Option<ApiError> {
+    let db = e.as_database_error()?;
+    let resource = db.table().map(singular_noun).unwrap_or_else(|| "record".to_string());
+    // Postgres reports the key as `Key (col, ...)=(values) ...`; only the
+    // column names are kept
+    let detail = db
+        .try_downcast_ref::<sqlx::postgres::PgDatabaseError>()
+        .and_then(|pg| pg.detail())
+        .unwrap_or_default();
+    let columns: Vec<String> = detail
+        .strip_prefix("Key (")
+        .and_then(|rest| rest.split_once(")="))
+        .map(|(cols, _)| cols.split(',').map(|c| c.trim().to_string()).collect())
+        .unwrap_or_default();
+
+    match db.code().as_deref() {
+        Some("23505") => {
+            // Scoping columns (user_id, tender_id, ...) say nothing useful
+            let fields: Vec<String> = columns
+                .iter()
+                .filter(|c| !c.ends_with("_id"))
+                .map(|c| c.replace('_', " "))
+                .collect();
+            let message = if fields.is_empty() {
+                format!("This {} already exists", resource)
+            } else {
+                format!("A {} with the same {} already exists", resource, fields.join(" and "))
+            };
+            Some(ApiError::conflict(message))
+        }
+        // Deleting a row that others still reference
+        Some("23503") if detail.contains("is still referenced") => {
+            Some(ApiError::conflict("This record is still in use and cannot be removed"))
+        }
+        Some("23503") => {
+            let referenced = columns
+                .first()
+                .map(|c| c.trim_end_matches("_id").replace('_', " "))
+                .unwrap_or_else(|| "record".to_string());
+            Some(ApiError::unprocessable(format!("The referenced {} does not exist", referenced)))
+        }
+        _ => None,
+    }
+}
+
+/// "saved_searches" -> "saved search"
+fn singular_noun(table: &str) -> String {
+    let singular = if let Some(stem) = table.strip_suffix("ies") {
+        format!("{}y", stem)
+    } else if let Some(stem) = table.strip_suffix("ches").or_else(|| table.strip_suffix("sses")) {
+        format!("{}{}", stem, &table[stem.len()..table.len() - 2])
+    } else {
+        table.strip_suffix('s').unwrap_or(table).to_string()
+    };
+    singular.replace('_', " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    use crate::test_support;
+
+    async fn response_parts(err: ApiError) -> (StatusCode, String) {
+        let response = err.into_response();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[test]
+    fn table_names_become_singular_nouns() {
+        assert_eq!(singular_noun("saved_searches"), "saved search");
+        assert_eq!(singular_noun("external_subcontractors"), "external subcontractor");
+        assert_eq!(singular_noun("bids"), "bid");
+        assert_eq!(singular_noun("addresses"), "address");
+        assert_eq!(singular_noun("activity"), "activity");
+    }
+
+    #[tokio::test]
+    async fn duplicate_insert_is_a_conflict_naming_the_field() {
+        let Some(db) = test_support::db().await else { return };
+        let existing = test_support::user(&db, "gc").await;
+
+        let err = sqlx::query("INSERT INTO profiles (id, email) VALUES ($1, $2)")
+            .bind(Uuid::new_v4())
+            .bind(format!("{}@test.example", existing))
+            .execute(&db)
+            .await
+            .map_err(map_db_err("duplicate_insert"))
+            .unwrap_err();
+
+        let (status, body) = response_parts(err).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert!(body.contains("\"CONFLICT\""), "{}", body);
+        assert!(body.contains("A profile with the same email already exists"), "{}", body);
+        assert!(!body.contains("profiles_email_key"), "{}", body);
+        assert!(!body.contains(&existing.to_string()), "{}", body);
+    }
+
+    #[tokio::test]
+    async fn missing_reference_is_unprocessable() {
+        let Some(db) = test_support::db().await else { return };
+
+        let err = sqlx::query("INSERT INTO saved_searches (user_id, name, filters) VALUES ($1, 'Electricians', '{}')")
+            .bind(Uuid::new_v4())
+            .execute(&db)
+            .await
+            .map_err(map_db_err("missing_reference"))
+            .unwrap_err();
+
+        let (status, body) = response_parts(err).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(body.contains("The referenced user does not exist"), "{}", body);
+        assert!(!body.contains("saved_searches_user_id_fkey"), "{}", body);
+    }
+}
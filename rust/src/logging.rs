@@ -1,34 +1,41 @@
-use crate::config::Environment;
+use crate::config::{LogFormat, Settings};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
 
-pub fn init_logging(env: &Environment) {
-    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| {
-        // Default log levels based on environment
-        match env {
-            Environment::Dev => "blueprintx_backend=debug,tower_http=debug,info".into(),
-            Environment::Staging => "blueprintx_backend=debug,tower_http=info,info".into(),
-            Environment::Prod => "blueprintx_backend=info,tower_http=info,warn".into(),
-        }
+/// Install the global subscriber using the configured format and filter.
+///
+/// `RUST_LOG_FORMAT` picks the output format independently of `ENV`, so a
+/// dev deployment can emit JSON or production can be read as pretty text.
+pub fn init_logging(settings: &Settings) {
+    let filter = EnvFilter::try_new(&settings.log_level).unwrap_or_else(|e| {
+        eprintln!("Invalid RUST_LOG '{}' ({}); falling back to info", settings.log_level, e);
+        EnvFilter::new("info")
     });
 
     let fmt_layer = tracing_subscriber::fmt::layer()
         .with_target(true)
         .with_thread_ids(false)
-        .with_file(env.is_dev())
-        .with_line_number(env.is_dev());
+        .with_file(settings.env.is_dev())
+        .with_line_number(settings.env.is_dev());
 
-    // Use JSON format in production, pretty format in dev
-    if matches!(env, Environment::Prod) {
-        tracing_subscriber::registry()
+    match settings.log_format {
+        LogFormat::Json => tracing_subscriber::registry()
             .with(filter)
-            .with(fmt_layer.json())
-            .init();
-    } else {
-        tracing_subscriber::registry()
+            .with(fmt_layer.json().with_current_span(true).with_span_list(false))
+            .init(),
+        LogFormat::Pretty => tracing_subscriber::registry()
             .with(filter)
             .with(fmt_layer.pretty())
-            .init();
+            .init(),
+        LogFormat::Compact => tracing_subscriber::registry()
+            .with(filter)
+            .with(fmt_layer.compact())
+            .init(),
     }
 
-    tracing::info!("Logging initialized for {:?} environment", env);
+    tracing::info!(
+        format = ?settings.log_format,
+        filter = %settings.log_level,
+        "Logging initialized for {:?} environment",
+        settings.env
+    );
 }
@@ -1,6 +1,7 @@
 mod api;
 mod app;
 mod auth;
+mod build_info;
 mod config;
 mod db;
 mod domain;
@@ -9,10 +10,13 @@ mod logging;
 mod middleware;
 mod routes;
 mod services;
+#[cfg(test)]
+mod test_support;
 
 use anyhow::Result;
+use std::net::SocketAddr;
 
-use services::{AiClient, RedisCache};
+use services::{AiClient, ObjectStorage, RedisCache};
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -23,10 +27,14 @@ async fn main() -> Result<()> {
     let settings = config::Settings::from_env()?;
 
     // Initialize logging
-    logging::init_logging(&settings.env);
+    logging::init_logging(&settings);
 
+    let build = build_info::BuildInfo::new(&settings.env);
     tracing::info!(
-        env = ?settings.env,
+        env = build.env,
+        version = build.version,
+        git_sha = build.git_sha,
+        built_at = ?build.built_at,
         server_addr = %settings.server_addr,
         "Starting BlueprintX backend"
     );
@@ -35,14 +43,17 @@ async fn main() -> Result<()> {
     let pool = db::create_pool(&settings).await?;
 
     // Create Redis cache
-    let cache = RedisCache::new(&settings.redis_url, settings.redis_cache_ttl_seconds).await?;
+    let cache = RedisCache::new(&settings.redis_url, settings.cache_ttls).await?;
     tracing::info!("Redis cache initialized");
+    services::notification_stream::install(cache.clone());
 
     // Create AI service client
     let ai_client = AiClient::new(
         &settings.ai_service_url,
         &settings.ai_service_token,
-        settings.ai_service_timeout_seconds,
+        settings.ai_timeouts,
+        settings.ai_models.clone(),
+        settings.ai_circuit_breaker,
     )?;
 
     // Optionally check AI service health (non-blocking)
@@ -89,8 +100,57 @@ async fn main() -> Result<()> {
         tracing::warn!(error = %e, "Failed to warm JWKS cache - will fetch on first request");
     }
 
+    // Requeue or fail jobs whose worker stopped reporting progress
+    services::job_reaper::spawn(
+        pool.clone(),
+        std::time::Duration::from_secs(settings.job_stale_threshold_seconds),
+        std::time::Duration::from_secs(settings.job_reaper_interval_seconds),
+    );
+
+    // Close open tenders once their bid due date passes
+    services::tender_closer::spawn(
+        pool.clone(),
+        cache.clone(),
+        std::time::Duration::from_secs(settings.tender_closer_interval_seconds),
+    );
+
+    // Expire hire requests the subcontractor never answered
+    services::hire_request_expirer::spawn(
+        pool.clone(),
+        std::time::Duration::from_secs(settings.hire_request_expirer_interval_seconds),
+    );
+
+    // Remind subs of expiring insurance and send lapsed verified subs back to review
+    services::insurance_monitor::spawn(
+        pool.clone(),
+        cache.clone(),
+        std::time::Duration::from_secs(settings.insurance_monitor_interval_seconds),
+    );
+
+    // Retry deleting files of removed documents
+    services::file_deletions::spawn(
+        pool.clone(),
+        std::time::Duration::from_secs(settings.file_deletion_interval_seconds),
+    );
+
+    // Media storage for avatars and logos
+    let storage = ObjectStorage::new(&settings.storage_root, &settings.storage_public_url);
+
     // Create application state
-    let state = app::AppState::new(pool, settings.clone(), jwks_cache, cache, ai_client, http_client);
+    let state = app::AppState::new(
+        pool,
+        settings.clone(),
+        jwks_cache,
+        cache,
+        ai_client,
+        http_client,
+        storage,
+    );
+
+    // Keep feature flags current
+    state
+        .feature_flags
+        .spawn(std::time::Duration::from_secs(settings.feature_flag_refresh_seconds));
 
     // Build application
     let app = app::create_app(state);
@@ -99,7 +159,8 @@ async fn main() -> Result<()> {
     let listener = tokio::net::TcpListener::bind(&settings.server_addr).await?;
     tracing::info!("Listening on {}", settings.server_addr);
 
-    axum::serve(listener, app).await?;
+    // Expose the peer address to handlers (used for audit log client IPs)
+    axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>()).await?;
 
     Ok(())
 }
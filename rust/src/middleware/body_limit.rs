@@ -0,0 +1,97 @@
+//! Request body size limits
+//!
+//! Two layers work together: a hard cap (`RequestBodyLimitLayer`) that rejects
+//! any body over the largest per-route limit as soon as its `Content-Length`
+//! is seen, and `DefaultBodyLimit`, which the body extractors enforce while
+//! reading, before anything is deserialized. Routes that accept uploads raise
+//! `DefaultBodyLimit` for themselves; everything else gets the default.
+
+use axum::{
+    extract::DefaultBodyLimit,
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+};
+use tower_http::limit::RequestBodyLimitLayer;
+
+use crate::error::ApiError;
+
+/// Default extractor limit plus the hard cap every route shares
+pub fn body_limit_layers(default_limit: usize, hard_cap: usize) -> (DefaultBodyLimit, RequestBodyLimitLayer) {
+    (
+        DefaultBodyLimit::max(default_limit),
+        RequestBodyLimitLayer::new(hard_cap.max(default_limit)),
+    )
+}
+
+/// Rewrite the plain-text 413s produced by the limit layers and extractors as
+/// a JSON `ErrorResponse`.
+pub async fn json_payload_too_large(response: Response) -> Response {
+    let is_json = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("application/json"));
+
+    if response.status() == StatusCode::PAYLOAD_TOO_LARGE && !is_json {
+        return ApiError::payload_too_large("Request body is too large").into_response();
+    }
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use axum::{body::Body, http::Request, routing::post, Json, Router};
+    use tower::ServiceExt;
+
+    const DEFAULT_LIMIT: usize = 1024;
+    const HARD_CAP: usize = 4096;
+
+    fn app() -> Router {
+        let (default_limit, hard_cap) = body_limit_layers(DEFAULT_LIMIT, HARD_CAP);
+        Router::new()
+            .route("/", post(|Json(body): Json<serde_json::Value>| async move { Json(body) }))
+            .layer(default_limit)
+            .layer(hard_cap)
+            .layer(axum::middleware::map_response(json_payload_too_large))
+    }
+
+    /// A JSON string literal `len` bytes long
+    fn json_body(len: usize) -> String {
+        format!("\"{}\"", "x".repeat(len - 2))
+    }
+
+    async fn post_json(body: String) -> (StatusCode, serde_json::Value) {
+        let request = Request::post("/")
+            .header(header::CONTENT_TYPE, "application/json")
+            .header(header::CONTENT_LENGTH, body.len())
+            .body(Body::from(body))
+            .unwrap();
+        let response = app().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap_or_default())
+    }
+
+    #[tokio::test]
+    async fn body_within_the_limit_is_accepted() {
+        let (status, _) = post_json(json_body(DEFAULT_LIMIT)).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn body_over_the_default_limit_is_a_json_413() {
+        let (status, body) = post_json(json_body(DEFAULT_LIMIT + 1)).await;
+        assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+        assert_eq!(body["code"], "PAYLOAD_TOO_LARGE");
+    }
+
+    #[tokio::test]
+    async fn body_over_the_hard_cap_is_a_json_413() {
+        let (status, body) = post_json(json_body(HARD_CAP + 1)).await;
+        assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+        assert_eq!(body["code"], "PAYLOAD_TOO_LARGE");
+    }
+}
@@ -0,0 +1,88 @@
+//! Client IP extraction for audit logging
+
+use axum::{
+    async_trait,
+    extract::{ConnectInfo, FromRequestParts},
+    http::request::Parts,
+};
+use std::convert::Infallible;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+
+use crate::app::AppState;
+
+/// Header set by reverse proxies with the originating client chain
+pub const X_FORWARDED_FOR: &str = "x-forwarded-for";
+
+/// Header set by some proxies (e.g. nginx) with the originating client
+pub const X_REAL_IP: &str = "x-real-ip";
+
+/// Extractor for the best-effort client IP address.
+///
+/// Forwarding headers are only honoured when the socket peer matches one of the
+/// configured `trusted_proxies`. In that case the `X-Forwarded-For` chain is walked
+/// right-to-left, skipping trusted hops, then `X-Real-IP` is tried. Otherwise the
+/// peer address from `ConnectInfo<SocketAddr>` is used. Never rejects the request.
+#[derive(Debug, Clone)]
+pub struct ExtractClientIp(pub Option<String>);
+
+#[async_trait]
+impl FromRequestParts<Arc<AppState>> for ExtractClientIp {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &Arc<AppState>,
+    ) -> Result<Self, Self::Rejection> {
+        let peer = parts
+            .extensions
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| addr.ip());
+
+        Ok(ExtractClientIp(
+            resolve_client_ip(parts, peer, &state.settings.trusted_proxies).map(|ip| ip.to_string()),
+        ))
+    }
+}
+
+fn is_trusted(ip: &IpAddr, trusted_proxies: &[ipnet::IpNet]) -> bool {
+    trusted_proxies.iter().any(|net| net.contains(ip))
+}
+
+fn resolve_client_ip(
+    parts: &Parts,
+    peer: Option<IpAddr>,
+    trusted_proxies: &[ipnet::IpNet],
+) -> Option<IpAddr> {
+    let peer = peer?;
+
+    if !is_trusted(&peer, trusted_proxies) {
+        return Some(peer);
+    }
+
+    // Rightmost untrusted hop is the first address we did not add ourselves
+    let forwarded = parts
+        .headers
+        .get_all(X_FORWARDED_FOR)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .filter_map(|v| v.trim().parse::<IpAddr>().ok())
+        .collect::<Vec<_>>();
+
+    if let Some(ip) = forwarded
+        .iter()
+        .rev()
+        .find(|ip| !is_trusted(ip, trusted_proxies))
+    {
+        return Some(*ip);
+    }
+
+    let real_ip = parts
+        .headers
+        .get(X_REAL_IP)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse::<IpAddr>().ok());
+
+    real_ip.or(forwarded.first().copied()).or(Some(peer))
+}
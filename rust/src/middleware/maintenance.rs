@@ -0,0 +1,79 @@
+//! Maintenance mode middleware
+//!
+//! Refuses mutating requests with 503 and `Retry-After` while maintenance
+//! mode is on (see `services::maintenance`). Reads (`GET`, `HEAD`,
+//! `OPTIONS`), paths under `MAINTENANCE_ALLOWED_PATHS`, admins and the AI
+//! service's internal callbacks get through; workers must keep claiming and
+//! heartbeating their jobs, or the reaper would requeue jobs still running.
+
+use axum::{
+    extract::{FromRequestParts, Request, State},
+    http::{header, request::Parts, HeaderValue, Method},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use std::sync::Arc;
+
+use crate::app::AppState;
+use crate::auth::{RequireAuth, RequireInternalToken};
+use crate::error::ApiError;
+use crate::services::maintenance;
+
+pub async fn maintenance_guard(
+    State(state): State<Arc<AppState>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let read_only = matches!(*request.method(), Method::GET | Method::HEAD | Method::OPTIONS);
+    let path = request.uri().path();
+    let allowed = state
+        .settings
+        .maintenance
+        .allowed_paths
+        .iter()
+        .any(|prefix| path.starts_with(prefix.as_str()));
+    if read_only || allowed {
+        return next.run(request).await;
+    }
+
+    let (mut parts, body) = request.into_parts();
+    if RequireInternalToken::from_request_parts(&mut parts, &state).await.is_ok() {
+        return next.run(Request::from_parts(parts, body)).await;
+    }
+
+    let Some(mode) = maintenance::current(&state.cache).await else {
+        return next.run(Request::from_parts(parts, body)).await;
+    };
+
+    if is_admin(&mut parts, &state).await {
+        return next.run(Request::from_parts(parts, body)).await;
+    }
+
+    let message = mode.message.unwrap_or_else(|| {
+        "The service is undergoing maintenance; please try again later".to_string()
+    });
+    let mut response = ApiError::service_unavailable(message).into_response();
+    response
+        .headers_mut()
+        .insert(header::RETRY_AFTER, HeaderValue::from(mode.retry_after_seconds));
+    response
+}
+
+/// Whether the request is authenticated as an admin
+async fn is_admin(parts: &mut Parts, state: &Arc<AppState>) -> bool {
+    let Ok(auth) = RequireAuth::from_request_parts(parts, state).await else {
+        return false;
+    };
+
+    match sqlx::query_scalar::<_, Option<bool>>("SELECT is_admin FROM profiles WHERE id = $1")
+        .bind(auth.user_id)
+        .fetch_optional(&state.db)
+        .await
+    {
+        Ok(is_admin) => is_admin.flatten() == Some(true),
+        Err(e) => {
+            tracing::warn!(error = %e, "Failed to check admin status during maintenance");
+            false
+        }
+    }
+}
@@ -1,3 +1,9 @@
+pub mod body_limit;
+pub mod client_ip;
+pub mod maintenance;
 pub mod request_id;
 
+pub use body_limit::{body_limit_layers, json_payload_too_large};
+pub use client_ip::ExtractClientIp;
+pub use maintenance::maintenance_guard;
 pub use request_id::request_id_layer;
@@ -2,7 +2,7 @@
 
 #![allow(dead_code)]
 
-use axum::http::HeaderName;
+use axum::{extract::Request, http::HeaderName, middleware::Next, response::Response};
 use tower_http::request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer};
 
 /// Header name for request ID
@@ -20,6 +20,25 @@ pub fn request_id_layer() -> (SetRequestIdLayer<MakeRequestUuid>, PropagateReque
     )
 }
 
+tokio::task_local! {
+    static REQUEST_ID: String;
+}
+
+/// Middleware making the request ID available to `current_request_id`
+/// while the request is handled (so error bodies can quote it)
+pub async fn scope_request_id(request: Request, next: Next) -> Response {
+    let id = request.headers().request_id().unwrap_or_default().to_string();
+    REQUEST_ID.scope(id, next.run(request)).await
+}
+
+/// ID of the request being handled on this task, if any
+pub fn current_request_id() -> Option<String> {
+    REQUEST_ID
+        .try_with(|id| id.clone())
+        .ok()
+        .filter(|id| !id.is_empty())
+}
+
 /// Extension trait for extracting request ID from headers
 pub trait RequestIdExt {
     fn request_id(&self) -> Option<&str>;
@@ -0,0 +1,108 @@
+//! Activity routes
+//!
+//! Project activity timeline built from the activity log.
+
+use axum::{
+    extract::{Path, Query, State},
+    response::IntoResponse,
+    Json,
+};
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::api::pagination::{Cursor, CursorPage, CursorParams};
+use crate::app::AppState;
+use crate::auth::RequireAuth;
+use crate::domain::activity::ActivityLogResponse;
+use crate::error::{map_db_err, ApiError};
+
+// ============================================================================
+// Database Row Types
+// ============================================================================
+
+#[derive(Debug, sqlx::FromRow)]
+struct ActivityLogRow {
+    id: Uuid,
+    actor_id: Uuid,
+    actor_name: Option<String>,
+    project_id: Option<Uuid>,
+    action: String,
+    target_type: String,
+    target_id: Option<Uuid>,
+    details: serde_json::Value,
+    created_at: DateTime<Utc>,
+}
+
+// ============================================================================
+// Activity Endpoints
+// ============================================================================
+
+/// GET /api/projects/:project_id/activity
+///
+/// Timeline of meaningful events on a project, newest first with cursor pagination.
+/// Only the project owner (GC) can view it.
+pub async fn list_project_activity(
+    State(state): State<Arc<AppState>>,
+    Path(project_id): Path<Uuid>,
+    Query(cursor): Query<CursorParams>,
+    auth: RequireAuth,
+) -> Result<impl IntoResponse, ApiError> {
+    let owner: Option<Uuid> = sqlx::query_scalar("SELECT owner_id FROM projects WHERE id = $1")
+        .bind(project_id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(map_db_err("list_project_activity"))?
+        .flatten();
+
+    match owner {
+        None => return Err(ApiError::not_found("Project not found")),
+        Some(owner_id) if owner_id != auth.user_id => {
+            return Err(ApiError::forbidden("You don't have access to this project"));
+        }
+        _ => {}
+    }
+
+    let limit = cursor.limit();
+    let before = cursor.before()?;
+
+    let rows = sqlx::query_as::<_, ActivityLogRow>(
+        r#"
+        SELECT a.id, a.actor_id, COALESCE(p.company_name, p.first_name || ' ' || p.last_name) as actor_name,
+               a.project_id, a.action, a.target_type, a.target_id,
+               COALESCE(a.details, '{}'::jsonb) as details, a.created_at
+        FROM activity_log a
+        LEFT JOIN profiles p ON a.actor_id = p.id
+        WHERE a.project_id = $1
+        AND ($2::timestamptz IS NULL OR (a.created_at, a.id) < ($2, $3))
+        ORDER BY a.created_at DESC, a.id DESC
+        LIMIT $4
+        "#,
+    )
+    .bind(project_id)
+    .bind(before.map(|c| c.created_at))
+    .bind(before.map(|c| c.id))
+    .bind(limit as i64 + 1)
+    .fetch_all(&state.db)
+    .await
+    .map_err(map_db_err("list_project_activity"))?;
+
+    let data: Vec<ActivityLogResponse> = rows
+        .into_iter()
+        .map(|r| ActivityLogResponse {
+            id: r.id,
+            actor_id: r.actor_id,
+            actor_name: r.actor_name,
+            project_id: r.project_id,
+            action: r.action,
+            target_type: r.target_type,
+            target_id: r.target_id,
+            details: r.details,
+            created_at: r.created_at,
+        })
+        .collect();
+
+    Ok(Json(CursorPage::from_rows(data, limit, |a| {
+        Cursor::new(a.created_at, a.id)
+    })))
+}
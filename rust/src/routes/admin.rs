@@ -3,29 +3,41 @@
 //! Protected admin endpoints for:
 //! - Dashboard statistics
 //! - Verification management (approve/reject subcontractors)
-//! - Audit log viewing
+//! - User management (list users, grant/revoke admin)
+//! - Audit log viewing and export
+//! - Contract template management
 //!
 //! All routes require admin privileges (is_admin flag on profile).
 
 use axum::{
     async_trait,
+    body::{Body, Bytes},
     extract::{FromRequestParts, Path, Query, State},
-    http::{request::Parts, StatusCode},
+    http::{header, request::Parts, StatusCode},
     response::{IntoResponse, Response},
     Json,
 };
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
+use futures::{SinkExt, StreamExt};
 use serde::Deserialize;
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::api::pagination::PaginationParams;
-use crate::api::response::{DataResponse, Paginated, PaginationMeta};
+use crate::api::pagination::{resolve_pagination, PaginationParams};
+use crate::api::sort::{SortFields, SortOrder, SortSpec};
+use crate::api::response::{Created, DataResponse, MessageResponse, NoContent, Paginated};
 use crate::app::AppState;
 use crate::auth::RequireAuth;
 use crate::domain::admin::*;
-use crate::error::{ApiError, ErrorResponse};
-use crate::services::notifications;
+use crate::domain::hiring::{
+    ContractSection, CreateContractTemplateInput, TemplateVariable, UpdateContractTemplateInput,
+};
+use crate::error::{map_db_err, ApiError, ErrorCode, ErrorResponse};
+use crate::middleware::ExtractClientIp;
+use crate::routes::{hiring, verification_documents};
+use crate::domain::ai::{AdminAiUsage, AiUsageQuery};
+use crate::services::ai_usage::{self, UsageGrouping};
+use crate::services::{contract_templates, maintenance, notifications, system_settings};
 
 // ============================================================================
 // RequireAdmin Middleware
@@ -56,20 +68,20 @@ pub enum AdminAuthError {
 
 impl IntoResponse for AdminAuthError {
     fn into_response(self) -> Response {
-        let (status, message) = match &self {
-            AdminAuthError::NotAuthenticated => (StatusCode::UNAUTHORIZED, "Authentication required"),
-            AdminAuthError::NotAdmin => (StatusCode::FORBIDDEN, "Admin privileges required"),
+        let (status, code, message) = match &self {
+            AdminAuthError::NotAuthenticated => {
+                (StatusCode::UNAUTHORIZED, ErrorCode::Unauthorized, "Authentication required")
+            }
+            AdminAuthError::NotAdmin => {
+                (StatusCode::FORBIDDEN, ErrorCode::Forbidden, "Admin privileges required")
+            }
             AdminAuthError::DatabaseError(_) => {
-                (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error")
+                (StatusCode::INTERNAL_SERVER_ERROR, ErrorCode::InternalError, "Internal server error")
             }
         };
 
         let body = ErrorResponse {
-            code: if status == StatusCode::FORBIDDEN {
-                "FORBIDDEN".to_string()
-            } else {
-                "UNAUTHORIZED".to_string()
-            },
+            code,
             message: message.to_string(),
             request_id: None,
         };
@@ -119,16 +131,20 @@ impl FromRequestParts<Arc<AppState>> for RequireAdmin {
 // Helper Functions
 // ============================================================================
 
-/// Log an admin action to the audit log
-async fn log_admin_action(
-    db: &sqlx::PgPool,
+/// Log an admin action to the audit log.
+/// Accepts a pool or a transaction so bulk actions can log atomically.
+async fn log_admin_action<'e, E>(
+    db: E,
     admin_id: Uuid,
     action: AdminAction,
     target_type: AuditTargetType,
     target_id: Option<Uuid>,
     details: serde_json::Value,
     ip_address: Option<String>,
-) -> Result<(), sqlx::Error> {
+) -> Result<(), sqlx::Error>
+where
+    E: sqlx::PgExecutor<'e>,
+{
     let id = Uuid::new_v4();
     sqlx::query(
         r#"
@@ -175,12 +191,25 @@ struct PendingVerificationRow {
     employee_count: Option<String>,
     certifications: serde_json::Value,
     insurance: serde_json::Value,
+    insurance_expiry: Option<NaiveDate>,
     license_info: serde_json::Value,
     created_at: DateTime<Utc>,
     profile_email: Option<String>,
     profile_name: Option<String>,
 }
 
+#[derive(Debug, sqlx::FromRow)]
+struct AdminUserRow {
+    id: Uuid,
+    email: String,
+    user_type: String,
+    company_name: Option<String>,
+    first_name: Option<String>,
+    last_name: Option<String>,
+    is_admin: bool,
+    created_at: DateTime<Utc>,
+}
+
 #[derive(Debug, sqlx::FromRow)]
 struct AuditLogRow {
     id: Uuid,
@@ -194,6 +223,22 @@ struct AuditLogRow {
     created_at: DateTime<Utc>,
 }
 
+impl From<AuditLogRow> for AdminAuditLogResponse {
+    fn from(r: AuditLogRow) -> Self {
+        Self {
+            id: r.id,
+            admin_id: r.admin_id,
+            admin_name: r.admin_name,
+            action: r.action,
+            target_type: r.target_type,
+            target_id: r.target_id,
+            details: r.details,
+            ip_address: r.ip_address,
+            created_at: r.created_at,
+        }
+    }
+}
+
 // ============================================================================
 // Query Types
 // ============================================================================
@@ -214,6 +259,14 @@ pub struct AuditLogQueryParams {
     pub filter: AuditLogQuery,
 }
 
+#[derive(Debug, Deserialize, Default)]
+pub struct AuditLogExportParams {
+    #[serde(default)]
+    pub format: AuditExportFormat,
+    #[serde(flatten)]
+    pub filter: AuditLogQuery,
+}
+
 // ============================================================================
 // Admin Dashboard
 // ============================================================================
@@ -224,6 +277,7 @@ pub struct AuditLogQueryParams {
 pub async fn get_admin_stats(
     State(state): State<Arc<AppState>>,
     admin: RequireAdmin,
+    ExtractClientIp(client_ip): ExtractClientIp,
 ) -> Result<impl IntoResponse, ApiError> {
     // Log the view action
     let _ = log_admin_action(
@@ -233,89 +287,58 @@ pub async fn get_admin_stats(
         AuditTargetType::SystemSetting,
         None,
         serde_json::json!({"viewed": "dashboard_stats"}),
-        None,
+        client_ip,
     )
     .await;
 
-    // Fetch all stats
-    let pending_verifications: i64 = sqlx::query_scalar(
-        "SELECT COUNT(*) FROM subcontractors WHERE verification_status = 'pending'",
+    // One aggregate per table, run concurrently: five pooled connections at
+    // most, well inside the default DATABASE_MAX_CONNECTIONS
+    let subcontractors = sqlx::query_as::<_, (i64, i64, i64, i64)>(
+        r#"
+        SELECT COUNT(*) FILTER (WHERE verification_status = 'pending'),
+               COUNT(*),
+               COUNT(*) FILTER (WHERE verification_status = 'verified'),
+               COUNT(*) FILTER (WHERE verified_at > NOW() - INTERVAL '7 days')
+        FROM subcontractors
+        "#,
     )
-    .fetch_one(&state.db)
-    .await
-    .unwrap_or(0);
-
-    let total_subcontractors: i64 =
-        sqlx::query_scalar("SELECT COUNT(*) FROM subcontractors")
-            .fetch_one(&state.db)
-            .await
-            .unwrap_or(0);
+    .fetch_one(&state.db);
 
-    let verified_subcontractors: i64 = sqlx::query_scalar(
-        "SELECT COUNT(*) FROM subcontractors WHERE verification_status = 'verified'",
+    let tenders = sqlx::query_as::<_, (i64, i64)>(
+        "SELECT COUNT(*), COUNT(*) FILTER (WHERE status = 'open') FROM tenders",
     )
-    .fetch_one(&state.db)
-    .await
-    .unwrap_or(0);
-
-    let total_tenders: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM tenders")
-        .fetch_one(&state.db)
-        .await
-        .unwrap_or(0);
-
-    let open_tenders: i64 =
-        sqlx::query_scalar("SELECT COUNT(*) FROM tenders WHERE status = 'open'")
-            .fetch_one(&state.db)
-            .await
-            .unwrap_or(0);
-
-    let total_bids: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM bids")
-        .fetch_one(&state.db)
-        .await
-        .unwrap_or(0);
+    .fetch_one(&state.db);
 
-    let total_contracts: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM contracts")
-        .fetch_one(&state.db)
-        .await
-        .unwrap_or(0);
+    let bids = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM bids").fetch_one(&state.db);
 
-    let active_contracts: i64 = sqlx::query_scalar(
-        "SELECT COUNT(*) FROM contracts WHERE status IN ('draft', 'pending_sub', 'pending_gc', 'gc_signed')",
+    let contracts = sqlx::query_as::<_, (i64, i64)>(
+        r#"
+        SELECT COUNT(*),
+               COUNT(*) FILTER (WHERE status IN ('draft', 'pending_sub', 'pending_gc', 'gc_signed'))
+        FROM contracts
+        "#,
     )
-    .fetch_one(&state.db)
-    .await
-    .unwrap_or(0);
-
-    let total_users: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM profiles")
-        .fetch_one(&state.db)
-        .await
-        .unwrap_or(0);
+    .fetch_one(&state.db);
 
-    let gc_users: i64 =
-        sqlx::query_scalar("SELECT COUNT(*) FROM profiles WHERE user_type = 'general_contractor'")
-            .fetch_one(&state.db)
-            .await
-            .unwrap_or(0);
-
-    let sub_users: i64 =
-        sqlx::query_scalar("SELECT COUNT(*) FROM profiles WHERE user_type = 'subcontractor'")
-            .fetch_one(&state.db)
-            .await
-            .unwrap_or(0);
-
-    let recent_signups_7d: i64 = sqlx::query_scalar(
-        "SELECT COUNT(*) FROM profiles WHERE created_at > NOW() - INTERVAL '7 days'",
+    let profiles = sqlx::query_as::<_, (i64, i64, i64, i64)>(
+        r#"
+        SELECT COUNT(*),
+               COUNT(*) FILTER (WHERE user_type = 'gc'),
+               COUNT(*) FILTER (WHERE user_type = 'sub'),
+               COUNT(*) FILTER (WHERE created_at > NOW() - INTERVAL '7 days')
+        FROM profiles
+        "#,
     )
-    .fetch_one(&state.db)
-    .await
-    .unwrap_or(0);
+    .fetch_one(&state.db);
 
-    let recent_verifications_7d: i64 = sqlx::query_scalar(
-        "SELECT COUNT(*) FROM subcontractors WHERE verified_at > NOW() - INTERVAL '7 days'",
-    )
-    .fetch_one(&state.db)
-    .await
-    .unwrap_or(0);
+    let (
+        (pending_verifications, total_subcontractors, verified_subcontractors, recent_verifications_7d),
+        (total_tenders, open_tenders),
+        total_bids,
+        (total_contracts, active_contracts),
+        (total_users, gc_users, sub_users, recent_signups_7d),
+    ) = tokio::try_join!(subcontractors, tenders, bids, contracts, profiles)
+        .map_err(map_db_err("get_admin_stats"))?;
 
     let stats = AdminDashboardStats {
         pending_verifications,
@@ -340,17 +363,18 @@ pub async fn get_admin_stats(
 // Verification Management
 // ============================================================================
 
+/// `sort_by` keys for pending verifications (default: oldest request first)
+const PENDING_VERIFICATION_SORT_FIELDS: SortFields = &[("created_at", "s.created_at"), ("name", "s.name")];
+
 /// GET /api/admin/verifications
 ///
 /// List pending verification requests.
 pub async fn list_pending_verifications(
     State(state): State<Arc<AppState>>,
     Query(query): Query<VerificationQueryParams>,
-    _admin: RequireAdmin,
+    admin: RequireAdmin,
 ) -> Result<impl IntoResponse, ApiError> {
-    let page = query.pagination.page.unwrap_or(1).max(1);
-    let per_page = query.pagination.per_page.unwrap_or(20).min(100);
-    let offset = ((page - 1) * per_page) as i64;
+    let page = resolve_pagination(&query.pagination, state.settings.pagination.admin)?;
 
     let total: i64 = sqlx::query_scalar(
         r#"
@@ -362,17 +386,14 @@ pub async fn list_pending_verifications(
     .bind(&query.filter.trade)
     .fetch_one(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("list_pending_verifications"))?;
 
-    // Determine sort
-    let order_by = match query.filter.sort_by.as_deref() {
-        Some("name") => "s.name",
-        _ => "s.created_at",
-    };
-    let order_dir = match query.filter.sort_order.as_deref() {
-        Some("desc") => "DESC",
-        _ => "ASC",
-    };
+    let sort = SortSpec::parse(
+        PENDING_VERIFICATION_SORT_FIELDS,
+        SortOrder::Asc,
+        query.filter.sort_by.as_deref(),
+        query.filter.sort_order.as_deref(),
+    )?;
 
     let query_str = format!(
         r#"
@@ -380,7 +401,7 @@ pub async fn list_pending_verifications(
             s.id, s.profile_id, s.name, s.trade, s.location, s.contact_email,
             s.headline, s.company_description, s.year_established, s.employee_count,
             COALESCE(s.certifications, '[]'::jsonb) as certifications,
-            COALESCE(s.insurance, '{{}}'::jsonb) as insurance,
+            COALESCE(s.insurance, '{{}}'::jsonb) as insurance, s.insurance_expiry,
             COALESCE(s.license_info, '{{}}'::jsonb) as license_info,
             s.created_at,
             p.email as profile_email,
@@ -389,23 +410,28 @@ pub async fn list_pending_verifications(
         LEFT JOIN profiles p ON s.profile_id = p.id
         WHERE s.verification_status = 'pending'
         AND ($1::text IS NULL OR s.trade ILIKE '%' || $1 || '%')
-        ORDER BY {} {}
+        ORDER BY {}, s.id
         LIMIT $2 OFFSET $3
         "#,
-        order_by, order_dir
+        sort
     );
 
     let rows = sqlx::query_as::<_, PendingVerificationRow>(&query_str)
         .bind(&query.filter.trade)
-        .bind(per_page as i64)
-        .bind(offset)
+        .bind(page.limit())
+        .bind(page.offset())
         .fetch_all(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+        .map_err(map_db_err("list_pending_verifications"))?;
+
+    let sub_ids: Vec<Uuid> = rows.iter().map(|r| r.id).collect();
+    let mut documents =
+        verification_documents::documents_by_subcontractor(&state, &sub_ids, admin.user_id()).await?;
 
     let data: Vec<PendingVerification> = rows
         .into_iter()
         .map(|r| PendingVerification {
+            documents: documents.remove(&r.id).unwrap_or_default(),
             id: r.id,
             profile_id: r.profile_id,
             name: r.name,
@@ -418,6 +444,7 @@ pub async fn list_pending_verifications(
             employee_count: r.employee_count,
             certifications: serde_json::from_value(r.certifications).unwrap_or_default(),
             insurance: serde_json::from_value(r.insurance).ok(),
+            insurance_expiry: r.insurance_expiry,
             license_info: serde_json::from_value(r.license_info).ok(),
             created_at: r.created_at,
             profile_email: r.profile_email,
@@ -425,19 +452,7 @@ pub async fn list_pending_verifications(
         })
         .collect();
 
-    let total_pages = ((total as f64) / (per_page as f64)).ceil() as u32;
-
-    Ok(Json(Paginated {
-        data,
-        pagination: PaginationMeta {
-            page,
-            per_page,
-            total_items: total as u64,
-            total_pages,
-            has_next: page < total_pages,
-            has_prev: page > 1,
-        },
-    }))
+    Ok(Json(Paginated::new(data, &page, total as u64)))
 }
 
 /// GET /api/admin/verifications/:id
@@ -446,7 +461,7 @@ pub async fn list_pending_verifications(
 pub async fn get_verification(
     State(state): State<Arc<AppState>>,
     Path(sub_id): Path<Uuid>,
-    _admin: RequireAdmin,
+    admin: RequireAdmin,
 ) -> Result<impl IntoResponse, ApiError> {
     let row = sqlx::query_as::<_, PendingVerificationRow>(
         r#"
@@ -454,7 +469,7 @@ pub async fn get_verification(
             s.id, s.profile_id, s.name, s.trade, s.location, s.contact_email,
             s.headline, s.company_description, s.year_established, s.employee_count,
             COALESCE(s.certifications, '[]'::jsonb) as certifications,
-            COALESCE(s.insurance, '{}'::jsonb) as insurance,
+            COALESCE(s.insurance, '{}'::jsonb) as insurance, s.insurance_expiry,
             COALESCE(s.license_info, '{}'::jsonb) as license_info,
             s.created_at,
             p.email as profile_email,
@@ -467,10 +482,16 @@ pub async fn get_verification(
     .bind(sub_id)
     .fetch_optional(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
+    .map_err(map_db_err("get_verification"))?
     .ok_or_else(|| ApiError::not_found("Subcontractor not found"))?;
 
+    let documents = verification_documents::documents_by_subcontractor(&state, &[sub_id], admin.user_id())
+        .await?
+        .remove(&sub_id)
+        .unwrap_or_default();
+
     let verification = PendingVerification {
+        documents,
         id: row.id,
         profile_id: row.profile_id,
         name: row.name,
@@ -483,6 +504,7 @@ pub async fn get_verification(
         employee_count: row.employee_count,
         certifications: serde_json::from_value(row.certifications).unwrap_or_default(),
         insurance: serde_json::from_value(row.insurance).ok(),
+        insurance_expiry: row.insurance_expiry,
         license_info: serde_json::from_value(row.license_info).ok(),
         created_at: row.created_at,
         profile_email: row.profile_email,
@@ -499,6 +521,7 @@ pub async fn approve_verification(
     State(state): State<Arc<AppState>>,
     Path(sub_id): Path<Uuid>,
     admin: RequireAdmin,
+    ExtractClientIp(client_ip): ExtractClientIp,
     Json(input): Json<ApproveVerificationRequest>,
 ) -> Result<impl IntoResponse, ApiError> {
     // Get subcontractor and profile info
@@ -512,7 +535,7 @@ pub async fn approve_verification(
     .bind(sub_id)
     .fetch_optional(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("approve_verification"))?;
 
     let (current_status, profile_id, sub_name) = sub_info
         .ok_or_else(|| ApiError::not_found("Subcontractor not found"))?;
@@ -539,7 +562,9 @@ pub async fn approve_verification(
     .bind(sub_id)
     .execute(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Failed to approve verification: {}", e)))?;
+    .map_err(map_db_err("approve_verification"))?;
+
+    state.cache.invalidate_subcontractor(sub_id).await;
 
     // Log the action
     let _ = log_admin_action(
@@ -552,7 +577,7 @@ pub async fn approve_verification(
             "subcontractor_name": sub_name,
             "notes": input.notes,
         }),
-        None,
+        client_ip,
     )
     .await;
 
@@ -563,10 +588,7 @@ pub async fn approve_verification(
         }
     }
 
-    Ok(Json(serde_json::json!({ 
-        "success": true, 
-        "message": "Subcontractor verified successfully" 
-    })))
+    Ok(MessageResponse::new("Subcontractor verified successfully"))
 }
 
 /// POST /api/admin/verifications/:id/reject
@@ -576,6 +598,7 @@ pub async fn reject_verification(
     State(state): State<Arc<AppState>>,
     Path(sub_id): Path<Uuid>,
     admin: RequireAdmin,
+    ExtractClientIp(client_ip): ExtractClientIp,
     Json(input): Json<RejectVerificationRequest>,
 ) -> Result<impl IntoResponse, ApiError> {
     if input.reason.is_empty() {
@@ -593,7 +616,7 @@ pub async fn reject_verification(
     .bind(sub_id)
     .fetch_optional(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("reject_verification"))?;
 
     let (current_status, profile_id, sub_name) = sub_info
         .ok_or_else(|| ApiError::not_found("Subcontractor not found"))?;
@@ -619,7 +642,9 @@ pub async fn reject_verification(
     .bind(sub_id)
     .execute(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Failed to reject verification: {}", e)))?;
+    .map_err(map_db_err("reject_verification"))?;
+
+    state.cache.invalidate_subcontractor(sub_id).await;
 
     // Log the action
     let _ = log_admin_action(
@@ -633,7 +658,7 @@ pub async fn reject_verification(
             "reason": input.reason,
             "notes": input.notes,
         }),
-        None,
+        client_ip,
     )
     .await;
 
@@ -646,9 +671,445 @@ pub async fn reject_verification(
         }
     }
 
-    Ok(Json(serde_json::json!({ 
-        "success": true, 
-        "message": "Verification rejected" 
+    Ok(MessageResponse::new("Verification rejected"))
+}
+
+/// Most ids, approved and rejected together, in one bulk verification request
+const MAX_BULK_VERIFICATIONS: usize = 100;
+
+/// POST /api/admin/verifications/bulk
+///
+/// Approve and reject many verification requests in a single transaction,
+/// up to [`MAX_BULK_VERIFICATIONS`] at once. Already-verified or missing
+/// subcontractors are reported per id rather than failing the whole batch;
+/// only a database error rolls everything back.
+pub async fn bulk_verification(
+    State(state): State<Arc<AppState>>,
+    admin: RequireAdmin,
+    ExtractClientIp(client_ip): ExtractClientIp,
+    Json(input): Json<BulkVerificationRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    if input.approve.is_empty() && input.reject.is_empty() {
+        return Err(ApiError::bad_request("Nothing to process: approve and reject are both empty"));
+    }
+    if input.approve.len() + input.reject.len() > MAX_BULK_VERIFICATIONS {
+        return Err(ApiError::bad_request(format!(
+            "At most {} verifications can be processed at once",
+            MAX_BULK_VERIFICATIONS
+        )));
+    }
+
+    let mut results: Vec<BulkVerificationResult> = Vec::new();
+    // (profile_id, rejection reason) for notifications sent after commit
+    let mut to_notify: Vec<(Uuid, Option<String>)> = Vec::new();
+
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(map_db_err("bulk_verification"))?;
+
+    let items = input
+        .approve
+        .iter()
+        .map(|id| (*id, None))
+        .chain(input.reject.iter().map(|r| (r.id, Some(r.reason.as_str()))));
+
+    for (sub_id, reject_reason) in items {
+        if results.iter().any(|r| r.id == sub_id) {
+            results.push(BulkVerificationResult {
+                id: sub_id,
+                outcome: BulkItemOutcome::Skipped,
+                message: Some("Duplicate id in request".to_string()),
+            });
+            continue;
+        }
+
+        if reject_reason.is_some_and(|r| r.trim().is_empty()) {
+            results.push(BulkVerificationResult {
+                id: sub_id,
+                outcome: BulkItemOutcome::Invalid,
+                message: Some("Rejection reason is required".to_string()),
+            });
+            continue;
+        }
+
+        let sub_info: Option<(String, Option<Uuid>, String)> = sqlx::query_as(
+            r#"
+            SELECT COALESCE(s.verification_status, 'pending'), s.profile_id, s.name
+            FROM subcontractors s
+            WHERE s.id = $1
+            FOR UPDATE
+            "#,
+        )
+        .bind(sub_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(map_db_err("bulk_verification"))?;
+
+        let Some((current_status, profile_id, sub_name)) = sub_info else {
+            results.push(BulkVerificationResult {
+                id: sub_id,
+                outcome: BulkItemOutcome::NotFound,
+                message: Some("Subcontractor not found".to_string()),
+            });
+            continue;
+        };
+
+        if current_status == "verified" {
+            results.push(BulkVerificationResult {
+                id: sub_id,
+                outcome: BulkItemOutcome::Skipped,
+                message: Some("Subcontractor is already verified".to_string()),
+            });
+            continue;
+        }
+
+        match reject_reason {
+            None => {
+                sqlx::query(
+                    r#"
+                    UPDATE subcontractors SET
+                        verification_status = 'verified',
+                        verified = true,
+                        verified_at = NOW(),
+                        verified_by = $1,
+                        verification_notes = $2,
+                        updated_at = NOW()
+                    WHERE id = $3
+                    "#,
+                )
+                .bind(admin.user_id())
+                .bind(&input.notes)
+                .bind(sub_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(map_db_err("bulk_verification"))?;
+
+                log_admin_action(
+                    &mut *tx,
+                    admin.user_id(),
+                    AdminAction::VerifySubcontractor,
+                    AuditTargetType::Subcontractor,
+                    Some(sub_id),
+                    serde_json::json!({
+                        "subcontractor_name": sub_name,
+                        "notes": input.notes,
+                        "bulk": true,
+                    }),
+                    client_ip.clone(),
+                )
+                .await
+                .map_err(map_db_err("bulk_verification"))?;
+
+                results.push(BulkVerificationResult {
+                    id: sub_id,
+                    outcome: BulkItemOutcome::Approved,
+                    message: None,
+                });
+            }
+            Some(reason) => {
+                sqlx::query(
+                    r#"
+                    UPDATE subcontractors SET
+                        verification_status = 'rejected',
+                        verified = false,
+                        verification_notes = $1,
+                        updated_at = NOW()
+                    WHERE id = $2
+                    "#,
+                )
+                .bind(format!(
+                    "Rejected: {}. Notes: {}",
+                    reason,
+                    input.notes.clone().unwrap_or_default()
+                ))
+                .bind(sub_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(map_db_err("bulk_verification"))?;
+
+                log_admin_action(
+                    &mut *tx,
+                    admin.user_id(),
+                    AdminAction::RejectSubcontractor,
+                    AuditTargetType::Subcontractor,
+                    Some(sub_id),
+                    serde_json::json!({
+                        "subcontractor_name": sub_name,
+                        "reason": reason,
+                        "notes": input.notes,
+                        "bulk": true,
+                    }),
+                    client_ip.clone(),
+                )
+                .await
+                .map_err(map_db_err("bulk_verification"))?;
+
+                results.push(BulkVerificationResult {
+                    id: sub_id,
+                    outcome: BulkItemOutcome::Rejected,
+                    message: None,
+                });
+            }
+        }
+
+        if let Some(profile_id) = profile_id {
+            to_notify.push((profile_id, reject_reason.map(str::to_string)));
+        }
+    }
+
+    tx.commit()
+        .await
+        .map_err(map_db_err("bulk_verification"))?;
+
+    for result in &results {
+        if matches!(result.outcome, BulkItemOutcome::Approved | BulkItemOutcome::Rejected) {
+            state.cache.invalidate_subcontractor(result.id).await;
+        }
+    }
+
+    // Notifications are best-effort and only sent once the batch is committed
+    for (profile_id, reason) in to_notify {
+        let result = match reason {
+            None => notifications::notify_profile_verified(&state.db, profile_id).await,
+            Some(reason) => {
+                notifications::notify_profile_rejected(&state.db, profile_id, &reason).await
+            }
+        };
+        if let Err(e) = result {
+            tracing::warn!(error = %e, "Failed to send bulk verification notification");
+        }
+    }
+
+    Ok(Json(DataResponse::new(results)))
+}
+
+// ============================================================================
+// User Management
+// ============================================================================
+
+/// GET /api/admin/users
+///
+/// List users with search and pagination, including user type and admin flag.
+pub async fn list_users(
+    State(state): State<Arc<AppState>>,
+    Query(pagination): Query<PaginationParams>,
+    Query(filter): Query<AdminUserQuery>,
+    _admin: RequireAdmin,
+) -> Result<impl IntoResponse, ApiError> {
+    let page = resolve_pagination(&pagination, state.settings.pagination.admin)?;
+
+    let total: i64 = sqlx::query_scalar(
+        r#"
+        SELECT COUNT(*) FROM profiles p
+        WHERE ($1::text IS NULL OR p.email ILIKE '%' || $1 || '%'
+               OR p.company_name ILIKE '%' || $1 || '%'
+               OR (p.first_name || ' ' || p.last_name) ILIKE '%' || $1 || '%')
+        AND ($2::text IS NULL OR p.user_type = $2)
+        AND ($3::bool IS NULL OR COALESCE(p.is_admin, false) = $3)
+        "#,
+    )
+    .bind(&filter.search)
+    .bind(&filter.user_type)
+    .bind(filter.is_admin)
+    .fetch_one(&state.db)
+    .await
+    .map_err(map_db_err("list_users"))?;
+
+    let rows = sqlx::query_as::<_, AdminUserRow>(
+        r#"
+        SELECT p.id, p.email, p.user_type, p.company_name, p.first_name, p.last_name,
+               COALESCE(p.is_admin, false) as is_admin, p.created_at
+        FROM profiles p
+        WHERE ($1::text IS NULL OR p.email ILIKE '%' || $1 || '%'
+               OR p.company_name ILIKE '%' || $1 || '%'
+               OR (p.first_name || ' ' || p.last_name) ILIKE '%' || $1 || '%')
+        AND ($2::text IS NULL OR p.user_type = $2)
+        AND ($3::bool IS NULL OR COALESCE(p.is_admin, false) = $3)
+        ORDER BY p.created_at DESC, p.id
+        LIMIT $4 OFFSET $5
+        "#,
+    )
+    .bind(&filter.search)
+    .bind(&filter.user_type)
+    .bind(filter.is_admin)
+    .bind(page.limit())
+    .bind(page.offset())
+    .fetch_all(&state.db)
+    .await
+    .map_err(map_db_err("list_users"))?;
+
+    let data: Vec<AdminUserResponse> = rows
+        .into_iter()
+        .map(|r| AdminUserResponse {
+            id: r.id,
+            email: r.email,
+            user_type: r.user_type,
+            company_name: r.company_name,
+            first_name: r.first_name,
+            last_name: r.last_name,
+            is_admin: r.is_admin,
+            created_at: r.created_at,
+        })
+        .collect();
+
+    Ok(Json(Paginated::new(data, &page, total as u64)))
+}
+
+/// POST /api/admin/users/:user_id/admin
+///
+/// Grant admin privileges to a user.
+pub async fn grant_admin(
+    State(state): State<Arc<AppState>>,
+    Path(user_id): Path<Uuid>,
+    admin: RequireAdmin,
+    ExtractClientIp(client_ip): ExtractClientIp,
+) -> Result<impl IntoResponse, ApiError> {
+    let current: Option<bool> = sqlx::query_scalar(
+        "SELECT COALESCE(is_admin, false) FROM profiles WHERE id = $1",
+    )
+    .bind(user_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("grant_admin"))?;
+
+    match current {
+        None => return Err(ApiError::not_found("User not found")),
+        Some(true) => return Err(ApiError::conflict("User is already an admin")),
+        Some(false) => {}
+    }
+
+    sqlx::query("UPDATE profiles SET is_admin = true, updated_at = NOW() WHERE id = $1")
+        .bind(user_id)
+        .execute(&state.db)
+        .await
+        .map_err(map_db_err("grant_admin"))?;
+
+    let _ = log_admin_action(
+        &state.db,
+        admin.user_id(),
+        AdminAction::GrantAdmin,
+        AuditTargetType::Profile,
+        Some(user_id),
+        serde_json::json!({}),
+        client_ip,
+    )
+    .await;
+
+    if let Err(e) = notifications::notify_admin_role_changed(&state.db, user_id, true).await {
+        tracing::warn!(error = %e, "Failed to send admin granted notification");
+    }
+
+    Ok(MessageResponse::new("Admin privileges granted"))
+}
+
+/// DELETE /api/admin/users/:user_id/admin
+///
+/// Revoke admin privileges from a user. Refuses to remove the last remaining admin.
+pub async fn revoke_admin(
+    State(state): State<Arc<AppState>>,
+    Path(user_id): Path<Uuid>,
+    admin: RequireAdmin,
+    ExtractClientIp(client_ip): ExtractClientIp,
+) -> Result<impl IntoResponse, ApiError> {
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(map_db_err("revoke_admin"))?;
+
+    // Lock all admin rows so concurrent revokes can't both pass the last-admin check
+    let admin_ids: Vec<Uuid> =
+        sqlx::query_scalar("SELECT id FROM profiles WHERE is_admin = true FOR UPDATE")
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(map_db_err("revoke_admin"))?;
+
+    if !admin_ids.contains(&user_id) {
+        let exists: bool =
+            sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM profiles WHERE id = $1)")
+                .bind(user_id)
+                .fetch_one(&mut *tx)
+                .await
+                .map_err(map_db_err("revoke_admin"))?;
+
+        return Err(if exists {
+            ApiError::conflict("User is not an admin")
+        } else {
+            ApiError::not_found("User not found")
+        });
+    }
+
+    if admin_ids.len() <= 1 {
+        return Err(ApiError::conflict(
+            "Cannot revoke admin privileges from the last remaining admin",
+        ));
+    }
+
+    sqlx::query("UPDATE profiles SET is_admin = false, updated_at = NOW() WHERE id = $1")
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(map_db_err("revoke_admin"))?;
+
+    tx.commit()
+        .await
+        .map_err(map_db_err("revoke_admin"))?;
+
+    let _ = log_admin_action(
+        &state.db,
+        admin.user_id(),
+        AdminAction::RevokeAdmin,
+        AuditTargetType::Profile,
+        Some(user_id),
+        serde_json::json!({ "self_revoke": user_id == admin.user_id() }),
+        client_ip,
+    )
+    .await;
+
+    if let Err(e) = notifications::notify_admin_role_changed(&state.db, user_id, false).await {
+        tracing::warn!(error = %e, "Failed to send admin revoked notification");
+    }
+
+    Ok(MessageResponse::new("Admin privileges revoked"))
+}
+
+// ============================================================================
+// AI Usage
+// ============================================================================
+
+/// Most projects and users listed in the admin AI usage report
+const AI_USAGE_TOP_LIMIT: i64 = 20;
+
+/// GET /api/admin/ai-usage
+///
+/// Platform-wide AI usage: totals, breakdowns by operation and model, and the
+/// heaviest projects and users. `?since=` / `?until=` bound the range.
+pub async fn get_ai_usage(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<AiUsageQuery>,
+    _admin: RequireAdmin,
+) -> Result<impl IntoResponse, ApiError> {
+    let (since, until) = (query.since, query.until);
+    let breakdown = |grouping| {
+        ai_usage::breakdown(&state.db, grouping, None, since, until, AI_USAGE_TOP_LIMIT)
+    };
+
+    let (totals, by_operation, by_model, top_projects, top_users) = tokio::try_join!(
+        ai_usage::totals(&state.db, None, since, until),
+        breakdown(UsageGrouping::Operation),
+        breakdown(UsageGrouping::Model),
+        breakdown(UsageGrouping::Project),
+        breakdown(UsageGrouping::User),
+    )?;
+
+    Ok(Json(DataResponse::new(AdminAiUsage {
+        totals,
+        by_operation,
+        by_model,
+        top_projects,
+        top_users,
     })))
 }
 
@@ -664,9 +1125,7 @@ pub async fn list_audit_log(
     Query(query): Query<AuditLogQueryParams>,
     _admin: RequireAdmin,
 ) -> Result<impl IntoResponse, ApiError> {
-    let page = query.pagination.page.unwrap_or(1).max(1);
-    let per_page = query.pagination.per_page.unwrap_or(50).min(100);
-    let offset = ((page - 1) * per_page) as i64;
+    let page = resolve_pagination(&query.pagination, state.settings.pagination.audit_log)?;
 
     let total: i64 = sqlx::query_scalar(
         r#"
@@ -687,7 +1146,7 @@ pub async fn list_audit_log(
     .bind(query.filter.to_date)
     .fetch_one(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("list_audit_log"))?;
 
     let rows = sqlx::query_as::<_, AuditLogRow>(
         r#"
@@ -703,7 +1162,7 @@ pub async fn list_audit_log(
         AND ($4::uuid IS NULL OR a.target_id = $4)
         AND ($5::timestamptz IS NULL OR a.created_at >= $5)
         AND ($6::timestamptz IS NULL OR a.created_at <= $6)
-        ORDER BY a.created_at DESC
+        ORDER BY a.created_at DESC, a.id
         LIMIT $7 OFFSET $8
         "#,
     )
@@ -713,40 +1172,163 @@ pub async fn list_audit_log(
     .bind(query.filter.target_id)
     .bind(query.filter.from_date)
     .bind(query.filter.to_date)
-    .bind(per_page as i64)
-    .bind(offset)
+    .bind(page.limit())
+    .bind(page.offset())
     .fetch_all(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("list_audit_log"))?;
 
-    let data: Vec<AdminAuditLogResponse> = rows
-        .into_iter()
-        .map(|r| AdminAuditLogResponse {
-            id: r.id,
-            admin_id: r.admin_id,
-            admin_name: r.admin_name,
-            action: r.action,
-            target_type: r.target_type,
-            target_id: r.target_id,
-            details: r.details,
-            ip_address: r.ip_address,
-            created_at: r.created_at,
-        })
-        .collect();
+    let data: Vec<AdminAuditLogResponse> = rows.into_iter().map(Into::into).collect();
 
-    let total_pages = ((total as f64) / (per_page as f64)).ceil() as u32;
-
-    Ok(Json(Paginated {
-        data,
-        pagination: PaginationMeta {
-            page,
-            per_page,
-            total_items: total as u64,
-            total_pages,
-            has_next: page < total_pages,
-            has_prev: page > 1,
-        },
-    }))
+    Ok(Json(Paginated::new(data, &page, total as u64)))
+}
+
+/// GET /api/admin/audit-log/export?format=csv|json
+///
+/// Stream the full filtered audit log as CSV or NDJSON. Rows are streamed from the
+/// database as they are read so large exports are never buffered in memory.
+pub async fn export_audit_log(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<AuditLogExportParams>,
+    admin: RequireAdmin,
+    ExtractClientIp(client_ip): ExtractClientIp,
+) -> Result<impl IntoResponse, ApiError> {
+    let format = query.format;
+    let filter = query.filter;
+
+    let _ = log_admin_action(
+        &state.db,
+        admin.user_id(),
+        AdminAction::ExportAuditLog,
+        AuditTargetType::AuditLog,
+        None,
+        serde_json::json!({
+            "format": if format == AuditExportFormat::Csv { "csv" } else { "json" },
+            "admin_id": filter.admin_id,
+            "action": filter.action,
+            "target_type": filter.target_type,
+            "target_id": filter.target_id,
+            "from": filter.from_date,
+            "to": filter.to_date,
+        }),
+        client_ip,
+    )
+    .await;
+
+    let (mut tx, rx) = futures::channel::mpsc::channel::<Result<Bytes, std::io::Error>>(64);
+    let db = state.db.clone();
+
+    tokio::spawn(async move {
+        if format == AuditExportFormat::Csv {
+            let columns = "id,admin_id,admin_name,action,target_type,target_id,details,ip_address,created_at\n";
+            if tx.send(Ok(Bytes::from_static(columns.as_bytes()))).await.is_err() {
+                return;
+            }
+        }
+
+        let mut rows = sqlx::query_as::<_, AuditLogRow>(
+            r#"
+            SELECT 
+                a.id, a.admin_id,
+                COALESCE(p.company_name, p.first_name || ' ' || p.last_name) as admin_name,
+                a.action, a.target_type, a.target_id, a.details, a.ip_address, a.created_at
+            FROM admin_audit_log a
+            LEFT JOIN profiles p ON a.admin_id = p.id
+            WHERE ($1::uuid IS NULL OR a.admin_id = $1)
+            AND ($2::text IS NULL OR a.action = $2)
+            AND ($3::text IS NULL OR a.target_type = $3)
+            AND ($4::uuid IS NULL OR a.target_id = $4)
+            AND ($5::timestamptz IS NULL OR a.created_at >= $5)
+            AND ($6::timestamptz IS NULL OR a.created_at <= $6)
+            ORDER BY a.created_at ASC
+            "#,
+        )
+        .bind(filter.admin_id)
+        .bind(&filter.action)
+        .bind(&filter.target_type)
+        .bind(filter.target_id)
+        .bind(filter.from_date)
+        .bind(filter.to_date)
+        .fetch(&db);
+
+        while let Some(row) = rows.next().await {
+            let chunk = match row {
+                Ok(r) => match format {
+                    AuditExportFormat::Csv => Ok(Bytes::from(audit_row_to_csv(&r))),
+                    AuditExportFormat::Json => {
+                        let mut line = serde_json::to_vec(&AdminAuditLogResponse::from(r))
+                            .unwrap_or_default();
+                        line.push(b'\n');
+                        Ok(Bytes::from(line))
+                    }
+                },
+                Err(e) => {
+                    tracing::error!(error = %e, "Audit log export query failed");
+                    Err(std::io::Error::other("audit log export failed"))
+                }
+            };
+
+            let failed = chunk.is_err();
+            if tx.send(chunk).await.is_err() || failed {
+                // Client disconnected or the query failed; stop streaming
+                return;
+            }
+        }
+    });
+
+    let (content_type, extension) = match format {
+        AuditExportFormat::Csv => ("text/csv; charset=utf-8", "csv"),
+        AuditExportFormat::Json => ("application/x-ndjson", "ndjson"),
+    };
+    let disposition = format!(
+        "attachment; filename=\"audit-log-{}.{}\"",
+        Utc::now().format("%Y%m%d"),
+        extension
+    );
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, content_type.to_string()),
+            (header::CONTENT_DISPOSITION, disposition),
+        ],
+        Body::from_stream(rx),
+    ))
+}
+
+/// Render an audit log row as a single CSV line
+fn audit_row_to_csv(r: &AuditLogRow) -> String {
+    let fields = [
+        r.id.to_string(),
+        r.admin_id.to_string(),
+        r.admin_name.clone().unwrap_or_default(),
+        r.action.clone(),
+        r.target_type.clone(),
+        r.target_id.map(|id| id.to_string()).unwrap_or_default(),
+        r.details.to_string(),
+        r.ip_address.clone().unwrap_or_default(),
+        r.created_at.to_rfc3339(),
+    ];
+
+    let mut line = fields.iter().map(|f| csv_escape(f)).collect::<Vec<_>>().join(",");
+    line.push('\n');
+    line
+}
+
+/// Quote a CSV field if needed, doubling embedded quotes. Text a spreadsheet
+/// would run as a formula gets a leading `'`; plain numbers are left alone.
+pub(crate) fn csv_escape(field: &str) -> String {
+    let is_number = field.parse::<f64>().is_ok_and(f64::is_finite);
+    let field = if field.starts_with(['=', '+', '-', '@', '\t', '\r']) && !is_number {
+        format!("'{}", field)
+    } else {
+        field.to_string()
+    };
+
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field
+    }
 }
 
 // ============================================================================
@@ -759,5 +1341,765 @@ pub async fn list_audit_log(
 pub async fn check_admin(
     _admin: RequireAdmin,
 ) -> Result<impl IntoResponse, ApiError> {
-    Ok(Json(serde_json::json!({ "is_admin": true })))
+    Ok(DataResponse::new(serde_json::json!({ "is_admin": true })))
+}
+
+// ============================================================================
+// Cache Management
+// ============================================================================
+
+/// DELETE /api/admin/cache/contract-templates
+///
+/// Drop the cached contract template list after editing templates directly in
+/// the database; the next request reloads it.
+pub async fn invalidate_contract_templates_cache(
+    State(state): State<Arc<AppState>>,
+    admin: RequireAdmin,
+    ExtractClientIp(client_ip): ExtractClientIp,
+) -> Result<impl IntoResponse, ApiError> {
+    hiring::invalidate_contract_templates_cache(&state.cache).await;
+
+    let _ = log_admin_action(
+        &state.db,
+        admin.user_id(),
+        AdminAction::InvalidateCache,
+        AuditTargetType::ContractTemplate,
+        None,
+        serde_json::json!({ "cache": "contract_templates" }),
+        client_ip,
+    )
+    .await;
+
+    Ok(NoContent)
+}
+
+// ============================================================================
+// Contract Templates
+// ============================================================================
+
+/// Values accepted by `contract_templates.template_type`
+const CONTRACT_TEMPLATE_TYPES: &[&str] = &[
+    "standard",
+    "time_materials",
+    "fixed_price",
+    "unit_price",
+    "master_service",
+];
+
+#[derive(Debug, sqlx::FromRow)]
+struct ContractTemplateAdminRow {
+    name: String,
+    description: Option<String>,
+    template_type: String,
+    content: String,
+    sections: serde_json::Value,
+    variables: serde_json::Value,
+    is_system: bool,
+    is_active: bool,
+}
+
+/// Check a template's fields and that every `{{placeholder}}` it uses is
+/// either a declared variable or derived from the hire request.
+fn validate_contract_template(
+    name: &str,
+    template_type: &str,
+    content: &str,
+    sections: &[ContractSection],
+    variables: &[TemplateVariable],
+) -> Result<(), ApiError> {
+    if name.trim().is_empty() {
+        return Err(ApiError::bad_request("Template name is required"));
+    }
+    if content.trim().is_empty() {
+        return Err(ApiError::bad_request("Template content is required"));
+    }
+    if !CONTRACT_TEMPLATE_TYPES.contains(&template_type) {
+        return Err(ApiError::bad_request(format!(
+            "Unknown template_type '{}'; expected one of: {}",
+            template_type,
+            CONTRACT_TEMPLATE_TYPES.join(", ")
+        )));
+    }
+
+    let undeclared = contract_templates::undeclared_placeholders(content, sections, variables);
+    if !undeclared.is_empty() {
+        return Err(ApiError::bad_request(format!(
+            "Template uses undeclared variables: {}",
+            undeclared.join(", ")
+        )));
+    }
+
+    Ok(())
+}
+
+fn to_json<T: serde::Serialize>(value: &T) -> serde_json::Value {
+    serde_json::to_value(value).unwrap_or(serde_json::json!([]))
+}
+
+/// POST /api/admin/contract-templates
+///
+/// Create a contract template available to all GCs.
+pub async fn create_contract_template(
+    State(state): State<Arc<AppState>>,
+    admin: RequireAdmin,
+    ExtractClientIp(client_ip): ExtractClientIp,
+    Json(input): Json<CreateContractTemplateInput>,
+) -> Result<impl IntoResponse, ApiError> {
+    let template_type = input.template_type.as_deref().unwrap_or("standard");
+    let sections = input.sections.unwrap_or_default();
+    let variables = input.variables.unwrap_or_default();
+    validate_contract_template(&input.name, template_type, &input.content, &sections, &variables)?;
+
+    let id = Uuid::new_v4();
+    sqlx::query(
+        r#"
+        INSERT INTO contract_templates (
+            id, name, description, template_type, content, sections, variables,
+            is_system, created_by, is_active
+        ) VALUES ($1, $2, $3, $4, $5, $6, $7, false, $8, $9)
+        "#,
+    )
+    .bind(id)
+    .bind(input.name.trim())
+    .bind(&input.description)
+    .bind(template_type)
+    .bind(&input.content)
+    .bind(to_json(&sections))
+    .bind(to_json(&variables))
+    .bind(admin.user_id())
+    .bind(input.is_active.unwrap_or(true))
+    .execute(&state.db)
+    .await
+    .map_err(map_db_err("create_contract_template"))?;
+
+    hiring::invalidate_contract_templates_cache(&state.cache).await;
+
+    let _ = log_admin_action(
+        &state.db,
+        admin.user_id(),
+        AdminAction::CreateContractTemplate,
+        AuditTargetType::ContractTemplate,
+        Some(id),
+        serde_json::json!({ "name": input.name.trim(), "template_type": template_type }),
+        client_ip,
+    )
+    .await;
+
+    Ok(Created::id(id))
+}
+
+/// PUT /api/admin/contract-templates/:template_id
+///
+/// Update a contract template. Omitted fields keep their current values; the
+/// merged template is validated as a whole.
+pub async fn update_contract_template(
+    State(state): State<Arc<AppState>>,
+    Path(template_id): Path<Uuid>,
+    admin: RequireAdmin,
+    ExtractClientIp(client_ip): ExtractClientIp,
+    Json(input): Json<UpdateContractTemplateInput>,
+) -> Result<impl IntoResponse, ApiError> {
+    let current = sqlx::query_as::<_, ContractTemplateAdminRow>(
+        r#"
+        SELECT name, description, template_type, content, sections, variables,
+               COALESCE(is_system, false) as is_system, COALESCE(is_active, true) as is_active
+        FROM contract_templates WHERE id = $1
+        "#,
+    )
+    .bind(template_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("update_contract_template"))?
+    .ok_or_else(|| ApiError::not_found("Contract template not found"))?;
+
+    let changed: Vec<&str> = [
+        ("name", input.name.is_some()),
+        ("description", input.description.is_some()),
+        ("template_type", input.template_type.is_some()),
+        ("content", input.content.is_some()),
+        ("sections", input.sections.is_some()),
+        ("variables", input.variables.is_some()),
+        ("is_active", input.is_active.is_some()),
+    ]
+    .into_iter()
+    .filter(|(_, set)| *set)
+    .map(|(field, _)| field)
+    .collect();
+
+    let name = input.name.unwrap_or(current.name);
+    let description = input.description.or(current.description);
+    let template_type = input.template_type.unwrap_or(current.template_type);
+    let content = input.content.unwrap_or(current.content);
+    let sections = input
+        .sections
+        .unwrap_or_else(|| serde_json::from_value(current.sections).unwrap_or_default());
+    let variables = input
+        .variables
+        .unwrap_or_else(|| serde_json::from_value(current.variables).unwrap_or_default());
+    let is_active = input.is_active.unwrap_or(current.is_active);
+    validate_contract_template(&name, &template_type, &content, &sections, &variables)?;
+
+    sqlx::query(
+        r#"
+        UPDATE contract_templates
+        SET name = $2, description = $3, template_type = $4, content = $5,
+            sections = $6, variables = $7, is_active = $8, updated_at = NOW()
+        WHERE id = $1
+        "#,
+    )
+    .bind(template_id)
+    .bind(name.trim())
+    .bind(&description)
+    .bind(&template_type)
+    .bind(&content)
+    .bind(to_json(&sections))
+    .bind(to_json(&variables))
+    .bind(is_active)
+    .execute(&state.db)
+    .await
+    .map_err(map_db_err("update_contract_template"))?;
+
+    hiring::invalidate_contract_templates_cache(&state.cache).await;
+
+    let _ = log_admin_action(
+        &state.db,
+        admin.user_id(),
+        AdminAction::UpdateContractTemplate,
+        AuditTargetType::ContractTemplate,
+        Some(template_id),
+        serde_json::json!({ "fields": changed, "is_system": current.is_system }),
+        client_ip,
+    )
+    .await;
+
+    Ok(MessageResponse::new("Contract template updated"))
+}
+
+/// DELETE /api/admin/contract-templates/:template_id
+///
+/// Delete a contract template. System templates and templates that contracts
+/// were drafted from cannot be deleted; deactivate them instead.
+pub async fn delete_contract_template(
+    State(state): State<Arc<AppState>>,
+    Path(template_id): Path<Uuid>,
+    admin: RequireAdmin,
+    ExtractClientIp(client_ip): ExtractClientIp,
+) -> Result<impl IntoResponse, ApiError> {
+    let current: Option<(String, bool, bool)> = sqlx::query_as(
+        r#"
+        SELECT name, COALESCE(is_system, false),
+               EXISTS(SELECT 1 FROM contracts WHERE template_id = contract_templates.id)
+        FROM contract_templates WHERE id = $1
+        "#,
+    )
+    .bind(template_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("delete_contract_template"))?;
+
+    let name = match current {
+        None => return Err(ApiError::not_found("Contract template not found")),
+        Some((_, true, _)) => {
+            return Err(ApiError::conflict(
+                "System templates cannot be deleted; deactivate them instead",
+            ))
+        }
+        Some((_, false, true)) => {
+            return Err(ApiError::conflict(
+                "Template is used by existing contracts; deactivate it instead",
+            ))
+        }
+        Some((name, false, false)) => name,
+    };
+
+    sqlx::query("DELETE FROM contract_templates WHERE id = $1 AND is_system IS NOT TRUE")
+        .bind(template_id)
+        .execute(&state.db)
+        .await
+        .map_err(map_db_err("delete_contract_template"))?;
+
+    hiring::invalidate_contract_templates_cache(&state.cache).await;
+
+    let _ = log_admin_action(
+        &state.db,
+        admin.user_id(),
+        AdminAction::DeleteContractTemplate,
+        AuditTargetType::ContractTemplate,
+        Some(template_id),
+        serde_json::json!({ "name": name }),
+        client_ip,
+    )
+    .await;
+
+    Ok(NoContent)
+}
+
+// ============================================================================
+// Verification Requirements
+// ============================================================================
+
+/// GET /api/admin/settings/verification-requirements
+///
+/// Fields a profile must fill in before its owner can request verification,
+/// and the fields that can be required.
+pub async fn get_verification_requirements(
+    State(state): State<Arc<AppState>>,
+    _admin: RequireAdmin,
+) -> Result<impl IntoResponse, ApiError> {
+    let requirements = system_settings::verification_requirements(&state.db)
+        .await
+        .map_err(map_db_err("get_verification_requirements"))?;
+
+    Ok(Json(DataResponse::new(serde_json::json!({
+        "required_fields": requirements.required_fields,
+        "available_fields": VERIFICATION_FIELDS,
+    }))))
+}
+
+/// PUT /api/admin/settings/verification-requirements
+///
+/// Replace the list of fields required before requesting verification.
+pub async fn update_verification_requirements(
+    State(state): State<Arc<AppState>>,
+    admin: RequireAdmin,
+    ExtractClientIp(client_ip): ExtractClientIp,
+    Json(input): Json<VerificationRequirements>,
+) -> Result<impl IntoResponse, ApiError> {
+    let unknown: Vec<&str> = input
+        .required_fields
+        .iter()
+        .map(String::as_str)
+        .filter(|f| !VERIFICATION_FIELDS.contains(f))
+        .collect();
+    if !unknown.is_empty() {
+        return Err(ApiError::bad_request(format!(
+            "Unknown fields: {}; expected any of: {}",
+            unknown.join(", "),
+            VERIFICATION_FIELDS.join(", ")
+        )));
+    }
+
+    let mut required_fields = input.required_fields;
+    required_fields.sort();
+    required_fields.dedup();
+    let requirements = VerificationRequirements { required_fields };
+
+    let previous = system_settings::verification_requirements(&state.db)
+        .await
+        .map_err(map_db_err("update_verification_requirements"))?;
+
+    system_settings::set(
+        &state.db,
+        system_settings::VERIFICATION_REQUIREMENTS,
+        &requirements,
+        admin.user_id(),
+    )
+    .await
+    .map_err(map_db_err("update_verification_requirements"))?;
+
+    let _ = log_admin_action(
+        &state.db,
+        admin.user_id(),
+        AdminAction::UpdateSystemSetting,
+        AuditTargetType::SystemSetting,
+        None,
+        serde_json::json!({
+            "key": system_settings::VERIFICATION_REQUIREMENTS,
+            "from": previous.required_fields,
+            "to": requirements.required_fields,
+        }),
+        client_ip,
+    )
+    .await;
+
+    Ok(Json(DataResponse::new(requirements)))
+}
+
+/// Longest feature flag key
+const MAX_FEATURE_FLAG_KEY_LEN: usize = 100;
+
+/// Flag keys are snake_case, as checked in code (`ctx.has_flag("streaming_ai")`)
+fn validate_feature_flag_key(key: &str) -> Result<(), ApiError> {
+    let valid = !key.is_empty()
+        && key.len() <= MAX_FEATURE_FLAG_KEY_LEN
+        && key.bytes().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_');
+    if !valid {
+        return Err(ApiError::bad_request(format!(
+            "Feature flag keys are 1-{} lowercase letters, digits and underscores",
+            MAX_FEATURE_FLAG_KEY_LEN
+        )));
+    }
+    Ok(())
+}
+
+/// Make a flag change take effect on this instance now rather than at the
+/// next scheduled reload
+async fn reload_feature_flags(state: &AppState) {
+    if let Err(e) = state.feature_flags.reload().await {
+        tracing::warn!(error = %e, "Failed to reload feature flags after change");
+    }
+}
+
+const FEATURE_FLAG_SELECT: &str = r#"
+    SELECT f.key, f.description, f.enabled,
+           COUNT(u.user_id) FILTER (WHERE u.enabled) AS users_enabled,
+           COUNT(u.user_id) FILTER (WHERE NOT u.enabled) AS users_disabled,
+           f.updated_at
+    FROM feature_flags f
+    LEFT JOIN feature_flag_users u ON u.flag_key = f.key
+"#;
+
+/// GET /api/admin/feature-flags
+///
+/// Every feature flag, its global setting and how many users override it.
+pub async fn list_feature_flags(
+    State(state): State<Arc<AppState>>,
+    _admin: RequireAdmin,
+) -> Result<impl IntoResponse, ApiError> {
+    let flags = sqlx::query_as::<_, FeatureFlagResponse>(&format!(
+        "{} GROUP BY f.key ORDER BY f.key",
+        FEATURE_FLAG_SELECT
+    ))
+    .fetch_all(&state.db)
+    .await
+    .map_err(map_db_err("list_feature_flags"))?;
+
+    Ok(Json(DataResponse::new(flags)))
+}
+
+/// PUT /api/admin/feature-flags/:key
+///
+/// Turn a flag on or off for everyone (users with an override keep it),
+/// creating the flag if it doesn't exist yet.
+pub async fn update_feature_flag(
+    State(state): State<Arc<AppState>>,
+    admin: RequireAdmin,
+    ExtractClientIp(client_ip): ExtractClientIp,
+    Path(key): Path<String>,
+    Json(input): Json<UpdateFeatureFlagRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    validate_feature_flag_key(&key)?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO feature_flags (key, description, enabled, updated_by)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT (key) DO UPDATE
+        SET enabled = EXCLUDED.enabled,
+            description = COALESCE(EXCLUDED.description, feature_flags.description),
+            updated_by = EXCLUDED.updated_by,
+            updated_at = NOW()
+        "#,
+    )
+    .bind(&key)
+    .bind(input.description.as_deref().map(str::trim).filter(|d| !d.is_empty()))
+    .bind(input.enabled)
+    .bind(admin.user_id())
+    .execute(&state.db)
+    .await
+    .map_err(map_db_err("update_feature_flag"))?;
+
+    reload_feature_flags(&state).await;
+
+    tracing::info!(admin_id = %admin.user_id(), flag = %key, enabled = input.enabled, "Feature flag updated");
+
+    let _ = log_admin_action(
+        &state.db,
+        admin.user_id(),
+        AdminAction::UpdateFeatureFlag,
+        AuditTargetType::FeatureFlag,
+        None,
+        serde_json::json!({ "key": key, "enabled": input.enabled }),
+        client_ip,
+    )
+    .await;
+
+    let flag = sqlx::query_as::<_, FeatureFlagResponse>(&format!(
+        "{} WHERE f.key = $1 GROUP BY f.key",
+        FEATURE_FLAG_SELECT
+    ))
+    .bind(&key)
+    .fetch_one(&state.db)
+    .await
+    .map_err(map_db_err("update_feature_flag"))?;
+
+    Ok(Json(DataResponse::new(flag)))
+}
+
+/// PUT /api/admin/feature-flags/:key/users/:user_id
+///
+/// Turn a flag on or off for one user, whatever its global setting.
+pub async fn set_user_feature_flag(
+    State(state): State<Arc<AppState>>,
+    admin: RequireAdmin,
+    ExtractClientIp(client_ip): ExtractClientIp,
+    Path((key, user_id)): Path<(String, Uuid)>,
+    Json(input): Json<UpdateUserFeatureFlagRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let exists: Option<String> = sqlx::query_scalar("SELECT key FROM feature_flags WHERE key = $1")
+        .bind(&key)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(map_db_err("set_user_feature_flag"))?;
+    if exists.is_none() {
+        return Err(ApiError::not_found("Feature flag not found"));
+    }
+
+    sqlx::query(
+        r#"
+        INSERT INTO feature_flag_users (flag_key, user_id, enabled, updated_by)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT (flag_key, user_id) DO UPDATE
+        SET enabled = EXCLUDED.enabled, updated_by = EXCLUDED.updated_by, updated_at = NOW()
+        "#,
+    )
+    .bind(&key)
+    .bind(user_id)
+    .bind(input.enabled)
+    .bind(admin.user_id())
+    .execute(&state.db)
+    .await
+    .map_err(map_db_err("set_user_feature_flag"))?;
+
+    reload_feature_flags(&state).await;
+
+    tracing::info!(
+        admin_id = %admin.user_id(),
+        flag = %key,
+        user_id = %user_id,
+        enabled = input.enabled,
+        "Feature flag overridden for user"
+    );
+
+    let _ = log_admin_action(
+        &state.db,
+        admin.user_id(),
+        AdminAction::UpdateFeatureFlag,
+        AuditTargetType::FeatureFlag,
+        Some(user_id),
+        serde_json::json!({ "key": key, "user_id": user_id, "enabled": input.enabled }),
+        client_ip,
+    )
+    .await;
+
+    Ok(MessageResponse::new(if input.enabled {
+        "Feature flag enabled for user"
+    } else {
+        "Feature flag disabled for user"
+    }))
+}
+
+/// DELETE /api/admin/feature-flags/:key/users/:user_id
+///
+/// Remove a user's override so the flag's global setting applies to them.
+pub async fn clear_user_feature_flag(
+    State(state): State<Arc<AppState>>,
+    admin: RequireAdmin,
+    ExtractClientIp(client_ip): ExtractClientIp,
+    Path((key, user_id)): Path<(String, Uuid)>,
+) -> Result<impl IntoResponse, ApiError> {
+    let result = sqlx::query("DELETE FROM feature_flag_users WHERE flag_key = $1 AND user_id = $2")
+        .bind(&key)
+        .bind(user_id)
+        .execute(&state.db)
+        .await
+        .map_err(map_db_err("clear_user_feature_flag"))?;
+
+    if result.rows_affected() == 0 {
+        return Err(ApiError::not_found("No override of this flag for this user"));
+    }
+
+    reload_feature_flags(&state).await;
+
+    tracing::info!(admin_id = %admin.user_id(), flag = %key, user_id = %user_id, "Feature flag override removed");
+
+    let _ = log_admin_action(
+        &state.db,
+        admin.user_id(),
+        AdminAction::UpdateFeatureFlag,
+        AuditTargetType::FeatureFlag,
+        Some(user_id),
+        serde_json::json!({ "key": key, "user_id": user_id, "override_removed": true }),
+        client_ip,
+    )
+    .await;
+
+    Ok(NoContent)
+}
+
+/// Maintenance mode setting key in the audit log
+const MAINTENANCE_MODE: &str = "maintenance_mode";
+
+/// GET /api/admin/maintenance
+///
+/// Whether maintenance mode is on, and the paths that stay writable.
+pub async fn get_maintenance_mode(
+    State(state): State<Arc<AppState>>,
+    _admin: RequireAdmin,
+) -> Result<impl IntoResponse, ApiError> {
+    let mode = maintenance::current(&state.cache).await;
+
+    Ok(Json(DataResponse::new(serde_json::json!({
+        "enabled": mode.is_some(),
+        "mode": mode,
+        "allowed_paths": state.settings.maintenance.allowed_paths,
+    }))))
+}
+
+/// PUT /api/admin/maintenance
+///
+/// Turn maintenance mode on or off for every instance. While it is on,
+/// mutating requests from non-admins are refused with 503.
+pub async fn update_maintenance_mode(
+    State(state): State<Arc<AppState>>,
+    admin: RequireAdmin,
+    ExtractClientIp(client_ip): ExtractClientIp,
+    Json(input): Json<UpdateMaintenanceModeRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let mode = MaintenanceMode {
+        enabled: input.enabled,
+        message: input.message.map(|m| m.trim().to_string()).filter(|m| !m.is_empty()),
+        retry_after_seconds: input
+            .retry_after_seconds
+            .unwrap_or(state.settings.maintenance.retry_after_seconds),
+        updated_by: Some(admin.user_id()),
+        updated_at: Some(Utc::now()),
+    };
+
+    let was_enabled = maintenance::current(&state.cache).await.is_some();
+
+    maintenance::set(&state.cache, &mode).await.map_err(|e| {
+        tracing::error!(error = %e, "Failed to store maintenance mode");
+        ApiError::service_unavailable("Failed to update maintenance mode; please try again")
+    })?;
+
+    if mode.enabled {
+        tracing::warn!(
+            admin_id = %admin.user_id(),
+            was_enabled,
+            retry_after_seconds = mode.retry_after_seconds,
+            "Maintenance mode enabled"
+        );
+    } else {
+        tracing::warn!(admin_id = %admin.user_id(), was_enabled, "Maintenance mode disabled");
+    }
+
+    let _ = log_admin_action(
+        &state.db,
+        admin.user_id(),
+        AdminAction::UpdateSystemSetting,
+        AuditTargetType::SystemSetting,
+        None,
+        serde_json::json!({
+            "key": MAINTENANCE_MODE,
+            "from": was_enabled,
+            "to": mode.enabled,
+            "message": mode.message,
+            "retry_after_seconds": mode.retry_after_seconds,
+        }),
+        client_ip,
+    )
+    .await;
+
+    Ok(Json(DataResponse::new(mode)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::Uri;
+    use std::time::{Duration, Instant};
+
+    use crate::test_support;
+
+    /// Time a dashboard stats request against a database 50ms away, on a pool
+    /// of `connections`, after a few warm-up requests
+    async fn timed_admin_stats(connections: u32) -> Option<Duration> {
+        let db = test_support::slow_db(Duration::from_millis(50), connections).await?;
+        let state = test_support::state_on(db).await?;
+        let admin_id = test_support::user(&state.db, "gc").await;
+        let admin = RequireAdmin { auth: test_support::auth(admin_id), admin_id };
+
+        let mut elapsed = Duration::ZERO;
+        for _ in 0..4 {
+            let started = Instant::now();
+            get_admin_stats(State(state.clone()), admin.clone(), ExtractClientIp(None))
+                .await
+                .unwrap();
+            elapsed = started.elapsed();
+        }
+        Some(elapsed)
+    }
+
+    #[tokio::test]
+    async fn admin_stats_queries_run_concurrently() {
+        // One connection runs the five stats queries one after another. The
+        // audit log write before them is sequential either way.
+        let Some(sequential) = timed_admin_stats(1).await else { return };
+        let Some(concurrent) = timed_admin_stats(5).await else { return };
+
+        assert!(
+            concurrent * 3 < sequential * 2,
+            "concurrent {:?}, sequential {:?}",
+            concurrent,
+            sequential
+        );
+    }
+
+    #[tokio::test]
+    async fn bulk_verification_is_capped() {
+        let Some(state) = test_support::state().await else { return };
+        let admin_id = test_support::user(&state.db, "gc").await;
+        let admin = RequireAdmin { auth: test_support::auth(admin_id), admin_id };
+        let input = BulkVerificationRequest {
+            approve: (0..MAX_BULK_VERIFICATIONS).map(|_| Uuid::new_v4()).collect(),
+            reject: vec![BulkRejectItem { id: Uuid::new_v4(), reason: "Expired insurance".to_string() }],
+            notes: None,
+        };
+
+        let err = bulk_verification(State(state), admin, ExtractClientIp(None), Json(input))
+            .await
+            .err()
+            .expect("over the cap");
+
+        assert!(matches!(&err, ApiError::BadRequest(m) if m == "At most 100 verifications can be processed at once"), "{:?}", err);
+    }
+
+    #[test]
+    fn csv_fields_cannot_start_a_formula() {
+        assert_eq!(csv_escape("=HYPERLINK(\"http://x\")"), "\"'=HYPERLINK(\"\"http://x\"\")\"");
+        assert_eq!(csv_escape("+1+2"), "'+1+2");
+        assert_eq!(csv_escape("-2+3"), "'-2+3");
+        assert_eq!(csv_escape("@SUM(A1)"), "'@SUM(A1)");
+        assert_eq!(csv_escape("\tcmd"), "'\tcmd");
+        assert_eq!(csv_escape("\r=1"), "\"'\r=1\"");
+        assert_eq!(csv_escape("-inf"), "'-inf");
+
+        // Numbers and ordinary text are unchanged
+        assert_eq!(csv_escape("-1250.50"), "-1250.50");
+        assert_eq!(csv_escape("Acme, Inc."), "\"Acme, Inc.\"");
+        assert_eq!(csv_escape("Jo-Ann"), "Jo-Ann");
+    }
+
+    #[test]
+    fn user_list_accepts_admin_filter() {
+        let uri: Uri = "/admin/users?is_admin=true&user_type=gc&search=acme&page=2&per_page=10"
+            .parse()
+            .unwrap();
+
+        let Query(filter) = Query::<AdminUserQuery>::try_from_uri(&uri).unwrap();
+        assert_eq!(filter.is_admin, Some(true));
+        assert_eq!(filter.user_type.as_deref(), Some("gc"));
+        assert_eq!(filter.search.as_deref(), Some("acme"));
+
+        let Query(pagination) = Query::<PaginationParams>::try_from_uri(&uri).unwrap();
+        assert_eq!(pagination.page, Some(2));
+        assert_eq!(pagination.per_page, Some(10));
+    }
+
+    #[test]
+    fn user_list_rejects_malformed_admin_filter() {
+        let uri: Uri = "/admin/users?is_admin=maybe".parse().unwrap();
+        assert!(Query::<AdminUserQuery>::try_from_uri(&uri).is_err());
+    }
 }
@@ -2,13 +2,14 @@
 //!
 //! These endpoints provide the frontend with AI capabilities while:
 //! - Enforcing authentication
-//! - Caching results in Redis
+//! - Caching results in Redis, computing each missing result once
+//! - Enforcing AI quotas (cache hits are free)
 //! - Validating project ownership
 //! - Propagating request IDs for tracing
 
 use axum::{
-    extract::{Path, State},
-    http::HeaderMap,
+    extract::{Path, Query, State},
+    http::{header, HeaderMap},
     response::IntoResponse,
     Json,
 };
@@ -19,13 +20,16 @@ use crate::api::response::DataResponse;
 use crate::app::AppState;
 use crate::auth::RequireAuth;
 use crate::domain::ai::{
-    PlanSummaryRequest, PlanSummaryResponse, QnARequest, QnAResponse,
+    AiOperation, AiUsageQuery, InvalidateAiCacheQuery, ProjectAiUsage, PlanSummaryRequest, PlanSummaryResponse, QnARequest, QnAResponse,
     StandardTradesResponse, TenderScopeDocRequest, TenderScopeDocResponse,
     TradeScopesRequest, TradeScopesResponse,
 };
-use crate::error::ApiResult;
+use crate::error::{map_db_err, ApiError, ApiResult};
 use crate::middleware::request_id::X_REQUEST_ID;
-use crate::services::cache::keys;
+use crate::services::ai_quota;
+use crate::services::ai_usage::{self, AiCall, UsageGrouping};
+use crate::services::cache::{keys, CacheNamespace};
+use crate::services::single_flight::{self, Flight};
 
 /// Helper to extract request ID from headers.
 fn get_request_id(headers: &HeaderMap) -> Option<String> {
@@ -43,7 +47,7 @@ fn get_request_id(headers: &HeaderMap) -> Option<String> {
 ///
 /// POST /api/projects/:project_id/ai/summary
 pub async fn generate_plan_summary(
-    _auth: RequireAuth,
+    auth: RequireAuth,
     Path(project_id): Path<Uuid>,
     headers: HeaderMap,
     State(state): State<Arc<AppState>>,
@@ -58,42 +62,67 @@ pub async fn generate_plan_summary(
     let cache_key = keys::plan_summary(project_id);
     if let Some(cached) = state.cache.get::<PlanSummaryResponse>(&cache_key).await {
         tracing::debug!(project_id = %project_id, "Returning cached plan summary");
-        return Ok(Json(DataResponse::new(PlanSummaryResponse {
-            cached: true,
-            ..cached
-        })));
+        return Ok((
+            HeaderMap::new(),
+            Json(DataResponse::new(PlanSummaryResponse {
+                cached: true,
+                ..cached
+            })),
+        ));
     }
 
-    // Call AI service
-    let summary = state
-        .ai_client
-        .generate_plan_summary(
-            project_id,
-            &req.document_text,
-            req.instructions.as_deref(),
-            request_id.as_deref(),
+    // Call AI service, unless a concurrent request already is
+    let flight = single_flight::run(&state.cache, &cache_key, state.settings.ai_timeouts.summary, async {
+        let mut quota = ai_quota::reserve(
+            &state.db,
+            &state.cache,
+            &state.settings.ai_quotas,
+            auth.user_id,
+            Some(project_id),
         )
         .await?;
+        let call = AiCall::start(auth.user_id, Some(project_id), AiOperation::Summary);
+        let result = state
+            .ai_client
+            .generate_plan_summary(
+                project_id,
+                &req.document_text,
+                req.instructions.as_deref(),
+                request_id.as_deref(),
+            )
+            .await;
+        let result = quota.settle(&state.cache, result).await;
+        let summary = call.finish(&state.db, result).await?;
+
+        let response = PlanSummaryResponse {
+            project_id: project_id.to_string(),
+            summary: summary.output,
+            model: summary.model,
+            cached: false,
+        };
+
+        // Cache the result
+        if let Err(e) = state.cache.set_in(CacheNamespace::Ai, &cache_key, &response).await {
+            tracing::warn!(error = %e, "Failed to cache plan summary");
+        }
 
-    let response = PlanSummaryResponse {
-        project_id: project_id.to_string(),
-        summary,
-        cached: false,
-    };
+        Ok((quota.headers(), response))
+    })
+    .await?;
 
-    // Cache the result
-    if let Err(e) = state.cache.set(&cache_key, &response).await {
-        tracing::warn!(error = %e, "Failed to cache plan summary");
-    }
+    let (headers, response) = match flight {
+        Flight::Shared(shared) => (HeaderMap::new(), PlanSummaryResponse { cached: true, ..shared }),
+        Flight::Computed(computed) => computed,
+    };
 
-    Ok(Json(DataResponse::new(response)))
+    Ok((headers, Json(DataResponse::new(response))))
 }
 
 /// Extract trade scopes from a project document.
 ///
 /// POST /api/projects/:project_id/ai/trade-scopes
 pub async fn extract_trade_scopes(
-    _auth: RequireAuth,
+    auth: RequireAuth,
     Path(project_id): Path<Uuid>,
     headers: HeaderMap,
     State(state): State<Arc<AppState>>,
@@ -105,35 +134,60 @@ pub async fn extract_trade_scopes(
     let cache_key = keys::trade_scopes(project_id);
     if let Some(cached) = state.cache.get::<TradeScopesResponse>(&cache_key).await {
         tracing::debug!(project_id = %project_id, "Returning cached trade scopes");
-        return Ok(Json(DataResponse::new(TradeScopesResponse {
-            cached: true,
-            ..cached
-        })));
+        return Ok((
+            HeaderMap::new(),
+            Json(DataResponse::new(TradeScopesResponse {
+                cached: true,
+                ..cached
+            })),
+        ));
     }
 
-    // Call AI service
-    let scopes = state
-        .ai_client
-        .extract_trade_scopes(
-            project_id,
-            &req.document_text,
-            req.trades,
-            request_id.as_deref(),
+    // Call AI service, unless a concurrent request already is
+    let flight = single_flight::run(&state.cache, &cache_key, state.settings.ai_timeouts.trade_scopes, async {
+        let mut quota = ai_quota::reserve(
+            &state.db,
+            &state.cache,
+            &state.settings.ai_quotas,
+            auth.user_id,
+            Some(project_id),
         )
         .await?;
+        let call = AiCall::start(auth.user_id, Some(project_id), AiOperation::TradeScopes);
+        let result = state
+            .ai_client
+            .extract_trade_scopes(
+                project_id,
+                &req.document_text,
+                req.trades,
+                request_id.as_deref(),
+            )
+            .await;
+        let result = quota.settle(&state.cache, result).await;
+        let scopes = call.finish(&state.db, result).await?;
+
+        let response = TradeScopesResponse {
+            project_id: project_id.to_string(),
+            scopes: scopes.output,
+            model: scopes.model,
+            cached: false,
+        };
+
+        // Cache the result
+        if let Err(e) = state.cache.set_in(CacheNamespace::Ai, &cache_key, &response).await {
+            tracing::warn!(error = %e, "Failed to cache trade scopes");
+        }
 
-    let response = TradeScopesResponse {
-        project_id: project_id.to_string(),
-        scopes,
-        cached: false,
-    };
+        Ok((quota.headers(), response))
+    })
+    .await?;
 
-    // Cache the result
-    if let Err(e) = state.cache.set(&cache_key, &response).await {
-        tracing::warn!(error = %e, "Failed to cache trade scopes");
-    }
+    let (headers, response) = match flight {
+        Flight::Shared(shared) => (HeaderMap::new(), TradeScopesResponse { cached: true, ..shared }),
+        Flight::Computed(computed) => computed,
+    };
 
-    Ok(Json(DataResponse::new(response)))
+    Ok((headers, Json(DataResponse::new(response))))
 }
 
 /// Get list of standard construction trades.
@@ -146,10 +200,11 @@ pub async fn get_standard_trades(
 ) -> ApiResult<impl IntoResponse> {
     let request_id = get_request_id(&headers);
 
-    // This is static data, cache it longer
-    let cache_key = "ai:standard_trades";
-    if let Some(cached) = state.cache.get::<StandardTradesResponse>(cache_key).await {
-        return Ok(Json(DataResponse::new(cached)));
+    // Static data: cached here as reference data and for a day by the client
+    let cache_control = [(header::CACHE_CONTROL, "private, max-age=86400")];
+    let cache_key = keys::standard_trades();
+    if let Some(cached) = state.cache.get::<StandardTradesResponse>(&cache_key).await {
+        return Ok((cache_control, Json(DataResponse::new(cached))));
     }
 
     let trades = state
@@ -159,16 +214,15 @@ pub async fn get_standard_trades(
 
     let response = StandardTradesResponse { trades };
 
-    // Cache for 24 hours (static data)
     if let Err(e) = state
         .cache
-        .set_with_ttl(cache_key, &response, std::time::Duration::from_secs(86400))
+        .set_in(CacheNamespace::Reference, &cache_key, &response)
         .await
     {
         tracing::warn!(error = %e, "Failed to cache standard trades");
     }
 
-    Ok(Json(DataResponse::new(response)))
+    Ok((cache_control, Json(DataResponse::new(response))))
 }
 
 // =============================================================================
@@ -178,8 +232,11 @@ pub async fn get_standard_trades(
 /// Generate a tender scope document.
 ///
 /// POST /api/projects/:project_id/ai/tender-scope-doc
+///
+/// Nothing is stored; `POST /projects/:project_id/tenders/:tender_id/scope-doc/generate`
+/// keeps a versioned document on the tender.
 pub async fn generate_tender_scope_doc(
-    _auth: RequireAuth,
+    auth: RequireAuth,
     Path(project_id): Path<Uuid>,
     headers: HeaderMap,
     State(state): State<Arc<AppState>>,
@@ -190,7 +247,16 @@ pub async fn generate_tender_scope_doc(
     // No caching for tender docs - they're generated fresh each time
     // as scope_data may differ
 
-    let document = state
+    let mut quota = ai_quota::reserve(
+        &state.db,
+        &state.cache,
+        &state.settings.ai_quotas,
+        auth.user_id,
+        Some(project_id),
+    )
+    .await?;
+    let call = AiCall::start(auth.user_id, Some(project_id), AiOperation::TenderScopeDoc);
+    let result = state
         .ai_client
         .generate_tender_scope_doc(
             project_id,
@@ -200,15 +266,18 @@ pub async fn generate_tender_scope_doc(
             req.bid_due_date.as_deref(),
             request_id.as_deref(),
         )
-        .await?;
+        .await;
+    let result = quota.settle(&state.cache, result).await;
+    let document = call.finish(&state.db, result).await?;
 
     let response = TenderScopeDocResponse {
         project_id: project_id.to_string(),
         trade: req.trade,
-        document,
+        document: document.output,
+        model: document.model,
     };
 
-    Ok(Json(DataResponse::new(response)))
+    Ok((quota.headers(), Json(DataResponse::new(response))))
 }
 
 // =============================================================================
@@ -219,7 +288,7 @@ pub async fn generate_tender_scope_doc(
 ///
 /// POST /api/projects/:project_id/ai/qna
 pub async fn ask_question(
-    _auth: RequireAuth,
+    auth: RequireAuth,
     Path(project_id): Path<Uuid>,
     headers: HeaderMap,
     State(state): State<Arc<AppState>>,
@@ -235,34 +304,59 @@ pub async fn ask_question(
     if req.document_text.is_none() {
         if let Some(cached) = state.cache.get::<QnAResponse>(&cache_key).await {
             tracing::debug!(project_id = %project_id, "Returning cached Q&A response");
-            return Ok(Json(DataResponse::new(cached)));
+            return Ok((HeaderMap::new(), Json(DataResponse::new(cached))));
         }
     }
 
     // Call AI service
-    let response = state
-        .ai_client
-        .ask_question(
-            project_id,
-            &req.question,
-            req.document_id,
-            req.document_text.as_deref(),
-            request_id.as_deref(),
+    let compute = async {
+        let mut quota = ai_quota::reserve(
+            &state.db,
+            &state.cache,
+            &state.settings.ai_quotas,
+            auth.user_id,
+            Some(project_id),
         )
         .await?;
+        let call = AiCall::start(auth.user_id, Some(project_id), AiOperation::Qna);
+        let result = state
+            .ai_client
+            .ask_question(
+                project_id,
+                &req.question,
+                req.document_id,
+                req.document_text.as_deref(),
+                request_id.as_deref(),
+            )
+            .await;
+        let result = quota.settle(&state.cache, result).await;
+        let response = call.finish(&state.db, result).await?.output;
+
+        // Cache the result (only if using RAG, not direct text)
+        if req.document_text.is_none() {
+            if let Err(e) = state
+                .cache
+                .set_in(CacheNamespace::Ai, &cache_key, &response)
+                .await
+            {
+                tracing::warn!(error = %e, "Failed to cache Q&A response");
+            }
+        }
 
-    // Cache the result (only if using RAG, not direct text)
-    if req.document_text.is_none() {
-        if let Err(e) = state
-            .cache
-            .set_with_ttl(&cache_key, &response, std::time::Duration::from_secs(3600))
-            .await
-        {
-            tracing::warn!(error = %e, "Failed to cache Q&A response");
+        Ok((quota.headers(), response))
+    };
+
+    // Cacheable answers are computed once for concurrent identical questions
+    let (headers, response) = if req.document_text.is_none() {
+        match single_flight::run(&state.cache, &cache_key, state.settings.ai_timeouts.qna, compute).await? {
+            Flight::Shared(shared) => (HeaderMap::new(), shared),
+            Flight::Computed(computed) => computed,
         }
-    }
+    } else {
+        compute.await?
+    };
 
-    Ok(Json(DataResponse::new(response)))
+    Ok((headers, Json(DataResponse::new(response))))
 }
 
 /// Simple MD5 hash for question deduplication (not cryptographic).
@@ -281,25 +375,78 @@ fn md5_hash(input: &str) -> u128 {
 
 /// Invalidate AI caches for a project.
 ///
-/// Called when documents are updated/added.
+/// Called when documents are updated/added. `?operation=summary|trade_scopes|qna`
+/// limits it to one operation, e.g. refreshing Q&A without discarding an
+/// expensive trade scope extraction. Safe to repeat; later calls delete 0 keys.
 ///
 /// DELETE /api/projects/:project_id/ai/cache
 pub async fn invalidate_ai_cache(
     _auth: RequireAuth,
     Path(project_id): Path<Uuid>,
+    Query(query): Query<InvalidateAiCacheQuery>,
     State(state): State<Arc<AppState>>,
 ) -> ApiResult<impl IntoResponse> {
-    let pattern = keys::ai_pattern(project_id);
+    let pattern = match query.operation {
+        Some(operation) => keys::ai_operation_pattern(operation.key_segment(), project_id),
+        None => keys::ai_pattern(project_id),
+    };
     let deleted = state.cache.delete_pattern(&pattern).await.unwrap_or(0);
 
     tracing::info!(
         project_id = %project_id,
+        operation = ?query.operation,
         deleted = deleted,
         "AI cache invalidated"
     );
 
-    Ok(Json(serde_json::json!({
+    Ok(DataResponse::new(serde_json::json!({
         "project_id": project_id.to_string(),
+        "operation": query.operation,
         "deleted_keys": deleted
     })))
 }
+
+// =============================================================================
+// Usage
+// =============================================================================
+
+/// Most rows in each usage breakdown
+const USAGE_BREAKDOWN_LIMIT: i64 = 50;
+
+/// AI usage of a project: totals and breakdowns by operation and user.
+/// `?since=` / `?until=` (RFC 3339) bound the range. Project owner only.
+///
+/// GET /api/projects/:project_id/ai/usage
+pub async fn get_project_ai_usage(
+    auth: RequireAuth,
+    Path(project_id): Path<Uuid>,
+    Query(query): Query<AiUsageQuery>,
+    State(state): State<Arc<AppState>>,
+) -> ApiResult<impl IntoResponse> {
+    let owner: Option<Uuid> = sqlx::query_scalar("SELECT owner_id FROM projects WHERE id = $1")
+        .bind(project_id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(map_db_err("get_project_ai_usage"))?;
+    if owner != Some(auth.user_id) {
+        return Err(ApiError::forbidden("You don't have access to this project"));
+    }
+
+    let (since, until) = (query.since, query.until);
+    let breakdown = |grouping| {
+        ai_usage::breakdown(&state.db, grouping, Some(project_id), since, until, USAGE_BREAKDOWN_LIMIT)
+    };
+
+    let (totals, by_operation, by_user) = tokio::try_join!(
+        ai_usage::totals(&state.db, Some(project_id), since, until),
+        breakdown(UsageGrouping::Operation),
+        breakdown(UsageGrouping::User),
+    )?;
+
+    Ok(Json(DataResponse::new(ProjectAiUsage {
+        project_id,
+        totals,
+        by_operation,
+        by_user,
+    })))
+}
@@ -1,31 +1,66 @@
 //! Authentication routes
 //!
-//! These routes proxy authentication requests to Supabase Auth.
+//! These routes proxy authentication requests to Supabase Auth. Sign-up,
+//! sign-in and refresh are throttled per client IP, and sign-in locks an
+//! email after repeated failures (see `services::auth_throttle`). Each
+//! sign-in starts a tracked session whose refresh token is rotated on every
+//! refresh and revoked on sign-out (see `services::auth_sessions`).
 
 use axum::{
-    extract::State,
-    http::StatusCode,
+    extract::{Path, State},
+    http::{header, HeaderMap, StatusCode},
     response::IntoResponse,
     Json,
 };
 use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
 
-use crate::api::response::DataResponse;
+use crate::api::response::{DataResponse, NoContent};
 use crate::app::AppState;
 use crate::auth::RequireAuth;
 use crate::domain::auth::{
-    AuthResponse, RefreshTokenRequest, SessionResponse, SignInRequest, SignUpRequest,
+    AuthResponse, AuthSessionResponse, RefreshTokenRequest, RevokedSessions, SessionResponse, SignInRequest, SignOutRequest, SignUpRequest,
     SignupPendingResponse, SupabaseAuthResponse, SupabaseErrorResponse, SupabaseSignupResponse, User,
 };
-use crate::error::ApiError;
+use crate::error::{map_db_err, ApiError};
+use crate::middleware::ExtractClientIp;
+use crate::services::auth_sessions::{self, ClientInfo, RevokeReason};
+use crate::services::auth_throttle::{self, AuthEndpoint};
+
+fn revocation_ttl(state: &AppState) -> Duration {
+    Duration::from_secs(state.settings.refresh_token_revocation_ttl_seconds)
+}
+
+/// Longest user agent kept for a session
+const MAX_USER_AGENT_LEN: usize = 500;
+
+fn client_info(ip_address: Option<String>, headers: &HeaderMap) -> ClientInfo {
+    let user_agent = headers
+        .get(header::USER_AGENT)
+        .and_then(|v| v.to_str().ok())
+        .map(|ua| ua.chars().take(MAX_USER_AGENT_LEN).collect());
+    ClientInfo { ip_address, user_agent }
+}
+
+/// Supabase session behind an access token the auth service just issued
+async fn issued_session_id(state: &AppState, access_token: &str) -> Option<Uuid> {
+    let claims = state.jwks_cache.verify_token(access_token).await.ok()?;
+    claims.session_id?.parse().ok()
+}
 
 /// POST /api/auth/signup
 /// 
 /// Register a new user with Supabase and create a profile.
 pub async fn sign_up(
     State(state): State<Arc<AppState>>,
+    ExtractClientIp(client_ip): ExtractClientIp,
+    headers: HeaderMap,
     Json(req): Json<SignUpRequest>,
 ) -> Result<impl IntoResponse, ApiError> {
+    let limits = &state.settings.auth_rate_limits;
+    auth_throttle::check_ip(&state.cache, limits, AuthEndpoint::SignUp, client_ip.as_deref()).await?;
+
     // Prepare the Supabase signup request with user metadata
     let supabase_req = serde_json::json!({
         "email": req.email,
@@ -49,6 +84,11 @@ pub async fn sign_up(
         .map_err(|e| ApiError::internal(format!("Failed to connect to auth service: {}", e)))?;
 
     if !response.status().is_success() {
+        // Auth service messages can say the email is already registered;
+        // answer every rejected sign-up the same way
+        if response.status().is_client_error() {
+            return Err(ApiError::bad_request("Could not create an account with these details"));
+        }
         let error: SupabaseErrorResponse = response.json().await.unwrap_or_else(|_| {
             SupabaseErrorResponse {
                 code: None,
@@ -95,7 +135,11 @@ pub async fn sign_up(
         .bind(&req.company_name)
         .execute(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Failed to create profile: {}", e)))?;
+        .map_err(map_db_err("sign_up"))?;
+
+        let auth_session_id = issued_session_id(&state, &auth_response.access_token).await;
+        let client = client_info(client_ip, &headers);
+        auth_sessions::start(&state.db, user_id, &auth_response.refresh_token, auth_session_id, &client).await?;
 
         let user: User = auth_response.user.into();
         let response = AuthResponse {
@@ -131,23 +175,30 @@ pub async fn sign_up(
         .bind(&req.company_name)
         .execute(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Failed to create profile: {}", e)))?;
+        .map_err(map_db_err("sign_up"))?;
 
         let pending_response: SignupPendingResponse = signup_response.into();
         return Ok((StatusCode::CREATED, Json(serde_json::to_value(DataResponse::new(pending_response)).unwrap())));
     }
 
     // Neither format matched
-    Err(ApiError::internal(format!("Failed to parse auth response: unexpected format")))
+    Err(ApiError::internal("Failed to parse auth response: unexpected format".to_string()))
 }
 
 /// POST /api/auth/signin
 /// 
-/// Sign in with email and password.
+/// Sign in with email and password. Repeated failures for the same email
+/// lock it out for a while, answered with 429 and `Retry-After`.
 pub async fn sign_in(
     State(state): State<Arc<AppState>>,
+    ExtractClientIp(client_ip): ExtractClientIp,
+    headers: HeaderMap,
     Json(req): Json<SignInRequest>,
 ) -> Result<impl IntoResponse, ApiError> {
+    let limits = &state.settings.auth_rate_limits;
+    auth_throttle::check_ip(&state.cache, limits, AuthEndpoint::SignIn, client_ip.as_deref()).await?;
+    auth_throttle::check_lockout(&state.cache, limits, &req.email).await?;
+
     let response = state
         .http_client
         .post(format!(
@@ -165,6 +216,12 @@ pub async fn sign_in(
         .map_err(|e| ApiError::internal(format!("Failed to connect to auth service: {}", e)))?;
 
     if !response.status().is_success() {
+        // Auth service messages can tell unknown from unconfirmed accounts;
+        // answer every rejected sign-in the same way
+        if response.status().is_client_error() {
+            auth_throttle::record_failed_sign_in(&state.cache, limits, &req.email, client_ip.as_deref()).await;
+            return Err(ApiError::unauthorized("Invalid email or password"));
+        }
         let error: SupabaseErrorResponse = response.json().await.unwrap_or_else(|_| {
             SupabaseErrorResponse {
                 code: None,
@@ -178,6 +235,8 @@ pub async fn sign_in(
         return Err(ApiError::unauthorized(error.get_message()));
     }
 
+    auth_throttle::record_successful_sign_in(&state.cache, &req.email).await;
+
     let auth_response: SupabaseAuthResponse = response.json().await.map_err(|e| {
         ApiError::internal(format!("Failed to parse auth response: {}", e))
     })?;
@@ -207,7 +266,11 @@ pub async fn sign_in(
     .bind(user_type_str)
     .execute(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Failed to ensure profile: {}", e)))?;
+    .map_err(map_db_err("sign_in"))?;
+
+    let auth_session_id = issued_session_id(&state, &auth_response.access_token).await;
+    let client = client_info(client_ip, &headers);
+    auth_sessions::start(&state.db, user_id, &auth_response.refresh_token, auth_session_id, &client).await?;
 
     let user: User = auth_response.user.into();
     let response = AuthResponse {
@@ -222,11 +285,24 @@ pub async fn sign_in(
 
 /// POST /api/auth/signout
 /// 
-/// Sign out the current user.
+/// Sign out the current user and revoke the session's refresh token. The
+/// session is found from the access token, or from an optional
+/// `refresh_token` in the body.
 pub async fn sign_out(
     State(state): State<Arc<AppState>>,
     auth: RequireAuth,
+    input: Option<Json<SignOutRequest>>,
 ) -> Result<impl IntoResponse, ApiError> {
+    let input = input.map(|Json(i)| i).unwrap_or_default();
+
+    let auth_session_id = auth.claims().session_id.as_deref().and_then(|s| s.parse().ok());
+    let session_id =
+        auth_sessions::find_active(&state.db, auth.user_id, auth_session_id, input.refresh_token.as_deref()).await?;
+    if let Some(session_id) = session_id {
+        auth_sessions::revoke(&state.db, &state.cache, revocation_ttl(&state), session_id, RevokeReason::SignOut)
+            .await?;
+    }
+
     // Call Supabase logout endpoint
     let _ = state
         .http_client
@@ -236,7 +312,7 @@ pub async fn sign_out(
         .send()
         .await;
 
-    Ok(StatusCode::NO_CONTENT)
+    Ok(NoContent)
 }
 
 /// GET /api/auth/session
@@ -281,8 +357,21 @@ pub async fn get_session(
 /// Refresh the access token.
 pub async fn refresh_token(
     State(state): State<Arc<AppState>>,
+    ExtractClientIp(client_ip): ExtractClientIp,
+    headers: HeaderMap,
     Json(req): Json<RefreshTokenRequest>,
 ) -> Result<impl IntoResponse, ApiError> {
+    auth_throttle::check_ip(
+        &state.cache,
+        &state.settings.auth_rate_limits,
+        AuthEndpoint::Refresh,
+        client_ip.as_deref(),
+    )
+    .await?;
+
+    let ttl = revocation_ttl(&state);
+    let session_id = auth_sessions::check_refresh(&state.db, &state.cache, &state.supabase_auth, ttl, &req.refresh_token).await?;
+
     let response = state
         .http_client
         .post(format!(
@@ -316,6 +405,24 @@ pub async fn refresh_token(
         ApiError::internal(format!("Failed to parse auth response: {}", e))
     })?;
 
+    let user_id: Uuid = auth_response.user.id.parse().map_err(|_| {
+        ApiError::internal("Invalid user ID from auth service")
+    })?;
+    let auth_session_id = issued_session_id(&state, &auth_response.access_token).await;
+    auth_sessions::rotate(
+        &state.db,
+        &state.cache,
+        &state.supabase_auth,
+        ttl,
+        session_id,
+        user_id,
+        &req.refresh_token,
+        &auth_response.refresh_token,
+        auth_session_id,
+        &client_info(client_ip, &headers),
+    )
+    .await?;
+
     let user: User = auth_response.user.into();
     let response = AuthResponse {
         access_token: auth_response.access_token,
@@ -326,3 +433,150 @@ pub async fn refresh_token(
 
     Ok(Json(DataResponse::new(response)))
 }
+
+/// Database row for an auth session
+#[derive(Debug, sqlx::FromRow)]
+struct AuthSessionRow {
+    id: Uuid,
+    auth_session_id: Option<Uuid>,
+    user_agent: Option<String>,
+    ip_address: Option<String>,
+    created_at: chrono::DateTime<chrono::Utc>,
+    last_seen_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// GET /api/auth/sessions
+///
+/// List the current user's active sessions (device, IP, when it signed in and
+/// was last used), most recently used first.
+pub async fn list_sessions(
+    State(state): State<Arc<AppState>>,
+    auth: RequireAuth,
+) -> Result<impl IntoResponse, ApiError> {
+    let rows = sqlx::query_as::<_, AuthSessionRow>(
+        r#"
+        SELECT id, auth_session_id, user_agent, ip_address, created_at,
+               COALESCE(last_refreshed_at, created_at) as last_seen_at
+        FROM auth_sessions
+        WHERE user_id = $1 AND revoked_at IS NULL
+        ORDER BY last_seen_at DESC, id
+        "#,
+    )
+    .bind(auth.user_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(map_db_err("list_sessions"))?;
+
+    let current: Option<Uuid> = auth.claims().session_id.as_deref().and_then(|s| s.parse().ok());
+    let sessions: Vec<AuthSessionResponse> = rows
+        .into_iter()
+        .map(|row| AuthSessionResponse {
+            id: row.id,
+            user_agent: row.user_agent,
+            ip_address: row.ip_address,
+            created_at: row.created_at,
+            last_seen_at: row.last_seen_at,
+            current: current.is_some() && row.auth_session_id == current,
+        })
+        .collect();
+
+    Ok(Json(DataResponse::new(sessions)))
+}
+
+/// DELETE /api/auth/sessions/:session_id
+///
+/// Revoke one of the current user's sessions so its refresh token stops
+/// working, here and at Supabase. Access tokens already issued to it last
+/// until they expire.
+pub async fn revoke_session(
+    State(state): State<Arc<AppState>>,
+    auth: RequireAuth,
+    Path(session_id): Path<Uuid>,
+) -> Result<impl IntoResponse, ApiError> {
+    let session: Option<Option<Uuid>> = sqlx::query_scalar(
+        "SELECT auth_session_id FROM auth_sessions WHERE id = $1 AND user_id = $2 AND revoked_at IS NULL",
+    )
+    .bind(session_id)
+    .bind(auth.user_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("revoke_session"))?;
+
+    let auth_session_id = session.ok_or_else(|| ApiError::not_found("Session not found"))?;
+
+    // End it at Supabase first, so a failure leaves the session listed to retry.
+    // Sessions started before their Supabase id was recorded can only be
+    // revoked here.
+    if let Some(auth_session_id) = auth_session_id {
+        state.supabase_auth.end_session(auth_session_id).await?;
+    }
+
+    auth_sessions::revoke(&state.db, &state.cache, revocation_ttl(&state), session_id, RevokeReason::User).await?;
+
+    Ok(NoContent)
+}
+
+/// DELETE /api/auth/sessions
+///
+/// Sign out everywhere else: revoke all of the current user's sessions except
+/// the one making the request, here and at Supabase.
+pub async fn revoke_other_sessions(
+    State(state): State<Arc<AppState>>,
+    auth: RequireAuth,
+) -> Result<impl IntoResponse, ApiError> {
+    state.supabase_auth.sign_out_others(auth.token()).await?;
+
+    let auth_session_id = auth.claims().session_id.as_deref().and_then(|s| s.parse().ok());
+    let current = auth_sessions::find_active(&state.db, auth.user_id, auth_session_id, None).await?;
+
+    let revoked = auth_sessions::revoke_all(
+        &state.db,
+        &state.cache,
+        revocation_ttl(&state),
+        auth.user_id,
+        current,
+        RevokeReason::User,
+    )
+    .await?;
+
+    Ok(Json(DataResponse::new(RevokedSessions { revoked })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::routing::post;
+
+    use crate::domain::auth::UserType;
+    use crate::test_support;
+
+    #[tokio::test]
+    async fn sign_up_does_not_reveal_registered_emails() {
+        let Some(state) = test_support::state().await else { return };
+        let router = axum::Router::new().route(
+            "/auth/v1/signup",
+            post(|| async {
+                (StatusCode::UNPROCESSABLE_ENTITY, Json(serde_json::json!({ "msg": "User already registered" })))
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+
+        let mut state = (*state).clone();
+        state.settings.supabase_url = format!("http://{}", addr);
+        let req = SignUpRequest {
+            email: format!("{}@test.example", Uuid::new_v4()),
+            password: "correct horse battery staple".to_string(),
+            user_type: UserType::Gc,
+            company_name: None,
+        };
+
+        let err = sign_up(State(Arc::new(state)), ExtractClientIp(None), HeaderMap::new(), Json(req))
+            .await
+            .err()
+            .expect("sign-up should fail");
+
+        assert!(matches!(&err, ApiError::BadRequest(m) if m == "Could not create an account with these details"), "{:?}", err);
+    }
+}
@@ -0,0 +1,337 @@
+//! Bid attachment routes
+//!
+//! Subcontractors attach a formal proposal or credentials to their bid while
+//! the tender is still taking bids. Files are kept outside the public media
+//! root and are only reachable through time-limited signed links issued to
+//! the bidder and the tender owner. Deleting an attachment row (here, on
+//! withdrawal, or by cascade) queues its file for deletion.
+
+use axum::{
+    body::Body,
+    extract::{Multipart, Path, Query, Request, State},
+    http::{header, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::fs;
+use tower::ServiceExt;
+use tower_http::services::ServeFile;
+use uuid::Uuid;
+
+use crate::api::response::{Created, DataResponse, NoContent};
+use crate::app::AppState;
+use crate::auth::RequireSub;
+use crate::domain::bids::BidAttachmentResponse;
+use crate::domain::tenders::SignedDownloadQuery;
+use crate::error::{map_db_err, ApiError};
+use crate::routes::marketplace::{assert_tender_biddable, fetch_biddable_tender};
+use crate::services::file_deletions;
+
+/// Largest bid attachment accepted
+pub const MAX_ATTACHMENT_BYTES: usize = 25 * 1024 * 1024;
+
+/// Most attachments on one bid
+const MAX_ATTACHMENTS_PER_BID: i64 = 10;
+
+/// Content types accepted for bid attachments
+const ALLOWED_MIME_TYPES: &[&str] = &[
+    "application/pdf",
+    "application/msword",
+    "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+    "image/jpeg",
+    "image/png",
+    "image/webp",
+];
+
+#[derive(Debug, sqlx::FromRow)]
+struct BidAttachmentRow {
+    id: Uuid,
+    bid_id: Uuid,
+    file_name: String,
+    file_size: i64,
+    mime_type: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+fn download_path(attachment_id: Uuid) -> String {
+    format!("/bid-attachments/{}/download", attachment_id)
+}
+
+fn to_response(state: &AppState, viewer_id: Uuid, row: BidAttachmentRow) -> BidAttachmentResponse {
+    let signed = state.url_signer.sign(&download_path(row.id), viewer_id);
+
+    BidAttachmentResponse {
+        id: row.id,
+        file_name: row.file_name,
+        file_size: row.file_size,
+        mime_type: row.mime_type,
+        download_url: signed.url,
+        download_expires_at: signed.expires_at,
+        created_at: row.created_at,
+    }
+}
+
+/// The caller's bid on `tender_id`: its id and status
+async fn fetch_own_bid(state: &AppState, tender_id: Uuid, user_id: Uuid) -> Result<(Uuid, String), ApiError> {
+    sqlx::query_as(
+        r#"
+        SELECT b.id, b.status FROM bids b
+        JOIN subcontractors s ON b.subcontractor_id = s.id
+        WHERE b.tender_id = $1 AND s.profile_id = $2
+        "#,
+    )
+    .bind(tender_id)
+    .bind(user_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("fetch_own_bid"))?
+    .ok_or_else(|| ApiError::not_found("You have not bid on this tender"))
+}
+
+/// The caller's live bid on a tender that is still taking bids
+async fn fetch_editable_bid(state: &AppState, tender_id: Uuid, user_id: Uuid) -> Result<Uuid, ApiError> {
+    let tender = fetch_biddable_tender(state, tender_id).await?;
+    assert_tender_biddable(&tender)?;
+
+    match fetch_own_bid(state, tender_id, user_id).await? {
+        (bid_id, status) if status == "submitted" => Ok(bid_id),
+        _ => Err(ApiError::bad_request("Attachments can only be changed on a submitted bid")),
+    }
+}
+
+/// Attachments on each of `bid_ids`, with download links for `viewer_id`.
+/// Bids without attachments are absent from the map.
+pub(crate) async fn attachments_by_bid(
+    state: &AppState,
+    bid_ids: &[Uuid],
+    viewer_id: Uuid,
+) -> Result<HashMap<Uuid, Vec<BidAttachmentResponse>>, ApiError> {
+    let rows = sqlx::query_as::<_, BidAttachmentRow>(
+        r#"
+        SELECT id, bid_id, file_name, file_size, mime_type, created_at
+        FROM bid_attachments
+        WHERE bid_id = ANY($1)
+        ORDER BY created_at, id
+        "#,
+    )
+    .bind(bid_ids)
+    .fetch_all(&state.db)
+    .await
+    .map_err(map_db_err("attachments_by_bid"))?;
+
+    let mut attachments: HashMap<Uuid, Vec<BidAttachmentResponse>> = HashMap::new();
+    for row in rows {
+        attachments
+            .entry(row.bid_id)
+            .or_default()
+            .push(to_response(state, viewer_id, row));
+    }
+    Ok(attachments)
+}
+
+/// GET /api/marketplace/tenders/:tender_id/bid/attachments
+///
+/// Attachments on the caller's bid, oldest first.
+pub async fn list_my_bid_attachments(
+    State(state): State<Arc<AppState>>,
+    Path(tender_id): Path<Uuid>,
+    auth: RequireSub,
+) -> Result<impl IntoResponse, ApiError> {
+    let (bid_id, _) = fetch_own_bid(&state, tender_id, auth.user_id).await?;
+
+    let attachments = attachments_by_bid(&state, &[bid_id], auth.user_id)
+        .await?
+        .remove(&bid_id)
+        .unwrap_or_default();
+
+    Ok(Json(DataResponse::new(attachments)))
+}
+
+/// POST /api/marketplace/tenders/:tender_id/bid/attachments
+///
+/// Multipart upload with a `file` (PDF, Word document or image). Only the
+/// bidder can attach, and only while the tender is taking bids.
+pub async fn upload_bid_attachment(
+    State(state): State<Arc<AppState>>,
+    Path(tender_id): Path<Uuid>,
+    auth: RequireSub,
+    mut multipart: Multipart,
+) -> Result<impl IntoResponse, ApiError> {
+    let bid_id = fetch_editable_bid(&state, tender_id, auth.user_id).await?;
+
+    let attached: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM bid_attachments WHERE bid_id = $1")
+        .bind(bid_id)
+        .fetch_one(&state.db)
+        .await
+        .map_err(map_db_err("upload_bid_attachment"))?;
+    if attached >= MAX_ATTACHMENTS_PER_BID {
+        return Err(ApiError::bad_request(format!(
+            "A bid can have at most {} attachments",
+            MAX_ATTACHMENTS_PER_BID
+        )));
+    }
+
+    let mut file_data: Option<Vec<u8>> = None;
+    let mut file_name: Option<String> = None;
+    let mut content_type: Option<String> = None;
+
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| ApiError::bad_request(format!("Failed to read multipart: {}", e)))?
+    {
+        if field.name() == Some("file") {
+            file_name = field.file_name().map(|s| s.to_string());
+            content_type = field.content_type().map(|s| s.to_string());
+            file_data = Some(
+                field
+                    .bytes()
+                    .await
+                    .map_err(|e| ApiError::bad_request(format!("Failed to read file: {}", e)))?
+                    .to_vec(),
+            );
+        }
+    }
+
+    let file_data = file_data.ok_or_else(|| ApiError::bad_request("No file provided in upload"))?;
+    let file_name = file_name.ok_or_else(|| ApiError::bad_request("No filename provided in upload"))?;
+
+    let mime = content_type.unwrap_or_else(|| "application/octet-stream".to_string());
+    if !ALLOWED_MIME_TYPES.contains(&mime.as_str()) {
+        return Err(ApiError::bad_request("Bid attachments must be PDF, Word, JPEG, PNG or WebP"));
+    }
+    if file_data.len() > MAX_ATTACHMENT_BYTES {
+        return Err(ApiError::bad_request(format!(
+            "Attachment exceeds the {} MB upload limit",
+            MAX_ATTACHMENT_BYTES / (1024 * 1024)
+        )));
+    }
+
+    let upload_dir = format!("./uploads/bids/{}", bid_id);
+    fs::create_dir_all(&upload_dir)
+        .await
+        .map_err(|e| ApiError::internal(format!("Failed to create upload directory: {}", e)))?;
+
+    let safe_filename: String = file_name
+        .chars()
+        .filter(|c| c.is_alphanumeric() || matches!(c, '.' | '-' | '_'))
+        .collect();
+    let file_path = format!("{}/{}_{}", upload_dir, Uuid::new_v4(), safe_filename);
+    fs::write(&file_path, &file_data)
+        .await
+        .map_err(|e| ApiError::internal(format!("Failed to write file: {}", e)))?;
+
+    let row = sqlx::query_as::<_, BidAttachmentRow>(
+        r#"
+        INSERT INTO bid_attachments (bid_id, file_name, file_path, file_size, mime_type, uploaded_by)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        RETURNING id, bid_id, file_name, file_size, mime_type, created_at
+        "#,
+    )
+    .bind(bid_id)
+    .bind(&file_name)
+    .bind(&file_path)
+    .bind(file_data.len() as i64)
+    .bind(&mime)
+    .bind(auth.user_id)
+    .fetch_one(&state.db)
+    .await;
+
+    let row = match row {
+        Ok(row) => row,
+        Err(e) => {
+            let _ = fs::remove_file(&file_path).await;
+            return Err(map_db_err("upload_bid_attachment")(e));
+        }
+    };
+
+    Ok(Created(to_response(&state, auth.user_id, row)))
+}
+
+/// DELETE /api/marketplace/tenders/:tender_id/bid/attachments/:attachment_id
+///
+/// Remove an attachment from the caller's bid while the tender is taking bids.
+pub async fn delete_bid_attachment(
+    State(state): State<Arc<AppState>>,
+    Path((tender_id, attachment_id)): Path<(Uuid, Uuid)>,
+    auth: RequireSub,
+) -> Result<impl IntoResponse, ApiError> {
+    let bid_id = fetch_editable_bid(&state, tender_id, auth.user_id).await?;
+
+    let file_path: Option<String> =
+        sqlx::query_scalar("DELETE FROM bid_attachments WHERE id = $1 AND bid_id = $2 RETURNING file_path")
+            .bind(attachment_id)
+            .bind(bid_id)
+            .fetch_optional(&state.db)
+            .await
+            .map_err(map_db_err("delete_bid_attachment"))?;
+
+    let file_path = file_path.ok_or_else(|| ApiError::not_found("Attachment not found"))?;
+    file_deletions::delete_queued_now(&state.db, &[file_path]).await;
+
+    Ok(NoContent)
+}
+
+/// GET /api/bid-attachments/:attachment_id/download
+///
+/// Follow a signed download link. Authenticated by the link's signature; the
+/// link's user must still be the bidder or the tender's project owner.
+pub async fn download_bid_attachment(
+    State(state): State<Arc<AppState>>,
+    Path(attachment_id): Path<Uuid>,
+    Query(link): Query<SignedDownloadQuery>,
+    request: Request,
+) -> Result<Response, ApiError> {
+    state
+        .url_signer
+        .verify(&download_path(attachment_id), link.user, link.expires, &link.signature)?;
+
+    let attachment: Option<(String, String, Option<String>, bool)> = sqlx::query_as(
+        r#"
+        SELECT ba.file_name, ba.file_path, ba.mime_type,
+               (p.owner_id = $2 OR COALESCE(s.profile_id = $2, false)) as allowed
+        FROM bid_attachments ba
+        JOIN bids b ON ba.bid_id = b.id
+        JOIN tenders t ON b.tender_id = t.id
+        JOIN projects p ON t.project_id = p.id
+        LEFT JOIN subcontractors s ON b.subcontractor_id = s.id
+        WHERE ba.id = $1
+        "#,
+    )
+    .bind(attachment_id)
+    .bind(link.user)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("download_bid_attachment"))?;
+
+    let (name, file_path, mime_type) = match attachment {
+        Some((name, file_path, mime_type, true)) => (name, file_path, mime_type),
+        _ => return Err(ApiError::not_found("Attachment not found")),
+    };
+
+    let mut response = ServeFile::new(&file_path)
+        .oneshot(request)
+        .await
+        .map_err(|e| ApiError::internal(format!("Failed to read attachment: {}", e)))?
+        .map(Body::new);
+
+    if response.status() == StatusCode::NOT_FOUND {
+        return Err(ApiError::not_found("Attachment file is missing"));
+    }
+
+    if let Some(mime) = mime_type.and_then(|m| HeaderValue::from_str(&m).ok()) {
+        response.headers_mut().insert(header::CONTENT_TYPE, mime);
+    }
+    let safe_name: String = name
+        .chars()
+        .filter(|c| c.is_alphanumeric() || matches!(c, '.' | '-' | '_' | ' '))
+        .collect();
+    if let Ok(disposition) = HeaderValue::from_str(&format!("attachment; filename=\"{}\"", safe_name)) {
+        response.headers_mut().insert(header::CONTENT_DISPOSITION, disposition);
+    }
+
+    Ok(response)
+}
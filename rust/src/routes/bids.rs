@@ -4,21 +4,22 @@
 
 use axum::{
     extract::{Path, Query, State},
-    http::StatusCode,
     response::IntoResponse,
     Json,
 };
 use chrono::{DateTime, Utc};
-use rust_decimal::prelude::*;
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::api::pagination::PaginationParams;
-use crate::api::response::{DataResponse, Paginated};
+use crate::api::pagination::{resolve_pagination, PaginationParams};
+use crate::api::sort::{SortFields, SortOrder, SortSpec};
+use crate::api::response::{Created, Paginated};
 use crate::app::AppState;
 use crate::auth::RequireAuth;
-use crate::domain::bids::{BidResponse, BidStatus, CreateBidRequest};
-use crate::error::ApiError;
+use crate::domain::bids::{BidListQuery, BidResponse, BidStatus, CreateBidRequest};
+use crate::domain::money::Money;
+use crate::error::{map_db_err, ApiError};
+use crate::routes::bid_attachments;
 
 /// Database row for bid
 #[allow(dead_code)]
@@ -31,7 +32,7 @@ struct BidRow {
     contact_name: Option<String>,
     contact_email: Option<String>,
     contact_phone: Option<String>,
-    bid_amount: rust_decimal::Decimal,
+    bid_amount: Money,
     status: String,
     notes: Option<String>,
     submitted_at: Option<DateTime<Utc>>,
@@ -51,11 +52,6 @@ impl From<BidRow> for BidResponse {
             _ => BidStatus::Draft,
         };
 
-        // Convert decimal to cents
-        let bid_amount = (row.bid_amount * rust_decimal::Decimal::from(100))
-            .to_i64()
-            .unwrap_or(0);
-
         Self {
             id: row.id,
             tender_id: row.tender_id,
@@ -63,16 +59,29 @@ impl From<BidRow> for BidResponse {
             contact_name: row.contact_name,
             contact_email: row.contact_email,
             contact_phone: row.contact_phone,
-            bid_amount,
+            bid_amount: row.bid_amount,
             status,
             notes: row.notes,
             submitted_at: row.submitted_at,
             created_at: row.created_at,
             updated_at: row.updated_at,
+            attachments: Vec::new(),
         }
     }
 }
 
+fn bid_status_to_string(status: &BidStatus) -> &'static str {
+    match status {
+        BidStatus::Draft => "draft",
+        BidStatus::Submitted => "submitted",
+        BidStatus::UnderReview => "under_review",
+        BidStatus::Shortlisted => "shortlisted",
+        BidStatus::Awarded => "awarded",
+        BidStatus::Rejected => "rejected",
+        BidStatus::Withdrawn => "withdrawn",
+    }
+}
+
 /// POST /api/tenders/:tender_id/bids
 ///
 /// Submit a bid for a tender.
@@ -86,7 +95,7 @@ pub async fn create_bid(
         user_id = %auth.user_id,
         tender_id = %tender_id,
         company_name = %req.company_name,
-        bid_amount = req.bid_amount,
+        bid_amount = %req.bid_amount,
         "Creating bid"
     );
 
@@ -96,7 +105,7 @@ pub async fn create_bid(
             .bind(tender_id)
             .fetch_optional(&state.db)
             .await
-            .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+            .map_err(map_db_err("create_bid"))?;
 
     match tender_status.as_deref() {
         None => return Err(ApiError::not_found("Tender not found")),
@@ -106,10 +115,6 @@ pub async fn create_bid(
         _ => {}
     }
 
-    // Convert cents to decimal
-    let bid_amount =
-        rust_decimal::Decimal::from(req.bid_amount) / rust_decimal::Decimal::from(100);
-
     let bid = sqlx::query_as::<_, BidRow>(
         r#"
         INSERT INTO bids (tender_id, bidder_id, company_name, contact_name, contact_email, contact_phone, bid_amount, status, notes, submitted_at)
@@ -123,24 +128,36 @@ pub async fn create_bid(
     .bind(&req.contact_name)
     .bind(&req.contact_email)
     .bind(&req.contact_phone)
-    .bind(bid_amount)
+    .bind(req.bid_amount)
     .bind(&req.notes)
     .fetch_one(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Failed to create bid: {}", e)))?;
+    .map_err(map_db_err("create_bid"))?;
 
     let response: BidResponse = bid.into();
-    Ok((StatusCode::CREATED, Json(DataResponse::new(response))))
+    Ok(Created(response))
 }
 
+/// `sort_by` keys for a tender's bids
+const BID_SORT_FIELDS: SortFields = &[
+    ("amount", "bid_amount"),
+    ("timeline", "proposed_timeline_days"),
+    ("submitted_at", "submitted_at"),
+];
+
 /// GET /api/tenders/:tender_id/bids
 ///
 /// List bids for a tender. Only the tender owner (project owner) can see all bids.
+/// Each bid carries its attachments with download links for the owner.
+///
+/// Supports `status`, `sort_by` (amount, timeline, submitted_at), `sort_order`,
+/// and `include_withdrawn` (withdrawn bids are hidden by default).
 pub async fn list_bids(
     auth: RequireAuth,
     State(state): State<Arc<AppState>>,
     Path(tender_id): Path<Uuid>,
     Query(pagination): Query<PaginationParams>,
+    Query(query): Query<BidListQuery>,
 ) -> Result<impl IntoResponse, ApiError> {
     tracing::info!(
         user_id = %auth.user_id,
@@ -162,7 +179,7 @@ pub async fn list_bids(
     .bind(auth.user_id)
     .fetch_one(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("list_bids"))?;
 
     if !is_owner {
         return Err(ApiError::forbidden(
@@ -170,33 +187,70 @@ pub async fn list_bids(
         ));
     }
 
-    let offset = pagination.offset() as i64;
-    let limit = pagination.limit() as i64;
+    let page = resolve_pagination(&pagination, state.settings.pagination.default)?;
+
+    let status = query.status.as_ref().map(bid_status_to_string);
+    let include_withdrawn = query.include_withdrawn || status == Some("withdrawn");
 
     // Get total count
-    let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM bids WHERE tender_id = $1")
-        .bind(tender_id)
-        .fetch_one(&state.db)
-        .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    let total: i64 = sqlx::query_scalar(
+        r#"
+        SELECT COUNT(*) FROM bids
+        WHERE tender_id = $1
+        AND ($2::text IS NULL OR status = $2)
+        AND ($3::bool OR status != 'withdrawn')
+        "#,
+    )
+    .bind(tender_id)
+    .bind(status)
+    .bind(include_withdrawn)
+    .fetch_one(&state.db)
+    .await
+    .map_err(map_db_err("list_bids"))?;
+
+    // Cheapest first by default
+    let sort = SortSpec::parse(
+        BID_SORT_FIELDS,
+        SortOrder::Asc,
+        query.sort_by.as_deref(),
+        query.sort_order.as_deref(),
+    )?;
 
     // Get bids
-    let bids = sqlx::query_as::<_, BidRow>(
+    let query_str = format!(
         r#"
         SELECT id, tender_id, bidder_id, company_name, contact_name, contact_email, contact_phone, bid_amount, status, notes, submitted_at, created_at, updated_at
         FROM bids
         WHERE tender_id = $1
-        ORDER BY bid_amount ASC, submitted_at ASC
-        LIMIT $2 OFFSET $3
+        AND ($2::text IS NULL OR status = $2)
+        AND ($3::bool OR status != 'withdrawn')
+        ORDER BY {} NULLS LAST, submitted_at ASC, id
+        LIMIT $4 OFFSET $5
         "#,
-    )
-    .bind(tender_id)
-    .bind(limit)
-    .bind(offset)
-    .fetch_all(&state.db)
-    .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+        sort
+    );
+    let bids = sqlx::query_as::<_, BidRow>(&query_str)
+        .bind(tender_id)
+        .bind(status)
+        .bind(include_withdrawn)
+        .bind(page.limit())
+        .bind(page.offset())
+        .fetch_all(&state.db)
+        .await
+        .map_err(map_db_err("list_bids"))?;
+
+    let bid_ids: Vec<Uuid> = bids.iter().map(|b| b.id).collect();
+    let mut attachments = bid_attachments::attachments_by_bid(&state, &bid_ids, auth.user_id).await?;
 
-    let data: Vec<BidResponse> = bids.into_iter().map(Into::into).collect();
-    Ok(Json(Paginated::new(data, &pagination, total as u64)))
+    let data: Vec<BidResponse> = bids
+        .into_iter()
+        .map(|row| {
+            let bid_attachments = attachments.remove(&row.id).unwrap_or_default();
+            BidResponse {
+                attachments: bid_attachments,
+                ..row.into()
+            }
+        })
+        .collect();
+    Ok(Json(Paginated::new(data, &page, total as u64)))
 }
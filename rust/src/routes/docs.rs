@@ -0,0 +1,123 @@
+//! API documentation routes
+//!
+//! Serves the generated OpenAPI document and a Swagger UI page for it when
+//! `API_DOCS_ENABLED` is set (the default in development). The Swagger UI
+//! assets are served from `SWAGGER_UI_DIR` rather than a CDN.
+
+use axum::{
+    http::header,
+    response::{Html, IntoResponse},
+    routing::get,
+    Router,
+};
+use std::sync::OnceLock;
+use tower_http::services::ServeDir;
+use utoipa::OpenApi;
+
+use crate::api::openapi::ApiDoc;
+use crate::config::Settings;
+
+/// Docs routes, or none when docs are disabled so the endpoints 404
+pub fn router<S>(settings: &Settings) -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    if !settings.api_docs_enabled {
+        return Router::new();
+    }
+
+    Router::new()
+        .route("/openapi.json", get(openapi_json))
+        .route("/docs", get(swagger_ui))
+        .nest_service("/docs/assets", ServeDir::new(&settings.swagger_ui_dir))
+}
+
+/// GET /api/openapi.json
+///
+/// The OpenAPI 3 document for the annotated route groups.
+pub async fn openapi_json() -> impl IntoResponse {
+    static SPEC: OnceLock<String> = OnceLock::new();
+    let spec = SPEC.get_or_init(|| {
+        ApiDoc::openapi()
+            .to_pretty_json()
+            .expect("OpenAPI document serializes")
+    });
+
+    ([(header::CONTENT_TYPE, "application/json")], spec.as_str())
+}
+
+/// GET /api/docs
+///
+/// Swagger UI for `/api/openapi.json`.
+pub async fn swagger_ui() -> impl IntoResponse {
+    Html(
+        r##"<!DOCTYPE html>
+<html lang="en">
+<head>
+  <meta charset="utf-8">
+  <title>BlueprintX API</title>
+  <link rel="stylesheet" href="docs/assets/swagger-ui.css">
+</head>
+<body>
+  <div id="swagger-ui"></div>
+  <script src="docs/assets/swagger-ui-bundle.js"></script>
+  <script>
+    window.ui = SwaggerUIBundle({ url: "openapi.json", dom_id: "#swagger-ui" });
+  </script>
+</body>
+</html>
+"##,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, http::Request, http::StatusCode};
+    use tower::ServiceExt;
+
+    async fn status(settings: &Settings, uri: &str) -> StatusCode {
+        let app = router(settings).with_state(());
+        app.oneshot(Request::get(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+            .status()
+    }
+
+    #[tokio::test]
+    async fn docs_are_absent_when_disabled() {
+        let mut settings = crate::test_support::settings();
+        settings.api_docs_enabled = false;
+
+        for uri in ["/openapi.json", "/docs", "/docs/assets/swagger-ui.css"] {
+            assert_eq!(status(&settings, uri).await, StatusCode::NOT_FOUND, "{}", uri);
+        }
+    }
+
+    #[tokio::test]
+    async fn docs_serve_local_assets_when_enabled() {
+        let dir = std::env::temp_dir().join(format!("swagger-ui-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("swagger-ui.css"), "body {}").unwrap();
+
+        let mut settings = crate::test_support::settings();
+        settings.api_docs_enabled = true;
+        settings.swagger_ui_dir = dir.to_string_lossy().into_owned();
+
+        assert_eq!(status(&settings, "/openapi.json").await, StatusCode::OK);
+        assert_eq!(status(&settings, "/docs").await, StatusCode::OK);
+        assert_eq!(status(&settings, "/docs/assets/swagger-ui.css").await, StatusCode::OK);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn docs_page_loads_no_remote_assets() {
+        let html = swagger_ui().await.into_response();
+        let body = axum::body::to_bytes(html.into_body(), usize::MAX).await.unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+
+        assert!(!body.contains("https://"));
+        assert!(body.contains("docs/assets/swagger-ui-bundle.js"));
+    }
+}
@@ -4,22 +4,26 @@
 
 use axum::{
     extract::{Multipart, Path, Query, State},
-    http::StatusCode,
     response::IntoResponse,
     Json,
 };
 use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
 use std::sync::Arc;
 use tokio::fs;
 use tokio::io::AsyncWriteExt;
 use uuid::Uuid;
 
-use crate::api::pagination::PaginationParams;
-use crate::api::response::{DataResponse, Paginated};
+use crate::api::pagination::{resolve_pagination, PaginationParams};
+use crate::api::response::{Created, DataResponse, Paginated};
 use crate::app::AppState;
 use crate::auth::RequireAuth;
-use crate::domain::{CreateDocumentRequest, DocumentResponse, DocumentStatus, DocumentType};
-use crate::error::ApiError;
+use crate::domain::{
+    CreateDocumentRequest, DeleteDocumentQuery, DocumentDeletionSummary, DocumentListQuery,
+    DocumentResponse, DocumentStatus, DocumentType,
+};
+use crate::error::{map_db_err, ApiError};
+use crate::services::{file_deletions, pdf};
 
 /// Database row for document
 #[allow(dead_code)]
@@ -33,11 +37,13 @@ struct DocumentRow {
     file_path: Option<String>,
     file_size: Option<i64>,
     mime_type: Option<String>,
+    page_count: Option<i32>,
     version: Option<i32>,
     status: String,
     category: Option<String>,
     revised: Option<String>,
     author: Option<String>,
+    supersedes_document_id: Option<Uuid>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
@@ -73,8 +79,10 @@ impl TryFrom<DocumentRow> for DocumentResponse {
             document_type,
             file_size: row.file_size.unwrap_or(0),
             mime_type: row.mime_type.unwrap_or_default(),
+            page_count: row.page_count,
             version: row.version.unwrap_or(1),
             status,
+            supersedes_document_id: row.supersedes_document_id,
             created_at: row.created_at,
             updated_at: row.updated_at,
         })
@@ -93,7 +101,7 @@ async fn verify_project_ownership(
             .bind(user_id)
             .fetch_optional(&state.db)
             .await
-            .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+            .map_err(map_db_err("verify_project_ownership"))?;
 
     if exists.is_none() {
         return Err(ApiError::not_found("Project not found"));
@@ -102,6 +110,42 @@ async fn verify_project_ownership(
     Ok(())
 }
 
+/// Mark `previous_id` as superseded by a new revision and return the new version number.
+///
+/// The previous row is locked so two concurrent revisions of the same document
+/// can't both succeed; only the latest version in a chain can be revised.
+async fn supersede_document(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    project_id: Uuid,
+    previous_id: Uuid,
+) -> Result<i32, ApiError> {
+    let previous: Option<(Option<i32>, String)> = sqlx::query_as(
+        "SELECT version, status FROM documents WHERE id = $1 AND project_id = $2 FOR UPDATE",
+    )
+    .bind(previous_id)
+    .bind(project_id)
+    .fetch_optional(&mut **tx)
+    .await
+    .map_err(map_db_err("supersede_document"))?;
+
+    let (version, status) =
+        previous.ok_or_else(|| ApiError::not_found("Superseded document not found"))?;
+
+    if status == "superseded" {
+        return Err(ApiError::conflict(
+            "Document has already been superseded; revise the latest version instead",
+        ));
+    }
+
+    sqlx::query("UPDATE documents SET status = 'superseded', updated_at = NOW() WHERE id = $1")
+        .bind(previous_id)
+        .execute(&mut **tx)
+        .await
+        .map_err(map_db_err("supersede_document"))?;
+
+    Ok(version.unwrap_or(1) + 1)
+}
+
 /// POST /api/projects/:project_id/documents
 ///
 /// Create a document metadata entry (without file).
@@ -131,23 +175,40 @@ pub async fn create_document(
         DocumentType::Other => "other",
     };
 
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(map_db_err("create_document"))?;
+
+    let version = match req.supersedes_document_id {
+        Some(previous_id) => supersede_document(&mut tx, project_id, previous_id).await?,
+        None => 1,
+    };
+
     let document = sqlx::query_as::<_, DocumentRow>(
         r#"
-        INSERT INTO documents (project_id, name, description, document_type, status)
-        VALUES ($1, $2, $3, $4, 'draft')
-        RETURNING id, project_id, name, description, document_type, file_path, file_size, mime_type, version, status, category, revised, author, created_at, updated_at
+        INSERT INTO documents (project_id, name, description, document_type, status, version, supersedes_document_id)
+        VALUES ($1, $2, $3, $4, 'draft', $5, $6)
+        RETURNING id, project_id, name, description, document_type, file_path, file_size, mime_type, page_count, version, status, category, revised, author, supersedes_document_id, created_at, updated_at
         "#,
     )
     .bind(project_id)
     .bind(&req.name)
     .bind(&req.description)
     .bind(document_type)
-    .fetch_one(&state.db)
+    .bind(version)
+    .bind(req.supersedes_document_id)
+    .fetch_one(&mut *tx)
     .await
-    .map_err(|e| ApiError::internal(format!("Failed to create document: {}", e)))?;
+    .map_err(map_db_err("create_document"))?;
+
+    tx.commit()
+        .await
+        .map_err(map_db_err("create_document"))?;
 
     let response: DocumentResponse = document.try_into()?;
-    Ok((StatusCode::CREATED, Json(DataResponse::new(response))))
+    Ok(Created(response))
 }
 
 /// POST /api/projects/:project_id/documents/upload
@@ -171,6 +232,7 @@ pub async fn upload_document(
     let mut file_name: Option<String> = None;
     let mut content_type: Option<String> = None;
     let mut document_type = "other".to_string();
+    let mut supersedes_document_id: Option<Uuid> = None;
 
     // Process multipart fields
     while let Some(field) = multipart
@@ -199,6 +261,15 @@ pub async fn upload_document(
                     .map_err(|e| ApiError::bad_request(format!("Failed to read field: {}", e)))?;
                 document_type = value;
             }
+            "supersedes_document_id" => {
+                let value = field
+                    .text()
+                    .await
+                    .map_err(|e| ApiError::bad_request(format!("Failed to read field: {}", e)))?;
+                supersedes_document_id = Some(value.trim().parse().map_err(|_| {
+                    ApiError::bad_request("supersedes_document_id must be a valid UUID")
+                })?);
+            }
             _ => {}
         }
     }
@@ -208,19 +279,40 @@ pub async fn upload_document(
     let file_name =
         file_name.ok_or_else(|| ApiError::bad_request("No filename provided in upload"))?;
 
-    // Validate file type (only allow PDFs for blueprints)
-    let mime = content_type.as_deref().unwrap_or("application/octet-stream");
-    if !mime.contains("pdf") && !mime.contains("octet-stream") {
-        // Allow PDFs and generic binary
-        tracing::warn!(mime_type = %mime, "Non-PDF file uploaded, allowing but logging");
-    }
-
     // Validate file size (max 100MB)
     let file_size = file_data.len() as i64;
     if file_size > 100 * 1024 * 1024 {
         return Err(ApiError::bad_request("File too large (max 100MB)"));
     }
 
+    // Only PDFs go through the AI pipeline; trust the file signature, not the
+    // client's content type
+    if !pdf::is_pdf(&file_data) {
+        tracing::warn!(
+            declared_type = content_type.as_deref().unwrap_or("none"),
+            "Rejected non-PDF document upload"
+        );
+        return Err(ApiError::unsupported_media_type("Documents must be PDF files"));
+    }
+    let mime = "application/pdf";
+
+    let (file_data, page_count) = tokio::task::spawn_blocking(move || {
+        let pages = pdf::page_count(&file_data);
+        (file_data, pages)
+    })
+    .await
+    .map_err(|e| ApiError::internal(format!("Failed to inspect document: {}", e)))?;
+
+    let max_pages = state.settings.max_document_pages;
+    if let Some(pages) = page_count.filter(|&p| p > max_pages) {
+        return Err(ApiError::unprocessable(format!(
+            "Document has {} pages; the limit is {}",
+            pages, max_pages
+        )));
+    }
+
+    let content_hash = hex::encode(Sha256::digest(&file_data));
+
     // Create upload directory
     let upload_dir = format!("./uploads/documents/{}", project_id);
     fs::create_dir_all(&upload_dir)
@@ -247,12 +339,23 @@ pub async fn upload_document(
         .await
         .map_err(|e| ApiError::internal(format!("Failed to flush file: {}", e)))?;
 
-    // Insert document record
+    // Insert document record (as a new version when revising an existing document)
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(map_db_err("upload_document"))?;
+
+    let version = match supersedes_document_id {
+        Some(previous_id) => supersede_document(&mut tx, project_id, previous_id).await?,
+        None => 1,
+    };
+
     let document = sqlx::query_as::<_, DocumentRow>(
         r#"
-        INSERT INTO documents (project_id, name, document_type, file_path, file_size, mime_type, status)
-        VALUES ($1, $2, $3, $4, $5, $6, 'active')
-        RETURNING id, project_id, name, description, document_type, file_path, file_size, mime_type, version, status, category, revised, author, created_at, updated_at
+        INSERT INTO documents (project_id, name, document_type, file_path, file_size, mime_type, page_count, content_hash, status, version, supersedes_document_id)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, 'active', $9, $10)
+        RETURNING id, project_id, name, description, document_type, file_path, file_size, mime_type, page_count, version, status, category, revised, author, supersedes_document_id, created_at, updated_at
         "#,
     )
     .bind(project_id)
@@ -261,22 +364,32 @@ pub async fn upload_document(
     .bind(&file_path)
     .bind(file_size)
     .bind(mime)
-    .fetch_one(&state.db)
+    .bind(page_count.map(|p| p as i32))
+    .bind(&content_hash)
+    .bind(version)
+    .bind(supersedes_document_id)
+    .fetch_one(&mut *tx)
     .await
-    .map_err(|e| ApiError::internal(format!("Failed to create document record: {}", e)))?;
+    .map_err(map_db_err("upload_document"))?;
+
+    tx.commit()
+        .await
+        .map_err(map_db_err("upload_document"))?;
 
     let response: DocumentResponse = document.try_into()?;
-    Ok((StatusCode::CREATED, Json(DataResponse::new(response))))
+    Ok(Created(response))
 }
 
 /// GET /api/projects/:project_id/documents
 ///
-/// List documents for a project.
+/// List documents for a project. Superseded versions are hidden unless
+/// `?include_superseded=true`.
 pub async fn list_documents(
     auth: RequireAuth,
     State(state): State<Arc<AppState>>,
     Path(project_id): Path<Uuid>,
     Query(pagination): Query<PaginationParams>,
+    Query(query): Query<DocumentListQuery>,
 ) -> Result<impl IntoResponse, ApiError> {
     tracing::info!(
         user_id = %auth.user_id,
@@ -286,39 +399,43 @@ pub async fn list_documents(
 
     verify_project_ownership(&state, project_id, auth.user_id).await?;
 
-    let offset = pagination.offset() as i64;
-    let limit = pagination.limit() as i64;
+    let page = resolve_pagination(&pagination, state.settings.pagination.default)?;
 
     // Get total count
-    let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM documents WHERE project_id = $1")
-        .bind(project_id)
-        .fetch_one(&state.db)
-        .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    let total: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM documents WHERE project_id = $1 AND ($2 OR status <> 'superseded')",
+    )
+    .bind(project_id)
+    .bind(query.include_superseded)
+    .fetch_one(&state.db)
+    .await
+    .map_err(map_db_err("list_documents"))?;
 
     // Get documents
     let documents = sqlx::query_as::<_, DocumentRow>(
         r#"
-        SELECT id, project_id, name, description, document_type, file_path, file_size, mime_type, version, status, category, revised, author, created_at, updated_at
+        SELECT id, project_id, name, description, document_type, file_path, file_size, mime_type, page_count, version, status, category, revised, author, supersedes_document_id, created_at, updated_at
         FROM documents
         WHERE project_id = $1
-        ORDER BY created_at DESC
+        AND ($4 OR status <> 'superseded')
+        ORDER BY created_at DESC, id
         LIMIT $2 OFFSET $3
         "#,
     )
     .bind(project_id)
-    .bind(limit)
-    .bind(offset)
+    .bind(page.limit())
+    .bind(page.offset())
+    .bind(query.include_superseded)
     .fetch_all(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("list_documents"))?;
 
     let data: Vec<DocumentResponse> = documents
         .into_iter()
         .filter_map(|row| row.try_into().ok())
         .collect();
 
-    Ok(Json(Paginated::new(data, &pagination, total as u64)))
+    Ok(Json(Paginated::new(data, &page, total as u64)))
 }
 
 /// GET /api/projects/:project_id/documents/:document_id
@@ -340,7 +457,7 @@ pub async fn get_document(
 
     let document = sqlx::query_as::<_, DocumentRow>(
         r#"
-        SELECT id, project_id, name, description, document_type, file_path, file_size, mime_type, version, status, category, revised, author, created_at, updated_at
+        SELECT id, project_id, name, description, document_type, file_path, file_size, mime_type, page_count, version, status, category, revised, author, supersedes_document_id, created_at, updated_at
         FROM documents
         WHERE id = $1 AND project_id = $2
         "#,
@@ -349,58 +466,241 @@ pub async fn get_document(
     .bind(project_id)
     .fetch_optional(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
+    .map_err(map_db_err("get_document"))?
     .ok_or_else(|| ApiError::not_found("Document not found"))?;
 
     let response: DocumentResponse = document.try_into()?;
     Ok(Json(DataResponse::new(response)))
 }
 
+/// GET /api/projects/:project_id/documents/:document_id/versions
+///
+/// List every version in a document's revision chain, newest first.
+pub async fn list_document_versions(
+    auth: RequireAuth,
+    State(state): State<Arc<AppState>>,
+    Path((project_id, document_id)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse, ApiError> {
+    verify_project_ownership(&state, project_id, auth.user_id).await?;
+
+    // Walk back to the oldest ancestor and forward to the newest revision
+    let documents = sqlx::query_as::<_, DocumentRow>(
+        r#"
+        WITH RECURSIVE older AS (
+            SELECT d.* FROM documents d WHERE d.id = $1 AND d.project_id = $2
+            UNION
+            SELECT d.* FROM documents d JOIN older o ON d.id = o.supersedes_document_id
+        ),
+        newer AS (
+            SELECT d.* FROM documents d WHERE d.id = $1 AND d.project_id = $2
+            UNION
+            SELECT d.* FROM documents d JOIN newer n ON d.supersedes_document_id = n.id
+        )
+        SELECT id, project_id, name, description, document_type, file_path, file_size, mime_type, page_count, version, status, category, revised, author, supersedes_document_id, created_at, updated_at
+        FROM (SELECT * FROM older UNION SELECT * FROM newer) chain
+        ORDER BY version DESC, created_at DESC
+        "#,
+    )
+    .bind(document_id)
+    .bind(project_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(map_db_err("list_document_versions"))?;
+
+    if documents.is_empty() {
+        return Err(ApiError::not_found("Document not found"));
+    }
+
+    let data: Vec<DocumentResponse> = documents
+        .into_iter()
+        .filter_map(|row| row.try_into().ok())
+        .collect();
+
+    Ok(Json(DataResponse::new(data)))
+}
+
 /// DELETE /api/projects/:project_id/documents/:document_id
 ///
-/// Delete a document and its file.
+/// Delete a document with the materials, rooms, trade scopes and embeddings
+/// extracted from it, and queue its file for removal, in one transaction.
+/// A document with a queued, running or paused processing job is a 409
+/// unless `?force=true`, which cancels the jobs first. Returns a summary of
+/// what was removed.
 pub async fn delete_document(
     auth: RequireAuth,
     State(state): State<Arc<AppState>>,
     Path((project_id, document_id)): Path<(Uuid, Uuid)>,
+    Query(query): Query<DeleteDocumentQuery>,
 ) -> Result<impl IntoResponse, ApiError> {
     tracing::info!(
         user_id = %auth.user_id,
         project_id = %project_id,
         document_id = %document_id,
+        force = query.force,
         "Deleting document"
     );
 
     verify_project_ownership(&state, project_id, auth.user_id).await?;
 
-    // Get file path before deleting
+    let db_err = map_db_err("delete_document");
+    let mut tx = state.db.begin().await.map_err(db_err)?;
+
     let file_path: Option<String> = sqlx::query_scalar(
-        "SELECT file_path FROM documents WHERE id = $1 AND project_id = $2",
+        "SELECT file_path FROM documents WHERE id = $1 AND project_id = $2 FOR UPDATE",
     )
     .bind(document_id)
     .bind(project_id)
-    .fetch_optional(&state.db)
+    .fetch_optional(&mut *tx)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
-    .flatten();
+    .map_err(db_err)?
+    .ok_or_else(|| ApiError::not_found("Document not found"))?;
 
-    // Delete from database
-    let result =
-        sqlx::query("DELETE FROM documents WHERE id = $1 AND project_id = $2")
-            .bind(document_id)
-            .bind(project_id)
-            .execute(&state.db)
-            .await
-            .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    let active_jobs: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM processing_jobs WHERE document_id = $1 AND status IN ('queued', 'running', 'paused')",
+    )
+    .bind(document_id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(db_err)?;
 
-    if result.rows_affected() == 0 {
-        return Err(ApiError::not_found("Document not found"));
+    if active_jobs > 0 && !query.force {
+        return Err(ApiError::conflict(
+            "Document has an active processing job; cancel it or delete with ?force=true",
+        ));
     }
 
-    // Delete file from disk (ignore errors)
-    if let Some(path) = file_path {
-        let _ = fs::remove_file(&path).await;
+    let cancelled_jobs = sqlx::query(
+        r#"
+        UPDATE processing_jobs SET status = 'cancelled', completed_at = NOW(), updated_at = NOW()
+        WHERE document_id = $1 AND status IN ('queued', 'running', 'paused')
+        "#,
+    )
+    .bind(document_id)
+    .execute(&mut *tx)
+    .await
+    .map_err(db_err)?
+    .rows_affected();
+
+    let deleted_materials = sqlx::query("DELETE FROM extracted_materials WHERE document_id = $1")
+        .bind(document_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_err)?
+        .rows_affected();
+
+    let deleted_rooms = sqlx::query("DELETE FROM extracted_rooms WHERE document_id = $1")
+        .bind(document_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_err)?
+        .rows_affected();
+
+    let deleted_trade_scopes = sqlx::query("DELETE FROM extracted_trade_scopes WHERE document_id = $1")
+        .bind(document_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_err)?
+        .rows_affected();
+
+    let deleted_embeddings = sqlx::query("DELETE FROM document_embeddings WHERE document_id = $1")
+        .bind(document_id.to_string())
+        .execute(&mut *tx)
+        .await
+        .map_err(db_err)?
+        .rows_affected();
+
+    let queued = match &file_path {
+        Some(path) => Some(
+            file_deletions::enqueue(&mut tx, path, "document_deleted")
+                .await
+                .map_err(db_err)?,
+        ),
+        None => None,
+    };
+
+    sqlx::query("DELETE FROM documents WHERE id = $1")
+        .bind(document_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_err)?;
+
+    tx.commit().await.map_err(db_err)?;
+
+    if let (Some(id), Some(path)) = (queued, &file_path) {
+        file_deletions::delete_now(&state.db, id, path).await;
     }
 
-    Ok(StatusCode::NO_CONTENT)
+    Ok(Json(DataResponse::new(DocumentDeletionSummary {
+        document_id,
+        cancelled_jobs,
+        deleted_materials,
+        deleted_rooms,
+        deleted_trade_scopes,
+        deleted_embeddings,
+        file_deletion_queued: queued.is_some(),
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use axum::body::Body;
+    use axum::extract::FromRequest;
+    use axum::http::{header, Request};
+
+    use crate::test_support;
+
+    const BOUNDARY: &str = "test-boundary";
+
+    /// A multipart upload of one `file` field
+    async fn upload(state: &Arc<AppState>, file_name: &str, content_type: &str, data: &[u8]) -> Multipart {
+        let mut body = format!(
+            "--{}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"{}\"\r\nContent-Type: {}\r\n\r\n",
+            BOUNDARY, file_name, content_type
+        )
+        .into_bytes();
+        body.extend_from_slice(data);
+        body.extend(format!("\r\n--{}--\r\n", BOUNDARY).bytes());
+
+        let request = Request::post("/")
+            .header(header::CONTENT_TYPE, format!("multipart/form-data; boundary={}", BOUNDARY))
+            .body(Body::from(body))
+            .unwrap();
+        Multipart::from_request(request, state).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn mislabeled_file_is_unsupported() {
+        let Some(state) = test_support::state().await else { return };
+        let owner_id = test_support::user(&state.db, "gc").await;
+        let project_id = test_support::project(&state.db, owner_id).await;
+        let png = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+
+        let multipart = upload(&state, "plan.pdf", "application/pdf", png).await;
+        let err = upload_document(test_support::auth(owner_id), State(state.clone()), Path(project_id), multipart)
+            .await
+            .err()
+            .expect("upload should be refused");
+
+        assert!(matches!(err, ApiError::UnsupportedMediaType(_)), "{:?}", err);
+    }
+
+    #[tokio::test]
+    async fn fake_pdf_over_the_page_limit_is_refused() {
+        let Some(state) = test_support::state().await else { return };
+        let owner_id = test_support::user(&state.db, "gc").await;
+        let project_id = test_support::project(&state.db, owner_id).await;
+        let pages = state.settings.max_document_pages as usize + 1;
+        let pdf = format!("%PDF-1.4\n{}%%EOF\n", "<< /Type /Page >>\n".repeat(pages));
+
+        // Labeled as a generic download; the signature is what counts
+        let multipart = upload(&state, "plans.bin", "application/octet-stream", pdf.as_bytes()).await;
+        let err = upload_document(test_support::auth(owner_id), State(state.clone()), Path(project_id), multipart)
+            .await
+            .err()
+            .expect("upload should be refused");
+
+        assert!(matches!(&err, ApiError::UnprocessableEntity(m) if m.starts_with(&format!("Document has {} pages", pages))), "{:?}", err);
+    }
 }
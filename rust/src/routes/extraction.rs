@@ -1,6 +1,11 @@
 //! Extraction routes
 //!
 //! Endpoints for managing AI-extracted data: materials, rooms, milestones, trade scopes.
+//!
+//! Every item type has the same update semantics: `PUT` replaces the whole
+//! item, so omitted optional fields are cleared (lists emptied, milestone
+//! status and progress reset to their create defaults), while `PATCH` changes
+//! only the fields present in the body and leaves the rest untouched.
 
 use axum::{
     extract::{Path, Query, State},
@@ -12,12 +17,14 @@ use serde::Deserialize;
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::api::pagination::PaginationParams;
-use crate::api::response::{DataResponse, Paginated, PaginationMeta};
+use crate::api::pagination::{resolve_pagination, PaginationParams};
+use crate::api::response::{Created, DataResponse, MessageResponse, NoContent, Paginated};
 use crate::app::AppState;
 use crate::auth::RequireAuth;
 use crate::domain::extraction::*;
-use crate::error::ApiError;
+use crate::domain::money::Money;
+use crate::domain::trades::{normalize_trade, normalized_trade_key};
+use crate::error::{map_db_err, ApiError};
 
 // ============================================================================
 // Database Row Types
@@ -28,16 +35,18 @@ struct ExtractedMaterialRow {
     id: Uuid,
     project_id: Uuid,
     document_id: Option<Uuid>,
+    document_version: Option<i32>,
     name: String,
     description: Option<String>,
     quantity: Option<sqlx::types::Decimal>,
     unit: Option<String>,
-    unit_cost: Option<sqlx::types::Decimal>,
-    total_cost: Option<sqlx::types::Decimal>,
+    unit_cost: Option<Money>,
+    total_cost: Option<Money>,
     location: Option<String>,
     room: Option<String>,
     specification: Option<String>,
     trade_category: Option<String>,
+    normalized_trade: Option<String>,
     csi_division: Option<String>,
     source_page: Option<i32>,
     confidence: sqlx::types::Decimal,
@@ -52,6 +61,7 @@ struct ExtractedRoomRow {
     id: Uuid,
     project_id: Uuid,
     document_id: Option<Uuid>,
+    document_version: Option<i32>,
     room_name: String,
     room_number: Option<String>,
     room_type: Option<String>,
@@ -89,6 +99,7 @@ struct MilestoneRow {
     status: String,
     progress: sqlx::types::Decimal,
     is_ai_generated: bool,
+    confidence: sqlx::types::Decimal,
     is_verified: bool,
     verified_at: Option<DateTime<Utc>>,
     created_at: DateTime<Utc>,
@@ -100,8 +111,10 @@ struct TradeScopeRow {
     id: Uuid,
     project_id: Uuid,
     document_id: Option<Uuid>,
+    document_version: Option<i32>,
     trade: String,
     trade_display_name: Option<String>,
+    normalized_trade: Option<String>,
     csi_division: Option<String>,
     inclusions: serde_json::Value,
     exclusions: serde_json::Value,
@@ -109,7 +122,7 @@ struct TradeScopeRow {
     spec_sections: serde_json::Value,
     rfi_needed: serde_json::Value,
     assumptions: serde_json::Value,
-    estimated_value: Option<sqlx::types::Decimal>,
+    estimated_value: Option<Money>,
     confidence: sqlx::types::Decimal,
     is_verified: bool,
     verified_at: Option<DateTime<Utc>>,
@@ -117,6 +130,170 @@ struct TradeScopeRow {
     updated_at: DateTime<Utc>,
 }
 
+#[derive(Debug, sqlx::FromRow)]
+struct ReviewQueueRow {
+    item_type: String,
+    id: Uuid,
+    label: String,
+    confidence: sqlx::types::Decimal,
+    document_id: Option<Uuid>,
+    source_page: Option<i32>,
+    created_at: DateTime<Utc>,
+}
+
+// ============================================================================
+// Row Conversions
+// ============================================================================
+
+impl From<ExtractedMaterialRow> for ExtractedMaterialResponse {
+    fn from(r: ExtractedMaterialRow) -> Self {
+        Self {
+            id: r.id,
+            project_id: r.project_id,
+            document_id: r.document_id,
+            document_version: r.document_version,
+            name: r.name,
+            description: r.description,
+            quantity: decimal_opt_to_f64(r.quantity),
+            unit: r.unit,
+            unit_cost: r.unit_cost,
+            total_cost: r.total_cost,
+            location: r.location,
+            room: r.room,
+            specification: r.specification,
+            trade_category: r.trade_category,
+            normalized_trade: r.normalized_trade,
+            csi_division: r.csi_division,
+            source_page: r.source_page,
+            confidence: decimal_to_f64(r.confidence),
+            is_verified: r.is_verified,
+            verified_at: r.verified_at,
+            created_at: r.created_at,
+            updated_at: r.updated_at,
+        }
+    }
+}
+
+impl From<ExtractedRoomRow> for ExtractedRoomResponse {
+    fn from(r: ExtractedRoomRow) -> Self {
+        let finishes: RoomFinishes = serde_json::from_value(r.finishes).unwrap_or_default();
+        let fixtures: Vec<String> = serde_json::from_value(r.fixtures).unwrap_or_default();
+
+        Self {
+            id: r.id,
+            project_id: r.project_id,
+            document_id: r.document_id,
+            document_version: r.document_version,
+            room_name: r.room_name,
+            room_number: r.room_number,
+            room_type: r.room_type,
+            floor: r.floor,
+            area_sqft: decimal_opt_to_f64(r.area_sqft),
+            ceiling_height: decimal_opt_to_f64(r.ceiling_height),
+            perimeter_ft: decimal_opt_to_f64(r.perimeter_ft),
+            finishes,
+            fixtures,
+            notes: r.notes,
+            source_page: r.source_page,
+            confidence: decimal_to_f64(r.confidence),
+            is_verified: r.is_verified,
+            verified_at: r.verified_at,
+            created_at: r.created_at,
+            updated_at: r.updated_at,
+        }
+    }
+}
+
+impl From<MilestoneRow> for MilestoneResponse {
+    fn from(r: MilestoneRow) -> Self {
+        let dependencies: Vec<String> = serde_json::from_value(r.dependencies).unwrap_or_default();
+        let trades_involved: Vec<String> =
+            serde_json::from_value(r.trades_involved).unwrap_or_default();
+        let deliverables: Vec<String> = serde_json::from_value(r.deliverables).unwrap_or_default();
+
+        Self {
+            id: r.id,
+            project_id: r.project_id,
+            name: r.name,
+            description: r.description,
+            phase: r.phase,
+            phase_order: r.phase_order,
+            estimated_duration_days: r.estimated_duration_days,
+            estimated_start_date: r.estimated_start_date,
+            estimated_end_date: r.estimated_end_date,
+            actual_start_date: r.actual_start_date,
+            actual_end_date: r.actual_end_date,
+            dependencies,
+            trades_involved,
+            deliverables,
+            status: r.status,
+            progress: decimal_to_f64(r.progress),
+            is_ai_generated: r.is_ai_generated,
+            confidence: decimal_to_f64(r.confidence),
+            is_verified: r.is_verified,
+            verified_at: r.verified_at,
+            created_at: r.created_at,
+            updated_at: r.updated_at,
+        }
+    }
+}
+
+impl From<ReviewQueueRow> for ReviewQueueItem {
+    fn from(r: ReviewQueueRow) -> Self {
+        let item_type = match r.item_type.as_str() {
+            "material" => ExtractionItemType::Material,
+            "room" => ExtractionItemType::Room,
+            "milestone" => ExtractionItemType::Milestone,
+            _ => ExtractionItemType::TradeScope,
+        };
+
+        Self {
+            item_type,
+            id: r.id,
+            label: r.label,
+            confidence: decimal_to_f64(r.confidence),
+            document_id: r.document_id,
+            source_page: r.source_page,
+            created_at: r.created_at,
+        }
+    }
+}
+
+impl From<TradeScopeRow> for TradeScopeResponse {
+    fn from(r: TradeScopeRow) -> Self {
+        let inclusions: Vec<ScopeItem> = serde_json::from_value(r.inclusions).unwrap_or_default();
+        let exclusions: Vec<ScopeItem> = serde_json::from_value(r.exclusions).unwrap_or_default();
+        let required_sheets: Vec<String> =
+            serde_json::from_value(r.required_sheets).unwrap_or_default();
+        let spec_sections: Vec<String> = serde_json::from_value(r.spec_sections).unwrap_or_default();
+        let rfi_needed: Vec<String> = serde_json::from_value(r.rfi_needed).unwrap_or_default();
+        let assumptions: Vec<String> = serde_json::from_value(r.assumptions).unwrap_or_default();
+
+        Self {
+            id: r.id,
+            project_id: r.project_id,
+            document_id: r.document_id,
+            document_version: r.document_version,
+            trade: r.trade,
+            trade_display_name: r.trade_display_name,
+            normalized_trade: r.normalized_trade,
+            csi_division: r.csi_division,
+            inclusions,
+            exclusions,
+            required_sheets,
+            spec_sections,
+            rfi_needed,
+            assumptions,
+            estimated_value: r.estimated_value,
+            confidence: decimal_to_f64(r.confidence),
+            is_verified: r.is_verified,
+            verified_at: r.verified_at,
+            created_at: r.created_at,
+            updated_at: r.updated_at,
+        }
+    }
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
@@ -130,6 +307,16 @@ fn decimal_opt_to_f64(d: Option<sqlx::types::Decimal>) -> Option<f64> {
     d.map(decimal_to_f64)
 }
 
+/// ORDER BY for an extraction list: its natural order, or unverified and
+/// least confident items first with the natural order as tie-breaker. `id`
+/// comes last so rows with equal sort keys keep their place between pages.
+fn order_by(sort: Option<ExtractionSort>, natural: &str) -> String {
+    match sort.unwrap_or_default() {
+        ExtractionSort::Default => format!("{}, id", natural),
+        ExtractionSort::ConfidenceAsc => format!("is_verified, confidence, {}, id", natural),
+    }
+}
+
 async fn verify_project_access(
     state: &AppState,
     project_id: Uuid,
@@ -139,7 +326,7 @@ async fn verify_project_access(
         .bind(project_id)
         .fetch_optional(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
+        .map_err(map_db_err("verify_project_access"))?
         .flatten();
 
     if owner != Some(user_id) {
@@ -155,6 +342,16 @@ async fn verify_project_access(
 /// GET /api/projects/:project_id/extraction
 ///
 /// Get extraction summary for a project.
+#[utoipa::path(
+    get,
+    path = "/projects/{project_id}/extraction",
+    tag = "extraction",
+    params(("project_id" = Uuid, Path, description = "Project id")),
+    responses(
+        (status = 200, description = "Counts and totals of extracted data", body = ExtractionSummaryData),
+        (status = 403, description = "No access to this project", body = ErrorResponse),
+    )
+)]
 pub async fn get_extraction_summary(
     State(state): State<Arc<AppState>>,
     Path(project_id): Path<Uuid>,
@@ -162,87 +359,58 @@ pub async fn get_extraction_summary(
 ) -> Result<impl IntoResponse, ApiError> {
     verify_project_access(&state, project_id, auth.user_id).await?;
 
-    let materials_count: i64 = sqlx::query_scalar(
-        "SELECT COUNT(*) FROM extracted_materials WHERE project_id = $1",
-    )
-    .bind(project_id)
-    .fetch_one(&state.db)
-    .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
-
-    let rooms_count: i64 = sqlx::query_scalar(
-        "SELECT COUNT(*) FROM extracted_rooms WHERE project_id = $1",
-    )
-    .bind(project_id)
-    .fetch_one(&state.db)
-    .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
-
-    let milestones_count: i64 = sqlx::query_scalar(
-        "SELECT COUNT(*) FROM project_milestones WHERE project_id = $1",
-    )
-    .bind(project_id)
-    .fetch_one(&state.db)
-    .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
-
-    let trade_scopes_count: i64 = sqlx::query_scalar(
-        "SELECT COUNT(*) FROM extracted_trade_scopes WHERE project_id = $1",
-    )
-    .bind(project_id)
-    .fetch_one(&state.db)
-    .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
-
-    let verified_materials: i64 = sqlx::query_scalar(
-        "SELECT COUNT(*) FROM extracted_materials WHERE project_id = $1 AND is_verified = true",
-    )
-    .bind(project_id)
-    .fetch_one(&state.db)
-    .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
-
-    let verified_rooms: i64 = sqlx::query_scalar(
-        "SELECT COUNT(*) FROM extracted_rooms WHERE project_id = $1 AND is_verified = true",
-    )
-    .bind(project_id)
-    .fetch_one(&state.db)
-    .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
-
-    let verified_milestones: i64 = sqlx::query_scalar(
-        "SELECT COUNT(*) FROM project_milestones WHERE project_id = $1 AND is_verified = true",
-    )
-    .bind(project_id)
-    .fetch_one(&state.db)
-    .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
-
-    let verified_trade_scopes: i64 = sqlx::query_scalar(
-        "SELECT COUNT(*) FROM extracted_trade_scopes WHERE project_id = $1 AND is_verified = true",
-    )
-    .bind(project_id)
-    .fetch_one(&state.db)
-    .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    // Total and verified counts per table, plus the job status, concurrently
+    let counts = |table: &str| {
+        format!(
+            "SELECT COUNT(*), COUNT(*) FILTER (WHERE is_verified = true) FROM {} WHERE project_id = $1",
+            table
+        )
+    };
+    let (materials_sql, rooms_sql, milestones_sql, trade_scopes_sql) = (
+        counts("extracted_materials"),
+        counts("extracted_rooms"),
+        counts("project_milestones"),
+        counts("extracted_trade_scopes"),
+    );
+    let materials = sqlx::query_as::<_, (i64, i64)>(&materials_sql)
+        .bind(project_id)
+        .fetch_one(&state.db);
+    let rooms = sqlx::query_as::<_, (i64, i64)>(&rooms_sql)
+        .bind(project_id)
+        .fetch_one(&state.db);
+    let milestones = sqlx::query_as::<_, (i64, i64)>(&milestones_sql)
+        .bind(project_id)
+        .fetch_one(&state.db);
+    let trade_scopes = sqlx::query_as::<_, (i64, i64)>(&trade_scopes_sql)
+        .bind(project_id)
+        .fetch_one(&state.db);
 
-    // Get latest processing job
-    let (processing_job_id, processing_status): (Option<Uuid>, Option<String>) = sqlx::query_as(
-        "SELECT id, status FROM processing_jobs WHERE project_id = $1 ORDER BY created_at DESC LIMIT 1",
+    // Latest processing job and the last completed extraction
+    let jobs = sqlx::query_as::<_, (Option<Uuid>, Option<String>, Option<DateTime<Utc>>)>(
+        r#"
+        SELECT latest.id, latest.status,
+               (SELECT MAX(completed_at) FROM processing_jobs
+                WHERE project_id = $1 AND status = 'completed')
+        FROM (SELECT 1) AS one
+        LEFT JOIN LATERAL (
+            SELECT id, status FROM processing_jobs
+            WHERE project_id = $1
+            ORDER BY created_at DESC
+            LIMIT 1
+        ) latest ON true
+        "#,
     )
     .bind(project_id)
-    .fetch_optional(&state.db)
-    .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
-    .unwrap_or((None, None));
+    .fetch_one(&state.db);
 
-    let last_extraction_at: Option<DateTime<Utc>> = sqlx::query_scalar(
-        "SELECT MAX(completed_at) FROM processing_jobs WHERE project_id = $1 AND status = 'completed'",
-    )
-    .bind(project_id)
-    .fetch_one(&state.db)
-    .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    let (
+        (materials_count, verified_materials),
+        (rooms_count, verified_rooms),
+        (milestones_count, verified_milestones),
+        (trade_scopes_count, verified_trade_scopes),
+        (processing_job_id, processing_status, last_extraction_at),
+    ) = tokio::try_join!(materials, rooms, milestones, trade_scopes, jobs)
+        .map_err(map_db_err("get_extraction_summary"))?;
 
     let summary = ExtractionSummary {
         project_id,
@@ -262,6 +430,103 @@ pub async fn get_extraction_summary(
     Ok(Json(DataResponse::new(summary)))
 }
 
+// ============================================================================
+// Review Queue
+// ============================================================================
+
+/// Unverified items of all four extracted types for project `$1`.
+/// A missing confidence counts as 0 so those items are reviewed first.
+const REVIEW_QUEUE_CTE: &str = r#"
+    WITH queue AS (
+        SELECT 'material' as item_type, id, name as label, COALESCE(confidence, 0) as confidence,
+               document_id, source_page, created_at
+        FROM extracted_materials
+        WHERE project_id = $1 AND is_verified IS NOT TRUE
+        UNION ALL
+        SELECT 'room', id, room_name, COALESCE(confidence, 0),
+               document_id, source_page, created_at
+        FROM extracted_rooms
+        WHERE project_id = $1 AND is_verified IS NOT TRUE
+        UNION ALL
+        SELECT 'milestone', id, name, COALESCE(confidence, 0),
+               NULL::uuid, NULL::int, created_at
+        FROM project_milestones
+        WHERE project_id = $1 AND is_verified IS NOT TRUE
+        UNION ALL
+        SELECT 'trade_scope', id, COALESCE(trade_display_name, trade), COALESCE(confidence, 0),
+               document_id, NULL::int, created_at
+        FROM extracted_trade_scopes
+        WHERE project_id = $1 AND is_verified IS NOT TRUE
+    )
+"#;
+
+/// GET /api/projects/:project_id/extraction/review-queue
+///
+/// Unverified materials, rooms, milestones and trade scopes in one list,
+/// least confident first, so reviewers start with the riskiest extractions.
+#[utoipa::path(
+    get,
+    path = "/projects/{project_id}/extraction/review-queue",
+    tag = "extraction",
+    params(
+        ("project_id" = Uuid, Path, description = "Project id"),
+        PaginationParams,
+        ReviewQueueQuery,
+    ),
+    responses(
+        (status = 200, description = "Unverified items, least confident first", body = ReviewQueuePage),
+        (status = 400, description = "Invalid confidence bound", body = ErrorResponse),
+        (status = 403, description = "No access to this project", body = ErrorResponse),
+    )
+)]
+pub async fn get_review_queue(
+    State(state): State<Arc<AppState>>,
+    Path(project_id): Path<Uuid>,
+    Query(pagination): Query<PaginationParams>,
+    Query(filter): Query<ReviewQueueQuery>,
+    auth: RequireAuth,
+) -> Result<impl IntoResponse, ApiError> {
+    verify_project_access(&state, project_id, auth.user_id).await?;
+
+    if filter.max_confidence.is_some_and(|v| !(0.0..=1.0).contains(&v)) {
+        return Err(ApiError::bad_request("max_confidence must be between 0 and 1"));
+    }
+
+    let page = resolve_pagination(&pagination, state.settings.pagination.extraction)?;
+
+    let total: i64 = sqlx::query_scalar(&format!(
+        "{} SELECT COUNT(*) FROM queue WHERE ($2::float8 IS NULL OR confidence <= $2)",
+        REVIEW_QUEUE_CTE
+    ))
+    .bind(project_id)
+    .bind(filter.max_confidence)
+    .fetch_one(&state.db)
+    .await
+    .map_err(map_db_err("get_review_queue"))?;
+
+    let rows = sqlx::query_as::<_, ReviewQueueRow>(&format!(
+        r#"{}
+        SELECT item_type, id, label, confidence, document_id, source_page, created_at
+        FROM queue
+        WHERE ($2::float8 IS NULL OR confidence <= $2)
+        ORDER BY confidence, created_at, id
+        LIMIT $3 OFFSET $4
+        "#,
+        REVIEW_QUEUE_CTE
+    ))
+    .bind(project_id)
+    .bind(filter.max_confidence)
+    .bind(page.limit())
+    .bind(page.offset())
+    .fetch_all(&state.db)
+    .await
+    .map_err(map_db_err("get_review_queue"))?;
+
+    let data: Vec<ReviewQueueItem> = rows.into_iter().map(ReviewQueueItem::from).collect();
+
+    Ok(Json(Paginated::new(data, &page, total as u64)))
+}
+
 // ============================================================================
 // Materials CRUD
 // ============================================================================
@@ -275,26 +540,44 @@ pub struct MaterialQueryParams {
 }
 
 /// GET /api/projects/:project_id/extraction/materials
+#[utoipa::path(
+    get,
+    path = "/projects/{project_id}/extraction/materials",
+    tag = "extraction",
+    params(
+        ("project_id" = Uuid, Path, description = "Project id"),
+        PaginationParams,
+        MaterialQuery,
+        ConfidenceQuery,
+    ),
+    responses(
+        (status = 200, description = "Extracted materials", body = ExtractedMaterialPage),
+        (status = 403, description = "No access to this project", body = ErrorResponse),
+    )
+)]
 pub async fn list_materials(
     State(state): State<Arc<AppState>>,
     Path(project_id): Path<Uuid>,
     Query(query): Query<MaterialQueryParams>,
+    Query(review): Query<ConfidenceQuery>,
     auth: RequireAuth,
 ) -> Result<impl IntoResponse, ApiError> {
     verify_project_access(&state, project_id, auth.user_id).await?;
+    review.validate().map_err(ApiError::bad_request)?;
 
-    let page = query.pagination.page.unwrap_or(1).max(1);
-    let per_page = query.pagination.per_page.unwrap_or(50).min(100);
-    let offset = ((page - 1) * per_page) as i64;
+    let page = resolve_pagination(&query.pagination, state.settings.pagination.extraction)?;
+    let trade_filter = normalized_trade_key(query.filter.trade_category.as_deref(), None);
 
     let total: i64 = sqlx::query_scalar(
         r#"
         SELECT COUNT(*) FROM extracted_materials 
         WHERE project_id = $1
-        AND ($2::text IS NULL OR trade_category ILIKE '%' || $2 || '%')
+        AND ($2::text IS NULL OR trade_category ILIKE '%' || $2 || '%' OR normalized_trade = $6)
         AND ($3::text IS NULL OR room ILIKE '%' || $3 || '%')
         AND ($4::bool IS NULL OR is_verified = $4)
         AND ($5::text IS NULL OR name ILIKE '%' || $5 || '%')
+        AND ($7::float8 IS NULL OR confidence >= $7)
+        AND ($8::float8 IS NULL OR confidence <= $8)
         "#,
     )
     .bind(project_id)
@@ -302,79 +585,66 @@ pub async fn list_materials(
     .bind(&query.filter.room)
     .bind(query.filter.is_verified)
     .bind(&query.filter.search)
+    .bind(trade_filter)
+    .bind(review.min_confidence)
+    .bind(review.max_confidence)
     .fetch_one(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("list_materials"))?;
 
-    let rows = sqlx::query_as::<_, ExtractedMaterialRow>(
+    let query_str = format!(
         r#"
-        SELECT id, project_id, document_id, name, description, quantity, unit,
+        SELECT id, project_id, document_id, document_version, name, description, quantity, unit,
                unit_cost, total_cost, location, room, specification, trade_category,
-               csi_division, source_page, confidence, is_verified, verified_at,
+               normalized_trade, csi_division, source_page, confidence, is_verified, verified_at,
                created_at, updated_at
         FROM extracted_materials
         WHERE project_id = $1
-        AND ($2::text IS NULL OR trade_category ILIKE '%' || $2 || '%')
+        AND ($2::text IS NULL OR trade_category ILIKE '%' || $2 || '%' OR normalized_trade = $8)
         AND ($3::text IS NULL OR room ILIKE '%' || $3 || '%')
         AND ($4::bool IS NULL OR is_verified = $4)
         AND ($5::text IS NULL OR name ILIKE '%' || $5 || '%')
-        ORDER BY trade_category, name
+        AND ($9::float8 IS NULL OR confidence >= $9)
+        AND ($10::float8 IS NULL OR confidence <= $10)
+        ORDER BY {}
         LIMIT $6 OFFSET $7
         "#,
-    )
-    .bind(project_id)
-    .bind(&query.filter.trade_category)
-    .bind(&query.filter.room)
-    .bind(query.filter.is_verified)
-    .bind(&query.filter.search)
-    .bind(per_page as i64)
-    .bind(offset)
-    .fetch_all(&state.db)
-    .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+        order_by(review.sort, "trade_category, name")
+    );
+    let rows = sqlx::query_as::<_, ExtractedMaterialRow>(&query_str)
+        .bind(project_id)
+        .bind(&query.filter.trade_category)
+        .bind(&query.filter.room)
+        .bind(query.filter.is_verified)
+        .bind(&query.filter.search)
+        .bind(page.limit())
+        .bind(page.offset())
+        .bind(trade_filter)
+        .bind(review.min_confidence)
+        .bind(review.max_confidence)
+        .fetch_all(&state.db)
+        .await
+        .map_err(map_db_err("list_materials"))?;
 
-    let data: Vec<ExtractedMaterialResponse> = rows
-        .into_iter()
-        .map(|r| ExtractedMaterialResponse {
-            id: r.id,
-            project_id: r.project_id,
-            document_id: r.document_id,
-            name: r.name,
-            description: r.description,
-            quantity: decimal_opt_to_f64(r.quantity),
-            unit: r.unit,
-            unit_cost: decimal_opt_to_f64(r.unit_cost),
-            total_cost: decimal_opt_to_f64(r.total_cost),
-            location: r.location,
-            room: r.room,
-            specification: r.specification,
-            trade_category: r.trade_category,
-            csi_division: r.csi_division,
-            source_page: r.source_page,
-            confidence: decimal_to_f64(r.confidence),
-            is_verified: r.is_verified,
-            verified_at: r.verified_at,
-            created_at: r.created_at,
-            updated_at: r.updated_at,
-        })
-        .collect();
-
-    let total_pages = ((total as f64) / (per_page as f64)).ceil() as u32;
-
-    Ok(Json(Paginated {
-        data,
-        pagination: PaginationMeta {
-            page,
-            per_page,
-            total_items: total as u64,
-            total_pages,
-            has_next: page < total_pages,
-            has_prev: page > 1,
-        },
-    }))
+    let data: Vec<ExtractedMaterialResponse> =
+        rows.into_iter().map(ExtractedMaterialResponse::from).collect();
+
+    Ok(Json(Paginated::new(data, &page, total as u64)))
 }
 
 /// POST /api/projects/:project_id/extraction/materials
+#[utoipa::path(
+    post,
+    path = "/projects/{project_id}/extraction/materials",
+    tag = "extraction",
+    params(("project_id" = Uuid, Path, description = "Project id")),
+    request_body = MaterialInput,
+    responses(
+        (status = 201, description = "Material created", body = ExtractedMaterialData),
+        (status = 400, description = "Invalid fields", body = ErrorResponse),
+        (status = 403, description = "No access to this project", body = ErrorResponse),
+    )
+)]
 pub async fn create_material(
     State(state): State<Arc<AppState>>,
     Path(project_id): Path<Uuid>,
@@ -384,15 +654,15 @@ pub async fn create_material(
     verify_project_access(&state, project_id, auth.user_id).await?;
 
     let id = Uuid::new_v4();
-    let total_cost = input.quantity.zip(input.unit_cost).map(|(q, c)| q * c);
+    let total_cost = input.quantity.zip(input.unit_cost).map(|(q, c)| c.scaled(q));
 
     sqlx::query(
         r#"
         INSERT INTO extracted_materials (
             id, project_id, name, description, quantity, unit, unit_cost, total_cost,
             location, room, specification, trade_category, csi_division, source_page,
-            confidence, is_verified
-        ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, 1.0, false)
+            normalized_trade, confidence, is_verified
+        ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, 1.0, false)
         "#,
     )
     .bind(id)
@@ -409,9 +679,10 @@ pub async fn create_material(
     .bind(&input.trade_category)
     .bind(&input.csi_division)
     .bind(input.source_page)
+    .bind(normalized_trade_key(input.trade_category.as_deref(), input.csi_division.as_deref()))
     .execute(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Failed to create material: {}", e)))?;
+    .map_err(map_db_err("create_material"))?;
 
     let row = sqlx::query_as::<_, ExtractedMaterialRow>(
         "SELECT * FROM extracted_materials WHERE id = $1",
@@ -419,35 +690,27 @@ pub async fn create_material(
     .bind(id)
     .fetch_one(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("create_material"))?;
 
-    let response = ExtractedMaterialResponse {
-        id: row.id,
-        project_id: row.project_id,
-        document_id: row.document_id,
-        name: row.name,
-        description: row.description,
-        quantity: decimal_opt_to_f64(row.quantity),
-        unit: row.unit,
-        unit_cost: decimal_opt_to_f64(row.unit_cost),
-        total_cost: decimal_opt_to_f64(row.total_cost),
-        location: row.location,
-        room: row.room,
-        specification: row.specification,
-        trade_category: row.trade_category,
-        csi_division: row.csi_division,
-        source_page: row.source_page,
-        confidence: decimal_to_f64(row.confidence),
-        is_verified: row.is_verified,
-        verified_at: row.verified_at,
-        created_at: row.created_at,
-        updated_at: row.updated_at,
-    };
-
-    Ok(Json(DataResponse::new(response)))
+    Ok(Created(ExtractedMaterialResponse::from(row)))
 }
 
 /// PUT /api/projects/:project_id/extraction/materials/:material_id
+#[utoipa::path(
+    put,
+    path = "/projects/{project_id}/extraction/materials/{material_id}",
+    tag = "extraction",
+    params(
+        ("project_id" = Uuid, Path, description = "Project id"),
+        ("material_id" = Uuid, Path, description = "Material id"),
+    ),
+    request_body = MaterialInput,
+    responses(
+        (status = 200, description = "Updated material", body = ExtractedMaterialData),
+        (status = 400, description = "Invalid fields", body = ErrorResponse),
+        (status = 404, description = "Material not found", body = ErrorResponse),
+    )
+)]
 pub async fn update_material(
     State(state): State<Arc<AppState>>,
     Path((project_id, material_id)): Path<(Uuid, Uuid)>,
@@ -456,14 +719,15 @@ pub async fn update_material(
 ) -> Result<impl IntoResponse, ApiError> {
     verify_project_access(&state, project_id, auth.user_id).await?;
 
-    let total_cost = input.quantity.zip(input.unit_cost).map(|(q, c)| q * c);
+    let total_cost = input.quantity.zip(input.unit_cost).map(|(q, c)| c.scaled(q));
 
     let result = sqlx::query(
         r#"
         UPDATE extracted_materials SET
             name = $1, description = $2, quantity = $3, unit = $4, unit_cost = $5,
             total_cost = $6, location = $7, room = $8, specification = $9,
-            trade_category = $10, csi_division = $11, source_page = $12, updated_at = NOW()
+            trade_category = $10, csi_division = $11, source_page = $12,
+            normalized_trade = $15, updated_at = NOW()
         WHERE id = $13 AND project_id = $14
         "#,
     )
@@ -481,9 +745,10 @@ pub async fn update_material(
     .bind(input.source_page)
     .bind(material_id)
     .bind(project_id)
+    .bind(normalized_trade_key(input.trade_category.as_deref(), input.csi_division.as_deref()))
     .execute(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Failed to update material: {}", e)))?;
+    .map_err(map_db_err("update_material"))?;
 
     if result.rows_affected() == 0 {
         return Err(ApiError::not_found("Material not found"));
@@ -495,22 +760,24 @@ pub async fn update_material(
     .bind(material_id)
     .fetch_one(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("update_material"))?;
 
     let response = ExtractedMaterialResponse {
         id: row.id,
         project_id: row.project_id,
         document_id: row.document_id,
+        document_version: row.document_version,
         name: row.name,
         description: row.description,
         quantity: decimal_opt_to_f64(row.quantity),
         unit: row.unit,
-        unit_cost: decimal_opt_to_f64(row.unit_cost),
-        total_cost: decimal_opt_to_f64(row.total_cost),
+        unit_cost: row.unit_cost,
+        total_cost: row.total_cost,
         location: row.location,
         room: row.room,
         specification: row.specification,
         trade_category: row.trade_category,
+        normalized_trade: row.normalized_trade,
         csi_division: row.csi_division,
         source_page: row.source_page,
         confidence: decimal_to_f64(row.confidence),
@@ -523,7 +790,99 @@ pub async fn update_material(
     Ok(Json(DataResponse::new(response)))
 }
 
+/// PATCH /api/projects/:project_id/extraction/materials/:material_id
+#[utoipa::path(
+    patch,
+    path = "/projects/{project_id}/extraction/materials/{material_id}",
+    tag = "extraction",
+    params(
+        ("project_id" = Uuid, Path, description = "Project id"),
+        ("material_id" = Uuid, Path, description = "Material id"),
+    ),
+    request_body = MaterialPatch,
+    responses(
+        (status = 200, description = "Updated material", body = ExtractedMaterialData),
+        (status = 400, description = "Invalid fields", body = ErrorResponse),
+        (status = 404, description = "Material not found", body = ErrorResponse),
+    )
+)]
+pub async fn patch_material(
+    State(state): State<Arc<AppState>>,
+    Path((project_id, material_id)): Path<(Uuid, Uuid)>,
+    auth: RequireAuth,
+    Json(input): Json<MaterialPatch>,
+) -> Result<impl IntoResponse, ApiError> {
+    verify_project_access(&state, project_id, auth.user_id).await?;
+
+    if input.name.as_deref().is_some_and(|n| n.trim().is_empty()) {
+        return Err(ApiError::bad_request("name must not be empty"));
+    }
+
+    // When only one of quantity / unit cost changes, the total is recomputed
+    // against the stored value of the other (SET expressions see the old row)
+    let row = sqlx::query_as::<_, ExtractedMaterialRow>(
+        r#"
+        UPDATE extracted_materials SET
+            name = COALESCE($1, name),
+            description = COALESCE($2, description),
+            quantity = COALESCE($3, quantity),
+            unit = COALESCE($4, unit),
+            unit_cost = COALESCE($5, unit_cost),
+            total_cost = CASE
+                WHEN $3::float8 IS NULL AND $5::numeric IS NULL THEN total_cost
+                ELSE ROUND(COALESCE($3::numeric, quantity) * COALESCE($5::numeric, unit_cost), 2)
+            END,
+            location = COALESCE($6, location),
+            room = COALESCE($7, room),
+            specification = COALESCE($8, specification),
+            trade_category = COALESCE($9, trade_category),
+            csi_division = COALESCE($10, csi_division),
+            source_page = COALESCE($11, source_page),
+            normalized_trade = COALESCE($14, normalized_trade),
+            updated_at = NOW()
+        WHERE id = $12 AND project_id = $13
+        RETURNING id, project_id, document_id, document_version, name, description, quantity, unit,
+                  unit_cost, total_cost, location, room, specification, trade_category,
+                  normalized_trade, csi_division, source_page, confidence, is_verified, verified_at,
+                  created_at, updated_at
+        "#,
+    )
+    .bind(&input.name)
+    .bind(&input.description)
+    .bind(input.quantity)
+    .bind(&input.unit)
+    .bind(input.unit_cost)
+    .bind(&input.location)
+    .bind(&input.room)
+    .bind(&input.specification)
+    .bind(&input.trade_category)
+    .bind(&input.csi_division)
+    .bind(input.source_page)
+    .bind(material_id)
+    .bind(project_id)
+    .bind(normalized_trade_key(input.trade_category.as_deref(), input.csi_division.as_deref()))
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("patch_material"))?
+    .ok_or_else(|| ApiError::not_found("Material not found"))?;
+
+    Ok(Json(DataResponse::new(ExtractedMaterialResponse::from(row))))
+}
+
 /// DELETE /api/projects/:project_id/extraction/materials/:material_id
+#[utoipa::path(
+    delete,
+    path = "/projects/{project_id}/extraction/materials/{material_id}",
+    tag = "extraction",
+    params(
+        ("project_id" = Uuid, Path, description = "Project id"),
+        ("material_id" = Uuid, Path, description = "Material id"),
+    ),
+    responses(
+        (status = 204, description = "Material deleted"),
+        (status = 404, description = "Material not found", body = ErrorResponse),
+    )
+)]
 pub async fn delete_material(
     State(state): State<Arc<AppState>>,
     Path((project_id, material_id)): Path<(Uuid, Uuid)>,
@@ -536,16 +895,30 @@ pub async fn delete_material(
         .bind(project_id)
         .execute(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Failed to delete material: {}", e)))?;
+        .map_err(map_db_err("delete_material"))?;
 
     if result.rows_affected() == 0 {
         return Err(ApiError::not_found("Material not found"));
     }
 
-    Ok(Json(serde_json::json!({ "success": true })))
+    Ok(NoContent)
 }
 
 /// POST /api/projects/:project_id/extraction/materials/:material_id/verify
+#[utoipa::path(
+    post,
+    path = "/projects/{project_id}/extraction/materials/{material_id}/verify",
+    tag = "extraction",
+    params(
+        ("project_id" = Uuid, Path, description = "Project id"),
+        ("material_id" = Uuid, Path, description = "Material id"),
+    ),
+    request_body = VerifyItemRequest,
+    responses(
+        (status = 200, description = "`{ data: { is_verified } }`"),
+        (status = 404, description = "Material not found", body = ErrorResponse),
+    )
+)]
 pub async fn verify_material(
     State(state): State<Arc<AppState>>,
     Path((project_id, material_id)): Path<(Uuid, Uuid)>,
@@ -570,13 +943,210 @@ pub async fn verify_material(
     .bind(project_id)
     .execute(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Failed to verify material: {}", e)))?;
+    .map_err(map_db_err("verify_material"))?;
 
     if result.rows_affected() == 0 {
         return Err(ApiError::not_found("Material not found"));
     }
 
-    Ok(Json(serde_json::json!({ "success": true, "is_verified": input.is_verified })))
+    Ok(DataResponse::new(serde_json::json!({ "is_verified": input.is_verified })))
+}
+
+/// Most materials a bulk trade assignment can list by id
+const MAX_BULK_ASSIGN_IDS: usize = 1000;
+
+/// POST /api/projects/:project_id/extraction/materials/bulk-assign
+///
+/// Reclassify many materials at once, chosen by id or by filter. The trade is
+/// normalized onto the canonical catalog and every match is updated in a
+/// single statement, so either all of them change or none do.
+#[utoipa::path(
+    post,
+    path = "/projects/{project_id}/extraction/materials/bulk-assign",
+    tag = "extraction",
+    params(("project_id" = Uuid, Path, description = "Project id")),
+    request_body = BulkAssignTradeRequest,
+    responses(
+        (status = 200, description = "Number of materials reclassified", body = BulkAssignTradeData),
+        (status = 400, description = "Unknown trade or no materials selected", body = ErrorResponse),
+        (status = 403, description = "No access to this project", body = ErrorResponse),
+    )
+)]
+pub async fn bulk_assign_material_trade(
+    State(state): State<Arc<AppState>>,
+    Path(project_id): Path<Uuid>,
+    auth: RequireAuth,
+    Json(input): Json<BulkAssignTradeRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    verify_project_access(&state, project_id, auth.user_id).await?;
+
+    let trade = normalize_trade(&input.trade_category).ok_or_else(|| {
+        ApiError::bad_request(format!(
+            "Unknown trade '{}'; see GET /api/trades for valid values",
+            input.trade_category
+        ))
+    })?;
+
+    let csi_division = input
+        .csi_division
+        .as_deref()
+        .map(str::trim)
+        .filter(|d| !d.is_empty());
+    if let Some(division) = csi_division {
+        if normalize_trade(division).is_none() {
+            return Err(ApiError::bad_request(format!("Unknown CSI division '{}'", division)));
+        }
+    }
+
+    let filter = match (input.ids.is_empty(), input.filter) {
+        (false, Some(_)) => {
+            return Err(ApiError::bad_request("Give either ids or filter, not both"));
+        }
+        (true, None) => return Err(ApiError::bad_request("No materials selected: give ids or filter")),
+        (false, None) => None,
+        (true, Some(filter)) => Some(filter),
+    };
+
+    if input.ids.len() > MAX_BULK_ASSIGN_IDS {
+        return Err(ApiError::bad_request(format!(
+            "At most {} materials can be assigned by id at once",
+            MAX_BULK_ASSIGN_IDS
+        )));
+    }
+
+    let filter = filter.map(|f| MaterialAssignFilter {
+        room: f.room.map(|r| r.trim().to_string()).filter(|r| !r.is_empty()),
+        trade_category: f.trade_category.map(|t| t.trim().to_string()).filter(|t| !t.is_empty()),
+        uncategorized: f.uncategorized,
+    });
+    if let Some(f) = &filter {
+        if f.room.is_none() && f.trade_category.is_none() && !f.uncategorized {
+            return Err(ApiError::bad_request(
+                "Filter needs at least one of room, trade_category or uncategorized",
+            ));
+        }
+        if f.trade_category.is_some() && f.uncategorized {
+            return Err(ApiError::bad_request(
+                "Filter cannot match both a trade category and uncategorized materials",
+            ));
+        }
+    }
+    let filter = filter.unwrap_or_default();
+
+    let result = sqlx::query(
+        r#"
+        UPDATE extracted_materials SET
+            trade_category = $1,
+            normalized_trade = $2,
+            csi_division = COALESCE($3, csi_division),
+            updated_at = NOW()
+        WHERE project_id = $4
+        AND (cardinality($5::uuid[]) = 0 OR id = ANY($5))
+        AND ($6::text IS NULL OR LOWER(room) = LOWER($6))
+        AND ($7::text IS NULL OR LOWER(trade_category) = LOWER($7))
+        AND (NOT $8 OR NULLIF(TRIM(trade_category), '') IS NULL)
+        "#,
+    )
+    .bind(trade.display_name)
+    .bind(trade.key)
+    .bind(csi_division)
+    .bind(project_id)
+    .bind(&input.ids)
+    .bind(&filter.room)
+    .bind(&filter.trade_category)
+    .bind(filter.uncategorized)
+    .execute(&state.db)
+    .await
+    .map_err(map_db_err("bulk_assign_material_trade"))?;
+
+    tracing::info!(
+        user_id = %auth.user_id,
+        project_id = %project_id,
+        trade = trade.key,
+        updated = result.rows_affected(),
+        "Bulk-assigned material trade"
+    );
+
+    Ok(DataResponse::new(BulkAssignTradeResponse {
+        updated: result.rows_affected(),
+        trade_category: trade.display_name.to_string(),
+        normalized_trade: trade.key.to_string(),
+    }))
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct MaterialSourceRow {
+    source_page: Option<i32>,
+    source_bbox: Option<serde_json::Value>,
+    document_id: Option<Uuid>,
+    document_name: Option<String>,
+    mime_type: Option<String>,
+    page_count: Option<i32>,
+    version: Option<i32>,
+    status: Option<String>,
+}
+
+/// GET /api/projects/:project_id/extraction/materials/:material_id/source
+///
+/// The document and page a material was extracted from, and the region of
+/// the page if the AI reported one.
+#[utoipa::path(
+    get,
+    path = "/projects/{project_id}/extraction/materials/{material_id}/source",
+    tag = "extraction",
+    params(
+        ("project_id" = Uuid, Path, description = "Project id"),
+        ("material_id" = Uuid, Path, description = "Material id"),
+    ),
+    responses(
+        (status = 200, description = "Source of the material", body = MaterialSourceData),
+        (status = 403, description = "No access to this project", body = ErrorResponse),
+        (status = 404, description = "Material not found", body = ErrorResponse),
+    )
+)]
+pub async fn get_material_source(
+    State(state): State<Arc<AppState>>,
+    Path((project_id, material_id)): Path<(Uuid, Uuid)>,
+    auth: RequireAuth,
+) -> Result<impl IntoResponse, ApiError> {
+    verify_project_access(&state, project_id, auth.user_id).await?;
+
+    let row = sqlx::query_as::<_, MaterialSourceRow>(
+        r#"
+        SELECT m.source_page, m.source_bbox, d.id AS document_id, d.name AS document_name,
+               d.mime_type, d.page_count, d.version, d.status
+        FROM extracted_materials m
+        LEFT JOIN documents d ON d.id = m.document_id
+        WHERE m.id = $1 AND m.project_id = $2
+        "#,
+    )
+    .bind(material_id)
+    .bind(project_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("get_material_source"))?
+    .ok_or_else(|| ApiError::not_found("Material not found"))?;
+
+    let document = row.document_id.zip(row.document_name).map(|(id, name)| SourceDocument {
+        id,
+        name,
+        mime_type: row.mime_type,
+        page_count: row.page_count,
+        version: row.version,
+        status: row.status,
+    });
+
+    // Boxes are written by the AI service; one that doesn't parse is left out
+    let bounding_box = row
+        .source_bbox
+        .and_then(|bbox| serde_json::from_value::<SourceBoundingBox>(bbox).ok());
+
+    Ok(DataResponse::new(MaterialSourceResponse {
+        material_id,
+        document,
+        source_page: row.source_page,
+        bounding_box,
+    }))
 }
 
 // ============================================================================
@@ -592,17 +1162,32 @@ pub struct RoomQueryParams {
 }
 
 /// GET /api/projects/:project_id/extraction/rooms
+#[utoipa::path(
+    get,
+    path = "/projects/{project_id}/extraction/rooms",
+    tag = "extraction",
+    params(
+        ("project_id" = Uuid, Path, description = "Project id"),
+        PaginationParams,
+        RoomQuery,
+        ConfidenceQuery,
+    ),
+    responses(
+        (status = 200, description = "Extracted rooms", body = ExtractedRoomPage),
+        (status = 403, description = "No access to this project", body = ErrorResponse),
+    )
+)]
 pub async fn list_rooms(
     State(state): State<Arc<AppState>>,
     Path(project_id): Path<Uuid>,
     Query(query): Query<RoomQueryParams>,
+    Query(review): Query<ConfidenceQuery>,
     auth: RequireAuth,
 ) -> Result<impl IntoResponse, ApiError> {
     verify_project_access(&state, project_id, auth.user_id).await?;
+    review.validate().map_err(ApiError::bad_request)?;
 
-    let page = query.pagination.page.unwrap_or(1).max(1);
-    let per_page = query.pagination.per_page.unwrap_or(50).min(100);
-    let offset = ((page - 1) * per_page) as i64;
+    let page = resolve_pagination(&query.pagination, state.settings.pagination.extraction)?;
 
     let total: i64 = sqlx::query_scalar(
         r#"
@@ -612,6 +1197,8 @@ pub async fn list_rooms(
         AND ($3::text IS NULL OR room_type = $3)
         AND ($4::bool IS NULL OR is_verified = $4)
         AND ($5::text IS NULL OR room_name ILIKE '%' || $5 || '%')
+        AND ($6::float8 IS NULL OR confidence >= $6)
+        AND ($7::float8 IS NULL OR confidence <= $7)
         "#,
     )
     .bind(project_id)
@@ -619,13 +1206,15 @@ pub async fn list_rooms(
     .bind(&query.filter.room_type)
     .bind(query.filter.is_verified)
     .bind(&query.filter.search)
+    .bind(review.min_confidence)
+    .bind(review.max_confidence)
     .fetch_one(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("list_rooms"))?;
 
-    let rows = sqlx::query_as::<_, ExtractedRoomRow>(
+    let query_str = format!(
         r#"
-        SELECT id, project_id, document_id, room_name, room_number, room_type,
+        SELECT id, project_id, document_id, document_version, room_name, room_number, room_type,
                floor, area_sqft, ceiling_height, perimeter_ft, finishes, fixtures,
                notes, source_page, confidence, is_verified, verified_at,
                created_at, updated_at
@@ -635,69 +1224,45 @@ pub async fn list_rooms(
         AND ($3::text IS NULL OR room_type = $3)
         AND ($4::bool IS NULL OR is_verified = $4)
         AND ($5::text IS NULL OR room_name ILIKE '%' || $5 || '%')
-        ORDER BY floor, room_number, room_name
+        AND ($8::float8 IS NULL OR confidence >= $8)
+        AND ($9::float8 IS NULL OR confidence <= $9)
+        ORDER BY {}
         LIMIT $6 OFFSET $7
         "#,
-    )
-    .bind(project_id)
-    .bind(&query.filter.floor)
-    .bind(&query.filter.room_type)
-    .bind(query.filter.is_verified)
-    .bind(&query.filter.search)
-    .bind(per_page as i64)
-    .bind(offset)
-    .fetch_all(&state.db)
-    .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
-
-    let data: Vec<ExtractedRoomResponse> = rows
-        .into_iter()
-        .map(|r| {
-            let finishes: RoomFinishes =
-                serde_json::from_value(r.finishes).unwrap_or_default();
-            let fixtures: Vec<String> =
-                serde_json::from_value(r.fixtures).unwrap_or_default();
-
-            ExtractedRoomResponse {
-                id: r.id,
-                project_id: r.project_id,
-                document_id: r.document_id,
-                room_name: r.room_name,
-                room_number: r.room_number,
-                room_type: r.room_type,
-                floor: r.floor,
-                area_sqft: decimal_opt_to_f64(r.area_sqft),
-                ceiling_height: decimal_opt_to_f64(r.ceiling_height),
-                perimeter_ft: decimal_opt_to_f64(r.perimeter_ft),
-                finishes,
-                fixtures,
-                notes: r.notes,
-                source_page: r.source_page,
-                confidence: decimal_to_f64(r.confidence),
-                is_verified: r.is_verified,
-                verified_at: r.verified_at,
-                created_at: r.created_at,
-                updated_at: r.updated_at,
-            }
-        })
-        .collect();
-
-    let total_pages = ((total as f64) / (per_page as f64)).ceil() as u32;
-
-    Ok(Json(Paginated {
-        data,
-        pagination: PaginationMeta {
-            page,
-            per_page,
-            total_items: total as u64,
-            total_pages,
-            has_next: page < total_pages,
-            has_prev: page > 1,
-        },
-    }))
+        order_by(review.sort, "floor, room_number, room_name")
+    );
+    let rows = sqlx::query_as::<_, ExtractedRoomRow>(&query_str)
+        .bind(project_id)
+        .bind(&query.filter.floor)
+        .bind(&query.filter.room_type)
+        .bind(query.filter.is_verified)
+        .bind(&query.filter.search)
+        .bind(page.limit())
+        .bind(page.offset())
+        .bind(review.min_confidence)
+        .bind(review.max_confidence)
+        .fetch_all(&state.db)
+        .await
+        .map_err(map_db_err("list_rooms"))?;
+
+    let data: Vec<ExtractedRoomResponse> = rows.into_iter().map(ExtractedRoomResponse::from).collect();
+
+    Ok(Json(Paginated::new(data, &page, total as u64)))
 }
 
 /// POST /api/projects/:project_id/extraction/rooms
+#[utoipa::path(
+    post,
+    path = "/projects/{project_id}/extraction/rooms",
+    tag = "extraction",
+    params(("project_id" = Uuid, Path, description = "Project id")),
+    request_body = RoomInput,
+    responses(
+        (status = 201, description = "Room created", body = ExtractedRoomData),
+        (status = 400, description = "Invalid fields", body = ErrorResponse),
+        (status = 403, description = "No access to this project", body = ErrorResponse),
+    )
+)]
 pub async fn create_room(
     State(state): State<Arc<AppState>>,
     Path(project_id): Path<Uuid>,
@@ -712,13 +1277,17 @@ pub async fn create_room(
     let fixtures = serde_json::to_value(input.fixtures.unwrap_or_default())
         .unwrap_or(serde_json::json!([]));
 
-    sqlx::query(
+    let row = sqlx::query_as::<_, ExtractedRoomRow>(
         r#"
         INSERT INTO extracted_rooms (
             id, project_id, room_name, room_number, room_type, floor,
             area_sqft, ceiling_height, perimeter_ft, finishes, fixtures,
             notes, source_page, confidence, is_verified
         ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, 1.0, false)
+        RETURNING id, project_id, document_id, document_version, room_name, room_number, room_type,
+                  floor, area_sqft, ceiling_height, perimeter_ft, finishes, fixtures,
+                  notes, source_page, confidence, is_verified, verified_at,
+                  created_at, updated_at
         "#,
     )
     .bind(id)
@@ -734,14 +1303,29 @@ pub async fn create_room(
     .bind(&fixtures)
     .bind(&input.notes)
     .bind(input.source_page)
-    .execute(&state.db)
+    .fetch_one(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Failed to create room: {}", e)))?;
+    .map_err(map_db_err("create_room"))?;
 
-    Ok(Json(serde_json::json!({ "id": id, "success": true })))
+    Ok(Created(ExtractedRoomResponse::from(row)))
 }
 
 /// PUT /api/projects/:project_id/extraction/rooms/:room_id
+#[utoipa::path(
+    put,
+    path = "/projects/{project_id}/extraction/rooms/{room_id}",
+    tag = "extraction",
+    params(
+        ("project_id" = Uuid, Path, description = "Project id"),
+        ("room_id" = Uuid, Path, description = "Room id"),
+    ),
+    request_body = RoomInput,
+    responses(
+        (status = 200, description = "Room updated", body = MessageResponse),
+        (status = 400, description = "Invalid fields", body = ErrorResponse),
+        (status = 404, description = "Room not found", body = ErrorResponse),
+    )
+)]
 pub async fn update_room(
     State(state): State<Arc<AppState>>,
     Path((project_id, room_id)): Path<(Uuid, Uuid)>,
@@ -750,15 +1334,17 @@ pub async fn update_room(
 ) -> Result<impl IntoResponse, ApiError> {
     verify_project_access(&state, project_id, auth.user_id).await?;
 
-    let finishes = input.finishes.map(|f| serde_json::to_value(f).unwrap_or(serde_json::json!({})));
-    let fixtures = input.fixtures.map(|f| serde_json::to_value(f).unwrap_or(serde_json::json!([])));
+    let finishes = serde_json::to_value(input.finishes.unwrap_or_default())
+        .unwrap_or(serde_json::json!({}));
+    let fixtures = serde_json::to_value(input.fixtures.unwrap_or_default())
+        .unwrap_or(serde_json::json!([]));
 
     let result = sqlx::query(
         r#"
         UPDATE extracted_rooms SET
             room_name = $1, room_number = $2, room_type = $3, floor = $4,
             area_sqft = $5, ceiling_height = $6, perimeter_ft = $7,
-            finishes = COALESCE($8, finishes), fixtures = COALESCE($9, fixtures),
+            finishes = $8, fixtures = $9,
             notes = $10, source_page = $11, updated_at = NOW()
         WHERE id = $12 AND project_id = $13
         "#,
@@ -770,24 +1356,111 @@ pub async fn update_room(
     .bind(input.area_sqft)
     .bind(input.ceiling_height)
     .bind(input.perimeter_ft)
-    .bind(finishes)
-    .bind(fixtures)
+    .bind(&finishes)
+    .bind(&fixtures)
     .bind(&input.notes)
     .bind(input.source_page)
     .bind(room_id)
     .bind(project_id)
     .execute(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Failed to update room: {}", e)))?;
+    .map_err(map_db_err("update_room"))?;
 
     if result.rows_affected() == 0 {
         return Err(ApiError::not_found("Room not found"));
     }
 
-    Ok(Json(serde_json::json!({ "success": true })))
+    Ok(MessageResponse::new("Room updated"))
+}
+
+/// PATCH /api/projects/:project_id/extraction/rooms/:room_id
+#[utoipa::path(
+    patch,
+    path = "/projects/{project_id}/extraction/rooms/{room_id}",
+    tag = "extraction",
+    params(
+        ("project_id" = Uuid, Path, description = "Project id"),
+        ("room_id" = Uuid, Path, description = "Room id"),
+    ),
+    request_body = RoomPatch,
+    responses(
+        (status = 200, description = "Updated room", body = ExtractedRoomData),
+        (status = 400, description = "Invalid fields", body = ErrorResponse),
+        (status = 404, description = "Room not found", body = ErrorResponse),
+    )
+)]
+pub async fn patch_room(
+    State(state): State<Arc<AppState>>,
+    Path((project_id, room_id)): Path<(Uuid, Uuid)>,
+    auth: RequireAuth,
+    Json(input): Json<RoomPatch>,
+) -> Result<impl IntoResponse, ApiError> {
+    verify_project_access(&state, project_id, auth.user_id).await?;
+
+    if input.room_name.as_deref().is_some_and(|n| n.trim().is_empty()) {
+        return Err(ApiError::bad_request("room_name must not be empty"));
+    }
+
+    let finishes = input.finishes.map(|f| serde_json::to_value(f).unwrap_or(serde_json::json!({})));
+    let fixtures = input.fixtures.map(|f| serde_json::to_value(f).unwrap_or(serde_json::json!([])));
+
+    let row = sqlx::query_as::<_, ExtractedRoomRow>(
+        r#"
+        UPDATE extracted_rooms SET
+            room_name = COALESCE($1, room_name),
+            room_number = COALESCE($2, room_number),
+            room_type = COALESCE($3, room_type),
+            floor = COALESCE($4, floor),
+            area_sqft = COALESCE($5, area_sqft),
+            ceiling_height = COALESCE($6, ceiling_height),
+            perimeter_ft = COALESCE($7, perimeter_ft),
+            finishes = COALESCE($8, finishes),
+            fixtures = COALESCE($9, fixtures),
+            notes = COALESCE($10, notes),
+            source_page = COALESCE($11, source_page),
+            updated_at = NOW()
+        WHERE id = $12 AND project_id = $13
+        RETURNING id, project_id, document_id, document_version, room_name, room_number, room_type,
+                  floor, area_sqft, ceiling_height, perimeter_ft, finishes, fixtures,
+                  notes, source_page, confidence, is_verified, verified_at,
+                  created_at, updated_at
+        "#,
+    )
+    .bind(&input.room_name)
+    .bind(&input.room_number)
+    .bind(&input.room_type)
+    .bind(&input.floor)
+    .bind(input.area_sqft)
+    .bind(input.ceiling_height)
+    .bind(input.perimeter_ft)
+    .bind(finishes)
+    .bind(fixtures)
+    .bind(&input.notes)
+    .bind(input.source_page)
+    .bind(room_id)
+    .bind(project_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("patch_room"))?
+    .ok_or_else(|| ApiError::not_found("Room not found"))?;
+
+    Ok(Json(DataResponse::new(ExtractedRoomResponse::from(row))))
 }
 
 /// DELETE /api/projects/:project_id/extraction/rooms/:room_id
+#[utoipa::path(
+    delete,
+    path = "/projects/{project_id}/extraction/rooms/{room_id}",
+    tag = "extraction",
+    params(
+        ("project_id" = Uuid, Path, description = "Project id"),
+        ("room_id" = Uuid, Path, description = "Room id"),
+    ),
+    responses(
+        (status = 204, description = "Room deleted"),
+        (status = 404, description = "Room not found", body = ErrorResponse),
+    )
+)]
 pub async fn delete_room(
     State(state): State<Arc<AppState>>,
     Path((project_id, room_id)): Path<(Uuid, Uuid)>,
@@ -800,13 +1473,13 @@ pub async fn delete_room(
         .bind(project_id)
         .execute(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Failed to delete room: {}", e)))?;
+        .map_err(map_db_err("delete_room"))?;
 
     if result.rows_affected() == 0 {
         return Err(ApiError::not_found("Room not found"));
     }
 
-    Ok(Json(serde_json::json!({ "success": true })))
+    Ok(NoContent)
 }
 
 // ============================================================================
@@ -822,17 +1495,32 @@ pub struct MilestoneQueryParams {
 }
 
 /// GET /api/projects/:project_id/extraction/milestones
+#[utoipa::path(
+    get,
+    path = "/projects/{project_id}/extraction/milestones",
+    tag = "extraction",
+    params(
+        ("project_id" = Uuid, Path, description = "Project id"),
+        PaginationParams,
+        MilestoneQuery,
+        ConfidenceQuery,
+    ),
+    responses(
+        (status = 200, description = "Extracted milestones", body = MilestonePage),
+        (status = 403, description = "No access to this project", body = ErrorResponse),
+    )
+)]
 pub async fn list_milestones(
     State(state): State<Arc<AppState>>,
     Path(project_id): Path<Uuid>,
     Query(query): Query<MilestoneQueryParams>,
+    Query(review): Query<ConfidenceQuery>,
     auth: RequireAuth,
 ) -> Result<impl IntoResponse, ApiError> {
     verify_project_access(&state, project_id, auth.user_id).await?;
+    review.validate().map_err(ApiError::bad_request)?;
 
-    let page = query.pagination.page.unwrap_or(1).max(1);
-    let per_page = query.pagination.per_page.unwrap_or(50).min(100);
-    let offset = ((page - 1) * per_page) as i64;
+    let page = resolve_pagination(&query.pagination, state.settings.pagination.extraction)?;
 
     let total: i64 = sqlx::query_scalar(
         r#"
@@ -841,94 +1529,70 @@ pub async fn list_milestones(
         AND ($2::text IS NULL OR phase = $2)
         AND ($3::text IS NULL OR status = $3)
         AND ($4::bool IS NULL OR is_verified = $4)
+        AND ($5::float8 IS NULL OR confidence >= $5)
+        AND ($6::float8 IS NULL OR confidence <= $6)
         "#,
     )
     .bind(project_id)
     .bind(&query.filter.phase)
     .bind(&query.filter.status)
     .bind(query.filter.is_verified)
+    .bind(review.min_confidence)
+    .bind(review.max_confidence)
     .fetch_one(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("list_milestones"))?;
 
-    let rows = sqlx::query_as::<_, MilestoneRow>(
+    let query_str = format!(
         r#"
         SELECT id, project_id, name, description, phase, phase_order,
                estimated_duration_days, estimated_start_date, estimated_end_date,
                actual_start_date, actual_end_date, dependencies, trades_involved,
-               deliverables, status, progress, is_ai_generated, is_verified,
+               deliverables, status, progress, is_ai_generated, confidence, is_verified,
                verified_at, created_at, updated_at
         FROM project_milestones
         WHERE project_id = $1
         AND ($2::text IS NULL OR phase = $2)
         AND ($3::text IS NULL OR status = $3)
         AND ($4::bool IS NULL OR is_verified = $4)
-        ORDER BY phase_order, estimated_start_date
+        AND ($7::float8 IS NULL OR confidence >= $7)
+        AND ($8::float8 IS NULL OR confidence <= $8)
+        ORDER BY {}
         LIMIT $5 OFFSET $6
         "#,
-    )
-    .bind(project_id)
-    .bind(&query.filter.phase)
-    .bind(&query.filter.status)
-    .bind(query.filter.is_verified)
-    .bind(per_page as i64)
-    .bind(offset)
-    .fetch_all(&state.db)
-    .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
-
-    let data: Vec<MilestoneResponse> = rows
-        .into_iter()
-        .map(|r| {
-            let dependencies: Vec<String> =
-                serde_json::from_value(r.dependencies).unwrap_or_default();
-            let trades_involved: Vec<String> =
-                serde_json::from_value(r.trades_involved).unwrap_or_default();
-            let deliverables: Vec<String> =
-                serde_json::from_value(r.deliverables).unwrap_or_default();
-
-            MilestoneResponse {
-                id: r.id,
-                project_id: r.project_id,
-                name: r.name,
-                description: r.description,
-                phase: r.phase,
-                phase_order: r.phase_order,
-                estimated_duration_days: r.estimated_duration_days,
-                estimated_start_date: r.estimated_start_date,
-                estimated_end_date: r.estimated_end_date,
-                actual_start_date: r.actual_start_date,
-                actual_end_date: r.actual_end_date,
-                dependencies,
-                trades_involved,
-                deliverables,
-                status: r.status,
-                progress: decimal_to_f64(r.progress),
-                is_ai_generated: r.is_ai_generated,
-                is_verified: r.is_verified,
-                verified_at: r.verified_at,
-                created_at: r.created_at,
-                updated_at: r.updated_at,
-            }
-        })
-        .collect();
-
-    let total_pages = ((total as f64) / (per_page as f64)).ceil() as u32;
-
-    Ok(Json(Paginated {
-        data,
-        pagination: PaginationMeta {
-            page,
-            per_page,
-            total_items: total as u64,
-            total_pages,
-            has_next: page < total_pages,
-            has_prev: page > 1,
-        },
-    }))
+        order_by(review.sort, "phase_order, estimated_start_date")
+    );
+    let rows = sqlx::query_as::<_, MilestoneRow>(&query_str)
+        .bind(project_id)
+        .bind(&query.filter.phase)
+        .bind(&query.filter.status)
+        .bind(query.filter.is_verified)
+        .bind(page.limit())
+        .bind(page.offset())
+        .bind(review.min_confidence)
+        .bind(review.max_confidence)
+        .fetch_all(&state.db)
+        .await
+        .map_err(map_db_err("list_milestones"))?;
+
+    let data: Vec<MilestoneResponse> = rows.into_iter().map(MilestoneResponse::from).collect();
+
+    Ok(Json(Paginated::new(data, &page, total as u64)))
 }
 
 /// POST /api/projects/:project_id/extraction/milestones
+#[utoipa::path(
+    post,
+    path = "/projects/{project_id}/extraction/milestones",
+    tag = "extraction",
+    params(("project_id" = Uuid, Path, description = "Project id")),
+    request_body = MilestoneInput,
+    responses(
+        (status = 201, description = "Milestone created", body = MilestoneData),
+        (status = 400, description = "Invalid fields", body = ErrorResponse),
+        (status = 403, description = "No access to this project", body = ErrorResponse),
+    )
+)]
 pub async fn create_milestone(
     State(state): State<Arc<AppState>>,
     Path(project_id): Path<Uuid>,
@@ -945,14 +1609,19 @@ pub async fn create_milestone(
     let deliverables = serde_json::to_value(input.deliverables.unwrap_or_default())
         .unwrap_or(serde_json::json!([]));
 
-    sqlx::query(
+    let row = sqlx::query_as::<_, MilestoneRow>(
         r#"
         INSERT INTO project_milestones (
             id, project_id, name, description, phase, phase_order,
             estimated_duration_days, estimated_start_date, estimated_end_date,
             dependencies, trades_involved, deliverables, status, progress,
-            is_ai_generated, is_verified
-        ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, false, false)
+            is_ai_generated, confidence, is_verified
+        ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, false, 1.0, false)
+        RETURNING id, project_id, name, description, phase, phase_order,
+                  estimated_duration_days, estimated_start_date, estimated_end_date,
+                  actual_start_date, actual_end_date, dependencies, trades_involved,
+                  deliverables, status, progress, is_ai_generated, confidence, is_verified,
+                  verified_at, created_at, updated_at
         "#,
     )
     .bind(id)
@@ -969,14 +1638,29 @@ pub async fn create_milestone(
     .bind(&deliverables)
     .bind(input.status.as_deref().unwrap_or("pending"))
     .bind(input.progress.unwrap_or(0.0))
-    .execute(&state.db)
+    .fetch_one(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Failed to create milestone: {}", e)))?;
+    .map_err(map_db_err("create_milestone"))?;
 
-    Ok(Json(serde_json::json!({ "id": id, "success": true })))
+    Ok(Created(MilestoneResponse::from(row)))
 }
 
 /// PUT /api/projects/:project_id/extraction/milestones/:milestone_id
+#[utoipa::path(
+    put,
+    path = "/projects/{project_id}/extraction/milestones/{milestone_id}",
+    tag = "extraction",
+    params(
+        ("project_id" = Uuid, Path, description = "Project id"),
+        ("milestone_id" = Uuid, Path, description = "Milestone id"),
+    ),
+    request_body = MilestoneInput,
+    responses(
+        (status = 200, description = "Milestone updated", body = MessageResponse),
+        (status = 400, description = "Invalid fields", body = ErrorResponse),
+        (status = 404, description = "Milestone not found", body = ErrorResponse),
+    )
+)]
 pub async fn update_milestone(
     State(state): State<Arc<AppState>>,
     Path((project_id, milestone_id)): Path<(Uuid, Uuid)>,
@@ -985,14 +1669,86 @@ pub async fn update_milestone(
 ) -> Result<impl IntoResponse, ApiError> {
     verify_project_access(&state, project_id, auth.user_id).await?;
 
+    let dependencies = serde_json::to_value(input.dependencies.unwrap_or_default())
+        .unwrap_or(serde_json::json!([]));
+    let trades_involved = serde_json::to_value(input.trades_involved.unwrap_or_default())
+        .unwrap_or(serde_json::json!([]));
+    let deliverables = serde_json::to_value(input.deliverables.unwrap_or_default())
+        .unwrap_or(serde_json::json!([]));
+
+    let result = sqlx::query(
+        r#"
+        UPDATE project_milestones SET
+            name = $1, description = $2, phase = $3, phase_order = $4,
+            estimated_duration_days = $5, estimated_start_date = $6, estimated_end_date = $7,
+            dependencies = $8, trades_involved = $9, deliverables = $10,
+            status = $11, progress = $12, updated_at = NOW()
+        WHERE id = $13 AND project_id = $14
+        "#,
+    )
+    .bind(&input.name)
+    .bind(&input.description)
+    .bind(&input.phase)
+    .bind(input.phase_order.unwrap_or(0))
+    .bind(input.estimated_duration_days)
+    .bind(input.estimated_start_date)
+    .bind(input.estimated_end_date)
+    .bind(&dependencies)
+    .bind(&trades_involved)
+    .bind(&deliverables)
+    .bind(input.status.as_deref().unwrap_or("pending"))
+    .bind(input.progress.unwrap_or(0.0))
+    .bind(milestone_id)
+    .bind(project_id)
+    .execute(&state.db)
+    .await
+    .map_err(map_db_err("update_milestone"))?;
+
+    if result.rows_affected() == 0 {
+        return Err(ApiError::not_found("Milestone not found"));
+    }
+
+    Ok(MessageResponse::new("Milestone updated"))
+}
+
+/// PATCH /api/projects/:project_id/extraction/milestones/:milestone_id
+#[utoipa::path(
+    patch,
+    path = "/projects/{project_id}/extraction/milestones/{milestone_id}",
+    tag = "extraction",
+    params(
+        ("project_id" = Uuid, Path, description = "Project id"),
+        ("milestone_id" = Uuid, Path, description = "Milestone id"),
+    ),
+    request_body = MilestonePatch,
+    responses(
+        (status = 200, description = "Updated milestone", body = MilestoneData),
+        (status = 400, description = "Invalid fields", body = ErrorResponse),
+        (status = 404, description = "Milestone not found", body = ErrorResponse),
+    )
+)]
+pub async fn patch_milestone(
+    State(state): State<Arc<AppState>>,
+    Path((project_id, milestone_id)): Path<(Uuid, Uuid)>,
+    auth: RequireAuth,
+    Json(input): Json<MilestonePatch>,
+) -> Result<impl IntoResponse, ApiError> {
+    verify_project_access(&state, project_id, auth.user_id).await?;
+
+    if input.name.as_deref().is_some_and(|n| n.trim().is_empty()) {
+        return Err(ApiError::bad_request("name must not be empty"));
+    }
+
     let dependencies = input.dependencies.map(|d| serde_json::to_value(d).unwrap_or(serde_json::json!([])));
     let trades_involved = input.trades_involved.map(|t| serde_json::to_value(t).unwrap_or(serde_json::json!([])));
     let deliverables = input.deliverables.map(|d| serde_json::to_value(d).unwrap_or(serde_json::json!([])));
 
-    let result = sqlx::query(
+    let row = sqlx::query_as::<_, MilestoneRow>(
         r#"
         UPDATE project_milestones SET
-            name = $1, description = $2, phase = COALESCE($3, phase),
+            name = COALESCE($1, name),
+            description = COALESCE($2, description),
+            phase = COALESCE($3, phase),
             phase_order = COALESCE($4, phase_order),
             estimated_duration_days = COALESCE($5, estimated_duration_days),
             estimated_start_date = COALESCE($6, estimated_start_date),
@@ -1004,6 +1760,11 @@ pub async fn update_milestone(
             progress = COALESCE($12, progress),
             updated_at = NOW()
         WHERE id = $13 AND project_id = $14
+        RETURNING id, project_id, name, description, phase, phase_order,
+                  estimated_duration_days, estimated_start_date, estimated_end_date,
+                  actual_start_date, actual_end_date, dependencies, trades_involved,
+                  deliverables, status, progress, is_ai_generated, confidence, is_verified,
+                  verified_at, created_at, updated_at
         "#,
     )
     .bind(&input.name)
@@ -1020,18 +1781,28 @@ pub async fn update_milestone(
     .bind(input.progress)
     .bind(milestone_id)
     .bind(project_id)
-    .execute(&state.db)
+    .fetch_optional(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Failed to update milestone: {}", e)))?;
-
-    if result.rows_affected() == 0 {
-        return Err(ApiError::not_found("Milestone not found"));
-    }
+    .map_err(map_db_err("patch_milestone"))?
+    .ok_or_else(|| ApiError::not_found("Milestone not found"))?;
 
-    Ok(Json(serde_json::json!({ "success": true })))
+    Ok(Json(DataResponse::new(MilestoneResponse::from(row))))
 }
 
 /// DELETE /api/projects/:project_id/extraction/milestones/:milestone_id
+#[utoipa::path(
+    delete,
+    path = "/projects/{project_id}/extraction/milestones/{milestone_id}",
+    tag = "extraction",
+    params(
+        ("project_id" = Uuid, Path, description = "Project id"),
+        ("milestone_id" = Uuid, Path, description = "Milestone id"),
+    ),
+    responses(
+        (status = 204, description = "Milestone deleted"),
+        (status = 404, description = "Milestone not found", body = ErrorResponse),
+    )
+)]
 pub async fn delete_milestone(
     State(state): State<Arc<AppState>>,
     Path((project_id, milestone_id)): Path<(Uuid, Uuid)>,
@@ -1044,13 +1815,13 @@ pub async fn delete_milestone(
         .bind(project_id)
         .execute(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Failed to delete milestone: {}", e)))?;
+        .map_err(map_db_err("delete_milestone"))?;
 
     if result.rows_affected() == 0 {
         return Err(ApiError::not_found("Milestone not found"));
     }
 
-    Ok(Json(serde_json::json!({ "success": true })))
+    Ok(NoContent)
 }
 
 // ============================================================================
@@ -1066,111 +1837,103 @@ pub struct TradeScopeQueryParams {
 }
 
 /// GET /api/projects/:project_id/extraction/trade-scopes
+#[utoipa::path(
+    get,
+    path = "/projects/{project_id}/extraction/trade-scopes",
+    tag = "extraction",
+    params(
+        ("project_id" = Uuid, Path, description = "Project id"),
+        PaginationParams,
+        TradeScopeQuery,
+        ConfidenceQuery,
+    ),
+    responses(
+        (status = 200, description = "Extracted trade scopes", body = TradeScopePage),
+        (status = 403, description = "No access to this project", body = ErrorResponse),
+    )
+)]
 pub async fn list_trade_scopes(
     State(state): State<Arc<AppState>>,
     Path(project_id): Path<Uuid>,
     Query(query): Query<TradeScopeQueryParams>,
+    Query(review): Query<ConfidenceQuery>,
     auth: RequireAuth,
 ) -> Result<impl IntoResponse, ApiError> {
     verify_project_access(&state, project_id, auth.user_id).await?;
+    review.validate().map_err(ApiError::bad_request)?;
 
-    let page = query.pagination.page.unwrap_or(1).max(1);
-    let per_page = query.pagination.per_page.unwrap_or(50).min(100);
-    let offset = ((page - 1) * per_page) as i64;
+    let page = resolve_pagination(&query.pagination, state.settings.pagination.extraction)?;
+    let trade_filter = normalized_trade_key(query.filter.trade.as_deref(), None);
 
     let total: i64 = sqlx::query_scalar(
         r#"
         SELECT COUNT(*) FROM extracted_trade_scopes 
         WHERE project_id = $1
-        AND ($2::text IS NULL OR trade ILIKE '%' || $2 || '%')
+        AND ($2::text IS NULL OR trade ILIKE '%' || $2 || '%' OR normalized_trade = $4)
         AND ($3::bool IS NULL OR is_verified = $3)
+        AND ($5::float8 IS NULL OR confidence >= $5)
+        AND ($6::float8 IS NULL OR confidence <= $6)
         "#,
     )
     .bind(project_id)
     .bind(&query.filter.trade)
     .bind(query.filter.is_verified)
+    .bind(trade_filter)
+    .bind(review.min_confidence)
+    .bind(review.max_confidence)
     .fetch_one(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("list_trade_scopes"))?;
 
-    let rows = sqlx::query_as::<_, TradeScopeRow>(
+    let query_str = format!(
         r#"
-        SELECT id, project_id, document_id, trade, trade_display_name, csi_division,
+        SELECT id, project_id, document_id, document_version, trade, trade_display_name,
+               normalized_trade, csi_division,
                inclusions, exclusions, required_sheets, spec_sections, rfi_needed,
                assumptions, estimated_value, confidence, is_verified, verified_at,
                created_at, updated_at
         FROM extracted_trade_scopes
         WHERE project_id = $1
-        AND ($2::text IS NULL OR trade ILIKE '%' || $2 || '%')
+        AND ($2::text IS NULL OR trade ILIKE '%' || $2 || '%' OR normalized_trade = $6)
         AND ($3::bool IS NULL OR is_verified = $3)
-        ORDER BY csi_division, trade
+        AND ($7::float8 IS NULL OR confidence >= $7)
+        AND ($8::float8 IS NULL OR confidence <= $8)
+        ORDER BY {}
         LIMIT $4 OFFSET $5
         "#,
-    )
-    .bind(project_id)
-    .bind(&query.filter.trade)
-    .bind(query.filter.is_verified)
-    .bind(per_page as i64)
-    .bind(offset)
-    .fetch_all(&state.db)
-    .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
-
-    let data: Vec<TradeScopeResponse> = rows
-        .into_iter()
-        .map(|r| {
-            let inclusions: Vec<ScopeItem> =
-                serde_json::from_value(r.inclusions).unwrap_or_default();
-            let exclusions: Vec<ScopeItem> =
-                serde_json::from_value(r.exclusions).unwrap_or_default();
-            let required_sheets: Vec<String> =
-                serde_json::from_value(r.required_sheets).unwrap_or_default();
-            let spec_sections: Vec<String> =
-                serde_json::from_value(r.spec_sections).unwrap_or_default();
-            let rfi_needed: Vec<String> =
-                serde_json::from_value(r.rfi_needed).unwrap_or_default();
-            let assumptions: Vec<String> =
-                serde_json::from_value(r.assumptions).unwrap_or_default();
-
-            TradeScopeResponse {
-                id: r.id,
-                project_id: r.project_id,
-                document_id: r.document_id,
-                trade: r.trade,
-                trade_display_name: r.trade_display_name,
-                csi_division: r.csi_division,
-                inclusions,
-                exclusions,
-                required_sheets,
-                spec_sections,
-                rfi_needed,
-                assumptions,
-                estimated_value: decimal_opt_to_f64(r.estimated_value),
-                confidence: decimal_to_f64(r.confidence),
-                is_verified: r.is_verified,
-                verified_at: r.verified_at,
-                created_at: r.created_at,
-                updated_at: r.updated_at,
-            }
-        })
-        .collect();
-
-    let total_pages = ((total as f64) / (per_page as f64)).ceil() as u32;
-
-    Ok(Json(Paginated {
-        data,
-        pagination: PaginationMeta {
-            page,
-            per_page,
-            total_items: total as u64,
-            total_pages,
-            has_next: page < total_pages,
-            has_prev: page > 1,
-        },
-    }))
+        order_by(review.sort, "csi_division, trade")
+    );
+    let rows = sqlx::query_as::<_, TradeScopeRow>(&query_str)
+        .bind(project_id)
+        .bind(&query.filter.trade)
+        .bind(query.filter.is_verified)
+        .bind(page.limit())
+        .bind(page.offset())
+        .bind(trade_filter)
+        .bind(review.min_confidence)
+        .bind(review.max_confidence)
+        .fetch_all(&state.db)
+        .await
+        .map_err(map_db_err("list_trade_scopes"))?;
+
+    let data: Vec<TradeScopeResponse> = rows.into_iter().map(TradeScopeResponse::from).collect();
+
+    Ok(Json(Paginated::new(data, &page, total as u64)))
 }
 
 /// POST /api/projects/:project_id/extraction/trade-scopes
+#[utoipa::path(
+    post,
+    path = "/projects/{project_id}/extraction/trade-scopes",
+    tag = "extraction",
+    params(("project_id" = Uuid, Path, description = "Project id")),
+    request_body = TradeScopeInput,
+    responses(
+        (status = 201, description = "Trade scope created", body = TradeScopeData),
+        (status = 400, description = "Invalid fields", body = ErrorResponse),
+        (status = 403, description = "No access to this project", body = ErrorResponse),
+    )
+)]
 pub async fn create_trade_scope(
     State(state): State<Arc<AppState>>,
     Path(project_id): Path<Uuid>,
@@ -1193,13 +1956,18 @@ pub async fn create_trade_scope(
     let assumptions = serde_json::to_value(input.assumptions.unwrap_or_default())
         .unwrap_or(serde_json::json!([]));
 
-    sqlx::query(
+    let row = sqlx::query_as::<_, TradeScopeRow>(
         r#"
         INSERT INTO extracted_trade_scopes (
             id, project_id, trade, trade_display_name, csi_division,
             inclusions, exclusions, required_sheets, spec_sections,
-            rfi_needed, assumptions, estimated_value, confidence, is_verified
-        ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, 1.0, false)
+            rfi_needed, assumptions, estimated_value, normalized_trade, confidence, is_verified
+        ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, 1.0, false)
+        RETURNING id, project_id, document_id, document_version, trade, trade_display_name,
+                  normalized_trade, csi_division,
+                  inclusions, exclusions, required_sheets, spec_sections, rfi_needed,
+                  assumptions, estimated_value, confidence, is_verified, verified_at,
+                  created_at, updated_at
         "#,
     )
     .bind(id)
@@ -1214,14 +1982,30 @@ pub async fn create_trade_scope(
     .bind(&rfi_needed)
     .bind(&assumptions)
     .bind(input.estimated_value)
-    .execute(&state.db)
+    .bind(normalized_trade_key(Some(&input.trade), input.csi_division.as_deref()))
+    .fetch_one(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Failed to create trade scope: {}", e)))?;
+    .map_err(map_db_err("create_trade_scope"))?;
 
-    Ok(Json(serde_json::json!({ "id": id, "success": true })))
+    Ok(Created(TradeScopeResponse::from(row)))
 }
 
 /// PUT /api/projects/:project_id/extraction/trade-scopes/:scope_id
+#[utoipa::path(
+    put,
+    path = "/projects/{project_id}/extraction/trade-scopes/{scope_id}",
+    tag = "extraction",
+    params(
+        ("project_id" = Uuid, Path, description = "Project id"),
+        ("scope_id" = Uuid, Path, description = "Trade scope id"),
+    ),
+    request_body = TradeScopeInput,
+    responses(
+        (status = 200, description = "Trade scope updated", body = MessageResponse),
+        (status = 400, description = "Invalid fields", body = ErrorResponse),
+        (status = 404, description = "Trade scope not found", body = ErrorResponse),
+    )
+)]
 pub async fn update_trade_scope(
     State(state): State<Arc<AppState>>,
     Path((project_id, scope_id)): Path<(Uuid, Uuid)>,
@@ -1230,6 +2014,82 @@ pub async fn update_trade_scope(
 ) -> Result<impl IntoResponse, ApiError> {
     verify_project_access(&state, project_id, auth.user_id).await?;
 
+    let inclusions = serde_json::to_value(input.inclusions.unwrap_or_default())
+        .unwrap_or(serde_json::json!([]));
+    let exclusions = serde_json::to_value(input.exclusions.unwrap_or_default())
+        .unwrap_or(serde_json::json!([]));
+    let required_sheets = serde_json::to_value(input.required_sheets.unwrap_or_default())
+        .unwrap_or(serde_json::json!([]));
+    let spec_sections = serde_json::to_value(input.spec_sections.unwrap_or_default())
+        .unwrap_or(serde_json::json!([]));
+    let rfi_needed = serde_json::to_value(input.rfi_needed.unwrap_or_default())
+        .unwrap_or(serde_json::json!([]));
+    let assumptions = serde_json::to_value(input.assumptions.unwrap_or_default())
+        .unwrap_or(serde_json::json!([]));
+
+    let result = sqlx::query(
+        r#"
+        UPDATE extracted_trade_scopes SET
+            trade = $1, trade_display_name = $2, csi_division = $3,
+            inclusions = $4, exclusions = $5, required_sheets = $6,
+            spec_sections = $7, rfi_needed = $8, assumptions = $9,
+            estimated_value = $10, normalized_trade = $13,
+            updated_at = NOW()
+        WHERE id = $11 AND project_id = $12
+        "#,
+    )
+    .bind(&input.trade)
+    .bind(&input.trade_display_name)
+    .bind(&input.csi_division)
+    .bind(&inclusions)
+    .bind(&exclusions)
+    .bind(&required_sheets)
+    .bind(&spec_sections)
+    .bind(&rfi_needed)
+    .bind(&assumptions)
+    .bind(input.estimated_value)
+    .bind(scope_id)
+    .bind(project_id)
+    .bind(normalized_trade_key(Some(&input.trade), input.csi_division.as_deref()))
+    .execute(&state.db)
+    .await
+    .map_err(map_db_err("update_trade_scope"))?;
+
+    if result.rows_affected() == 0 {
+        return Err(ApiError::not_found("Trade scope not found"));
+    }
+
+    Ok(MessageResponse::new("Trade scope updated"))
+}
+
+/// PATCH /api/projects/:project_id/extraction/trade-scopes/:scope_id
+#[utoipa::path(
+    patch,
+    path = "/projects/{project_id}/extraction/trade-scopes/{scope_id}",
+    tag = "extraction",
+    params(
+        ("project_id" = Uuid, Path, description = "Project id"),
+        ("scope_id" = Uuid, Path, description = "Trade scope id"),
+    ),
+    request_body = TradeScopePatch,
+    responses(
+        (status = 200, description = "Updated trade scope", body = TradeScopeData),
+        (status = 400, description = "Invalid fields", body = ErrorResponse),
+        (status = 404, description = "Trade scope not found", body = ErrorResponse),
+    )
+)]
+pub async fn patch_trade_scope(
+    State(state): State<Arc<AppState>>,
+    Path((project_id, scope_id)): Path<(Uuid, Uuid)>,
+    auth: RequireAuth,
+    Json(input): Json<TradeScopePatch>,
+) -> Result<impl IntoResponse, ApiError> {
+    verify_project_access(&state, project_id, auth.user_id).await?;
+
+    if input.trade.as_deref().is_some_and(|t| t.trim().is_empty()) {
+        return Err(ApiError::bad_request("trade must not be empty"));
+    }
+
     let inclusions = input.inclusions.map(|i| serde_json::to_value(i).unwrap_or(serde_json::json!([])));
     let exclusions = input.exclusions.map(|e| serde_json::to_value(e).unwrap_or(serde_json::json!([])));
     let required_sheets = input.required_sheets.map(|r| serde_json::to_value(r).unwrap_or(serde_json::json!([])));
@@ -1237,10 +2097,11 @@ pub async fn update_trade_scope(
     let rfi_needed = input.rfi_needed.map(|r| serde_json::to_value(r).unwrap_or(serde_json::json!([])));
     let assumptions = input.assumptions.map(|a| serde_json::to_value(a).unwrap_or(serde_json::json!([])));
 
-    let result = sqlx::query(
+    let row = sqlx::query_as::<_, TradeScopeRow>(
         r#"
         UPDATE extracted_trade_scopes SET
-            trade = $1, trade_display_name = COALESCE($2, trade_display_name),
+            trade = COALESCE($1, trade),
+            trade_display_name = COALESCE($2, trade_display_name),
             csi_division = COALESCE($3, csi_division),
             inclusions = COALESCE($4, inclusions),
             exclusions = COALESCE($5, exclusions),
@@ -1249,8 +2110,14 @@ pub async fn update_trade_scope(
             rfi_needed = COALESCE($8, rfi_needed),
             assumptions = COALESCE($9, assumptions),
             estimated_value = COALESCE($10, estimated_value),
+            normalized_trade = COALESCE($13, normalized_trade),
             updated_at = NOW()
         WHERE id = $11 AND project_id = $12
+        RETURNING id, project_id, document_id, document_version, trade, trade_display_name,
+                  normalized_trade, csi_division,
+                  inclusions, exclusions, required_sheets, spec_sections, rfi_needed,
+                  assumptions, estimated_value, confidence, is_verified, verified_at,
+                  created_at, updated_at
         "#,
     )
     .bind(&input.trade)
@@ -1265,18 +2132,29 @@ pub async fn update_trade_scope(
     .bind(input.estimated_value)
     .bind(scope_id)
     .bind(project_id)
-    .execute(&state.db)
+    .bind(normalized_trade_key(input.trade.as_deref(), input.csi_division.as_deref()))
+    .fetch_optional(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Failed to update trade scope: {}", e)))?;
-
-    if result.rows_affected() == 0 {
-        return Err(ApiError::not_found("Trade scope not found"));
-    }
+    .map_err(map_db_err("patch_trade_scope"))?
+    .ok_or_else(|| ApiError::not_found("Trade scope not found"))?;
 
-    Ok(Json(serde_json::json!({ "success": true })))
+    Ok(Json(DataResponse::new(TradeScopeResponse::from(row))))
 }
 
 /// DELETE /api/projects/:project_id/extraction/trade-scopes/:scope_id
+#[utoipa::path(
+    delete,
+    path = "/projects/{project_id}/extraction/trade-scopes/{scope_id}",
+    tag = "extraction",
+    params(
+        ("project_id" = Uuid, Path, description = "Project id"),
+        ("scope_id" = Uuid, Path, description = "Trade scope id"),
+    ),
+    responses(
+        (status = 204, description = "Trade scope deleted"),
+        (status = 404, description = "Trade scope not found", body = ErrorResponse),
+    )
+)]
 pub async fn delete_trade_scope(
     State(state): State<Arc<AppState>>,
     Path((project_id, scope_id)): Path<(Uuid, Uuid)>,
@@ -1289,11 +2167,272 @@ pub async fn delete_trade_scope(
         .bind(project_id)
         .execute(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Failed to delete trade scope: {}", e)))?;
+        .map_err(map_db_err("delete_trade_scope"))?;
 
     if result.rows_affected() == 0 {
         return Err(ApiError::not_found("Trade scope not found"));
     }
 
-    Ok(Json(serde_json::json!({ "success": true })))
+    Ok(NoContent)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::Uri;
+    use std::time::{Duration, Instant};
+
+    use crate::test_support;
+
+    fn uri(query: &str) -> Uri {
+        format!("/projects/{}/extraction/materials?{}", Uuid::nil(), query)
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn list_handlers_accept_numeric_confidence_filters() {
+        let uri = uri("min_confidence=0.8&max_confidence=0.95&sort=confidence_asc&room=kitchen");
+
+        let Query(review) = Query::<ConfidenceQuery>::try_from_uri(&uri).unwrap();
+        assert_eq!(review.min_confidence, Some(0.8));
+        assert_eq!(review.max_confidence, Some(0.95));
+        assert_eq!(review.sort, Some(ExtractionSort::ConfidenceAsc));
+        assert!(review.validate().is_ok());
+
+        // Each list handler also extracts its own params from the same query
+        let Query(materials) = Query::<MaterialQueryParams>::try_from_uri(&uri).unwrap();
+        assert_eq!(materials.filter.room.as_deref(), Some("kitchen"));
+        assert!(Query::<RoomQueryParams>::try_from_uri(&uri).is_ok());
+        assert!(Query::<MilestoneQueryParams>::try_from_uri(&uri).is_ok());
+        assert!(Query::<TradeScopeQueryParams>::try_from_uri(&uri).is_ok());
+    }
+
+    #[test]
+    fn review_queue_accepts_numeric_confidence_filter() {
+        let Query(filter) = Query::<ReviewQueueQuery>::try_from_uri(&uri("max_confidence=0.5")).unwrap();
+        assert_eq!(filter.max_confidence, Some(0.5));
+    }
+
+    #[test]
+    fn confidence_bounds_are_validated() {
+        let Query(review) = Query::<ConfidenceQuery>::try_from_uri(&uri("min_confidence=1.5")).unwrap();
+        assert!(review.validate().is_err());
+
+        let Query(review) =
+            Query::<ConfidenceQuery>::try_from_uri(&uri("min_confidence=0.9&max_confidence=0.2")).unwrap();
+        assert!(review.validate().is_err());
+    }
+
+    /// A priced kitchen material on a new project. Returns the project id,
+    /// material id and the owner's user id.
+    async fn material(db: &sqlx::PgPool) -> (Uuid, Uuid, Uuid) {
+        let owner_id = test_support::user(db, "gc").await;
+        let project_id = test_support::project(db, owner_id).await;
+        let material_id = sqlx::query_scalar(
+            r#"
+            INSERT INTO extracted_materials
+                (project_id, name, description, quantity, unit, unit_cost, total_cost, room, trade_category)
+            VALUES ($1, 'Copper pipe', 'Type L', 10, 'ft', 12.50, 125.00, 'Kitchen', 'plumbing')
+            RETURNING id
+            "#,
+        )
+        .bind(project_id)
+        .fetch_one(db)
+        .await
+        .unwrap();
+        (project_id, material_id, owner_id)
+    }
+
+    async fn patch(state: &Arc<AppState>, ids: (Uuid, Uuid, Uuid), patch: serde_json::Value) -> ExtractedMaterialResponse {
+        let (project_id, material_id, owner_id) = ids;
+        let response = patch_material(
+            State(state.clone()),
+            Path((project_id, material_id)),
+            test_support::auth(owner_id),
+            Json(serde_json::from_value(patch).unwrap()),
+        )
+        .await
+        .unwrap()
+        .into_response();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        serde_json::from_value(body["data"].clone()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn material_patch_leaves_omitted_fields_alone() {
+        let Some(state) = test_support::state().await else { return };
+        let ids = material(&state.db).await;
+
+        let updated = patch(&state, ids, serde_json::json!({ "description": "Type M", "room": null })).await;
+
+        assert_eq!(updated.description.as_deref(), Some("Type M"));
+        assert_eq!(updated.name, "Copper pipe");
+        assert_eq!(updated.quantity, Some(10.0));
+        assert_eq!(updated.unit.as_deref(), Some("ft"));
+        assert_eq!(dollars(updated.unit_cost).as_deref(), Some("12.50"));
+        assert_eq!(dollars(updated.total_cost).as_deref(), Some("125.00"));
+        assert_eq!(updated.room.as_deref(), Some("Kitchen"));
+        assert_eq!(updated.trade_category.as_deref(), Some("plumbing"));
+    }
+
+    fn dollars(amount: Option<Money>) -> Option<String> {
+        amount.map(|a| a.to_string())
+    }
+
+    #[tokio::test]
+    async fn quantity_patch_totals_against_stored_unit_cost() {
+        let Some(state) = test_support::state().await else { return };
+        let ids = material(&state.db).await;
+
+        let updated = patch(&state, ids, serde_json::json!({ "quantity": 3.5 })).await;
+
+        assert_eq!(updated.quantity, Some(3.5));
+        assert_eq!(dollars(updated.unit_cost).as_deref(), Some("12.50"));
+        assert_eq!(dollars(updated.total_cost).as_deref(), Some("43.75"));
+    }
+
+    #[tokio::test]
+    async fn unit_cost_patch_totals_against_stored_quantity() {
+        let Some(state) = test_support::state().await else { return };
+        let ids = material(&state.db).await;
+
+        let updated = patch(&state, ids, serde_json::json!({ "unit_cost": "9.99" })).await;
+
+        assert_eq!(updated.quantity, Some(10.0));
+        assert_eq!(dollars(updated.total_cost).as_deref(), Some("99.90"));
+    }
+
+    /// Time a summary request against a database 50ms away, on a pool of
+    /// `connections`, after a few warm-up requests
+    async fn timed_summary(connections: u32) -> Option<Duration> {
+        let db = test_support::slow_db(Duration::from_millis(50), connections).await?;
+        let state = test_support::state_on(db).await?;
+        let owner_id = test_support::user(&state.db, "gc").await;
+        let project_id = test_support::project(&state.db, owner_id).await;
+
+        let mut elapsed = Duration::ZERO;
+        for _ in 0..4 {
+            let started = Instant::now();
+            get_extraction_summary(State(state.clone()), Path(project_id), test_support::auth(owner_id))
+                .await
+                .unwrap();
+            elapsed = started.elapsed();
+        }
+        Some(elapsed)
+    }
+
+    #[tokio::test]
+    async fn summary_queries_run_concurrently() {
+        // One connection runs the five summary queries one after another. The
+        // project access check before them is sequential either way.
+        let Some(sequential) = timed_summary(1).await else { return };
+        let Some(concurrent) = timed_summary(5).await else { return };
+
+        assert!(
+            concurrent * 3 < sequential * 2,
+            "concurrent {:?}, sequential {:?}",
+            concurrent,
+            sequential
+        );
+    }
+
+    #[tokio::test]
+    async fn extracted_records_carry_the_source_document_version() {
+        let Some(db) = test_support::db().await else { return };
+        let owner_id = test_support::user(&db, "gc").await;
+        let project_id = test_support::project(&db, owner_id).await;
+        let document_id: Uuid = sqlx::query_scalar(
+            "INSERT INTO documents (project_id, name, version) VALUES ($1, 'A-101.pdf', 2) RETURNING id",
+        )
+        .bind(project_id)
+        .fetch_one(&db)
+        .await
+        .unwrap();
+        let job_id: Uuid = sqlx::query_scalar(
+            "INSERT INTO processing_jobs (document_id, project_id, document_version) VALUES ($1, $2, 2) RETURNING id",
+        )
+        .bind(document_id)
+        .bind(project_id)
+        .fetch_one(&db)
+        .await
+        .unwrap();
+
+        // A newer revision arrives before the job's output is written
+        sqlx::query("UPDATE documents SET version = 3 WHERE id = $1")
+            .bind(document_id)
+            .execute(&db)
+            .await
+            .unwrap();
+
+        let material = sqlx::query_as::<_, ExtractedMaterialRow>(
+            "INSERT INTO extracted_materials (project_id, document_id, job_id, name) VALUES ($1, $2, $3, 'Copper pipe') RETURNING *",
+        )
+        .bind(project_id)
+        .bind(document_id)
+        .bind(job_id)
+        .fetch_one(&db)
+        .await
+        .unwrap();
+        assert_eq!(ExtractedMaterialResponse::from(material).document_version, Some(2));
+
+        let room = sqlx::query_as::<_, ExtractedRoomRow>(
+            "INSERT INTO extracted_rooms (project_id, document_id, room_name) VALUES ($1, $2, 'Kitchen') RETURNING *",
+        )
+        .bind(project_id)
+        .bind(document_id)
+        .fetch_one(&db)
+        .await
+        .unwrap();
+        assert_eq!(ExtractedRoomResponse::from(room).document_version, Some(3));
+    }
+
+    #[tokio::test]
+    async fn room_patch_leaves_omitted_fields_alone() {
+        let Some(state) = test_support::state().await else { return };
+        let owner_id = test_support::user(&state.db, "gc").await;
+        let project_id = test_support::project(&state.db, owner_id).await;
+        let room_id: Uuid = sqlx::query_scalar(
+            r#"
+            INSERT INTO extracted_rooms (project_id, room_name, room_number, floor, area_sqft, fixtures)
+            VALUES ($1, 'Kitchen', '101', '1', 180.5, '["sink"]')
+            RETURNING id
+            "#,
+        )
+        .bind(project_id)
+        .fetch_one(&state.db)
+        .await
+        .unwrap();
+
+        patch_room(
+            State(state.clone()),
+            Path((project_id, room_id)),
+            test_support::auth(owner_id),
+            Json(RoomPatch { notes: Some("Check vent".to_string()), ..Default::default() }),
+        )
+        .await
+        .unwrap();
+
+        let (name, number, floor, area, fixtures, notes): (
+            String,
+            Option<String>,
+            Option<String>,
+            Option<String>,
+            serde_json::Value,
+            Option<String>,
+        ) = sqlx::query_as(
+            "SELECT room_name, room_number, floor, area_sqft::text, fixtures, notes FROM extracted_rooms WHERE id = $1",
+        )
+        .bind(room_id)
+        .fetch_one(&state.db)
+        .await
+        .unwrap();
+        assert_eq!(name, "Kitchen");
+        assert_eq!(number.as_deref(), Some("101"));
+        assert_eq!(floor.as_deref(), Some("1"));
+        assert_eq!(area.as_deref(), Some("180.50"));
+        assert_eq!(fixtures, serde_json::json!(["sink"]));
+        assert_eq!(notes.as_deref(), Some("Check vent"));
+    }
 }
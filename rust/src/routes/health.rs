@@ -3,11 +3,14 @@ use serde::Serialize;
 use std::sync::Arc;
 
 use crate::app::AppState;
+use crate::build_info::BuildInfo;
+use crate::services::circuit_breaker::{CircuitSnapshot, CircuitState};
 
 #[derive(Serialize)]
 pub struct HealthResponse {
     pub status: String,
-    pub version: String,
+    #[serde(flatten)]
+    pub build: BuildInfo,
     pub services: ServiceHealth,
 }
 
@@ -16,6 +19,15 @@ pub struct ServiceHealth {
     pub database: String,
     pub redis: String,
     pub ai_service: String,
+    /// This instance's circuit breaker in front of the AI service
+    pub ai_circuit: CircuitSnapshot,
+}
+
+/// Version endpoint - public
+///
+/// Which build is running, for confirming a rollout landed.
+pub async fn version(State(state): State<Arc<AppState>>) -> Json<BuildInfo> {
+    Json(BuildInfo::new(&state.settings.env))
 }
 
 /// Health check endpoint - public
@@ -32,9 +44,11 @@ pub async fn health_check(
     let db_status = if db_result.is_ok() { "ok" } else { "error" };
     let redis_status = if redis_result.is_ok() { "ok" } else { "error" };
     let ai_status = if ai_result.is_ok() { "ok" } else { "error" };
+    let ai_circuit = state.ai_client.circuit();
+    let ai_ok = ai_result.is_ok() && ai_circuit.state == CircuitState::Closed;
 
     // Determine overall status
-    let status = if db_result.is_ok() && redis_result.is_ok() && ai_result.is_ok() {
+    let status = if db_result.is_ok() && redis_result.is_ok() && ai_ok {
         "healthy"
     } else if db_result.is_ok() {
         // DB is critical, others are degraded
@@ -54,11 +68,12 @@ pub async fn health_check(
         status_code,
         Json(HealthResponse {
             status: status.to_string(),
-            version: env!("CARGO_PKG_VERSION").to_string(),
+            build: BuildInfo::new(&state.settings.env),
             services: ServiceHealth {
                 database: db_status.to_string(),
                 redis: redis_status.to_string(),
                 ai_service: ai_status.to_string(),
+                ai_circuit,
             },
         }),
     )
@@ -4,20 +4,31 @@
 
 use axum::{
     extract::{Path, Query, State},
-    response::IntoResponse,
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
     Json,
 };
 use chrono::{DateTime, Utc};
 use serde::Deserialize;
+use sha2::{Digest, Sha256};
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::api::pagination::PaginationParams;
-use crate::api::response::{DataResponse, Paginated, PaginationMeta};
+use crate::api::pagination::{resolve_pagination, Cursor, CursorPage, CursorParams, PaginationParams};
+use crate::api::response::{Created, DataResponse, MessageResponse, NoContent, Paginated};
 use crate::app::AppState;
 use crate::auth::RequireAuth;
+use crate::domain::activity::ActivityAction;
+use crate::domain::admin::AuditTargetType;
 use crate::domain::hiring::*;
-use crate::error::ApiError;
+use crate::domain::money::Money;
+use crate::domain::rating::Rating;
+use crate::error::{map_db_err, ApiError};
+use crate::middleware::ExtractClientIp;
+use crate::services::audit::{self, ActivityEntry};
+use crate::services::cache::{keys, CacheNamespace, RedisCache};
+use crate::services::contract_templates;
+use crate::services::notifications;
 
 // ============================================================================
 // Database Row Types
@@ -41,6 +52,7 @@ struct ExternalSubRow {
     rating: sqlx::types::Decimal,
     projects_together: i32,
     is_preferred: bool,
+    linked_subcontractor_id: Option<Uuid>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
@@ -68,7 +80,7 @@ struct HireRequestRow {
     title: String,
     message: Option<String>,
     scope_description: Option<String>,
-    proposed_amount: Option<sqlx::types::Decimal>,
+    proposed_amount: Option<Money>,
     rate_type: Option<String>,
     unit_description: Option<String>,
     estimated_hours: Option<i32>,
@@ -76,7 +88,8 @@ struct HireRequestRow {
     estimated_end_date: Option<DateTime<Utc>>,
     response_deadline: Option<DateTime<Utc>>,
     sub_response: Option<String>,
-    sub_counter_amount: Option<sqlx::types::Decimal>,
+    sub_counter_amount: Option<Money>,
+    counter_offer_status: Option<String>,
     viewed_at: Option<DateTime<Utc>>,
     responded_at: Option<DateTime<Utc>>,
     hired_at: Option<DateTime<Utc>>,
@@ -84,6 +97,17 @@ struct HireRequestRow {
     updated_at: DateTime<Utc>,
 }
 
+/// Who is on a hire request and where it stands
+#[derive(Debug, sqlx::FromRow)]
+struct HireRequestPartiesRow {
+    status: String,
+    gc_id: Uuid,
+    sub_profile_id: Option<Uuid>,
+    project_id: Uuid,
+    counter_offer_status: Option<String>,
+    sub_counter_amount: Option<Money>,
+}
+
 #[derive(Debug, sqlx::FromRow)]
 struct HireMessageRow {
     id: Uuid,
@@ -129,7 +153,7 @@ struct ContractRow {
     content: String,
     sections: serde_json::Value,
     terms_summary: Option<String>,
-    amount: sqlx::types::Decimal,
+    amount: Money,
     payment_schedule: serde_json::Value,
     start_date: Option<DateTime<Utc>>,
     end_date: Option<DateTime<Utc>>,
@@ -165,28 +189,136 @@ struct TeamMemberRow {
     responsibilities: Option<String>,
     start_date: Option<DateTime<Utc>>,
     end_date: Option<DateTime<Utc>>,
-    hourly_rate: Option<sqlx::types::Decimal>,
+    hourly_rate: Option<Money>,
     status: String,
     performance_rating: Option<sqlx::types::Decimal>,
+    review_id: Option<Uuid>,
+    review_rating: Option<sqlx::types::Decimal>,
+    review_title: Option<String>,
+    review_comment: Option<String>,
+    review_would_hire_again: Option<bool>,
+    reviewed_at: Option<DateTime<Utc>>,
     notes: Option<String>,
     joined_at: DateTime<Utc>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
 
+/// A team member being reviewed, locked for the review transaction
+#[derive(Debug, sqlx::FromRow)]
+struct TeamMemberReviewTargetRow {
+    status: String,
+    subcontractor_id: Option<Uuid>,
+    external_sub_id: Option<Uuid>,
+    contract_id: Option<Uuid>,
+    review_id: Option<Uuid>,
+}
+
+/// Subcontractor columns joined onto a contract: (subcontractor_id, external_sub_id,
+/// company_name, contact_name, contact_email, contact_phone, trade, location, rating, verified)
+type ContractSubInfoRow = (
+    Option<Uuid>,
+    Option<Uuid>,
+    String,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    String,
+    Option<String>,
+    Option<sqlx::types::Decimal>,
+    bool,
+);
+
+/// Contract fields needed to sign it
+#[derive(Debug, sqlx::FromRow)]
+struct ContractSigningRow {
+    gc_id: Uuid,
+    sub_profile_id: Option<Uuid>,
+    status: String,
+    project_id: Uuid,
+    title: String,
+    content: String,
+    sections: serde_json::Value,
+    amount: Money,
+    gc_content_hash: Option<String>,
+}
+
+/// Contract content and stored signature records
+#[derive(Debug, sqlx::FromRow)]
+struct ContractSignatureRow {
+    gc_id: Uuid,
+    sub_profile_id: Option<Uuid>,
+    status: String,
+    title: String,
+    content: String,
+    sections: serde_json::Value,
+    amount: Money,
+    gc_signed_by: Option<Uuid>,
+    gc_signed_at: Option<DateTime<Utc>>,
+    gc_signed_ip: Option<String>,
+    gc_content_hash: Option<String>,
+    sub_signed_by: Option<Uuid>,
+    sub_signed_at: Option<DateTime<Utc>>,
+    sub_signed_ip: Option<String>,
+    sub_content_hash: Option<String>,
+}
+
+/// Contract payment state with the parties allowed to view it
+#[derive(Debug, sqlx::FromRow)]
+struct ContractPaymentRow {
+    gc_id: Uuid,
+    sub_profile_id: Option<Uuid>,
+    project_id: Uuid,
+    status: String,
+    amount: Money,
+    payment_schedule: serde_json::Value,
+}
+
+impl ContractPaymentRow {
+    fn milestones(&self) -> Result<Vec<PaymentMilestone>, ApiError> {
+        serde_json::from_value(self.payment_schedule.clone())
+            .map_err(|e| ApiError::internal(format!("Invalid payment schedule: {}", e)))
+    }
+}
+
+/// Parties and project data substituted into contract templates
+#[derive(Debug, sqlx::FromRow)]
+struct ContractTemplateContextRow {
+    gc_company_name: Option<String>,
+    gc_contact_name: Option<String>,
+    gc_address: Option<String>,
+    sub_company_name: Option<String>,
+    sub_contact_name: Option<String>,
+    sub_address: Option<String>,
+    project_name: String,
+    project_address: Option<String>,
+    trade: String,
+    scope_description: Option<String>,
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
 
-fn decimal_to_f64(d: sqlx::types::Decimal) -> f64 {
-    use std::str::FromStr;
-    f64::from_str(&d.to_string()).unwrap_or(0.0)
-}
-
-fn decimal_opt_to_f64(d: Option<sqlx::types::Decimal>) -> Option<f64> {
-    d.map(decimal_to_f64)
+/// SHA-256 over the signed terms of a contract (title, content, sections, amount).
+///
+/// Fields are NUL-separated so content cannot shift between them; `sections`
+/// comes from JSONB, whose key order is canonical.
+fn contract_content_hash(
+    title: &str,
+    content: &str,
+    sections: &serde_json::Value,
+    amount: Money,
+) -> String {
+    let mut hasher = Sha256::new();
+    for part in [title, content, &sections.to_string(), &amount.to_decimal().normalize().to_string()] {
+        hasher.update(part.as_bytes());
+        hasher.update([0u8]);
+    }
+    hex::encode(hasher.finalize())
 }
 
+#[allow(clippy::too_many_arguments)]
 fn make_subcontractor_info(
     id: Option<Uuid>,
     external_id: Option<Uuid>,
@@ -208,11 +340,138 @@ fn make_subcontractor_info(
         contact_phone,
         trade,
         location,
-        rating: rating.map(decimal_to_f64),
+        rating: rating.map(Rating::from),
         verified,
     }
 }
 
+impl From<ExternalSubRow> for ExternalSubcontractorResponse {
+    fn from(row: ExternalSubRow) -> Self {
+        let secondary_trades: Vec<String> =
+            serde_json::from_value(row.secondary_trades).unwrap_or_default();
+        Self {
+            id: row.id,
+            added_by: row.added_by,
+            company_name: row.company_name,
+            contact_name: row.contact_name,
+            contact_email: row.contact_email,
+            contact_phone: row.contact_phone,
+            trade: row.trade,
+            secondary_trades,
+            location: row.location,
+            address: row.address,
+            license_number: row.license_number,
+            insurance_info: row.insurance_info,
+            notes: row.notes,
+            rating: Rating::from(row.rating),
+            projects_together: row.projects_together,
+            is_preferred: row.is_preferred,
+            linked_subcontractor_id: row.linked_subcontractor_id,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+impl From<HireRequestRow> for HireRequestResponse {
+    fn from(row: HireRequestRow) -> Self {
+        let subcontractor = make_subcontractor_info(
+            row.subcontractor_id,
+            row.external_sub_id,
+            row.sub_company_name,
+            row.sub_contact_name,
+            row.sub_contact_email,
+            row.sub_contact_phone,
+            row.sub_trade,
+            row.sub_location,
+            row.sub_rating,
+            row.sub_verified,
+        );
+
+        Self {
+            id: row.id,
+            project_id: row.project_id,
+            project_name: row.project_name,
+            tender_id: row.tender_id,
+            gc_id: row.gc_id,
+            gc_company_name: row.gc_company_name,
+            subcontractor,
+            status: row.status,
+            trade: row.trade,
+            title: row.title,
+            message: row.message,
+            scope_description: row.scope_description,
+            proposed_amount: row.proposed_amount,
+            rate_type: row.rate_type,
+            unit_description: row.unit_description,
+            estimated_hours: row.estimated_hours,
+            estimated_start_date: row.estimated_start_date,
+            estimated_end_date: row.estimated_end_date,
+            response_deadline: row.response_deadline,
+            days_until_deadline: row
+                .response_deadline
+                .map(|deadline| (deadline - Utc::now()).num_days()),
+            sub_response: row.sub_response,
+            sub_counter_amount: row.sub_counter_amount,
+            current_amount: current_amount(
+                row.proposed_amount,
+                row.sub_counter_amount,
+                row.counter_offer_status.as_deref(),
+            ),
+            counter_offer_status: row.counter_offer_status,
+            unread_messages: 0, // TODO: Calculate from messages
+            contract_id: None,  // TODO: Fetch from contracts
+            viewed_at: row.viewed_at,
+            responded_at: row.responded_at,
+            hired_at: row.hired_at,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+/// Columns and joins behind `HireRequestRow`; callers append the WHERE clause
+const HIRE_REQUEST_SELECT: &str = r#"
+        SELECT 
+            hr.id, hr.project_id, p.name as project_name, hr.tender_id, hr.gc_id,
+            gc.company_name as gc_company_name, hr.subcontractor_id, hr.external_sub_id,
+            COALESCE(s.name, es.company_name) as sub_company_name,
+            COALESCE(s.contact_email, es.contact_name) as sub_contact_name,
+            COALESCE(s.contact_email, es.contact_email) as sub_contact_email,
+            COALESCE(s.contact_phone, es.contact_phone) as sub_contact_phone,
+            COALESCE(s.trade, es.trade) as sub_trade,
+            COALESCE(s.location, es.location) as sub_location,
+            s.rating as sub_rating,
+            COALESCE(s.verified, false) as sub_verified,
+            hr.status, hr.trade, hr.title, hr.message, hr.scope_description,
+            hr.proposed_amount, hr.rate_type, hr.unit_description, hr.estimated_hours,
+            hr.estimated_start_date, hr.estimated_end_date, hr.response_deadline,
+            hr.sub_response, hr.sub_counter_amount, hr.counter_offer_status, hr.viewed_at, hr.responded_at,
+            hr.hired_at, hr.created_at, hr.updated_at
+        FROM hire_requests hr
+        JOIN projects p ON hr.project_id = p.id
+        JOIN profiles gc ON hr.gc_id = gc.id
+        LEFT JOIN subcontractors s ON hr.subcontractor_id = s.id
+        LEFT JOIN external_subcontractors es ON hr.external_sub_id = es.id
+"#;
+
+/// A hire request visible to `user_id`, as either its GC or its subcontractor
+async fn fetch_hire_request(
+    state: &AppState,
+    request_id: Uuid,
+    user_id: Uuid,
+) -> Result<Option<HireRequestRow>, ApiError> {
+    sqlx::query_as::<_, HireRequestRow>(&format!(
+        "{} WHERE hr.id = $1 AND (hr.gc_id = $2 OR s.profile_id = $2)",
+        HIRE_REQUEST_SELECT
+    ))
+    .bind(request_id)
+    .bind(user_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("fetch_hire_request"))
+}
+
 // ============================================================================
 // External Subcontractors
 // ============================================================================
@@ -228,15 +487,22 @@ pub struct ExternalSubQueryParams {
 /// GET /api/my-subcontractors
 ///
 /// List external subcontractors added by the current user.
+#[utoipa::path(
+    get,
+    path = "/my-subcontractors",
+    tag = "hiring",
+    params(PaginationParams, ExternalSubcontractorQuery),
+    responses(
+        (status = 200, description = "The caller's external subcontractors", body = ExternalSubcontractorPage),
+    )
+)]
 pub async fn list_external_subcontractors(
     State(state): State<Arc<AppState>>,
     Query(query): Query<ExternalSubQueryParams>,
     auth: RequireAuth,
 ) -> Result<impl IntoResponse, ApiError> {
     let user_id = auth.user_id;
-    let page = query.pagination.page.unwrap_or(1).max(1);
-    let per_page = query.pagination.per_page.unwrap_or(20).min(100);
-    let offset = ((page - 1) * per_page) as i64;
+    let page = resolve_pagination(&query.pagination, state.settings.pagination.default)?;
 
     let total: i64 = sqlx::query_scalar(
         r#"
@@ -253,19 +519,20 @@ pub async fn list_external_subcontractors(
     .bind(&query.filter.search)
     .fetch_one(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("list_external_subcontractors"))?;
 
     let rows = sqlx::query_as::<_, ExternalSubRow>(
         r#"
         SELECT id, added_by, company_name, contact_name, contact_email, contact_phone,
                trade, secondary_trades, location, address, license_number, insurance_info,
-               notes, rating, projects_together, is_preferred, created_at, updated_at
+               notes, rating, projects_together, is_preferred, linked_subcontractor_id,
+               created_at, updated_at
         FROM external_subcontractors
         WHERE added_by = $1
         AND ($2::text IS NULL OR trade ILIKE '%' || $2 || '%')
         AND ($3::bool IS NULL OR is_preferred = $3)
         AND ($4::text IS NULL OR company_name ILIKE '%' || $4 || '%')
-        ORDER BY is_preferred DESC, company_name
+        ORDER BY is_preferred DESC, company_name, id
         LIMIT $5 OFFSET $6
         "#,
     )
@@ -273,73 +540,174 @@ pub async fn list_external_subcontractors(
     .bind(&query.filter.trade)
     .bind(query.filter.is_preferred)
     .bind(&query.filter.search)
-    .bind(per_page as i64)
-    .bind(offset)
+    .bind(page.limit())
+    .bind(page.offset())
     .fetch_all(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("list_external_subcontractors"))?;
 
     let data: Vec<ExternalSubcontractorResponse> = rows
         .into_iter()
-        .map(|r| {
-            let secondary_trades: Vec<String> =
-                serde_json::from_value(r.secondary_trades).unwrap_or_default();
-            ExternalSubcontractorResponse {
+        .map(ExternalSubcontractorResponse::from)
+        .collect();
+
+    Ok(Json(Paginated::new(data, &page, total as u64)))
+}
+
+/// Company names at least this similar (pg_trgm `similarity`, 0 to 1) in the
+/// same trade are flagged as possible duplicates
+const DUPLICATE_NAME_SIMILARITY: f32 = 0.6;
+
+/// Most existing matches returned with a duplicate conflict
+const MAX_DUPLICATE_MATCHES: i64 = 5;
+
+/// Most pairs returned by the duplicates report
+const MAX_DUPLICATE_PAIRS: i64 = 100;
+
+#[derive(Debug, sqlx::FromRow)]
+struct DuplicateMatchRow {
+    id: Uuid,
+    company_name: String,
+    trade: String,
+    contact_email: Option<String>,
+    contact_phone: Option<String>,
+    name_similarity: f32,
+    same_trade: bool,
+    same_email: bool,
+    same_phone: bool,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct DuplicatePairRow {
+    first_id: Uuid,
+    first_company_name: String,
+    first_trade: String,
+    first_contact_email: Option<String>,
+    first_contact_phone: Option<String>,
+    second_id: Uuid,
+    second_company_name: String,
+    second_trade: String,
+    second_contact_email: Option<String>,
+    second_contact_phone: Option<String>,
+    name_similarity: f32,
+    same_trade: bool,
+    same_email: bool,
+    same_phone: bool,
+}
+
+fn duplicate_reasons(name_similarity: f32, same_trade: bool, same_email: bool, same_phone: bool) -> Vec<DuplicateReason> {
+    [
+        (same_trade && name_similarity >= DUPLICATE_NAME_SIMILARITY, DuplicateReason::SimilarName),
+        (same_email, DuplicateReason::SameEmail),
+        (same_phone, DuplicateReason::SamePhone),
+    ]
+    .into_iter()
+    .filter_map(|(matched, reason)| matched.then_some(reason))
+    .collect()
+}
+
+/// External subcontractors in `user_id`'s book that look like `input`
+async fn find_duplicate_matches(
+    state: &AppState,
+    user_id: Uuid,
+    input: &CreateExternalSubcontractorInput,
+) -> Result<Vec<ExternalSubcontractorMatch>, ApiError> {
+    let rows = sqlx::query_as::<_, DuplicateMatchRow>(
+        r#"
+        SELECT * FROM (
+            SELECT e.id, e.company_name, e.trade, e.contact_email, e.contact_phone,
+                   similarity(normalize_company_name(e.company_name), normalize_company_name($2)) as name_similarity,
+                   lower(btrim(e.trade)) = lower(btrim($3)) as same_trade,
+                   COALESCE(btrim($4) <> '' AND lower(btrim(e.contact_email)) = lower(btrim($4)), false) as same_email,
+                   length(normalize_phone($5)) >= 7 AND normalize_phone(e.contact_phone) = normalize_phone($5) as same_phone
+            FROM external_subcontractors e
+            WHERE e.added_by = $1
+        ) m
+        WHERE (same_trade AND name_similarity >= $6) OR same_email OR same_phone
+        ORDER BY name_similarity DESC, id
+        LIMIT $7
+        "#,
+    )
+    .bind(user_id)
+    .bind(&input.company_name)
+    .bind(&input.trade)
+    .bind(&input.contact_email)
+    .bind(&input.contact_phone)
+    .bind(DUPLICATE_NAME_SIMILARITY)
+    .bind(MAX_DUPLICATE_MATCHES)
+    .fetch_all(&state.db)
+    .await
+    .map_err(map_db_err("find_duplicate_matches"))?;
+
+    Ok(rows
+        .into_iter()
+        .map(|r| ExternalSubcontractorMatch {
+            reasons: duplicate_reasons(r.name_similarity, r.same_trade, r.same_email, r.same_phone),
+            name_similarity: r.name_similarity,
+            subcontractor: ExternalSubcontractorSummary {
                 id: r.id,
-                added_by: r.added_by,
                 company_name: r.company_name,
-                contact_name: r.contact_name,
+                trade: r.trade,
                 contact_email: r.contact_email,
                 contact_phone: r.contact_phone,
-                trade: r.trade,
-                secondary_trades,
-                location: r.location,
-                address: r.address,
-                license_number: r.license_number,
-                insurance_info: r.insurance_info,
-                notes: r.notes,
-                rating: decimal_to_f64(r.rating),
-                projects_together: r.projects_together,
-                is_preferred: r.is_preferred,
-                created_at: r.created_at,
-                updated_at: r.updated_at,
-            }
+            },
         })
-        .collect();
-
-    let total_pages = ((total as f64) / (per_page as f64)).ceil() as u32;
-
-    Ok(Json(Paginated {
-        data,
-        pagination: PaginationMeta {
-            page,
-            per_page,
-            total_items: total as u64,
-            total_pages,
-            has_next: page < total_pages,
-            has_prev: page > 1,
-        },
-    }))
+        .collect())
 }
 
 /// POST /api/my-subcontractors
+///
+/// Rejected with `409` and the existing matches when the company looks like
+/// one already in the caller's book (similar name in the same trade, or the
+/// same contact email or phone). `?allow_duplicate=true` saves it anyway.
+#[utoipa::path(
+    post,
+    path = "/my-subcontractors",
+    tag = "hiring",
+    params(CreateExternalSubcontractorQuery),
+    request_body = CreateExternalSubcontractorInput,
+    responses(
+        (status = 201, description = "External subcontractor created", body = ExternalSubcontractorData),
+        (status = 400, description = "Invalid fields", body = ErrorResponse),
+        (status = 409, description = "Looks like an existing subcontractor", body = DuplicateExternalSubcontractorError),
+    )
+)]
 pub async fn create_external_subcontractor(
     State(state): State<Arc<AppState>>,
+    Query(query): Query<CreateExternalSubcontractorQuery>,
     auth: RequireAuth,
     Json(input): Json<CreateExternalSubcontractorInput>,
-) -> Result<impl IntoResponse, ApiError> {
+) -> Result<Response, ApiError> {
     let user_id = auth.user_id;
+
+    if !query.allow_duplicate {
+        let matches = find_duplicate_matches(&state, user_id, &input).await?;
+        if !matches.is_empty() {
+            let body = DuplicateExternalSubcontractorError {
+                code: "DUPLICATE_SUBCONTRACTOR".to_string(),
+                message: "This looks like a subcontractor already in your list. Retry with ?allow_duplicate=true to add it anyway."
+                    .to_string(),
+                matches,
+            };
+            return Ok((StatusCode::CONFLICT, Json(body)).into_response());
+        }
+    }
+
     let id = Uuid::new_v4();
     let secondary_trades = serde_json::to_value(input.secondary_trades.unwrap_or_default())
         .unwrap_or(serde_json::json!([]));
 
-    sqlx::query(
+    let row = sqlx::query_as::<_, ExternalSubRow>(
         r#"
         INSERT INTO external_subcontractors (
             id, added_by, company_name, contact_name, contact_email, contact_phone,
             trade, secondary_trades, location, address, license_number, insurance_info,
             notes, is_preferred
         ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+        RETURNING id, added_by, company_name, contact_name, contact_email, contact_phone,
+                  trade, secondary_trades, location, address, license_number, insurance_info,
+                  notes, rating, projects_together, is_preferred, linked_subcontractor_id,
+                  created_at, updated_at
         "#,
     )
     .bind(id)
@@ -356,14 +724,92 @@ pub async fn create_external_subcontractor(
     .bind(&input.insurance_info)
     .bind(&input.notes)
     .bind(input.is_preferred.unwrap_or(false))
-    .execute(&state.db)
+    .fetch_one(&state.db)
+    .await
+    .map_err(map_db_err("create_external_subcontractor"))?;
+
+    Ok(Created(ExternalSubcontractorResponse::from(row)).into_response())
+}
+
+/// GET /api/my-subcontractors/duplicates
+///
+/// Pairs of the caller's external subcontractors that look like the same
+/// company, most similar names first.
+#[utoipa::path(
+    get,
+    path = "/my-subcontractors/duplicates",
+    tag = "hiring",
+    responses(
+        (status = 200, description = "Likely duplicate pairs", body = ExternalSubcontractorDuplicatePairListData),
+    )
+)]
+pub async fn list_external_subcontractor_duplicates(
+    State(state): State<Arc<AppState>>,
+    auth: RequireAuth,
+) -> Result<impl IntoResponse, ApiError> {
+    let rows = sqlx::query_as::<_, DuplicatePairRow>(
+        r#"
+        SELECT * FROM (
+            SELECT a.id as first_id, a.company_name as first_company_name, a.trade as first_trade,
+                   a.contact_email as first_contact_email, a.contact_phone as first_contact_phone,
+                   b.id as second_id, b.company_name as second_company_name, b.trade as second_trade,
+                   b.contact_email as second_contact_email, b.contact_phone as second_contact_phone,
+                   similarity(normalize_company_name(a.company_name), normalize_company_name(b.company_name)) as name_similarity,
+                   lower(btrim(a.trade)) = lower(btrim(b.trade)) as same_trade,
+                   COALESCE(btrim(a.contact_email) <> '' AND lower(btrim(a.contact_email)) = lower(btrim(b.contact_email)), false) as same_email,
+                   length(normalize_phone(a.contact_phone)) >= 7 AND normalize_phone(a.contact_phone) = normalize_phone(b.contact_phone) as same_phone
+            FROM external_subcontractors a
+            JOIN external_subcontractors b ON b.added_by = a.added_by AND a.id < b.id
+            WHERE a.added_by = $1
+        ) m
+        WHERE (same_trade AND name_similarity >= $2) OR same_email OR same_phone
+        ORDER BY name_similarity DESC, first_id, second_id
+        LIMIT $3
+        "#,
+    )
+    .bind(auth.user_id)
+    .bind(DUPLICATE_NAME_SIMILARITY)
+    .bind(MAX_DUPLICATE_PAIRS)
+    .fetch_all(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Failed to create subcontractor: {}", e)))?;
+    .map_err(map_db_err("list_external_subcontractor_duplicates"))?;
+
+    let pairs: Vec<ExternalSubcontractorDuplicatePair> = rows
+        .into_iter()
+        .map(|r| ExternalSubcontractorDuplicatePair {
+            reasons: duplicate_reasons(r.name_similarity, r.same_trade, r.same_email, r.same_phone),
+            name_similarity: r.name_similarity,
+            first: ExternalSubcontractorSummary {
+                id: r.first_id,
+                company_name: r.first_company_name,
+                trade: r.first_trade,
+                contact_email: r.first_contact_email,
+                contact_phone: r.first_contact_phone,
+            },
+            second: ExternalSubcontractorSummary {
+                id: r.second_id,
+                company_name: r.second_company_name,
+                trade: r.second_trade,
+                contact_email: r.second_contact_email,
+                contact_phone: r.second_contact_phone,
+            },
+        })
+        .collect();
 
-    Ok(Json(serde_json::json!({ "id": id, "success": true })))
+    Ok(Json(DataResponse::new(pairs)))
 }
 
 /// GET /api/my-subcontractors/:id
+#[utoipa::path(
+    get,
+    path = "/my-subcontractors/{sub_id}",
+    tag = "hiring",
+    params(("sub_id" = Uuid, Path, description = "External subcontractor id")),
+    responses(
+        (status = 200, description = "External subcontractor", body = ExternalSubcontractorData),
+        (status = 404, description = "Subcontractor not found", body = ErrorResponse),
+    )
+)]
 pub async fn get_external_subcontractor(
     State(state): State<Arc<AppState>>,
     Path(sub_id): Path<Uuid>,
@@ -378,37 +824,27 @@ pub async fn get_external_subcontractor(
     .bind(user_id)
     .fetch_optional(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
+    .map_err(map_db_err("get_external_subcontractor"))?
     .ok_or_else(|| ApiError::not_found("Subcontractor not found"))?;
 
-    let secondary_trades: Vec<String> =
-        serde_json::from_value(row.secondary_trades).unwrap_or_default();
-
-    let response = ExternalSubcontractorResponse {
-        id: row.id,
-        added_by: row.added_by,
-        company_name: row.company_name,
-        contact_name: row.contact_name,
-        contact_email: row.contact_email,
-        contact_phone: row.contact_phone,
-        trade: row.trade,
-        secondary_trades,
-        location: row.location,
-        address: row.address,
-        license_number: row.license_number,
-        insurance_info: row.insurance_info,
-        notes: row.notes,
-        rating: decimal_to_f64(row.rating),
-        projects_together: row.projects_together,
-        is_preferred: row.is_preferred,
-        created_at: row.created_at,
-        updated_at: row.updated_at,
-    };
+    let response = ExternalSubcontractorResponse::from(row);
 
     Ok(Json(DataResponse::new(response)))
 }
 
 /// PUT /api/my-subcontractors/:id
+#[utoipa::path(
+    put,
+    path = "/my-subcontractors/{sub_id}",
+    tag = "hiring",
+    params(("sub_id" = Uuid, Path, description = "External subcontractor id")),
+    request_body = UpdateExternalSubcontractorInput,
+    responses(
+        (status = 200, description = "External subcontractor updated", body = MessageResponse),
+        (status = 400, description = "Invalid fields", body = ErrorResponse),
+        (status = 404, description = "Subcontractor not found", body = ErrorResponse),
+    )
+)]
 pub async fn update_external_subcontractor(
     State(state): State<Arc<AppState>>,
     Path(sub_id): Path<Uuid>,
@@ -422,49 +858,67 @@ pub async fn update_external_subcontractor(
         r#"
         UPDATE external_subcontractors SET
             company_name = COALESCE($1, company_name),
-            contact_name = COALESCE($2, contact_name),
-            contact_email = COALESCE($3, contact_email),
-            contact_phone = COALESCE($4, contact_phone),
-            trade = COALESCE($5, trade),
-            secondary_trades = COALESCE($6, secondary_trades),
-            location = COALESCE($7, location),
-            address = COALESCE($8, address),
-            license_number = COALESCE($9, license_number),
-            insurance_info = COALESCE($10, insurance_info),
-            notes = COALESCE($11, notes),
-            rating = COALESCE($12, rating),
-            is_preferred = COALESCE($13, is_preferred),
+            contact_name = CASE WHEN $2 THEN $3 ELSE contact_name END,
+            contact_email = CASE WHEN $4 THEN $5 ELSE contact_email END,
+            contact_phone = CASE WHEN $6 THEN $7 ELSE contact_phone END,
+            trade = COALESCE($8, trade),
+            secondary_trades = COALESCE($9, secondary_trades),
+            location = CASE WHEN $10 THEN $11 ELSE location END,
+            address = CASE WHEN $12 THEN $13 ELSE address END,
+            license_number = CASE WHEN $14 THEN $15 ELSE license_number END,
+            insurance_info = CASE WHEN $16 THEN $17 ELSE insurance_info END,
+            notes = CASE WHEN $18 THEN $19 ELSE notes END,
+            rating = COALESCE($20, rating),
+            is_preferred = COALESCE($21, is_preferred),
             updated_at = NOW()
-        WHERE id = $14 AND added_by = $15
+        WHERE id = $22 AND added_by = $23
         "#,
     )
     .bind(&input.company_name)
-    .bind(&input.contact_name)
-    .bind(&input.contact_email)
-    .bind(&input.contact_phone)
+    .bind(input.contact_name.is_set())
+    .bind(input.contact_name.value())
+    .bind(input.contact_email.is_set())
+    .bind(input.contact_email.value())
+    .bind(input.contact_phone.is_set())
+    .bind(input.contact_phone.value())
     .bind(&input.trade)
     .bind(secondary_trades)
-    .bind(&input.location)
-    .bind(&input.address)
-    .bind(&input.license_number)
-    .bind(&input.insurance_info)
-    .bind(&input.notes)
-    .bind(input.rating)
+    .bind(input.location.is_set())
+    .bind(input.location.value())
+    .bind(input.address.is_set())
+    .bind(input.address.value())
+    .bind(input.license_number.is_set())
+    .bind(input.license_number.value())
+    .bind(input.insurance_info.is_set())
+    .bind(input.insurance_info.value())
+    .bind(input.notes.is_set())
+    .bind(input.notes.value())
+    .bind(input.rating.map(Rating::to_decimal))
     .bind(input.is_preferred)
     .bind(sub_id)
     .bind(user_id)
     .execute(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Failed to update subcontractor: {}", e)))?;
+    .map_err(map_db_err("update_external_subcontractor"))?;
 
     if result.rows_affected() == 0 {
         return Err(ApiError::not_found("Subcontractor not found"));
     }
 
-    Ok(Json(serde_json::json!({ "success": true })))
+    Ok(MessageResponse::new("External subcontractor updated"))
 }
 
 /// DELETE /api/my-subcontractors/:id
+#[utoipa::path(
+    delete,
+    path = "/my-subcontractors/{sub_id}",
+    tag = "hiring",
+    params(("sub_id" = Uuid, Path, description = "External subcontractor id")),
+    responses(
+        (status = 204, description = "External subcontractor deleted"),
+        (status = 404, description = "Subcontractor not found", body = ErrorResponse),
+    )
+)]
 pub async fn delete_external_subcontractor(
     State(state): State<Arc<AppState>>,
     Path(sub_id): Path<Uuid>,
@@ -477,13 +931,242 @@ pub async fn delete_external_subcontractor(
         .bind(user_id)
         .execute(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Failed to delete subcontractor: {}", e)))?;
+        .map_err(map_db_err("delete_external_subcontractor"))?;
 
     if result.rows_affected() == 0 {
         return Err(ApiError::not_found("Subcontractor not found"));
     }
 
-    Ok(Json(serde_json::json!({ "success": true })))
+    Ok(NoContent)
+}
+
+/// Most platform matches suggested for an external subcontractor
+const MAX_PLATFORM_MATCHES: i64 = 10;
+
+#[derive(Debug, sqlx::FromRow)]
+struct PlatformMatchRow {
+    subcontractor_id: Uuid,
+    name: String,
+    trade: String,
+    location: Option<String>,
+    verified: bool,
+    name_similarity: f32,
+    same_trade: bool,
+    same_email: bool,
+    same_phone: bool,
+}
+
+/// Registered platform subcontractors compared with external sub `external_id`:
+/// those that look like it, or just `only` whether or not it matches
+async fn match_platform_subcontractors(
+    state: &AppState,
+    external_id: Uuid,
+    only: Option<Uuid>,
+) -> Result<Vec<PlatformMatchRow>, ApiError> {
+    sqlx::query_as::<_, PlatformMatchRow>(
+        r#"
+        SELECT * FROM (
+            SELECT s.id as subcontractor_id, s.name, s.trade, s.location,
+                   COALESCE(s.verified, false) as verified,
+                   similarity(normalize_company_name(s.name), normalize_company_name(e.company_name)) as name_similarity,
+                   lower(btrim(s.trade)) = lower(btrim(e.trade)) as same_trade,
+                   COALESCE(btrim(e.contact_email) <> '' AND lower(btrim(s.contact_email)) = lower(btrim(e.contact_email)), false) as same_email,
+                   length(normalize_phone(e.contact_phone)) >= 7 AND normalize_phone(s.contact_phone) = normalize_phone(e.contact_phone) as same_phone
+            FROM external_subcontractors e
+            JOIN subcontractors s ON s.profile_id IS NOT NULL AND ($2::uuid IS NULL OR s.id = $2)
+            WHERE e.id = $1
+        ) m
+        WHERE $2::uuid IS NOT NULL OR (same_trade AND name_similarity >= $3) OR same_email OR same_phone
+        ORDER BY name_similarity DESC, subcontractor_id
+        LIMIT $4
+        "#,
+    )
+    .bind(external_id)
+    .bind(only)
+    .bind(DUPLICATE_NAME_SIMILARITY)
+    .bind(MAX_PLATFORM_MATCHES)
+    .fetch_all(&state.db)
+    .await
+    .map_err(map_db_err("match_platform_subcontractors"))
+}
+
+/// The caller's external subcontractor and the platform profile it is linked to
+async fn fetch_own_external_sub(state: &AppState, id: Uuid, user_id: Uuid) -> Result<Option<Uuid>, ApiError> {
+    let linked: Option<Option<Uuid>> = sqlx::query_scalar(
+        "SELECT linked_subcontractor_id FROM external_subcontractors WHERE id = $1 AND added_by = $2",
+    )
+    .bind(id)
+    .bind(user_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("fetch_own_external_sub"))?;
+
+    linked.ok_or_else(|| ApiError::not_found("Subcontractor not found"))
+}
+
+/// GET /api/my-subcontractors/:id/platform-matches
+///
+/// Registered platform subcontractors that look like this external one, by
+/// contact email, phone or a similar name in the same trade. Candidates for
+/// `POST /api/my-subcontractors/:id/link/:sub_id`.
+#[utoipa::path(
+    get,
+    path = "/my-subcontractors/{sub_id}/platform-matches",
+    tag = "hiring",
+    params(("sub_id" = Uuid, Path, description = "External subcontractor id")),
+    responses(
+        (status = 200, description = "Likely platform profiles", body = PlatformSubcontractorMatchListData),
+        (status = 404, description = "Subcontractor not found", body = ErrorResponse),
+    )
+)]
+pub async fn list_platform_matches(
+    State(state): State<Arc<AppState>>,
+    Path(sub_id): Path<Uuid>,
+    auth: RequireAuth,
+) -> Result<impl IntoResponse, ApiError> {
+    fetch_own_external_sub(&state, sub_id, auth.user_id).await?;
+
+    let matches: Vec<PlatformSubcontractorMatch> = match_platform_subcontractors(&state, sub_id, None)
+        .await?
+        .into_iter()
+        .map(|r| PlatformSubcontractorMatch {
+            reasons: duplicate_reasons(r.name_similarity, r.same_trade, r.same_email, r.same_phone),
+            subcontractor_id: r.subcontractor_id,
+            name: r.name,
+            trade: r.trade,
+            location: r.location,
+            verified: r.verified,
+            name_similarity: r.name_similarity,
+        })
+        .collect();
+
+    Ok(Json(DataResponse::new(matches)))
+}
+
+/// POST /api/my-subcontractors/:id/link/:platform_sub_id
+///
+/// Link an external subcontractor to the platform profile the company created,
+/// moving its hire requests, reviews and team memberships there. Without
+/// `confirm: true` nothing changes and the response previews what would move.
+/// Only a profile that matches the company's email, phone or name can be linked.
+#[utoipa::path(
+    post,
+    path = "/my-subcontractors/{sub_id}/link/{platform_sub_id}",
+    tag = "hiring",
+    params(
+        ("sub_id" = Uuid, Path, description = "External subcontractor id"),
+        ("platform_sub_id" = Uuid, Path, description = "Platform subcontractor id"),
+    ),
+    request_body = LinkExternalSubcontractorInput,
+    responses(
+        (status = 200, description = "Link preview or result", body = ExternalSubcontractorLinkResultData),
+        (status = 404, description = "Subcontractor not found", body = ErrorResponse),
+        (status = 409, description = "Already linked to another profile", body = ErrorResponse),
+        (status = 422, description = "Profile does not match the subcontractor", body = ErrorResponse),
+    )
+)]
+pub async fn link_external_subcontractor(
+    State(state): State<Arc<AppState>>,
+    Path((sub_id, platform_sub_id)): Path<(Uuid, Uuid)>,
+    auth: RequireAuth,
+    Json(input): Json<LinkExternalSubcontractorInput>,
+) -> Result<impl IntoResponse, ApiError> {
+    let linked = fetch_own_external_sub(&state, sub_id, auth.user_id).await?;
+    if linked.is_some_and(|id| id != platform_sub_id) {
+        return Err(ApiError::conflict(
+            "This subcontractor is already linked to another platform profile",
+        ));
+    }
+
+    let comparison = match_platform_subcontractors(&state, sub_id, Some(platform_sub_id))
+        .await?
+        .pop()
+        .ok_or_else(|| ApiError::not_found("Platform subcontractor not found"))?;
+    let reasons = duplicate_reasons(
+        comparison.name_similarity,
+        comparison.same_trade,
+        comparison.same_email,
+        comparison.same_phone,
+    );
+
+    // Moving reviews and history onto an unrelated company would rewrite its
+    // public rating and show it this GC's private records
+    if input.confirm && reasons.is_empty() {
+        return Err(ApiError::unprocessable(
+            "This platform profile does not match the subcontractor's email, phone or company name",
+        ));
+    }
+
+    let db_err = map_db_err("link_external_subcontractor");
+
+    if !input.confirm {
+        let (hire_requests, reviews, team_memberships): (i64, i64, i64) = sqlx::query_as(
+            r#"
+            SELECT (SELECT COUNT(*) FROM hire_requests WHERE external_sub_id = $1),
+                   (SELECT COUNT(*) FROM subcontractor_reviews WHERE external_sub_id = $1),
+                   (SELECT COUNT(*) FROM project_team WHERE external_sub_id = $1)
+            "#,
+        )
+        .bind(sub_id)
+        .fetch_one(&state.db)
+        .await
+        .map_err(db_err)?;
+
+        return Ok(Json(DataResponse::new(ExternalSubcontractorLinkResult {
+            external_sub_id: sub_id,
+            subcontractor_id: platform_sub_id,
+            linked: false,
+            hire_requests,
+            reviews,
+            team_memberships,
+            reasons,
+        })));
+    }
+
+    let mut tx = state.db.begin().await.map_err(db_err)?;
+
+    let mut moved = [0i64; 3];
+    for (count, table) in moved.iter_mut().zip(["hire_requests", "subcontractor_reviews", "project_team"]) {
+        *count = sqlx::query(&format!(
+            "UPDATE {} SET subcontractor_id = $2, external_sub_id = NULL WHERE external_sub_id = $1",
+            table
+        ))
+        .bind(sub_id)
+        .bind(platform_sub_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_err)?
+        .rows_affected() as i64;
+    }
+
+    sqlx::query(
+        r#"
+        UPDATE external_subcontractors
+        SET linked_subcontractor_id = $2, linked_at = COALESCE(linked_at, NOW()), updated_at = NOW()
+        WHERE id = $1
+        "#,
+    )
+    .bind(sub_id)
+    .bind(platform_sub_id)
+    .execute(&mut *tx)
+    .await
+    .map_err(db_err)?;
+
+    tx.commit().await.map_err(db_err)?;
+
+    // Moved reviews change the profile's rating
+    state.cache.invalidate_subcontractor(platform_sub_id).await;
+
+    let [hire_requests, reviews, team_memberships] = moved;
+    Ok(Json(DataResponse::new(ExternalSubcontractorLinkResult {
+        external_sub_id: sub_id,
+        subcontractor_id: platform_sub_id,
+        linked: true,
+        hire_requests,
+        reviews,
+        team_memberships,
+        reasons,
+    })))
 }
 
 // ============================================================================
@@ -501,15 +1184,22 @@ pub struct HireRequestQueryParams {
 /// GET /api/hiring
 ///
 /// List hire requests (as GC or as subcontractor).
+#[utoipa::path(
+    get,
+    path = "/hiring",
+    tag = "hiring",
+    params(PaginationParams, HireRequestQuery),
+    responses(
+        (status = 200, description = "Hire requests sent or received", body = HireRequestPage),
+    )
+)]
 pub async fn list_hire_requests(
     State(state): State<Arc<AppState>>,
     Query(query): Query<HireRequestQueryParams>,
     auth: RequireAuth,
 ) -> Result<impl IntoResponse, ApiError> {
     let user_id = auth.user_id;
-    let page = query.pagination.page.unwrap_or(1).max(1);
-    let per_page = query.pagination.per_page.unwrap_or(20).min(100);
-    let offset = ((page - 1) * per_page) as i64;
+    let page = resolve_pagination(&query.pagination, state.settings.pagination.default)?;
 
     // Determine if viewing as GC or sub
     let as_gc = query.filter.as_gc.unwrap_or(true);
@@ -535,117 +1225,52 @@ pub async fn list_hire_requests(
     .bind(&query.filter.trade)
     .fetch_one(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("list_hire_requests"))?;
 
-    let rows = sqlx::query_as::<_, HireRequestRow>(
-        r#"
-        SELECT 
-            hr.id, hr.project_id, p.name as project_name, hr.tender_id, hr.gc_id,
-            gc.company_name as gc_company_name, hr.subcontractor_id, hr.external_sub_id,
-            COALESCE(s.name, es.company_name) as sub_company_name,
-            COALESCE(s.contact_email, es.contact_name) as sub_contact_name,
-            COALESCE(s.contact_email, es.contact_email) as sub_contact_email,
-            COALESCE(s.contact_phone, es.contact_phone) as sub_contact_phone,
-            COALESCE(s.trade, es.trade) as sub_trade,
-            COALESCE(s.location, es.location) as sub_location,
-            s.rating as sub_rating,
-            COALESCE(s.verified, false) as sub_verified,
-            hr.status, hr.trade, hr.title, hr.message, hr.scope_description,
-            hr.proposed_amount, hr.rate_type, hr.unit_description, hr.estimated_hours,
-            hr.estimated_start_date, hr.estimated_end_date, hr.response_deadline,
-            hr.sub_response, hr.sub_counter_amount, hr.viewed_at, hr.responded_at,
-            hr.hired_at, hr.created_at, hr.updated_at
-        FROM hire_requests hr
-        JOIN projects p ON hr.project_id = p.id
-        JOIN profiles gc ON hr.gc_id = gc.id
-        LEFT JOIN subcontractors s ON hr.subcontractor_id = s.id
-        LEFT JOIN external_subcontractors es ON hr.external_sub_id = es.id
+    let rows = sqlx::query_as::<_, HireRequestRow>(&format!(
+        r#"{}
         WHERE (($1 AND hr.gc_id = $2) OR (NOT $1 AND s.profile_id = $2))
         AND ($3::uuid IS NULL OR hr.project_id = $3)
         AND ($4::text IS NULL OR hr.status = $4)
         AND ($5::text IS NULL OR hr.trade ILIKE '%' || $5 || '%')
-        ORDER BY hr.updated_at DESC
+        ORDER BY hr.updated_at DESC, hr.id
         LIMIT $6 OFFSET $7
         "#,
-    )
+        HIRE_REQUEST_SELECT
+    ))
     .bind(as_gc)
     .bind(user_id)
     .bind(query.filter.project_id)
     .bind(&query.filter.status)
     .bind(&query.filter.trade)
-    .bind(per_page as i64)
-    .bind(offset)
+    .bind(page.limit())
+    .bind(page.offset())
     .fetch_all(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("list_hire_requests"))?;
 
     let data: Vec<HireRequestResponse> = rows
         .into_iter()
-        .map(|r| {
-            let subcontractor = make_subcontractor_info(
-                r.subcontractor_id,
-                r.external_sub_id,
-                r.sub_company_name,
-                r.sub_contact_name,
-                r.sub_contact_email,
-                r.sub_contact_phone,
-                r.sub_trade,
-                r.sub_location,
-                r.sub_rating,
-                r.sub_verified,
-            );
-
-            HireRequestResponse {
-                id: r.id,
-                project_id: r.project_id,
-                project_name: r.project_name,
-                tender_id: r.tender_id,
-                gc_id: r.gc_id,
-                gc_company_name: r.gc_company_name,
-                subcontractor,
-                status: r.status,
-                trade: r.trade,
-                title: r.title,
-                message: r.message,
-                scope_description: r.scope_description,
-                proposed_amount: decimal_opt_to_f64(r.proposed_amount),
-                rate_type: r.rate_type,
-                unit_description: r.unit_description,
-                estimated_hours: r.estimated_hours,
-                estimated_start_date: r.estimated_start_date,
-                estimated_end_date: r.estimated_end_date,
-                response_deadline: r.response_deadline,
-                sub_response: r.sub_response,
-                sub_counter_amount: decimal_opt_to_f64(r.sub_counter_amount),
-                unread_messages: 0, // TODO: Calculate from messages
-                contract_id: None,  // TODO: Fetch from contracts
-                viewed_at: r.viewed_at,
-                responded_at: r.responded_at,
-                hired_at: r.hired_at,
-                created_at: r.created_at,
-                updated_at: r.updated_at,
-            }
-        })
+        .map(HireRequestResponse::from)
         .collect();
 
-    let total_pages = ((total as f64) / (per_page as f64)).ceil() as u32;
-
-    Ok(Json(Paginated {
-        data,
-        pagination: PaginationMeta {
-            page,
-            per_page,
-            total_items: total as u64,
-            total_pages,
-            has_next: page < total_pages,
-            has_prev: page > 1,
-        },
-    }))
+    Ok(Json(Paginated::new(data, &page, total as u64)))
 }
 
 /// POST /api/hiring
 ///
 /// Create a new hire request.
+#[utoipa::path(
+    post,
+    path = "/hiring",
+    tag = "hiring",
+    request_body = CreateHireRequestInput,
+    responses(
+        (status = 201, description = "Hire request created", body = HireRequestData),
+        (status = 400, description = "Invalid request or no subcontractor given", body = ErrorResponse),
+        (status = 403, description = "Caller does not own the project", body = ErrorResponse),
+    )
+)]
 pub async fn create_hire_request(
     State(state): State<Arc<AppState>>,
     auth: RequireAuth,
@@ -658,7 +1283,7 @@ pub async fn create_hire_request(
         .bind(input.project_id)
         .fetch_optional(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
+        .map_err(map_db_err("create_hire_request"))?
         .flatten();
 
     if project_owner != Some(user_id) {
@@ -714,12 +1339,26 @@ pub async fn create_hire_request(
     .bind(input.response_deadline)
     .execute(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Failed to create hire request: {}", e)))?;
+    .map_err(map_db_err("create_hire_request"))?;
+
+    let row = fetch_hire_request(&state, id, user_id)
+        .await?
+        .ok_or_else(|| ApiError::internal("Created hire request could not be read back"))?;
 
-    Ok(Json(serde_json::json!({ "id": id, "status": status, "success": true })))
+    Ok(Created(HireRequestResponse::from(row)))
 }
 
 /// GET /api/hiring/:id
+#[utoipa::path(
+    get,
+    path = "/hiring/{id}",
+    tag = "hiring",
+    params(("id" = Uuid, Path, description = "Hire request id")),
+    responses(
+        (status = 200, description = "Hire request", body = HireRequestData),
+        (status = 404, description = "Hire request not found", body = ErrorResponse),
+    )
+)]
 pub async fn get_hire_request(
     State(state): State<Arc<AppState>>,
     Path(request_id): Path<Uuid>,
@@ -727,37 +1366,8 @@ pub async fn get_hire_request(
 ) -> Result<impl IntoResponse, ApiError> {
     let user_id = auth.user_id;
 
-    let row = sqlx::query_as::<_, HireRequestRow>(
-        r#"
-        SELECT 
-            hr.id, hr.project_id, p.name as project_name, hr.tender_id, hr.gc_id,
-            gc.company_name as gc_company_name, hr.subcontractor_id, hr.external_sub_id,
-            COALESCE(s.name, es.company_name) as sub_company_name,
-            COALESCE(s.contact_email, es.contact_name) as sub_contact_name,
-            COALESCE(s.contact_email, es.contact_email) as sub_contact_email,
-            COALESCE(s.contact_phone, es.contact_phone) as sub_contact_phone,
-            COALESCE(s.trade, es.trade) as sub_trade,
-            COALESCE(s.location, es.location) as sub_location,
-            s.rating as sub_rating,
-            COALESCE(s.verified, false) as sub_verified,
-            hr.status, hr.trade, hr.title, hr.message, hr.scope_description,
-            hr.proposed_amount, hr.rate_type, hr.unit_description, hr.estimated_hours,
-            hr.estimated_start_date, hr.estimated_end_date, hr.response_deadline,
-            hr.sub_response, hr.sub_counter_amount, hr.viewed_at, hr.responded_at,
-            hr.hired_at, hr.created_at, hr.updated_at
-        FROM hire_requests hr
-        JOIN projects p ON hr.project_id = p.id
-        JOIN profiles gc ON hr.gc_id = gc.id
-        LEFT JOIN subcontractors s ON hr.subcontractor_id = s.id
-        LEFT JOIN external_subcontractors es ON hr.external_sub_id = es.id
-        WHERE hr.id = $1 AND (hr.gc_id = $2 OR s.profile_id = $2)
-        "#,
-    )
-    .bind(request_id)
-    .bind(user_id)
-    .fetch_optional(&state.db)
-    .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
+    let row = fetch_hire_request(&state, request_id, user_id)
+        .await?
     .ok_or_else(|| ApiError::not_found("Hire request not found"))?;
 
     // Mark as viewed if sub is viewing
@@ -770,54 +1380,24 @@ pub async fn get_hire_request(
         .await;
     }
 
-    let subcontractor = make_subcontractor_info(
-        row.subcontractor_id,
-        row.external_sub_id,
-        row.sub_company_name,
-        row.sub_contact_name,
-        row.sub_contact_email,
-        row.sub_contact_phone,
-        row.sub_trade,
-        row.sub_location,
-        row.sub_rating,
-        row.sub_verified,
-    );
-
-    let response = HireRequestResponse {
-        id: row.id,
-        project_id: row.project_id,
-        project_name: row.project_name,
-        tender_id: row.tender_id,
-        gc_id: row.gc_id,
-        gc_company_name: row.gc_company_name,
-        subcontractor,
-        status: row.status,
-        trade: row.trade,
-        title: row.title,
-        message: row.message,
-        scope_description: row.scope_description,
-        proposed_amount: decimal_opt_to_f64(row.proposed_amount),
-        rate_type: row.rate_type,
-        unit_description: row.unit_description,
-        estimated_hours: row.estimated_hours,
-        estimated_start_date: row.estimated_start_date,
-        estimated_end_date: row.estimated_end_date,
-        response_deadline: row.response_deadline,
-        sub_response: row.sub_response,
-        sub_counter_amount: decimal_opt_to_f64(row.sub_counter_amount),
-        unread_messages: 0,
-        contract_id: None,
-        viewed_at: row.viewed_at,
-        responded_at: row.responded_at,
-        hired_at: row.hired_at,
-        created_at: row.created_at,
-        updated_at: row.updated_at,
-    };
+    let response = HireRequestResponse::from(row);
 
     Ok(Json(DataResponse::new(response)))
 }
 
 /// PUT /api/hiring/:id
+#[utoipa::path(
+    put,
+    path = "/hiring/{id}",
+    tag = "hiring",
+    params(("id" = Uuid, Path, description = "Hire request id")),
+    request_body = UpdateHireRequestInput,
+    responses(
+        (status = 200, description = "Hire request updated", body = MessageResponse),
+        (status = 400, description = "Invalid fields", body = ErrorResponse),
+        (status = 404, description = "Hire request not found or cannot be updated", body = ErrorResponse),
+    )
+)]
 pub async fn update_hire_request(
     State(state): State<Arc<AppState>>,
     Path(request_id): Path<Uuid>,
@@ -830,93 +1410,105 @@ pub async fn update_hire_request(
         r#"
         UPDATE hire_requests SET
             title = COALESCE($1, title),
-            message = COALESCE($2, message),
-            scope_description = COALESCE($3, scope_description),
-            proposed_amount = COALESCE($4, proposed_amount),
-            rate_type = COALESCE($5, rate_type),
-            unit_description = COALESCE($6, unit_description),
-            estimated_hours = COALESCE($7, estimated_hours),
-            estimated_start_date = COALESCE($8, estimated_start_date),
-            estimated_end_date = COALESCE($9, estimated_end_date),
-            response_deadline = COALESCE($10, response_deadline),
+            message = CASE WHEN $2 THEN $3 ELSE message END,
+            scope_description = CASE WHEN $4 THEN $5 ELSE scope_description END,
+            proposed_amount = CASE WHEN $6 THEN $7 ELSE proposed_amount END,
+            rate_type = COALESCE($8, rate_type),
+            unit_description = CASE WHEN $9 THEN $10 ELSE unit_description END,
+            estimated_hours = CASE WHEN $11 THEN $12 ELSE estimated_hours END,
+            estimated_start_date = CASE WHEN $13 THEN $14 ELSE estimated_start_date END,
+            estimated_end_date = CASE WHEN $15 THEN $16 ELSE estimated_end_date END,
+            response_deadline = CASE WHEN $17 THEN $18 ELSE response_deadline END,
             updated_at = NOW()
-        WHERE id = $11 AND gc_id = $12 AND status IN ('draft', 'pending', 'sent')
+        WHERE id = $19 AND gc_id = $20 AND status IN ('draft', 'pending', 'sent')
         "#,
     )
     .bind(&input.title)
-    .bind(&input.message)
-    .bind(&input.scope_description)
-    .bind(input.proposed_amount)
+    .bind(input.message.is_set())
+    .bind(input.message.value())
+    .bind(input.scope_description.is_set())
+    .bind(input.scope_description.value())
+    .bind(input.proposed_amount.is_set())
+    .bind(input.proposed_amount.copied())
     .bind(&input.rate_type)
-    .bind(&input.unit_description)
-    .bind(input.estimated_hours)
-    .bind(input.estimated_start_date)
-    .bind(input.estimated_end_date)
-    .bind(input.response_deadline)
+    .bind(input.unit_description.is_set())
+    .bind(input.unit_description.value())
+    .bind(input.estimated_hours.is_set())
+    .bind(input.estimated_hours.copied())
+    .bind(input.estimated_start_date.is_set())
+    .bind(input.estimated_start_date.copied())
+    .bind(input.estimated_end_date.is_set())
+    .bind(input.estimated_end_date.copied())
+    .bind(input.response_deadline.is_set())
+    .bind(input.response_deadline.copied())
     .bind(request_id)
     .bind(user_id)
     .execute(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Failed to update hire request: {}", e)))?;
+    .map_err(map_db_err("update_hire_request"))?;
 
     if result.rows_affected() == 0 {
         return Err(ApiError::not_found("Hire request not found or cannot be updated"));
     }
 
-    Ok(Json(serde_json::json!({ "success": true })))
+    Ok(MessageResponse::new("Hire request updated"))
 }
 
 /// POST /api/hiring/:id/status
 ///
 /// Update hire request status (for status transitions).
+#[utoipa::path(
+    post,
+    path = "/hiring/{id}/status",
+    tag = "hiring",
+    params(("id" = Uuid, Path, description = "Hire request id")),
+    request_body = HireRequestStatusInput,
+    responses(
+        (status = 200, description = "`{ data: { status } }`"),
+        (status = 400, description = "Invalid status transition or counter amount", body = ErrorResponse),
+        (status = 403, description = "Caller cannot make this transition", body = ErrorResponse),
+        (status = 404, description = "Hire request not found", body = ErrorResponse),
+        (status = 409, description = "Status changed concurrently", body = ErrorResponse),
+    )
+)]
 pub async fn update_hire_request_status(
     State(state): State<Arc<AppState>>,
     Path(request_id): Path<Uuid>,
     auth: RequireAuth,
+    ExtractClientIp(client_ip): ExtractClientIp,
     Json(input): Json<HireRequestStatusInput>,
 ) -> Result<impl IntoResponse, ApiError> {
     let user_id = auth.user_id;
 
     // Validate status transition
-    let current: Option<(String, Uuid, Option<Uuid>)> = sqlx::query_as(
-        r#"
-        SELECT hr.status, hr.gc_id, s.profile_id
-        FROM hire_requests hr
-        LEFT JOIN subcontractors s ON hr.subcontractor_id = s.id
-        WHERE hr.id = $1
-        "#,
-    )
-    .bind(request_id)
-    .fetch_optional(&state.db)
-    .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
-
-    let (current_status, gc_id, sub_profile_id) = current
-        .ok_or_else(|| ApiError::not_found("Hire request not found"))?;
+    let parties = fetch_hire_request_parties(&state, request_id).await?;
+    let current_status = parties.status;
+    let project_id = parties.project_id;
 
-    let is_gc = gc_id == user_id;
-    let is_sub = sub_profile_id == Some(user_id);
+    let is_gc = parties.gc_id == user_id;
+    let is_sub = parties.sub_profile_id == Some(user_id);
 
     if !is_gc && !is_sub {
         return Err(ApiError::forbidden("You don't have access to this hire request"));
     }
 
-    // Validate status transition based on role
+    // Validate status transition based on role. Requests awaiting the sub
+    // expire on their own at the response deadline; the GC may expire one early.
     let new_status = input.status.as_str();
-    let valid_transition = match (current_status.as_str(), new_status, is_gc) {
-        ("draft", "sent", true) => true,
-        ("draft", "cancelled", true) => true,
-        ("sent", "cancelled", true) => true,
-        ("viewed", "interested", false) => true,
-        ("viewed", "declined", false) => true,
-        ("interested", "negotiating", _) => true,
-        ("negotiating", "contract_sent", true) => true,
-        ("contract_sent", "contract_signed", false) => true,
-        ("contract_signed", "hired", true) => true,
-        (_, "cancelled", true) => true,
-        (_, "declined", false) => true,
-        _ => false,
-    };
+    let valid_transition = matches!(
+        (current_status.as_str(), new_status, is_gc),
+        ("draft", "sent", true)
+            | ("draft", "cancelled", true)
+            | ("sent", "cancelled", true)
+            | ("viewed", "interested", false)
+            | ("viewed", "declined", false)
+            | ("interested", "negotiating", _)
+            | ("negotiating", "contract_sent", true)
+            | ("contract_sent", "contract_signed", false)
+            | ("contract_signed", "hired", true)
+            | (_, "cancelled", true)
+            | (_, "declined", false)
+    ) || (new_status == "expired" && is_gc && HireRequestStatus::is_expirable(&current_status));
 
     if !valid_transition {
         return Err(ApiError::bad_request(format!(
@@ -925,72 +1517,444 @@ pub async fn update_hire_request_status(
         )));
     }
 
-    let mut query = String::from("UPDATE hire_requests SET status = $1, updated_at = NOW()");
-    
-    if new_status == "interested" || new_status == "declined" {
-        query.push_str(", responded_at = NOW(), sub_response = $3");
-        if let Some(counter) = input.counter_amount {
-            query.push_str(&format!(", sub_counter_amount = {}", counter));
-        }
+    // Expressing interest with a price is a counter-offer
+    let counter_amount = input.counter_amount.filter(|_| new_status == "interested");
+    if let Some(amount) = counter_amount {
+        validate_counter_amount(amount)?;
     }
-    if new_status == "hired" {
-        query.push_str(", hired_at = NOW()");
+
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(map_db_err("update_hire_request_status"))?;
+
+    // Every value is bound; the status guard rejects a transition that raced another one
+    let responding = matches!(new_status, "interested" | "declined");
+    let result = sqlx::query(
+        r#"
+        UPDATE hire_requests SET
+            status = $1,
+            responded_at = CASE WHEN $3 THEN NOW() ELSE responded_at END,
+            sub_response = CASE WHEN $3 THEN COALESCE($4, sub_response) ELSE sub_response END,
+            hired_at = CASE WHEN $1 = 'hired' THEN NOW() ELSE hired_at END,
+            updated_at = NOW()
+        WHERE id = $2 AND status = $5
+        "#,
+    )
+    .bind(new_status)
+    .bind(request_id)
+    .bind(responding)
+    .bind(&input.response)
+    .bind(&current_status)
+    .execute(&mut *tx)
+    .await
+    .map_err(map_db_err("update_hire_request_status"))?;
+
+    if result.rows_affected() == 0 {
+        return Err(ApiError::conflict("Hire request status changed; reload and try again"));
     }
-    
-    query.push_str(" WHERE id = $2");
 
-    if input.response.is_some() {
-        sqlx::query(&query)
-            .bind(new_status)
-            .bind(request_id)
-            .bind(&input.response)
-            .execute(&state.db)
-            .await
-            .map_err(|e| ApiError::internal(format!("Failed to update status: {}", e)))?;
-    } else {
-        sqlx::query("UPDATE hire_requests SET status = $1, updated_at = NOW() WHERE id = $2")
-            .bind(new_status)
-            .bind(request_id)
-            .execute(&state.db)
-            .await
-            .map_err(|e| ApiError::internal(format!("Failed to update status: {}", e)))?;
+    if let Some(amount) = counter_amount {
+        record_counter_offer(&mut tx, request_id, user_id, amount, None, input.response.as_deref()).await?;
+    }
+
+    tx.commit()
+        .await
+        .map_err(map_db_err("update_hire_request_status"))?;
+
+    if let Err(e) = audit::log_activity(
+        &state.db,
+        ActivityEntry {
+            actor_id: user_id,
+            project_id: Some(project_id),
+            action: ActivityAction::HireRequestStatusChanged,
+            target_type: AuditTargetType::HireRequest,
+            target_id: Some(request_id),
+            details: serde_json::json!({ "from": current_status, "to": new_status }),
+            ip_address: client_ip,
+        },
+    )
+    .await
+    {
+        tracing::warn!(error = %e, "Failed to log hire request status change");
     }
 
-    Ok(Json(serde_json::json!({ "success": true, "status": new_status })))
+    Ok(DataResponse::new(serde_json::json!({ "status": new_status })))
 }
 
-// ============================================================================
-// Hire Messages
-// ============================================================================
+/// Latest accepted counter-offer, otherwise the GC's proposed amount
+fn current_amount(
+    proposed_amount: Option<Money>,
+    counter_amount: Option<Money>,
+    counter_offer_status: Option<&str>,
+) -> Option<Money> {
+    match counter_offer_status {
+        Some("accepted") => counter_amount.or(proposed_amount),
+        _ => proposed_amount,
+    }
+}
 
-/// GET /api/hiring/:id/messages
-pub async fn list_hire_messages(
+async fn fetch_hire_request_parties(
+    state: &AppState,
+    request_id: Uuid,
+) -> Result<HireRequestPartiesRow, ApiError> {
+    sqlx::query_as::<_, HireRequestPartiesRow>(
+        r#"
+        SELECT hr.status, hr.gc_id, s.profile_id as sub_profile_id, hr.project_id,
+               hr.counter_offer_status, hr.sub_counter_amount
+        FROM hire_requests hr
+        LEFT JOIN subcontractors s ON hr.subcontractor_id = s.id
+        WHERE hr.id = $1
+        "#,
+    )
+    .bind(request_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("fetch_hire_request_parties"))?
+    .ok_or_else(|| ApiError::not_found("Hire request not found"))
+}
+
+fn validate_counter_amount(amount: Money) -> Result<(), ApiError> {
+    if amount <= Money::ZERO {
+        return Err(ApiError::bad_request("Counter-offer amount must be greater than zero"));
+    }
+    Ok(())
+}
+
+/// Post a `counter_offer` message and make it the request's pending counter.
+/// Any earlier unanswered counter-offer is marked superseded.
+async fn record_counter_offer(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    request_id: Uuid,
+    sender_id: Uuid,
+    amount: Money,
+    terms: Option<&str>,
+    message: Option<&str>,
+) -> Result<Uuid, ApiError> {
+    let id = Uuid::new_v4();
+    let metadata = CounterOfferMetadata {
+        amount,
+        terms: terms.map(str::to_string),
+        status: CounterOfferStatus::Pending,
+    };
+    let message = message
+        .map(str::to_string)
+        .unwrap_or_else(|| format!("Counter-offer: ${}", amount));
+
+    set_pending_counter_status(tx, request_id, CounterOfferStatus::Superseded).await?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO hire_messages (id, hire_request_id, sender_id, sender_type, message, message_type, metadata)
+        VALUES ($1, $2, $3, 'sub', $4, $5, $6)
+        "#,
+    )
+    .bind(id)
+    .bind(request_id)
+    .bind(sender_id)
+    .bind(&message)
+    .bind(MessageType::CounterOffer.to_string())
+    .bind(serde_json::to_value(&metadata).unwrap_or_default())
+    .execute(&mut **tx)
+    .await
+    .map_err(map_db_err("record_counter_offer"))?;
+
+    sqlx::query(
+        r#"
+        UPDATE hire_requests SET sub_counter_amount = $2, counter_offer_status = $3, updated_at = NOW()
+        WHERE id = $1
+        "#,
+    )
+    .bind(request_id)
+    .bind(amount)
+    .bind(CounterOfferStatus::Pending.to_string())
+    .execute(&mut **tx)
+    .await
+    .map_err(map_db_err("record_counter_offer"))?;
+
+    Ok(id)
+}
+
+/// Set the status recorded on the request's unanswered counter-offer messages
+async fn set_pending_counter_status(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    request_id: Uuid,
+    status: CounterOfferStatus,
+) -> Result<(), ApiError> {
+    sqlx::query(
+        r#"
+        UPDATE hire_messages SET metadata = jsonb_set(metadata, '{status}', to_jsonb($2::text))
+        WHERE hire_request_id = $1 AND message_type = 'counter_offer'
+        AND metadata->>'status' = 'pending'
+        "#,
+    )
+    .bind(request_id)
+    .bind(status.to_string())
+    .execute(&mut **tx)
+    .await
+    .map_err(map_db_err("set_pending_counter_status"))?;
+    Ok(())
+}
+
+/// POST /api/hiring/:id/counter-offer
+///
+/// Subcontractor proposes a different price (and optional terms). Posts a
+/// `counter_offer` message to the thread and notifies the GC.
+#[utoipa::path(
+    post,
+    path = "/hiring/{id}/counter-offer",
+    tag = "hiring",
+    params(("id" = Uuid, Path, description = "Hire request id")),
+    request_body = CounterOfferInput,
+    responses(
+        (status = 201, description = "`{ data: { id, counter_offer_status } }`"),
+        (status = 400, description = "Hire request cannot be countered or invalid amount", body = ErrorResponse),
+        (status = 403, description = "Only the subcontractor can counter", body = ErrorResponse),
+        (status = 404, description = "Hire request not found", body = ErrorResponse),
+    )
+)]
+pub async fn submit_counter_offer(
     State(state): State<Arc<AppState>>,
     Path(request_id): Path<Uuid>,
     auth: RequireAuth,
+    Json(input): Json<CounterOfferInput>,
 ) -> Result<impl IntoResponse, ApiError> {
     let user_id = auth.user_id;
+    let parties = fetch_hire_request_parties(&state, request_id).await?;
 
-    // Verify access
-    let has_access: bool = sqlx::query_scalar(
+    if parties.sub_profile_id != Some(user_id) {
+        return Err(ApiError::forbidden("Only the subcontractor can make a counter-offer"));
+    }
+    if !matches!(parties.status.as_str(), "viewed" | "interested" | "negotiating") {
+        return Err(ApiError::bad_request(format!(
+            "Cannot make a counter-offer on a hire request that is '{}'",
+            parties.status
+        )));
+    }
+    validate_counter_amount(input.amount)?;
+
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(map_db_err("submit_counter_offer"))?;
+
+    let message_id = record_counter_offer(
+        &mut tx,
+        request_id,
+        user_id,
+        input.amount,
+        input.terms.as_deref(),
+        input.message.as_deref(),
+    )
+    .await?;
+
+    sqlx::query(
+        "UPDATE hire_requests SET status = 'negotiating', updated_at = NOW() WHERE id = $1 AND status IN ('viewed', 'interested')",
+    )
+    .bind(request_id)
+    .execute(&mut *tx)
+    .await
+    .map_err(map_db_err("submit_counter_offer"))?;
+
+    tx.commit()
+        .await
+        .map_err(map_db_err("submit_counter_offer"))?;
+
+    notify_counter_offer_party(
+        &state,
+        parties.gc_id,
+        request_id,
+        user_id,
+        &format!("Counter-offer: ${}", input.amount),
+    )
+    .await;
+
+    Ok(Created(serde_json::json!({
+        "id": message_id,
+        "counter_offer_status": CounterOfferStatus::Pending,
+    })))
+}
+
+/// POST /api/hiring/:id/counter-offer/accept
+///
+/// GC accepts the pending counter-offer; it becomes the proposed amount.
+#[utoipa::path(
+    post,
+    path = "/hiring/{id}/counter-offer/accept",
+    tag = "hiring",
+    params(("id" = Uuid, Path, description = "Hire request id")),
+    responses(
+        (status = 200, description = "`{ data: { counter_offer_status, proposed_amount } }`"),
+        (status = 400, description = "No pending counter-offer", body = ErrorResponse),
+        (status = 403, description = "Only the GC can answer", body = ErrorResponse),
+        (status = 404, description = "Hire request not found", body = ErrorResponse),
+    )
+)]
+pub async fn accept_counter_offer(
+    State(state): State<Arc<AppState>>,
+    Path(request_id): Path<Uuid>,
+    auth: RequireAuth,
+) -> Result<impl IntoResponse, ApiError> {
+    answer_counter_offer(&state, request_id, auth.user_id, CounterOfferStatus::Accepted).await
+}
+
+/// POST /api/hiring/:id/counter-offer/reject
+///
+/// GC declines the pending counter-offer; the proposed amount is unchanged.
+#[utoipa::path(
+    post,
+    path = "/hiring/{id}/counter-offer/reject",
+    tag = "hiring",
+    params(("id" = Uuid, Path, description = "Hire request id")),
+    responses(
+        (status = 200, description = "`{ data: { counter_offer_status, proposed_amount } }`"),
+        (status = 400, description = "No pending counter-offer", body = ErrorResponse),
+        (status = 403, description = "Only the GC can answer", body = ErrorResponse),
+        (status = 404, description = "Hire request not found", body = ErrorResponse),
+    )
+)]
+pub async fn reject_counter_offer(
+    State(state): State<Arc<AppState>>,
+    Path(request_id): Path<Uuid>,
+    auth: RequireAuth,
+) -> Result<impl IntoResponse, ApiError> {
+    answer_counter_offer(&state, request_id, auth.user_id, CounterOfferStatus::Rejected).await
+}
+
+async fn answer_counter_offer(
+    state: &AppState,
+    request_id: Uuid,
+    user_id: Uuid,
+    decision: CounterOfferStatus,
+) -> Result<DataResponse<serde_json::Value>, ApiError> {
+    let parties = fetch_hire_request_parties(state, request_id).await?;
+
+    if parties.gc_id != user_id {
+        return Err(ApiError::forbidden("Only the GC can answer a counter-offer"));
+    }
+    let amount = match (parties.counter_offer_status.as_deref(), parties.sub_counter_amount) {
+        (Some("pending"), Some(amount)) => amount,
+        _ => return Err(ApiError::bad_request("There is no pending counter-offer")),
+    };
+
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(map_db_err("answer_counter_offer"))?;
+
+    let proposed_amount: Option<Money> = sqlx::query_scalar(
         r#"
-        SELECT EXISTS(
-            SELECT 1 FROM hire_requests hr
-            LEFT JOIN subcontractors s ON hr.subcontractor_id = s.id
-            WHERE hr.id = $1 AND (hr.gc_id = $2 OR s.profile_id = $2)
-        )
+        UPDATE hire_requests SET
+            proposed_amount = CASE WHEN $2 = 'accepted' THEN sub_counter_amount ELSE proposed_amount END,
+            counter_offer_status = $2,
+            updated_at = NOW()
+        WHERE id = $1 AND counter_offer_status = 'pending'
+        RETURNING proposed_amount
         "#,
     )
     .bind(request_id)
+    .bind(decision.to_string())
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(map_db_err("answer_counter_offer"))?
+    .ok_or_else(|| ApiError::conflict("The counter-offer was already answered"))?;
+
+    set_pending_counter_status(&mut tx, request_id, decision).await?;
+
+    let summary = format!("Counter-offer of ${} {}", amount, decision);
+    sqlx::query(
+        r#"
+        INSERT INTO hire_messages (id, hire_request_id, sender_id, sender_type, message, message_type, metadata)
+        VALUES ($1, $2, $3, 'gc', $4, $5, $6)
+        "#,
+    )
+    .bind(Uuid::new_v4())
+    .bind(request_id)
     .bind(user_id)
-    .fetch_one(&state.db)
+    .bind(&summary)
+    .bind(MessageType::System.to_string())
+    .bind(serde_json::json!({ "amount": amount, "status": decision }))
+    .execute(&mut *tx)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("answer_counter_offer"))?;
 
-    if !has_access {
-        return Err(ApiError::forbidden("You don't have access to these messages"));
+    tx.commit()
+        .await
+        .map_err(map_db_err("answer_counter_offer"))?;
+
+    if let Some(sub_user_id) = parties.sub_profile_id {
+        notify_counter_offer_party(state, sub_user_id, request_id, user_id, &summary).await;
+    }
+
+    Ok(DataResponse::new(serde_json::json!({
+        "counter_offer_status": decision,
+        "proposed_amount": proposed_amount,
+    })))
+}
+
+/// Tell the other side of a negotiation about a counter-offer update
+async fn notify_counter_offer_party(
+    state: &AppState,
+    recipient_id: Uuid,
+    request_id: Uuid,
+    sender_id: Uuid,
+    message: &str,
+) {
+    let sender_name: Option<String> = sqlx::query_scalar(
+        "SELECT COALESCE(company_name, first_name || ' ' || last_name) FROM profiles WHERE id = $1",
+    )
+    .bind(sender_id)
+    .fetch_optional(&state.db)
+    .await
+    .ok()
+    .flatten()
+    .flatten();
+
+    if let Err(e) = notifications::notify_new_message(
+        &state.db,
+        recipient_id,
+        request_id,
+        sender_name.as_deref().unwrap_or("Someone"),
+        message,
+    )
+    .await
+    {
+        tracing::warn!(error = %e, "Failed to create counter-offer notification");
     }
+}
 
+// ============================================================================
+// Hire Messages
+// ============================================================================
+
+/// GET /api/hiring/:id/messages
+#[utoipa::path(
+    get,
+    path = "/hiring/{id}/messages",
+    tag = "hiring",
+    params(("id" = Uuid, Path, description = "Hire request id"), CursorParams),
+    responses(
+        (status = 200, description = "Messages, newest first", body = HireMessageCursorPage),
+        (status = 400, description = "Invalid cursor", body = ErrorResponse),
+        (status = 403, description = "No access to this hire request", body = ErrorResponse),
+    )
+)]
+pub async fn list_hire_messages(
+    State(state): State<Arc<AppState>>,
+    Path(request_id): Path<Uuid>,
+    Query(cursor): Query<CursorParams>,
+    auth: RequireAuth,
+) -> Result<impl IntoResponse, ApiError> {
+    let user_id = auth.user_id;
+    let limit = cursor.limit();
+    let before = cursor.before()?;
+
+    verify_hire_request_access(&state, request_id, user_id).await?;
+
+    // Newest first; `before` pages backward into older history
     let rows = sqlx::query_as::<_, HireMessageRow>(
         r#"
         SELECT hm.id, hm.hire_request_id, hm.sender_id,
@@ -1000,23 +1964,18 @@ pub async fn list_hire_messages(
         FROM hire_messages hm
         JOIN profiles p ON hm.sender_id = p.id
         WHERE hm.hire_request_id = $1
-        ORDER BY hm.created_at ASC
+        AND ($2::timestamptz IS NULL OR (hm.created_at, hm.id) < ($2, $3))
+        ORDER BY hm.created_at DESC, hm.id DESC
+        LIMIT $4
         "#,
     )
     .bind(request_id)
+    .bind(before.map(|c| c.created_at))
+    .bind(before.map(|c| c.id))
+    .bind(limit as i64 + 1)
     .fetch_all(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
-
-    // Mark unread messages as read
-    sqlx::query(
-        "UPDATE hire_messages SET is_read = true, read_at = NOW() WHERE hire_request_id = $1 AND sender_id != $2 AND is_read = false",
-    )
-    .bind(request_id)
-    .bind(user_id)
-    .execute(&state.db)
-    .await
-    .ok();
+    .map_err(map_db_err("list_hire_messages"))?;
 
     let messages: Vec<HireMessageResponse> = rows
         .into_iter()
@@ -1035,10 +1994,118 @@ pub async fn list_hire_messages(
         })
         .collect();
 
-    Ok(Json(DataResponse::new(messages)))
+    let page = CursorPage::from_rows(messages, limit, |m| Cursor::new(m.created_at, m.id));
+
+    // Mark only the counterparty's messages on this page as read
+    let unread_ids: Vec<Uuid> = page
+        .data
+        .iter()
+        .filter(|m| !m.is_read && m.sender_id != user_id)
+        .map(|m| m.id)
+        .collect();
+
+    if !unread_ids.is_empty() {
+        sqlx::query(
+            "UPDATE hire_messages SET is_read = true, read_at = NOW() WHERE id = ANY($1) AND is_read = false",
+        )
+        .bind(&unread_ids)
+        .execute(&state.db)
+        .await
+        .ok();
+    }
+
+    Ok(Json(page))
+}
+
+/// POST /api/hiring/:id/messages/read
+///
+/// Explicit read receipt: marks the counterparty's messages as read, optionally
+/// only up to (and including) a given message.
+#[utoipa::path(
+    post,
+    path = "/hiring/{id}/messages/read",
+    tag = "hiring",
+    params(("id" = Uuid, Path, description = "Hire request id")),
+    request_body = MarkMessagesReadInput,
+    responses(
+        (status = 200, description = "`{ data: { marked_count } }`"),
+        (status = 403, description = "No access to this hire request", body = ErrorResponse),
+    )
+)]
+pub async fn mark_hire_messages_read(
+    State(state): State<Arc<AppState>>,
+    Path(request_id): Path<Uuid>,
+    auth: RequireAuth,
+    Json(input): Json<MarkMessagesReadInput>,
+) -> Result<impl IntoResponse, ApiError> {
+    let user_id = auth.user_id;
+
+    verify_hire_request_access(&state, request_id, user_id).await?;
+
+    let result = sqlx::query(
+        r#"
+        UPDATE hire_messages SET is_read = true, read_at = NOW()
+        WHERE hire_request_id = $1 AND sender_id != $2 AND is_read = false
+        AND ($3::uuid IS NULL OR created_at <= (
+            SELECT created_at FROM hire_messages WHERE id = $3 AND hire_request_id = $1
+        ))
+        "#,
+    )
+    .bind(request_id)
+    .bind(user_id)
+    .bind(input.up_to)
+    .execute(&state.db)
+    .await
+    .map_err(map_db_err("mark_hire_messages_read"))?;
+
+    Ok(DataResponse::new(serde_json::json!({
+        "marked_count": result.rows_affected()
+    })))
+}
+
+/// Ensure the user is the GC or the platform sub on a hire request
+async fn verify_hire_request_access(
+    state: &AppState,
+    request_id: Uuid,
+    user_id: Uuid,
+) -> Result<(), ApiError> {
+    let has_access: bool = sqlx::query_scalar(
+        r#"
+        SELECT EXISTS(
+            SELECT 1 FROM hire_requests hr
+            LEFT JOIN subcontractors s ON hr.subcontractor_id = s.id
+            WHERE hr.id = $1 AND (hr.gc_id = $2 OR s.profile_id = $2)
+        )
+        "#,
+    )
+    .bind(request_id)
+    .bind(user_id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(map_db_err("verify_hire_request_access"))?;
+
+    if !has_access {
+        return Err(ApiError::forbidden(
+            "You don't have access to these messages",
+        ));
+    }
+
+    Ok(())
 }
 
 /// POST /api/hiring/:id/messages
+#[utoipa::path(
+    post,
+    path = "/hiring/{id}/messages",
+    tag = "hiring",
+    params(("id" = Uuid, Path, description = "Hire request id")),
+    request_body = SendMessageInput,
+    responses(
+        (status = 201, description = "Message sent", body = CreatedIdData),
+        (status = 400, description = "Invalid message", body = ErrorResponse),
+        (status = 403, description = "No access to this hire request", body = ErrorResponse),
+    )
+)]
 pub async fn send_hire_message(
     State(state): State<Arc<AppState>>,
     Path(request_id): Path<Uuid>,
@@ -1047,13 +2114,12 @@ pub async fn send_hire_message(
 ) -> Result<impl IntoResponse, ApiError> {
     let user_id = auth.user_id;
 
-    // Get user type and verify access
-    let access: Option<(Uuid, Option<Uuid>, String)> = sqlx::query_as(
+    // Verify access; the sender's side follows from which party they are
+    let access: Option<(Uuid, Option<Uuid>)> = sqlx::query_as(
         r#"
-        SELECT hr.gc_id, s.profile_id, p.user_type
+        SELECT hr.gc_id, s.profile_id
         FROM hire_requests hr
         LEFT JOIN subcontractors s ON hr.subcontractor_id = s.id
-        JOIN profiles p ON p.id = $2
         WHERE hr.id = $1 AND (hr.gc_id = $2 OR s.profile_id = $2)
         "#,
     )
@@ -1061,9 +2127,9 @@ pub async fn send_hire_message(
     .bind(user_id)
     .fetch_optional(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("send_hire_message"))?;
 
-    let (gc_id, _, _user_type) = access
+    let (gc_id, sub_profile_id) = access
         .ok_or_else(|| ApiError::forbidden("You don't have access to this conversation"))?;
 
     let sender_type = if gc_id == user_id { "gc" } else { "sub" };
@@ -1071,6 +2137,12 @@ pub async fn send_hire_message(
     let message_type = input.message_type.unwrap_or_else(|| "text".to_string());
     let metadata = input.metadata.unwrap_or(serde_json::json!({}));
 
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(map_db_err("send_hire_message"))?;
+
     sqlx::query(
         r#"
         INSERT INTO hire_messages (id, hire_request_id, sender_id, sender_type, message, message_type, metadata)
@@ -1084,21 +2156,53 @@ pub async fn send_hire_message(
     .bind(&input.message)
     .bind(&message_type)
     .bind(&metadata)
-    .execute(&state.db)
+    .execute(&mut *tx)
     .await
-    .map_err(|e| ApiError::internal(format!("Failed to send message: {}", e)))?;
+    .map_err(map_db_err("send_hire_message"))?;
 
     // Update hire request to negotiating if applicable
     if sender_type == "sub" {
-        let _ = sqlx::query(
+        sqlx::query(
             "UPDATE hire_requests SET status = 'negotiating', updated_at = NOW() WHERE id = $1 AND status IN ('viewed', 'interested')"
         )
         .bind(request_id)
-        .execute(&state.db)
-        .await;
+        .execute(&mut *tx)
+        .await
+        .map_err(map_db_err("send_hire_message"))?;
+    }
+
+    tx.commit()
+        .await
+        .map_err(map_db_err("send_hire_message"))?;
+
+    // Notify the counterparty after commit; a failed notification doesn't
+    // undo the message (external subs have no account to notify)
+    let recipient = if sender_type == "gc" { sub_profile_id } else { Some(gc_id) };
+    if let Some(recipient_id) = recipient {
+        let sender_name: Option<String> = sqlx::query_scalar(
+            "SELECT COALESCE(company_name, first_name || ' ' || last_name) FROM profiles WHERE id = $1",
+        )
+        .bind(user_id)
+        .fetch_optional(&state.db)
+        .await
+        .ok()
+        .flatten()
+        .flatten();
+
+        if let Err(e) = notifications::notify_new_message(
+            &state.db,
+            recipient_id,
+            request_id,
+            sender_name.as_deref().unwrap_or("Someone"),
+            &input.message,
+        )
+        .await
+        {
+            tracing::warn!(error = %e, "Failed to create message notification");
+        }
     }
 
-    Ok(Json(serde_json::json!({ "id": id, "success": true })))
+    Ok(Created::id(id))
 }
 
 // ============================================================================
@@ -1106,10 +2210,27 @@ pub async fn send_hire_message(
 // ============================================================================
 
 /// GET /api/contract-templates
+///
+/// Active templates, cached until they change (see
+/// `invalidate_contract_templates_cache`).
+#[utoipa::path(
+    get,
+    path = "/contract-templates",
+    tag = "hiring",
+    responses(
+        (status = 200, description = "Available contract templates", body = ContractTemplateListData),
+    )
+)]
 pub async fn list_contract_templates(
     State(state): State<Arc<AppState>>,
     _auth: RequireAuth,
 ) -> Result<impl IntoResponse, ApiError> {
+    let cache_control = [(header::CACHE_CONTROL, "private, max-age=300")];
+    let cache_key = keys::contract_templates();
+    if let Some(cached) = state.cache.get::<Vec<ContractTemplateResponse>>(&cache_key).await {
+        return Ok((cache_control, Json(DataResponse::new(cached))));
+    }
+
     let rows = sqlx::query_as::<_, ContractTemplateRow>(
         r#"
         SELECT id, name, description, template_type, content, sections, variables,
@@ -1121,7 +2242,7 @@ pub async fn list_contract_templates(
     )
     .fetch_all(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("list_contract_templates"))?;
 
     let templates: Vec<ContractTemplateResponse> = rows
         .into_iter()
@@ -1146,7 +2267,22 @@ pub async fn list_contract_templates(
         })
         .collect();
 
-    Ok(Json(DataResponse::new(templates)))
+    if let Err(e) = state
+        .cache
+        .set_in(CacheNamespace::Reference, &cache_key, &templates)
+        .await
+    {
+        tracing::warn!(error = %e, "Failed to cache contract templates");
+    }
+
+    Ok((cache_control, Json(DataResponse::new(templates))))
+}
+
+/// Drop the cached template list; call after templates change.
+pub async fn invalidate_contract_templates_cache(cache: &RedisCache) {
+    if let Err(e) = cache.delete(&keys::contract_templates()).await {
+        tracing::warn!(error = %e, "Failed to invalidate contract templates cache");
+    }
 }
 
 // ============================================================================
@@ -1154,6 +2290,20 @@ pub async fn list_contract_templates(
 // ============================================================================
 
 /// POST /api/hiring/:hire_request_id/contract
+#[utoipa::path(
+    post,
+    path = "/hiring/{id}/contract",
+    tag = "hiring",
+    params(("id" = Uuid, Path, description = "Hire request id")),
+    request_body = CreateContractInput,
+    responses(
+        (status = 201, description = "`{ data: { id, contract_number } }`"),
+        (status = 400, description = "Invalid contract", body = ErrorResponse),
+        (status = 403, description = "Only the GC can create contracts", body = ErrorResponse),
+        (status = 404, description = "Hire request not found", body = ErrorResponse),
+        (status = 422, description = "Template variables missing", body = ErrorResponse),
+    )
+)]
 pub async fn create_contract(
     State(state): State<Arc<AppState>>,
     Path(hire_request_id): Path<Uuid>,
@@ -1162,14 +2312,21 @@ pub async fn create_contract(
 ) -> Result<impl IntoResponse, ApiError> {
     let user_id = auth.user_id;
 
-    // Verify GC owns the hire request
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(map_db_err("create_contract"))?;
+
+    // Verify GC owns the hire request; hold it until the contract exists so
+    // it cannot be deleted or reassigned in between
     let hire_request: Option<(Uuid, Uuid)> = sqlx::query_as(
-        "SELECT gc_id, project_id FROM hire_requests WHERE id = $1",
+        "SELECT gc_id, project_id FROM hire_requests WHERE id = $1 FOR SHARE",
     )
     .bind(hire_request_id)
-    .fetch_optional(&state.db)
+    .fetch_optional(&mut *tx)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("create_contract"))?;
 
     let (gc_id, project_id) = hire_request
         .ok_or_else(|| ApiError::not_found("Hire request not found"))?;
@@ -1181,30 +2338,41 @@ pub async fn create_contract(
     let id = Uuid::new_v4();
     let contract_number = format!("CON-{}", &id.to_string()[..8].to_uppercase());
     
-    // Get template content if specified
-    let (content, sections) = if let Some(template_id) = input.template_id {
-        let template: Option<(String, serde_json::Value)> = sqlx::query_as(
-            "SELECT content, sections FROM contract_templates WHERE id = $1",
+    // Get template content if specified, filling in its variables
+    let template = match input.template_id {
+        Some(template_id) => sqlx::query_as::<_, (String, serde_json::Value, serde_json::Value)>(
+            "SELECT content, sections, variables FROM contract_templates WHERE id = $1",
         )
         .bind(template_id)
-        .fetch_optional(&state.db)
+        .fetch_optional(&mut *tx)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+        .map_err(map_db_err("create_contract"))?,
+        None => None,
+    };
 
-        template.unwrap_or_else(|| {
-            (
-                input.content.clone().unwrap_or_default(),
-                serde_json::to_value(input.sections.clone().unwrap_or_default()).unwrap_or(serde_json::json!([])),
-            )
-        })
+    let (content, sections) = if let Some((template_content, mut template_sections, variables)) = template {
+        let definitions: Vec<TemplateVariable> = serde_json::from_value(variables).unwrap_or_default();
+        let mut values = contract_template_values(&state, hire_request_id, &contract_number, &input).await?;
+        contract_templates::apply_defaults(&mut values, &definitions);
+
+        let missing = contract_templates::missing_required(&values, &definitions);
+        if !missing.is_empty() {
+            return Err(ApiError::unprocessable(format!(
+                "Missing required template variables: {}",
+                missing.join(", ")
+            )));
+        }
+
+        contract_templates::render_json(&mut template_sections, &values);
+        (contract_templates::render(&template_content, &values), template_sections)
     } else {
         (
-            input.content.unwrap_or_default(),
-            serde_json::to_value(input.sections.unwrap_or_default()).unwrap_or(serde_json::json!([])),
+            input.content.clone().unwrap_or_default(),
+            serde_json::to_value(input.sections.clone().unwrap_or_default()).unwrap_or(serde_json::json!([])),
         )
     };
 
-    let payment_schedule = serde_json::to_value(input.payment_schedule.unwrap_or_default())
+    let payment_schedule = serde_json::to_value(input.payment_schedule.clone().unwrap_or_default())
         .unwrap_or(serde_json::json!([]));
 
     sqlx::query(
@@ -1230,14 +2398,93 @@ pub async fn create_contract(
     .bind(input.start_date)
     .bind(input.end_date)
     .bind(&input.notes)
-    .execute(&state.db)
+    .execute(&mut *tx)
+    .await
+    .map_err(map_db_err("create_contract"))?;
+
+    tx.commit()
+        .await
+        .map_err(map_db_err("create_contract"))?;
+
+    Ok(Created(serde_json::json!({ "id": id, "contract_number": contract_number })))
+}
+
+/// Template variable values for a new contract: data derived from the hire
+/// request, parties, and input, overridden by the caller's `variables`.
+async fn contract_template_values(
+    state: &AppState,
+    hire_request_id: Uuid,
+    contract_number: &str,
+    input: &CreateContractInput,
+) -> Result<contract_templates::TemplateValues, ApiError> {
+    let context = sqlx::query_as::<_, ContractTemplateContextRow>(
+        r#"
+        SELECT gc.company_name as gc_company_name,
+               NULLIF(concat_ws(' ', gc.first_name, gc.last_name), '') as gc_contact_name,
+               gc.location as gc_address,
+               COALESCE(s.name, es.company_name) as sub_company_name,
+               es.contact_name as sub_contact_name,
+               COALESCE(es.address, s.location, es.location) as sub_address,
+               p.name as project_name,
+               NULLIF(concat_ws(', ', p.address, p.city, p.state, p.zip_code), '') as project_address,
+               hr.trade, hr.scope_description
+        FROM hire_requests hr
+        JOIN projects p ON hr.project_id = p.id
+        JOIN profiles gc ON hr.gc_id = gc.id
+        LEFT JOIN subcontractors s ON hr.subcontractor_id = s.id
+        LEFT JOIN external_subcontractors es ON hr.external_sub_id = es.id
+        WHERE hr.id = $1
+        "#,
+    )
+    .bind(hire_request_id)
+    .fetch_one(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Failed to create contract: {}", e)))?;
+    .map_err(map_db_err("contract_template_values"))?;
+
+    let format_date = |d: DateTime<Utc>| d.format("%B %-d, %Y").to_string();
+    let mut values = contract_templates::TemplateValues::new();
+    let derived = [
+        ("gc_company_name", context.gc_company_name),
+        ("gc_contact_name", context.gc_contact_name),
+        ("gc_address", context.gc_address),
+        ("sub_company_name", context.sub_company_name),
+        ("sub_contact_name", context.sub_contact_name),
+        ("sub_address", context.sub_address),
+        ("project_name", Some(context.project_name)),
+        ("project_address", context.project_address),
+        ("trade", Some(context.trade)),
+        ("scope_of_work", context.scope_description),
+        ("contract_number", Some(contract_number.to_string())),
+        ("contract_title", Some(input.title.clone())),
+        ("contract_amount", Some(input.amount.to_string())),
+        ("effective_date", Some(format_date(input.start_date.unwrap_or_else(Utc::now)))),
+        ("start_date", input.start_date.map(format_date)),
+        ("end_date", input.end_date.map(format_date)),
+    ];
+    for (key, value) in derived {
+        if let Some(value) = value {
+            values.insert(key.to_string(), value);
+        }
+    }
+
+    if let Some(variables) = &input.variables {
+        values.extend(contract_templates::flatten_variables(variables));
+    }
 
-    Ok(Json(serde_json::json!({ "id": id, "contract_number": contract_number, "success": true })))
+    Ok(values)
 }
 
 /// GET /api/contracts/:id
+#[utoipa::path(
+    get,
+    path = "/contracts/{id}",
+    tag = "hiring",
+    params(("id" = Uuid, Path, description = "Contract id")),
+    responses(
+        (status = 200, description = "Contract", body = ContractData),
+        (status = 404, description = "Contract not found", body = ErrorResponse),
+    )
+)]
 pub async fn get_contract(
     State(state): State<Arc<AppState>>,
     Path(contract_id): Path<Uuid>,
@@ -1266,7 +2513,7 @@ pub async fn get_contract(
     .bind(user_id)
     .fetch_optional(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
+    .map_err(map_db_err("get_contract"))?
     .ok_or_else(|| ApiError::not_found("Contract not found"))?;
 
     let sections: Vec<ContractSection> =
@@ -1275,7 +2522,7 @@ pub async fn get_contract(
         serde_json::from_value(row.payment_schedule).unwrap_or_default();
 
     // Get subcontractor info
-    let sub_info: Option<(Option<Uuid>, Option<Uuid>, String, Option<String>, Option<String>, Option<String>, String, Option<String>, Option<sqlx::types::Decimal>, bool)> = sqlx::query_as(
+    let sub_info: Option<ContractSubInfoRow> = sqlx::query_as(
         r#"
         SELECT hr.subcontractor_id, hr.external_sub_id,
                COALESCE(s.name, es.company_name) as company_name,
@@ -1295,7 +2542,7 @@ pub async fn get_contract(
     .bind(row.hire_request_id)
     .fetch_optional(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("get_contract"))?;
 
     let subcontractor = if let Some((sub_id, ext_id, company, contact, email, phone, trade, loc, rating, verified)) = sub_info {
         make_subcontractor_info(sub_id, ext_id, company, contact, email, phone, trade, loc, rating, verified)
@@ -1326,7 +2573,7 @@ pub async fn get_contract(
         content: row.content,
         sections,
         terms_summary: row.terms_summary,
-        amount: decimal_to_f64(row.amount),
+        amount: row.amount,
         payment_schedule,
         start_date: row.start_date,
         end_date: row.end_date,
@@ -1346,10 +2593,28 @@ pub async fn get_contract(
 }
 
 /// POST /api/contracts/:id/sign
+///
+/// The signature that makes a contract fully signed also adds the
+/// subcontractor to the project team.
+#[utoipa::path(
+    post,
+    path = "/contracts/{id}/sign",
+    tag = "hiring",
+    params(("id" = Uuid, Path, description = "Contract id")),
+    request_body = SignContractInput,
+    responses(
+        (status = 200, description = "`{ data: { status } }`"),
+        (status = 400, description = "Terms not agreed or contract not signable", body = ErrorResponse),
+        (status = 403, description = "Caller cannot sign this contract", body = ErrorResponse),
+        (status = 404, description = "Contract not found", body = ErrorResponse),
+        (status = 409, description = "Contract changed concurrently", body = ErrorResponse),
+    )
+)]
 pub async fn sign_contract(
     State(state): State<Arc<AppState>>,
     Path(contract_id): Path<Uuid>,
     auth: RequireAuth,
+    ExtractClientIp(client_ip): ExtractClientIp,
     Json(input): Json<SignContractInput>,
 ) -> Result<impl IntoResponse, ApiError> {
     let user_id = auth.user_id;
@@ -1358,26 +2623,35 @@ pub async fn sign_contract(
         return Err(ApiError::bad_request("Must agree to terms to sign"));
     }
 
-    // Get contract and determine if user is GC or sub
-    let contract_info: Option<(Uuid, Option<Uuid>, String)> = sqlx::query_as(
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(map_db_err("sign_contract"))?;
+
+    // Get contract and determine if user is GC or sub; lock it so the content
+    // cannot change between hashing and recording the signature
+    let contract = sqlx::query_as::<_, ContractSigningRow>(
         r#"
-        SELECT hr.gc_id, s.profile_id, c.status
+        SELECT hr.gc_id, s.profile_id as sub_profile_id, c.status, c.project_id,
+               c.title, c.content, COALESCE(c.sections, '[]'::jsonb) as sections, c.amount,
+               c.gc_content_hash
         FROM contracts c
         JOIN hire_requests hr ON c.hire_request_id = hr.id
         LEFT JOIN subcontractors s ON hr.subcontractor_id = s.id
         WHERE c.id = $1
+        FOR UPDATE OF c
         "#,
     )
     .bind(contract_id)
-    .fetch_optional(&state.db)
+    .fetch_optional(&mut *tx)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
-
-    let (gc_id, sub_profile_id, current_status) = contract_info
-        .ok_or_else(|| ApiError::not_found("Contract not found"))?;
+    .map_err(map_db_err("sign_contract"))?
+    .ok_or_else(|| ApiError::not_found("Contract not found"))?;
 
-    let is_gc = gc_id == user_id;
-    let is_sub = sub_profile_id == Some(user_id);
+    let current_status = contract.status.as_str();
+    let is_gc = contract.gc_id == user_id;
+    let is_sub = contract.sub_profile_id == Some(user_id);
 
     if !is_gc && !is_sub {
         return Err(ApiError::forbidden("You cannot sign this contract"));
@@ -1395,31 +2669,322 @@ pub async fn sign_contract(
         ("sub", "fully_signed")
     };
 
+    let content_hash = contract_content_hash(&contract.title, &contract.content, &contract.sections, contract.amount);
+
+    // The second signer must be agreeing to exactly what the first signer saw
+    if is_sub {
+        if let Some(gc_hash) = &contract.gc_content_hash {
+            if *gc_hash != content_hash {
+                return Err(ApiError::conflict(
+                    "Contract was modified after the GC signed; it must be re-signed by the GC",
+                ));
+            }
+        }
+    }
+
     let query = format!(
-        "UPDATE contracts SET {}_signature = $1, {}_signed_at = NOW(), status = $2, updated_at = NOW() WHERE id = $3",
-        column, column
+        r#"
+        UPDATE contracts
+        SET {col}_signature = $1, {col}_signed_at = NOW(), {col}_signed_by = $2,
+            {col}_signed_ip = $3, {col}_content_hash = $4, status = $5, updated_at = NOW()
+        WHERE id = $6
+        "#,
+        col = column
     );
 
     sqlx::query(&query)
         .bind(&input.signature)
+        .bind(user_id)
+        .bind(&client_ip)
+        .bind(&content_hash)
         .bind(new_status)
         .bind(contract_id)
-        .execute(&state.db)
+        .execute(&mut *tx)
         .await
-        .map_err(|e| ApiError::internal(format!("Failed to sign contract: {}", e)))?;
+        .map_err(map_db_err("sign_contract"))?;
 
-    // If fully signed, update hire request status
+    // Once fully signed, the hire request and project team follow in the
+    // same transaction
     if new_status == "fully_signed" {
         sqlx::query(
             "UPDATE hire_requests SET status = 'contract_signed', updated_at = NOW() WHERE id = (SELECT hire_request_id FROM contracts WHERE id = $1)"
         )
         .bind(contract_id)
-        .execute(&state.db)
+        .execute(&mut *tx)
         .await
-        .ok();
+        .map_err(map_db_err("sign_contract"))?;
+
+        add_signed_contract_to_team(&mut tx, contract_id).await?;
+    }
+
+    tx.commit()
+        .await
+        .map_err(map_db_err("sign_contract"))?;
+    let project_id = contract.project_id;
+
+    // Side effects only after commit; a failed log doesn't undo the signature
+    if let Err(e) = audit::log_activity(
+        &state.db,
+        ActivityEntry {
+            actor_id: user_id,
+            project_id: Some(project_id),
+            action: ActivityAction::ContractSigned,
+            target_type: AuditTargetType::Contract,
+            target_id: Some(contract_id),
+            details: serde_json::json!({
+                "signed_as": column,
+                "status": new_status,
+                "content_hash": content_hash,
+            }),
+            ip_address: client_ip,
+        },
+    )
+    .await
+    {
+        tracing::warn!(error = %e, "Failed to log contract signature");
+    }
+
+    Ok(DataResponse::new(serde_json::json!({ "status": new_status })))
+}
+
+/// GET /api/contracts/:id/signatures/verify
+///
+/// Recompute the contract content hash and check it against each stored signature.
+#[utoipa::path(
+    get,
+    path = "/contracts/{id}/signatures/verify",
+    tag = "hiring",
+    params(("id" = Uuid, Path, description = "Contract id")),
+    responses(
+        (status = 200, description = "Signature verification", body = ContractSignatureVerificationData),
+        (status = 404, description = "Contract not found", body = ErrorResponse),
+    )
+)]
+pub async fn verify_contract_signatures(
+    State(state): State<Arc<AppState>>,
+    Path(contract_id): Path<Uuid>,
+    auth: RequireAuth,
+) -> Result<impl IntoResponse, ApiError> {
+    let row = sqlx::query_as::<_, ContractSignatureRow>(
+        r#"
+        SELECT hr.gc_id, s.profile_id as sub_profile_id, c.status,
+               c.title, c.content, COALESCE(c.sections, '[]'::jsonb) as sections, c.amount,
+               c.gc_signed_by, c.gc_signed_at, c.gc_signed_ip, c.gc_content_hash,
+               c.sub_signed_by, c.sub_signed_at, c.sub_signed_ip, c.sub_content_hash
+        FROM contracts c
+        JOIN hire_requests hr ON c.hire_request_id = hr.id
+        LEFT JOIN subcontractors s ON hr.subcontractor_id = s.id
+        WHERE c.id = $1
+        "#,
+    )
+    .bind(contract_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("verify_contract_signatures"))?
+    .ok_or_else(|| ApiError::not_found("Contract not found"))?;
+
+    if row.gc_id != auth.user_id && row.sub_profile_id != Some(auth.user_id) {
+        return Err(ApiError::not_found("Contract not found"));
+    }
+
+    let content_hash = contract_content_hash(&row.title, &row.content, &row.sections, row.amount);
+    let verify = |signed_by: Option<Uuid>,
+                  signed_at: Option<DateTime<Utc>>,
+                  signed_ip: Option<String>,
+                  stored_hash: Option<String>| {
+        signed_at.map(|signed_at| SignatureVerification {
+            signed_by,
+            signed_at,
+            signed_ip,
+            hash_matches: stored_hash.as_deref() == Some(content_hash.as_str()),
+            content_hash: stored_hash,
+        })
+    };
+
+    let gc = verify(row.gc_signed_by, row.gc_signed_at, row.gc_signed_ip, row.gc_content_hash);
+    let sub = verify(row.sub_signed_by, row.sub_signed_at, row.sub_signed_ip, row.sub_content_hash);
+    let valid = [&gc, &sub].iter().all(|sig| sig.as_ref().map_or(true, |s| s.hash_matches));
+
+    Ok(Json(DataResponse::new(ContractSignatureVerification {
+        contract_id,
+        status: row.status,
+        content_hash,
+        gc_signature: gc,
+        sub_signature: sub,
+        valid,
+    })))
+}
+
+const CONTRACT_PAYMENT_SELECT: &str = r#"
+        SELECT hr.gc_id, s.profile_id as sub_profile_id, c.project_id, c.status,
+               c.amount, COALESCE(c.payment_schedule, '[]'::jsonb) as payment_schedule
+        FROM contracts c
+        JOIN hire_requests hr ON c.hire_request_id = hr.id
+        LEFT JOIN subcontractors s ON hr.subcontractor_id = s.id
+        WHERE c.id = $1"#;
+
+/// GET /api/contracts/:id/payments
+///
+/// Payment schedule with total, paid, and outstanding amounts.
+#[utoipa::path(
+    get,
+    path = "/contracts/{id}/payments",
+    tag = "hiring",
+    params(("id" = Uuid, Path, description = "Contract id")),
+    responses(
+        (status = 200, description = "Payment schedule and totals", body = ContractPaymentSummaryData),
+        (status = 404, description = "Contract not found", body = ErrorResponse),
+    )
+)]
+pub async fn get_contract_payments(
+    State(state): State<Arc<AppState>>,
+    Path(contract_id): Path<Uuid>,
+    auth: RequireAuth,
+) -> Result<impl IntoResponse, ApiError> {
+    let row = sqlx::query_as::<_, ContractPaymentRow>(CONTRACT_PAYMENT_SELECT)
+        .bind(contract_id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(map_db_err("get_contract_payments"))?
+        .ok_or_else(|| ApiError::not_found("Contract not found"))?;
+
+    if row.gc_id != auth.user_id && row.sub_profile_id != Some(auth.user_id) {
+        return Err(ApiError::not_found("Contract not found"));
+    }
+
+    let milestones = row.milestones()?;
+    let summary = ContractPaymentSummary::new(contract_id, row.status, row.amount, milestones);
+
+    Ok(Json(DataResponse::new(summary)))
+}
+
+/// POST /api/contracts/:id/payments/:milestone_index/pay
+///
+/// Mark a payment milestone as paid (GC only, signed contracts).
+#[utoipa::path(
+    post,
+    path = "/contracts/{id}/payments/{milestone_index}/pay",
+    tag = "hiring",
+    params(
+        ("id" = Uuid, Path, description = "Contract id"),
+        ("milestone_index" = usize, Path, description = "Position in the payment schedule"),
+    ),
+    request_body = Option<MarkMilestonePaidInput>,
+    responses(
+        (status = 200, description = "Updated payment summary", body = ContractPaymentSummaryData),
+        (status = 400, description = "Milestone index out of range", body = ErrorResponse),
+        (status = 403, description = "Only the GC can record payments", body = ErrorResponse),
+        (status = 404, description = "Contract not found", body = ErrorResponse),
+        (status = 409, description = "Milestone already paid", body = ErrorResponse),
+    )
+)]
+pub async fn mark_milestone_paid(
+    State(state): State<Arc<AppState>>,
+    Path((contract_id, milestone_index)): Path<(Uuid, usize)>,
+    auth: RequireAuth,
+    ExtractClientIp(client_ip): ExtractClientIp,
+    input: Option<Json<MarkMilestonePaidInput>>,
+) -> Result<impl IntoResponse, ApiError> {
+    let user_id = auth.user_id;
+    let input = input.map(|Json(i)| i).unwrap_or_default();
+    let reference_number = input
+        .reference_number
+        .map(|r| r.trim().to_string())
+        .filter(|r| !r.is_empty());
+
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(map_db_err("mark_milestone_paid"))?;
+
+    // Lock the contract row so concurrent requests cannot pay the same milestone twice
+    let row = sqlx::query_as::<_, ContractPaymentRow>(&format!("{} FOR UPDATE OF c", CONTRACT_PAYMENT_SELECT))
+        .bind(contract_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(map_db_err("mark_milestone_paid"))?
+        .ok_or_else(|| ApiError::not_found("Contract not found"))?;
+
+    if row.gc_id != user_id {
+        return Err(ApiError::forbidden("Only the GC can record payments"));
+    }
+
+    if !ContractPaymentSummary::PAYABLE_STATUSES.contains(&row.status.as_str()) {
+        return Err(ApiError::bad_request(format!(
+            "Payments cannot be recorded on a contract with status '{}'",
+            row.status
+        )));
+    }
+
+    let mut milestones = row.milestones()?;
+    let milestone = milestones
+        .get_mut(milestone_index)
+        .ok_or_else(|| ApiError::not_found("Payment milestone not found"))?;
+
+    if milestone.is_paid {
+        return Err(ApiError::conflict("Payment milestone is already paid"));
+    }
+
+    milestone.is_paid = true;
+    milestone.paid_at = Some(Utc::now());
+    milestone.reference_number = reference_number;
+    let milestone_name = milestone.name.clone();
+    let milestone_amount = milestone.amount;
+
+    let payment_schedule = serde_json::to_value(&milestones)
+        .map_err(|e| ApiError::internal(format!("Failed to serialize payment schedule: {}", e)))?;
+
+    sqlx::query("UPDATE contracts SET payment_schedule = $1, updated_at = NOW() WHERE id = $2")
+        .bind(&payment_schedule)
+        .bind(contract_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(map_db_err("mark_milestone_paid"))?;
+
+    tx.commit()
+        .await
+        .map_err(map_db_err("mark_milestone_paid"))?;
+
+    let summary = ContractPaymentSummary::new(contract_id, row.status, row.amount, milestones);
+
+    if let Some(sub_user_id) = row.sub_profile_id {
+        if let Err(e) = notifications::notify_milestone_paid(
+            &state.db,
+            sub_user_id,
+            contract_id,
+            &milestone_name,
+            milestone_amount,
+            summary.outstanding_amount,
+        )
+        .await
+        {
+            tracing::warn!(error = %e, "Failed to send milestone paid notification");
+        }
     }
 
-    Ok(Json(serde_json::json!({ "success": true, "status": new_status })))
+    if let Err(e) = audit::log_activity(
+        &state.db,
+        ActivityEntry {
+            actor_id: user_id,
+            project_id: Some(row.project_id),
+            action: ActivityAction::PaymentMilestonePaid,
+            target_type: AuditTargetType::Contract,
+            target_id: Some(contract_id),
+            details: serde_json::json!({
+                "milestone_index": milestone_index,
+                "milestone_name": milestone_name,
+                "amount": milestone_amount,
+            }),
+            ip_address: client_ip,
+        },
+    )
+    .await
+    {
+        tracing::warn!(error = %e, "Failed to log milestone payment");
+    }
+
+    Ok(Json(DataResponse::new(summary)))
 }
 
 // ============================================================================
@@ -1427,6 +2992,16 @@ pub async fn sign_contract(
 // ============================================================================
 
 /// GET /api/projects/:project_id/team
+#[utoipa::path(
+    get,
+    path = "/projects/{project_id}/team",
+    tag = "hiring",
+    params(("project_id" = Uuid, Path, description = "Project id")),
+    responses(
+        (status = 200, description = "Project team", body = TeamMemberListData),
+        (status = 403, description = "No access to this project", body = ErrorResponse),
+    )
+)]
 pub async fn list_team_members(
     State(state): State<Arc<AppState>>,
     Path(project_id): Path<Uuid>,
@@ -1439,7 +3014,7 @@ pub async fn list_team_members(
         .bind(project_id)
         .fetch_optional(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
+        .map_err(map_db_err("list_team_members"))?
         .flatten();
 
     if owner != Some(user_id) {
@@ -1459,11 +3034,15 @@ pub async fn list_team_members(
                s.rating as sub_rating,
                COALESCE(s.verified, false) as sub_verified,
                pt.role, pt.trade, pt.responsibilities, pt.start_date, pt.end_date,
-               pt.hourly_rate, pt.status, pt.performance_rating, pt.notes,
-               pt.joined_at, pt.created_at, pt.updated_at
+               pt.hourly_rate, pt.status, pt.performance_rating,
+               pt.review_id, r.rating as review_rating, r.title as review_title,
+               r.comment as review_comment, r.would_hire_again as review_would_hire_again,
+               r.created_at as reviewed_at,
+               pt.notes, pt.joined_at, pt.created_at, pt.updated_at
         FROM project_team pt
         LEFT JOIN subcontractors s ON pt.subcontractor_id = s.id
         LEFT JOIN external_subcontractors es ON pt.external_sub_id = es.id
+        LEFT JOIN subcontractor_reviews r ON pt.review_id = r.id
         WHERE pt.project_id = $1
         ORDER BY pt.joined_at DESC
         "#,
@@ -1471,7 +3050,7 @@ pub async fn list_team_members(
     .bind(project_id)
     .fetch_all(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("list_team_members"))?;
 
     let data: Vec<TeamMemberResponse> = rows
         .into_iter()
@@ -1488,6 +3067,17 @@ pub async fn list_team_members(
                 r.sub_rating,
                 r.sub_verified,
             );
+            let review = match (r.review_id, r.review_rating, r.reviewed_at) {
+                (Some(id), Some(rating), Some(created_at)) => Some(TeamMemberReview {
+                    id,
+                    rating: Rating::from(rating),
+                    title: r.review_title,
+                    comment: r.review_comment,
+                    would_hire_again: r.review_would_hire_again,
+                    created_at,
+                }),
+                _ => None,
+            };
 
             TeamMemberResponse {
                 id: r.id,
@@ -1500,9 +3090,10 @@ pub async fn list_team_members(
                 responsibilities: r.responsibilities,
                 start_date: r.start_date,
                 end_date: r.end_date,
-                hourly_rate: decimal_opt_to_f64(r.hourly_rate),
+                hourly_rate: r.hourly_rate,
                 status: r.status,
-                performance_rating: decimal_opt_to_f64(r.performance_rating),
+                performance_rating: r.performance_rating.map(Rating::from),
+                review,
                 notes: r.notes,
                 joined_at: r.joined_at,
                 created_at: r.created_at,
@@ -1515,6 +3106,19 @@ pub async fn list_team_members(
 }
 
 /// POST /api/projects/:project_id/team
+#[utoipa::path(
+    post,
+    path = "/projects/{project_id}/team",
+    tag = "hiring",
+    params(("project_id" = Uuid, Path, description = "Project id")),
+    request_body = AddTeamMemberInput,
+    responses(
+        (status = 201, description = "Team member added", body = CreatedIdData),
+        (status = 400, description = "Invalid member", body = ErrorResponse),
+        (status = 403, description = "Caller does not own the project", body = ErrorResponse),
+        (status = 409, description = "Hire request already has a team member", body = ErrorResponse),
+    )
+)]
 pub async fn add_team_member(
     State(state): State<Arc<AppState>>,
     Path(project_id): Path<Uuid>,
@@ -1528,13 +3132,27 @@ pub async fn add_team_member(
         .bind(project_id)
         .fetch_optional(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
+        .map_err(map_db_err("add_team_member"))?
         .flatten();
 
     if owner != Some(user_id) {
         return Err(ApiError::forbidden("You don't own this project"));
     }
 
+    if let Some(hire_request_id) = input.hire_request_id {
+        let already_on_team: bool = sqlx::query_scalar(
+            "SELECT EXISTS(SELECT 1 FROM project_team WHERE hire_request_id = $1)",
+        )
+        .bind(hire_request_id)
+        .fetch_one(&state.db)
+        .await
+        .map_err(map_db_err("add_team_member"))?;
+
+        if already_on_team {
+            return Err(ApiError::conflict("This hire request already has a team member"));
+        }
+    }
+
     let id = Uuid::new_v4();
 
     sqlx::query(
@@ -1560,12 +3178,67 @@ pub async fn add_team_member(
     .bind(&input.notes)
     .execute(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Failed to add team member: {}", e)))?;
+    .map_err(map_db_err("add_team_member"))?;
+
+    Ok(Created::id(id))
+}
 
-    Ok(Json(serde_json::json!({ "id": id, "success": true })))
+/// Put the subcontractor on a fully signed contract onto the project team.
+///
+/// Trade, dates and (for hourly work) the agreed rate come from the contract
+/// and its hire request. Does nothing if the hire request already has a team
+/// row, so signing and manual adds never double up.
+async fn add_signed_contract_to_team(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    contract_id: Uuid,
+) -> Result<(), ApiError> {
+    sqlx::query(
+        r#"
+        INSERT INTO project_team (
+            project_id, hire_request_id, contract_id, subcontractor_id, external_sub_id,
+            trade, responsibilities, start_date, end_date, hourly_rate, status
+        )
+        SELECT c.project_id, c.hire_request_id, c.id, hr.subcontractor_id, hr.external_sub_id,
+               hr.trade, hr.scope_description,
+               COALESCE(c.start_date, hr.estimated_start_date),
+               COALESCE(c.end_date, hr.estimated_end_date),
+               CASE WHEN hr.rate_type = 'hourly' THEN
+                   CASE WHEN hr.counter_offer_status = 'accepted'
+                        THEN COALESCE(hr.sub_counter_amount, hr.proposed_amount)
+                        ELSE hr.proposed_amount END
+               END,
+               'active'
+        FROM contracts c
+        JOIN hire_requests hr ON c.hire_request_id = hr.id
+        WHERE c.id = $1
+        AND NOT EXISTS (
+            SELECT 1 FROM project_team pt WHERE pt.hire_request_id = c.hire_request_id
+        )
+        "#,
+    )
+    .bind(contract_id)
+    .execute(&mut **tx)
+    .await
+    .map_err(map_db_err("add_signed_contract_to_team"))?;
+    Ok(())
 }
 
 /// PUT /api/projects/:project_id/team/:member_id
+#[utoipa::path(
+    put,
+    path = "/projects/{project_id}/team/{member_id}",
+    tag = "hiring",
+    params(
+        ("project_id" = Uuid, Path, description = "Project id"),
+        ("member_id" = Uuid, Path, description = "Team member id"),
+    ),
+    request_body = UpdateTeamMemberInput,
+    responses(
+        (status = 200, description = "Team member updated", body = MessageResponse),
+        (status = 403, description = "Caller does not own the project", body = ErrorResponse),
+        (status = 404, description = "Team member not found", body = ErrorResponse),
+    )
+)]
 pub async fn update_team_member(
     State(state): State<Arc<AppState>>,
     Path((project_id, member_id)): Path<(Uuid, Uuid)>,
@@ -1579,7 +3252,7 @@ pub async fn update_team_member(
         .bind(project_id)
         .fetch_optional(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
+        .map_err(map_db_err("update_team_member"))?
         .flatten();
 
     if owner != Some(user_id) {
@@ -1589,40 +3262,205 @@ pub async fn update_team_member(
     let result = sqlx::query(
         r#"
         UPDATE project_team SET
-            role = COALESCE($1, role),
-            responsibilities = COALESCE($2, responsibilities),
-            start_date = COALESCE($3, start_date),
-            end_date = COALESCE($4, end_date),
-            hourly_rate = COALESCE($5, hourly_rate),
-            status = COALESCE($6, status),
-            performance_rating = COALESCE($7, performance_rating),
-            notes = COALESCE($8, notes),
+            role = CASE WHEN $1 THEN $2 ELSE role END,
+            responsibilities = CASE WHEN $3 THEN $4 ELSE responsibilities END,
+            start_date = CASE WHEN $5 THEN $6 ELSE start_date END,
+            end_date = CASE WHEN $7 THEN $8 ELSE end_date END,
+            hourly_rate = CASE WHEN $9 THEN $10 ELSE hourly_rate END,
+            status = COALESCE($11, status),
+            performance_rating = COALESCE($12, performance_rating),
+            notes = CASE WHEN $13 THEN $14 ELSE notes END,
             updated_at = NOW()
-        WHERE id = $9 AND project_id = $10
+        WHERE id = $15 AND project_id = $16
         "#,
     )
-    .bind(&input.role)
-    .bind(&input.responsibilities)
-    .bind(input.start_date)
-    .bind(input.end_date)
-    .bind(input.hourly_rate)
+    .bind(input.role.is_set())
+    .bind(input.role.value())
+    .bind(input.responsibilities.is_set())
+    .bind(input.responsibilities.value())
+    .bind(input.start_date.is_set())
+    .bind(input.start_date.copied())
+    .bind(input.end_date.is_set())
+    .bind(input.end_date.copied())
+    .bind(input.hourly_rate.is_set())
+    .bind(input.hourly_rate.copied())
     .bind(&input.status)
-    .bind(input.performance_rating)
-    .bind(&input.notes)
+    .bind(input.performance_rating.map(Rating::to_decimal))
+    .bind(input.notes.is_set())
+    .bind(input.notes.value())
     .bind(member_id)
     .bind(project_id)
     .execute(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Failed to update team member: {}", e)))?;
+    .map_err(map_db_err("update_team_member"))?;
 
     if result.rows_affected() == 0 {
         return Err(ApiError::not_found("Team member not found"));
     }
 
-    Ok(Json(serde_json::json!({ "success": true })))
+    Ok(MessageResponse::new("Team member updated"))
+}
+
+/// POST /api/projects/:project_id/team/:member_id/review
+///
+/// GC reviews a completed team member. Writes a `subcontractor_reviews` row
+/// and sets the member's performance rating from it in one transaction.
+#[utoipa::path(
+    post,
+    path = "/projects/{project_id}/team/{member_id}/review",
+    tag = "hiring",
+    params(
+        ("project_id" = Uuid, Path, description = "Project id"),
+        ("member_id" = Uuid, Path, description = "Team member id"),
+    ),
+    request_body = TeamMemberReviewInput,
+    responses(
+        (status = 201, description = "Review recorded", body = TeamMemberReviewData),
+        (status = 400, description = "Member not completed, or invalid ratings", body = ErrorResponse),
+        (status = 403, description = "Caller does not own the project", body = ErrorResponse),
+        (status = 404, description = "Team member not found", body = ErrorResponse),
+        (status = 409, description = "Team member already reviewed", body = ErrorResponse),
+    )
+)]
+pub async fn review_team_member(
+    State(state): State<Arc<AppState>>,
+    Path((project_id, member_id)): Path<(Uuid, Uuid)>,
+    auth: RequireAuth,
+    Json(input): Json<TeamMemberReviewInput>,
+) -> Result<impl IntoResponse, ApiError> {
+    let user_id = auth.user_id;
+
+    // Verify project ownership
+    let owner: Option<Uuid> = sqlx::query_scalar("SELECT owner_id FROM projects WHERE id = $1")
+        .bind(project_id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(map_db_err("review_team_member"))?
+        .flatten();
+
+    if owner != Some(user_id) {
+        return Err(ApiError::forbidden("You don't own this project"));
+    }
+
+    // Reviews are scored 1-5; zero only means "unrated" elsewhere
+    let ratings = [
+        Some(input.rating),
+        input.quality_rating,
+        input.communication_rating,
+        input.timeliness_rating,
+        input.value_rating,
+    ];
+    if ratings.into_iter().flatten().any(|r| r.value() < 1.0) {
+        return Err(ApiError::bad_request("Review ratings must be between 1 and 5"));
+    }
+
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(map_db_err("review_team_member"))?;
+
+    let member = sqlx::query_as::<_, TeamMemberReviewTargetRow>(
+        r#"
+        SELECT status, subcontractor_id, external_sub_id, contract_id, review_id
+        FROM project_team
+        WHERE id = $1 AND project_id = $2
+        FOR UPDATE
+        "#,
+    )
+    .bind(member_id)
+    .bind(project_id)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(map_db_err("review_team_member"))?
+    .ok_or_else(|| ApiError::not_found("Team member not found"))?;
+
+    if member.status != "completed" {
+        return Err(ApiError::bad_request(
+            "Team member must be completed before they can be reviewed",
+        ));
+    }
+    if member.review_id.is_some() {
+        return Err(ApiError::conflict("Team member has already been reviewed"));
+    }
+    if member.subcontractor_id.is_none() && member.external_sub_id.is_none() {
+        return Err(ApiError::bad_request("Team member has no subcontractor to review"));
+    }
+
+    let review = sqlx::query_as::<_, (Uuid, DateTime<Utc>)>(
+        r#"
+        INSERT INTO subcontractor_reviews (
+            subcontractor_id, external_sub_id, reviewer_id, project_id, contract_id,
+            rating, quality_rating, communication_rating, timeliness_rating, value_rating,
+            title, comment, would_hire_again, is_verified
+        ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, true)
+        RETURNING id, created_at
+        "#,
+    )
+    .bind(member.subcontractor_id)
+    .bind(member.external_sub_id)
+    .bind(user_id)
+    .bind(project_id)
+    .bind(member.contract_id)
+    .bind(input.rating.to_decimal())
+    .bind(input.quality_rating.map(Rating::to_decimal))
+    .bind(input.communication_rating.map(Rating::to_decimal))
+    .bind(input.timeliness_rating.map(Rating::to_decimal))
+    .bind(input.value_rating.map(Rating::to_decimal))
+    .bind(&input.title)
+    .bind(&input.comment)
+    .bind(input.would_hire_again)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(map_db_err("review_team_member"))?;
+
+    sqlx::query(
+        r#"
+        UPDATE project_team SET performance_rating = $1, review_id = $2, updated_at = NOW()
+        WHERE id = $3
+        "#,
+    )
+    .bind(input.rating.to_decimal())
+    .bind(review.0)
+    .bind(member_id)
+    .execute(&mut *tx)
+    .await
+    .map_err(map_db_err("review_team_member"))?;
+
+    tx.commit()
+        .await
+        .map_err(map_db_err("review_team_member"))?;
+
+    // The review changes the sub's rating and review averages
+    if let Some(sub_id) = member.subcontractor_id {
+        state.cache.invalidate_subcontractor(sub_id).await;
+    }
+
+    Ok(Created(TeamMemberReview {
+        id: review.0,
+        rating: input.rating,
+        title: input.title,
+        comment: input.comment,
+        would_hire_again: input.would_hire_again,
+        created_at: review.1,
+    }))
 }
 
 /// DELETE /api/projects/:project_id/team/:member_id
+#[utoipa::path(
+    delete,
+    path = "/projects/{project_id}/team/{member_id}",
+    tag = "hiring",
+    params(
+        ("project_id" = Uuid, Path, description = "Project id"),
+        ("member_id" = Uuid, Path, description = "Team member id"),
+    ),
+    responses(
+        (status = 204, description = "Team member removed"),
+        (status = 403, description = "Caller does not own the project", body = ErrorResponse),
+        (status = 404, description = "Team member not found", body = ErrorResponse),
+    )
+)]
 pub async fn remove_team_member(
     State(state): State<Arc<AppState>>,
     Path((project_id, member_id)): Path<(Uuid, Uuid)>,
@@ -1635,7 +3473,7 @@ pub async fn remove_team_member(
         .bind(project_id)
         .fetch_optional(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
+        .map_err(map_db_err("remove_team_member"))?
         .flatten();
 
     if owner != Some(user_id) {
@@ -1647,11 +3485,183 @@ pub async fn remove_team_member(
         .bind(project_id)
         .execute(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Failed to remove team member: {}", e)))?;
+        .map_err(map_db_err("remove_team_member"))?;
 
     if result.rows_affected() == 0 {
         return Err(ApiError::not_found("Team member not found"));
     }
 
-    Ok(Json(serde_json::json!({ "success": true })))
+    Ok(NoContent)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::test_support;
+
+    /// A hire request from a new GC to a new registered sub, in `status`.
+    /// Returns the request id and the sub's user id.
+    async fn hire_request(db: &sqlx::PgPool, status: &str) -> (Uuid, Uuid) {
+        let gc_id = test_support::user(db, "gc").await;
+        let sub_user_id = test_support::user(db, "sub").await;
+        let project_id = test_support::project(db, gc_id).await;
+        let subcontractor_id = test_support::subcontractor(db, sub_user_id).await;
+        let request_id = sqlx::query_scalar(
+            r#"
+            INSERT INTO hire_requests (project_id, gc_id, subcontractor_id, status, trade, title)
+            VALUES ($1, $2, $3, $4, 'electrical', 'Panel upgrade')
+            RETURNING id
+            "#,
+        )
+        .bind(project_id)
+        .bind(gc_id)
+        .bind(subcontractor_id)
+        .bind(status)
+        .fetch_one(db)
+        .await
+        .unwrap();
+        (request_id, sub_user_id)
+    }
+
+    #[tokio::test]
+    async fn unmatched_profile_cannot_be_linked() {
+        let Some(state) = test_support::state().await else { return };
+        let gc_id = test_support::user(&state.db, "gc").await;
+        let sub_user_id = test_support::user(&state.db, "sub").await;
+        let platform_sub_id = test_support::subcontractor(&state.db, sub_user_id).await;
+        let project_id = test_support::project(&state.db, gc_id).await;
+        let external_id: Uuid = sqlx::query_scalar(
+            "INSERT INTO external_subcontractors (added_by, company_name, trade) VALUES ($1, 'Unrelated Roofing', 'roofing') RETURNING id",
+        )
+        .bind(gc_id)
+        .fetch_one(&state.db)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO hire_requests (project_id, gc_id, external_sub_id, status, trade, title) VALUES ($1, $2, $3, 'hired', 'roofing', 'Reroof')",
+        )
+        .bind(project_id)
+        .bind(gc_id)
+        .bind(external_id)
+        .execute(&state.db)
+        .await
+        .unwrap();
+
+        let result = link_external_subcontractor(
+            State(state.clone()),
+            Path((external_id, platform_sub_id)),
+            test_support::auth(gc_id),
+            Json(LinkExternalSubcontractorInput { confirm: true }),
+        )
+        .await;
+        let err = result.err().expect("link should be refused");
+        assert!(matches!(&err, ApiError::UnprocessableEntity(_)), "{:?}", err);
+
+        let (moved, linked): (i64, Option<Uuid>) = sqlx::query_as(
+            r#"
+            SELECT (SELECT COUNT(*) FROM hire_requests WHERE subcontractor_id = $2),
+                   (SELECT linked_subcontractor_id FROM external_subcontractors WHERE id = $1)
+            "#,
+        )
+        .bind(external_id)
+        .bind(platform_sub_id)
+        .fetch_one(&state.db)
+        .await
+        .unwrap();
+        assert_eq!(moved, 0);
+        assert_eq!(linked, None);
+    }
+
+    #[tokio::test]
+    async fn counter_amount_is_stored_exactly() {
+        let Some(state) = test_support::state().await else { return };
+        let (request_id, sub_user_id) = hire_request(&state.db, "viewed").await;
+
+        // 1234567.89 has no exact f64 representation
+        let input: HireRequestStatusInput = serde_json::from_value(serde_json::json!({
+            "status": "interested",
+            "response": "Can do it for less",
+            "counter_amount": "1234567.89",
+        }))
+        .unwrap();
+        update_hire_request_status(
+            State(state.clone()),
+            Path(request_id),
+            test_support::auth(sub_user_id),
+            ExtractClientIp(None),
+            Json(input),
+        )
+        .await
+        .unwrap();
+
+        let (status, stored, counter_status): (String, String, Option<String>) = sqlx::query_as(
+            "SELECT status, sub_counter_amount::text, counter_offer_status FROM hire_requests WHERE id = $1",
+        )
+        .bind(request_id)
+        .fetch_one(&state.db)
+        .await
+        .unwrap();
+        assert_eq!(status, "interested");
+        assert_eq!(stored, "1234567.89");
+        assert_eq!(counter_status.as_deref(), Some("pending"));
+    }
+
+    #[tokio::test]
+    async fn failed_signing_step_rolls_back_the_signature() {
+        let Some(state) = test_support::state().await else { return };
+        let (request_id, sub_user_id) = hire_request(&state.db, "contract_sent").await;
+
+        // An hourly rate too large for project_team.hourly_rate, so the last
+        // write of a full signature fails after the contract and hire request
+        // updates have run
+        sqlx::query("UPDATE hire_requests SET rate_type = 'hourly', proposed_amount = 123456789 WHERE id = $1")
+            .bind(request_id)
+            .execute(&state.db)
+            .await
+            .unwrap();
+        let contract_id: Uuid = sqlx::query_scalar(
+            r#"
+            INSERT INTO contracts (hire_request_id, project_id, title, content, amount, status)
+            SELECT id, project_id, 'Panel upgrade', 'Terms', 123456789, 'pending_sub'
+            FROM hire_requests WHERE id = $1
+            RETURNING id
+            "#,
+        )
+        .bind(request_id)
+        .fetch_one(&state.db)
+        .await
+        .unwrap();
+
+        let input = SignContractInput { signature: "Test Sub".to_string(), agreed_to_terms: true };
+        let result = sign_contract(
+            State(state.clone()),
+            Path(contract_id),
+            test_support::auth(sub_user_id),
+            ExtractClientIp(None),
+            Json(input),
+        )
+        .await;
+        let err = result.err().expect("signing should fail");
+        assert!(format!("{:?}", err).contains("add_signed_contract_to_team"), "{:?}", err);
+
+        let (contract_status, signature): (String, Option<String>) =
+            sqlx::query_as("SELECT status, sub_signature FROM contracts WHERE id = $1")
+                .bind(contract_id)
+                .fetch_one(&state.db)
+                .await
+                .unwrap();
+        assert_eq!(contract_status, "pending_sub");
+        assert_eq!(signature, None);
+
+        let (request_status, team_members): (String, i64) = sqlx::query_as(
+            "SELECT status, (SELECT COUNT(*) FROM project_team WHERE hire_request_id = $1) FROM hire_requests WHERE id = $1",
+        )
+        .bind(request_id)
+        .fetch_one(&state.db)
+        .await
+        .unwrap();
+        assert_eq!(request_status, "contract_sent");
+        assert_eq!(team_members, 0);
+    }
 }
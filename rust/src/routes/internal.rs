@@ -0,0 +1,352 @@
+//! Internal service routes
+//!
+//! Endpoints called by the Python AI service (not the frontend), authenticated
+//! with the shared internal token rather than a user JWT:
+//! - Job claiming and heartbeats for pull-based workers
+//! - Progress callbacks for running jobs
+
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+    Json,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::app::AppState;
+use crate::auth::RequireInternalToken;
+use crate::domain::ai::TradeScopesOutput;
+use crate::domain::extraction::ScopeItem;
+use crate::domain::trades::normalized_trade_key;
+use crate::api::response::{DataResponse, MessageResponse};
+use crate::domain::jobs::{
+    ClaimJobRequest, ClaimedJobResponse, JobHeartbeatRequest, JobProgressCallback,
+    ProcessingJobType, StepStatus,
+};
+use crate::error::{map_db_err, ApiError};
+use crate::routes::jobs::get_job_steps;
+
+#[derive(Debug, sqlx::FromRow)]
+struct ClaimedJobRow {
+    id: Uuid,
+    job_type: String,
+    project_id: Uuid,
+    document_id: Uuid,
+    storage_key: Option<String>,
+    content_hash: Option<String>,
+    input: serde_json::Value,
+}
+
+/// POST /internal/jobs/claim
+///
+/// Atomically claim the oldest queued job for a worker. `FOR UPDATE SKIP LOCKED`
+/// lets concurrent workers claim without ever receiving the same job.
+/// Returns `data: null` when nothing is queued.
+pub async fn claim_job(
+    State(state): State<Arc<AppState>>,
+    _internal: RequireInternalToken,
+    Json(input): Json<ClaimJobRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let worker_id = input.worker_id.trim();
+    if worker_id.is_empty() {
+        return Err(ApiError::bad_request("worker_id is required"));
+    }
+
+    let job_types: Option<Vec<String>> = input
+        .job_types
+        .map(|types| types.iter().map(|t| t.to_string()).collect());
+
+    let claimed = sqlx::query_as::<_, ClaimedJobRow>(
+        r#"
+        UPDATE processing_jobs j SET
+            status = 'running',
+            claimed_by = $1,
+            claimed_at = NOW(),
+            heartbeat_at = NOW(),
+            last_progress_at = NOW(),
+            started_at = COALESCE(j.started_at, NOW()),
+            updated_at = NOW()
+        FROM documents d
+        WHERE j.id = (
+            SELECT id FROM processing_jobs
+            WHERE status = 'queued'
+            AND ($2::text[] IS NULL OR job_type = ANY($2))
+            ORDER BY created_at ASC
+            LIMIT 1
+            FOR UPDATE SKIP LOCKED
+        )
+        AND d.id = j.document_id
+        RETURNING j.id, j.job_type, j.project_id, j.document_id, d.file_path AS storage_key,
+                  j.content_hash, COALESCE(j.input, '{}'::jsonb) AS input
+        "#,
+    )
+    .bind(worker_id)
+    .bind(&job_types)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("claim_job"))?;
+
+    let Some(job) = claimed else {
+        return Ok(Json(DataResponse::new(None)));
+    };
+
+    tracing::info!(job_id = %job.id, worker_id = %worker_id, "Job claimed");
+
+    let steps = get_job_steps(&state, job.id).await?;
+
+    Ok(Json(DataResponse::new(Some(ClaimedJobResponse {
+        job_id: job.id,
+        job_type: job.job_type,
+        project_id: job.project_id,
+        document_id: job.document_id,
+        storage_key: job.storage_key,
+        content_hash: job.content_hash,
+        input: job.input,
+        steps,
+    }))))
+}
+
+/// POST /internal/jobs/:job_id/heartbeat
+///
+/// Keep a claimed job alive. Fails with 409 once the job is no longer running
+/// for this worker (cancelled, paused, finished or requeued), telling it to stop.
+pub async fn job_heartbeat(
+    State(state): State<Arc<AppState>>,
+    Path(job_id): Path<Uuid>,
+    _internal: RequireInternalToken,
+    Json(input): Json<JobHeartbeatRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let result = sqlx::query(
+        r#"
+        UPDATE processing_jobs SET heartbeat_at = NOW(), last_progress_at = NOW()
+        WHERE id = $1 AND status = 'running' AND claimed_by = $2
+        "#,
+    )
+    .bind(job_id)
+    .bind(input.worker_id.trim())
+    .execute(&state.db)
+    .await
+    .map_err(map_db_err("job_heartbeat"))?;
+
+    if result.rows_affected() == 0 {
+        return Err(ApiError::conflict("Job is not running for this worker"));
+    }
+
+    Ok(MessageResponse::new("Heartbeat recorded"))
+}
+
+/// POST /internal/jobs/:job_id/progress
+///
+/// Progress callback from the AI service. Updates the reported step, rolls step
+/// progress up into the job, and on completion stores the job output.
+pub async fn report_job_progress(
+    State(state): State<Arc<AppState>>,
+    Path(job_id): Path<Uuid>,
+    _internal: RequireInternalToken,
+    Json(input): Json<JobProgressCallback>,
+) -> Result<impl IntoResponse, ApiError> {
+    let job: Option<(Uuid, Uuid, Option<i32>, String, String)> = sqlx::query_as(
+        "SELECT project_id, document_id, document_version, job_type, status FROM processing_jobs WHERE id = $1",
+    )
+    .bind(job_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("report_job_progress"))?;
+
+    let (project_id, document_id, document_version, job_type, status) =
+        job.ok_or_else(|| ApiError::not_found("Job not found"))?;
+
+    // Tell the worker to stop if the job was cancelled or already finished
+    if matches!(status.as_str(), "completed" | "failed" | "cancelled") {
+        return Err(ApiError::conflict(format!("Job is already {}", status)));
+    }
+
+    let step_status = input
+        .status
+        .clone()
+        .or_else(|| input.error.as_ref().map(|_| StepStatus::Failed))
+        .map(|s| s.to_string());
+
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(map_db_err("report_job_progress"))?;
+
+    if let Some(step_key) = &input.step_key {
+        let result = sqlx::query(
+            r#"
+            UPDATE processing_steps SET
+                status = COALESCE($3::text, status),
+                progress = CASE WHEN $3::text = 'completed' THEN 100 ELSE COALESCE($4, progress) END,
+                message = COALESCE($5, message),
+                items_processed = COALESCE($6, items_processed),
+                items_total = COALESCE($7, items_total),
+                error_message = COALESCE($8, error_message),
+                started_at = CASE WHEN $3::text IS NOT NULL AND $3::text <> 'pending'
+                             THEN COALESCE(started_at, NOW()) ELSE started_at END,
+                completed_at = CASE WHEN $3::text IN ('completed', 'failed', 'skipped')
+                               THEN NOW() ELSE completed_at END
+            WHERE job_id = $1 AND step_key = $2
+            "#,
+        )
+        .bind(job_id)
+        .bind(step_key)
+        .bind(&step_status)
+        .bind(input.progress.map(|p| p.clamp(0.0, 100.0)))
+        .bind(&input.message)
+        .bind(input.items_processed)
+        .bind(input.items_total)
+        .bind(&input.error)
+        .execute(&mut *tx)
+        .await
+        .map_err(map_db_err("report_job_progress"))?;
+
+        if result.rows_affected() == 0 {
+            return Err(ApiError::not_found("Step not found"));
+        }
+    }
+
+    if let Some(error) = &input.error {
+        sqlx::query(
+            r#"
+            UPDATE processing_jobs SET status = 'failed', error_message = $2, error_step = $3,
+                   completed_at = NOW(), updated_at = NOW()
+            WHERE id = $1
+            "#,
+        )
+        .bind(job_id)
+        .bind(error)
+        .bind(&input.step_key)
+        .execute(&mut *tx)
+        .await
+        .map_err(map_db_err("report_job_progress"))?;
+    } else if let Some(output) = input.output {
+        if job_type == ProcessingJobType::TradeScopeExtraction.to_string() {
+            let scopes: TradeScopesOutput = serde_json::from_value(output)
+                .map_err(|e| ApiError::bad_request(format!("Invalid trade scope output: {}", e)))?;
+            store_trade_scopes(&mut tx, job_id, project_id, document_id, document_version, &scopes)
+                .await?;
+        }
+
+        sqlx::query(
+            r#"
+            UPDATE processing_steps SET status = 'completed', progress = 100,
+                   started_at = COALESCE(started_at, NOW()), completed_at = COALESCE(completed_at, NOW())
+            WHERE job_id = $1 AND status IN ('pending', 'running')
+            "#,
+        )
+        .bind(job_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(map_db_err("report_job_progress"))?;
+
+        sqlx::query(
+            r#"
+            UPDATE processing_jobs SET status = 'completed', progress = 100, completed_steps = total_steps,
+                   current_step = NULL, completed_at = NOW(), updated_at = NOW()
+            WHERE id = $1
+            "#,
+        )
+        .bind(job_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(map_db_err("report_job_progress"))?;
+    } else {
+        // Roll step progress up into the job
+        sqlx::query(
+            r#"
+            UPDATE processing_jobs j SET
+                completed_steps = s.done,
+                progress = s.progress,
+                current_step = COALESCE($2, j.current_step),
+                heartbeat_at = NOW(),
+                last_progress_at = NOW(),
+                updated_at = NOW()
+            FROM (
+                SELECT COUNT(*) FILTER (WHERE status IN ('completed', 'skipped'))::int AS done,
+                       COALESCE(AVG(CASE WHEN status IN ('completed', 'skipped') THEN 100 ELSE progress END), 0) AS progress
+                FROM processing_steps WHERE job_id = $1
+            ) s
+            WHERE j.id = $1
+            "#,
+        )
+        .bind(job_id)
+        .bind(&input.step_key)
+        .execute(&mut *tx)
+        .await
+        .map_err(map_db_err("report_job_progress"))?;
+    }
+
+    tx.commit()
+        .await
+        .map_err(map_db_err("report_job_progress"))?;
+
+    Ok(MessageResponse::new("Progress recorded"))
+}
+
+/// Replace unverified AI-extracted scopes for the document with this job's output.
+/// Scopes a user created or verified are left untouched.
+async fn store_trade_scopes(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    job_id: Uuid,
+    project_id: Uuid,
+    document_id: Uuid,
+    document_version: Option<i32>,
+    scopes: &TradeScopesOutput,
+) -> Result<(), ApiError> {
+    sqlx::query(
+        r#"
+        DELETE FROM extracted_trade_scopes
+        WHERE document_id = $1 AND job_id IS NOT NULL AND job_id <> $2 AND is_verified = false
+        "#,
+    )
+    .bind(document_id)
+    .bind(job_id)
+    .execute(&mut **tx)
+    .await
+    .map_err(map_db_err("store_trade_scopes"))?;
+
+    let confidence = scopes.confidence.clamp(0.0, 1.0);
+    let scope_items = |items: &[String]| {
+        serde_json::json!(items
+            .iter()
+            .map(|item| ScopeItem {
+                item: item.clone(),
+                details: None,
+            })
+            .collect::<Vec<_>>())
+    };
+
+    for item in &scopes.trades {
+        sqlx::query(
+            r#"
+            INSERT INTO extracted_trade_scopes (
+                id, project_id, document_id, document_version, job_id, trade, csi_division,
+                inclusions, exclusions, required_sheets, spec_sections,
+                rfi_needed, assumptions, confidence, normalized_trade, is_verified
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, false)
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(project_id)
+        .bind(document_id)
+        .bind(document_version)
+        .bind(job_id)
+        .bind(&item.trade)
+        .bind(&item.csi_division)
+        .bind(scope_items(&item.inclusions))
+        .bind(scope_items(&item.exclusions))
+        .bind(serde_json::json!(item.required_sheets))
+        .bind(serde_json::json!(item.spec_sections))
+        .bind(serde_json::json!(item.rfi_needed))
+        .bind(serde_json::json!(item.assumptions))
+        .bind(confidence)
+        .bind(normalized_trade_key(Some(&item.trade), item.csi_division.as_deref()))
+        .execute(&mut **tx)
+        .await
+        .map_err(map_db_err("store_trade_scopes"))?;
+    }
+
+    Ok(())
+}
@@ -4,6 +4,7 @@
 
 use axum::{
     extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
     response::{
         sse::{Event, Sse},
         IntoResponse,
@@ -17,15 +18,17 @@ use serde::Deserialize;
 use std::{convert::Infallible, sync::Arc, time::Duration};
 use uuid::Uuid;
 
-use crate::api::pagination::PaginationParams;
-use crate::api::response::{DataResponse, Paginated, PaginationMeta};
+use crate::api::pagination::{resolve_pagination, PaginationParams};
+use crate::api::response::{DataResponse, Paginated};
 use crate::app::AppState;
 use crate::auth::RequireAuth;
 use crate::domain::jobs::{
-    default_ingestion_steps, JobControlRequest, JobProgressEvent, ProcessingJobResponse,
-    ProcessingStepResponse, StartProcessingRequest,
+    default_ingestion_steps, trade_scope_extraction_steps, JobControlRequest, JobProgressEvent,
+    ProcessingJobResponse, ProcessingJobType, ProcessingStepResponse, StartJobQuery,
+    StartJobResponse, StartProcessingRequest, StartTradeScopeExtractionRequest,
 };
-use crate::error::ApiError;
+use crate::error::{map_db_err, ApiError};
+use crate::middleware::request_id::RequestIdExt;
 
 // ============================================================================
 // Database Row Types
@@ -37,6 +40,7 @@ struct ProcessingJobRow {
     id: Uuid,
     document_id: Uuid,
     project_id: Uuid,
+    job_type: String,
     status: String,
     current_step: Option<String>,
     progress: sqlx::types::Decimal,
@@ -122,9 +126,12 @@ pub struct JobQuery {
 /// POST /api/projects/:project_id/documents/:document_id/process
 ///
 /// Start processing a document (triggers AI ingestion pipeline).
+/// Returns the last completed job with `reused: true` if the document content is
+/// unchanged since it succeeded, unless `?force=true`.
 pub async fn start_processing(
     State(state): State<Arc<AppState>>,
     Path((project_id, document_id)): Path<(Uuid, Uuid)>,
+    Query(query): Query<StartJobQuery>,
     auth: RequireAuth,
     Json(input): Json<StartProcessingRequest>,
 ) -> Result<impl IntoResponse, ApiError> {
@@ -135,7 +142,7 @@ pub async fn start_processing(
         .bind(project_id)
         .fetch_optional(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
+        .map_err(map_db_err("start_processing"))?
         .flatten();
 
     if project_owner != Some(user_id) {
@@ -143,27 +150,17 @@ pub async fn start_processing(
     }
 
     // Verify document exists and belongs to project
-    let doc_exists: bool = sqlx::query_scalar(
-        "SELECT EXISTS(SELECT 1 FROM documents WHERE id = $1 AND project_id = $2)",
-    )
-    .bind(document_id)
-    .bind(project_id)
-    .fetch_one(&state.db)
-    .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
-
-    if !doc_exists {
-        return Err(ApiError::not_found("Document not found"));
-    }
+    let (content_hash, document_version, page_count) =
+        get_document_version(&state, project_id, document_id).await?;
 
     // Check if there's already an active job for this document
     let existing_job: Option<Uuid> = sqlx::query_scalar(
-        "SELECT id FROM processing_jobs WHERE document_id = $1 AND status IN ('queued', 'running', 'paused')",
+        "SELECT id FROM processing_jobs WHERE document_id = $1 AND job_type = 'document_ingestion' AND status IN ('queued', 'running', 'paused')",
     )
     .bind(document_id)
     .fetch_optional(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("start_processing"))?;
 
     if let Some(job_id) = existing_job {
         return Err(ApiError::conflict(format!(
@@ -172,31 +169,58 @@ pub async fn start_processing(
         )));
     }
 
-    // Create the processing job
+    // Unchanged content since the last successful run: hand back that job
+    if !query.force {
+        if let Some(job) = find_reusable_job(
+            &state,
+            document_id,
+            ProcessingJobType::DocumentIngestion,
+            content_hash.as_deref(),
+            None,
+        )
+        .await?
+        {
+            return Ok(Json(DataResponse::new(StartJobResponse { job, reused: true })));
+        }
+    }
+
+    // Create the processing job. Queued jobs are picked up by AI workers via
+    // /internal/jobs/claim; with auto_start disabled the job is held paused until resumed.
     let job_id = Uuid::new_v4();
     let steps = default_ingestion_steps();
     let total_steps = steps.len() as i32;
+    let auto_start = input.auto_start.unwrap_or(true);
+
+    // Job and steps are created together so a worker never claims a job without steps
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(map_db_err("start_processing"))?;
 
     sqlx::query(
         r#"
-        INSERT INTO processing_jobs (id, document_id, project_id, status, total_steps)
-        VALUES ($1, $2, $3, 'queued', $4)
+        INSERT INTO processing_jobs (id, document_id, project_id, status, total_steps, content_hash, document_version, paused_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, CASE WHEN $4 = 'paused' THEN NOW() END)
         "#,
     )
     .bind(job_id)
     .bind(document_id)
     .bind(project_id)
+    .bind(if auto_start { "queued" } else { "paused" })
     .bind(total_steps)
-    .execute(&state.db)
+    .bind(&content_hash)
+    .bind(document_version)
+    .execute(&mut *tx)
     .await
-    .map_err(|e| ApiError::internal(format!("Failed to create job: {}", e)))?;
+    .map_err(map_db_err("start_processing"))?;
 
     // Create processing steps
     for (step_key, step_name, step_order) in steps {
         sqlx::query(
             r#"
-            INSERT INTO processing_steps (id, job_id, step_name, step_key, step_order, status)
-            VALUES ($1, $2, $3, $4, $5, 'pending')
+            INSERT INTO processing_steps (id, job_id, step_name, step_key, step_order, status, items_total)
+            VALUES ($1, $2, $3, $4, $5, 'pending', $6)
             "#,
         )
         .bind(Uuid::new_v4())
@@ -204,28 +228,170 @@ pub async fn start_processing(
         .bind(step_name)
         .bind(step_key.to_string())
         .bind(step_order)
-        .execute(&state.db)
+        .bind(step_key.items_total(page_count))
+        .execute(&mut *tx)
         .await
-        .map_err(|e| ApiError::internal(format!("Failed to create step: {}", e)))?;
+        .map_err(map_db_err("start_processing"))?;
     }
 
-    // If auto_start is true (default), trigger the processing via the AI service
-    if input.auto_start.unwrap_or(true) {
-        // Update job status to running
-        sqlx::query("UPDATE processing_jobs SET status = 'running', started_at = NOW() WHERE id = $1")
-            .bind(job_id)
-            .execute(&state.db)
-            .await
-            .map_err(|e| ApiError::internal(format!("Failed to update job: {}", e)))?;
+    tx.commit()
+        .await
+        .map_err(map_db_err("start_processing"))?;
+
+    // Fetch and return the created job
+    let job = get_job_with_steps(&state, job_id).await?;
+    Ok(Json(DataResponse::new(StartJobResponse { job, reused: false })))
+}
 
-        // TODO: Trigger the AI service to start processing
-        // This would be an async call to the Python AI service
-        // For now, we just mark the job as running and the AI service will poll for jobs
+/// POST /api/projects/:project_id/documents/:document_id/trade-scopes/extract
+///
+/// Start AI trade scope extraction as a processing job. Returns the job immediately;
+/// progress streams over the jobs SSE endpoint as the AI service reports back, and
+/// the final scopes are stored in `extracted_trade_scopes`. Like document processing,
+/// an identical completed run is reused unless `?force=true`.
+pub async fn start_trade_scope_extraction(
+    State(state): State<Arc<AppState>>,
+    Path((project_id, document_id)): Path<(Uuid, Uuid)>,
+    Query(query): Query<StartJobQuery>,
+    headers: HeaderMap,
+    auth: RequireAuth,
+    Json(input): Json<StartTradeScopeExtractionRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let user_id = auth.user_id;
+    let request_id = headers.request_id().map(str::to_string);
+
+    // Verify project ownership
+    let project_owner: Option<Uuid> = sqlx::query_scalar("SELECT owner_id FROM projects WHERE id = $1")
+        .bind(project_id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(map_db_err("start_trade_scope_extraction"))?
+        .flatten();
+
+    if project_owner != Some(user_id) {
+        return Err(ApiError::forbidden("You don't have access to this project"));
+    }
+
+    let (content_hash, document_version, page_count) =
+        get_document_version(&state, project_id, document_id).await?;
+    // Pasted text has no pages to count
+    let page_count = page_count.filter(|_| input.document_text.is_none());
+
+    let existing_job: Option<Uuid> = sqlx::query_scalar(
+        "SELECT id FROM processing_jobs WHERE document_id = $1 AND job_type = $2 AND status IN ('queued', 'running', 'paused')",
+    )
+    .bind(document_id)
+    .bind(ProcessingJobType::TradeScopeExtraction.to_string())
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("start_trade_scope_extraction"))?;
+
+    if let Some(job_id) = existing_job {
+        return Err(ApiError::conflict(format!(
+            "Document already has an active trade scope extraction job: {}",
+            job_id
+        )));
+    }
+
+    let job_input = serde_json::json!({ "trades": input.trades });
+
+    // Raw text overrides the stored file, so only file-backed runs are reusable
+    if !query.force && input.document_text.is_none() {
+        if let Some(job) = find_reusable_job(
+            &state,
+            document_id,
+            ProcessingJobType::TradeScopeExtraction,
+            content_hash.as_deref(),
+            Some(&job_input),
+        )
+        .await?
+        {
+            return Ok((StatusCode::OK, Json(DataResponse::new(StartJobResponse { job, reused: true }))));
+        }
+    }
+
+    let job_id = Uuid::new_v4();
+    let steps = trade_scope_extraction_steps();
+
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(map_db_err("start_trade_scope_extraction"))?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO processing_jobs (id, document_id, project_id, job_type, input, status, total_steps, content_hash, document_version, started_at)
+        VALUES ($1, $2, $3, $4, $5, 'running', $6, $7, $8, NOW())
+        "#,
+    )
+    .bind(job_id)
+    .bind(document_id)
+    .bind(project_id)
+    .bind(ProcessingJobType::TradeScopeExtraction.to_string())
+    .bind(&job_input)
+    .bind(steps.len() as i32)
+    .bind(if input.document_text.is_none() { content_hash.as_deref() } else { None })
+    .bind(document_version)
+    .execute(&mut *tx)
+    .await
+    .map_err(map_db_err("start_trade_scope_extraction"))?;
+
+    for (step_key, step_name, step_order) in steps {
+        sqlx::query(
+            r#"
+            INSERT INTO processing_steps (id, job_id, step_name, step_key, step_order, status, items_total)
+            VALUES ($1, $2, $3, $4, $5, 'pending', $6)
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(job_id)
+        .bind(step_name)
+        .bind(step_key.to_string())
+        .bind(step_order)
+        .bind(step_key.items_total(page_count))
+        .execute(&mut *tx)
+        .await
+        .map_err(map_db_err("start_trade_scope_extraction"))?;
+    }
+
+    tx.commit()
+        .await
+        .map_err(map_db_err("start_trade_scope_extraction"))?;
+
+    // Hand the job to the AI service; it reports progress via the internal callback route
+    let callback_url = format!(
+        "{}/internal/jobs/{}/progress",
+        state.settings.internal_api_url.trim_end_matches('/'),
+        job_id
+    );
+
+    if let Err(e) = state
+        .ai_client
+        .start_trade_scope_job(
+            job_id,
+            project_id,
+            document_id,
+            input.document_text.as_deref(),
+            input.trades.as_deref(),
+            &callback_url,
+            request_id.as_deref(),
+        )
+        .await
+    {
+        sqlx::query(
+            "UPDATE processing_jobs SET status = 'failed', error_message = $2, completed_at = NOW(), updated_at = NOW() WHERE id = $1",
+        )
+        .bind(job_id)
+        .bind("Failed to start AI trade scope extraction")
+        .execute(&state.db)
+        .await
+        .ok();
+        return Err(e);
     }
 
-    // Fetch and return the created job
     let job = get_job_with_steps(&state, job_id).await?;
-    Ok(Json(DataResponse::new(job)))
+    Ok((StatusCode::ACCEPTED, Json(DataResponse::new(StartJobResponse { job, reused: false }))))
 }
 
 /// GET /api/projects/:project_id/jobs
@@ -244,16 +410,14 @@ pub async fn list_project_jobs(
         .bind(project_id)
         .fetch_optional(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
+        .map_err(map_db_err("list_project_jobs"))?
         .flatten();
 
     if project_owner != Some(user_id) {
         return Err(ApiError::forbidden("You don't have access to this project"));
     }
 
-    let page = query.pagination.page.unwrap_or(1).max(1);
-    let per_page = query.pagination.per_page.unwrap_or(20).min(100);
-    let offset = ((page - 1) * per_page) as i64;
+    let page = resolve_pagination(&query.pagination, state.settings.pagination.default)?;
 
     let total: i64 = sqlx::query_scalar(
         r#"
@@ -268,11 +432,11 @@ pub async fn list_project_jobs(
     .bind(query.document_id)
     .fetch_one(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("list_project_jobs"))?;
 
     let jobs = sqlx::query_as::<_, ProcessingJobRow>(
         r#"
-        SELECT id, document_id, project_id, status, current_step, progress,
+        SELECT id, document_id, project_id, job_type, status, current_step, progress,
                total_steps, completed_steps, error_message, error_step,
                can_retry, retry_count, max_retries, paused_at, started_at,
                completed_at, created_at, updated_at
@@ -280,18 +444,18 @@ pub async fn list_project_jobs(
         WHERE project_id = $1
         AND ($2::text IS NULL OR status = $2)
         AND ($3::uuid IS NULL OR document_id = $3)
-        ORDER BY created_at DESC
+        ORDER BY created_at DESC, id
         LIMIT $4 OFFSET $5
         "#,
     )
     .bind(project_id)
     .bind(&query.status)
     .bind(query.document_id)
-    .bind(per_page as i64)
-    .bind(offset)
+    .bind(page.limit())
+    .bind(page.offset())
     .fetch_all(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("list_project_jobs"))?;
 
     let mut responses = Vec::with_capacity(jobs.len());
     for job in jobs {
@@ -300,6 +464,7 @@ pub async fn list_project_jobs(
             id: job.id,
             document_id: job.document_id,
             project_id: job.project_id,
+            job_type: job.job_type,
             status: job.status,
             current_step: job.current_step,
             progress: decimal_to_f64(job.progress),
@@ -317,19 +482,8 @@ pub async fn list_project_jobs(
         });
     }
 
-    let total_pages = ((total as f64) / (per_page as f64)).ceil() as u32;
-
-    Ok(Json(Paginated {
-        data: responses,
-        pagination: PaginationMeta {
-            page,
-            per_page,
-            total_items: total as u64,
-            total_pages,
-            has_next: page < total_pages,
-            has_prev: page > 1,
-        },
-    }))
+
+    Ok(Json(Paginated::new(responses, &page, total as u64)))
 }
 
 /// GET /api/projects/:project_id/jobs/:job_id
@@ -347,7 +501,7 @@ pub async fn get_job(
         .bind(project_id)
         .fetch_optional(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
+        .map_err(map_db_err("get_job"))?
         .flatten();
 
     if project_owner != Some(user_id) {
@@ -379,7 +533,7 @@ pub async fn control_job(
         .bind(project_id)
         .fetch_optional(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
+        .map_err(map_db_err("control_job"))?
         .flatten();
 
     if project_owner != Some(user_id) {
@@ -389,7 +543,7 @@ pub async fn control_job(
     // Get current job status
     let job = sqlx::query_as::<_, ProcessingJobRow>(
         r#"
-        SELECT id, document_id, project_id, status, current_step, progress,
+        SELECT id, document_id, project_id, job_type, status, current_step, progress,
                total_steps, completed_steps, error_message, error_step,
                can_retry, retry_count, max_retries, paused_at, started_at,
                completed_at, created_at, updated_at
@@ -400,7 +554,7 @@ pub async fn control_job(
     .bind(project_id)
     .fetch_optional(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
+    .map_err(map_db_err("control_job"))?
     .ok_or_else(|| ApiError::not_found("Job not found"))?;
 
     use crate::domain::jobs::JobControlAction;
@@ -413,17 +567,18 @@ pub async fn control_job(
                 .bind(job_id)
                 .execute(&state.db)
                 .await
-                .map_err(|e| ApiError::internal(format!("Failed to pause job: {}", e)))?;
+                .map_err(map_db_err("control_job"))?;
         }
         JobControlAction::Resume => {
             if job.status != "paused" {
                 return Err(ApiError::bad_request("Can only resume paused jobs"));
             }
-            sqlx::query("UPDATE processing_jobs SET status = 'running', paused_at = NULL, updated_at = NOW() WHERE id = $1")
+            // Jobs no worker has claimed yet go back in the queue
+            sqlx::query("UPDATE processing_jobs SET status = CASE WHEN claimed_at IS NULL THEN 'queued' ELSE 'running' END, paused_at = NULL, last_progress_at = NOW(), updated_at = NOW() WHERE id = $1")
                 .bind(job_id)
                 .execute(&state.db)
                 .await
-                .map_err(|e| ApiError::internal(format!("Failed to resume job: {}", e)))?;
+                .map_err(map_db_err("control_job"))?;
         }
         JobControlAction::Cancel => {
             if job.status == "completed" || job.status == "cancelled" {
@@ -433,7 +588,7 @@ pub async fn control_job(
                 .bind(job_id)
                 .execute(&state.db)
                 .await
-                .map_err(|e| ApiError::internal(format!("Failed to cancel job: {}", e)))?;
+                .map_err(map_db_err("control_job"))?;
         }
         JobControlAction::RetryStep { step_key } => {
             if job.status != "failed" && job.status != "paused" {
@@ -447,16 +602,16 @@ pub async fn control_job(
             .bind(&step_key)
             .execute(&state.db)
             .await
-            .map_err(|e| ApiError::internal(format!("Failed to reset step: {}", e)))?;
+            .map_err(map_db_err("control_job"))?;
 
             // Update job status
             sqlx::query(
-                "UPDATE processing_jobs SET status = 'running', error_message = NULL, error_step = NULL, retry_count = retry_count + 1, updated_at = NOW() WHERE id = $1"
+                "UPDATE processing_jobs SET status = 'running', error_message = NULL, error_step = NULL, retry_count = retry_count + 1, last_progress_at = NOW(), updated_at = NOW() WHERE id = $1"
             )
             .bind(job_id)
             .execute(&state.db)
             .await
-            .map_err(|e| ApiError::internal(format!("Failed to update job: {}", e)))?;
+            .map_err(map_db_err("control_job"))?;
         }
         JobControlAction::RetryJob => {
             if job.status != "failed" {
@@ -472,16 +627,16 @@ pub async fn control_job(
             .bind(job_id)
             .execute(&state.db)
             .await
-            .map_err(|e| ApiError::internal(format!("Failed to reset steps: {}", e)))?;
+            .map_err(map_db_err("control_job"))?;
 
             // Update job status
             sqlx::query(
-                "UPDATE processing_jobs SET status = 'running', error_message = NULL, error_step = NULL, retry_count = retry_count + 1, updated_at = NOW() WHERE id = $1"
+                "UPDATE processing_jobs SET status = 'running', error_message = NULL, error_step = NULL, retry_count = retry_count + 1, last_progress_at = NOW(), updated_at = NOW() WHERE id = $1"
             )
             .bind(job_id)
             .execute(&state.db)
             .await
-            .map_err(|e| ApiError::internal(format!("Failed to update job: {}", e)))?;
+            .map_err(map_db_err("control_job"))?;
         }
     }
 
@@ -505,7 +660,7 @@ pub async fn stream_job_progress(
         .bind(project_id)
         .fetch_optional(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
+        .map_err(map_db_err("stream_job_progress"))?
         .flatten();
 
     if project_owner != Some(user_id) {
@@ -523,16 +678,17 @@ pub async fn stream_job_progress(
 
             let now = Utc::now();
 
-            // Fetch active jobs with recent updates
+            // Fetch active jobs, plus jobs that just finished so clients see the final state
             let jobs: Vec<ProcessingJobRow> = sqlx::query_as(
                 r#"
-                SELECT id, document_id, project_id, status, current_step, progress,
+                SELECT id, document_id, project_id, job_type, status, current_step, progress,
                        total_steps, completed_steps, error_message, error_step,
                        can_retry, retry_count, max_retries, paused_at, started_at,
                        completed_at, created_at, updated_at
                 FROM processing_jobs
                 WHERE project_id = $1
-                AND status IN ('queued', 'running', 'paused')
+                AND (status IN ('queued', 'running', 'paused')
+                     OR updated_at > NOW() - INTERVAL '5 seconds')
                 ORDER BY updated_at DESC
                 "#,
             )
@@ -581,7 +737,61 @@ pub async fn stream_job_progress(
 // Helper Functions
 // ============================================================================
 
-async fn get_job_steps(
+/// Look up a document's content hash, version and page count, verifying it belongs to the project
+async fn get_document_version(
+    state: &AppState,
+    project_id: Uuid,
+    document_id: Uuid,
+) -> Result<(Option<String>, Option<i32>, Option<i32>), ApiError> {
+    sqlx::query_as(
+        "SELECT content_hash, version, page_count FROM documents WHERE id = $1 AND project_id = $2",
+    )
+    .bind(document_id)
+    .bind(project_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("get_document_version"))?
+    .ok_or_else(|| ApiError::not_found("Document not found"))
+}
+
+/// Find the latest completed job of this type that ran against identical content
+/// (and, when given, identical input). Documents without a hash never match.
+async fn find_reusable_job(
+    state: &AppState,
+    document_id: Uuid,
+    job_type: ProcessingJobType,
+    content_hash: Option<&str>,
+    input: Option<&serde_json::Value>,
+) -> Result<Option<ProcessingJobResponse>, ApiError> {
+    let Some(content_hash) = content_hash else {
+        return Ok(None);
+    };
+
+    let job_id: Option<Uuid> = sqlx::query_scalar(
+        r#"
+        SELECT id FROM processing_jobs
+        WHERE document_id = $1 AND job_type = $2 AND content_hash = $3
+        AND status = 'completed'
+        AND ($4::jsonb IS NULL OR input = $4)
+        ORDER BY completed_at DESC NULLS LAST
+        LIMIT 1
+        "#,
+    )
+    .bind(document_id)
+    .bind(job_type.to_string())
+    .bind(content_hash)
+    .bind(input)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("find_reusable_job"))?;
+
+    match job_id {
+        Some(job_id) => Ok(Some(get_job_with_steps(state, job_id).await?)),
+        None => Ok(None),
+    }
+}
+
+pub(crate) async fn get_job_steps(
     state: &AppState,
     job_id: Uuid,
 ) -> Result<Vec<ProcessingStepResponse>, ApiError> {
@@ -598,7 +808,7 @@ async fn get_job_steps(
     .bind(job_id)
     .fetch_all(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("get_job_steps"))?;
 
     Ok(steps.into_iter().map(Into::into).collect())
 }
@@ -609,7 +819,7 @@ async fn get_job_with_steps(
 ) -> Result<ProcessingJobResponse, ApiError> {
     let job = sqlx::query_as::<_, ProcessingJobRow>(
         r#"
-        SELECT id, document_id, project_id, status, current_step, progress,
+        SELECT id, document_id, project_id, job_type, status, current_step, progress,
                total_steps, completed_steps, error_message, error_step,
                can_retry, retry_count, max_retries, paused_at, started_at,
                completed_at, created_at, updated_at
@@ -619,7 +829,7 @@ async fn get_job_with_steps(
     .bind(job_id)
     .fetch_optional(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
+    .map_err(map_db_err("get_job_with_steps"))?
     .ok_or_else(|| ApiError::not_found("Job not found"))?;
 
     let steps = get_job_steps(state, job_id).await?;
@@ -628,6 +838,7 @@ async fn get_job_with_steps(
         id: job.id,
         document_id: job.document_id,
         project_id: job.project_id,
+        job_type: job.job_type,
         status: job.status,
         current_step: job.current_step,
         progress: decimal_to_f64(job.progress),
@@ -9,22 +9,41 @@
 //! - Bid management
 
 use axum::{
-    extract::{Path, Query, State},
+    extract::{Multipart, Path, Query, RawQuery, State},
     response::IntoResponse,
     Json,
 };
 use chrono::{DateTime, NaiveDate, Utc};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::sync::Arc;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
 use uuid::Uuid;
 
-use crate::api::pagination::PaginationParams;
-use crate::api::response::{DataResponse, Paginated, PaginationMeta};
+use crate::api::fields::{FieldSet, FieldsQuery};
+use crate::api::pagination::{resolve_pagination, Counted, PaginationParams, TOTAL_COUNT_COLUMN};
+use crate::api::sort::{SortFields, SortOrder, SortSpec};
+use crate::api::response::{Created, DataResponse, MessageResponse, NoContent, Paginated};
 use crate::app::AppState;
-use crate::auth::RequireAuth;
+use crate::config::CompletenessWeights;
+use crate::auth::{RequireAuth, RequireGc, RequireSub};
+use crate::domain::activity::ActivityAction;
+use crate::domain::admin::AuditTargetType;
 use crate::domain::marketplace::*;
-use crate::error::ApiError;
+use crate::domain::money::Money;
+use crate::domain::rating::Rating;
+use crate::error::{map_db_err, ApiError};
+use crate::middleware::ExtractClientIp;
+use crate::services::audit::{self, ActivityEntry};
+use crate::services::file_deletions;
+use crate::services::cache::{keys as cache_keys, ttl as cache_ttl, CacheNamespace};
+use crate::services::geocoding::{self, GeocodedTable};
+use crate::services::images;
+use crate::services::storage::{is_external_url, ObjectStorage};
 use crate::services::notifications;
+use crate::services::watchers::{self, WatchedResource};
+use crate::services::system_settings;
 
 // ============================================================================
 // Database Row Types
@@ -39,14 +58,16 @@ struct MarketplaceSubRow {
     secondary_trades: serde_json::Value,
     headline: Option<String>,
     company_description: Option<String>,
-    rating: f64,
+    rating: Decimal,
     review_count: i32,
     location: Option<String>,
     contact_email: Option<String>,
     contact_phone: Option<String>,
     website: Option<String>,
+    logo_key: Option<String>,
+    logo_thumb_key: Option<String>,
     projects_completed: i32,
-    average_bid_value: Option<i64>,
+    average_bid_value: Option<Money>,
     response_time: Option<String>,
     response_time_hours: Option<i32>,
     verified: bool,
@@ -55,15 +76,257 @@ struct MarketplaceSubRow {
     service_areas: serde_json::Value,
     certifications: serde_json::Value,
     insurance: serde_json::Value,
+    insurance_expiry: Option<NaiveDate>,
     license_info: serde_json::Value,
     year_established: Option<i32>,
     employee_count: Option<String>,
-    min_project_value: Option<i64>,
-    max_project_value: Option<i64>,
+    min_project_value: Option<Money>,
+    max_project_value: Option<Money>,
     availability_status: String,
     recent_projects: serde_json::Value,
     portfolio_count: i64,
+    avg_quality: Option<Decimal>,
+    avg_communication: Option<Decimal>,
+    avg_timeliness: Option<Decimal>,
+    avg_value: Option<Decimal>,
+    would_hire_again_pct: Option<Decimal>,
     created_at: DateTime<Utc>,
+    #[sqlx(default)]
+    distance_km: Option<f64>,
+}
+
+/// Directory card columns for list views
+#[derive(Debug, sqlx::FromRow)]
+struct SubcontractorListRow {
+    id: Uuid,
+    name: String,
+    trade: String,
+    secondary_trades: serde_json::Value,
+    headline: Option<String>,
+    location: Option<String>,
+    rating: Decimal,
+    review_count: i32,
+    logo_thumb_key: Option<String>,
+    projects_completed: i32,
+    response_time_hours: Option<i32>,
+    verified: bool,
+    availability_status: String,
+    insurance_expiry: Option<NaiveDate>,
+    min_project_value: Option<Money>,
+    max_project_value: Option<Money>,
+    created_at: DateTime<Utc>,
+    #[sqlx(default)]
+    distance_km: Option<f64>,
+}
+
+/// Cached directory page
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedSubcontractorList {
+    data: Vec<SubcontractorListItem>,
+    total: u64,
+}
+
+/// Columns selected into `SubcontractorListRow` (from `subcontractors s`)
+const SUBCONTRACTOR_LIST_COLUMNS: &str = r#"
+            s.id, s.name, s.trade,
+            COALESCE(to_jsonb(s.secondary_trades), '[]'::jsonb) as secondary_trades,
+            s.headline, s.location, s.rating, s.review_count, s.logo_thumb_key,
+            s.projects_completed, s.response_time_hours, s.verified,
+            COALESCE(s.availability_status, 'available') as availability_status,
+            s.insurance_expiry, s.min_project_value, s.max_project_value,
+            s.created_at"#;
+
+impl SubcontractorListRow {
+    fn into_list_item(self, storage: &ObjectStorage) -> SubcontractorListItem {
+        let today = Utc::now().date_naive();
+
+        SubcontractorListItem {
+            id: self.id,
+            name: self.name,
+            trade: self.trade,
+            secondary_trades: serde_json::from_value(self.secondary_trades).unwrap_or_default(),
+            headline: self.headline,
+            location: self.location,
+            rating: Rating::from(self.rating),
+            review_count: self.review_count,
+            logo_thumbnail_url: self.logo_thumb_key.as_deref().map(|k| storage.url(k)),
+            projects_completed: self.projects_completed,
+            response_time_hours: self.response_time_hours,
+            verified: self.verified,
+            availability_status: self.availability_status,
+            insurance_expired: self.insurance_expiry.is_some_and(|d| d < today),
+            min_project_value: self.min_project_value,
+            max_project_value: self.max_project_value,
+            distance_km: self.distance_km,
+            created_at: self.created_at,
+        }
+    }
+}
+
+/// Columns selected into `MarketplaceSubRow` (from `subcontractors s`)
+const MARKETPLACE_SUB_COLUMNS: &str = r#"
+            s.id, s.profile_id, s.name, s.trade,
+            COALESCE(to_jsonb(s.secondary_trades), '[]'::jsonb) as secondary_trades,
+            s.headline, s.company_description, s.rating, s.review_count,
+            s.location, s.contact_email, s.contact_phone, s.website,
+            s.logo_key, s.logo_thumb_key,
+            s.projects_completed, s.average_bid_value, s.response_time, s.response_time_hours,
+            s.verified, COALESCE(s.verification_status, 'pending') as verification_status,
+            COALESCE(to_jsonb(s.specialties), '[]'::jsonb) as specialties,
+            COALESCE(s.service_areas, '[]'::jsonb) as service_areas,
+            COALESCE(s.certifications, '[]'::jsonb) as certifications,
+            COALESCE(s.insurance, '{}'::jsonb) as insurance, s.insurance_expiry,
+            COALESCE(s.license_info, '{}'::jsonb) as license_info,
+            s.year_established, s.employee_count,
+            s.min_project_value, s.max_project_value,
+            COALESCE(s.availability_status, 'available') as availability_status,
+            COALESCE(s.recent_projects, '[]'::jsonb) as recent_projects,
+            (SELECT COUNT(*) FROM portfolio_projects pp WHERE pp.subcontractor_id = s.id) as portfolio_count,
+            rv.avg_quality, rv.avg_communication, rv.avg_timeliness, rv.avg_value,
+            rv.would_hire_again_pct,
+            s.created_at"#;
+
+/// Per-criterion review aggregates joined as `rv` (follows `FROM subcontractors s`).
+///
+/// Averages are NULL when no review rated that criterion, so "unrated" stays
+/// distinguishable from a low score.
+const MARKETPLACE_SUB_REVIEWS_JOIN: &str = r#"
+        LEFT JOIN LATERAL (
+            SELECT
+                AVG(r.quality_rating) as avg_quality,
+                AVG(r.communication_rating) as avg_communication,
+                AVG(r.timeliness_rating) as avg_timeliness,
+                AVG(r.value_rating) as avg_value,
+                ROUND(100.0 * COUNT(*) FILTER (WHERE r.would_hire_again)
+                      / NULLIF(COUNT(r.would_hire_again), 0), 1) as would_hire_again_pct
+            FROM subcontractor_reviews r
+            WHERE r.subcontractor_id = s.id
+        ) rv ON true"#;
+
+impl MarketplaceSubRow {
+    fn into_profile(self, storage: &ObjectStorage) -> SubcontractorProfile {
+        let today = Utc::now().date_naive();
+        let insurance_expired = self.insurance_expiry.is_some_and(|d| d < today);
+        let insurance_expiring_soon = !insurance_expired
+            && self
+                .insurance_expiry
+                .is_some_and(|d| (d - today).num_days() <= InsuranceInfo::EXPIRY_WARNING_DAYS);
+
+        SubcontractorProfile {
+            id: self.id,
+            profile_id: self.profile_id,
+            name: self.name,
+            trade: self.trade,
+            secondary_trades: serde_json::from_value(self.secondary_trades).unwrap_or_default(),
+            headline: self.headline,
+            company_description: self.company_description,
+            rating: Rating::from(self.rating),
+            review_count: self.review_count,
+            location: self.location,
+            contact_email: self.contact_email,
+            contact_phone: self.contact_phone,
+            website: self.website,
+            logo_url: self.logo_key.as_deref().map(|k| storage.url(k)),
+            logo_thumbnail_url: self.logo_thumb_key.as_deref().map(|k| storage.url(k)),
+            projects_completed: self.projects_completed,
+            average_bid_value: self.average_bid_value,
+            response_time: self.response_time,
+            response_time_hours: self.response_time_hours,
+            verified: self.verified,
+            verification_status: self.verification_status,
+            specialties: serde_json::from_value(self.specialties).unwrap_or_default(),
+            service_areas: serde_json::from_value(self.service_areas).unwrap_or_default(),
+            certifications: serde_json::from_value(self.certifications).unwrap_or_default(),
+            insurance: serde_json::from_value(self.insurance).ok(),
+            insurance_expired,
+            insurance_expiring_soon,
+            license_info: serde_json::from_value(self.license_info).ok(),
+            year_established: self.year_established,
+            employee_count: self.employee_count,
+            min_project_value: self.min_project_value,
+            max_project_value: self.max_project_value,
+            availability_status: self.availability_status,
+            recent_projects: serde_json::from_value(self.recent_projects).unwrap_or_default(),
+            portfolio_count: self.portfolio_count as i32,
+            avg_quality: self.avg_quality.map(Rating::from),
+            avg_communication: self.avg_communication.map(Rating::from),
+            avg_timeliness: self.avg_timeliness.map(Rating::from),
+            avg_value: self.avg_value.map(Rating::from),
+            would_hire_again_pct: self.would_hire_again_pct.and_then(|p| p.to_f64()),
+            distance_km: self.distance_km,
+            completeness: None,
+            created_at: self.created_at,
+        }
+    }
+}
+
+/// Required verification fields (see `domain::admin::VERIFICATION_FIELDS`)
+/// that the profile leaves empty
+fn missing_verification_fields(profile: &SubcontractorProfile, required: &[String]) -> Vec<String> {
+    let filled = |value: &Option<String>| value.as_deref().is_some_and(|v| !v.trim().is_empty());
+
+    required
+        .iter()
+        .filter(|field| {
+            let present = match field.as_str() {
+                "insurance" => has_insurance(profile),
+                "license_info" => has_license(profile),
+                "contact_email" => filled(&profile.contact_email),
+                "contact_phone" => filled(&profile.contact_phone),
+                "headline" => filled(&profile.headline),
+                "company_description" => filled(&profile.company_description),
+                "certifications" => !profile.certifications.is_empty(),
+                "service_areas" => !profile.service_areas.is_empty(),
+                _ => true,
+            };
+            !present
+        })
+        .cloned()
+        .collect()
+}
+
+fn has_insurance(profile: &SubcontractorProfile) -> bool {
+    profile.insurance.as_ref().is_some_and(|i| {
+        i.general_liability.is_some()
+            || i.workers_comp.is_some()
+            || i.auto_liability.is_some()
+            || i.carrier.as_deref().is_some_and(|c| !c.trim().is_empty())
+    })
+}
+
+fn has_license(profile: &SubcontractorProfile) -> bool {
+    profile
+        .license_info
+        .as_ref()
+        .is_some_and(|l| l.number.as_deref().is_some_and(|n| !n.trim().is_empty()))
+}
+
+/// Score how complete a profile is from its already-loaded fields
+fn profile_completeness(profile: &SubcontractorProfile, weights: &CompletenessWeights) -> ProfileCompleteness {
+    let filled = |value: &Option<String>| value.as_deref().is_some_and(|v| !v.trim().is_empty());
+
+    let fields = [
+        ("headline", weights.headline, filled(&profile.headline)),
+        ("description", weights.description, filled(&profile.company_description)),
+        ("insurance", weights.insurance, has_insurance(profile)),
+        ("license", weights.license, has_license(profile)),
+        ("certifications", weights.certifications, !profile.certifications.is_empty()),
+        ("portfolio", weights.portfolio, profile.portfolio_count > 0),
+        ("service_areas", weights.service_areas, !profile.service_areas.is_empty()),
+    ];
+
+    let total: u32 = fields.iter().map(|(_, weight, _)| weight).sum();
+    let earned: u32 = fields.iter().filter(|(_, _, done)| *done).map(|(_, weight, _)| weight).sum();
+    let percent = (earned * 100 + total / 2).checked_div(total).unwrap_or(100);
+
+    ProfileCompleteness {
+        percent: percent.min(100) as u8,
+        missing: fields
+            .iter()
+            .filter(|(_, weight, done)| !done && *weight > 0)
+            .map(|(name, _, _)| name.to_string())
+            .collect(),
+    }
 }
 
 #[derive(Debug, sqlx::FromRow)]
@@ -80,15 +343,17 @@ struct TenderRow {
     status: String,
     visibility: String,
     bid_due_date: Option<DateTime<Utc>>,
-    estimated_value: Option<i64>,
-    reserve_price: Option<i64>,
+    estimated_value: Option<Money>,
+    reserve_price: Option<Money>,
     requirements: serde_json::Value,
     bids_received: i64,
     priority: Option<String>,
     created_at: DateTime<Utc>,
+    #[sqlx(default)]
+    distance_km: Option<f64>,
     // User's bid info (from LEFT JOIN) - for N+1 optimization
     my_bid_id: Option<Uuid>,
-    my_bid_amount: Option<sqlx::types::Decimal>,
+    my_bid_amount: Option<Money>,
     my_bid_status: Option<String>,
     my_bid_submitted_at: Option<DateTime<Utc>>,
 }
@@ -101,7 +366,7 @@ struct BidRow {
     project_name: Option<String>,
     subcontractor_id: Option<Uuid>,
     company_name: String,
-    bid_amount: sqlx::types::Decimal,
+    bid_amount: Money,
     breakdown: serde_json::Value,
     proposed_timeline_days: Option<i32>,
     proposed_start_date: Option<NaiveDate>,
@@ -113,6 +378,27 @@ struct BidRow {
     created_at: DateTime<Utc>,
 }
 
+#[derive(Debug, sqlx::FromRow)]
+struct RecommendationRow {
+    #[sqlx(flatten)]
+    sub: MarketplaceSubRow,
+    trade_match: bool,
+    related_trade_match: bool,
+    location_match: bool,
+    value_fit: bool,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct OwnedTenderRow {
+    id: Uuid,
+    name: String,
+    trade_category: String,
+    location: Option<String>,
+    estimated_value: Option<Money>,
+    status: String,
+    gc_company_name: Option<String>,
+}
+
 // ============================================================================
 // Query Types
 // ============================================================================
@@ -137,10 +423,64 @@ pub struct MarketplaceTenderQueryParams {
 // Helper Functions
 // ============================================================================
 
-fn decimal_to_i64(d: sqlx::types::Decimal) -> i64 {
-    use std::str::FromStr;
-    // Decimal in cents
-    i64::from_str(&d.to_string().replace(".", "")).unwrap_or(0)
+/// `sort_by` keys for subcontractor search (default: rating, descending)
+const SUBCONTRACTOR_SORT_FIELDS: SortFields = &[
+    ("rating", "s.rating"),
+    ("reviews", "s.review_count"),
+    ("response_time", "s.response_time_hours"),
+    ("newest", "s.created_at"),
+];
+
+/// `sort_by` keys for marketplace tenders (default: due date, ascending)
+const TENDER_SORT_FIELDS: SortFields = &[
+    ("due_date", "t.bid_due_date"),
+    ("value", "t.estimated_value"),
+    ("newest", "t.created_at"),
+];
+
+/// Effective tender location: the tender's own, else its project's city/state
+const TENDER_LOCATION_SQL: &str =
+    "COALESCE(t.location, NULLIF(concat_ws(', ', p.city, p.state), ''))";
+
+/// SQL distance in km from `alias.latitude/longitude` to the `near` point bound
+/// at `$lat`/`$lng`; NULL when either side lacks coordinates.
+fn geo_distance_sql(alias: &str, lat: u8, lng: u8) -> String {
+    format!(
+        "CASE WHEN ${lat}::float8 IS NOT NULL AND {a}.latitude IS NOT NULL AND {a}.longitude IS NOT NULL \
+         THEN earth_distance(ll_to_earth({a}.latitude, {a}.longitude), ll_to_earth(${lat}, ${lng})) / 1000.0 END",
+        a = alias,
+        lat = lat,
+        lng = lng,
+    )
+}
+
+/// SQL predicate for location filtering.
+///
+/// Rows with coordinates match when within `$radius` meters of the `near`
+/// point; rows without them (or when `near` is absent) fall back to a text
+/// match of `location_sql` against `$text`.
+fn geo_location_filter(alias: &str, location_sql: &str, text: u8, lat: u8, lng: u8, radius: u8) -> String {
+    format!(
+        r#"(CASE
+            WHEN ${lat}::float8 IS NOT NULL AND {a}.latitude IS NOT NULL AND {a}.longitude IS NOT NULL THEN
+                earth_box(ll_to_earth(${lat}, ${lng}), ${radius}) @> ll_to_earth({a}.latitude, {a}.longitude)
+                AND earth_distance(ll_to_earth({a}.latitude, {a}.longitude), ll_to_earth(${lat}, ${lng})) <= ${radius}
+            WHEN ${text}::text IS NOT NULL THEN {loc} ILIKE '%' || ${text} || '%'
+            ELSE ${lat}::float8 IS NULL
+        END)"#,
+        a = alias,
+        loc = location_sql,
+        text = text,
+        lat = lat,
+        lng = lng,
+        radius = radius,
+    )
+}
+
+/// Parse the `near`/`radius_km` query, rejecting malformed coordinates
+fn parse_geo_query(geo: &GeoQuery) -> Result<(Option<f64>, Option<f64>, f64), ApiError> {
+    let point = geo.point().map_err(ApiError::bad_request)?;
+    Ok((point.map(|p| p.0), point.map(|p| p.1), geo.radius_meters()))
 }
 
 // ============================================================================
@@ -150,99 +490,75 @@ fn decimal_to_i64(d: sqlx::types::Decimal) -> i64 {
 /// GET /api/marketplace/subcontractors
 ///
 /// Enhanced subcontractor search with advanced filtering.
+#[utoipa::path(
+    get,
+    path = "/marketplace/subcontractors",
+    tag = "marketplace",
+    params(PaginationParams, MarketplaceSubcontractorQuery, GeoQuery, FieldsQuery),
+    responses(
+        (status = 200, description = "Matching subcontractors", body = SubcontractorListPage),
+        (status = 400, description = "Invalid filter, location or sort", body = ErrorResponse),
+    )
+)]
 pub async fn list_marketplace_subcontractors(
     State(state): State<Arc<AppState>>,
     Query(query): Query<MarketplaceSubQueryParams>,
+    Query(geo): Query<GeoQuery>,
+    Query(fields): Query<FieldsQuery>,
+    RawQuery(raw_query): RawQuery,
     _auth: RequireAuth,
 ) -> Result<impl IntoResponse, ApiError> {
-    let page = query.pagination.page.unwrap_or(1).max(1);
-    let per_page = query.pagination.per_page.unwrap_or(20).min(100);
-    let offset = ((page - 1) * per_page) as i64;
+    let page = resolve_pagination(&query.pagination, state.settings.pagination.marketplace)?;
+    let fields = FieldSet::parse(SubcontractorListItem::FIELDS, fields.fields.as_deref())?;
+
+    // The directory is the same for every viewer, so pages are cached by query
+    let query_hash = hex::encode(Sha256::digest(raw_query.unwrap_or_default().as_bytes()));
+    let cache_key = cache_keys::marketplace_directory(&query_hash);
+    if let Some(cached) = state.cache.get::<CachedSubcontractorList>(&cache_key).await {
+        return fields.paginated(cached.data, &page, cached.total);
+    }
 
     let verified_only = query.filter.verified_only.unwrap_or(false);
     let min_rating = query.filter.min_rating.unwrap_or(0.0);
     let has_insurance = query.filter.has_insurance.unwrap_or(false);
+    let (near_lat, near_lng, radius_m) = parse_geo_query(&geo)?;
 
-    // Count total
-    let total: i64 = sqlx::query_scalar(
-        r#"
-        SELECT COUNT(*) FROM subcontractors s
-        WHERE ($1::bool = false OR s.verified = true)
-        AND s.rating >= $2
-        AND ($3::text IS NULL OR s.trade ILIKE '%' || $3 || '%' OR 
-             EXISTS (SELECT 1 FROM jsonb_array_elements_text(s.secondary_trades) t WHERE t ILIKE '%' || $3 || '%'))
-        AND ($4::text IS NULL OR s.location ILIKE '%' || $4 || '%')
-        AND ($5::text IS NULL OR s.name ILIKE '%' || $5 || '%' OR s.headline ILIKE '%' || $5 || '%')
-        AND ($6::text IS NULL OR s.availability_status = $6)
-        AND ($7::bigint IS NULL OR s.max_project_value >= $7)
-        AND ($8::bigint IS NULL OR s.min_project_value <= $8)
-        AND ($9::bool = false OR s.insurance IS NOT NULL AND s.insurance != '{}'::jsonb)
-        "#,
-    )
-    .bind(verified_only)
-    .bind(min_rating)
-    .bind(&query.filter.trade)
-    .bind(&query.filter.location)
-    .bind(&query.filter.search)
-    .bind(&query.filter.availability)
-    .bind(query.filter.min_project_value)
-    .bind(query.filter.max_project_value)
-    .bind(has_insurance)
-    .fetch_one(&state.db)
-    .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
-
-    // Determine sort
-    let order_by = match query.filter.sort_by.as_deref() {
-        Some("rating") => "s.rating",
-        Some("reviews") => "s.review_count",
-        Some("response_time") => "s.response_time_hours",
-        Some("newest") => "s.created_at",
-        _ => "s.rating",
-    };
-    let order_dir = match query.filter.sort_order.as_deref() {
-        Some("asc") => "ASC",
-        _ => "DESC",
-    };
+    let sort = SortSpec::parse(
+        SUBCONTRACTOR_SORT_FIELDS,
+        SortOrder::Desc,
+        query.filter.sort_by.as_deref(),
+        query.filter.sort_order.as_deref(),
+    )?;
+    // Distance search returns the nearest results first
+    let distance_order = if near_lat.is_some() { "distance_km ASC NULLS LAST, " } else { "" };
 
     let query_str = format!(
         r#"
-        SELECT 
-            s.id, s.profile_id, s.name, s.trade,
-            COALESCE(to_jsonb(s.secondary_trades), '[]'::jsonb) as secondary_trades,
-            s.headline, s.company_description, s.rating, s.review_count,
-            s.location, s.contact_email, s.contact_phone, s.website,
-            s.projects_completed, s.average_bid_value, s.response_time, s.response_time_hours,
-            s.verified, COALESCE(s.verification_status, 'pending') as verification_status,
-            COALESCE(to_jsonb(s.specialties), '[]'::jsonb) as specialties,
-            COALESCE(s.service_areas, '[]'::jsonb) as service_areas,
-            COALESCE(s.certifications, '[]'::jsonb) as certifications,
-            COALESCE(s.insurance, '{{}}'::jsonb) as insurance,
-            COALESCE(s.license_info, '{{}}'::jsonb) as license_info,
-            s.year_established, s.employee_count,
-            s.min_project_value, s.max_project_value,
-            COALESCE(s.availability_status, 'available') as availability_status,
-            COALESCE(s.recent_projects, '[]'::jsonb) as recent_projects,
-            (SELECT COUNT(*) FROM portfolio_projects pp WHERE pp.subcontractor_id = s.id) as portfolio_count,
-            s.created_at
+        SELECT {}, {} as distance_km, {}
         FROM subcontractors s
         WHERE ($1::bool = false OR s.verified = true)
         AND s.rating >= $2
         AND ($3::text IS NULL OR s.trade ILIKE '%' || $3 || '%' OR 
              EXISTS (SELECT 1 FROM jsonb_array_elements_text(s.secondary_trades) t WHERE t ILIKE '%' || $3 || '%'))
-        AND ($4::text IS NULL OR s.location ILIKE '%' || $4 || '%')
+        AND {}
         AND ($5::text IS NULL OR s.name ILIKE '%' || $5 || '%' OR s.headline ILIKE '%' || $5 || '%')
         AND ($6::text IS NULL OR s.availability_status = $6)
-        AND ($7::bigint IS NULL OR s.max_project_value >= $7)
-        AND ($8::bigint IS NULL OR s.min_project_value <= $8)
-        AND ($9::bool = false OR s.insurance IS NOT NULL AND s.insurance != '{{}}'::jsonb)
-        ORDER BY {} {} NULLS LAST
+        AND ($7::numeric IS NULL OR s.max_project_value >= $7)
+        AND ($8::numeric IS NULL OR s.min_project_value <= $8)
+        AND ($9::bool = false OR s.insurance IS NOT NULL AND s.insurance != '{{}}'::jsonb
+             AND (s.insurance_expiry IS NULL OR s.insurance_expiry >= CURRENT_DATE))
+        ORDER BY {}{} NULLS LAST, s.id
         LIMIT $10 OFFSET $11
         "#,
-        order_by, order_dir
+        SUBCONTRACTOR_LIST_COLUMNS,
+        geo_distance_sql("s", 12, 13),
+        TOTAL_COUNT_COLUMN,
+        geo_location_filter("s", "s.location", 4, 12, 13, 14),
+        distance_order,
+        sort
     );
 
-    let rows = sqlx::query_as::<_, MarketplaceSubRow>(&query_str)
+    let rows = sqlx::query_as::<_, Counted<SubcontractorListRow>>(&query_str)
         .bind(verified_only)
         .bind(min_rating)
         .bind(&query.filter.trade)
@@ -252,137 +568,103 @@ pub async fn list_marketplace_subcontractors(
         .bind(query.filter.min_project_value)
         .bind(query.filter.max_project_value)
         .bind(has_insurance)
-        .bind(per_page as i64)
-        .bind(offset)
+        .bind(page.limit())
+        .bind(page.offset())
+        .bind(near_lat)
+        .bind(near_lng)
+        .bind(radius_m)
         .fetch_all(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+        .map_err(map_db_err("list_marketplace_subcontractors"))?;
+
+    let total = match page.counted_total(&rows) {
+        Some(total) => total,
+        // Past the last page: count separately
+        None => {
+            let total: i64 = sqlx::query_scalar(&format!(
+                r#"
+                SELECT COUNT(*) FROM subcontractors s
+                WHERE ($1::bool = false OR s.verified = true)
+                AND s.rating >= $2
+                AND ($3::text IS NULL OR s.trade ILIKE '%' || $3 || '%' OR 
+                     EXISTS (SELECT 1 FROM jsonb_array_elements_text(s.secondary_trades) t WHERE t ILIKE '%' || $3 || '%'))
+                AND {}
+                AND ($5::text IS NULL OR s.name ILIKE '%' || $5 || '%' OR s.headline ILIKE '%' || $5 || '%')
+                AND ($6::text IS NULL OR s.availability_status = $6)
+                AND ($7::numeric IS NULL OR s.max_project_value >= $7)
+                AND ($8::numeric IS NULL OR s.min_project_value <= $8)
+                AND ($9::bool = false OR s.insurance IS NOT NULL AND s.insurance != '{{}}'::jsonb
+                     AND (s.insurance_expiry IS NULL OR s.insurance_expiry >= CURRENT_DATE))
+                "#,
+                geo_location_filter("s", "s.location", 4, 10, 11, 12)
+            ))
+            .bind(verified_only)
+            .bind(min_rating)
+            .bind(&query.filter.trade)
+            .bind(&query.filter.location)
+            .bind(&query.filter.search)
+            .bind(&query.filter.availability)
+            .bind(query.filter.min_project_value)
+            .bind(query.filter.max_project_value)
+            .bind(has_insurance)
+            .bind(near_lat)
+            .bind(near_lng)
+            .bind(radius_m)
+            .fetch_one(&state.db)
+            .await
+            .map_err(map_db_err("list_marketplace_subcontractors"))?;
+            total as u64
+        }
+    };
 
-    let data: Vec<SubcontractorProfile> = rows
-        .into_iter()
-        .map(|r| SubcontractorProfile {
-            id: r.id,
-            profile_id: r.profile_id,
-            name: r.name,
-            trade: r.trade,
-            secondary_trades: serde_json::from_value(r.secondary_trades).unwrap_or_default(),
-            headline: r.headline,
-            company_description: r.company_description,
-            rating: r.rating,
-            review_count: r.review_count,
-            location: r.location,
-            contact_email: r.contact_email,
-            contact_phone: r.contact_phone,
-            website: r.website,
-            projects_completed: r.projects_completed,
-            average_bid_value: r.average_bid_value,
-            response_time: r.response_time,
-            response_time_hours: r.response_time_hours,
-            verified: r.verified,
-            verification_status: r.verification_status,
-            specialties: serde_json::from_value(r.specialties).unwrap_or_default(),
-            service_areas: serde_json::from_value(r.service_areas).unwrap_or_default(),
-            certifications: serde_json::from_value(r.certifications).unwrap_or_default(),
-            insurance: serde_json::from_value(r.insurance).ok(),
-            license_info: serde_json::from_value(r.license_info).ok(),
-            year_established: r.year_established,
-            employee_count: r.employee_count,
-            min_project_value: r.min_project_value,
-            max_project_value: r.max_project_value,
-            availability_status: r.availability_status,
-            recent_projects: serde_json::from_value(r.recent_projects).unwrap_or_default(),
-            portfolio_count: r.portfolio_count as i32,
-            created_at: r.created_at,
-        })
-        .collect();
+    let cached = CachedSubcontractorList {
+        data: rows.into_iter().map(|r| r.row.into_list_item(&state.storage)).collect(),
+        total,
+    };
+    let _ = state.cache.set_in(CacheNamespace::Listing, &cache_key, &cached).await;
 
-    let total_pages = ((total as f64) / (per_page as f64)).ceil() as u32;
-
-    Ok(Json(Paginated {
-        data,
-        pagination: PaginationMeta {
-            page,
-            per_page,
-            total_items: total as u64,
-            total_pages,
-            has_next: page < total_pages,
-            has_prev: page > 1,
-        },
-    }))
+    fields.paginated(cached.data, &page, cached.total)
 }
 
 /// GET /api/marketplace/subcontractors/:id
 ///
 /// Get full subcontractor profile.
+#[utoipa::path(
+    get,
+    path = "/marketplace/subcontractors/{sub_id}",
+    tag = "marketplace",
+    params(("sub_id" = Uuid, Path, description = "Subcontractor id")),
+    responses(
+        (status = 200, description = "Subcontractor profile", body = SubcontractorProfileData),
+        (status = 404, description = "Subcontractor not found", body = ErrorResponse),
+    )
+)]
 pub async fn get_marketplace_subcontractor(
     State(state): State<Arc<AppState>>,
     Path(sub_id): Path<Uuid>,
     _auth: RequireAuth,
 ) -> Result<impl IntoResponse, ApiError> {
-    let row = sqlx::query_as::<_, MarketplaceSubRow>(
+    let cache_key = cache_keys::marketplace_subcontractor(sub_id);
+    if let Some(profile) = state.cache.get::<SubcontractorProfile>(&cache_key).await {
+        return Ok(Json(DataResponse::new(profile)));
+    }
+
+    let row = sqlx::query_as::<_, MarketplaceSubRow>(&format!(
         r#"
-        SELECT 
-            s.id, s.profile_id, s.name, s.trade,
-            COALESCE(to_jsonb(s.secondary_trades), '[]'::jsonb) as secondary_trades,
-            s.headline, s.company_description, s.rating, s.review_count,
-            s.location, s.contact_email, s.contact_phone, s.website,
-            s.projects_completed, s.average_bid_value, s.response_time, s.response_time_hours,
-            s.verified, COALESCE(s.verification_status, 'pending') as verification_status,
-            COALESCE(to_jsonb(s.specialties), '[]'::jsonb) as specialties,
-            COALESCE(s.service_areas, '[]'::jsonb) as service_areas,
-            COALESCE(s.certifications, '[]'::jsonb) as certifications,
-            COALESCE(s.insurance, '{}'::jsonb) as insurance,
-            COALESCE(s.license_info, '{}'::jsonb) as license_info,
-            s.year_established, s.employee_count,
-            s.min_project_value, s.max_project_value,
-            COALESCE(s.availability_status, 'available') as availability_status,
-            COALESCE(s.recent_projects, '[]'::jsonb) as recent_projects,
-            (SELECT COUNT(*) FROM portfolio_projects pp WHERE pp.subcontractor_id = s.id) as portfolio_count,
-            s.created_at
-        FROM subcontractors s
+        SELECT {}
+        FROM subcontractors s {}
         WHERE s.id = $1
         "#,
-    )
+        MARKETPLACE_SUB_COLUMNS, MARKETPLACE_SUB_REVIEWS_JOIN
+    ))
     .bind(sub_id)
     .fetch_optional(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
+    .map_err(map_db_err("get_marketplace_subcontractor"))?
     .ok_or_else(|| ApiError::not_found("Subcontractor not found"))?;
 
-    let profile = SubcontractorProfile {
-        id: row.id,
-        profile_id: row.profile_id,
-        name: row.name,
-        trade: row.trade,
-        secondary_trades: serde_json::from_value(row.secondary_trades).unwrap_or_default(),
-        headline: row.headline,
-        company_description: row.company_description,
-        rating: row.rating,
-        review_count: row.review_count,
-        location: row.location,
-        contact_email: row.contact_email,
-        contact_phone: row.contact_phone,
-        website: row.website,
-        projects_completed: row.projects_completed,
-        average_bid_value: row.average_bid_value,
-        response_time: row.response_time,
-        response_time_hours: row.response_time_hours,
-        verified: row.verified,
-        verification_status: row.verification_status,
-        specialties: serde_json::from_value(row.specialties).unwrap_or_default(),
-        service_areas: serde_json::from_value(row.service_areas).unwrap_or_default(),
-        certifications: serde_json::from_value(row.certifications).unwrap_or_default(),
-        insurance: serde_json::from_value(row.insurance).ok(),
-        license_info: serde_json::from_value(row.license_info).ok(),
-        year_established: row.year_established,
-        employee_count: row.employee_count,
-        min_project_value: row.min_project_value,
-        max_project_value: row.max_project_value,
-        availability_status: row.availability_status,
-        recent_projects: serde_json::from_value(row.recent_projects).unwrap_or_default(),
-        portfolio_count: row.portfolio_count as i32,
-        created_at: row.created_at,
-    };
+    let profile = row.into_profile(&state.storage);
+    let _ = state.cache.set_with_ttl(&cache_key, &profile, cache_ttl::ENTITY).await;
 
     Ok(Json(DataResponse::new(profile)))
 }
@@ -390,6 +672,15 @@ pub async fn get_marketplace_subcontractor(
 /// GET /api/marketplace/subcontractors/:id/portfolio
 ///
 /// Get subcontractor's portfolio projects.
+#[utoipa::path(
+    get,
+    path = "/marketplace/subcontractors/{sub_id}/portfolio",
+    tag = "marketplace",
+    params(("sub_id" = Uuid, Path, description = "Subcontractor id")),
+    responses(
+        (status = 200, description = "Portfolio projects", body = PortfolioProjectListData),
+    )
+)]
 pub async fn get_subcontractor_portfolio(
     State(state): State<Arc<AppState>>,
     Path(sub_id): Path<Uuid>,
@@ -408,9 +699,12 @@ pub async fn get_subcontractor_portfolio(
     .bind(sub_id)
     .fetch_all(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("get_subcontractor_portfolio"))?;
 
-    let data: Vec<PortfolioProjectResponse> = rows.into_iter().map(Into::into).collect();
+    let data: Vec<PortfolioProjectResponse> = rows
+        .into_iter()
+        .map(|p| portfolio_response(p, &state.storage))
+        .collect();
 
     Ok(Json(DataResponse::new(data)))
 }
@@ -421,77 +715,39 @@ pub async fn get_subcontractor_portfolio(
 
 /// GET /api/marketplace/profile
 ///
-/// Get the current user's subcontractor profile.
+/// Get the current user's subcontractor profile, with its completeness score.
+#[utoipa::path(
+    get,
+    path = "/marketplace/profile",
+    tag = "marketplace",
+    responses(
+        (status = 200, description = "The caller's subcontractor profile", body = SubcontractorProfileData),
+        (status = 404, description = "No subcontractor profile", body = ErrorResponse),
+        (status = 403, description = "Caller is not a subcontractor", body = ErrorResponse),
+    )
+)]
 pub async fn get_my_marketplace_profile(
     State(state): State<Arc<AppState>>,
-    auth: RequireAuth,
+    auth: RequireSub,
 ) -> Result<impl IntoResponse, ApiError> {
     let user_id = auth.user_id;
 
-    let row = sqlx::query_as::<_, MarketplaceSubRow>(
+    let row = sqlx::query_as::<_, MarketplaceSubRow>(&format!(
         r#"
-        SELECT 
-            s.id, s.profile_id, s.name, s.trade,
-            COALESCE(to_jsonb(s.secondary_trades), '[]'::jsonb) as secondary_trades,
-            s.headline, s.company_description, s.rating, s.review_count,
-            s.location, s.contact_email, s.contact_phone, s.website,
-            s.projects_completed, s.average_bid_value, s.response_time, s.response_time_hours,
-            s.verified, COALESCE(s.verification_status, 'pending') as verification_status,
-            COALESCE(to_jsonb(s.specialties), '[]'::jsonb) as specialties,
-            COALESCE(s.service_areas, '[]'::jsonb) as service_areas,
-            COALESCE(s.certifications, '[]'::jsonb) as certifications,
-            COALESCE(s.insurance, '{}'::jsonb) as insurance,
-            COALESCE(s.license_info, '{}'::jsonb) as license_info,
-            s.year_established, s.employee_count,
-            s.min_project_value, s.max_project_value,
-            COALESCE(s.availability_status, 'available') as availability_status,
-            COALESCE(s.recent_projects, '[]'::jsonb) as recent_projects,
-            (SELECT COUNT(*) FROM portfolio_projects pp WHERE pp.subcontractor_id = s.id) as portfolio_count,
-            s.created_at
-        FROM subcontractors s
+        SELECT {}
+        FROM subcontractors s {}
         WHERE s.profile_id = $1
         "#,
-    )
+        MARKETPLACE_SUB_COLUMNS, MARKETPLACE_SUB_REVIEWS_JOIN
+    ))
     .bind(user_id)
     .fetch_optional(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
+    .map_err(map_db_err("get_my_marketplace_profile"))?
     .ok_or_else(|| ApiError::not_found("No subcontractor profile found. Create one first."))?;
 
-    let profile = SubcontractorProfile {
-        id: row.id,
-        profile_id: row.profile_id,
-        name: row.name,
-        trade: row.trade,
-        secondary_trades: serde_json::from_value(row.secondary_trades).unwrap_or_default(),
-        headline: row.headline,
-        company_description: row.company_description,
-        rating: row.rating,
-        review_count: row.review_count,
-        location: row.location,
-        contact_email: row.contact_email,
-        contact_phone: row.contact_phone,
-        website: row.website,
-        projects_completed: row.projects_completed,
-        average_bid_value: row.average_bid_value,
-        response_time: row.response_time,
-        response_time_hours: row.response_time_hours,
-        verified: row.verified,
-        verification_status: row.verification_status,
-        specialties: serde_json::from_value(row.specialties).unwrap_or_default(),
-        service_areas: serde_json::from_value(row.service_areas).unwrap_or_default(),
-        certifications: serde_json::from_value(row.certifications).unwrap_or_default(),
-        insurance: serde_json::from_value(row.insurance).ok(),
-        license_info: serde_json::from_value(row.license_info).ok(),
-        year_established: row.year_established,
-        employee_count: row.employee_count,
-        min_project_value: row.min_project_value,
-        max_project_value: row.max_project_value,
-        availability_status: row.availability_status,
-        recent_projects: serde_json::from_value(row.recent_projects).unwrap_or_default(),
-        portfolio_count: row.portfolio_count as i32,
-        created_at: row.created_at,
-    };
+    let mut profile = row.into_profile(&state.storage);
+    profile.completeness = Some(profile_completeness(&profile, &state.settings.completeness_weights));
 
     Ok(Json(DataResponse::new(profile)))
 }
@@ -499,9 +755,21 @@ pub async fn get_my_marketplace_profile(
 /// PUT /api/marketplace/profile
 ///
 /// Update the current user's subcontractor profile.
+#[utoipa::path(
+    put,
+    path = "/marketplace/profile",
+    tag = "marketplace",
+    request_body = UpdateMarketplaceProfileRequest,
+    responses(
+        (status = 200, description = "Profile updated", body = MessageResponse),
+        (status = 400, description = "Invalid profile fields", body = ErrorResponse),
+        (status = 404, description = "No subcontractor profile", body = ErrorResponse),
+        (status = 403, description = "Caller is not a subcontractor", body = ErrorResponse),
+    )
+)]
 pub async fn update_my_marketplace_profile(
     State(state): State<Arc<AppState>>,
-    auth: RequireAuth,
+    auth: RequireSub,
     Json(input): Json<UpdateMarketplaceProfileRequest>,
 ) -> Result<impl IntoResponse, ApiError> {
     let user_id = auth.user_id;
@@ -511,7 +779,7 @@ pub async fn update_my_marketplace_profile(
         .bind(user_id)
         .fetch_optional(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+        .map_err(map_db_err("update_my_marketplace_profile"))?;
 
     let sub_id = sub_id.ok_or_else(|| {
         ApiError::not_found("No subcontractor profile found. Create one first.")
@@ -519,12 +787,27 @@ pub async fn update_my_marketplace_profile(
 
     // Build update
     let secondary_trades = input.secondary_trades.map(|t| serde_json::to_value(t).unwrap_or_default());
-    let specialties = input.specialties.map(|t| serde_json::to_value(t).unwrap_or_default());
     let service_areas = input.service_areas.map(|t| serde_json::to_value(t).unwrap_or_default());
     let certifications = input.certifications.map(|t| serde_json::to_value(t).unwrap_or_default());
+    let insurance_expiry = match &input.insurance {
+        Some(insurance) => insurance.expiry().map_err(ApiError::bad_request)?,
+        None => None,
+    };
     let insurance = input.insurance.map(|t| serde_json::to_value(t).unwrap_or_default());
     let license_info = input.license_info.map(|t| serde_json::to_value(t).unwrap_or_default());
 
+    // Explicit coordinates win; a changed location otherwise clears them until re-geocoded
+    let coordinates = match (input.latitude, input.longitude) {
+        (Some(lat), Some(lng)) => {
+            if !(-90.0..=90.0).contains(&lat) || !(-180.0..=180.0).contains(&lng) {
+                return Err(ApiError::bad_request("latitude/longitude are out of range"));
+            }
+            Some((Some(lat), Some(lng)))
+        }
+        (None, None) => input.location.as_ref().map(|_| (None, None)),
+        _ => return Err(ApiError::bad_request("latitude and longitude must be provided together")),
+    };
+
     sqlx::query(
         r#"
         UPDATE subcontractors SET
@@ -541,12 +824,15 @@ pub async fn update_my_marketplace_profile(
             service_areas = COALESCE($11, service_areas),
             certifications = COALESCE($12, certifications),
             insurance = COALESCE($13, insurance),
+            insurance_expiry = CASE WHEN $13::jsonb IS NULL THEN insurance_expiry ELSE $24 END,
             license_info = COALESCE($14, license_info),
             year_established = COALESCE($15, year_established),
             employee_count = COALESCE($16, employee_count),
             min_project_value = COALESCE($17, min_project_value),
             max_project_value = COALESCE($18, max_project_value),
             availability_status = COALESCE($19, availability_status),
+            latitude = CASE WHEN $21 THEN $22 ELSE latitude END,
+            longitude = CASE WHEN $21 THEN $23 ELSE longitude END,
             updated_at = NOW()
         WHERE id = $20
         "#,
@@ -560,7 +846,7 @@ pub async fn update_my_marketplace_profile(
     .bind(&input.contact_email)
     .bind(&input.contact_phone)
     .bind(&input.website)
-    .bind(specialties)
+    .bind(&input.specialties)
     .bind(service_areas)
     .bind(certifications)
     .bind(insurance)
@@ -571,39 +857,207 @@ pub async fn update_my_marketplace_profile(
     .bind(input.max_project_value)
     .bind(&input.availability_status)
     .bind(sub_id)
+    .bind(coordinates.is_some())
+    .bind(coordinates.and_then(|c| c.0))
+    .bind(coordinates.and_then(|c| c.1))
+    .bind(insurance_expiry)
     .execute(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Failed to update profile: {}", e)))?;
+    .map_err(map_db_err("update_my_marketplace_profile"))?;
+
+    state.cache.invalidate_subcontractor(sub_id).await;
+
+    if let (Some(location), Some((None, None))) = (&input.location, coordinates) {
+        geocoding::spawn_geocode_row(
+            state.geocoder.clone(),
+            state.db.clone(),
+            GeocodedTable::Subcontractors,
+            sub_id,
+            location.clone(),
+        );
+    }
+
+    Ok(MessageResponse::new("Profile updated"))
+}
+
+/// PUT /api/marketplace/profile/logo
+///
+/// Upload a company logo (multipart field `file`) for the current user's
+/// subcontractor profile. The previous logo and its thumbnail are removed.
+#[utoipa::path(
+    put,
+    path = "/marketplace/profile/logo",
+    tag = "marketplace",
+    request_body(content = ImageUpload, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "`{ data: { logo_url, logo_thumbnail_url } }`"),
+        (status = 400, description = "Missing or invalid image", body = ErrorResponse),
+        (status = 404, description = "No subcontractor profile", body = ErrorResponse),
+        (status = 403, description = "Caller is not a subcontractor", body = ErrorResponse),
+    )
+)]
+pub async fn upload_my_marketplace_logo(
+    State(state): State<Arc<AppState>>,
+    auth: RequireSub,
+    mut multipart: Multipart,
+) -> Result<impl IntoResponse, ApiError> {
+    let sub_id: Uuid = sqlx::query_scalar("SELECT id FROM subcontractors WHERE profile_id = $1")
+        .bind(auth.user_id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(map_db_err("upload_my_marketplace_logo"))?
+        .ok_or_else(|| ApiError::not_found("No subcontractor profile found. Create one first."))?;
+
+    let (bytes, content_type) = images::read_upload(&mut multipart).await?;
+    let stored = images::store_upload(
+        &state.storage,
+        &format!("logos/{}", sub_id),
+        bytes,
+        content_type,
+    )
+    .await?;
+
+    // Swap keys in one statement, returning the replaced ones for cleanup
+    let previous: Result<(Option<String>, Option<String>), ApiError> = sqlx::query_as(
+        r#"
+        UPDATE subcontractors s SET logo_key = $2, logo_thumb_key = $3
+        FROM (SELECT id, logo_key, logo_thumb_key FROM subcontractors WHERE id = $1 FOR UPDATE) old
+        WHERE s.id = old.id
+        RETURNING old.logo_key, old.logo_thumb_key
+        "#,
+    )
+    .bind(sub_id)
+    .bind(&stored.key)
+    .bind(&stored.thumb_key)
+    .fetch_one(&state.db)
+    .await
+    .map_err(map_db_err("upload_my_marketplace_logo"));
+
+    let (old_key, old_thumb_key) = match previous {
+        Ok(previous) => previous,
+        Err(e) => {
+            state
+                .storage
+                .delete_best_effort(&[Some(stored.key.as_str()), Some(stored.thumb_key.as_str())])
+                .await;
+            return Err(e);
+        }
+    };
+
+    state
+        .storage
+        .delete_best_effort(&[old_key.as_deref(), old_thumb_key.as_deref()])
+        .await;
+    state.cache.invalidate_subcontractor(sub_id).await;
 
-    Ok(Json(serde_json::json!({ "success": true })))
+    Ok(Json(DataResponse::new(serde_json::json!({
+        "logo_url": state.storage.url(&stored.key),
+        "logo_thumbnail_url": state.storage.url(&stored.thumb_key),
+    }))))
 }
 
 /// POST /api/marketplace/profile/request-verification
 ///
-/// Request verification for the subcontractor profile.
+/// Request verification for the subcontractor profile. The profile must have
+/// every admin-required field and reach the configured completeness score;
+/// otherwise this is a 422 listing what is missing.
+#[utoipa::path(
+    post,
+    path = "/marketplace/profile/request-verification",
+    tag = "marketplace",
+    responses(
+        (status = 200, description = "Verification requested", body = MessageResponse),
+        (status = 400, description = "Already verified or pending", body = ErrorResponse),
+        (status = 422, description = "Required fields missing or profile too incomplete", body = ErrorResponse),
+        (status = 404, description = "No subcontractor profile", body = ErrorResponse),
+        (status = 403, description = "Caller is not a subcontractor", body = ErrorResponse),
+    )
+)]
 pub async fn request_verification(
     State(state): State<Arc<AppState>>,
-    auth: RequireAuth,
+    auth: RequireSub,
+    ExtractClientIp(client_ip): ExtractClientIp,
 ) -> Result<impl IntoResponse, ApiError> {
     let user_id = auth.user_id;
 
-    let result = sqlx::query(
+    let row = sqlx::query_as::<_, MarketplaceSubRow>(&format!(
+        r#"
+        SELECT {}
+        FROM subcontractors s {}
+        WHERE s.profile_id = $1
+        "#,
+        MARKETPLACE_SUB_COLUMNS, MARKETPLACE_SUB_REVIEWS_JOIN
+    ))
+    .bind(user_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("request_verification"))?
+    .ok_or_else(|| ApiError::not_found("No subcontractor profile found. Create one first."))?;
+
+    let requirements = system_settings::verification_requirements(&state.db)
+        .await
+        .map_err(map_db_err("request_verification"))?;
+
+    let profile = row.into_profile(&state.storage);
+    if profile.insurance_expired {
+        return Err(ApiError::unprocessable(
+            "Insurance on file has expired; update it before requesting verification",
+        ));
+    }
+
+    let missing = missing_verification_fields(&profile, &requirements.required_fields);
+    if !missing.is_empty() {
+        return Err(ApiError::unprocessable(format!(
+            "Complete these fields before requesting verification: {}",
+            missing.join(", ")
+        )));
+    }
+
+    let completeness = profile_completeness(&profile, &state.settings.completeness_weights);
+    let required = state.settings.verification_min_completeness;
+    if completeness.percent < required {
+        return Err(ApiError::unprocessable(format!(
+            "Profile is {}% complete; at least {}% is required before requesting verification. Missing: {}",
+            completeness.percent,
+            required,
+            completeness.missing.join(", ")
+        )));
+    }
+
+    let sub_id: Option<Uuid> = sqlx::query_scalar(
         r#"
         UPDATE subcontractors 
         SET verification_status = 'pending', updated_at = NOW()
         WHERE profile_id = $1 AND verification_status != 'verified'
+        RETURNING id
         "#,
     )
     .bind(user_id)
-    .execute(&state.db)
+    .fetch_optional(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("request_verification"))?;
 
-    if result.rows_affected() == 0 {
-        return Err(ApiError::bad_request("Profile not found or already verified"));
+    let sub_id = sub_id.ok_or_else(|| ApiError::bad_request("Profile not found or already verified"))?;
+    state.cache.invalidate_subcontractor(sub_id).await;
+
+    if let Err(e) = audit::log_activity(
+        &state.db,
+        ActivityEntry {
+            actor_id: user_id,
+            project_id: None,
+            action: ActivityAction::VerificationRequested,
+            target_type: AuditTargetType::Subcontractor,
+            target_id: Some(sub_id),
+            details: serde_json::json!({}),
+            ip_address: client_ip,
+        },
+    )
+    .await
+    {
+        tracing::warn!(error = %e, "Failed to log verification request");
     }
 
-    Ok(Json(serde_json::json!({ "success": true, "message": "Verification request submitted" })))
+    Ok(MessageResponse::new("Verification request submitted"))
 }
 
 // ============================================================================
@@ -613,9 +1067,19 @@ pub async fn request_verification(
 /// GET /api/marketplace/profile/portfolio
 ///
 /// Get the current user's portfolio projects.
+#[utoipa::path(
+    get,
+    path = "/marketplace/profile/portfolio",
+    tag = "marketplace",
+    responses(
+        (status = 200, description = "The caller's portfolio projects", body = PortfolioProjectListData),
+        (status = 404, description = "No subcontractor profile", body = ErrorResponse),
+        (status = 403, description = "Caller is not a subcontractor", body = ErrorResponse),
+    )
+)]
 pub async fn get_my_portfolio(
     State(state): State<Arc<AppState>>,
-    auth: RequireAuth,
+    auth: RequireSub,
 ) -> Result<impl IntoResponse, ApiError> {
     let user_id = auth.user_id;
 
@@ -623,7 +1087,7 @@ pub async fn get_my_portfolio(
         .bind(user_id)
         .fetch_optional(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+        .map_err(map_db_err("get_my_portfolio"))?;
 
     let sub_id = sub_id.ok_or_else(|| ApiError::not_found("No subcontractor profile found"))?;
 
@@ -640,9 +1104,12 @@ pub async fn get_my_portfolio(
     .bind(sub_id)
     .fetch_all(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("get_my_portfolio"))?;
 
-    let data: Vec<PortfolioProjectResponse> = rows.into_iter().map(Into::into).collect();
+    let data: Vec<PortfolioProjectResponse> = rows
+        .into_iter()
+        .map(|p| portfolio_response(p, &state.storage))
+        .collect();
 
     Ok(Json(DataResponse::new(data)))
 }
@@ -650,9 +1117,21 @@ pub async fn get_my_portfolio(
 /// POST /api/marketplace/profile/portfolio
 ///
 /// Add a new portfolio project.
+#[utoipa::path(
+    post,
+    path = "/marketplace/profile/portfolio",
+    tag = "marketplace",
+    request_body = PortfolioProjectRequest,
+    responses(
+        (status = 201, description = "Portfolio project created", body = CreatedIdData),
+        (status = 400, description = "Invalid project fields", body = ErrorResponse),
+        (status = 404, description = "No subcontractor profile", body = ErrorResponse),
+        (status = 403, description = "Caller is not a subcontractor", body = ErrorResponse),
+    )
+)]
 pub async fn create_portfolio_project(
     State(state): State<Arc<AppState>>,
-    auth: RequireAuth,
+    auth: RequireSub,
     Json(input): Json<PortfolioProjectRequest>,
 ) -> Result<impl IntoResponse, ApiError> {
     let user_id = auth.user_id;
@@ -661,12 +1140,17 @@ pub async fn create_portfolio_project(
         .bind(user_id)
         .fetch_optional(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+        .map_err(map_db_err("create_portfolio_project"))?;
 
     let sub_id = sub_id.ok_or_else(|| ApiError::not_found("No subcontractor profile found"))?;
 
     let id = Uuid::new_v4();
-    let images = serde_json::to_value(input.images.unwrap_or_default()).unwrap_or(serde_json::json!([]));
+    let images = normalize_portfolio_images(
+        &state.storage,
+        &portfolio_image_prefix(sub_id, id),
+        input.images.unwrap_or_default(),
+    )?;
+    let images = serde_json::to_value(images).unwrap_or(serde_json::json!([]));
     let display_order = input.display_order.unwrap_or(0);
 
     sqlx::query(
@@ -694,43 +1178,52 @@ pub async fn create_portfolio_project(
     .bind(display_order)
     .execute(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Failed to create portfolio project: {}", e)))?;
+    .map_err(map_db_err("create_portfolio_project"))?;
 
-    Ok(Json(serde_json::json!({ "id": id, "success": true })))
+    state.cache.invalidate_subcontractor(sub_id).await;
+
+    Ok(Created::id(id))
 }
 
 /// PUT /api/marketplace/profile/portfolio/:id
 ///
 /// Update a portfolio project.
+#[utoipa::path(
+    put,
+    path = "/marketplace/profile/portfolio/{project_id}",
+    tag = "marketplace",
+    params(("project_id" = Uuid, Path, description = "Portfolio project id")),
+    request_body = PortfolioProjectRequest,
+    responses(
+        (status = 200, description = "Portfolio project updated", body = MessageResponse),
+        (status = 400, description = "Invalid project fields", body = ErrorResponse),
+        (status = 404, description = "Portfolio project not found", body = ErrorResponse),
+        (status = 403, description = "Caller is not a subcontractor", body = ErrorResponse),
+    )
+)]
 pub async fn update_portfolio_project(
     State(state): State<Arc<AppState>>,
     Path(project_id): Path<Uuid>,
-    auth: RequireAuth,
+    auth: RequireSub,
     Json(input): Json<PortfolioProjectRequest>,
 ) -> Result<impl IntoResponse, ApiError> {
     let user_id = auth.user_id;
 
-    // Verify ownership
-    let exists: bool = sqlx::query_scalar(
-        r#"
-        SELECT EXISTS(
-            SELECT 1 FROM portfolio_projects pp
-            JOIN subcontractors s ON pp.subcontractor_id = s.id
-            WHERE pp.id = $1 AND s.profile_id = $2
-        )
-        "#,
-    )
-    .bind(project_id)
-    .bind(user_id)
-    .fetch_one(&state.db)
-    .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
-
-    if !exists {
-        return Err(ApiError::not_found("Portfolio project not found"));
-    }
-
-    let images = input.images.map(|i| serde_json::to_value(i).unwrap_or_default());
+    let (sub_id, previous_images) = fetch_owned_portfolio_images(&state, project_id, user_id).await?;
+
+    let prefix = portfolio_image_prefix(sub_id, project_id);
+    let images = input
+        .images
+        .map(|i| normalize_portfolio_images(&state.storage, &prefix, i))
+        .transpose()?;
+    let removed: Vec<String> = match &images {
+        Some(images) => previous_images
+            .into_iter()
+            .filter(|i| !images.contains(i))
+            .collect(),
+        None => Vec::new(),
+    };
+    let images = images.map(|i| serde_json::to_value(i).unwrap_or_default());
 
     sqlx::query(
         r#"
@@ -766,40 +1259,544 @@ pub async fn update_portfolio_project(
     .bind(project_id)
     .execute(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Failed to update portfolio project: {}", e)))?;
+    .map_err(map_db_err("update_portfolio_project"))?;
+
+    delete_portfolio_objects(&state.storage, &prefix, &removed).await;
+
+    Ok(MessageResponse::new("Portfolio project updated"))
+}
+
+/// DELETE /api/marketplace/profile/portfolio/:id
+///
+/// Delete a portfolio project.
+#[utoipa::path(
+    delete,
+    path = "/marketplace/profile/portfolio/{project_id}",
+    tag = "marketplace",
+    params(("project_id" = Uuid, Path, description = "Portfolio project id")),
+    responses(
+        (status = 204, description = "Portfolio project deleted"),
+        (status = 404, description = "Portfolio project not found", body = ErrorResponse),
+        (status = 403, description = "Caller is not a subcontractor", body = ErrorResponse),
+    )
+)]
+pub async fn delete_portfolio_project(
+    State(state): State<Arc<AppState>>,
+    Path(project_id): Path<Uuid>,
+    auth: RequireSub,
+) -> Result<impl IntoResponse, ApiError> {
+    let user_id = auth.user_id;
+
+    let (sub_id, images): (Uuid, sqlx::types::Json<Vec<String>>) = sqlx::query_as(
+        r#"
+        DELETE FROM portfolio_projects 
+        WHERE id = $1 AND subcontractor_id IN (
+            SELECT id FROM subcontractors WHERE profile_id = $2
+        )
+        RETURNING subcontractor_id, COALESCE(images, '[]'::jsonb)
+        "#,
+    )
+    .bind(project_id)
+    .bind(user_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("delete_portfolio_project"))?
+    .ok_or_else(|| ApiError::not_found("Portfolio project not found"))?;
+
+    delete_portfolio_objects(&state.storage, &portfolio_image_prefix(sub_id, project_id), &images.0)
+        .await;
+
+    state.cache.invalidate_subcontractor(sub_id).await;
+
+    Ok(NoContent)
+}
+
+/// POST /api/marketplace/profile/portfolio/:id/images
+///
+/// Upload an image (multipart field `file`) and append it to a portfolio project.
+#[utoipa::path(
+    post,
+    path = "/marketplace/profile/portfolio/{project_id}/images",
+    tag = "marketplace",
+    params(("project_id" = Uuid, Path, description = "Portfolio project id")),
+    request_body(content = ImageUpload, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "Updated portfolio project", body = PortfolioProjectData),
+        (status = 400, description = "Missing or invalid image, or image limit reached", body = ErrorResponse),
+        (status = 404, description = "Portfolio project not found", body = ErrorResponse),
+        (status = 403, description = "Caller is not a subcontractor", body = ErrorResponse),
+    )
+)]
+pub async fn upload_portfolio_image(
+    State(state): State<Arc<AppState>>,
+    Path(project_id): Path<Uuid>,
+    auth: RequireSub,
+    mut multipart: Multipart,
+) -> Result<impl IntoResponse, ApiError> {
+    let (sub_id, existing) = fetch_owned_portfolio_images(&state, project_id, auth.user_id).await?;
+    if existing.len() >= MAX_PORTFOLIO_IMAGES {
+        return Err(ApiError::bad_request(format!(
+            "Portfolio projects can have at most {} images",
+            MAX_PORTFOLIO_IMAGES
+        )));
+    }
+
+    let (bytes, content_type) = images::read_upload(&mut multipart).await?;
+    let stored = images::store_upload(
+        &state.storage,
+        &portfolio_image_prefix(sub_id, project_id),
+        bytes,
+        content_type,
+    )
+    .await?;
+
+    // Re-check the limit atomically in case of concurrent uploads
+    let project = sqlx::query_as::<_, PortfolioProject>(
+        r#"
+        UPDATE portfolio_projects
+        SET images = COALESCE(images, '[]'::jsonb) || jsonb_build_array($2::text)
+        WHERE id = $1 AND jsonb_array_length(COALESCE(images, '[]'::jsonb)) < $3
+        RETURNING id, subcontractor_id, title, description, project_type, trade_category,
+                  location, completion_date, project_value, client_name, client_testimonial,
+                  images, is_featured, display_order, created_at
+        "#,
+    )
+    .bind(project_id)
+    .bind(&stored.key)
+    .bind(MAX_PORTFOLIO_IMAGES as i32)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("upload_portfolio_image"))
+    .and_then(|row| {
+        row.ok_or_else(|| {
+            ApiError::bad_request(format!(
+                "Portfolio projects can have at most {} images",
+                MAX_PORTFOLIO_IMAGES
+            ))
+        })
+    });
+
+    let project = match project {
+        Ok(project) => project,
+        Err(e) => {
+            state
+                .storage
+                .delete_best_effort(&[Some(stored.key.as_str()), Some(stored.thumb_key.as_str())])
+                .await;
+            return Err(e);
+        }
+    };
+
+    Ok(Json(DataResponse::new(portfolio_response(project, &state.storage))))
+}
+
+/// DELETE /api/marketplace/profile/portfolio/:id/images?index=N | ?url=...
+///
+/// Remove an image from a portfolio project, deleting it from storage if uploaded.
+#[utoipa::path(
+    delete,
+    path = "/marketplace/profile/portfolio/{project_id}/images",
+    tag = "marketplace",
+    params(
+        ("project_id" = Uuid, Path, description = "Portfolio project id"),
+        PortfolioImageDeleteQuery,
+    ),
+    responses(
+        (status = 200, description = "Updated portfolio project", body = PortfolioProjectData),
+        (status = 400, description = "Neither index nor url given", body = ErrorResponse),
+        (status = 404, description = "Portfolio project or image not found", body = ErrorResponse),
+        (status = 403, description = "Caller is not a subcontractor", body = ErrorResponse),
+    )
+)]
+pub async fn delete_portfolio_image(
+    State(state): State<Arc<AppState>>,
+    Path(project_id): Path<Uuid>,
+    auth: RequireSub,
+    Query(query): Query<PortfolioImageDeleteQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(map_db_err("delete_portfolio_image"))?;
+
+    let (sub_id, images): (Uuid, sqlx::types::Json<Vec<String>>) = sqlx::query_as(
+        r#"
+        SELECT pp.subcontractor_id, COALESCE(pp.images, '[]'::jsonb)
+        FROM portfolio_projects pp
+        JOIN subcontractors s ON pp.subcontractor_id = s.id
+        WHERE pp.id = $1 AND s.profile_id = $2
+        FOR UPDATE OF pp
+        "#,
+    )
+    .bind(project_id)
+    .bind(auth.user_id)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(map_db_err("delete_portfolio_image"))?
+    .ok_or_else(|| ApiError::not_found("Portfolio project not found"))?;
+    let mut images = images.0;
+
+    let position = match (query.index, query.url.as_deref()) {
+        (Some(index), _) => (index < images.len()).then_some(index),
+        (None, Some(url)) => images
+            .iter()
+            .position(|i| i == url || state.storage.resolve_url(i) == url),
+        (None, None) => return Err(ApiError::bad_request("Provide an image index or url")),
+    };
+    let position = position.ok_or_else(|| ApiError::not_found("Image not found"))?;
+    let removed = images.remove(position);
+
+    let project = sqlx::query_as::<_, PortfolioProject>(
+        r#"
+        UPDATE portfolio_projects SET images = $2
+        WHERE id = $1
+        RETURNING id, subcontractor_id, title, description, project_type, trade_category,
+                  location, completion_date, project_value, client_name, client_testimonial,
+                  images, is_featured, display_order, created_at
+        "#,
+    )
+    .bind(project_id)
+    .bind(sqlx::types::Json(&images))
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(map_db_err("delete_portfolio_image"))?;
+
+    tx.commit()
+        .await
+        .map_err(map_db_err("delete_portfolio_image"))?;
+
+    delete_portfolio_objects(&state.storage, &portfolio_image_prefix(sub_id, project_id), &[removed])
+        .await;
+
+    Ok(Json(DataResponse::new(portfolio_response(project, &state.storage))))
+}
+
+/// Storage prefix for a portfolio project's uploaded images
+fn portfolio_image_prefix(sub_id: Uuid, project_id: Uuid) -> String {
+    format!("portfolio/{}/{}", sub_id, project_id)
+}
+
+/// Build a portfolio response with stored image keys resolved to URLs
+fn portfolio_response(project: PortfolioProject, storage: &ObjectStorage) -> PortfolioProjectResponse {
+    let mut response = PortfolioProjectResponse::from(project);
+    response.images = response
+        .images
+        .iter()
+        .map(|i| storage.resolve_url(i))
+        .collect();
+    response
+}
+
+/// Validate a client-supplied image list.
+///
+/// External URLs are kept as-is; URLs of this project's uploads are mapped back
+/// to their storage keys so responses can be round-tripped for reordering.
+fn normalize_portfolio_images(
+    storage: &ObjectStorage,
+    prefix: &str,
+    images: Vec<String>,
+) -> Result<Vec<String>, ApiError> {
+    if images.len() > MAX_PORTFOLIO_IMAGES {
+        return Err(ApiError::bad_request(format!(
+            "Portfolio projects can have at most {} images",
+            MAX_PORTFOLIO_IMAGES
+        )));
+    }
+
+    images
+        .into_iter()
+        .map(|image| {
+            if let Some(key) = storage.key_from_url(&image).filter(|k| k.starts_with(prefix)) {
+                Ok(key.to_string())
+            } else if is_external_url(&image) {
+                Ok(image)
+            } else {
+                Err(ApiError::bad_request(format!("Invalid portfolio image: {}", image)))
+            }
+        })
+        .collect()
+}
+
+/// Load a portfolio project's owning subcontractor and images, checking ownership
+async fn fetch_owned_portfolio_images(
+    state: &AppState,
+    project_id: Uuid,
+    user_id: Uuid,
+) -> Result<(Uuid, Vec<String>), ApiError> {
+    let (sub_id, images): (Uuid, sqlx::types::Json<Vec<String>>) = sqlx::query_as(
+        r#"
+        SELECT pp.subcontractor_id, COALESCE(pp.images, '[]'::jsonb)
+        FROM portfolio_projects pp
+        JOIN subcontractors s ON pp.subcontractor_id = s.id
+        WHERE pp.id = $1 AND s.profile_id = $2
+        "#,
+    )
+    .bind(project_id)
+    .bind(user_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("fetch_owned_portfolio_images"))?
+    .ok_or_else(|| ApiError::not_found("Portfolio project not found"))?;
+
+    Ok((sub_id, images.0))
+}
+
+/// Delete uploaded images (and thumbnails) under `prefix`; external URLs are skipped
+async fn delete_portfolio_objects(storage: &ObjectStorage, prefix: &str, entries: &[String]) {
+    for key in entries.iter().filter(|i| i.starts_with(prefix)) {
+        let thumb_key = images::thumbnail_key(key);
+        storage
+            .delete_best_effort(&[Some(key.as_str()), Some(thumb_key.as_str())])
+            .await;
+    }
+}
+
+// ============================================================================
+// Tender Recommendations (for GCs)
+// ============================================================================
+
+/// Candidates scored per request before ranking and truncation
+const RECOMMENDATION_CANDIDATES: i64 = 200;
+
+/// GET /api/tenders/:tender_id/recommended-subs
+///
+/// Suggest marketplace subcontractors for a tender, ranked by how well their
+/// trade, location, availability, rating and project size match. Subs who
+/// already bid or were invited are excluded.
+#[utoipa::path(
+    get,
+    path = "/tenders/{tender_id}/recommended-subs",
+    tag = "marketplace",
+    params(
+        ("tender_id" = Uuid, Path, description = "Tender id"),
+        RecommendedSubsQuery,
+    ),
+    responses(
+        (status = 200, description = "Ranked subcontractors", body = RecommendedSubcontractorListData),
+        (status = 404, description = "Tender not found", body = ErrorResponse),
+        (status = 403, description = "Caller is not a general contractor", body = ErrorResponse),
+    )
+)]
+pub async fn recommend_subs_for_tender(
+    State(state): State<Arc<AppState>>,
+    Path(tender_id): Path<Uuid>,
+    Query(query): Query<RecommendedSubsQuery>,
+    auth: RequireGc,
+) -> Result<impl IntoResponse, ApiError> {
+    let tender = fetch_owned_tender(&state, tender_id, auth.user_id).await?;
+    let trade = tender.trade_category.replace('_', " ");
+
+    let rows = sqlx::query_as::<_, RecommendationRow>(&format!(
+        r#"
+        SELECT {},
+            s.trade ILIKE '%' || $2 || '%' as trade_match,
+            (EXISTS (SELECT 1 FROM jsonb_array_elements_text(s.secondary_trades) st WHERE st ILIKE '%' || $2 || '%')
+             OR EXISTS (SELECT 1 FROM unnest(s.specialties) sp WHERE sp ILIKE '%' || $2 || '%')) as related_trade_match,
+            ($3::text IS NOT NULL AND (s.location ILIKE '%' || $3 || '%'
+             OR EXISTS (SELECT 1 FROM jsonb_array_elements_text(COALESCE(s.service_areas, '[]'::jsonb)) sa
+                        WHERE $3 ILIKE '%' || sa || '%' OR sa ILIKE '%' || $3 || '%'))) as location_match,
+            ($4::numeric IS NOT NULL
+             AND (s.min_project_value IS NULL OR s.min_project_value <= $4)
+             AND (s.max_project_value IS NULL OR s.max_project_value >= $4)) as value_fit
+        FROM subcontractors s {}
+        WHERE COALESCE(s.availability_status, 'available') != 'not_taking_work'
+        AND (s.trade ILIKE '%' || $2 || '%'
+             OR EXISTS (SELECT 1 FROM jsonb_array_elements_text(s.secondary_trades) st WHERE st ILIKE '%' || $2 || '%')
+             OR EXISTS (SELECT 1 FROM unnest(s.specialties) sp WHERE sp ILIKE '%' || $2 || '%'))
+        AND NOT EXISTS (
+            SELECT 1 FROM bids b
+            WHERE b.tender_id = $1 AND (b.subcontractor_id = s.id OR b.bidder_id = s.profile_id)
+        )
+        AND NOT EXISTS (
+            SELECT 1 FROM tenders t
+            WHERE t.id = $1 AND s.id = ANY(COALESCE(t.invited_subcontractors, '{{}}'))
+        )
+        ORDER BY s.rating DESC NULLS LAST, s.id
+        LIMIT $5
+        "#,
+        MARKETPLACE_SUB_COLUMNS, MARKETPLACE_SUB_REVIEWS_JOIN
+    ))
+    .bind(tender.id)
+    .bind(&trade)
+    .bind(&tender.location)
+    .bind(tender.estimated_value)
+    .bind(RECOMMENDATION_CANDIDATES)
+    .fetch_all(&state.db)
+    .await
+    .map_err(map_db_err("recommend_subs_for_tender"))?;
+
+    let mut data: Vec<RecommendedSubcontractor> = rows
+        .into_iter()
+        .map(|row| score_recommendation(row, &tender, &trade, &state.storage))
+        .collect();
+    data.sort_by(|a, b| {
+        b.match_score
+            .cmp(&a.match_score)
+            .then_with(|| b.subcontractor.rating.cmp(&a.subcontractor.rating))
+    });
+    data.truncate(query.limit() as usize);
 
-    Ok(Json(serde_json::json!({ "success": true })))
+    Ok(Json(DataResponse::new(data)))
 }
 
-/// DELETE /api/marketplace/profile/portfolio/:id
+/// POST /api/tenders/:tender_id/invitations
 ///
-/// Delete a portfolio project.
-pub async fn delete_portfolio_project(
+/// Invite a marketplace subcontractor to bid on a tender and notify them.
+#[utoipa::path(
+    post,
+    path = "/tenders/{tender_id}/invitations",
+    tag = "marketplace",
+    params(("tender_id" = Uuid, Path, description = "Tender id")),
+    request_body = InviteSubcontractorRequest,
+    responses(
+        (status = 200, description = "Subcontractor invited", body = MessageResponse),
+        (status = 400, description = "Tender is not open for invitations", body = ErrorResponse),
+        (status = 404, description = "Tender or subcontractor not found", body = ErrorResponse),
+        (status = 409, description = "Subcontractor already invited", body = ErrorResponse),
+        (status = 403, description = "Caller is not a general contractor", body = ErrorResponse),
+    )
+)]
+pub async fn invite_subcontractor_to_tender(
     State(state): State<Arc<AppState>>,
-    Path(project_id): Path<Uuid>,
-    auth: RequireAuth,
+    Path(tender_id): Path<Uuid>,
+    auth: RequireGc,
+    Json(input): Json<InviteSubcontractorRequest>,
 ) -> Result<impl IntoResponse, ApiError> {
-    let user_id = auth.user_id;
+    let tender = fetch_owned_tender(&state, tender_id, auth.user_id).await?;
+    if !matches!(tender.status.as_str(), "draft" | "open") {
+        return Err(ApiError::bad_request(format!(
+            "Cannot invite subcontractors to a {} tender",
+            tender.status
+        )));
+    }
+
+    let sub_profile_id: Option<Uuid> =
+        sqlx::query_scalar("SELECT profile_id FROM subcontractors WHERE id = $1")
+            .bind(input.subcontractor_id)
+            .fetch_optional(&state.db)
+            .await
+            .map_err(map_db_err("invite_subcontractor_to_tender"))?
+            .ok_or_else(|| ApiError::not_found("Subcontractor not found"))?;
 
     let result = sqlx::query(
         r#"
-        DELETE FROM portfolio_projects 
-        WHERE id = $1 AND subcontractor_id IN (
-            SELECT id FROM subcontractors WHERE profile_id = $2
-        )
+        UPDATE tenders
+        SET invited_subcontractors = array_append(COALESCE(invited_subcontractors, '{}'), $2),
+            updated_at = NOW()
+        WHERE id = $1 AND NOT ($2 = ANY(COALESCE(invited_subcontractors, '{}')))
         "#,
     )
-    .bind(project_id)
-    .bind(user_id)
+    .bind(tender_id)
+    .bind(input.subcontractor_id)
     .execute(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("invite_subcontractor_to_tender"))?;
 
     if result.rows_affected() == 0 {
-        return Err(ApiError::not_found("Portfolio project not found"));
+        return Err(ApiError::conflict("Subcontractor has already been invited"));
+    }
+
+    if let Some(sub_user_id) = sub_profile_id {
+        if let Err(e) = notifications::notify_tender_invitation(
+            &state.db,
+            sub_user_id,
+            tender_id,
+            &tender.name,
+            tender.gc_company_name.as_deref(),
+        )
+        .await
+        {
+            tracing::warn!(error = %e, tender_id = %tender_id, "Failed to send tender invitation notification");
+        }
+    }
+
+    Ok(MessageResponse::new("Subcontractor invited"))
+}
+
+/// Load a tender owned (via its project) by the given GC
+async fn fetch_owned_tender(
+    state: &AppState,
+    tender_id: Uuid,
+    user_id: Uuid,
+) -> Result<OwnedTenderRow, ApiError> {
+    sqlx::query_as::<_, OwnedTenderRow>(
+        r#"
+        SELECT t.id, t.name, t.trade_category,
+               COALESCE(t.location, NULLIF(concat_ws(', ', p.city, p.state), '')) as location,
+               t.estimated_value, t.status,
+               COALESCE(t.gc_company_name, pr.company_name) as gc_company_name
+        FROM tenders t
+        JOIN projects p ON t.project_id = p.id
+        JOIN profiles pr ON p.owner_id = pr.id
+        WHERE t.id = $1 AND p.owner_id = $2
+        "#,
+    )
+    .bind(tender_id)
+    .bind(user_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("fetch_owned_tender"))?
+    .ok_or_else(|| ApiError::not_found("Tender not found"))
+}
+
+/// Score a candidate out of 100: trade 35, location 20, rating 15,
+/// project value 15, availability 10, verification 5.
+fn score_recommendation(
+    row: RecommendationRow,
+    tender: &OwnedTenderRow,
+    trade: &str,
+    storage: &ObjectStorage,
+) -> RecommendedSubcontractor {
+    let sub = row.sub.into_profile(storage);
+    let mut score = 0u32;
+    let mut reasons = Vec::new();
+
+    if row.trade_match {
+        score += 35;
+        reasons.push(format!("Primary trade matches {}", trade));
+    } else if row.related_trade_match {
+        score += 20;
+        reasons.push(format!("Lists {} as a secondary trade or specialty", trade));
+    }
+
+    if row.location_match {
+        score += 20;
+        if let Some(location) = &tender.location {
+            reasons.push(format!("Located in or serves {}", location));
+        }
+    }
+
+    let rating = sub.rating.value();
+    score += (rating / 5.0 * 15.0).round() as u32;
+    if rating >= 4.0 && sub.review_count > 0 {
+        reasons.push(format!("Rated {:.1} from {} reviews", rating, sub.review_count));
+    }
+
+    if row.value_fit {
+        score += 15;
+        reasons.push("Project value fits their typical range".to_string());
+    }
+
+    match AvailabilityStatus::from(sub.availability_status.clone()) {
+        AvailabilityStatus::Available => {
+            score += 10;
+            reasons.push("Currently available".to_string());
+        }
+        AvailabilityStatus::Busy => score += 4,
+        AvailabilityStatus::NotTakingWork => {}
     }
 
-    Ok(Json(serde_json::json!({ "success": true })))
+    if sub.verified {
+        score += 5;
+        reasons.push("Verified business".to_string());
+    }
+
+    RecommendedSubcontractor {
+        subcontractor: sub,
+        match_score: score.min(100),
+        match_reasons: reasons,
+    }
 }
 
 // ============================================================================
@@ -809,6 +1806,14 @@ pub async fn delete_portfolio_project(
 /// GET /api/marketplace/saved-searches
 ///
 /// List user's saved searches.
+#[utoipa::path(
+    get,
+    path = "/marketplace/saved-searches",
+    tag = "marketplace",
+    responses(
+        (status = 200, description = "The caller's saved searches", body = SavedSearchListData),
+    )
+)]
 pub async fn list_saved_searches(
     State(state): State<Arc<AppState>>,
     auth: RequireAuth,
@@ -827,7 +1832,7 @@ pub async fn list_saved_searches(
     .bind(user_id)
     .fetch_all(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("list_saved_searches"))?;
 
     let data: Vec<SavedSearchResponse> = rows.into_iter().map(Into::into).collect();
 
@@ -837,6 +1842,16 @@ pub async fn list_saved_searches(
 /// POST /api/marketplace/saved-searches
 ///
 /// Create a new saved search.
+#[utoipa::path(
+    post,
+    path = "/marketplace/saved-searches",
+    tag = "marketplace",
+    request_body = CreateSavedSearchRequest,
+    responses(
+        (status = 201, description = "Saved search created", body = SavedSearchData),
+        (status = 400, description = "Invalid search", body = ErrorResponse),
+    )
+)]
 pub async fn create_saved_search(
     State(state): State<Arc<AppState>>,
     auth: RequireAuth,
@@ -845,10 +1860,12 @@ pub async fn create_saved_search(
     let user_id = auth.user_id;
     let id = Uuid::new_v4();
 
-    sqlx::query(
+    let row = sqlx::query_as::<_, SavedSearch>(
         r#"
         INSERT INTO saved_searches (id, user_id, name, search_type, filters, notify_new_matches)
         VALUES ($1, $2, $3, $4, $5, $6)
+        RETURNING id, user_id, name, search_type, filters, notify_new_matches,
+                  last_run_at, created_at, updated_at
         "#,
     )
     .bind(id)
@@ -857,16 +1874,26 @@ pub async fn create_saved_search(
     .bind(&input.search_type)
     .bind(&input.filters)
     .bind(input.notify_new_matches)
-    .execute(&state.db)
+    .fetch_one(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Failed to create saved search: {}", e)))?;
+    .map_err(map_db_err("create_saved_search"))?;
 
-    Ok(Json(serde_json::json!({ "id": id, "success": true })))
+    Ok(Created(SavedSearchResponse::from(row)))
 }
 
 /// DELETE /api/marketplace/saved-searches/:id
 ///
 /// Delete a saved search.
+#[utoipa::path(
+    delete,
+    path = "/marketplace/saved-searches/{search_id}",
+    tag = "marketplace",
+    params(("search_id" = Uuid, Path, description = "Saved search id")),
+    responses(
+        (status = 204, description = "Saved search deleted"),
+        (status = 404, description = "Saved search not found", body = ErrorResponse),
+    )
+)]
 pub async fn delete_saved_search(
     State(state): State<Arc<AppState>>,
     Path(search_id): Path<Uuid>,
@@ -879,13 +1906,13 @@ pub async fn delete_saved_search(
         .bind(user_id)
         .execute(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+        .map_err(map_db_err("delete_saved_search"))?;
 
     if result.rows_affected() == 0 {
         return Err(ApiError::not_found("Saved search not found"));
     }
 
-    Ok(Json(serde_json::json!({ "success": true })))
+    Ok(NoContent)
 }
 
 // ============================================================================
@@ -895,58 +1922,43 @@ pub async fn delete_saved_search(
 /// GET /api/marketplace/tenders
 ///
 /// List open tenders for subcontractors to browse and bid on.
+#[utoipa::path(
+    get,
+    path = "/marketplace/tenders",
+    tag = "marketplace",
+    params(PaginationParams, MarketplaceTenderQuery, GeoQuery, FieldsQuery),
+    responses(
+        (status = 200, description = "Open tenders", body = MarketplaceTenderPage),
+        (status = 400, description = "Invalid filter, location or sort", body = ErrorResponse),
+    )
+)]
 pub async fn list_marketplace_tenders(
     State(state): State<Arc<AppState>>,
     Query(query): Query<MarketplaceTenderQueryParams>,
+    Query(geo): Query<GeoQuery>,
+    Query(fields): Query<FieldsQuery>,
     auth: RequireAuth,
 ) -> Result<impl IntoResponse, ApiError> {
     let user_id = auth.user_id;
-    let page = query.pagination.page.unwrap_or(1).max(1);
-    let per_page = query.pagination.per_page.unwrap_or(20).min(100);
-    let offset = ((page - 1) * per_page) as i64;
+    let (near_lat, near_lng, radius_m) = parse_geo_query(&geo)?;
+    let page = resolve_pagination(&query.pagination, state.settings.pagination.marketplace)?;
+    let fields = FieldSet::parse(MarketplaceTender::FIELDS, fields.fields.as_deref())?;
 
     // Get sub_id if user is a subcontractor (to show their bids)
     let sub_id: Option<Uuid> = sqlx::query_scalar("SELECT id FROM subcontractors WHERE profile_id = $1")
         .bind(user_id)
         .fetch_optional(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+        .map_err(map_db_err("list_marketplace_tenders"))?;
 
-    // Count
-    let total: i64 = sqlx::query_scalar(
-        r#"
-        SELECT COUNT(*) FROM tenders t
-        JOIN projects p ON t.project_id = p.id
-        WHERE t.status = 'open'
-        AND t.visibility = 'public'
-        AND (t.bid_due_date IS NULL OR t.bid_due_date > NOW())
-        AND ($1::text IS NULL OR t.trade_category ILIKE '%' || $1 || '%')
-        AND ($2::text IS NULL OR t.location ILIKE '%' || $2 || '%' OR p.location ILIKE '%' || $2 || '%')
-        AND ($3::text IS NULL OR t.name ILIKE '%' || $3 || '%' OR t.description ILIKE '%' || $3 || '%')
-        AND ($4::bigint IS NULL OR t.estimated_value >= $4)
-        AND ($5::bigint IS NULL OR t.estimated_value <= $5)
-        "#,
-    )
-    .bind(&query.filter.trade)
-    .bind(&query.filter.location)
-    .bind(&query.filter.search)
-    .bind(query.filter.min_value)
-    .bind(query.filter.max_value)
-    .fetch_one(&state.db)
-    .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
-
-    // Determine sort
-    let order_by = match query.filter.sort_by.as_deref() {
-        Some("due_date") => "t.bid_due_date",
-        Some("value") => "t.estimated_value",
-        Some("newest") => "t.created_at",
-        _ => "t.bid_due_date",
-    };
-    let order_dir = match query.filter.sort_order.as_deref() {
-        Some("desc") => "DESC",
-        _ => "ASC",
-    };
+    let sort = SortSpec::parse(
+        TENDER_SORT_FIELDS,
+        SortOrder::Asc,
+        query.filter.sort_by.as_deref(),
+        query.filter.sort_order.as_deref(),
+    )?;
+    // Distance search returns the nearest results first
+    let distance_order = if near_lat.is_some() { "distance_km ASC NULLS LAST, " } else { "" };
 
     // Optimized query with LEFT JOIN to avoid N+1 for user's bid
     let query_str = format!(
@@ -955,12 +1967,14 @@ pub async fn list_marketplace_tenders(
             t.id, t.project_id, p.name as project_name, 
             pr.company_name as gc_company_name,
             t.name, t.description, t.trade_category, t.scope_of_work,
-            COALESCE(t.location, p.location) as location,
+            {} as location,
             t.status, COALESCE(t.visibility, 'public') as visibility,
             t.bid_due_date, t.estimated_value, t.reserve_price,
             COALESCE(t.requirements, '{{}}'::jsonb) as requirements,
             (SELECT COUNT(*) FROM bids b WHERE b.tender_id = t.id) as bids_received,
             t.priority, t.created_at,
+            {} as distance_km,
+            {},
             -- User's bid info via LEFT JOIN (avoids N+1)
             my_bid.id as my_bid_id,
             my_bid.bid_amount as my_bid_amount,
@@ -974,37 +1988,79 @@ pub async fn list_marketplace_tenders(
         AND t.visibility = 'public'
         AND (t.bid_due_date IS NULL OR t.bid_due_date > NOW())
         AND ($1::text IS NULL OR t.trade_category ILIKE '%' || $1 || '%')
-        AND ($2::text IS NULL OR t.location ILIKE '%' || $2 || '%' OR p.location ILIKE '%' || $2 || '%')
+        AND {}
         AND ($3::text IS NULL OR t.name ILIKE '%' || $3 || '%' OR t.description ILIKE '%' || $3 || '%')
-        AND ($4::bigint IS NULL OR t.estimated_value >= $4)
-        AND ($5::bigint IS NULL OR t.estimated_value <= $5)
-        ORDER BY {} {} NULLS LAST
+        AND ($4::numeric IS NULL OR t.estimated_value >= $4)
+        AND ($5::numeric IS NULL OR t.estimated_value <= $5)
+        ORDER BY {}{} NULLS LAST, t.id
         LIMIT $6 OFFSET $7
         "#,
-        order_by, order_dir
+        TENDER_LOCATION_SQL,
+        geo_distance_sql("t", 9, 10),
+        TOTAL_COUNT_COLUMN,
+        geo_location_filter("t", TENDER_LOCATION_SQL, 2, 9, 10, 11),
+        distance_order,
+        sort
     );
 
-    let rows = sqlx::query_as::<_, TenderRow>(&query_str)
+    let rows = sqlx::query_as::<_, Counted<TenderRow>>(&query_str)
         .bind(&query.filter.trade)
         .bind(&query.filter.location)
         .bind(&query.filter.search)
         .bind(query.filter.min_value)
         .bind(query.filter.max_value)
-        .bind(per_page as i64)
-        .bind(offset)
+        .bind(page.limit())
+        .bind(page.offset())
         .bind(sub_id) // $8 for LEFT JOIN on user's bid
+        .bind(near_lat)
+        .bind(near_lng)
+        .bind(radius_m)
         .fetch_all(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+        .map_err(map_db_err("list_marketplace_tenders"))?;
+
+    let total = match page.counted_total(&rows) {
+        Some(total) => total,
+        // Past the last page: count separately
+        None => {
+            let total: i64 = sqlx::query_scalar(&format!(
+                r#"
+                SELECT COUNT(*) FROM tenders t
+                JOIN projects p ON t.project_id = p.id
+                WHERE t.status = 'open'
+                AND t.visibility = 'public'
+                AND (t.bid_due_date IS NULL OR t.bid_due_date > NOW())
+                AND ($1::text IS NULL OR t.trade_category ILIKE '%' || $1 || '%')
+                AND {}
+                AND ($3::text IS NULL OR t.name ILIKE '%' || $3 || '%' OR t.description ILIKE '%' || $3 || '%')
+                AND ($4::numeric IS NULL OR t.estimated_value >= $4)
+                AND ($5::numeric IS NULL OR t.estimated_value <= $5)
+                "#,
+                geo_location_filter("t", TENDER_LOCATION_SQL, 2, 6, 7, 8)
+            ))
+            .bind(&query.filter.trade)
+            .bind(&query.filter.location)
+            .bind(&query.filter.search)
+            .bind(query.filter.min_value)
+            .bind(query.filter.max_value)
+            .bind(near_lat)
+            .bind(near_lng)
+            .bind(radius_m)
+            .fetch_one(&state.db)
+            .await
+            .map_err(map_db_err("list_marketplace_tenders"))?;
+            total as u64
+        }
+    };
 
     // Map rows to response - bid info already included via LEFT JOIN (no N+1!)
     let data: Vec<MarketplaceTender> = rows
         .into_iter()
-        .map(|r| {
+        .map(|Counted { row: r, .. }| {
             // Extract user's bid from the LEFT JOIN columns
             let my_bid = r.my_bid_id.map(|id| MarketplaceBidSummary {
                 id,
-                bid_amount: r.my_bid_amount.map(decimal_to_i64).unwrap_or(0),
+                bid_amount: r.my_bid_amount.unwrap_or_default(),
                 status: r.my_bid_status.unwrap_or_default(),
                 submitted_at: r.my_bid_submitted_at,
             });
@@ -1027,30 +2083,29 @@ pub async fn list_marketplace_tenders(
                 requirements: r.requirements,
                 bids_received: r.bids_received as i32,
                 priority: r.priority,
+                distance_km: r.distance_km,
                 created_at: r.created_at,
                 my_bid,
             }
         })
         .collect();
 
-    let total_pages = ((total as f64) / (per_page as f64)).ceil() as u32;
-
-    Ok(Json(Paginated {
-        data,
-        pagination: PaginationMeta {
-            page,
-            per_page,
-            total_items: total as u64,
-            total_pages,
-            has_next: page < total_pages,
-            has_prev: page > 1,
-        },
-    }))
+    fields.paginated(data, &page, total)
 }
 
 /// GET /api/marketplace/tenders/:id
 ///
 /// Get a specific tender for bidding.
+#[utoipa::path(
+    get,
+    path = "/marketplace/tenders/{tender_id}",
+    tag = "marketplace",
+    params(("tender_id" = Uuid, Path, description = "Tender id")),
+    responses(
+        (status = 200, description = "Tender details", body = MarketplaceTenderData),
+        (status = 404, description = "Tender not found", body = ErrorResponse),
+    )
+)]
 pub async fn get_marketplace_tender(
     State(state): State<Arc<AppState>>,
     Path(tender_id): Path<Uuid>,
@@ -1063,7 +2118,7 @@ pub async fn get_marketplace_tender(
         .bind(user_id)
         .fetch_optional(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+        .map_err(map_db_err("get_marketplace_tender"))?;
 
     let row = sqlx::query_as::<_, TenderRow>(
         r#"
@@ -1071,7 +2126,7 @@ pub async fn get_marketplace_tender(
             t.id, t.project_id, p.name as project_name, 
             pr.company_name as gc_company_name,
             t.name, t.description, t.trade_category, t.scope_of_work,
-            COALESCE(t.location, p.location) as location,
+            COALESCE(t.location, NULLIF(concat_ws(', ', p.city, p.state), '')) as location,
             t.status, COALESCE(t.visibility, 'public') as visibility,
             t.bid_due_date, t.estimated_value, t.reserve_price,
             COALESCE(t.requirements, '{}'::jsonb) as requirements,
@@ -1086,7 +2141,7 @@ pub async fn get_marketplace_tender(
     .bind(tender_id)
     .fetch_optional(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
+    .map_err(map_db_err("get_marketplace_tender"))?
     .ok_or_else(|| ApiError::not_found("Tender not found"))?;
 
     // Check visibility
@@ -1097,7 +2152,7 @@ pub async fn get_marketplace_tender(
 
     // Get user's bid if they have one
     let my_bid = if let Some(sid) = sub_id {
-        sqlx::query_as::<_, (Uuid, sqlx::types::Decimal, String, Option<DateTime<Utc>>)>(
+        sqlx::query_as::<_, (Uuid, Money, String, Option<DateTime<Utc>>)>(
             r#"
             SELECT id, bid_amount, status, submitted_at
             FROM bids
@@ -1112,7 +2167,7 @@ pub async fn get_marketplace_tender(
         .flatten()
         .map(|(id, amount, status, submitted_at)| MarketplaceBidSummary {
             id,
-            bid_amount: decimal_to_i64(amount),
+            bid_amount: amount,
             status,
             submitted_at,
         })
@@ -1138,6 +2193,7 @@ pub async fn get_marketplace_tender(
         requirements: row.requirements,
         bids_received: row.bids_received as i32,
         priority: row.priority,
+        distance_km: row.distance_km,
         created_at: row.created_at,
         my_bid,
     };
@@ -1149,13 +2205,71 @@ pub async fn get_marketplace_tender(
 // Bidding
 // ============================================================================
 
+/// Tender fields the bid write paths check and notify with
+#[derive(Debug, sqlx::FromRow)]
+pub(crate) struct BiddableTender {
+    status: String,
+    bid_due_date: Option<DateTime<Utc>>,
+    pub(crate) owner_id: Uuid,
+    pub(crate) name: String,
+}
+
+pub(crate) async fn fetch_biddable_tender(state: &AppState, tender_id: Uuid) -> Result<BiddableTender, ApiError> {
+    sqlx::query_as::<_, BiddableTender>(
+        r#"
+        SELECT t.status, t.bid_due_date, p.owner_id, t.name
+        FROM tenders t
+        JOIN projects p ON t.project_id = p.id
+        WHERE t.id = $1
+        "#,
+    )
+    .bind(tender_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("fetch_biddable_tender"))?
+    .ok_or_else(|| ApiError::not_found("Tender not found"))
+}
+
+/// Bids can be submitted, edited, withdrawn or restored only while the tender
+/// is open and its due date is still in the future. This matches the
+/// marketplace listing filter, so an expired tender the background closer
+/// hasn't reached yet is treated as closed.
+pub(crate) fn assert_tender_biddable(tender: &BiddableTender) -> Result<(), ApiError> {
+    assert_tender_biddable_at(tender, Utc::now())
+}
+
+fn assert_tender_biddable_at(tender: &BiddableTender, now: DateTime<Utc>) -> Result<(), ApiError> {
+    if tender.status != "open" {
+        return Err(ApiError::bad_request("This tender is no longer accepting bids"));
+    }
+
+    if tender.bid_due_date.is_some_and(|due| due <= now) {
+        return Err(ApiError::bad_request("The bid deadline has passed"));
+    }
+
+    Ok(())
+}
+
 /// POST /api/marketplace/tenders/:id/bid
 ///
 /// Submit a bid on a tender.
+#[utoipa::path(
+    post,
+    path = "/marketplace/tenders/{tender_id}/bid",
+    tag = "marketplace",
+    params(("tender_id" = Uuid, Path, description = "Tender id")),
+    request_body = SubmitBidRequest,
+    responses(
+        (status = 201, description = "Bid submitted", body = CreatedIdData),
+        (status = 400, description = "Tender closed, deadline passed, or already bid", body = ErrorResponse),
+        (status = 404, description = "Tender not found", body = ErrorResponse),
+        (status = 403, description = "Caller is not a subcontractor", body = ErrorResponse),
+    )
+)]
 pub async fn submit_bid(
     State(state): State<Arc<AppState>>,
     Path(tender_id): Path<Uuid>,
-    auth: RequireAuth,
+    auth: RequireSub,
     Json(input): Json<SubmitBidRequest>,
 ) -> Result<impl IntoResponse, ApiError> {
     let user_id = auth.user_id;
@@ -1165,38 +2279,28 @@ pub async fn submit_bid(
         .bind(user_id)
         .fetch_optional(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+        .map_err(map_db_err("submit_bid"))?;
 
     let sub_id = sub_id.ok_or_else(|| {
         ApiError::forbidden("You need a subcontractor profile to submit bids")
     })?;
 
-    // Check if tender exists and is open
-    let tender: Option<(String, Option<DateTime<Utc>>, Uuid, String)> = sqlx::query_as(
-        r#"
-        SELECT t.status, t.bid_due_date, p.owner_id, t.name
-        FROM tenders t
-        JOIN projects p ON t.project_id = p.id
-        WHERE t.id = $1
-        "#,
-    )
-    .bind(tender_id)
-    .fetch_optional(&state.db)
-    .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
-
-    let (status, due_date, gc_user_id, tender_name) = tender
-        .ok_or_else(|| ApiError::not_found("Tender not found"))?;
+    let tender = fetch_biddable_tender(&state, tender_id).await?;
+    assert_tender_biddable(&tender)?;
 
-    if status != "open" {
-        return Err(ApiError::bad_request("This tender is no longer accepting bids"));
-    }
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(map_db_err("submit_bid"))?;
 
-    if let Some(due) = due_date {
-        if due < Utc::now() {
-            return Err(ApiError::bad_request("The bid deadline has passed"));
-        }
-    }
+    // Lock the tender so concurrent submissions by the same sub serialize on
+    // the existing-bid check
+    sqlx::query("SELECT id FROM tenders WHERE id = $1 FOR UPDATE")
+        .bind(tender_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(map_db_err("submit_bid"))?;
 
     // Check for existing bid
     let existing_bid: Option<Uuid> = sqlx::query_scalar(
@@ -1204,9 +2308,9 @@ pub async fn submit_bid(
     )
     .bind(tender_id)
     .bind(sub_id)
-    .fetch_optional(&state.db)
+    .fetch_optional(&mut *tx)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("submit_bid"))?;
 
     if existing_bid.is_some() {
         return Err(ApiError::bad_request("You have already submitted a bid. Use PUT to update it."));
@@ -1233,11 +2337,16 @@ pub async fn submit_bid(
     .bind(input.proposed_start_date)
     .bind(&input.cover_letter)
     .bind(&input.notes)
-    .execute(&state.db)
+    .execute(&mut *tx)
     .await
-    .map_err(|e| ApiError::internal(format!("Failed to submit bid: {}", e)))?;
+    .map_err(map_db_err("submit_bid"))?;
+
+    tx.commit()
+        .await
+        .map_err(map_db_err("submit_bid"))?;
 
-    // Get subcontractor name for notification
+    // Notifications go out after commit; a failure is logged and doesn't
+    // undo the bid. Get subcontractor name for notification
     let sub_name: Option<String> = sqlx::query_scalar("SELECT name FROM subcontractors WHERE id = $1")
         .bind(sub_id)
         .fetch_optional(&state.db)
@@ -1248,27 +2357,56 @@ pub async fn submit_bid(
     // Notify GC about new bid
     if let Err(e) = notifications::notify_bid_received(
         &state.db,
-        gc_user_id,
+        tender.owner_id,
         tender_id,
-        &tender_name,
+        id,
+        &tender.name,
         &sub_name.unwrap_or_else(|| "A subcontractor".to_string()),
-        input.bid_amount as f64 / 100.0, // Convert cents to dollars
+        input.bid_amount,
     )
     .await
     {
         tracing::warn!(error = %e, "Failed to create bid notification");
     }
 
-    Ok(Json(serde_json::json!({ "id": id, "success": true })))
+    let watchers_notified = async {
+        let recipients = watchers::recipients(
+            &state.db,
+            WatchedResource::Tender,
+            tender_id,
+            &[],
+            &[tender.owner_id, user_id],
+        )
+        .await?;
+        notifications::notify_tender_bid_placed(&state.db, &recipients, tender_id, &tender.name).await
+    };
+    if let Err(e) = watchers_notified.await {
+        tracing::warn!(error = %e, tender_id = %tender_id, "Failed to notify tender watchers of bid");
+    }
+
+    Ok(Created::id(id))
 }
 
 /// PUT /api/marketplace/tenders/:id/bid
 ///
-/// Update an existing bid.
+/// Update an existing bid. Bids can only be edited until the tender's due date.
+#[utoipa::path(
+    put,
+    path = "/marketplace/tenders/{tender_id}/bid",
+    tag = "marketplace",
+    params(("tender_id" = Uuid, Path, description = "Tender id")),
+    request_body = SubmitBidRequest,
+    responses(
+        (status = 200, description = "Bid updated", body = MessageResponse),
+        (status = 400, description = "Tender closed or deadline passed", body = ErrorResponse),
+        (status = 404, description = "Bid not found or cannot be updated", body = ErrorResponse),
+        (status = 403, description = "Caller is not a subcontractor", body = ErrorResponse),
+    )
+)]
 pub async fn update_bid(
     State(state): State<Arc<AppState>>,
     Path(tender_id): Path<Uuid>,
-    auth: RequireAuth,
+    auth: RequireSub,
     Json(input): Json<SubmitBidRequest>,
 ) -> Result<impl IntoResponse, ApiError> {
     let user_id = auth.user_id;
@@ -1277,20 +2415,12 @@ pub async fn update_bid(
         .bind(user_id)
         .fetch_optional(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+        .map_err(map_db_err("update_bid"))?;
 
     let sub_id = sub_id.ok_or_else(|| ApiError::forbidden("No subcontractor profile found"))?;
 
-    // Check tender is still open
-    let status: Option<String> = sqlx::query_scalar("SELECT status FROM tenders WHERE id = $1")
-        .bind(tender_id)
-        .fetch_optional(&state.db)
-        .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
-
-    if status.as_deref() != Some("open") {
-        return Err(ApiError::bad_request("This tender is no longer accepting bids"));
-    }
+    let tender = fetch_biddable_tender(&state, tender_id).await?;
+    assert_tender_biddable(&tender)?;
 
     let breakdown = input.breakdown.map(|b| serde_json::to_value(b).unwrap_or_default());
 
@@ -1317,22 +2447,35 @@ pub async fn update_bid(
     .bind(sub_id)
     .execute(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Failed to update bid: {}", e)))?;
+    .map_err(map_db_err("update_bid"))?;
 
     if result.rows_affected() == 0 {
         return Err(ApiError::not_found("Bid not found or cannot be updated"));
     }
 
-    Ok(Json(serde_json::json!({ "success": true })))
+    Ok(MessageResponse::new("Bid updated"))
 }
 
 /// DELETE /api/marketplace/tenders/:id/bid
 ///
-/// Withdraw a bid.
+/// Withdraw a bid. Bids can only be withdrawn until the tender's due date.
+/// The bid's attachments are deleted; attach them again after a restore.
+#[utoipa::path(
+    delete,
+    path = "/marketplace/tenders/{tender_id}/bid",
+    tag = "marketplace",
+    params(("tender_id" = Uuid, Path, description = "Tender id")),
+    responses(
+        (status = 200, description = "Bid withdrawn", body = MessageResponse),
+        (status = 400, description = "Tender closed or deadline passed", body = ErrorResponse),
+        (status = 404, description = "Bid not found or already processed", body = ErrorResponse),
+        (status = 403, description = "Caller is not a subcontractor", body = ErrorResponse),
+    )
+)]
 pub async fn withdraw_bid(
     State(state): State<Arc<AppState>>,
     Path(tender_id): Path<Uuid>,
-    auth: RequireAuth,
+    auth: RequireSub,
 ) -> Result<impl IntoResponse, ApiError> {
     let user_id = auth.user_id;
 
@@ -1340,24 +2483,104 @@ pub async fn withdraw_bid(
         .bind(user_id)
         .fetch_optional(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+        .map_err(map_db_err("withdraw_bid"))?;
 
     let sub_id = sub_id.ok_or_else(|| ApiError::forbidden("No subcontractor profile found"))?;
 
-    let result = sqlx::query(
-        "UPDATE bids SET status = 'withdrawn', updated_at = NOW() WHERE tender_id = $1 AND subcontractor_id = $2 AND status = 'submitted'",
+    let tender = fetch_biddable_tender(&state, tender_id).await?;
+    assert_tender_biddable(&tender)?;
+
+    let db_err = map_db_err("withdraw_bid");
+    let mut tx = state.db.begin().await.map_err(db_err)?;
+
+    let bid_id: Option<Uuid> = sqlx::query_scalar(
+        "UPDATE bids SET status = 'withdrawn', updated_at = NOW() WHERE tender_id = $1 AND subcontractor_id = $2 AND status = 'submitted' RETURNING id",
     )
     .bind(tender_id)
     .bind(sub_id)
-    .execute(&state.db)
+    .fetch_optional(&mut *tx)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(db_err)?;
 
-    if result.rows_affected() == 0 {
-        return Err(ApiError::not_found("Bid not found or already processed"));
+    let bid_id = bid_id.ok_or_else(|| ApiError::not_found("Bid not found or already processed"))?;
+
+    let attachment_paths: Vec<String> =
+        sqlx::query_scalar("DELETE FROM bid_attachments WHERE bid_id = $1 RETURNING file_path")
+            .bind(bid_id)
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(db_err)?;
+
+    tx.commit().await.map_err(db_err)?;
+
+    file_deletions::delete_queued_now(&state.db, &attachment_paths).await;
+
+    Ok(MessageResponse::new("Bid withdrawn"))
+}
+
+/// POST /api/marketplace/tenders/:id/bid/restore
+///
+/// Restore a withdrawn bid while the tender is still open and before its due date.
+#[utoipa::path(
+    post,
+    path = "/marketplace/tenders/{tender_id}/bid/restore",
+    tag = "marketplace",
+    params(("tender_id" = Uuid, Path, description = "Tender id")),
+    responses(
+        (status = 200, description = "Bid restored", body = MessageResponse),
+        (status = 400, description = "Tender closed or deadline passed", body = ErrorResponse),
+        (status = 404, description = "Tender or withdrawn bid not found", body = ErrorResponse),
+        (status = 403, description = "Caller is not a subcontractor", body = ErrorResponse),
+    )
+)]
+pub async fn restore_bid(
+    State(state): State<Arc<AppState>>,
+    Path(tender_id): Path<Uuid>,
+    auth: RequireSub,
+) -> Result<impl IntoResponse, ApiError> {
+    let user_id = auth.user_id;
+
+    let sub: Option<(Uuid, String)> =
+        sqlx::query_as("SELECT id, name FROM subcontractors WHERE profile_id = $1")
+            .bind(user_id)
+            .fetch_optional(&state.db)
+            .await
+            .map_err(map_db_err("restore_bid"))?;
+
+    let (sub_id, sub_name) = sub.ok_or_else(|| ApiError::forbidden("No subcontractor profile found"))?;
+
+    let tender = fetch_biddable_tender(&state, tender_id).await?;
+    assert_tender_biddable(&tender)?;
+
+    let bid_id: Option<Uuid> = sqlx::query_scalar(
+        r#"
+        UPDATE bids SET status = 'submitted', updated_at = NOW()
+        WHERE tender_id = $1 AND subcontractor_id = $2 AND status = 'withdrawn'
+        RETURNING id
+        "#,
+    )
+    .bind(tender_id)
+    .bind(sub_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("restore_bid"))?;
+
+    let bid_id = bid_id.ok_or_else(|| ApiError::not_found("No withdrawn bid to restore"))?;
+
+    if let Err(e) = notifications::notify_bid_restored(
+        &state.db,
+        tender.owner_id,
+        tender_id,
+        bid_id,
+        &tender.name,
+        &sub_name,
+    )
+    .await
+    {
+        tracing::warn!(error = %e, "Failed to create bid restored notification");
     }
 
-    Ok(Json(serde_json::json!({ "success": true })))
+    Ok(MessageResponse::new("Bid restored"))
 }
 
 // ============================================================================
@@ -1367,21 +2590,29 @@ pub async fn withdraw_bid(
 /// GET /api/marketplace/my-bids
 ///
 /// List the current user's submitted bids.
+#[utoipa::path(
+    get,
+    path = "/marketplace/my-bids",
+    tag = "marketplace",
+    params(PaginationParams),
+    responses(
+        (status = 200, description = "The caller's bids", body = MarketplaceBidPage),
+        (status = 403, description = "Caller is not a subcontractor", body = ErrorResponse),
+    )
+)]
 pub async fn list_my_bids(
     State(state): State<Arc<AppState>>,
     Query(query): Query<PaginationParams>,
-    auth: RequireAuth,
+    auth: RequireSub,
 ) -> Result<impl IntoResponse, ApiError> {
     let user_id = auth.user_id;
-    let page = query.page.unwrap_or(1).max(1);
-    let per_page = query.per_page.unwrap_or(20).min(100);
-    let offset = ((page - 1) * per_page) as i64;
+    let page = resolve_pagination(&query, state.settings.pagination.marketplace)?;
 
     let sub_id: Option<Uuid> = sqlx::query_scalar("SELECT id FROM subcontractors WHERE profile_id = $1")
         .bind(user_id)
         .fetch_optional(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+        .map_err(map_db_err("list_my_bids"))?;
 
     let sub_id = sub_id.ok_or_else(|| ApiError::forbidden("No subcontractor profile found"))?;
 
@@ -1389,7 +2620,7 @@ pub async fn list_my_bids(
         .bind(sub_id)
         .fetch_one(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+        .map_err(map_db_err("list_my_bids"))?;
 
     let rows = sqlx::query_as::<_, BidRow>(
         r#"
@@ -1405,16 +2636,16 @@ pub async fn list_my_bids(
         JOIN projects p ON t.project_id = p.id
         JOIN subcontractors s ON b.subcontractor_id = s.id
         WHERE b.subcontractor_id = $1
-        ORDER BY b.created_at DESC
+        ORDER BY b.created_at DESC, b.id
         LIMIT $2 OFFSET $3
         "#,
     )
     .bind(sub_id)
-    .bind(per_page as i64)
-    .bind(offset)
+    .bind(page.limit())
+    .bind(page.offset())
     .fetch_all(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("list_my_bids"))?;
 
     let data: Vec<MarketplaceBidResponse> = rows
         .into_iter()
@@ -1425,7 +2656,7 @@ pub async fn list_my_bids(
             project_name: r.project_name,
             subcontractor_id: r.subcontractor_id,
             company_name: r.company_name,
-            bid_amount: decimal_to_i64(r.bid_amount),
+            bid_amount: r.bid_amount,
             breakdown: serde_json::from_value(r.breakdown).unwrap_or_default(),
             proposed_timeline_days: r.proposed_timeline_days,
             proposed_start_date: r.proposed_start_date,
@@ -1438,17 +2669,306 @@ pub async fn list_my_bids(
         })
         .collect();
 
-    let total_pages = ((total as f64) / (per_page as f64)).ceil() as u32;
-
-    Ok(Json(Paginated {
-        data,
-        pagination: PaginationMeta {
-            page,
-            per_page,
-            total_items: total as u64,
-            total_pages,
-            has_next: page < total_pages,
-            has_prev: page > 1,
-        },
-    }))
+    Ok(Json(Paginated::new(data, &page, total as u64)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use axum::http::StatusCode;
+
+    use crate::test_support;
+
+    fn saved_search(search_type: &str) -> Json<CreateSavedSearchRequest> {
+        Json(CreateSavedSearchRequest {
+            name: "Electricians nearby".to_string(),
+            search_type: search_type.to_string(),
+            filters: serde_json::json!({ "trade": "electrical" }),
+            notify_new_matches: false,
+        })
+    }
+
+    #[tokio::test]
+    async fn saved_search_for_unknown_user_is_unprocessable() {
+        let Some(state) = test_support::state().await else { return };
+
+        let err = create_saved_search(State(state), test_support::auth(Uuid::new_v4()), saved_search("subcontractors"))
+            .await
+            .err()
+            .expect("insert should fail");
+
+        assert!(matches!(&err, ApiError::UnprocessableEntity(m) if m == "The referenced user does not exist"), "{:?}", err);
+    }
+
+    #[tokio::test]
+    async fn list_item_is_smaller_than_the_profile() {
+        let Some(state) = test_support::state().await else { return };
+        let sub_user_id = test_support::user(&state.db, "sub").await;
+        let sub_id = test_support::subcontractor(&state.db, sub_user_id).await;
+        sqlx::query(
+            r#"
+            UPDATE subcontractors SET
+                headline = 'Commercial and residential electrical',
+                company_description = 'Family-run electrical contractor serving the metro area since 1998.',
+                location = 'Portland, OR', contact_email = 'office@test-electric.example',
+                contact_phone = '555-0100', website = 'https://test-electric.example',
+                specialties = ARRAY['Panel upgrades', 'EV chargers'],
+                service_areas = '["Portland", "Beaverton"]'::jsonb,
+                certifications = '[{"name": "Master Electrician"}]'::jsonb
+            WHERE id = $1
+            "#,
+        )
+        .bind(sub_id)
+        .execute(&state.db)
+        .await
+        .unwrap();
+
+        let list_row = sqlx::query_as::<_, SubcontractorListRow>(&format!(
+            "SELECT {} FROM subcontractors s WHERE s.id = $1",
+            SUBCONTRACTOR_LIST_COLUMNS
+        ))
+        .bind(sub_id)
+        .fetch_one(&state.db)
+        .await
+        .unwrap();
+        let profile_row = sqlx::query_as::<_, MarketplaceSubRow>(&format!(
+            "SELECT {} FROM subcontractors s {} WHERE s.id = $1",
+            MARKETPLACE_SUB_COLUMNS, MARKETPLACE_SUB_REVIEWS_JOIN
+        ))
+        .bind(sub_id)
+        .fetch_one(&state.db)
+        .await
+        .unwrap();
+
+        let item = serde_json::to_vec(&list_row.into_list_item(&state.storage)).unwrap();
+        let profile = serde_json::to_vec(&profile_row.into_profile(&state.storage)).unwrap();
+        assert!(item.len() < profile.len(), "list item {} bytes, profile {} bytes", item.len(), profile.len());
+    }
+
+    async fn body_json(response: impl IntoResponse) -> serde_json::Value {
+        let body = axum::body::to_bytes(response.into_response().into_body(), usize::MAX).await.unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    /// The sub's headline as served by the (cached) detail and directory endpoints
+    async fn served_headlines(state: &Arc<AppState>, sub_id: Uuid, name: &str) -> (String, String) {
+        let auth = || test_support::auth(Uuid::new_v4());
+        let detail = get_marketplace_subcontractor(State(state.clone()), Path(sub_id), auth()).await.unwrap();
+        let detail = body_json(detail).await;
+
+        let mut query = MarketplaceSubQueryParams::default();
+        query.filter.search = Some(name.to_string());
+        let raw_query = format!("search={}", name);
+        let list = list_marketplace_subcontractors(
+            State(state.clone()),
+            Query(query),
+            Query(GeoQuery::default()),
+            Query(FieldsQuery::default()),
+            RawQuery(Some(raw_query)),
+            auth(),
+        )
+        .await
+        .unwrap();
+        let list = body_json(list).await;
+
+        (
+            detail["data"]["headline"].as_str().unwrap().to_string(),
+            list["data"][0]["headline"].as_str().unwrap().to_string(),
+        )
+    }
+
+    #[tokio::test]
+    async fn profile_update_is_visible_on_the_next_read() {
+        let Some(state) = test_support::state().await else { return };
+        let sub_user_id = test_support::user(&state.db, "sub").await;
+        let sub_id = test_support::subcontractor(&state.db, sub_user_id).await;
+        let name = format!("Cache{}", sub_id.simple());
+        sqlx::query("UPDATE subcontractors SET name = $2, headline = 'Old headline' WHERE id = $1")
+            .bind(sub_id)
+            .bind(&name)
+            .execute(&state.db)
+            .await
+            .unwrap();
+
+        // Warm both caches
+        assert_eq!(served_headlines(&state, sub_id, &name).await, ("Old headline".to_string(), "Old headline".to_string()));
+
+        let input = serde_json::from_value(serde_json::json!({ "headline": "New headline" })).unwrap();
+        update_my_marketplace_profile(State(state.clone()), test_support::sub(sub_user_id), Json(input))
+            .await
+            .unwrap();
+
+        assert_eq!(served_headlines(&state, sub_id, &name).await, ("New headline".to_string(), "New headline".to_string()));
+    }
+
+    fn open_tender(bid_due_date: Option<DateTime<Utc>>) -> BiddableTender {
+        BiddableTender {
+            status: "open".to_string(),
+            bid_due_date,
+            owner_id: Uuid::nil(),
+            name: "Test tender".to_string(),
+        }
+    }
+
+    /// A tender due at `bid_due_date` holding one bid in `bid_status` from a
+    /// new sub. Returns the tender id and the sub's user id.
+    async fn tender_with_bid(
+        db: &sqlx::PgPool,
+        bid_due_date: DateTime<Utc>,
+        bid_status: &str,
+    ) -> (Uuid, Uuid) {
+        let gc_id = test_support::user(db, "gc").await;
+        let project_id = test_support::project(db, gc_id).await;
+        let tender_id = test_support::tender(db, project_id, Some(bid_due_date)).await;
+        let sub_user_id = test_support::user(db, "sub").await;
+        let sub_id = test_support::subcontractor(db, sub_user_id).await;
+
+        sqlx::query(
+            r#"
+            INSERT INTO bids (tender_id, subcontractor_id, bidder_id, company_name, bid_amount, status)
+            VALUES ($1, $2, $3, 'Test Electric', 1000, $4)
+            "#,
+        )
+        .bind(tender_id)
+        .bind(sub_id)
+        .bind(sub_user_id)
+        .bind(bid_status)
+        .execute(db)
+        .await
+        .unwrap();
+
+        (tender_id, sub_user_id)
+    }
+
+    async fn bid_status(db: &sqlx::PgPool, tender_id: Uuid) -> String {
+        sqlx::query_scalar("SELECT status FROM bids WHERE tender_id = $1")
+            .bind(tender_id)
+            .fetch_one(db)
+            .await
+            .unwrap()
+    }
+
+    fn is_deadline_passed(err: &ApiError) -> bool {
+        matches!(err, ApiError::BadRequest(m) if m == "The bid deadline has passed")
+    }
+
+    #[test]
+    fn bidding_closes_at_the_due_date() {
+        let now = Utc::now();
+        let second = chrono::Duration::seconds(1);
+
+        assert!(assert_tender_biddable_at(&open_tender(Some(now + second)), now).is_ok());
+        assert!(assert_tender_biddable_at(&open_tender(None), now).is_ok());
+        assert!(is_deadline_passed(&assert_tender_biddable_at(&open_tender(Some(now)), now).unwrap_err()));
+        assert!(is_deadline_passed(&assert_tender_biddable_at(&open_tender(Some(now - second)), now).unwrap_err()));
+    }
+
+    #[tokio::test]
+    async fn withdrawn_bid_is_restored_before_the_deadline() {
+        let Some(state) = test_support::state().await else { return };
+        let due = Utc::now() + chrono::Duration::hours(1);
+        let (tender_id, sub_user_id) = tender_with_bid(&state.db, due, "withdrawn").await;
+
+        restore_bid(State(state.clone()), Path(tender_id), test_support::sub(sub_user_id))
+            .await
+            .unwrap();
+
+        assert_eq!(bid_status(&state.db, tender_id).await, "submitted");
+    }
+
+    #[tokio::test]
+    async fn withdrawn_bid_is_not_restored_after_the_deadline() {
+        let Some(state) = test_support::state().await else { return };
+        let due = Utc::now() - chrono::Duration::seconds(1);
+        let (tender_id, sub_user_id) = tender_with_bid(&state.db, due, "withdrawn").await;
+
+        let err = restore_bid(State(state.clone()), Path(tender_id), test_support::sub(sub_user_id))
+            .await
+            .err()
+            .expect("restore should be refused");
+
+        assert!(is_deadline_passed(&err), "{:?}", err);
+        assert_eq!(bid_status(&state.db, tender_id).await, "withdrawn");
+    }
+
+    #[tokio::test]
+    async fn bid_is_not_edited_after_the_deadline() {
+        let Some(state) = test_support::state().await else { return };
+        let due = Utc::now() - chrono::Duration::seconds(1);
+        let (tender_id, sub_user_id) = tender_with_bid(&state.db, due, "submitted").await;
+        let input = serde_json::from_value(serde_json::json!({ "bid_amount": "900" })).unwrap();
+
+        let err = update_bid(State(state.clone()), Path(tender_id), test_support::sub(sub_user_id), Json(input))
+            .await
+            .err()
+            .expect("update should be refused");
+
+        assert!(is_deadline_passed(&err), "{:?}", err);
+        let amount: String = sqlx::query_scalar("SELECT bid_amount::text FROM bids WHERE tender_id = $1")
+            .bind(tender_id)
+            .fetch_one(&state.db)
+            .await
+            .unwrap();
+        assert_eq!(amount, "1000.00");
+    }
+
+    #[tokio::test]
+    async fn open_but_expired_tender_refuses_new_bids() {
+        let Some(state) = test_support::state().await else { return };
+        let gc_id = test_support::user(&state.db, "gc").await;
+        let project_id = test_support::project(&state.db, gc_id).await;
+        let due = Utc::now() - chrono::Duration::seconds(1);
+        let tender_id = test_support::tender(&state.db, project_id, Some(due)).await;
+        let sub_user_id = test_support::user(&state.db, "sub").await;
+        test_support::subcontractor(&state.db, sub_user_id).await;
+        let input = serde_json::from_value(serde_json::json!({ "bid_amount": "1000" })).unwrap();
+
+        let err = submit_bid(State(state.clone()), Path(tender_id), test_support::sub(sub_user_id), Json(input))
+            .await
+            .err()
+            .expect("submission should be refused");
+
+        assert!(is_deadline_passed(&err), "{:?}", err);
+        let bids: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM bids WHERE tender_id = $1")
+            .bind(tender_id)
+            .fetch_one(&state.db)
+            .await
+            .unwrap();
+        assert_eq!(bids, 0);
+    }
+
+    #[tokio::test]
+    async fn open_but_expired_tender_refuses_withdrawal() {
+        let Some(state) = test_support::state().await else { return };
+        let due = Utc::now() - chrono::Duration::seconds(1);
+        let (tender_id, sub_user_id) = tender_with_bid(&state.db, due, "submitted").await;
+
+        let err = withdraw_bid(State(state.clone()), Path(tender_id), test_support::sub(sub_user_id))
+            .await
+            .err()
+            .expect("withdrawal should be refused");
+
+        assert!(is_deadline_passed(&err), "{:?}", err);
+        assert_eq!(bid_status(&state.db, tender_id).await, "submitted");
+    }
+
+    #[tokio::test]
+    async fn check_violation_hides_database_detail() {
+        let Some(state) = test_support::state().await else { return };
+        let user_id = test_support::user(&state.db, "gc").await;
+
+        let err = create_saved_search(State(state), test_support::auth(user_id), saved_search("contractors"))
+            .await
+            .err()
+            .expect("insert should fail");
+
+        let response = err.into_response();
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        for leak in ["saved_searches_type_check", "violates", "INSERT", "search_type"] {
+            assert!(!body.contains(leak), "response body leaks {:?}: {}", leak, body);
+        }
+    }
 }
@@ -1,8 +1,27 @@
-use axum::Json;
+use axum::{extract::State, Json};
 use serde::Serialize;
+use std::sync::Arc;
 use uuid::Uuid;
 
+use crate::api::response::DataResponse;
+use crate::app::AppState;
 use crate::auth::RequireAuth;
+use crate::domain::ai::AiQuotaStatus;
+use crate::domain::hiring::HireRequestStatus;
+use crate::error::{map_db_err, ApiError};
+use crate::services::ai_quota;
+use crate::services::feature_flags::FlagSet;
+
+/// Profile summary included in the bootstrap response
+#[derive(Serialize)]
+pub struct MeProfile {
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub company_name: Option<String>,
+    pub user_type: String,
+    pub is_admin: bool,
+    pub has_subcontractor_profile: bool,
+}
 
 #[derive(Serialize)]
 pub struct MeResponse {
@@ -11,15 +30,109 @@ pub struct MeResponse {
     pub role: Option<String>,
     pub issuer: String,
     pub audience: String,
+    pub avatar_url: Option<String>,
+    pub avatar_thumbnail_url: Option<String>,
+    /// `None` until the user's profile row exists
+    pub profile: Option<MeProfile>,
+    pub unread_notifications: i64,
+    /// Hire requests sent to or by the user still awaiting the subcontractor
+    pub pending_hire_requests: i64,
+    /// Feature flags enabled for the user
+    pub feature_flags: FlagSet,
+}
+
+#[derive(sqlx::FromRow)]
+struct MeProfileRow {
+    first_name: Option<String>,
+    last_name: Option<String>,
+    company_name: Option<String>,
+    user_type: String,
+    is_admin: Option<bool>,
+    avatar_key: Option<String>,
+    avatar_thumb_key: Option<String>,
+    has_subcontractor_profile: bool,
 }
 
 /// Get current authenticated user info
-pub async fn get_me(auth: RequireAuth) -> Json<MeResponse> {
-    Json(MeResponse {
+///
+/// Bootstrap call for the frontend: claims, profile summary and the badge
+/// counts it shows on load, fetched concurrently.
+pub async fn get_me(
+    State(state): State<Arc<AppState>>,
+    auth: RequireAuth,
+) -> Result<Json<MeResponse>, ApiError> {
+    let profile = sqlx::query_as::<_, MeProfileRow>(
+        r#"
+        SELECT p.first_name, p.last_name, p.company_name, p.user_type, p.is_admin,
+               p.avatar_key, p.avatar_thumb_key,
+               EXISTS (SELECT 1 FROM subcontractors s WHERE s.profile_id = p.id) as has_subcontractor_profile
+        FROM profiles p
+        WHERE p.id = $1
+        "#,
+    )
+    .bind(auth.user_id)
+    .fetch_optional(&state.db);
+
+    let unread_notifications = sqlx::query_scalar::<_, i64>(
+        "SELECT COUNT(*) FROM notifications WHERE user_id = $1 AND is_read = false",
+    )
+    .bind(auth.user_id)
+    .fetch_one(&state.db);
+
+    let awaiting: Vec<String> = HireRequestStatus::EXPIRABLE.iter().map(|s| s.to_string()).collect();
+    let pending_hire_requests = sqlx::query_scalar::<_, i64>(
+        r#"
+        SELECT COUNT(*) FROM hire_requests
+        WHERE status = ANY($2)
+        AND (gc_id = $1 OR subcontractor_id IN (SELECT id FROM subcontractors WHERE profile_id = $1))
+        "#,
+    )
+    .bind(auth.user_id)
+    .bind(&awaiting)
+    .fetch_one(&state.db);
+
+    let (profile, unread_notifications, pending_hire_requests) =
+        tokio::try_join!(profile, unread_notifications, pending_hire_requests)
+            .map_err(map_db_err("get_me"))?;
+
+    let avatar_url = profile
+        .as_ref()
+        .and_then(|p| p.avatar_key.as_deref())
+        .map(|k| state.storage.url(k));
+    let avatar_thumbnail_url = profile
+        .as_ref()
+        .and_then(|p| p.avatar_thumb_key.as_deref())
+        .map(|k| state.storage.url(k));
+
+    Ok(Json(MeResponse {
         user_id: auth.user_id,
         email: auth.email.clone(),
         role: auth.role.clone(),
         issuer: auth.issuer.clone(),
         audience: auth.audience.clone(),
-    })
+        avatar_url,
+        avatar_thumbnail_url,
+        profile: profile.map(|p| MeProfile {
+            first_name: p.first_name,
+            last_name: p.last_name,
+            company_name: p.company_name,
+            user_type: p.user_type,
+            is_admin: p.is_admin.unwrap_or(false),
+            has_subcontractor_profile: p.has_subcontractor_profile,
+        }),
+        unread_notifications,
+        pending_hire_requests,
+        feature_flags: auth.flags().clone(),
+    }))
+}
+
+/// GET /api/me/ai-quota
+///
+/// The caller's AI allowance: today's limit, usage and when it resets.
+pub async fn get_my_ai_quota(
+    State(state): State<Arc<AppState>>,
+    auth: RequireAuth,
+) -> Result<Json<DataResponse<AiQuotaStatus>>, ApiError> {
+    let status = ai_quota::status(&state.db, &state.cache, &state.settings.ai_quotas, auth.user_id).await?;
+    Ok(Json(DataResponse::new(status)))
 }
@@ -1,33 +1,78 @@
+pub mod activity;
 pub mod admin;
 pub mod ai;
 pub mod auth;
+pub mod bid_attachments;
 pub mod bids;
+pub mod docs;
 pub mod documents;
 pub mod extraction;
 pub mod health;
+pub mod internal;
 pub mod hiring;
 pub mod jobs;
 pub mod marketplace;
 pub mod me;
 pub mod notifications;
 pub mod profiles;
+pub mod project_export;
 pub mod projects;
 pub mod rfis;
 pub mod settings;
 pub mod subcontractors;
 pub mod tasks;
+pub mod tender_documents;
+pub mod tender_export;
+pub mod tender_questions;
+pub mod tender_scope_docs;
 pub mod tenders;
+pub mod trades;
+pub mod verification_documents;
+pub mod watchers;
 
-use axum::{routing::delete, routing::get, routing::post, routing::put, Router};
+use axum::{
+    extract::DefaultBodyLimit, routing::delete, routing::get, routing::post, routing::put, Router,
+};
 use std::sync::Arc;
 
 use crate::app::AppState;
+use crate::config::Settings;
+use crate::services::images;
+
+/// Request body limit for image uploads: the image plus multipart overhead
+fn image_upload_limit() -> usize {
+    images::MAX_IMAGE_BYTES + 64 * 1024
+}
+
+/// Request body limit for verification documents: the file plus multipart overhead
+fn verification_document_upload_limit() -> usize {
+    verification_documents::MAX_DOCUMENT_BYTES + 64 * 1024
+}
+
+/// Request body limit for bid attachments: the file plus multipart overhead
+fn bid_attachment_upload_limit() -> usize {
+    bid_attachments::MAX_ATTACHMENT_BYTES + 64 * 1024
+}
+
+/// Largest body any route accepts; bodies over this are rejected up front
+pub fn max_body_limit(settings: &Settings) -> usize {
+    settings
+        .max_request_body_bytes
+        .max(settings.max_upload_bytes)
+        .max(image_upload_limit())
+        .max(verification_document_upload_limit())
+        .max(bid_attachment_upload_limit())
+}
 
 /// Build the API router with all routes
-pub fn api_router() -> Router<Arc<AppState>> {
+pub fn api_router(settings: &Settings) -> Router<Arc<AppState>> {
     Router::new()
         // Public routes
         .route("/health", get(health::health_check))
+        .route("/version", get(health::version))
+        .route("/trades", get(trades::list_trades))
+        // API docs (when API_DOCS_ENABLED)
+        .merge(docs::router(settings))
         // Auth routes (public)
         .route("/auth/signup", post(auth::sign_up))
         .route("/auth/signin", post(auth::sign_in))
@@ -35,11 +80,19 @@ pub fn api_router() -> Router<Arc<AppState>> {
         // Auth routes (protected)
         .route("/auth/signout", post(auth::sign_out))
         .route("/auth/session", get(auth::get_session))
+        .route("/auth/sessions", get(auth::list_sessions))
+        .route("/auth/sessions", delete(auth::revoke_other_sessions))
+        .route("/auth/sessions/:session_id", delete(auth::revoke_session))
         // Protected routes
         .route("/me", get(me::get_me))
+        .route("/me/ai-quota", get(me::get_my_ai_quota))
         // Profile routes
         .route("/profiles/me", get(profiles::get_my_profile))
         .route("/profiles/me", put(profiles::update_my_profile))
+        .route(
+            "/profiles/me/avatar",
+            put(profiles::upload_my_avatar).layer(DefaultBodyLimit::max(image_upload_limit())),
+        )
         // Settings routes
         .route("/settings", get(settings::get_settings))
         .route("/settings", put(settings::update_settings))
@@ -49,6 +102,13 @@ pub fn api_router() -> Router<Arc<AppState>> {
         .route("/projects/:project_id", get(projects::get_project))
         .route("/projects/:project_id", put(projects::update_project))
         .route("/projects/:project_id", delete(projects::delete_project))
+        .route("/projects/:project_id/stats", get(projects::get_project_stats))
+        .route("/projects/:project_id/export", get(project_export::export_project))
+        // Project activity timeline
+        .route(
+            "/projects/:project_id/activity",
+            get(activity::list_project_activity),
+        )
         // Documents (nested under projects)
         .route(
             "/projects/:project_id/documents",
@@ -60,7 +120,8 @@ pub fn api_router() -> Router<Arc<AppState>> {
         )
         .route(
             "/projects/:project_id/documents/upload",
-            post(documents::upload_document),
+            post(documents::upload_document)
+                .layer(DefaultBodyLimit::max(settings.max_upload_bytes)),
         )
         .route(
             "/projects/:project_id/documents/:document_id",
@@ -70,20 +131,81 @@ pub fn api_router() -> Router<Arc<AppState>> {
             "/projects/:project_id/documents/:document_id",
             delete(documents::delete_document),
         )
+        .route(
+            "/projects/:project_id/documents/:document_id/versions",
+            get(documents::list_document_versions),
+        )
         // Tenders (nested under projects)
         .route(
             "/projects/:project_id/tenders",
             post(tenders::create_tender),
         )
         .route("/projects/:project_id/tenders", get(tenders::list_tenders))
+        .route(
+            "/projects/:project_id/tenders/export",
+            get(tender_export::export_project_tenders),
+        )
         .route("/projects/:project_id/tenders/:tender_id", get(tenders::get_tender))
         .route("/projects/:project_id/tenders/:tender_id", put(tenders::update_tender))
         .route("/projects/:project_id/tenders/:tender_id", delete(tenders::delete_tender))
+        .route(
+            "/projects/:project_id/tenders/:tender_id/publish",
+            post(tenders::publish_tender),
+        )
+        .route(
+            "/projects/:project_id/tenders/:tender_id/close",
+            post(tenders::close_tender),
+        )
+        .route(
+            "/projects/:project_id/tenders/:tender_id/scope-doc",
+            get(tender_scope_docs::get_scope_doc).put(tender_scope_docs::update_scope_doc),
+        )
+        .route(
+            "/projects/:project_id/tenders/:tender_id/scope-doc/generate",
+            post(tender_scope_docs::generate_scope_doc),
+        )
         // All tenders (for flat access)
         .route("/tenders", get(tenders::list_all_tenders))
+        .route(
+            "/tenders/:tender_id/recommended-subs",
+            get(marketplace::recommend_subs_for_tender),
+        )
+        .route(
+            "/tenders/:tender_id/invitations",
+            post(marketplace::invite_subcontractor_to_tender),
+        )
+        // Tender document sharing (GC)
+        .route(
+            "/tenders/:tender_id/documents",
+            post(tender_documents::share_tender_documents),
+        )
+        .route(
+            "/tenders/:tender_id/documents/downloads",
+            get(tender_documents::list_tender_document_downloads),
+        )
+        .route(
+            "/tenders/:tender_id/documents/:document_id",
+            delete(tender_documents::unshare_tender_document),
+        )
+        // Tender clarification answers (GC)
+        .route(
+            "/tenders/:tender_id/questions",
+            get(tender_questions::list_tender_questions),
+        )
+        .route(
+            "/tenders/:tender_id/questions/:question_id/answer",
+            post(tender_questions::answer_tender_question),
+        )
+        // Tender watchers (anyone who can see the tender)
+        .route("/tenders/:tender_id/watch", post(watchers::watch_tender))
+        .route("/tenders/:tender_id/watch", delete(watchers::unwatch_tender))
         // Bids (nested under tenders)
         .route("/tenders/:tender_id/bids", post(bids::create_bid))
         .route("/tenders/:tender_id/bids", get(bids::list_bids))
+        .route(
+            "/tenders/:tender_id/bids/export",
+            get(tender_export::export_tender_bids),
+        )
         // Tasks (nested under projects)
         .route("/projects/:project_id/tasks", post(tasks::create_task))
         .route("/projects/:project_id/tasks", get(tasks::list_tasks))
@@ -100,6 +222,12 @@ pub fn api_router() -> Router<Arc<AppState>> {
         .route("/projects/:project_id/rfis/:rfi_id", delete(rfis::delete_rfi))
         .route("/projects/:project_id/rfis/:rfi_id/responses", post(rfis::add_rfi_response))
         .route("/projects/:project_id/rfis/:rfi_id/responses", get(rfis::get_rfi_responses))
+        .route(
+            "/projects/:project_id/rfis/:rfi_id/responses/:response_id/accept",
+            post(rfis::accept_rfi_response),
+        )
+        .route("/projects/:project_id/rfis/:rfi_id/watch", post(watchers::watch_rfi))
+        .route("/projects/:project_id/rfis/:rfi_id/watch", delete(watchers::unwatch_rfi))
         // All RFIs (for flat access)
         .route("/rfis", get(rfis::list_all_rfis))
         // Subcontractors (marketplace)
@@ -119,6 +247,7 @@ pub fn api_router() -> Router<Arc<AppState>> {
             post(ai::generate_tender_scope_doc),
         )
         .route("/projects/:project_id/ai/qna", post(ai::ask_question))
+        .route("/projects/:project_id/ai/usage", get(ai::get_project_ai_usage))
         .route(
             "/projects/:project_id/ai/cache",
             delete(ai::invalidate_ai_cache),
@@ -130,6 +259,10 @@ pub fn api_router() -> Router<Arc<AppState>> {
             "/projects/:project_id/documents/:document_id/process",
             post(jobs::start_processing),
         )
+        .route(
+            "/projects/:project_id/documents/:document_id/trade-scopes/extract",
+            post(jobs::start_trade_scope_extraction),
+        )
         .route("/projects/:project_id/jobs", get(jobs::list_project_jobs))
         .route("/projects/:project_id/jobs/stream", get(jobs::stream_job_progress))
         .route("/projects/:project_id/jobs/:job_id", get(jobs::get_job))
@@ -142,6 +275,10 @@ pub fn api_router() -> Router<Arc<AppState>> {
             "/projects/:project_id/extraction",
             get(extraction::get_extraction_summary),
         )
+        .route(
+            "/projects/:project_id/extraction/review-queue",
+            get(extraction::get_review_queue),
+        )
         .route(
             "/projects/:project_id/extraction/materials",
             get(extraction::list_materials),
@@ -152,7 +289,7 @@ pub fn api_router() -> Router<Arc<AppState>> {
         )
         .route(
             "/projects/:project_id/extraction/materials/:material_id",
-            put(extraction::update_material),
+            put(extraction::update_material).patch(extraction::patch_material),
         )
         .route(
             "/projects/:project_id/extraction/materials/:material_id",
@@ -162,6 +299,14 @@ pub fn api_router() -> Router<Arc<AppState>> {
             "/projects/:project_id/extraction/materials/:material_id/verify",
             post(extraction::verify_material),
         )
+        .route(
+            "/projects/:project_id/extraction/materials/bulk-assign",
+            post(extraction::bulk_assign_material_trade),
+        )
+        .route(
+            "/projects/:project_id/extraction/materials/:material_id/source",
+            get(extraction::get_material_source),
+        )
         .route(
             "/projects/:project_id/extraction/rooms",
             get(extraction::list_rooms),
@@ -172,7 +317,7 @@ pub fn api_router() -> Router<Arc<AppState>> {
         )
         .route(
             "/projects/:project_id/extraction/rooms/:room_id",
-            put(extraction::update_room),
+            put(extraction::update_room).patch(extraction::patch_room),
         )
         .route(
             "/projects/:project_id/extraction/rooms/:room_id",
@@ -188,7 +333,7 @@ pub fn api_router() -> Router<Arc<AppState>> {
         )
         .route(
             "/projects/:project_id/extraction/milestones/:milestone_id",
-            put(extraction::update_milestone),
+            put(extraction::update_milestone).patch(extraction::patch_milestone),
         )
         .route(
             "/projects/:project_id/extraction/milestones/:milestone_id",
@@ -204,7 +349,7 @@ pub fn api_router() -> Router<Arc<AppState>> {
         )
         .route(
             "/projects/:project_id/extraction/trade-scopes/:scope_id",
-            put(extraction::update_trade_scope),
+            put(extraction::update_trade_scope).patch(extraction::patch_trade_scope),
         )
         .route(
             "/projects/:project_id/extraction/trade-scopes/:scope_id",
@@ -221,6 +366,10 @@ pub fn api_router() -> Router<Arc<AppState>> {
             "/projects/:project_id/team/:member_id",
             delete(hiring::remove_team_member),
         )
+        .route(
+            "/projects/:project_id/team/:member_id/review",
+            post(hiring::review_team_member),
+        )
         // External Subcontractors (my-subcontractors)
         .route(
             "/my-subcontractors",
@@ -230,6 +379,10 @@ pub fn api_router() -> Router<Arc<AppState>> {
             "/my-subcontractors",
             post(hiring::create_external_subcontractor),
         )
+        .route(
+            "/my-subcontractors/duplicates",
+            get(hiring::list_external_subcontractor_duplicates),
+        )
         .route(
             "/my-subcontractors/:id",
             get(hiring::get_external_subcontractor),
@@ -242,23 +395,51 @@ pub fn api_router() -> Router<Arc<AppState>> {
             "/my-subcontractors/:id",
             delete(hiring::delete_external_subcontractor),
         )
+        .route(
+            "/my-subcontractors/:id/platform-matches",
+            get(hiring::list_platform_matches),
+        )
+        .route(
+            "/my-subcontractors/:id/link/:sub_id",
+            post(hiring::link_external_subcontractor),
+        )
         // Hire Requests
         .route("/hiring", get(hiring::list_hire_requests))
         .route("/hiring", post(hiring::create_hire_request))
         .route("/hiring/:id", get(hiring::get_hire_request))
         .route("/hiring/:id", put(hiring::update_hire_request))
         .route("/hiring/:id/status", post(hiring::update_hire_request_status))
+        .route("/hiring/:id/counter-offer", post(hiring::submit_counter_offer))
+        .route(
+            "/hiring/:id/counter-offer/accept",
+            post(hiring::accept_counter_offer),
+        )
+        .route(
+            "/hiring/:id/counter-offer/reject",
+            post(hiring::reject_counter_offer),
+        )
         .route("/hiring/:id/messages", get(hiring::list_hire_messages))
         .route("/hiring/:id/messages", post(hiring::send_hire_message))
+        .route("/hiring/:id/messages/read", post(hiring::mark_hire_messages_read))
         .route("/hiring/:id/contract", post(hiring::create_contract))
         // Contracts
         .route("/contracts/:id", get(hiring::get_contract))
         .route("/contracts/:id/sign", post(hiring::sign_contract))
+        .route(
+            "/contracts/:id/signatures/verify",
+            get(hiring::verify_contract_signatures),
+        )
+        .route("/contracts/:id/payments", get(hiring::get_contract_payments))
+        .route(
+            "/contracts/:id/payments/:milestone_index/pay",
+            post(hiring::mark_milestone_paid),
+        )
         // Contract Templates
         .route("/contract-templates", get(hiring::list_contract_templates))
         // Notifications
         .route("/notifications", get(notifications::list_notifications))
-        .route("/notifications", delete(notifications::delete_all_read))
+        .route("/notifications", delete(notifications::delete_notifications_by_filter))
+        .route("/notifications/stream", get(notifications::stream_notifications))
         .route(
             "/notifications/unread-count",
             get(notifications::get_unread_count),
@@ -267,6 +448,7 @@ pub fn api_router() -> Router<Arc<AppState>> {
             "/notifications/read-all",
             put(notifications::mark_all_read),
         )
+        .route("/notifications/read", put(notifications::mark_read_by_filter))
         .route(
             "/notifications/mark-read",
             post(notifications::mark_batch_read),
@@ -305,10 +487,28 @@ pub fn api_router() -> Router<Arc<AppState>> {
             "/marketplace/profile",
             put(marketplace::update_my_marketplace_profile),
         )
+        .route(
+            "/marketplace/profile/logo",
+            put(marketplace::upload_my_marketplace_logo)
+                .layer(DefaultBodyLimit::max(image_upload_limit())),
+        )
         .route(
             "/marketplace/profile/request-verification",
             post(marketplace::request_verification),
         )
+        .route(
+            "/marketplace/profile/verification-documents",
+            get(verification_documents::list_my_verification_documents),
+        )
+        .route(
+            "/marketplace/profile/verification-documents",
+            post(verification_documents::upload_verification_document)
+                .layer(DefaultBodyLimit::max(verification_document_upload_limit())),
+        )
+        .route(
+            "/verification-documents/:document_id/view",
+            get(verification_documents::view_verification_document),
+        )
         .route(
             "/marketplace/profile/portfolio",
             get(marketplace::get_my_portfolio),
@@ -325,6 +525,15 @@ pub fn api_router() -> Router<Arc<AppState>> {
             "/marketplace/profile/portfolio/:project_id",
             delete(marketplace::delete_portfolio_project),
         )
+        .route(
+            "/marketplace/profile/portfolio/:project_id/images",
+            post(marketplace::upload_portfolio_image)
+                .layer(DefaultBodyLimit::max(image_upload_limit())),
+        )
+        .route(
+            "/marketplace/profile/portfolio/:project_id/images",
+            delete(marketplace::delete_portfolio_image),
+        )
         // Marketplace - Saved Searches
         .route(
             "/marketplace/saved-searches",
@@ -359,15 +568,53 @@ pub fn api_router() -> Router<Arc<AppState>> {
             "/marketplace/tenders/:tender_id/bid",
             delete(marketplace::withdraw_bid),
         )
+        .route(
+            "/marketplace/tenders/:tender_id/bid/restore",
+            post(marketplace::restore_bid),
+        )
+        .route(
+            "/marketplace/tenders/:tender_id/bid/attachments",
+            get(bid_attachments::list_my_bid_attachments),
+        )
+        .route(
+            "/marketplace/tenders/:tender_id/bid/attachments",
+            post(bid_attachments::upload_bid_attachment)
+                .layer(DefaultBodyLimit::max(bid_attachment_upload_limit())),
+        )
+        .route(
+            "/marketplace/tenders/:tender_id/bid/attachments/:attachment_id",
+            delete(bid_attachments::delete_bid_attachment),
+        )
+        .route(
+            "/marketplace/tenders/:tender_id/questions",
+            get(tender_questions::list_tender_questions).post(tender_questions::ask_tender_question),
+        )
+        .route(
+            "/bid-attachments/:attachment_id/download",
+            get(bid_attachments::download_bid_attachment),
+        )
+        .route(
+            "/marketplace/tenders/:tender_id/documents",
+            get(tender_documents::list_marketplace_tender_documents),
+        )
+        .route(
+            "/marketplace/tenders/:tender_id/documents/:document_id/download",
+            get(tender_documents::download_tender_document),
+        )
         // Marketplace - My Bids
         .route("/marketplace/my-bids", get(marketplace::list_my_bids))
         // Admin routes (protected by RequireAdmin middleware)
         .route("/admin/check", get(admin::check_admin))
         .route("/admin/stats", get(admin::get_admin_stats))
+        .route("/admin/ai-usage", get(admin::get_ai_usage))
         .route(
             "/admin/verifications",
             get(admin::list_pending_verifications),
         )
+        .route(
+            "/admin/verifications/bulk",
+            post(admin::bulk_verification),
+        )
         .route(
             "/admin/verifications/:sub_id",
             get(admin::get_verification),
@@ -380,5 +627,51 @@ pub fn api_router() -> Router<Arc<AppState>> {
             "/admin/verifications/:sub_id/reject",
             post(admin::reject_verification),
         )
+        .route("/admin/users", get(admin::list_users))
+        .route("/admin/users/:user_id/admin", post(admin::grant_admin))
+        .route("/admin/users/:user_id/admin", delete(admin::revoke_admin))
         .route("/admin/audit-log", get(admin::list_audit_log))
+        .route("/admin/audit-log/export", get(admin::export_audit_log))
+        .route(
+            "/admin/cache/contract-templates",
+            delete(admin::invalidate_contract_templates_cache),
+        )
+        .route(
+            "/admin/contract-templates",
+            post(admin::create_contract_template),
+        )
+        .route(
+            "/admin/contract-templates/:template_id",
+            put(admin::update_contract_template).delete(admin::delete_contract_template),
+        )
+        .route(
+            "/admin/settings/verification-requirements",
+            get(admin::get_verification_requirements).put(admin::update_verification_requirements),
+        )
+        .route(
+            "/admin/feature-flags",
+            get(admin::list_feature_flags),
+        )
+        .route(
+            "/admin/feature-flags/:key",
+            put(admin::update_feature_flag),
+        )
+        .route(
+            "/admin/feature-flags/:key/users/:user_id",
+            put(admin::set_user_feature_flag).delete(admin::clear_user_feature_flag),
+        )
+        .route(
+            "/admin/maintenance",
+            get(admin::get_maintenance_mode).put(admin::update_maintenance_mode),
+        )
+        // Internal service callbacks (authenticated with the internal token)
+        .route("/internal/jobs/claim", post(internal::claim_job))
+        .route(
+            "/internal/jobs/:job_id/heartbeat",
+            post(internal::job_heartbeat),
+        )
+        .route(
+            "/internal/jobs/:job_id/progress",
+            post(internal::report_job_progress),
+        )
 }
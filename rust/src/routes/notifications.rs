@@ -1,42 +1,33 @@
 //! Notification routes
 //!
-//! Endpoints for in-app notifications: list, mark read, delete.
+//! Endpoints for in-app notifications: list, mark read, delete, and a live
+//! stream of changes.
 
 use axum::{
     extract::{Path, Query, State},
-    response::IntoResponse,
+    response::{
+        sse::{Event, Sse},
+        IntoResponse,
+    },
     Json,
 };
-use chrono::{DateTime, Utc};
+use chrono::Utc;
+use futures::stream::{self, Stream};
+use futures::StreamExt;
 use serde::Deserialize;
-use std::sync::Arc;
+use std::{convert::Infallible, sync::Arc, time::Duration};
 use uuid::Uuid;
 
-use crate::api::pagination::PaginationParams;
-use crate::api::response::{DataResponse, Paginated, PaginationMeta};
+use crate::api::pagination::{resolve_pagination, PaginationParams};
+use crate::api::response::{DataResponse, MessageResponse, NoContent, Paginated};
 use crate::app::AppState;
 use crate::auth::RequireAuth;
 use crate::domain::notifications::*;
-use crate::error::ApiError;
+use crate::error::{map_db_err, ApiError};
+use crate::services::notification_stream;
 
-// ============================================================================
-// Database Row Types
-// ============================================================================
-
-#[allow(dead_code)]
-#[derive(Debug, sqlx::FromRow)]
-struct NotificationRow {
-    id: Uuid,
-    user_id: Uuid,
-    #[sqlx(rename = "type")]
-    notification_type: String,
-    title: String,
-    message: Option<String>,
-    data: serde_json::Value,
-    is_read: bool,
-    read_at: Option<DateTime<Utc>>,
-    created_at: DateTime<Utc>,
-}
+/// How often the live stream sends a heartbeat
+const STREAM_HEARTBEAT_INTERVAL: Duration = Duration::from_secs(15);
 
 // ============================================================================
 // Query Types
@@ -63,9 +54,7 @@ pub async fn list_notifications(
     auth: RequireAuth,
 ) -> Result<impl IntoResponse, ApiError> {
     let user_id = auth.user_id;
-    let page = query.pagination.page.unwrap_or(1).max(1);
-    let per_page = query.pagination.per_page.unwrap_or(20).min(100);
-    let offset = ((page - 1) * per_page) as i64;
+    let page = resolve_pagination(&query.pagination, state.settings.pagination.default)?;
 
     let unread_only = query.filter.unread_only.unwrap_or(false);
 
@@ -83,56 +72,32 @@ pub async fn list_notifications(
     .bind(&query.filter.notification_type)
     .fetch_one(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("list_notifications"))?;
 
     // Fetch notifications
-    let rows = sqlx::query_as::<_, NotificationRow>(
+    let rows = sqlx::query_as::<_, Notification>(
         r#"
         SELECT id, user_id, type, title, message, data, is_read, read_at, created_at
         FROM notifications
         WHERE user_id = $1
         AND ($2::bool = false OR is_read = false)
         AND ($3::text IS NULL OR type = $3)
-        ORDER BY created_at DESC
+        ORDER BY created_at DESC, id
         LIMIT $4 OFFSET $5
         "#,
     )
     .bind(user_id)
     .bind(unread_only)
     .bind(&query.filter.notification_type)
-    .bind(per_page as i64)
-    .bind(offset)
+    .bind(page.limit())
+    .bind(page.offset())
     .fetch_all(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
-
-    let data: Vec<NotificationResponse> = rows
-        .into_iter()
-        .map(|r| NotificationResponse {
-            id: r.id,
-            notification_type: r.notification_type,
-            title: r.title,
-            message: r.message,
-            data: r.data,
-            is_read: r.is_read,
-            read_at: r.read_at,
-            created_at: r.created_at,
-        })
-        .collect();
-
-    let total_pages = ((total as f64) / (per_page as f64)).ceil() as u32;
-
-    Ok(Json(Paginated {
-        data,
-        pagination: PaginationMeta {
-            page,
-            per_page,
-            total_items: total as u64,
-            total_pages,
-            has_next: page < total_pages,
-            has_prev: page > 1,
-        },
-    }))
+    .map_err(map_db_err("list_notifications"))?;
+
+    let data: Vec<NotificationResponse> = rows.into_iter().map(NotificationResponse::from).collect();
+
+    Ok(Json(Paginated::new(data, &page, total as u64)))
 }
 
 /// GET /api/notifications/unread-count
@@ -142,17 +107,19 @@ pub async fn get_unread_count(
     State(state): State<Arc<AppState>>,
     auth: RequireAuth,
 ) -> Result<impl IntoResponse, ApiError> {
-    let user_id = auth.user_id;
+    let count = unread_count(&state, auth.user_id).await?;
+
+    Ok(Json(UnreadCountResponse { count }))
+}
 
-    let count: i64 = sqlx::query_scalar(
+async fn unread_count(state: &AppState, user_id: Uuid) -> Result<i64, ApiError> {
+    sqlx::query_scalar(
         "SELECT COUNT(*) FROM notifications WHERE user_id = $1 AND is_read = false",
     )
     .bind(user_id)
     .fetch_one(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
-
-    Ok(Json(UnreadCountResponse { count }))
+    .map_err(map_db_err("unread_count"))
 }
 
 /// GET /api/notifications/:id
@@ -165,7 +132,7 @@ pub async fn get_notification(
 ) -> Result<impl IntoResponse, ApiError> {
     let user_id = auth.user_id;
 
-    let row = sqlx::query_as::<_, NotificationRow>(
+    let row = sqlx::query_as::<_, Notification>(
         r#"
         SELECT id, user_id, type, title, message, data, is_read, read_at, created_at
         FROM notifications
@@ -176,21 +143,10 @@ pub async fn get_notification(
     .bind(user_id)
     .fetch_optional(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
+    .map_err(map_db_err("get_notification"))?
     .ok_or_else(|| ApiError::not_found("Notification not found"))?;
 
-    let response = NotificationResponse {
-        id: row.id,
-        notification_type: row.notification_type,
-        title: row.title,
-        message: row.message,
-        data: row.data,
-        is_read: row.is_read,
-        read_at: row.read_at,
-        created_at: row.created_at,
-    };
-
-    Ok(Json(DataResponse::new(response)))
+    Ok(Json(DataResponse::new(NotificationResponse::from(row))))
 }
 
 /// PUT /api/notifications/:id/read
@@ -214,7 +170,7 @@ pub async fn mark_notification_read(
     .bind(user_id)
     .execute(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("mark_notification_read"))?;
 
     if result.rows_affected() == 0 {
         // Check if it exists
@@ -225,15 +181,24 @@ pub async fn mark_notification_read(
         .bind(user_id)
         .fetch_one(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+        .map_err(map_db_err("mark_notification_read"))?;
 
         if !exists {
             return Err(ApiError::not_found("Notification not found"));
         }
         // Already read, that's fine
+    } else {
+        notification_stream::publish_to(
+            user_id,
+            NotificationStreamEvent::Read {
+                notification_ids: Some(vec![notification_id]),
+                unread_delta: -1,
+            },
+        )
+        .await;
     }
 
-    Ok(Json(serde_json::json!({ "success": true })))
+    Ok(MessageResponse::new("Notification marked as read"))
 }
 
 /// PUT /api/notifications/read-all
@@ -255,14 +220,58 @@ pub async fn mark_all_read(
     .bind(user_id)
     .execute(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("mark_all_read"))?;
+
+    publish_read(user_id, None, result.rows_affected()).await;
 
-    Ok(Json(serde_json::json!({ 
-        "success": true, 
+    Ok(DataResponse::new(serde_json::json!({
         "marked_count": result.rows_affected() 
     })))
 }
 
+/// PUT /api/notifications/read?type=bid_received&before=<ts>
+///
+/// Mark all of the current user's notifications matching a type and/or
+/// created before a timestamp as read.
+pub async fn mark_read_by_filter(
+    State(state): State<Arc<AppState>>,
+    Query(filter): Query<MarkReadFilter>,
+    auth: RequireAuth,
+) -> Result<impl IntoResponse, ApiError> {
+    let user_id = auth.user_id;
+
+    if filter.notification_type.is_none() && filter.before.is_none() {
+        return Err(ApiError::bad_request(
+            "Provide type and/or before, or use /notifications/read-all",
+        ));
+    }
+
+    let result = sqlx::query(
+        r#"
+        UPDATE notifications
+        SET is_read = true, read_at = NOW()
+        WHERE user_id = $1 AND is_read = false
+        AND ($2::text IS NULL OR type = $2)
+        AND ($3::timestamptz IS NULL OR created_at < $3)
+        "#,
+    )
+    .bind(user_id)
+    .bind(&filter.notification_type)
+    .bind(filter.before)
+    .execute(&state.db)
+    .await
+    .map_err(map_db_err("mark_read_by_filter"))?;
+
+    publish_read(user_id, None, result.rows_affected()).await;
+
+    let unread_count = unread_count(&state, user_id).await?;
+
+    Ok(DataResponse::new(MarkReadResponse {
+        marked_count: result.rows_affected(),
+        unread_count,
+    }))
+}
+
 /// POST /api/notifications/mark-read
 ///
 /// Mark specific notifications as read (batch operation).
@@ -278,22 +287,25 @@ pub async fn mark_batch_read(
         return Err(ApiError::bad_request("notification_ids is required"));
     }
 
-    let result = sqlx::query(
+    let marked: Vec<Uuid> = sqlx::query_scalar(
         r#"
         UPDATE notifications 
         SET is_read = true, read_at = NOW() 
         WHERE user_id = $1 AND id = ANY($2) AND is_read = false
+        RETURNING id
         "#,
     )
     .bind(user_id)
     .bind(&notification_ids)
-    .execute(&state.db)
+    .fetch_all(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("mark_batch_read"))?;
 
-    Ok(Json(serde_json::json!({ 
-        "success": true, 
-        "marked_count": result.rows_affected() 
+    let marked_count = marked.len() as u64;
+    publish_read(user_id, Some(marked), marked_count).await;
+
+    Ok(DataResponse::new(serde_json::json!({
+        "marked_count": marked_count
     })))
 }
 
@@ -307,37 +319,184 @@ pub async fn delete_notification(
 ) -> Result<impl IntoResponse, ApiError> {
     let user_id = auth.user_id;
 
-    let result = sqlx::query("DELETE FROM notifications WHERE id = $1 AND user_id = $2")
-        .bind(notification_id)
-        .bind(user_id)
-        .execute(&state.db)
-        .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    let was_read: Option<bool> =
+        sqlx::query_scalar("DELETE FROM notifications WHERE id = $1 AND user_id = $2 RETURNING is_read")
+            .bind(notification_id)
+            .bind(user_id)
+            .fetch_optional(&state.db)
+            .await
+            .map_err(map_db_err("delete_notification"))?;
 
-    if result.rows_affected() == 0 {
+    let Some(was_read) = was_read else {
         return Err(ApiError::not_found("Notification not found"));
-    }
+    };
 
-    Ok(Json(serde_json::json!({ "success": true })))
+    notification_stream::publish_to(
+        user_id,
+        NotificationStreamEvent::Deleted {
+            notification_ids: vec![notification_id],
+            unread_delta: if was_read { 0 } else { -1 },
+        },
+    )
+    .await;
+
+    Ok(NoContent)
 }
 
-/// DELETE /api/notifications
+/// DELETE /api/notifications?type=bid_received&before=<ts>&read=true
 ///
-/// Delete all read notifications for the current user.
-pub async fn delete_all_read(
+/// Delete the current user's notifications matching a type and/or created
+/// before a timestamp; with no filters, all read notifications. Unread
+/// notifications are kept unless `include_unread=true` (then `read=false`
+/// deletes only unread ones).
+pub async fn delete_notifications_by_filter(
     State(state): State<Arc<AppState>>,
+    Query(filter): Query<DeleteNotificationsFilter>,
     auth: RequireAuth,
 ) -> Result<impl IntoResponse, ApiError> {
     let user_id = auth.user_id;
 
-    let result = sqlx::query("DELETE FROM notifications WHERE user_id = $1 AND is_read = true")
+    let read = match (filter.read, filter.include_unread) {
+        (Some(false), false) => {
+            return Err(ApiError::bad_request(
+                "Set include_unread=true to delete unread notifications",
+            ));
+        }
+        (read, true) => read,
+        (_, false) => Some(true),
+    };
+
+    let deleted: Vec<(Uuid, bool)> = sqlx::query_as(
+        r#"
+        DELETE FROM notifications
+        WHERE user_id = $1
+        AND ($2::bool IS NULL OR is_read = $2)
+        AND ($3::text IS NULL OR type = $3)
+        AND ($4::timestamptz IS NULL OR created_at < $4)
+        RETURNING id, is_read
+        "#,
+    )
+    .bind(user_id)
+    .bind(read)
+    .bind(&filter.notification_type)
+    .bind(filter.before)
+    .fetch_all(&state.db)
+    .await
+    .map_err(map_db_err("delete_notifications_by_filter"))?;
+
+    let deleted_count = deleted.len() as u64;
+    if !deleted.is_empty() {
+        let unread = deleted.iter().filter(|(_, is_read)| !is_read).count() as i64;
+        notification_stream::publish_to(
+            user_id,
+            NotificationStreamEvent::Deleted {
+                notification_ids: deleted.into_iter().map(|(id, _)| id).collect(),
+                unread_delta: -unread,
+            },
+        )
+        .await;
+    }
+
+    Ok(DataResponse::new(serde_json::json!({
+        "deleted_count": deleted_count
+    })))
+}
+
+/// Tell the user's live stream that `count` notifications were marked read
+async fn publish_read(user_id: Uuid, notification_ids: Option<Vec<Uuid>>, count: u64) {
+    if count == 0 {
+        return;
+    }
+    notification_stream::publish_to(
+        user_id,
+        NotificationStreamEvent::Read {
+            notification_ids,
+            unread_delta: -(count as i64),
+        },
+    )
+    .await;
+}
+
+fn stream_event(event: &NotificationStreamEvent) -> Event {
+    Event::default()
+        .event(event.name())
+        .data(serde_json::to_string(event).unwrap_or_default())
+}
+
+/// GET /api/notifications/stream
+///
+/// SSE stream of the current user's notification changes: `unread_count`
+/// when it opens, then `created`, `updated`, `read` and `deleted` events
+/// carrying `unread_delta`, and a `heartbeat` every 15 seconds.
+pub async fn stream_notifications(
+    State(state): State<Arc<AppState>>,
+    auth: RequireAuth,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, ApiError> {
+    let user_id = auth.user_id;
+
+    // Subscribe before counting so no change falls between the two
+    let live = notification_stream::subscribe(&state.cache, user_id)
+        .await
+        .map_err(|e| ApiError::internal(format!("Failed to open notification stream: {}", e)))?
+        .map(|(name, payload)| Event::default().event(name).data(payload));
+
+    let count = unread_count(&state, user_id).await?;
+    let opening = stream_event(&NotificationStreamEvent::UnreadCount { count });
+
+    let heartbeats = stream::unfold((), |()| async {
+        tokio::time::sleep(STREAM_HEARTBEAT_INTERVAL).await;
+        let event = stream_event(&NotificationStreamEvent::Heartbeat { timestamp: Utc::now() });
+        Some((event, ()))
+    });
+
+    let stream = stream::once(async move { opening })
+        .chain(stream::select(live, heartbeats))
+        .map(Ok::<_, Infallible>);
+
+    Ok(Sse::new(stream))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::test_support;
+
+    #[tokio::test]
+    async fn pages_do_not_overlap_when_created_at_ties() {
+        let Some(state) = test_support::state().await else { return };
+        let user_id = test_support::user(&state.db, "gc").await;
+
+        // One statement, so every row gets the same NOW()
+        sqlx::query(
+            "INSERT INTO notifications (user_id, type, title) SELECT $1, 'test', 'Tied' FROM generate_series(1, 7)",
+        )
         .bind(user_id)
         .execute(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+        .unwrap();
+
+        let mut ids = Vec::new();
+        for page in 1..=4 {
+            let query = NotificationQueryParams {
+                pagination: PaginationParams { page: Some(page), per_page: Some(2) },
+                ..Default::default()
+            };
+            let response = list_notifications(State(state.clone()), Query(query), test_support::auth(user_id))
+                .await
+                .unwrap()
+                .into_response();
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+            ids.extend(body["data"].as_array().unwrap().iter().map(|row| row["id"].as_str().unwrap().to_string()));
+        }
 
-    Ok(Json(serde_json::json!({ 
-        "success": true, 
-        "deleted_count": result.rows_affected() 
-    })))
+        // Ties fall back to id order, so every row shows up exactly once
+        let mut expected = ids.clone();
+        expected.sort();
+        expected.dedup();
+        assert_eq!(ids, expected);
+        assert_eq!(ids.len(), 7);
+    }
 }
@@ -2,7 +2,11 @@
 //!
 //! User profile management endpoints with Redis caching.
 
-use axum::{extract::State, response::IntoResponse, Json};
+use axum::{
+    extract::{Multipart, State},
+    response::IntoResponse,
+    Json,
+};
 use chrono::{DateTime, Utc};
 use std::sync::Arc;
 
@@ -11,8 +15,10 @@ use crate::app::AppState;
 use crate::auth::RequireAuth;
 use crate::domain::auth::UserType;
 use crate::domain::profiles::{ProfileResponse, UpdateProfileRequest};
-use crate::error::ApiError;
+use crate::error::{map_db_err, ApiError};
 use crate::services::cache::{keys as cache_keys, ttl as cache_ttl};
+use crate::services::images;
+use crate::services::ObjectStorage;
 
 /// Database row for profile
 #[derive(Debug, sqlx::FromRow)]
@@ -27,26 +33,39 @@ struct ProfileRow {
     title: Option<String>,
     bio: Option<String>,
     location: Option<String>,
+    avatar_key: Option<String>,
+    avatar_thumb_key: Option<String>,
     updated_at: DateTime<Utc>,
 }
 
-impl From<ProfileRow> for ProfileResponse {
-    fn from(row: ProfileRow) -> Self {
-        Self {
-            id: row.id,
-            email: row.email,
-            user_type: match row.user_type.as_str() {
+/// Profile row returned by an avatar swap, with the keys it replaced
+#[derive(Debug, sqlx::FromRow)]
+struct AvatarUpdateRow {
+    #[sqlx(flatten)]
+    profile: ProfileRow,
+    previous_key: Option<String>,
+    previous_thumb_key: Option<String>,
+}
+
+impl ProfileRow {
+    fn into_response(self, storage: &ObjectStorage) -> ProfileResponse {
+        ProfileResponse {
+            id: self.id,
+            email: self.email,
+            user_type: match self.user_type.as_str() {
                 "sub" => UserType::Sub,
                 _ => UserType::Gc,
             },
-            company_name: row.company_name,
-            first_name: row.first_name,
-            last_name: row.last_name,
-            phone: row.phone,
-            title: row.title,
-            bio: row.bio,
-            location: row.location,
-            updated_at: row.updated_at,
+            company_name: self.company_name,
+            first_name: self.first_name,
+            last_name: self.last_name,
+            phone: self.phone,
+            title: self.title,
+            bio: self.bio,
+            location: self.location,
+            avatar_url: self.avatar_key.as_deref().map(|k| storage.url(k)),
+            avatar_thumbnail_url: self.avatar_thumb_key.as_deref().map(|k| storage.url(k)),
+            updated_at: self.updated_at,
         }
     }
 }
@@ -70,7 +89,7 @@ pub async fn get_my_profile(
     let profile = sqlx::query_as::<_, ProfileRow>(
         r#"
         SELECT id, email, user_type, company_name, first_name, last_name,
-               phone, title, bio, location, updated_at
+               phone, title, bio, location, avatar_key, avatar_thumb_key, updated_at
         FROM profiles
         WHERE id = $1
         "#,
@@ -78,10 +97,10 @@ pub async fn get_my_profile(
     .bind(auth.user_id)
     .fetch_optional(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
+    .map_err(map_db_err("get_my_profile"))?
     .ok_or_else(|| ApiError::not_found("Profile not found"))?;
 
-    let response: ProfileResponse = profile.into();
+    let response = profile.into_response(&state.storage);
 
     // Cache the result
     let _ = state.cache.set_with_ttl(&cache_key, &response, cache_ttl::PROFILE).await;
@@ -111,7 +130,7 @@ pub async fn update_my_profile(
             updated_at = NOW()
         WHERE id = $1
         RETURNING id, email, user_type, company_name, first_name, last_name,
-                  phone, title, bio, location, updated_at
+                  phone, title, bio, location, avatar_key, avatar_thumb_key, updated_at
         "#,
     )
     .bind(auth.user_id)
@@ -124,10 +143,10 @@ pub async fn update_my_profile(
     .bind(&req.location)
     .fetch_optional(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
+    .map_err(map_db_err("update_my_profile"))?
     .ok_or_else(|| ApiError::not_found("Profile not found"))?;
 
-    let response: ProfileResponse = profile.into();
+    let response = profile.into_response(&state.storage);
 
     // Invalidate cache after update
     let cache_key = cache_keys::profile(auth.user_id);
@@ -138,3 +157,68 @@ pub async fn update_my_profile(
 
     Ok(Json(DataResponse::new(response)))
 }
+
+/// PUT /api/profiles/me/avatar
+///
+/// Upload a new avatar image (multipart field `file`). The previous avatar and
+/// its thumbnail are removed once the profile points at the new one.
+pub async fn upload_my_avatar(
+    State(state): State<Arc<AppState>>,
+    auth: RequireAuth,
+    mut multipart: Multipart,
+) -> Result<impl IntoResponse, ApiError> {
+    let (bytes, content_type) = images::read_upload(&mut multipart).await?;
+    let stored = images::store_upload(
+        &state.storage,
+        &format!("avatars/{}", auth.user_id),
+        bytes,
+        content_type,
+    )
+    .await?;
+
+    // Swap keys in one statement, returning the replaced ones for cleanup
+    let result = sqlx::query_as::<_, AvatarUpdateRow>(
+        r#"
+        UPDATE profiles p SET
+            avatar_key = $2,
+            avatar_thumb_key = $3,
+            updated_at = NOW()
+        FROM (SELECT id, avatar_key, avatar_thumb_key FROM profiles WHERE id = $1 FOR UPDATE) old
+        WHERE p.id = old.id
+        RETURNING p.id, p.email, p.user_type, p.company_name, p.first_name, p.last_name,
+                  p.phone, p.title, p.bio, p.location, p.avatar_key, p.avatar_thumb_key,
+                  p.updated_at, old.avatar_key AS previous_key,
+                  old.avatar_thumb_key AS previous_thumb_key
+        "#,
+    )
+    .bind(auth.user_id)
+    .bind(&stored.key)
+    .bind(&stored.thumb_key)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("upload_my_avatar"))
+    .and_then(|row| row.ok_or_else(|| ApiError::not_found("Profile not found")));
+
+    let updated = match result {
+        Ok(updated) => updated,
+        Err(e) => {
+            state
+                .storage
+                .delete_best_effort(&[Some(stored.key.as_str()), Some(stored.thumb_key.as_str())])
+                .await;
+            return Err(e);
+        }
+    };
+
+    state
+        .storage
+        .delete_best_effort(&[updated.previous_key.as_deref(), updated.previous_thumb_key.as_deref()])
+        .await;
+
+    let response = updated.profile.into_response(&state.storage);
+
+    let cache_key = cache_keys::profile(auth.user_id);
+    let _ = state.cache.set_with_ttl(&cache_key, &response, cache_ttl::PROFILE).await;
+
+    Ok(Json(DataResponse::new(response)))
+}
@@ -0,0 +1,393 @@
+//! Project export
+//!
+//! Bundles a project's data, and optionally its uploaded documents, into a ZIP
+//! archive streamed as it is built:
+//!
+//! | Entry                   | Contents                                             |
+//! |-------------------------|------------------------------------------------------|
+//! | `project.json`          | The project                                          |
+//! | `tasks.json`            | Tasks                                                |
+//! | `milestones.json`       | Milestones                                           |
+//! | `rfis.json`             | RFIs, each with its responses                        |
+//! | `trade_scopes.json`     | Extracted trade scopes                               |
+//! | `materials.csv`         | Extracted materials                                  |
+//! | `documents.json`        | Document metadata, with whether the file is included |
+//! | `documents/<id>_<name>` | Document files (unless `include_documents=false`)    |
+
+use axum::{
+    body::{Body, Bytes},
+    extract::{Path, Query, State},
+    http::header,
+    response::IntoResponse,
+};
+use chrono::{DateTime, Utc};
+use futures::channel::mpsc;
+use futures::{SinkExt, StreamExt};
+use sqlx::PgPool;
+use std::io;
+use std::sync::Arc;
+use tokio::io::AsyncReadExt;
+use uuid::Uuid;
+
+use crate::app::AppState;
+use crate::auth::RequireAuth;
+use crate::domain::activity::ActivityAction;
+use crate::domain::admin::AuditTargetType;
+use crate::domain::projects::ProjectExportQuery;
+use crate::error::{map_db_err, ApiError};
+use crate::middleware::ExtractClientIp;
+use crate::routes::admin::csv_escape;
+use crate::services::audit::{self, ActivityEntry};
+use crate::services::zip_stream::ZipWriter;
+
+/// Bytes read from a document file per chunk
+const FILE_CHUNK_SIZE: usize = 64 * 1024;
+
+type ChunkSender = mpsc::Sender<Result<Bytes, io::Error>>;
+
+/// Extracted material, with every column rendered as text for the CSV
+#[derive(Debug, sqlx::FromRow)]
+struct MaterialCsvRow {
+    id: String,
+    document_id: Option<String>,
+    name: String,
+    description: Option<String>,
+    quantity: Option<String>,
+    unit: Option<String>,
+    unit_cost: Option<String>,
+    total_cost: Option<String>,
+    location: Option<String>,
+    room: Option<String>,
+    specification: Option<String>,
+    trade_category: Option<String>,
+    csi_division: Option<String>,
+    source_page: Option<String>,
+    confidence: Option<String>,
+    is_verified: Option<String>,
+    created_at: String,
+}
+
+const MATERIAL_CSV_COLUMNS: &str = "id,document_id,name,description,quantity,unit,unit_cost,total_cost,location,room,specification,trade_category,csi_division,source_page,confidence,is_verified,created_at\n";
+
+impl MaterialCsvRow {
+    fn to_csv(&self) -> String {
+        let fields = [
+            Some(self.id.as_str()),
+            self.document_id.as_deref(),
+            Some(self.name.as_str()),
+            self.description.as_deref(),
+            self.quantity.as_deref(),
+            self.unit.as_deref(),
+            self.unit_cost.as_deref(),
+            self.total_cost.as_deref(),
+            self.location.as_deref(),
+            self.room.as_deref(),
+            self.specification.as_deref(),
+            self.trade_category.as_deref(),
+            self.csi_division.as_deref(),
+            self.source_page.as_deref(),
+            self.confidence.as_deref(),
+            self.is_verified.as_deref(),
+            Some(self.created_at.as_str()),
+        ];
+
+        let mut line = fields
+            .iter()
+            .map(|f| csv_escape(f.unwrap_or_default()))
+            .collect::<Vec<_>>()
+            .join(",");
+        line.push('\n');
+        line
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct DocumentExportRow {
+    id: Uuid,
+    name: String,
+    description: Option<String>,
+    document_type: Option<String>,
+    file_path: Option<String>,
+    file_size: Option<i64>,
+    mime_type: Option<String>,
+    version: Option<i32>,
+    status: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+/// GET /api/projects/:project_id/export
+///
+/// Download the project as a ZIP archive (see the module docs for its layout).
+/// Only the project owner can export. Pass `include_documents=false` to leave
+/// out the document files. Documents whose file is missing from storage are
+/// listed in `documents.json` with `included: false`.
+pub async fn export_project(
+    auth: RequireAuth,
+    State(state): State<Arc<AppState>>,
+    Path(project_id): Path<Uuid>,
+    Query(query): Query<ProjectExportQuery>,
+    ExtractClientIp(client_ip): ExtractClientIp,
+) -> Result<impl IntoResponse, ApiError> {
+    let project_name: Option<String> =
+        sqlx::query_scalar("SELECT name FROM projects WHERE id = $1 AND owner_id = $2")
+            .bind(project_id)
+            .bind(auth.user_id)
+            .fetch_optional(&state.db)
+            .await
+            .map_err(map_db_err("export_project"))?;
+
+    if project_name.is_none() {
+        return Err(ApiError::not_found("Project not found"));
+    }
+
+    tracing::info!(
+        user_id = %auth.user_id,
+        project_id = %project_id,
+        include_documents = query.include_documents,
+        "Exporting project"
+    );
+
+    if let Err(e) = audit::log_activity(
+        &state.db,
+        ActivityEntry {
+            actor_id: auth.user_id,
+            project_id: Some(project_id),
+            action: ActivityAction::ProjectExported,
+            target_type: AuditTargetType::Project,
+            target_id: Some(project_id),
+            details: serde_json::json!({ "include_documents": query.include_documents }),
+            ip_address: client_ip,
+        },
+    )
+    .await
+    {
+        tracing::warn!(error = %e, "Failed to log project export");
+    }
+
+    let (mut tx, rx) = mpsc::channel::<Result<Bytes, io::Error>>(64);
+    let db = state.db.clone();
+    let include_documents = query.include_documents;
+
+    tokio::spawn(async move {
+        if let Err(e) = write_export(&db, project_id, include_documents, &mut tx).await {
+            if e.kind() == io::ErrorKind::BrokenPipe {
+                tracing::debug!(project_id = %project_id, "Project export cancelled by client");
+            } else {
+                tracing::error!(project_id = %project_id, error = %e, "Project export failed");
+                // Fail the response so the client doesn't keep a truncated archive
+                let _ = tx.send(Err(e)).await;
+            }
+        }
+    });
+
+    let disposition = format!(
+        "attachment; filename=\"project-{}-{}.zip\"",
+        project_id,
+        Utc::now().format("%Y%m%d")
+    );
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/zip".to_string()),
+            (header::CONTENT_DISPOSITION, disposition),
+        ],
+        Body::from_stream(rx),
+    ))
+}
+
+/// Send a chunk to the client; a closed channel means they went away
+async fn send(tx: &mut ChunkSender, bytes: Vec<u8>) -> io::Result<()> {
+    if bytes.is_empty() {
+        return Ok(());
+    }
+    tx.send(Ok(Bytes::from(bytes)))
+        .await
+        .map_err(|_| io::Error::from(io::ErrorKind::BrokenPipe))
+}
+
+fn query_failed(e: sqlx::Error) -> io::Error {
+    io::Error::other(format!("export query failed: {}", e))
+}
+
+/// Build the archive, sending it to `tx` as it goes
+async fn write_export(
+    db: &PgPool,
+    project_id: Uuid,
+    include_documents: bool,
+    tx: &mut ChunkSender,
+) -> io::Result<()> {
+    let mut zip = ZipWriter::new();
+    let now = Utc::now();
+
+    let sections: [(&str, &str); 5] = [
+        (
+            "project.json",
+            "SELECT row_to_json(p) FROM projects p WHERE p.id = $1",
+        ),
+        (
+            "tasks.json",
+            "SELECT COALESCE(json_agg(t ORDER BY t.created_at, t.id), '[]') FROM tasks t WHERE t.project_id = $1",
+        ),
+        (
+            "milestones.json",
+            "SELECT COALESCE(json_agg(m ORDER BY m.phase_order, m.created_at, m.id), '[]') FROM project_milestones m WHERE m.project_id = $1",
+        ),
+        (
+            "rfis.json",
+            r#"
+            SELECT COALESCE(json_agg(
+                to_jsonb(r) || jsonb_build_object('responses', (
+                    SELECT COALESCE(jsonb_agg(to_jsonb(rr) ORDER BY rr.created_at, rr.id), '[]')
+                    FROM rfi_responses rr WHERE rr.rfi_id = r.id
+                ))
+                ORDER BY r.created_at, r.id
+            ), '[]')
+            FROM rfis r WHERE r.project_id = $1
+            "#,
+        ),
+        (
+            "trade_scopes.json",
+            "SELECT COALESCE(json_agg(s ORDER BY s.trade, s.id), '[]') FROM extracted_trade_scopes s WHERE s.project_id = $1",
+        ),
+    ];
+
+    for (name, sql) in sections {
+        let value: serde_json::Value = sqlx::query_scalar(sql)
+            .bind(project_id)
+            .fetch_one(db)
+            .await
+            .map_err(query_failed)?;
+        let json = serde_json::to_vec_pretty(&value).map_err(io::Error::other)?;
+        send(tx, zip.add(name, now, &json)?).await?;
+    }
+
+    // Materials can run to thousands of rows, so stream them into the entry
+    let (mut entry, header) = zip.start_entry("materials.csv", now)?;
+    send(tx, header).await?;
+    send(tx, zip.write(&mut entry, MATERIAL_CSV_COLUMNS.as_bytes())?).await?;
+    {
+        let mut rows = sqlx::query_as::<_, MaterialCsvRow>(
+            r#"
+            SELECT id::text, document_id::text, name, description, quantity::text, unit,
+                   unit_cost::text, total_cost::text, location, room, specification,
+                   trade_category, csi_division, source_page::text, confidence::text,
+                   is_verified::text, created_at::text
+            FROM extracted_materials
+            WHERE project_id = $1
+            ORDER BY trade_category NULLS LAST, name, id
+            "#,
+        )
+        .bind(project_id)
+        .fetch(db);
+
+        while let Some(row) = rows.next().await {
+            let row = row.map_err(query_failed)?;
+            send(tx, zip.write(&mut entry, row.to_csv().as_bytes())?).await?;
+        }
+    }
+    send(tx, zip.finish_entry(entry)?).await?;
+
+    let documents = sqlx::query_as::<_, DocumentExportRow>(
+        r#"
+        SELECT id, name, description, document_type, file_path, file_size, mime_type, version, status, created_at, updated_at
+        FROM documents
+        WHERE project_id = $1
+        ORDER BY created_at, id
+        "#,
+    )
+    .bind(project_id)
+    .fetch_all(db)
+    .await
+    .map_err(query_failed)?;
+
+    let mut manifest = Vec::with_capacity(documents.len());
+    for doc in &documents {
+        let mut archive_path = None;
+        if include_documents {
+            if let Some(file_path) = &doc.file_path {
+                let entry_name = document_entry_name(doc, file_path);
+                match write_document(&mut zip, tx, &entry_name, file_path, doc.updated_at).await? {
+                    true => archive_path = Some(entry_name),
+                    false => tracing::warn!(
+                        project_id = %project_id,
+                        document_id = %doc.id,
+                        "Document file missing; left out of project export"
+                    ),
+                }
+            }
+        }
+
+        manifest.push(serde_json::json!({
+            "id": doc.id,
+            "name": doc.name,
+            "description": doc.description,
+            "document_type": doc.document_type,
+            "file_size": doc.file_size,
+            "mime_type": doc.mime_type,
+            "version": doc.version,
+            "status": doc.status,
+            "created_at": doc.created_at,
+            "updated_at": doc.updated_at,
+            "included": archive_path.is_some(),
+            "archive_path": archive_path,
+        }));
+    }
+
+    let json = serde_json::to_vec_pretty(&manifest).map_err(io::Error::other)?;
+    send(tx, zip.add("documents.json", now, &json)?).await?;
+
+    send(tx, zip.finish()?).await
+}
+
+/// Copy a document file into the archive. Returns `false`, having written
+/// nothing, if the file can't be opened.
+async fn write_document(
+    zip: &mut ZipWriter,
+    tx: &mut ChunkSender,
+    entry_name: &str,
+    file_path: &str,
+    modified: DateTime<Utc>,
+) -> io::Result<bool> {
+    let mut file = match tokio::fs::File::open(file_path).await {
+        Ok(file) => file,
+        Err(e) => {
+            tracing::debug!(path = %file_path, error = %e, "Cannot open document file");
+            return Ok(false);
+        }
+    };
+
+    let (mut entry, header) = zip.start_entry(entry_name, modified)?;
+    send(tx, header).await?;
+
+    let mut buf = vec![0u8; FILE_CHUNK_SIZE];
+    loop {
+        let n = file.read(&mut buf).await?;
+        if n == 0 {
+            break;
+        }
+        send(tx, zip.write(&mut entry, &buf[..n])?).await?;
+    }
+
+    send(tx, zip.finish_entry(entry)?).await?;
+    Ok(true)
+}
+
+/// `documents/<id>_<name>`, keeping the stored file's extension when the
+/// display name has none
+fn document_entry_name(doc: &DocumentExportRow, file_path: &str) -> String {
+    let mut name: String = doc
+        .name
+        .chars()
+        .map(|c| if c.is_alphanumeric() || matches!(c, '.' | '-' | '_') { c } else { '_' })
+        .collect();
+
+    if !name.contains('.') {
+        if let Some(ext) = std::path::Path::new(file_path).extension().and_then(|e| e.to_str()) {
+            name.push('.');
+            name.push_str(ext);
+        }
+    }
+
+    format!("documents/{}_{}", doc.id, name)
+}
@@ -4,22 +4,24 @@
 
 use axum::{
     extract::{Path, Query, State},
-    http::StatusCode,
     response::IntoResponse,
     Json,
 };
 use chrono::{DateTime, Utc};
-use rust_decimal::prelude::*;
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::api::pagination::PaginationParams;
-use crate::api::response::{DataResponse, Paginated};
+use crate::api::pagination::{resolve_pagination, PaginationParams};
+use crate::api::response::{Created, DataResponse, NoContent, Paginated};
 use crate::app::AppState;
 use crate::auth::RequireAuth;
-use crate::domain::{CreateProjectRequest, ProjectResponse, ProjectStatus, UpdateProjectRequest};
-use crate::error::ApiError;
-use crate::services::cache::{keys as cache_keys, ttl as cache_ttl};
+use crate::domain::money::Money;
+use crate::domain::{
+    CreateProjectRequest, ExtractionStats, ProjectResponse, ProjectStats, ProjectStatus, RfiStats, StatusCounts,
+    UpdateProjectRequest, VerifiedCount,
+};
+use crate::error::{map_db_err, ApiError};
+use crate::services::cache::{keys as cache_keys, ttl as cache_ttl, CacheNamespace};
 
 /// Database row for project
 #[allow(dead_code)]
@@ -34,7 +36,7 @@ struct ProjectRow {
     state: Option<String>,
     zip_code: Option<String>,
     status: String,
-    estimated_value: Option<rust_decimal::Decimal>,
+    estimated_value: Option<Money>,
     bid_due_date: Option<DateTime<Utc>>,
     start_date: Option<DateTime<Utc>>,
     end_date: Option<DateTime<Utc>>,
@@ -57,11 +59,6 @@ impl TryFrom<ProjectRow> for ProjectResponse {
             _ => ProjectStatus::Draft,
         };
 
-        // Convert decimal to cents (i64)
-        let estimated_value = row
-            .estimated_value
-            .map(|d| (d * rust_decimal::Decimal::from(100)).to_i64().unwrap_or(0));
-
         Ok(Self {
             id: row.id,
             name: row.name,
@@ -71,7 +68,7 @@ impl TryFrom<ProjectRow> for ProjectResponse {
             state: row.state,
             zip_code: row.zip_code,
             status,
-            estimated_value,
+            estimated_value: row.estimated_value,
             bid_due_date: row.bid_due_date,
             start_date: row.start_date,
             end_date: row.end_date,
@@ -96,11 +93,6 @@ pub async fn create_project(
         "Creating project"
     );
 
-    // Convert cents to decimal for storage
-    let estimated_value = req
-        .estimated_value
-        .map(|cents| rust_decimal::Decimal::from(cents) / rust_decimal::Decimal::from(100));
-
     let project = sqlx::query_as::<_, ProjectRow>(
         r#"
         INSERT INTO projects (owner_id, name, description, address, city, state, zip_code, status, estimated_value, bid_due_date, start_date, end_date)
@@ -115,13 +107,13 @@ pub async fn create_project(
     .bind(&req.city)
     .bind(&req.state)
     .bind(&req.zip_code)
-    .bind(estimated_value)
+    .bind(req.estimated_value)
     .bind(req.bid_due_date)
     .bind(req.start_date)
     .bind(req.end_date)
     .fetch_one(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Failed to create project: {}", e)))?;
+    .map_err(map_db_err("create_project"))?;
 
     let response: ProjectResponse = project.try_into()?;
 
@@ -131,7 +123,7 @@ pub async fn create_project(
     // Invalidate dashboard stats
     let _ = state.cache.delete(&cache_keys::dashboard_stats(auth.user_id)).await;
 
-    Ok((StatusCode::CREATED, Json(DataResponse::new(response))))
+    Ok(Created(response))
 }
 
 /// Cached paginated response for projects
@@ -149,26 +141,23 @@ pub async fn list_projects(
     State(state): State<Arc<AppState>>,
     Query(pagination): Query<PaginationParams>,
 ) -> Result<impl IntoResponse, ApiError> {
-    let page = pagination.page();
-    let per_page = pagination.per_page();
+    let page = resolve_pagination(&pagination, state.settings.pagination.default)?;
 
     tracing::debug!(
         user_id = %auth.user_id,
-        page = page,
-        per_page = per_page,
+        page = page.page,
+        per_page = page.per_page,
         "Listing projects"
     );
 
-    let cache_key = cache_keys::project_list(auth.user_id, page, per_page);
+    let cache_key = cache_keys::project_list(auth.user_id, page.page, page.per_page);
 
     // Try cache first
     if let Some(cached) = state.cache.get::<CachedProjectList>(&cache_key).await {
         tracing::debug!(user_id = %auth.user_id, "Projects list cache hit");
-        return Ok(Json(Paginated::new(cached.data, &pagination, cached.total)));
+        return Ok(Json(Paginated::new(cached.data, &page, cached.total)));
     }
 
-    let offset = pagination.offset() as i64;
-    let limit = pagination.limit() as i64;
 
     // Get total count (also cached separately for reuse)
     let count_cache_key = cache_keys::project_count(auth.user_id);
@@ -179,7 +168,7 @@ pub async fn list_projects(
             .bind(auth.user_id)
             .fetch_one(&state.db)
             .await
-            .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+            .map_err(map_db_err("list_projects"))?;
         let _ = state.cache.set_with_ttl(&count_cache_key, &count, cache_ttl::COUNT).await;
         count
     };
@@ -190,16 +179,16 @@ pub async fn list_projects(
         SELECT id, owner_id, name, description, address, city, state, zip_code, status, estimated_value, bid_due_date, start_date, end_date, created_at, updated_at
         FROM projects
         WHERE owner_id = $1
-        ORDER BY created_at DESC
+        ORDER BY created_at DESC, id
         LIMIT $2 OFFSET $3
         "#,
     )
     .bind(auth.user_id)
-    .bind(limit)
-    .bind(offset)
+    .bind(page.limit())
+    .bind(page.offset())
     .fetch_all(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("list_projects"))?;
 
     let data: Vec<ProjectResponse> = projects
         .into_iter()
@@ -208,9 +197,9 @@ pub async fn list_projects(
 
     // Cache the result
     let cached = CachedProjectList { data: data.clone(), total: total as u64 };
-    let _ = state.cache.set_with_ttl(&cache_key, &cached, cache_ttl::LIST).await;
+    let _ = state.cache.set_in(CacheNamespace::Listing, &cache_key, &cached).await;
 
-    Ok(Json(Paginated::new(data, &pagination, total as u64)))
+    Ok(Json(Paginated::new(data, &page, total as u64)))
 }
 
 /// GET /api/projects/:project_id
@@ -248,7 +237,7 @@ pub async fn get_project(
     .bind(auth.user_id)
     .fetch_optional(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
+    .map_err(map_db_err("get_project"))?
     .ok_or_else(|| ApiError::not_found("Project not found"))?;
 
     let response: ProjectResponse = project.try_into()?;
@@ -259,6 +248,131 @@ pub async fn get_project(
     Ok(Json(DataResponse::new(response)))
 }
 
+/// Aggregate counts for the project stats endpoint
+#[derive(Debug, sqlx::FromRow)]
+struct ProjectStatsRow {
+    documents: i64,
+    bids_received: i64,
+    team_members: i64,
+    rfis_total: i64,
+    rfis_open: i64,
+    rfis_overdue: i64,
+    materials_total: i64,
+    materials_verified: i64,
+    materials_total_cost: Money,
+    rooms_total: i64,
+    rooms_verified: i64,
+    milestones_total: i64,
+    milestones_verified: i64,
+    trade_scopes_total: i64,
+    trade_scopes_verified: i64,
+    trade_scopes_estimated_value: Money,
+}
+
+/// GET /api/projects/:project_id/stats
+///
+/// Dashboard statistics for a project: documents, tenders and bids, RFIs,
+/// tasks, team, and extraction progress.
+pub async fn get_project_stats(
+    auth: RequireAuth,
+    State(state): State<Arc<AppState>>,
+    Path(project_id): Path<Uuid>,
+) -> Result<impl IntoResponse, ApiError> {
+    let is_owner: bool = sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM projects WHERE id = $1 AND owner_id = $2)",
+    )
+    .bind(project_id)
+    .bind(auth.user_id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(map_db_err("get_project_stats"))?;
+
+    if !is_owner {
+        return Err(ApiError::not_found("Project not found"));
+    }
+
+    let row = sqlx::query_as::<_, ProjectStatsRow>(
+        r#"
+        SELECT
+            (SELECT COUNT(*) FROM documents WHERE project_id = $1) AS documents,
+            (SELECT COUNT(*) FROM bids b JOIN tenders t ON t.id = b.tender_id
+             WHERE t.project_id = $1) AS bids_received,
+            (SELECT COUNT(*) FROM project_team
+             WHERE project_id = $1 AND status != 'terminated') AS team_members,
+            r.total AS rfis_total, r.open AS rfis_open, r.overdue AS rfis_overdue,
+            m.total AS materials_total, m.verified AS materials_verified, m.cost AS materials_total_cost,
+            rm.total AS rooms_total, rm.verified AS rooms_verified,
+            ms.total AS milestones_total, ms.verified AS milestones_verified,
+            ts.total AS trade_scopes_total, ts.verified AS trade_scopes_verified,
+            ts.value AS trade_scopes_estimated_value
+        FROM
+            (SELECT COUNT(*) AS total,
+                    COUNT(*) FILTER (WHERE status = 'open') AS open,
+                    COUNT(*) FILTER (WHERE status = 'open' AND due_date < NOW()) AS overdue
+             FROM rfis WHERE project_id = $1) r,
+            (SELECT COUNT(*) AS total, COUNT(*) FILTER (WHERE is_verified) AS verified,
+                    COALESCE(SUM(total_cost), 0) AS cost
+             FROM extracted_materials WHERE project_id = $1) m,
+            (SELECT COUNT(*) AS total, COUNT(*) FILTER (WHERE is_verified) AS verified
+             FROM extracted_rooms WHERE project_id = $1) rm,
+            (SELECT COUNT(*) AS total, COUNT(*) FILTER (WHERE is_verified) AS verified
+             FROM project_milestones WHERE project_id = $1) ms,
+            (SELECT COUNT(*) AS total, COUNT(*) FILTER (WHERE is_verified) AS verified,
+                    COALESCE(SUM(estimated_value), 0) AS value
+             FROM extracted_trade_scopes WHERE project_id = $1) ts
+        "#,
+    )
+    .bind(project_id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(map_db_err("get_project_stats"))?;
+
+    let status_counts: Vec<(String, String, i64)> = sqlx::query_as(
+        r#"
+        SELECT 'tender', COALESCE(status, 'unknown'), COUNT(*) FROM tenders WHERE project_id = $1 GROUP BY 2
+        UNION ALL
+        SELECT 'task', COALESCE(status, 'unknown'), COUNT(*) FROM tasks WHERE project_id = $1 GROUP BY 2
+        "#,
+    )
+    .bind(project_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(map_db_err("get_project_stats"))?;
+
+    let mut tenders = StatusCounts::default();
+    let mut tasks = StatusCounts::default();
+    for (kind, status, count) in status_counts {
+        match kind.as_str() {
+            "tender" => tenders.add(status, count),
+            _ => tasks.add(status, count),
+        }
+    }
+
+    let stats = ProjectStats {
+        project_id,
+        documents: row.documents,
+        tenders,
+        bids_received: row.bids_received,
+        rfis: RfiStats {
+            total: row.rfis_total,
+            open: row.rfis_open,
+            overdue: row.rfis_overdue,
+        },
+        tasks,
+        team_members: row.team_members,
+        extraction: ExtractionStats {
+            materials: VerifiedCount::new(row.materials_total, row.materials_verified),
+            rooms: VerifiedCount::new(row.rooms_total, row.rooms_verified),
+            milestones: VerifiedCount::new(row.milestones_total, row.milestones_verified),
+            trade_scopes: VerifiedCount::new(row.trade_scopes_total, row.trade_scopes_verified),
+            materials_total_cost: row.materials_total_cost,
+            trade_scopes_estimated_value: row.trade_scopes_estimated_value,
+        },
+    };
+
+    Ok(Json(DataResponse::new(stats)))
+}
+
 /// PUT /api/projects/:project_id
 ///
 /// Update a project.
@@ -282,7 +396,7 @@ pub async fn update_project(
     .bind(auth.user_id)
     .fetch_optional(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("update_project"))?;
 
     if exists.is_none() {
         return Err(ApiError::not_found("Project not found"));
@@ -299,11 +413,6 @@ pub async fn update_project(
         ProjectStatus::Cancelled => "cancelled",
     });
 
-    // Convert cents to decimal
-    let estimated_value = req
-        .estimated_value
-        .map(|cents| rust_decimal::Decimal::from(cents) / rust_decimal::Decimal::from(100));
-
     let project = sqlx::query_as::<_, ProjectRow>(
         r#"
         UPDATE projects SET
@@ -332,13 +441,13 @@ pub async fn update_project(
     .bind(&req.state)
     .bind(&req.zip_code)
     .bind(status)
-    .bind(estimated_value)
+    .bind(req.estimated_value)
     .bind(req.bid_due_date)
     .bind(req.start_date)
     .bind(req.end_date)
     .fetch_one(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Failed to update project: {}", e)))?;
+    .map_err(map_db_err("update_project"))?;
 
     let response: ProjectResponse = project.try_into()?;
 
@@ -372,7 +481,7 @@ pub async fn delete_project(
         .bind(auth.user_id)
         .execute(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+        .map_err(map_db_err("delete_project"))?;
 
     if result.rows_affected() == 0 {
         return Err(ApiError::not_found("Project not found"));
@@ -392,5 +501,5 @@ pub async fn delete_project(
     let _ = state.cache.delete_pattern(&cache_keys::tender_user_pattern(auth.user_id)).await;
     let _ = state.cache.delete_pattern(&cache_keys::task_user_pattern(auth.user_id)).await;
 
-    Ok(StatusCode::NO_CONTENT)
+    Ok(NoContent)
 }
@@ -4,7 +4,6 @@
 
 use axum::{
     extract::{Path, Query, State},
-    http::StatusCode,
     response::IntoResponse,
     Json,
 };
@@ -12,15 +11,18 @@ use chrono::{DateTime, Utc};
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::api::pagination::PaginationParams;
-use crate::api::response::{DataResponse, MessageResponse, Paginated, PaginationMeta};
+use crate::api::pagination::{resolve_pagination, PaginationParams};
+use crate::api::sort::{SortFields, SortOrder, SortSpec};
+use crate::api::response::{Created, DataResponse, NoContent, Paginated};
 use crate::app::AppState;
 use crate::auth::RequireAuth;
 use crate::domain::rfis::{
-    CreateRFIRequest, CreateRFIResponseRequest, RFIPriority, RFIResponse, RFIResponseDTO,
-    RFIStatus, UpdateRFIRequest,
+    AcceptRFIResponseRequest, CreateRFIRequest, CreateRFIResponseRequest, RFIListQuery, RFIPriority, RFIResponse,
+    RFIResponseDTO, RFIStatus, UpdateRFIRequest,
 };
-use crate::error::ApiError;
+use crate::error::{map_db_err, ApiError};
+use crate::services::notifications;
+use crate::services::watchers::{self, WatchedResource};
 
 /// Database row for RFI
 #[derive(Debug, sqlx::FromRow)]
@@ -40,6 +42,8 @@ struct RFIRow {
     due_date: Option<DateTime<Utc>>,
     responses_count: i32,
     attachments_count: i32,
+    accepted_response_id: Option<Uuid>,
+    accepted_at: Option<DateTime<Utc>>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
@@ -71,6 +75,8 @@ impl From<RFIRow> for RFIResponse {
             due_date: row.due_date,
             responses_count: row.responses_count,
             attachments_count: row.attachments_count,
+            accepted_response_id: row.accepted_response_id,
+            accepted_at: row.accepted_at,
             created_at: row.created_at,
             updated_at: row.updated_at,
         }
@@ -85,6 +91,7 @@ struct RFIResponseRow {
     content: String,
     author: Option<String>,
     author_id: Uuid,
+    is_accepted: bool,
     created_at: DateTime<Utc>,
 }
 
@@ -96,6 +103,7 @@ impl From<RFIResponseRow> for RFIResponseDTO {
             content: row.content,
             author: row.author.unwrap_or_default(),
             author_id: row.author_id,
+            is_accepted: row.is_accepted,
             created_at: row.created_at,
         }
     }
@@ -110,16 +118,14 @@ pub async fn list_rfis(
     Query(pagination): Query<PaginationParams>,
     _auth: RequireAuth,
 ) -> Result<impl IntoResponse, ApiError> {
-    let page = pagination.page.unwrap_or(1).max(1);
-    let per_page = pagination.per_page.unwrap_or(20).min(100);
-    let offset = ((page - 1) * per_page) as i64;
+    let page = resolve_pagination(&pagination, state.settings.pagination.default)?;
 
     // Get total count
     let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM rfis WHERE project_id = $1")
         .bind(project_id)
         .fetch_one(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+        .map_err(map_db_err("list_rfis"))?;
 
     // Get RFIs
     let rfis = sqlx::query_as::<_, RFIRow>(
@@ -130,67 +136,107 @@ pub async fn list_rfis(
                r.category, r.due_date,
                (SELECT COUNT(*)::int FROM rfi_responses WHERE rfi_id = r.id) as responses_count,
                0 as attachments_count,
+               r.accepted_response_id, r.accepted_at,
                r.created_at, r.updated_at
         FROM rfis r
         LEFT JOIN profiles req ON r.requester_id = req.id
         LEFT JOIN profiles asg ON r.assignee_id = asg.id
         WHERE r.project_id = $1
-        ORDER BY r.created_at DESC
+        ORDER BY r.created_at DESC, r.id
         LIMIT $2 OFFSET $3
         "#,
     )
     .bind(project_id)
-    .bind(per_page as i64)
-    .bind(offset)
+    .bind(page.limit())
+    .bind(page.offset())
     .fetch_all(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("list_rfis"))?;
 
     let data: Vec<RFIResponse> = rfis.into_iter().map(Into::into).collect();
-    let total_pages = ((total as f64) / (per_page as f64)).ceil() as u32;
-
-    let response = Paginated {
-        data,
-        pagination: PaginationMeta {
-            page,
-            per_page,
-            total_items: total as u64,
-            total_pages,
-            has_next: page < total_pages,
-            has_prev: page > 1,
-        },
-    };
+
+    let response = Paginated::new(data, &page, total as u64);
 
     Ok(Json(response))
 }
 
+/// `sort_by` keys for the cross-project RFI list
+const RFI_SORT_FIELDS: SortFields = &[
+    ("created_at", "r.created_at"),
+    ("updated_at", "r.updated_at"),
+    ("due_date", "r.due_date"),
+    (
+        "priority",
+        "CASE r.priority WHEN 'urgent' THEN 4 WHEN 'high' THEN 3 WHEN 'medium' THEN 2 ELSE 1 END",
+    ),
+    ("number", "r.number"),
+];
+
+/// Filters shared by the count and page queries of `list_all_rfis`; `$1` is
+/// the caller, whose own projects are the only ones searched
+const ALL_RFIS_FILTER: &str = r#"
+    WHERE p.owner_id = $1
+    AND ($2::text IS NULL OR r.status = $2)
+    AND ($3::text IS NULL OR r.priority = $3)
+    AND ($4::uuid IS NULL OR r.project_id = $4)
+    AND ($5::uuid IS NULL OR r.assignee_id = $5)
+    AND ($6::timestamptz IS NULL OR r.due_date >= $6)
+    AND ($7::timestamptz IS NULL OR r.due_date <= $7)
+"#;
+
 /// GET /api/rfis
 ///
-/// List all RFIs for the current user across all projects.
+/// List RFIs across all of the current user's projects, e.g. an inbox of open
+/// RFIs on every job.
+///
+/// Supports `status`, `priority`, `project_id`, `assignee_id`, a due date
+/// range (`due_from`, `due_to`), `sort_by` (created_at, updated_at, due_date,
+/// priority, number) and `sort_order`. Newest first by default.
 pub async fn list_all_rfis(
     State(state): State<Arc<AppState>>,
     Query(pagination): Query<PaginationParams>,
+    Query(query): Query<RFIListQuery>,
     auth: RequireAuth,
 ) -> Result<impl IntoResponse, ApiError> {
-    let page = pagination.page.unwrap_or(1).max(1);
-    let per_page = pagination.per_page.unwrap_or(20).min(100);
-    let offset = ((page - 1) * per_page) as i64;
+    let page = resolve_pagination(&pagination, state.settings.pagination.default)?;
+
+    if let (Some(from), Some(to)) = (query.due_from, query.due_to) {
+        if from > to {
+            return Err(ApiError::bad_request("due_from must not be after due_to"));
+        }
+    }
+
+    let sort = SortSpec::parse(
+        RFI_SORT_FIELDS,
+        SortOrder::Desc,
+        query.sort_by.as_deref(),
+        query.sort_order.as_deref(),
+    )?;
+    let status = query.status.as_ref().map(RFIStatus::as_str);
+    let priority = query.priority.as_ref().map(RFIPriority::as_str);
 
     // Get total count
-    let total: i64 = sqlx::query_scalar(
+    let total: i64 = sqlx::query_scalar(&format!(
         r#"
         SELECT COUNT(*) FROM rfis r
         JOIN projects p ON r.project_id = p.id
-        WHERE p.owner_id = $1
+        {}
         "#,
-    )
+        ALL_RFIS_FILTER
+    ))
     .bind(auth.user_id)
+    .bind(status)
+    .bind(priority)
+    .bind(query.project_id)
+    .bind(query.assignee_id)
+    .bind(query.due_from)
+    .bind(query.due_to)
     .fetch_one(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("list_all_rfis"))?;
 
     // Get RFIs
-    let rfis = sqlx::query_as::<_, RFIRow>(
+    let rfis = sqlx::query_as::<_, RFIRow>(&format!(
         r#"
         SELECT r.id, r.project_id, r.number, r.title, r.description, r.status, r.priority,
                req.first_name || ' ' || req.last_name as requester, r.requester_id,
@@ -198,37 +244,34 @@ pub async fn list_all_rfis(
                r.category, r.due_date,
                (SELECT COUNT(*)::int FROM rfi_responses WHERE rfi_id = r.id) as responses_count,
                0 as attachments_count,
+               r.accepted_response_id, r.accepted_at,
                r.created_at, r.updated_at
         FROM rfis r
         JOIN projects p ON r.project_id = p.id
         LEFT JOIN profiles req ON r.requester_id = req.id
         LEFT JOIN profiles asg ON r.assignee_id = asg.id
-        WHERE p.owner_id = $1
-        ORDER BY r.created_at DESC
-        LIMIT $2 OFFSET $3
+        {}
+        ORDER BY {} NULLS LAST, r.id
+        LIMIT $8 OFFSET $9
         "#,
-    )
+        ALL_RFIS_FILTER, sort
+    ))
     .bind(auth.user_id)
-    .bind(per_page as i64)
-    .bind(offset)
+    .bind(status)
+    .bind(priority)
+    .bind(query.project_id)
+    .bind(query.assignee_id)
+    .bind(query.due_from)
+    .bind(query.due_to)
+    .bind(page.limit())
+    .bind(page.offset())
     .fetch_all(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("list_all_rfis"))?;
 
     let data: Vec<RFIResponse> = rfis.into_iter().map(Into::into).collect();
-    let total_pages = ((total as f64) / (per_page as f64)).ceil() as u32;
-
-    let response = Paginated {
-        data,
-        pagination: PaginationMeta {
-            page,
-            per_page,
-            total_items: total as u64,
-            total_pages,
-            has_next: page < total_pages,
-            has_prev: page > 1,
-        },
-    };
+
+    let response = Paginated::new(data, &page, total as u64);
 
     Ok(Json(response))
 }
@@ -249,6 +292,7 @@ pub async fn get_rfi(
                r.category, r.due_date,
                (SELECT COUNT(*)::int FROM rfi_responses WHERE rfi_id = r.id) as responses_count,
                0 as attachments_count,
+               r.accepted_response_id, r.accepted_at,
                r.created_at, r.updated_at
         FROM rfis r
         LEFT JOIN profiles req ON r.requester_id = req.id
@@ -260,7 +304,7 @@ pub async fn get_rfi(
     .bind(project_id)
     .fetch_optional(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
+    .map_err(map_db_err("get_rfi"))?
     .ok_or_else(|| ApiError::not_found("RFI not found"))?;
 
     let response: RFIResponse = rfi.into();
@@ -290,7 +334,7 @@ pub async fn create_rfi(
     .bind(project_id)
     .fetch_one(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("create_rfi"))?;
 
     let rfi = sqlx::query_as::<_, RFIRow>(
         r#"
@@ -300,6 +344,7 @@ pub async fn create_rfi(
         RETURNING id, project_id, number, title, description, status, priority,
                   NULL as requester, requester_id, NULL as assignee, assignee_id,
                   category, due_date, 0 as responses_count, 0 as attachments_count,
+                  NULL::uuid as accepted_response_id, NULL::timestamptz as accepted_at,
                   created_at, updated_at
         "#,
     )
@@ -309,24 +354,25 @@ pub async fn create_rfi(
     .bind(&req.description)
     .bind(priority)
     .bind(auth.user_id)
-    .bind(&req.assignee_id)
+    .bind(req.assignee_id)
     .bind(&req.category)
-    .bind(&req.due_date)
+    .bind(req.due_date)
     .fetch_one(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("create_rfi"))?;
 
     let response: RFIResponse = rfi.into();
-    Ok((StatusCode::CREATED, Json(DataResponse::new(response))))
+    Ok(Created(response))
 }
 
 /// PUT /api/projects/:project_id/rfis/:rfi_id
 ///
-/// Update an RFI.
+/// Update an RFI. Setting `status` back to `open` reopens it and clears the
+/// accepted response.
 pub async fn update_rfi(
     State(state): State<Arc<AppState>>,
     Path((project_id, rfi_id)): Path<(Uuid, Uuid)>,
-    _auth: RequireAuth,
+    auth: RequireAuth,
     Json(req): Json<UpdateRFIRequest>,
 ) -> Result<impl IntoResponse, ApiError> {
     let status = req.status.map(|s| match s {
@@ -342,6 +388,14 @@ pub async fn update_rfi(
         RFIPriority::Medium => "medium",
     });
 
+    let previous_status: Option<String> =
+        sqlx::query_scalar("SELECT status FROM rfis WHERE id = $1 AND project_id = $2")
+            .bind(rfi_id)
+            .bind(project_id)
+            .fetch_optional(&state.db)
+            .await
+            .map_err(map_db_err("update_rfi"))?;
+
     let rfi = sqlx::query_as::<_, RFIRow>(
         r#"
         UPDATE rfis SET
@@ -352,6 +406,9 @@ pub async fn update_rfi(
             assignee_id = COALESCE($7, assignee_id),
             category = COALESCE($8, category),
             due_date = COALESCE($9, due_date),
+            accepted_response_id = CASE WHEN $5 = 'open' THEN NULL ELSE accepted_response_id END,
+            accepted_by = CASE WHEN $5 = 'open' THEN NULL ELSE accepted_by END,
+            accepted_at = CASE WHEN $5 = 'open' THEN NULL ELSE accepted_at END,
             updated_at = NOW()
         WHERE id = $1 AND project_id = $2
         RETURNING id, project_id, number, title, description, status, priority,
@@ -359,6 +416,7 @@ pub async fn update_rfi(
                   category, due_date,
                   (SELECT COUNT(*)::int FROM rfi_responses WHERE rfi_id = rfis.id) as responses_count,
                   0 as attachments_count,
+                  accepted_response_id, accepted_at,
                   created_at, updated_at
         "#,
     )
@@ -368,14 +426,35 @@ pub async fn update_rfi(
     .bind(&req.description)
     .bind(status)
     .bind(priority)
-    .bind(&req.assignee_id)
+    .bind(req.assignee_id)
     .bind(&req.category)
-    .bind(&req.due_date)
+    .bind(req.due_date)
     .fetch_optional(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
+    .map_err(map_db_err("update_rfi"))?
     .ok_or_else(|| ApiError::not_found("RFI not found"))?;
 
+    if previous_status.is_some_and(|previous| previous != rfi.status) {
+        let participants: Vec<Uuid> = [Some(rfi.requester_id), rfi.assignee_id].into_iter().flatten().collect();
+        let notified = async {
+            let recipients =
+                watchers::recipients(&state.db, WatchedResource::Rfi, rfi_id, &participants, &[auth.user_id])
+                    .await?;
+            notifications::notify_rfi_status_changed(
+                &state.db,
+                &recipients,
+                project_id,
+                rfi_id,
+                &rfi.title,
+                &rfi.status,
+            )
+            .await
+        };
+        if let Err(e) = notified.await {
+            tracing::warn!(error = %e, rfi_id = %rfi_id, "Failed to send RFI status notifications");
+        }
+    }
+
     let response: RFIResponse = rfi.into();
     Ok(Json(DataResponse::new(response)))
 }
@@ -393,16 +472,13 @@ pub async fn delete_rfi(
         .bind(project_id)
         .execute(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+        .map_err(map_db_err("delete_rfi"))?;
 
     if result.rows_affected() == 0 {
         return Err(ApiError::not_found("RFI not found"));
     }
 
-    Ok((
-        StatusCode::OK,
-        Json(MessageResponse::new("RFI deleted successfully")),
-    ))
+    Ok(NoContent)
 }
 
 /// POST /api/projects/:project_id/rfis/:rfi_id/responses
@@ -415,24 +491,21 @@ pub async fn add_rfi_response(
     Json(req): Json<CreateRFIResponseRequest>,
 ) -> Result<impl IntoResponse, ApiError> {
     // Verify RFI exists
-    let exists: bool = sqlx::query_scalar(
-        "SELECT EXISTS(SELECT 1 FROM rfis WHERE id = $1 AND project_id = $2)",
+    let rfi = sqlx::query_as::<_, RFIParticipants>(
+        "SELECT title, requester_id, assignee_id FROM rfis WHERE id = $1 AND project_id = $2",
     )
     .bind(rfi_id)
     .bind(project_id)
-    .fetch_one(&state.db)
+    .fetch_optional(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
-
-    if !exists {
-        return Err(ApiError::not_found("RFI not found"));
-    }
+    .map_err(map_db_err("add_rfi_response"))?
+    .ok_or_else(|| ApiError::not_found("RFI not found"))?;
 
     let response = sqlx::query_as::<_, RFIResponseRow>(
         r#"
         INSERT INTO rfi_responses (rfi_id, content, author_id, created_at)
         VALUES ($1, $2, $3, NOW())
-        RETURNING id, rfi_id, content, NULL as author, author_id, created_at
+        RETURNING id, rfi_id, content, NULL as author, author_id, false as is_accepted, created_at
         "#,
     )
     .bind(rfi_id)
@@ -440,10 +513,43 @@ pub async fn add_rfi_response(
     .bind(auth.user_id)
     .fetch_one(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("add_rfi_response"))?;
+
+    let author_name: Option<String> =
+        sqlx::query_scalar("SELECT first_name || ' ' || last_name FROM profiles WHERE id = $1")
+            .bind(auth.user_id)
+            .fetch_optional(&state.db)
+            .await
+            .ok()
+            .flatten();
+    let author_name = author_name.unwrap_or_else(|| "Someone".to_string());
+
+    let notified = async {
+        let recipients = watchers::recipients(
+            &state.db,
+            WatchedResource::Rfi,
+            rfi_id,
+            &rfi.participants(),
+            &[auth.user_id],
+        )
+        .await?;
+        notifications::notify_rfi_response_added(
+            &state.db,
+            &recipients,
+            project_id,
+            rfi_id,
+            response.id,
+            &rfi.title,
+            &author_name,
+        )
+        .await
+    };
+    if let Err(e) = notified.await {
+        tracing::warn!(error = %e, rfi_id = %rfi_id, "Failed to send RFI response notifications");
+    }
 
     let dto: RFIResponseDTO = response.into();
-    Ok((StatusCode::CREATED, Json(DataResponse::new(dto))))
+    Ok(Created(dto))
 }
 
 /// GET /api/projects/:project_id/rfis/:rfi_id/responses
@@ -462,7 +568,7 @@ pub async fn get_rfi_responses(
     .bind(project_id)
     .fetch_one(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("get_rfi_responses"))?;
 
     if !exists {
         return Err(ApiError::not_found("RFI not found"));
@@ -471,8 +577,10 @@ pub async fn get_rfi_responses(
     let responses = sqlx::query_as::<_, RFIResponseRow>(
         r#"
         SELECT r.id, r.rfi_id, r.content, 
-               p.first_name || ' ' || p.last_name as author, r.author_id, r.created_at
+               p.first_name || ' ' || p.last_name as author, r.author_id,
+               COALESCE(f.accepted_response_id = r.id, false) as is_accepted, r.created_at
         FROM rfi_responses r
+        JOIN rfis f ON r.rfi_id = f.id
         LEFT JOIN profiles p ON r.author_id = p.id
         WHERE r.rfi_id = $1
         ORDER BY r.created_at ASC
@@ -481,8 +589,130 @@ pub async fn get_rfi_responses(
     .bind(rfi_id)
     .fetch_all(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("get_rfi_responses"))?;
 
     let data: Vec<RFIResponseDTO> = responses.into_iter().map(Into::into).collect();
     Ok(Json(DataResponse::new(data)))
 }
+
+/// RFI fields needed to accept a response
+#[derive(Debug, sqlx::FromRow)]
+struct RFIAcceptTarget {
+    title: String,
+    requester_id: Option<Uuid>,
+    assignee_id: Option<Uuid>,
+    owner_id: Option<Uuid>,
+}
+
+/// The people an RFI notifies besides its watchers
+#[derive(Debug, sqlx::FromRow)]
+struct RFIParticipants {
+    title: String,
+    requester_id: Option<Uuid>,
+    assignee_id: Option<Uuid>,
+}
+
+impl RFIParticipants {
+    fn participants(&self) -> Vec<Uuid> {
+        [self.requester_id, self.assignee_id].into_iter().flatten().collect()
+    }
+}
+
+/// POST /api/projects/:project_id/rfis/:rfi_id/responses/:response_id/accept
+///
+/// Accept a response as the RFI's answer (asker or project owner only). The
+/// RFI moves to `answered`, or `closed` with `{"close": true}`; accepting
+/// another response replaces the earlier one. The asker, the assignee, the
+/// response's author and the RFI's watchers are notified.
+pub async fn accept_rfi_response(
+    State(state): State<Arc<AppState>>,
+    Path((project_id, rfi_id, response_id)): Path<(Uuid, Uuid, Uuid)>,
+    auth: RequireAuth,
+    input: Option<Json<AcceptRFIResponseRequest>>,
+) -> Result<impl IntoResponse, ApiError> {
+    let req = input.map(|Json(i)| i).unwrap_or_default();
+
+    let target = sqlx::query_as::<_, RFIAcceptTarget>(
+        r#"
+        SELECT r.title, r.requester_id, r.assignee_id, p.owner_id
+        FROM rfis r
+        JOIN projects p ON r.project_id = p.id
+        WHERE r.id = $1 AND r.project_id = $2
+        "#,
+    )
+    .bind(rfi_id)
+    .bind(project_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("accept_rfi_response"))?
+    .ok_or_else(|| ApiError::not_found("RFI not found"))?;
+
+    if target.requester_id != Some(auth.user_id) && target.owner_id != Some(auth.user_id) {
+        return Err(ApiError::forbidden(
+            "Only the RFI's asker or the project owner can accept a response",
+        ));
+    }
+
+    let author_id: Uuid = sqlx::query_scalar("SELECT author_id FROM rfi_responses WHERE id = $1 AND rfi_id = $2")
+        .bind(response_id)
+        .bind(rfi_id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(map_db_err("accept_rfi_response"))?
+        .ok_or_else(|| ApiError::not_found("Response not found"))?;
+
+    let status = if req.close { RFIStatus::Closed } else { RFIStatus::Answered };
+
+    let rfi = sqlx::query_as::<_, RFIRow>(
+        r#"
+        UPDATE rfis SET
+            status = $3,
+            accepted_response_id = $4,
+            accepted_by = $5,
+            accepted_at = NOW(),
+            updated_at = NOW()
+        WHERE id = $1 AND project_id = $2
+        RETURNING id, project_id, number, title, description, status, priority,
+                  NULL as requester, requester_id, NULL as assignee, assignee_id,
+                  category, due_date,
+                  (SELECT COUNT(*)::int FROM rfi_responses WHERE rfi_id = rfis.id) as responses_count,
+                  0 as attachments_count,
+                  accepted_response_id, accepted_at,
+                  created_at, updated_at
+        "#,
+    )
+    .bind(rfi_id)
+    .bind(project_id)
+    .bind(status.as_str())
+    .bind(response_id)
+    .bind(auth.user_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("accept_rfi_response"))?
+    .ok_or_else(|| ApiError::not_found("RFI not found"))?;
+
+    let participants: Vec<Uuid> = [target.requester_id, target.assignee_id, Some(author_id)]
+        .into_iter()
+        .flatten()
+        .collect();
+
+    let notified = async {
+        let recipients =
+            watchers::recipients(&state.db, WatchedResource::Rfi, rfi_id, &participants, &[auth.user_id]).await?;
+        notifications::notify_rfi_response_accepted(
+            &state.db,
+            &recipients,
+            project_id,
+            rfi_id,
+            response_id,
+            &target.title,
+        )
+        .await
+    };
+    if let Err(e) = notified.await {
+        tracing::warn!(error = %e, "Failed to send RFI acceptance notifications");
+    }
+
+    let response: RFIResponse = rfi.into();
+    Ok(Json(DataResponse::new(response)))
+}
@@ -1,6 +1,8 @@
 //! User settings routes
 //!
-//! User preferences and notification settings endpoints.
+//! User preferences and notification settings endpoints. Every account has
+//! settings: a row is seeded with the defaults on first read, and updates
+//! are upserts that merge the sent keys into the stored ones.
 
 use axum::{extract::State, response::IntoResponse, Json};
 use chrono::{DateTime, Utc};
@@ -10,14 +12,17 @@ use uuid::Uuid;
 use crate::api::response::DataResponse;
 use crate::app::AppState;
 use crate::auth::RequireAuth;
-use crate::domain::settings::{NotificationSettings, UpdateUserSettingsRequest, UserSettingsResponse};
-use crate::error::ApiError;
+use crate::domain::settings::{
+    NotificationSettings, UpdateUserSettingsRequest, UserSettingsResponse, SETTINGS_SCHEMA_VERSION,
+};
+use crate::error::{map_db_err, ApiError};
 
 /// Database row for user settings
 #[derive(Debug, sqlx::FromRow)]
 struct UserSettingsRow {
     user_id: Uuid,
     notification_settings: serde_json::Value,
+    schema_version: i32,
     updated_at: DateTime<Utc>,
 }
 
@@ -31,74 +36,121 @@ impl TryFrom<UserSettingsRow> for UserSettingsResponse {
 
         Ok(Self {
             user_id: row.user_id,
+            schema_version: row.schema_version,
             notification_settings,
             updated_at: row.updated_at,
         })
     }
 }
 
+/// Default notification settings as stored JSON
+fn default_notification_settings() -> Result<serde_json::Value, ApiError> {
+    serde_json::to_value(NotificationSettings::default())
+        .map_err(|e| ApiError::internal(format!("Failed to serialize settings: {}", e)))
+}
+
 /// GET /api/settings
 ///
-/// Get user settings.
+/// Get user settings, seeding the defaults on first read. Rows written under
+/// an older schema version are upgraded with the defaults of newer keys.
 pub async fn get_settings(
     State(state): State<Arc<AppState>>,
     auth: RequireAuth,
 ) -> Result<impl IntoResponse, ApiError> {
+    let defaults = default_notification_settings()?;
+
+    // The seeded row is not visible to the second SELECT in the same
+    // statement, so at most one of the two branches returns a row
     let settings = sqlx::query_as::<_, UserSettingsRow>(
         r#"
-        SELECT user_id, notification_settings, updated_at
+        WITH seeded AS (
+            INSERT INTO user_settings (user_id, notification_settings, schema_version)
+            SELECT $1, $2, $3
+            WHERE EXISTS (SELECT 1 FROM profiles WHERE id = $1)
+            ON CONFLICT (user_id) DO NOTHING
+            RETURNING user_id, notification_settings, schema_version, updated_at
+        )
+        SELECT user_id, notification_settings, schema_version, updated_at FROM seeded
+        UNION ALL
+        SELECT user_id, notification_settings, schema_version, updated_at
         FROM user_settings
         WHERE user_id = $1
+        LIMIT 1
         "#,
     )
     .bind(auth.user_id)
+    .bind(&defaults)
+    .bind(SETTINGS_SCHEMA_VERSION)
     .fetch_optional(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("get_settings"))?;
 
-    match settings {
-        Some(row) => {
-            let response: UserSettingsResponse = row.try_into()?;
-            Ok(Json(DataResponse::new(response)))
+    let settings = match settings {
+        Some(row) if row.schema_version < SETTINGS_SCHEMA_VERSION => {
+            sqlx::query_as::<_, UserSettingsRow>(
+                r#"
+                UPDATE user_settings SET
+                    notification_settings = $2 || notification_settings,
+                    schema_version = $3
+                WHERE user_id = $1
+                RETURNING user_id, notification_settings, schema_version, updated_at
+                "#,
+            )
+            .bind(auth.user_id)
+            .bind(&defaults)
+            .bind(SETTINGS_SCHEMA_VERSION)
+            .fetch_one(&state.db)
+            .await
+            .map_err(map_db_err("get_settings"))?
         }
+        Some(row) => row,
         None => {
-            // Return default settings if none exist
+            // No profile row to attach settings to yet
             let default = UserSettingsResponse {
                 user_id: auth.user_id,
+                schema_version: SETTINGS_SCHEMA_VERSION,
                 notification_settings: NotificationSettings::default(),
                 updated_at: Utc::now(),
             };
-            Ok(Json(DataResponse::new(default)))
+            return Ok(Json(DataResponse::new(default)));
         }
-    }
+    };
+
+    let response: UserSettingsResponse = settings.try_into()?;
+    Ok(Json(DataResponse::new(response)))
 }
 
 /// PUT /api/settings
 ///
-/// Update user settings.
+/// Update user settings. Only the keys sent change; unknown keys are
+/// rejected. Creates the settings row if the user has none.
 pub async fn update_settings(
     State(state): State<Arc<AppState>>,
     auth: RequireAuth,
     Json(req): Json<UpdateUserSettingsRequest>,
 ) -> Result<impl IntoResponse, ApiError> {
-    let notification_settings = serde_json::to_value(&req.notification_settings)
+    let defaults = default_notification_settings()?;
+    let changes = serde_json::to_value(&req.notification_settings)
         .map_err(|e| ApiError::internal(format!("Failed to serialize settings: {}", e)))?;
 
     let settings = sqlx::query_as::<_, UserSettingsRow>(
         r#"
-        INSERT INTO user_settings (user_id, notification_settings, updated_at)
-        VALUES ($1, $2, NOW())
+        INSERT INTO user_settings (user_id, notification_settings, schema_version, updated_at)
+        VALUES ($1, $2 || $3, $4, NOW())
         ON CONFLICT (user_id) DO UPDATE SET
-            notification_settings = EXCLUDED.notification_settings,
+            notification_settings = $2 || user_settings.notification_settings || $3,
+            schema_version = $4,
             updated_at = NOW()
-        RETURNING user_id, notification_settings, updated_at
+        RETURNING user_id, notification_settings, schema_version, updated_at
         "#,
     )
     .bind(auth.user_id)
-    .bind(&notification_settings)
+    .bind(&defaults)
+    .bind(&changes)
+    .bind(SETTINGS_SCHEMA_VERSION)
     .fetch_one(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("update_settings"))?;
 
     let response: UserSettingsResponse = settings.try_into()?;
     Ok(Json(DataResponse::new(response)))
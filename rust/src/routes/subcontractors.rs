@@ -9,14 +9,17 @@ use axum::{
 };
 use chrono::{DateTime, Utc};
 use std::sync::Arc;
+use rust_decimal::Decimal;
 use uuid::Uuid;
 
-use crate::api::pagination::PaginationParams;
-use crate::api::response::{DataResponse, Paginated, PaginationMeta};
+use crate::api::pagination::{resolve_pagination, PaginationParams};
+use crate::api::response::{DataResponse, Paginated};
 use crate::app::AppState;
 use crate::auth::RequireAuth;
+use crate::domain::money::Money;
+use crate::domain::rating::Rating;
 use crate::domain::subcontractors::{RecentProject, SubcontractorQuery, SubcontractorResponse};
-use crate::error::ApiError;
+use crate::error::{map_db_err, ApiError};
 
 /// Database row for subcontractor
 #[derive(Debug, sqlx::FromRow)]
@@ -24,14 +27,14 @@ struct SubcontractorRow {
     id: Uuid,
     name: String,
     trade: String,
-    rating: f64,
+    rating: Decimal,
     review_count: i32,
     location: String,
     description: Option<String>,
     contact_email: Option<String>,
     contact_phone: Option<String>,
     projects_completed: i32,
-    average_bid_value: Option<i64>,
+    average_bid_value: Option<Money>,
     response_time: Option<String>,
     verified: bool,
     specialties: serde_json::Value,
@@ -51,7 +54,7 @@ impl TryFrom<SubcontractorRow> for SubcontractorResponse {
             id: row.id,
             name: row.name,
             trade: row.trade,
-            rating: row.rating,
+            rating: Rating::from(row.rating),
             review_count: row.review_count,
             location: row.location,
             description: row.description,
@@ -85,9 +88,7 @@ pub async fn list_subcontractors(
     Query(query): Query<ListSubcontractorsQuery>,
     _auth: RequireAuth,
 ) -> Result<impl IntoResponse, ApiError> {
-    let page = query.pagination.page.unwrap_or(1).max(1);
-    let per_page = query.pagination.per_page.unwrap_or(20).min(100);
-    let offset = ((page - 1) * per_page) as i64;
+    let page = resolve_pagination(&query.pagination, state.settings.pagination.default)?;
 
     // Build dynamic query with filters
     let mut sql = String::from(
@@ -122,7 +123,7 @@ pub async fn list_subcontractors(
         count_sql.push_str(" AND rating >= $3");
     }
 
-    sql.push_str(" ORDER BY rating DESC, review_count DESC LIMIT $1 OFFSET $2");
+    sql.push_str(" ORDER BY rating DESC, review_count DESC, id LIMIT $1 OFFSET $2");
 
     // For simplicity, use a basic query without dynamic filters for now
     // In production, you'd use a query builder or dynamic SQL
@@ -130,7 +131,7 @@ pub async fn list_subcontractors(
     let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM subcontractors")
         .fetch_one(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+        .map_err(map_db_err("list_subcontractors"))?;
 
     let subcontractors = sqlx::query_as::<_, SubcontractorRow>(
         r#"
@@ -141,34 +142,23 @@ pub async fn list_subcontractors(
                COALESCE(recent_projects, '[]'::jsonb) as recent_projects,
                created_at
         FROM subcontractors
-        ORDER BY rating DESC, review_count DESC
+        ORDER BY rating DESC, review_count DESC, id
         LIMIT $1 OFFSET $2
         "#,
     )
-    .bind(per_page as i64)
-    .bind(offset)
+    .bind(page.limit())
+    .bind(page.offset())
     .fetch_all(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("list_subcontractors"))?;
 
     let data: Vec<SubcontractorResponse> = subcontractors
         .into_iter()
         .filter_map(|row| row.try_into().ok())
         .collect();
 
-    let total_pages = ((total as f64) / (per_page as f64)).ceil() as u32;
 
-    let response = Paginated {
-        data,
-        pagination: PaginationMeta {
-            page,
-            per_page,
-            total_items: total as u64,
-            total_pages,
-            has_next: page < total_pages,
-            has_prev: page > 1,
-        },
-    };
+    let response = Paginated::new(data, &page, total as u64);
 
     Ok(Json(response))
 }
@@ -196,7 +186,7 @@ pub async fn get_subcontractor(
     .bind(subcontractor_id)
     .fetch_optional(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
+    .map_err(map_db_err("get_subcontractor"))?
     .ok_or_else(|| ApiError::not_found("Subcontractor not found"))?;
 
     let response: SubcontractorResponse = subcontractor.try_into()?;
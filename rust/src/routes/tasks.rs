@@ -4,7 +4,6 @@
 
 use axum::{
     extract::{Path, Query, State},
-    http::StatusCode,
     response::IntoResponse,
     Json,
 };
@@ -12,15 +11,16 @@ use chrono::{DateTime, Utc};
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::api::pagination::PaginationParams;
-use crate::api::response::{DataResponse, MessageResponse, Paginated, PaginationMeta};
+use crate::api::pagination::{resolve_pagination, PaginationParams};
+use crate::api::sort::{SortFields, SortOrder, SortSpec};
+use crate::api::response::{Created, DataResponse, NoContent, Paginated};
 use crate::app::AppState;
 use crate::auth::RequireAuth;
 use crate::domain::tasks::{
-    CreateTaskRequest, TaskPriority, TaskResponse, TaskStatus, UpdateTaskRequest,
+    CreateTaskRequest, TaskListQuery, TaskPriority, TaskResponse, TaskStatus, UpdateTaskRequest,
 };
-use crate::error::ApiError;
-use crate::services::cache::{keys as cache_keys, ttl as cache_ttl};
+use crate::error::{map_db_err, ApiError};
+use crate::services::cache::{keys as cache_keys, ttl as cache_ttl, CacheNamespace};
 
 /// Database row for task
 #[derive(Debug, sqlx::FromRow)]
@@ -85,30 +85,16 @@ pub async fn list_tasks(
     Query(pagination): Query<PaginationParams>,
     _auth: RequireAuth,
 ) -> Result<impl IntoResponse, ApiError> {
-    let page = pagination.page.unwrap_or(1).max(1);
-    let per_page = pagination.per_page.unwrap_or(20).min(100);
-    let cache_key = cache_keys::task_list(project_id, page, per_page);
+    let page = resolve_pagination(&pagination, state.settings.pagination.default)?;
+    let cache_key = cache_keys::task_list(project_id, page.page, page.per_page);
 
     // Try cache first
     if let Some(cached) = state.cache.get::<CachedTaskList>(&cache_key).await {
         tracing::debug!(project_id = %project_id, "Tasks list cache hit");
-        let total_pages = ((cached.total as f64) / (per_page as f64)).ceil() as u32;
-        let response = Paginated {
-            data: cached.data,
-            pagination: PaginationMeta {
-                page,
-                per_page,
-                total_items: cached.total,
-                total_pages,
-                has_next: page < total_pages,
-                has_prev: page > 1,
-            },
-        };
+        let response = Paginated::new(cached.data, &page, cached.total);
         return Ok(Json(response));
     }
 
-    let offset = ((page - 1) * per_page) as i64;
-
     // Get total count (with caching)
     let count_cache_key = cache_keys::task_count(project_id);
     let total: i64 = if let Some(cached_count) = state.cache.get::<i64>(&count_cache_key).await {
@@ -120,7 +106,7 @@ pub async fn list_tasks(
         .bind(project_id)
         .fetch_one(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+        .map_err(map_db_err("list_tasks"))?;
         let _ = state.cache.set_with_ttl(&count_cache_key, &count, cache_ttl::COUNT).await;
         count
     };
@@ -134,93 +120,131 @@ pub async fn list_tasks(
         FROM tasks t
         LEFT JOIN profiles p ON t.assignee_id = p.id
         WHERE t.project_id = $1
-        ORDER BY t.created_at DESC
+        ORDER BY t.created_at DESC, t.id
         LIMIT $2 OFFSET $3
         "#,
     )
     .bind(project_id)
-    .bind(per_page as i64)
-    .bind(offset)
+    .bind(page.limit())
+    .bind(page.offset())
     .fetch_all(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("list_tasks"))?;
 
     let data: Vec<TaskResponse> = tasks.into_iter().map(Into::into).collect();
-    let total_pages = ((total as f64) / (per_page as f64)).ceil() as u32;
 
     // Cache the result
     let cached = CachedTaskList { data: data.clone(), total: total as u64 };
-    let _ = state.cache.set_with_ttl(&cache_key, &cached, cache_ttl::LIST).await;
-
-    let response = Paginated {
-        data,
-        pagination: PaginationMeta {
-            page,
-            per_page,
-            total_items: total as u64,
-            total_pages,
-            has_next: page < total_pages,
-            has_prev: page > 1,
-        },
-    };
+    let _ = state.cache.set_in(CacheNamespace::Listing, &cache_key, &cached).await;
+
+    let response = Paginated::new(data, &page, total as u64);
 
     Ok(Json(response))
 }
 
+/// `sort_by` keys for the cross-project task list
+const TASK_SORT_FIELDS: SortFields = &[
+    ("created_at", "t.created_at"),
+    ("updated_at", "t.updated_at"),
+    ("due_date", "t.due_date"),
+    (
+        "priority",
+        "CASE t.priority WHEN 'urgent' THEN 4 WHEN 'high' THEN 3 WHEN 'medium' THEN 2 ELSE 1 END",
+    ),
+    ("progress", "t.progress"),
+];
+
+/// Filters shared by the count and page queries of `list_all_tasks`; `$1` is
+/// the caller, whose own projects are the only ones searched
+const ALL_TASKS_FILTER: &str = r#"
+    WHERE pr.owner_id = $1
+    AND ($2::text IS NULL OR t.status = $2)
+    AND ($3::text IS NULL OR t.priority = $3)
+    AND ($4::uuid IS NULL OR t.project_id = $4)
+    AND ($5::uuid IS NULL OR t.assignee_id = $5)
+    AND ($6::timestamptz IS NULL OR t.due_date >= $6)
+    AND ($7::timestamptz IS NULL OR t.due_date <= $7)
+"#;
+
 /// GET /api/tasks
 ///
-/// List all tasks for the current user across all projects. Uses Redis cache.
+/// List tasks across all of the current user's projects.
+///
+/// Supports `status`, `priority`, `project_id`, `assignee_id`, a due date
+/// range (`due_from`, `due_to`), `sort_by` (created_at, updated_at, due_date,
+/// priority, progress) and `sort_order`. Newest first by default. Only the
+/// unfiltered default view is served from Redis.
 pub async fn list_all_tasks(
     State(state): State<Arc<AppState>>,
     Query(pagination): Query<PaginationParams>,
+    Query(query): Query<TaskListQuery>,
     auth: RequireAuth,
 ) -> Result<impl IntoResponse, ApiError> {
-    let page = pagination.page.unwrap_or(1).max(1);
-    let per_page = pagination.per_page.unwrap_or(20).min(100);
-    let cache_key = cache_keys::task_list_all(auth.user_id, page, per_page);
+    let page = resolve_pagination(&pagination, state.settings.pagination.default)?;
 
-    // Try cache first
-    if let Some(cached) = state.cache.get::<CachedTaskList>(&cache_key).await {
-        tracing::debug!(user_id = %auth.user_id, "All tasks list cache hit");
-        let total_pages = ((cached.total as f64) / (per_page as f64)).ceil() as u32;
-        let response = Paginated {
-            data: cached.data,
-            pagination: PaginationMeta {
-                page,
-                per_page,
-                total_items: cached.total,
-                total_pages,
-                has_next: page < total_pages,
-                has_prev: page > 1,
-            },
-        };
-        return Ok(Json(response));
+    if let (Some(from), Some(to)) = (query.due_from, query.due_to) {
+        if from > to {
+            return Err(ApiError::bad_request("due_from must not be after due_to"));
+        }
     }
 
-    let offset = ((page - 1) * per_page) as i64;
+    let sort = SortSpec::parse(
+        TASK_SORT_FIELDS,
+        SortOrder::Desc,
+        query.sort_by.as_deref(),
+        query.sort_order.as_deref(),
+    )?;
+    let status = query.status.as_ref().map(TaskStatus::as_str);
+    let priority = query.priority.as_ref().map(TaskPriority::as_str);
 
-    // Get total count (with caching)
+    let cacheable = query.is_unfiltered();
+    let cache_key = cache_keys::task_list_all(auth.user_id, page.page, page.per_page);
+
+    // Try cache first
+    if cacheable {
+        if let Some(cached) = state.cache.get::<CachedTaskList>(&cache_key).await {
+            tracing::debug!(user_id = %auth.user_id, "All tasks list cache hit");
+            let response = Paginated::new(cached.data, &page, cached.total);
+            return Ok(Json(response));
+        }
+    }
+
+    // Get total count (cached for the unfiltered view)
     let count_cache_key = cache_keys::task_count_all(auth.user_id);
-    let total: i64 = if let Some(cached_count) = state.cache.get::<i64>(&count_cache_key).await {
+    let cached_count = if cacheable {
+        state.cache.get::<i64>(&count_cache_key).await
+    } else {
+        None
+    };
+    let total: i64 = if let Some(cached_count) = cached_count {
         cached_count
     } else {
-        let count: i64 = sqlx::query_scalar(
+        let count: i64 = sqlx::query_scalar(&format!(
             r#"
             SELECT COUNT(*) FROM tasks t
             JOIN projects pr ON t.project_id = pr.id
-            WHERE pr.owner_id = $1
+            {}
             "#,
-        )
+            ALL_TASKS_FILTER
+        ))
         .bind(auth.user_id)
+        .bind(status)
+        .bind(priority)
+        .bind(query.project_id)
+        .bind(query.assignee_id)
+        .bind(query.due_from)
+        .bind(query.due_to)
         .fetch_one(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
-        let _ = state.cache.set_with_ttl(&count_cache_key, &count, cache_ttl::COUNT).await;
+        .map_err(map_db_err("list_all_tasks"))?;
+        if cacheable {
+            let _ = state.cache.set_with_ttl(&count_cache_key, &count, cache_ttl::COUNT).await;
+        }
         count
     };
 
     // Get tasks
-    let tasks = sqlx::query_as::<_, TaskRow>(
+    let tasks = sqlx::query_as::<_, TaskRow>(&format!(
         r#"
         SELECT t.id, t.project_id, t.title, t.description, t.status, t.priority,
                p.first_name || ' ' || p.last_name as assignee, t.assignee_id,
@@ -228,36 +252,34 @@ pub async fn list_all_tasks(
         FROM tasks t
         JOIN projects pr ON t.project_id = pr.id
         LEFT JOIN profiles p ON t.assignee_id = p.id
-        WHERE pr.owner_id = $1
-        ORDER BY t.created_at DESC
-        LIMIT $2 OFFSET $3
+        {}
+        ORDER BY {} NULLS LAST, t.id
+        LIMIT $8 OFFSET $9
         "#,
-    )
+        ALL_TASKS_FILTER, sort
+    ))
     .bind(auth.user_id)
-    .bind(per_page as i64)
-    .bind(offset)
+    .bind(status)
+    .bind(priority)
+    .bind(query.project_id)
+    .bind(query.assignee_id)
+    .bind(query.due_from)
+    .bind(query.due_to)
+    .bind(page.limit())
+    .bind(page.offset())
     .fetch_all(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("list_all_tasks"))?;
 
     let data: Vec<TaskResponse> = tasks.into_iter().map(Into::into).collect();
-    let total_pages = ((total as f64) / (per_page as f64)).ceil() as u32;
 
     // Cache the result
-    let cached = CachedTaskList { data: data.clone(), total: total as u64 };
-    let _ = state.cache.set_with_ttl(&cache_key, &cached, cache_ttl::LIST).await;
-
-    let response = Paginated {
-        data,
-        pagination: PaginationMeta {
-            page,
-            per_page,
-            total_items: total as u64,
-            total_pages,
-            has_next: page < total_pages,
-            has_prev: page > 1,
-        },
-    };
+    if cacheable {
+        let cached = CachedTaskList { data: data.clone(), total: total as u64 };
+        let _ = state.cache.set_in(CacheNamespace::Listing, &cache_key, &cached).await;
+    }
+
+    let response = Paginated::new(data, &page, total as u64);
 
     Ok(Json(response))
 }
@@ -284,7 +306,7 @@ pub async fn get_task(
     .bind(project_id)
     .fetch_optional(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
+    .map_err(map_db_err("get_task"))?
     .ok_or_else(|| ApiError::not_found("Task not found"))?;
 
     let response: TaskResponse = task.into();
@@ -328,12 +350,12 @@ pub async fn create_task(
     .bind(&req.description)
     .bind(status)
     .bind(priority)
-    .bind(&req.assignee_id)
-    .bind(&req.due_date)
+    .bind(req.assignee_id)
+    .bind(req.due_date)
     .bind(&req.category)
     .fetch_one(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("create_task"))?;
 
     let response: TaskResponse = task.into();
 
@@ -345,7 +367,7 @@ pub async fn create_task(
     // Invalidate dashboard
     let _ = state.cache.delete(&cache_keys::dashboard_stats(auth.user_id)).await;
 
-    Ok((StatusCode::CREATED, Json(DataResponse::new(response))))
+    Ok(Created(response))
 }
 
 /// PUT /api/projects/:project_id/tasks/:task_id
@@ -394,13 +416,13 @@ pub async fn update_task(
     .bind(&req.description)
     .bind(status)
     .bind(priority)
-    .bind(&req.assignee_id)
-    .bind(&req.due_date)
+    .bind(req.assignee_id)
+    .bind(req.due_date)
     .bind(&req.category)
-    .bind(&req.progress)
+    .bind(req.progress)
     .fetch_optional(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
+    .map_err(map_db_err("update_task"))?
     .ok_or_else(|| ApiError::not_found("Task not found"))?;
 
     let response: TaskResponse = task.into();
@@ -427,7 +449,7 @@ pub async fn delete_task(
         .bind(project_id)
         .execute(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+        .map_err(map_db_err("delete_task"))?;
 
     if result.rows_affected() == 0 {
         return Err(ApiError::not_found("Task not found"));
@@ -441,8 +463,5 @@ pub async fn delete_task(
     // Invalidate dashboard
     let _ = state.cache.delete(&cache_keys::dashboard_stats(auth.user_id)).await;
 
-    Ok((
-        StatusCode::OK,
-        Json(MessageResponse::new("Task deleted successfully")),
-    ))
+    Ok(NoContent)
 }
@@ -0,0 +1,355 @@
+//! Tender document routes
+//!
+//! GCs share specific project documents (plans, specs) with a tender; subs who
+//! can view the tender get time-limited signed download links. Only documents
+//! listed in `tender_documents` are exposed, never the whole project, and every
+//! download is logged for the GC.
+
+use axum::{
+    body::Body,
+    extract::{Path, Query, Request, State},
+    http::{header, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use tower::ServiceExt;
+use tower_http::services::ServeFile;
+use uuid::Uuid;
+
+use crate::api::response::{Created, DataResponse, NoContent};
+use crate::app::AppState;
+use crate::auth::RequireAuth;
+use crate::domain::tenders::{
+    ShareTenderDocumentsRequest, SignedDownloadQuery, TenderDocumentDownload, TenderDocumentResponse,
+};
+use crate::error::{map_db_err, ApiError};
+use crate::middleware::ExtractClientIp;
+
+/// Most documents that can be shared in one request
+const MAX_SHARE_BATCH: usize = 100;
+
+#[derive(Debug, sqlx::FromRow)]
+struct TenderDocumentRow {
+    document_id: Uuid,
+    name: String,
+    document_type: Option<String>,
+    file_path: Option<String>,
+    file_size: Option<i64>,
+    mime_type: Option<String>,
+    version: Option<i32>,
+    shared_at: DateTime<Utc>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct TenderAccessRow {
+    owner_id: Uuid,
+    status: String,
+    visibility: String,
+    invited: bool,
+}
+
+fn download_path(tender_id: Uuid, document_id: Uuid) -> String {
+    format!("/marketplace/tenders/{}/documents/{}/download", tender_id, document_id)
+}
+
+fn to_response(state: &AppState, tender_id: Uuid, user_id: Uuid, row: TenderDocumentRow) -> TenderDocumentResponse {
+    let signed = row
+        .file_path
+        .as_ref()
+        .map(|_| state.url_signer.sign(&download_path(tender_id, row.document_id), user_id));
+
+    TenderDocumentResponse {
+        document_id: row.document_id,
+        name: row.name,
+        document_type: row.document_type,
+        file_size: row.file_size,
+        mime_type: row.mime_type,
+        version: row.version.unwrap_or(1),
+        shared_at: row.shared_at,
+        download_expires_at: signed.as_ref().map(|s| s.expires_at),
+        download_url: signed.map(|s| s.url),
+    }
+}
+
+/// Verify the user owns the tender's project; returns the project id.
+async fn verify_tender_ownership(state: &AppState, tender_id: Uuid, user_id: Uuid) -> Result<Uuid, ApiError> {
+    sqlx::query_scalar(
+        r#"
+        SELECT t.project_id
+        FROM tenders t
+        JOIN projects p ON t.project_id = p.id
+        WHERE t.id = $1 AND p.owner_id = $2
+        "#,
+    )
+    .bind(tender_id)
+    .bind(user_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("verify_tender_ownership"))?
+    .ok_or_else(|| ApiError::not_found("Tender not found"))
+}
+
+/// Verify the user can view the tender: its GC, or any sub once it is
+/// published (invited subs only for `invited_only` tenders). Returns whether
+/// the user is the GC.
+pub(crate) async fn verify_tender_access(state: &AppState, tender_id: Uuid, user_id: Uuid) -> Result<bool, ApiError> {
+    let access = sqlx::query_as::<_, TenderAccessRow>(
+        r#"
+        SELECT p.owner_id, t.status, COALESCE(t.visibility, 'public') as visibility,
+               EXISTS (
+                   SELECT 1 FROM subcontractors s
+                   WHERE s.profile_id = $2 AND s.id = ANY(COALESCE(t.invited_subcontractors, '{}'))
+               ) as invited
+        FROM tenders t
+        JOIN projects p ON t.project_id = p.id
+        WHERE t.id = $1
+        "#,
+    )
+    .bind(tender_id)
+    .bind(user_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("verify_tender_access"))?
+    .ok_or_else(|| ApiError::not_found("Tender not found"))?;
+
+    let visible = access.owner_id == user_id
+        || (access.status != "draft" && (access.visibility != "invited_only" || access.invited));
+    if !visible {
+        return Err(ApiError::not_found("Tender not found"));
+    }
+
+    Ok(access.owner_id == user_id)
+}
+
+async fn fetch_tender_documents(state: &AppState, tender_id: Uuid) -> Result<Vec<TenderDocumentRow>, ApiError> {
+    sqlx::query_as::<_, TenderDocumentRow>(
+        r#"
+        SELECT d.id as document_id, d.name, d.document_type, d.file_path, d.file_size,
+               d.mime_type, d.version, td.created_at as shared_at
+        FROM tender_documents td
+        JOIN documents d ON td.document_id = d.id
+        WHERE td.tender_id = $1
+        ORDER BY td.created_at, d.name
+        "#,
+    )
+    .bind(tender_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(map_db_err("fetch_tender_documents"))
+}
+
+/// POST /api/tenders/:tender_id/documents
+///
+/// Share project documents with a tender (GC only). Already-shared documents are ignored.
+pub async fn share_tender_documents(
+    State(state): State<Arc<AppState>>,
+    Path(tender_id): Path<Uuid>,
+    auth: RequireAuth,
+    Json(input): Json<ShareTenderDocumentsRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let project_id = verify_tender_ownership(&state, tender_id, auth.user_id).await?;
+
+    let mut document_ids = input.document_ids;
+    document_ids.sort();
+    document_ids.dedup();
+    if document_ids.is_empty() {
+        return Err(ApiError::bad_request("document_ids must not be empty"));
+    }
+    if document_ids.len() > MAX_SHARE_BATCH {
+        return Err(ApiError::bad_request(format!(
+            "At most {} documents can be shared at once",
+            MAX_SHARE_BATCH
+        )));
+    }
+
+    // Only documents from the tender's own project can be shared
+    let found: Vec<Uuid> = sqlx::query_scalar("SELECT id FROM documents WHERE project_id = $1 AND id = ANY($2)")
+        .bind(project_id)
+        .bind(&document_ids)
+        .fetch_all(&state.db)
+        .await
+        .map_err(map_db_err("share_tender_documents"))?;
+
+    let missing: Vec<String> = document_ids
+        .iter()
+        .filter(|id| !found.contains(id))
+        .map(|id| id.to_string())
+        .collect();
+    if !missing.is_empty() {
+        return Err(ApiError::bad_request(format!(
+            "Documents not found in this project: {}",
+            missing.join(", ")
+        )));
+    }
+
+    sqlx::query(
+        r#"
+        INSERT INTO tender_documents (tender_id, document_id, shared_by)
+        SELECT $1, unnest($2::uuid[]), $3
+        ON CONFLICT (tender_id, document_id) DO NOTHING
+        "#,
+    )
+    .bind(tender_id)
+    .bind(&document_ids)
+    .bind(auth.user_id)
+    .execute(&state.db)
+    .await
+    .map_err(map_db_err("share_tender_documents"))?;
+
+    let documents: Vec<TenderDocumentResponse> = fetch_tender_documents(&state, tender_id)
+        .await?
+        .into_iter()
+        .map(|row| to_response(&state, tender_id, auth.user_id, row))
+        .collect();
+
+    Ok(Created(documents))
+}
+
+/// DELETE /api/tenders/:tender_id/documents/:document_id
+///
+/// Stop sharing a document with a tender (GC only). The document itself is kept.
+pub async fn unshare_tender_document(
+    State(state): State<Arc<AppState>>,
+    Path((tender_id, document_id)): Path<(Uuid, Uuid)>,
+    auth: RequireAuth,
+) -> Result<impl IntoResponse, ApiError> {
+    verify_tender_ownership(&state, tender_id, auth.user_id).await?;
+
+    let result = sqlx::query("DELETE FROM tender_documents WHERE tender_id = $1 AND document_id = $2")
+        .bind(tender_id)
+        .bind(document_id)
+        .execute(&state.db)
+        .await
+        .map_err(map_db_err("unshare_tender_document"))?;
+
+    if result.rows_affected() == 0 {
+        return Err(ApiError::not_found("Document is not shared with this tender"));
+    }
+
+    Ok(NoContent)
+}
+
+/// GET /api/tenders/:tender_id/documents/downloads
+///
+/// Download log for a tender's shared documents (GC only), newest first.
+pub async fn list_tender_document_downloads(
+    State(state): State<Arc<AppState>>,
+    Path(tender_id): Path<Uuid>,
+    auth: RequireAuth,
+) -> Result<impl IntoResponse, ApiError> {
+    verify_tender_ownership(&state, tender_id, auth.user_id).await?;
+
+    let downloads = sqlx::query_as::<_, TenderDocumentDownload>(
+        r#"
+        SELECT dl.id, dl.document_id, d.name as document_name, dl.user_id,
+               NULLIF(concat_ws(' ', pr.first_name, pr.last_name), '') as user_name,
+               pr.company_name, dl.ip_address, dl.downloaded_at
+        FROM tender_document_downloads dl
+        JOIN documents d ON dl.document_id = d.id
+        JOIN profiles pr ON dl.user_id = pr.id
+        WHERE dl.tender_id = $1
+        ORDER BY dl.downloaded_at DESC, dl.id
+        LIMIT 500
+        "#,
+    )
+    .bind(tender_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(map_db_err("list_tender_document_downloads"))?;
+
+    Ok(Json(DataResponse::new(downloads)))
+}
+
+/// GET /api/marketplace/tenders/:tender_id/documents
+///
+/// Documents shared with a tender, with signed download links for the caller.
+pub async fn list_marketplace_tender_documents(
+    State(state): State<Arc<AppState>>,
+    Path(tender_id): Path<Uuid>,
+    auth: RequireAuth,
+) -> Result<impl IntoResponse, ApiError> {
+    verify_tender_access(&state, tender_id, auth.user_id).await?;
+
+    let documents: Vec<TenderDocumentResponse> = fetch_tender_documents(&state, tender_id)
+        .await?
+        .into_iter()
+        .map(|row| to_response(&state, tender_id, auth.user_id, row))
+        .collect();
+
+    Ok(Json(DataResponse::new(documents)))
+}
+
+/// GET /api/marketplace/tenders/:tender_id/documents/:document_id/download
+///
+/// Follow a signed download link. Authenticated by the link's signature rather
+/// than a bearer token; access to the tender is re-checked for the link's user.
+pub async fn download_tender_document(
+    State(state): State<Arc<AppState>>,
+    Path((tender_id, document_id)): Path<(Uuid, Uuid)>,
+    Query(link): Query<SignedDownloadQuery>,
+    ExtractClientIp(client_ip): ExtractClientIp,
+    request: Request,
+) -> Result<Response, ApiError> {
+    state.url_signer.verify(
+        &download_path(tender_id, document_id),
+        link.user,
+        link.expires,
+        &link.signature,
+    )?;
+    verify_tender_access(&state, tender_id, link.user).await?;
+
+    let (name, file_path): (String, Option<String>) = sqlx::query_as(
+        r#"
+        SELECT d.name, d.file_path
+        FROM tender_documents td
+        JOIN documents d ON td.document_id = d.id
+        WHERE td.tender_id = $1 AND td.document_id = $2
+        "#,
+    )
+    .bind(tender_id)
+    .bind(document_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("download_tender_document"))?
+    .ok_or_else(|| ApiError::not_found("Document not found"))?;
+
+    let file_path = file_path.ok_or_else(|| ApiError::not_found("Document has no file"))?;
+
+    let mut response = ServeFile::new(&file_path)
+        .oneshot(request)
+        .await
+        .map_err(|e| ApiError::internal(format!("Failed to read document: {}", e)))?
+        .map(Body::new);
+
+    if response.status() == StatusCode::NOT_FOUND {
+        return Err(ApiError::not_found("Document file is missing"));
+    }
+
+    let safe_name: String = name
+        .chars()
+        .filter(|c| c.is_alphanumeric() || matches!(c, '.' | '-' | '_' | ' '))
+        .collect();
+    if let Ok(disposition) = HeaderValue::from_str(&format!("attachment; filename=\"{}\"", safe_name)) {
+        response.headers_mut().insert(header::CONTENT_DISPOSITION, disposition);
+    }
+
+    if let Err(e) = sqlx::query(
+        r#"
+        INSERT INTO tender_document_downloads (tender_id, document_id, user_id, ip_address)
+        VALUES ($1, $2, $3, $4)
+        "#,
+    )
+    .bind(tender_id)
+    .bind(document_id)
+    .bind(link.user)
+    .bind(&client_ip)
+    .execute(&state.db)
+    .await
+    {
+        tracing::warn!(error = %e, tender_id = %tender_id, document_id = %document_id, "Failed to log document download");
+    }
+
+    Ok(response)
+}
@@ -0,0 +1,198 @@
+//! Tender and bid exports
+//!
+//! CSV downloads for a GC's internal review: every bid on a tender, and a
+//! one-row-per-tender summary of a project's bidding. Rows are streamed from
+//! the database as they are read. Amounts are compared with the tender's
+//! reserve price (the minimum acceptable bid).
+
+use axum::{
+    body::{Body, Bytes},
+    extract::{Path, Query, State},
+    http::header,
+    response::IntoResponse,
+};
+use chrono::Utc;
+use futures::{SinkExt, StreamExt};
+use sqlx::{PgPool, Row};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::app::AppState;
+use crate::auth::RequireAuth;
+use crate::domain::tenders::{TenderExportFormat, TenderExportQuery};
+use crate::error::{map_db_err, ApiError};
+use crate::routes::admin::csv_escape;
+
+const BID_EXPORT_COLUMNS: &str = "bid_id,company_name,contact_name,contact_email,bid_amount,reserve_price,difference_from_reserve,meets_reserve,timeline_days,proposed_start_date,status,submitted_at\n";
+
+/// Every column is cast to text; the CSV writer takes them in order
+const BID_EXPORT_QUERY: &str = r#"
+    SELECT b.id::text, b.company_name, b.contact_name, b.contact_email,
+           b.bid_amount::text, t.reserve_price::text,
+           (b.bid_amount - t.reserve_price)::text,
+           CASE WHEN t.reserve_price IS NULL THEN NULL
+                WHEN b.bid_amount >= t.reserve_price THEN 'yes' ELSE 'no' END,
+           b.proposed_timeline_days::text, b.proposed_start_date::text,
+           b.status, b.submitted_at::text
+    FROM bids b
+    JOIN tenders t ON t.id = b.tender_id
+    WHERE b.tender_id = $1
+    ORDER BY b.bid_amount ASC, b.submitted_at ASC NULLS LAST, b.id
+"#;
+
+const TENDER_EXPORT_COLUMNS: &str = "tender_id,name,trade_category,status,bid_due_date,estimated_value,reserve_price,bid_count,lowest_bid,highest_bid,average_bid,lowest_vs_reserve,bids_meeting_reserve,awarded_company,awarded_amount\n";
+
+/// Bid figures count live bids only (not drafts or withdrawn bids)
+const TENDER_EXPORT_QUERY: &str = r#"
+    SELECT t.id::text, t.name, t.trade_category, t.status, t.bid_due_date::text,
+           t.estimated_value::text, t.reserve_price::text,
+           COUNT(b.id)::text,
+           MIN(b.bid_amount)::text,
+           MAX(b.bid_amount)::text,
+           ROUND(AVG(b.bid_amount), 2)::text,
+           (MIN(b.bid_amount) - t.reserve_price)::text,
+           CASE WHEN t.reserve_price IS NULL THEN NULL
+                ELSE COUNT(b.id) FILTER (WHERE b.bid_amount >= t.reserve_price)::text END,
+           MAX(b.company_name) FILTER (WHERE b.status = 'awarded'),
+           MAX(b.bid_amount) FILTER (WHERE b.status = 'awarded')::text
+    FROM tenders t
+    LEFT JOIN bids b ON b.tender_id = t.id AND b.status NOT IN ('draft', 'withdrawn')
+    WHERE t.project_id = $1
+    GROUP BY t.id
+    ORDER BY t.created_at, t.id
+"#;
+
+/// GET /api/tenders/:tender_id/bids/export?format=csv
+///
+/// Download every bid on a tender, cheapest first, with how each compares to
+/// the reserve price. Only the tender owner (project owner) can export.
+pub async fn export_tender_bids(
+    auth: RequireAuth,
+    State(state): State<Arc<AppState>>,
+    Path(tender_id): Path<Uuid>,
+    Query(query): Query<TenderExportQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let owner_id: Option<Uuid> = sqlx::query_scalar(
+        r#"
+        SELECT p.owner_id FROM tenders t
+        JOIN projects p ON t.project_id = p.id
+        WHERE t.id = $1
+        "#,
+    )
+    .bind(tender_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("export_tender_bids"))?;
+
+    match owner_id {
+        None => return Err(ApiError::not_found("Tender not found")),
+        Some(owner_id) if owner_id != auth.user_id => {
+            return Err(ApiError::forbidden("Only the project owner can export bids"));
+        }
+        Some(_) => {}
+    }
+
+    tracing::info!(user_id = %auth.user_id, tender_id = %tender_id, "Exporting tender bids");
+
+    let filename = format!("tender-{}-bids-{}", tender_id, Utc::now().format("%Y%m%d"));
+    Ok(stream_csv(
+        state.db.clone(),
+        query.format,
+        filename,
+        BID_EXPORT_COLUMNS,
+        BID_EXPORT_QUERY,
+        tender_id,
+    ))
+}
+
+/// GET /api/projects/:project_id/tenders/export?format=csv
+///
+/// Download a summary of a project's tenders: bid count, lowest, highest and
+/// average bid, the lowest bid against the reserve price, and the award.
+/// Only the project owner can export.
+pub async fn export_project_tenders(
+    auth: RequireAuth,
+    State(state): State<Arc<AppState>>,
+    Path(project_id): Path<Uuid>,
+    Query(query): Query<TenderExportQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let exists: Option<Uuid> =
+        sqlx::query_scalar("SELECT id FROM projects WHERE id = $1 AND owner_id = $2")
+            .bind(project_id)
+            .bind(auth.user_id)
+            .fetch_optional(&state.db)
+            .await
+            .map_err(map_db_err("export_project_tenders"))?;
+
+    if exists.is_none() {
+        return Err(ApiError::not_found("Project not found"));
+    }
+
+    tracing::info!(user_id = %auth.user_id, project_id = %project_id, "Exporting project tenders");
+
+    let filename = format!("project-{}-tenders-{}", project_id, Utc::now().format("%Y%m%d"));
+    Ok(stream_csv(
+        state.db.clone(),
+        query.format,
+        filename,
+        TENDER_EXPORT_COLUMNS,
+        TENDER_EXPORT_QUERY,
+        project_id,
+    ))
+}
+
+/// Stream the rows of `sql` (bound to `id`, every column text) as a CSV
+/// attachment headed by `columns`
+fn stream_csv(
+    db: PgPool,
+    format: TenderExportFormat,
+    filename: String,
+    columns: &'static str,
+    sql: &'static str,
+    id: Uuid,
+) -> impl IntoResponse {
+    let (mut tx, rx) = futures::channel::mpsc::channel::<Result<Bytes, std::io::Error>>(64);
+
+    tokio::spawn(async move {
+        if tx.send(Ok(Bytes::from_static(columns.as_bytes()))).await.is_err() {
+            return;
+        }
+
+        let mut rows = sqlx::query(sql).bind(id).fetch(&db);
+        while let Some(row) = rows.next().await {
+            let chunk = match row {
+                Ok(row) => {
+                    let mut line = (0..row.len())
+                        .map(|i| csv_escape(&row.try_get::<Option<String>, _>(i).ok().flatten().unwrap_or_default()))
+                        .collect::<Vec<_>>()
+                        .join(",");
+                    line.push('\n');
+                    Ok(Bytes::from(line))
+                }
+                Err(e) => {
+                    tracing::error!(error = %e, "Tender export query failed");
+                    Err(std::io::Error::other("tender export failed"))
+                }
+            };
+
+            let failed = chunk.is_err();
+            if tx.send(chunk).await.is_err() || failed {
+                // Client disconnected or the query failed; stop streaming
+                return;
+            }
+        }
+    });
+
+    let (content_type, extension) = match format {
+        TenderExportFormat::Csv => ("text/csv; charset=utf-8", "csv"),
+    };
+    let disposition = format!("attachment; filename=\"{}.{}\"", filename, extension);
+
+    (
+        [
+            (header::CONTENT_TYPE, content_type.to_string()),
+            (header::CONTENT_DISPOSITION, disposition),
+        ],
+        Body::from_stream(rx),
+    )
+}
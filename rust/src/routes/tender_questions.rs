@@ -0,0 +1,277 @@
+//! Tender clarification routes
+//!
+//! Subs who can see a tender ask questions before bidding; the GC's answers
+//! are visible to everyone who can see the tender so all bids rest on the
+//! same information. Who asked is shown only to the GC.
+
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+    Json,
+};
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::api::response::{Created, DataResponse};
+use crate::app::AppState;
+use crate::auth::{RequireAuth, RequireSub};
+use crate::domain::tenders::{
+    AnswerTenderQuestionRequest, AskTenderQuestionRequest, TenderQuestionAsker, TenderQuestionResponse,
+};
+use crate::error::{map_db_err, ApiError};
+use crate::routes::marketplace::{assert_tender_biddable, fetch_biddable_tender};
+use crate::routes::tender_documents::verify_tender_access;
+use crate::services::notifications;
+use crate::services::watchers::{self, WatchedResource};
+
+/// Longest question or answer accepted
+const MAX_TEXT_LENGTH: usize = 4000;
+
+#[derive(Debug, sqlx::FromRow)]
+struct TenderQuestionRow {
+    id: Uuid,
+    tender_id: Uuid,
+    asked_by: Option<Uuid>,
+    subcontractor_id: Option<Uuid>,
+    subcontractor_name: Option<String>,
+    question: String,
+    answer: Option<String>,
+    answered_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+}
+
+const QUESTION_COLUMNS: &str = r#"
+    q.id, q.tender_id, q.asked_by, q.subcontractor_id, s.name as subcontractor_name,
+    q.question, q.answer, q.answered_at, q.created_at
+"#;
+
+impl TenderQuestionRow {
+    fn into_response(self, viewer_id: Uuid, viewer_is_owner: bool) -> TenderQuestionResponse {
+        TenderQuestionResponse {
+            id: self.id,
+            tender_id: self.tender_id,
+            question: self.question,
+            answer: self.answer,
+            answered_at: self.answered_at,
+            created_at: self.created_at,
+            asked_by_me: self.asked_by == Some(viewer_id),
+            asker: viewer_is_owner.then_some(TenderQuestionAsker {
+                subcontractor_id: self.subcontractor_id,
+                name: self.subcontractor_name,
+            }),
+        }
+    }
+}
+
+fn validate_text(field: &str, value: &str) -> Result<String, ApiError> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(ApiError::bad_request(format!("{} must not be empty", field)));
+    }
+    if value.chars().count() > MAX_TEXT_LENGTH {
+        return Err(ApiError::bad_request(format!(
+            "{} must be at most {} characters",
+            field, MAX_TEXT_LENGTH
+        )));
+    }
+    Ok(value.to_string())
+}
+
+async fn fetch_question(state: &AppState, tender_id: Uuid, question_id: Uuid) -> Result<TenderQuestionRow, ApiError> {
+    sqlx::query_as::<_, TenderQuestionRow>(&format!(
+        r#"
+        SELECT {}
+        FROM tender_questions q
+        LEFT JOIN subcontractors s ON q.subcontractor_id = s.id
+        WHERE q.id = $1 AND q.tender_id = $2
+        "#,
+        QUESTION_COLUMNS
+    ))
+    .bind(question_id)
+    .bind(tender_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("fetch_question"))?
+    .ok_or_else(|| ApiError::not_found("Question not found"))
+}
+
+/// GET /api/marketplace/tenders/:tender_id/questions
+///
+/// The tender's clarification thread, oldest first. Open to the GC and to
+/// every sub who can see the tender.
+pub async fn list_tender_questions(
+    State(state): State<Arc<AppState>>,
+    Path(tender_id): Path<Uuid>,
+    auth: RequireAuth,
+) -> Result<impl IntoResponse, ApiError> {
+    let is_owner = verify_tender_access(&state, tender_id, auth.user_id).await?;
+
+    let rows = sqlx::query_as::<_, TenderQuestionRow>(&format!(
+        r#"
+        SELECT {}
+        FROM tender_questions q
+        LEFT JOIN subcontractors s ON q.subcontractor_id = s.id
+        WHERE q.tender_id = $1
+        ORDER BY q.created_at, q.id
+        "#,
+        QUESTION_COLUMNS
+    ))
+    .bind(tender_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(map_db_err("list_tender_questions"))?;
+
+    let questions: Vec<TenderQuestionResponse> = rows
+        .into_iter()
+        .map(|row| row.into_response(auth.user_id, is_owner))
+        .collect();
+
+    Ok(Json(DataResponse::new(questions)))
+}
+
+/// POST /api/marketplace/tenders/:tender_id/questions
+///
+/// Ask the GC a question while the tender is taking bids.
+pub async fn ask_tender_question(
+    State(state): State<Arc<AppState>>,
+    Path(tender_id): Path<Uuid>,
+    auth: RequireSub,
+    Json(input): Json<AskTenderQuestionRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let question = validate_text("question", &input.question)?;
+
+    if verify_tender_access(&state, tender_id, auth.user_id).await? {
+        return Err(ApiError::bad_request("You cannot ask questions on your own tender"));
+    }
+
+    let tender = fetch_biddable_tender(&state, tender_id).await?;
+    assert_tender_biddable(&tender)?;
+
+    let sub_id: Uuid = sqlx::query_scalar("SELECT id FROM subcontractors WHERE profile_id = $1")
+        .bind(auth.user_id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(map_db_err("ask_tender_question"))?
+        .ok_or_else(|| ApiError::forbidden("You need a subcontractor profile to ask questions"))?;
+
+    let question_id: Uuid = sqlx::query_scalar(
+        r#"
+        INSERT INTO tender_questions (tender_id, asked_by, subcontractor_id, question)
+        VALUES ($1, $2, $3, $4)
+        RETURNING id
+        "#,
+    )
+    .bind(tender_id)
+    .bind(auth.user_id)
+    .bind(sub_id)
+    .bind(&question)
+    .fetch_one(&state.db)
+    .await
+    .map_err(map_db_err("ask_tender_question"))?;
+
+    if let Err(e) = notifications::notify_tender_question_asked(
+        &state.db,
+        tender.owner_id,
+        tender_id,
+        question_id,
+        &tender.name,
+    )
+    .await
+    {
+        tracing::warn!(error = %e, "Failed to create tender question notification");
+    }
+
+    let row = fetch_question(&state, tender_id, question_id).await?;
+    Ok(Created(row.into_response(auth.user_id, false)))
+}
+
+/// POST /api/tenders/:tender_id/questions/:question_id/answer
+///
+/// Answer a question (GC only). Answering again replaces the answer. Everyone
+/// with a live bid, the asker and the tender's watchers are notified.
+pub async fn answer_tender_question(
+    State(state): State<Arc<AppState>>,
+    Path((tender_id, question_id)): Path<(Uuid, Uuid)>,
+    auth: RequireAuth,
+    Json(input): Json<AnswerTenderQuestionRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let answer = validate_text("answer", &input.answer)?;
+
+    let tender_name: String = sqlx::query_scalar(
+        r#"
+        SELECT t.name FROM tenders t
+        JOIN projects p ON t.project_id = p.id
+        WHERE t.id = $1 AND p.owner_id = $2
+        "#,
+    )
+    .bind(tender_id)
+    .bind(auth.user_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("answer_tender_question"))?
+    .ok_or_else(|| ApiError::not_found("Tender not found"))?;
+
+    let result = sqlx::query(
+        r#"
+        UPDATE tender_questions SET answer = $1, answered_by = $2, answered_at = NOW()
+        WHERE id = $3 AND tender_id = $4
+        "#,
+    )
+    .bind(&answer)
+    .bind(auth.user_id)
+    .bind(question_id)
+    .bind(tender_id)
+    .execute(&state.db)
+    .await
+    .map_err(map_db_err("answer_tender_question"))?;
+
+    if result.rows_affected() == 0 {
+        return Err(ApiError::not_found("Question not found"));
+    }
+
+    let recipients: Vec<Uuid> = match sqlx::query_scalar(
+        r#"
+        SELECT COALESCE(b.bidder_id, s.profile_id) FROM bids b
+        LEFT JOIN subcontractors s ON b.subcontractor_id = s.id
+        WHERE b.tender_id = $1 AND b.status NOT IN ('draft', 'withdrawn')
+        AND COALESCE(b.bidder_id, s.profile_id) IS NOT NULL
+        UNION
+        SELECT asked_by FROM tender_questions WHERE id = $2 AND asked_by IS NOT NULL
+        "#,
+    )
+    .bind(tender_id)
+    .bind(question_id)
+    .fetch_all(&state.db)
+    .await
+    {
+        Ok(ids) => ids,
+        Err(e) => {
+            tracing::warn!(error = %e, tender_id = %tender_id, "Failed to load bidders to notify of answer");
+            Vec::new()
+        }
+    };
+    let mut recipients: Vec<Uuid> = recipients.into_iter().filter(|id| *id != auth.user_id).collect();
+
+    let mut exclude = recipients.clone();
+    exclude.push(auth.user_id);
+    match watchers::recipients(&state.db, WatchedResource::Tender, tender_id, &[], &exclude).await {
+        Ok(tender_watchers) => recipients.extend(tender_watchers),
+        Err(e) => tracing::warn!(error = %e, tender_id = %tender_id, "Failed to load tender watchers"),
+    }
+
+    if let Err(e) = notifications::notify_tender_question_answered(
+        &state.db,
+        &recipients,
+        tender_id,
+        question_id,
+        &tender_name,
+    )
+    .await
+    {
+        tracing::warn!(error = %e, "Failed to send tender answer notifications");
+    }
+
+    let row = fetch_question(&state, tender_id, question_id).await?;
+    Ok(Json(DataResponse::new(row.into_response(auth.user_id, true))))
+}
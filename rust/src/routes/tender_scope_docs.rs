@@ -0,0 +1,382 @@
+//! Tender scope document routes
+//!
+//! The AI-generated scope document for a tender is stored as numbered
+//! versions: each generation from the project's trade scopes and each GC edit
+//! adds one. A version remembers which trade scopes it was generated from, so
+//! it can be flagged stale once those scopes change.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::HeaderMap,
+    response::IntoResponse,
+    Json,
+};
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::api::response::{Created, DataResponse};
+use crate::app::AppState;
+use crate::auth::RequireAuth;
+use crate::domain::ai::AiOperation;
+use crate::domain::tenders::{
+    GenerateScopeDocRequest, ScopeDocQuery, ScopeDocSource, TenderScopeDocVersion, UpdateScopeDocRequest,
+};
+use crate::domain::trades::normalized_trade_key;
+use crate::error::{map_db_err, ApiError};
+use crate::middleware::request_id::X_REQUEST_ID;
+use crate::services::ai_quota;
+use crate::services::ai_usage::AiCall;
+
+#[derive(Debug, sqlx::FromRow)]
+struct ScopeDocRow {
+    id: Uuid,
+    tender_id: Uuid,
+    version: i32,
+    latest_version: i32,
+    source: String,
+    document: Option<serde_json::Value>,
+    markdown: String,
+    trade_scope_ids: Vec<Uuid>,
+    sources_updated_at: Option<DateTime<Utc>>,
+    stale: bool,
+    created_by: Option<Uuid>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<ScopeDocRow> for TenderScopeDocVersion {
+    fn from(row: ScopeDocRow) -> Self {
+        Self {
+            id: row.id,
+            tender_id: row.tender_id,
+            version: row.version,
+            latest_version: row.latest_version,
+            source: row.source,
+            document: row.document.and_then(|d| serde_json::from_value(d).ok()),
+            markdown: row.markdown,
+            trade_scope_ids: row.trade_scope_ids,
+            stale: row.stale,
+            created_by: row.created_by,
+            created_at: row.created_at,
+        }
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct TenderScopeSourceRow {
+    trade_category: String,
+    bid_due_date: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct TradeScopeSourceRow {
+    id: Uuid,
+    trade: String,
+    csi_division: Option<String>,
+    inclusions: serde_json::Value,
+    exclusions: serde_json::Value,
+    required_sheets: serde_json::Value,
+    spec_sections: serde_json::Value,
+    rfi_needed: serde_json::Value,
+    assumptions: serde_json::Value,
+    updated_at: DateTime<Utc>,
+}
+
+/// A scope document version to store
+struct NewScopeDocVersion {
+    source: ScopeDocSource,
+    document: Option<serde_json::Value>,
+    markdown: String,
+    trade_scope_ids: Vec<Uuid>,
+    sources_updated_at: Option<DateTime<Utc>>,
+}
+
+/// Trade scope key a tender's documents are generated from
+fn scope_trade_key(trade_category: &str) -> String {
+    normalized_trade_key(Some(trade_category), None)
+        .map(str::to_string)
+        .unwrap_or_else(|| trade_category.to_lowercase())
+}
+
+/// Verify the user owns the tender's project; returns what generation needs.
+async fn fetch_owned_tender(
+    state: &AppState,
+    project_id: Uuid,
+    tender_id: Uuid,
+    user_id: Uuid,
+) -> Result<TenderScopeSourceRow, ApiError> {
+    sqlx::query_as::<_, TenderScopeSourceRow>(
+        r#"
+        SELECT t.trade_category, t.bid_due_date
+        FROM tenders t
+        JOIN projects p ON t.project_id = p.id
+        WHERE t.id = $1 AND t.project_id = $2 AND p.owner_id = $3
+        "#,
+    )
+    .bind(tender_id)
+    .bind(project_id)
+    .bind(user_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("fetch_owned_tender"))?
+    .ok_or_else(|| ApiError::not_found("Tender not found"))
+}
+
+/// A version of the tender's scope document (the latest when `version` is
+/// `None`), with staleness against the project's current trade scopes.
+async fn fetch_scope_doc(
+    state: &AppState,
+    project_id: Uuid,
+    tender_id: Uuid,
+    trade_key: &str,
+    version: Option<i32>,
+) -> Result<Option<ScopeDocRow>, ApiError> {
+    sqlx::query_as::<_, ScopeDocRow>(
+        r#"
+        SELECT d.id, d.tender_id, d.version,
+               (SELECT MAX(version) FROM tender_scope_docs WHERE tender_id = d.tender_id) as latest_version,
+               d.source, d.document, d.markdown, d.trade_scope_ids, d.sources_updated_at,
+               (
+                   EXISTS (
+                       SELECT 1 FROM extracted_trade_scopes ets
+                       WHERE ets.project_id = $3
+                       AND COALESCE(ets.normalized_trade, LOWER(ets.trade)) = $4
+                       AND (NOT (ets.id = ANY(d.trade_scope_ids)) OR ets.updated_at > d.sources_updated_at)
+                   )
+                   OR (SELECT COUNT(*) FROM extracted_trade_scopes WHERE id = ANY(d.trade_scope_ids))
+                      < cardinality(d.trade_scope_ids)
+               ) as stale,
+               d.created_by, d.created_at
+        FROM tender_scope_docs d
+        WHERE d.tender_id = $1 AND ($2::int IS NULL OR d.version = $2)
+        ORDER BY d.version DESC, d.id
+        LIMIT 1
+        "#,
+    )
+    .bind(tender_id)
+    .bind(version)
+    .bind(project_id)
+    .bind(trade_key)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("fetch_scope_doc"))
+}
+
+/// Store `new` as the tender's next version; returns its version number.
+async fn insert_version(
+    state: &AppState,
+    tender_id: Uuid,
+    user_id: Uuid,
+    new: NewScopeDocVersion,
+) -> Result<i32, ApiError> {
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(map_db_err("insert_version"))?;
+
+    // Serialize version numbering per tender
+    sqlx::query("SELECT id FROM tenders WHERE id = $1 FOR UPDATE")
+        .bind(tender_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(map_db_err("insert_version"))?;
+
+    let version: i32 = sqlx::query_scalar(
+        r#"
+        INSERT INTO tender_scope_docs (
+            tender_id, version, source, document, markdown, trade_scope_ids,
+            sources_updated_at, created_by
+        )
+        SELECT $1, COALESCE(MAX(version), 0) + 1, $2, $3, $4, $5, $6, $7
+        FROM tender_scope_docs WHERE tender_id = $1
+        RETURNING version
+        "#,
+    )
+    .bind(tender_id)
+    .bind(new.source.to_string())
+    .bind(new.document)
+    .bind(&new.markdown)
+    .bind(&new.trade_scope_ids)
+    .bind(new.sources_updated_at)
+    .bind(user_id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(map_db_err("insert_version"))?;
+
+    tx.commit()
+        .await
+        .map_err(map_db_err("insert_version"))?;
+
+    Ok(version)
+}
+
+/// GET /api/projects/:project_id/tenders/:tender_id/scope-doc
+///
+/// The tender's scope document: the latest version, or `?version=` for history.
+/// `stale` is set when the trade scopes it was generated from have changed.
+pub async fn get_scope_doc(
+    auth: RequireAuth,
+    State(state): State<Arc<AppState>>,
+    Path((project_id, tender_id)): Path<(Uuid, Uuid)>,
+    Query(query): Query<ScopeDocQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let tender = fetch_owned_tender(&state, project_id, tender_id, auth.user_id).await?;
+    let trade_key = scope_trade_key(&tender.trade_category);
+
+    let doc = fetch_scope_doc(&state, project_id, tender_id, &trade_key, query.version)
+        .await?
+        .ok_or_else(|| match query.version {
+            Some(version) => ApiError::not_found(format!("Scope document version {} not found", version)),
+            None => ApiError::not_found("No scope document has been generated for this tender"),
+        })?;
+
+    Ok(Json(DataResponse::new(TenderScopeDocVersion::from(doc))))
+}
+
+/// POST /api/projects/:project_id/tenders/:tender_id/scope-doc/generate
+///
+/// Generate a new version from the project's current trade scopes for the
+/// tender's trade.
+pub async fn generate_scope_doc(
+    auth: RequireAuth,
+    State(state): State<Arc<AppState>>,
+    Path((project_id, tender_id)): Path<(Uuid, Uuid)>,
+    headers: HeaderMap,
+    Json(req): Json<GenerateScopeDocRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let tender = fetch_owned_tender(&state, project_id, tender_id, auth.user_id).await?;
+    let trade_key = scope_trade_key(&tender.trade_category);
+
+    let scopes = sqlx::query_as::<_, TradeScopeSourceRow>(
+        r#"
+        SELECT id, trade, csi_division,
+               COALESCE(inclusions, '[]'::jsonb) as inclusions,
+               COALESCE(exclusions, '[]'::jsonb) as exclusions,
+               COALESCE(required_sheets, '[]'::jsonb) as required_sheets,
+               COALESCE(spec_sections, '[]'::jsonb) as spec_sections,
+               COALESCE(rfi_needed, '[]'::jsonb) as rfi_needed,
+               COALESCE(assumptions, '[]'::jsonb) as assumptions,
+               updated_at
+        FROM extracted_trade_scopes
+        WHERE project_id = $1 AND COALESCE(normalized_trade, LOWER(trade)) = $2
+        ORDER BY created_at, id
+        "#,
+    )
+    .bind(project_id)
+    .bind(&trade_key)
+    .fetch_all(&state.db)
+    .await
+    .map_err(map_db_err("generate_scope_doc"))?;
+
+    if scopes.is_empty() {
+        return Err(ApiError::unprocessable(format!(
+            "No trade scopes have been extracted for {} in this project",
+            tender.trade_category
+        )));
+    }
+
+    let scope_data: Vec<serde_json::Value> = scopes
+        .iter()
+        .map(|s| {
+            serde_json::json!({
+                "trade": s.trade,
+                "csi_division": s.csi_division,
+                "inclusions": s.inclusions,
+                "exclusions": s.exclusions,
+                "required_sheets": s.required_sheets,
+                "spec_sections": s.spec_sections,
+                "rfi_needed": s.rfi_needed,
+                "assumptions": s.assumptions,
+            })
+        })
+        .collect();
+
+    let request_id = headers.get(X_REQUEST_ID).and_then(|v| v.to_str().ok());
+    let bid_due_date = tender.bid_due_date.map(|d| d.format("%Y-%m-%d").to_string());
+
+    let mut quota = ai_quota::reserve(
+        &state.db,
+        &state.cache,
+        &state.settings.ai_quotas,
+        auth.user_id,
+        Some(project_id),
+    )
+    .await?;
+    let call = AiCall::start(auth.user_id, Some(project_id), AiOperation::TenderScopeDoc);
+    let result = state
+        .ai_client
+        .generate_tender_scope_doc(
+            project_id,
+            &tender.trade_category,
+            &serde_json::Value::Array(scope_data),
+            req.project_context.as_deref(),
+            bid_due_date.as_deref(),
+            request_id,
+        )
+        .await;
+    let result = quota.settle(&state.cache, result).await;
+    let document = call.finish(&state.db, result).await?.output;
+
+    let version = insert_version(
+        &state,
+        tender_id,
+        auth.user_id,
+        NewScopeDocVersion {
+            source: ScopeDocSource::Generated,
+            markdown: document.markdown.clone(),
+            document: serde_json::to_value(&document).ok(),
+            trade_scope_ids: scopes.iter().map(|s| s.id).collect(),
+            sources_updated_at: scopes.iter().map(|s| s.updated_at).max(),
+        },
+    )
+    .await?;
+
+    let doc = fetch_scope_doc(&state, project_id, tender_id, &trade_key, Some(version))
+        .await?
+        .ok_or_else(|| ApiError::internal("Scope document missing after save"))?;
+
+    Ok((quota.headers(), Created(TenderScopeDocVersion::from(doc))))
+}
+
+/// PUT /api/projects/:project_id/tenders/:tender_id/scope-doc
+///
+/// Save the GC's edited text as a new version. The revision keeps the source
+/// trade scopes of the version it was edited from.
+pub async fn update_scope_doc(
+    auth: RequireAuth,
+    State(state): State<Arc<AppState>>,
+    Path((project_id, tender_id)): Path<(Uuid, Uuid)>,
+    Json(req): Json<UpdateScopeDocRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    if req.markdown.trim().is_empty() {
+        return Err(ApiError::bad_request("markdown cannot be empty"));
+    }
+
+    let tender = fetch_owned_tender(&state, project_id, tender_id, auth.user_id).await?;
+    let trade_key = scope_trade_key(&tender.trade_category);
+
+    let latest = fetch_scope_doc(&state, project_id, tender_id, &trade_key, None)
+        .await?
+        .ok_or_else(|| ApiError::not_found("No scope document has been generated for this tender"))?;
+
+    let version = insert_version(
+        &state,
+        tender_id,
+        auth.user_id,
+        NewScopeDocVersion {
+            source: ScopeDocSource::Edited,
+            document: latest.document,
+            markdown: req.markdown,
+            trade_scope_ids: latest.trade_scope_ids,
+            sources_updated_at: latest.sources_updated_at,
+        },
+    )
+    .await?;
+
+    let doc = fetch_scope_doc(&state, project_id, tender_id, &trade_key, Some(version))
+        .await?
+        .ok_or_else(|| ApiError::internal("Scope document missing after save"))?;
+
+    Ok(Json(DataResponse::new(TenderScopeDocVersion::from(doc))))
+}
@@ -4,22 +4,32 @@
 
 use axum::{
     extract::{Path, Query, State},
-    http::StatusCode,
     response::IntoResponse,
     Json,
 };
 use chrono::{DateTime, Utc};
-use rust_decimal::prelude::*;
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::api::pagination::PaginationParams;
-use crate::api::response::{DataResponse, Paginated};
+use crate::api::pagination::{resolve_pagination, PaginationParams};
+use crate::api::response::{Created, DataResponse, NoContent, Paginated};
 use crate::app::AppState;
 use crate::auth::RequireAuth;
-use crate::domain::tenders::{CreateTenderRequest, TradeCategory, UpdateTenderRequest};
-use crate::error::ApiError;
-use crate::services::cache::{keys as cache_keys, ttl as cache_ttl};
+use crate::domain::activity::ActivityAction;
+use crate::domain::admin::AuditTargetType;
+use crate::domain::money::Money;
+use crate::domain::notifications::NotificationData;
+use crate::domain::tenders::{CreateTenderRequest, TenderStatus, TradeCategory, UpdateTenderRequest};
+use crate::error::{map_db_err, ApiError};
+use crate::middleware::ExtractClientIp;
+use crate::services::audit::{self, ActivityEntry};
+use crate::services::cache::{keys as cache_keys, ttl as cache_ttl, CacheNamespace};
+use crate::services::geocoding::{self, GeocodedTable};
+use crate::services::notifications::{self, NewNotification};
+use crate::services::watchers::{self, WatchedResource};
+
+/// Most matching subcontractors notified when a public tender is published
+const PUBLISH_NOTIFY_LIMIT: i64 = 500;
 
 /// Database row for tender with computed bid counts
 #[derive(Debug, sqlx::FromRow)]
@@ -32,7 +42,7 @@ struct TenderRow {
     scope_of_work: Option<String>,
     status: String,
     bid_due_date: Option<DateTime<Utc>>,
-    estimated_value: Option<rust_decimal::Decimal>,
+    estimated_value: Option<Money>,
     awarded_to: Option<Uuid>,
     priority: Option<String>,
     created_at: DateTime<Utc>,
@@ -40,6 +50,16 @@ struct TenderRow {
     bids_received: Option<i64>,
 }
 
+/// Fields checked by status transitions
+#[derive(Debug, sqlx::FromRow)]
+struct TenderStateRow {
+    name: String,
+    status: String,
+    trade_category: String,
+    scope_of_work: Option<String>,
+    bid_due_date: Option<DateTime<Utc>>,
+}
+
 /// Response DTO for tender
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct TenderResponse {
@@ -51,7 +71,8 @@ pub struct TenderResponse {
     scope_of_work: Option<String>,
     status: String,
     bid_due_date: Option<DateTime<Utc>>,
-    estimated_value: Option<i64>,
+    /// Estimated value in dollars
+    estimated_value: Option<Money>,
     bids_received: i32,
     bids_invited: i32,
     awarded_to: Option<String>,
@@ -69,11 +90,6 @@ struct CachedTenderList {
 
 impl From<TenderRow> for TenderResponse {
     fn from(row: TenderRow) -> Self {
-        // Convert decimal to cents
-        let estimated_value = row
-            .estimated_value
-            .map(|d| (d * rust_decimal::Decimal::from(100)).to_i64().unwrap_or(0));
-
         Self {
             id: row.id,
             project_id: row.project_id,
@@ -83,7 +99,7 @@ impl From<TenderRow> for TenderResponse {
             scope_of_work: row.scope_of_work,
             status: row.status,
             bid_due_date: row.bid_due_date,
-            estimated_value,
+            estimated_value: row.estimated_value,
             bids_received: row.bids_received.unwrap_or(0) as i32,
             bids_invited: 0, // Not tracked in current schema
             awarded_to: row.awarded_to.map(|id| id.to_string()),
@@ -106,7 +122,7 @@ async fn verify_project_ownership(
             .bind(user_id)
             .fetch_optional(&state.db)
             .await
-            .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+            .map_err(map_db_err("verify_project_ownership"))?;
 
     if exists.is_none() {
         return Err(ApiError::not_found("Project not found"));
@@ -115,6 +131,59 @@ async fn verify_project_ownership(
     Ok(())
 }
 
+/// Load the fields a status transition depends on
+async fn fetch_tender_state(
+    state: &AppState,
+    project_id: Uuid,
+    tender_id: Uuid,
+) -> Result<TenderStateRow, ApiError> {
+    sqlx::query_as::<_, TenderStateRow>(
+        r#"
+        SELECT name, status, trade_category, scope_of_work, bid_due_date
+        FROM tenders
+        WHERE id = $1 AND project_id = $2
+        "#,
+    )
+    .bind(tender_id)
+    .bind(project_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("fetch_tender_state"))?
+    .ok_or_else(|| ApiError::not_found("Tender not found"))
+}
+
+fn tender_status_to_string(status: &TenderStatus) -> &'static str {
+    match status {
+        TenderStatus::Draft => "draft",
+        TenderStatus::Published => "open",
+        TenderStatus::Closed => "closed",
+        TenderStatus::Awarded => "awarded",
+        TenderStatus::Cancelled => "cancelled",
+    }
+}
+
+/// Tender lifecycle: draft -> open (publish) -> closed -> awarded.
+/// A closed tender can be republished, and anything not yet awarded can be
+/// cancelled. Opening always goes through the publish endpoint.
+fn status_transition_allowed(from: &str, to: &str) -> bool {
+    matches!(
+        (from, to),
+        ("draft", "cancelled")
+            | ("open", "closed")
+            | ("open", "awarded")
+            | ("open", "cancelled")
+            | ("closed", "awarded")
+            | ("closed", "cancelled")
+    )
+}
+
+/// Invalidate the caches that list or count a project's tenders
+async fn invalidate_tender_caches(state: &AppState, project_id: Uuid, user_id: Uuid) {
+    let _ = state.cache.delete_pattern(&cache_keys::tender_list_pattern(project_id)).await;
+    let _ = state.cache.delete_pattern(&cache_keys::tender_user_pattern(user_id)).await;
+    let _ = state.cache.delete(&cache_keys::dashboard_stats(user_id)).await;
+}
+
 fn trade_category_to_string(cat: &TradeCategory) -> &'static str {
     match cat {
         TradeCategory::GeneralConditions => "general_conditions",
@@ -160,11 +229,6 @@ pub async fn create_tender(
 
     let trade_category = trade_category_to_string(&req.trade_category);
 
-    // Convert cents to decimal
-    let estimated_value = req
-        .estimated_value
-        .map(|cents| rust_decimal::Decimal::from(cents) / rust_decimal::Decimal::from(100));
-
     let tender = sqlx::query_as::<_, TenderRow>(
         r#"
         INSERT INTO tenders (project_id, name, description, trade_category, scope_of_work, status, bid_due_date, estimated_value)
@@ -179,10 +243,28 @@ pub async fn create_tender(
     .bind(trade_category)
     .bind(&req.scope_of_work)
     .bind(req.bid_due_date)
-    .bind(estimated_value)
+    .bind(req.estimated_value)
+    .fetch_one(&state.db)
+    .await
+    .map_err(map_db_err("create_tender"))?;
+
+    // Geocode the project address for marketplace distance search
+    let address: Option<String> = sqlx::query_scalar(
+        "SELECT NULLIF(concat_ws(', ', address, city, state, zip_code), '') FROM projects WHERE id = $1",
+    )
+    .bind(project_id)
     .fetch_one(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Failed to create tender: {}", e)))?;
+    .unwrap_or_default();
+    if let Some(address) = address {
+        geocoding::spawn_geocode_row(
+            state.geocoder.clone(),
+            state.db.clone(),
+            GeocodedTable::Tenders,
+            tender.id,
+            address,
+        );
+    }
 
     let response: TenderResponse = tender.into();
 
@@ -194,7 +276,7 @@ pub async fn create_tender(
     // Invalidate dashboard
     let _ = state.cache.delete(&cache_keys::dashboard_stats(auth.user_id)).await;
 
-    Ok((StatusCode::CREATED, Json(DataResponse::new(response))))
+    Ok(Created(response))
 }
 
 /// GET /api/projects/:project_id/tenders
@@ -208,18 +290,15 @@ pub async fn list_tenders(
 ) -> Result<impl IntoResponse, ApiError> {
     verify_project_ownership(&state, project_id, auth.user_id).await?;
 
-    let page = pagination.page();
-    let per_page = pagination.per_page();
-    let cache_key = cache_keys::tender_list(project_id, page, per_page);
+    let page = resolve_pagination(&pagination, state.settings.pagination.default)?;
+    let cache_key = cache_keys::tender_list(project_id, page.page, page.per_page);
 
     // Try cache first
     if let Some(cached) = state.cache.get::<CachedTenderList>(&cache_key).await {
         tracing::debug!(project_id = %project_id, "Tenders list cache hit");
-        return Ok(Json(Paginated::new(cached.data, &pagination, cached.total)));
+        return Ok(Json(Paginated::new(cached.data, &page, cached.total)));
     }
 
-    let offset = pagination.offset() as i64;
-    let limit = pagination.limit() as i64;
 
     // Get total count (with caching)
     let count_cache_key = cache_keys::tender_count(project_id);
@@ -230,7 +309,7 @@ pub async fn list_tenders(
             .bind(project_id)
             .fetch_one(&state.db)
             .await
-            .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+            .map_err(map_db_err("list_tenders"))?;
         let _ = state.cache.set_with_ttl(&count_cache_key, &count, cache_ttl::COUNT).await;
         count
     };
@@ -244,24 +323,24 @@ pub async fn list_tenders(
                (SELECT COUNT(*) FROM bids WHERE tender_id = t.id) as bids_received
         FROM tenders t
         WHERE t.project_id = $1
-        ORDER BY t.created_at DESC
+        ORDER BY t.created_at DESC, t.id
         LIMIT $2 OFFSET $3
         "#,
     )
     .bind(project_id)
-    .bind(limit)
-    .bind(offset)
+    .bind(page.limit())
+    .bind(page.offset())
     .fetch_all(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("list_tenders"))?;
 
     let data: Vec<TenderResponse> = tenders.into_iter().map(Into::into).collect();
 
     // Cache the result
     let cached = CachedTenderList { data: data.clone(), total: total as u64 };
-    let _ = state.cache.set_with_ttl(&cache_key, &cached, cache_ttl::LIST).await;
+    let _ = state.cache.set_in(CacheNamespace::Listing, &cache_key, &cached).await;
 
-    Ok(Json(Paginated::new(data, &pagination, total as u64)))
+    Ok(Json(Paginated::new(data, &page, total as u64)))
 }
 
 /// GET /api/tenders
@@ -272,18 +351,15 @@ pub async fn list_all_tenders(
     State(state): State<Arc<AppState>>,
     Query(pagination): Query<PaginationParams>,
 ) -> Result<impl IntoResponse, ApiError> {
-    let page = pagination.page();
-    let per_page = pagination.per_page();
-    let cache_key = cache_keys::tender_list_all(auth.user_id, page, per_page);
+    let page = resolve_pagination(&pagination, state.settings.pagination.default)?;
+    let cache_key = cache_keys::tender_list_all(auth.user_id, page.page, page.per_page);
 
     // Try cache first
     if let Some(cached) = state.cache.get::<CachedTenderList>(&cache_key).await {
         tracing::debug!(user_id = %auth.user_id, "All tenders list cache hit");
-        return Ok(Json(Paginated::new(cached.data, &pagination, cached.total)));
+        return Ok(Json(Paginated::new(cached.data, &page, cached.total)));
     }
 
-    let offset = pagination.offset() as i64;
-    let limit = pagination.limit() as i64;
 
     // Get total count (with caching)
     let count_cache_key = cache_keys::tender_count_all(auth.user_id);
@@ -300,7 +376,7 @@ pub async fn list_all_tenders(
         .bind(auth.user_id)
         .fetch_one(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+        .map_err(map_db_err("list_all_tenders"))?;
         let _ = state.cache.set_with_ttl(&count_cache_key, &count, cache_ttl::COUNT).await;
         count
     };
@@ -315,24 +391,24 @@ pub async fn list_all_tenders(
         FROM tenders t
         JOIN projects p ON t.project_id = p.id
         WHERE p.owner_id = $1
-        ORDER BY t.created_at DESC
+        ORDER BY t.created_at DESC, t.id
         LIMIT $2 OFFSET $3
         "#,
     )
     .bind(auth.user_id)
-    .bind(limit)
-    .bind(offset)
+    .bind(page.limit())
+    .bind(page.offset())
     .fetch_all(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(map_db_err("list_all_tenders"))?;
 
     let data: Vec<TenderResponse> = tenders.into_iter().map(Into::into).collect();
 
     // Cache the result
     let cached = CachedTenderList { data: data.clone(), total: total as u64 };
-    let _ = state.cache.set_with_ttl(&cache_key, &cached, cache_ttl::LIST).await;
+    let _ = state.cache.set_in(CacheNamespace::Listing, &cache_key, &cached).await;
 
-    Ok(Json(Paginated::new(data, &pagination, total as u64)))
+    Ok(Json(Paginated::new(data, &page, total as u64)))
 }
 
 /// GET /api/projects/:project_id/tenders/:tender_id
@@ -359,7 +435,7 @@ pub async fn get_tender(
     .bind(project_id)
     .fetch_optional(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
+    .map_err(map_db_err("get_tender"))?
     .ok_or_else(|| ApiError::not_found("Tender not found"))?;
 
     let response: TenderResponse = tender.into();
@@ -373,35 +449,42 @@ pub async fn update_tender(
     auth: RequireAuth,
     State(state): State<Arc<AppState>>,
     Path((project_id, tender_id)): Path<(Uuid, Uuid)>,
+    ExtractClientIp(client_ip): ExtractClientIp,
     Json(req): Json<UpdateTenderRequest>,
 ) -> Result<impl IntoResponse, ApiError> {
     verify_project_ownership(&state, project_id, auth.user_id).await?;
 
-    // Check tender exists
-    let exists: Option<Uuid> =
-        sqlx::query_scalar("SELECT id FROM tenders WHERE id = $1 AND project_id = $2")
-            .bind(tender_id)
-            .bind(project_id)
-            .fetch_optional(&state.db)
-            .await
-            .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    let current = fetch_tender_state(&state, project_id, tender_id).await?;
+    let previous_status = current.status.clone();
 
-    if exists.is_none() {
-        return Err(ApiError::not_found("Tender not found"));
+    let trade_category = req.trade_category.as_ref().map(trade_category_to_string);
+    let status = req.status.as_ref().map(tender_status_to_string);
+
+    if let Some(status) = status.filter(|s| *s != previous_status) {
+        if status == "open" {
+            return Err(ApiError::bad_request(
+                "Use POST /projects/:project_id/tenders/:tender_id/publish to open a tender",
+            ));
+        }
+        if !status_transition_allowed(&previous_status, status) {
+            return Err(ApiError::conflict(format!(
+                "Cannot change tender status from {} to {}",
+                previous_status, status
+            )));
+        }
     }
 
-    let trade_category = req.trade_category.as_ref().map(trade_category_to_string);
-    let status = req.status.as_ref().map(|s| match s {
-        crate::domain::tenders::TenderStatus::Draft => "draft",
-        crate::domain::tenders::TenderStatus::Published => "open",
-        crate::domain::tenders::TenderStatus::Closed => "closed",
-        crate::domain::tenders::TenderStatus::Awarded => "awarded",
-        crate::domain::tenders::TenderStatus::Cancelled => "cancelled",
-    });
-
-    let estimated_value = req
-        .estimated_value
-        .map(|cents| rust_decimal::Decimal::from(cents) / rust_decimal::Decimal::from(100));
+    // Bidders priced the published scope, so changing it needs explicit confirmation
+    let scope_changed = req
+        .scope_of_work
+        .as_ref()
+        .is_some_and(|s| current.scope_of_work.as_ref() != Some(s))
+        || trade_category.is_some_and(|t| t != current.trade_category);
+    if scope_changed && previous_status == "open" && !req.confirm_republish {
+        return Err(ApiError::conflict(
+            "Tender is open for bids; set confirm_republish to change its scope or trade",
+        ));
+    }
 
     let tender = sqlx::query_as::<_, TenderRow>(
         r#"
@@ -427,10 +510,49 @@ pub async fn update_tender(
     .bind(&req.scope_of_work)
     .bind(status)
     .bind(req.bid_due_date)
-    .bind(estimated_value)
+    .bind(req.estimated_value)
     .fetch_one(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Failed to update tender: {}", e)))?;
+    .map_err(map_db_err("update_tender"))?;
+
+    if tender.status == "awarded" && previous_status != "awarded" {
+        if let Err(e) = audit::log_activity(
+            &state.db,
+            ActivityEntry {
+                actor_id: auth.user_id,
+                project_id: Some(project_id),
+                action: ActivityAction::BidAwarded,
+                target_type: AuditTargetType::Tender,
+                target_id: Some(tender_id),
+                details: serde_json::json!({
+                    "tender_name": tender.name,
+                    "awarded_to": tender.awarded_to,
+                }),
+                ip_address: client_ip,
+            },
+        )
+        .await
+        {
+            tracing::warn!(error = %e, "Failed to log tender award");
+        }
+
+        notify_tender_awarded(&state, tender_id, &tender.name, project_id, tender.awarded_to, auth.user_id).await;
+    }
+
+    if scope_changed && previous_status == "open" {
+        notify_bidders(
+            &state,
+            tender_id,
+            auth.user_id,
+            "Tender scope updated",
+            &format!("The scope of '{}' has changed. Review your bid before the due date.", tender.name),
+            NotificationData::TenderPublished {
+                tender_id,
+                tender_title: tender.name.clone(),
+            },
+        )
+        .await;
+    }
 
     let response: TenderResponse = tender.into();
 
@@ -458,7 +580,7 @@ pub async fn delete_tender(
         .bind(project_id)
         .execute(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+        .map_err(map_db_err("delete_tender"))?;
 
     if result.rows_affected() == 0 {
         return Err(ApiError::not_found("Tender not found"));
@@ -472,5 +594,365 @@ pub async fn delete_tender(
     // Invalidate dashboard
     let _ = state.cache.delete(&cache_keys::dashboard_stats(auth.user_id)).await;
 
-    Ok(StatusCode::NO_CONTENT)
+    Ok(NoContent)
+}
+
+/// POST /api/projects/:project_id/tenders/:tender_id/publish
+///
+/// Open a draft (or reopen a closed) tender for bids. Requires a trade
+/// category, a scope of work and a future bid due date, then notifies invited
+/// subcontractors and, for public tenders, subcontractors in the trade.
+pub async fn publish_tender(
+    auth: RequireAuth,
+    State(state): State<Arc<AppState>>,
+    Path((project_id, tender_id)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse, ApiError> {
+    verify_project_ownership(&state, project_id, auth.user_id).await?;
+
+    let current = fetch_tender_state(&state, project_id, tender_id).await?;
+    if !matches!(current.status.as_str(), "draft" | "closed") {
+        return Err(ApiError::conflict(format!(
+            "Cannot publish a tender that is {}",
+            current.status
+        )));
+    }
+
+    let mut missing = Vec::new();
+    if current.trade_category.trim().is_empty() {
+        missing.push("trade_category");
+    }
+    if current.scope_of_work.as_deref().map_or(true, |s| s.trim().is_empty()) {
+        missing.push("scope_of_work");
+    }
+    if current.bid_due_date.is_none() {
+        missing.push("bid_due_date");
+    }
+    if !missing.is_empty() {
+        return Err(ApiError::bad_request(format!(
+            "Tender cannot be published without: {}",
+            missing.join(", ")
+        )));
+    }
+    if current.bid_due_date.is_some_and(|due| due <= Utc::now()) {
+        return Err(ApiError::bad_request("bid_due_date must be in the future"));
+    }
+
+    let tender = sqlx::query_as::<_, TenderRow>(
+        r#"
+        UPDATE tenders SET status = 'open', updated_at = NOW()
+        WHERE id = $1 AND project_id = $2 AND status = $3
+        RETURNING id, project_id, name, description, trade_category, scope_of_work, status, bid_due_date, estimated_value, awarded_to, priority, created_at, updated_at,
+                  (SELECT COUNT(*) FROM bids WHERE tender_id = id) as bids_received
+        "#,
+    )
+    .bind(tender_id)
+    .bind(project_id)
+    .bind(&current.status)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("publish_tender"))?
+    .ok_or_else(|| ApiError::conflict("Tender status changed; reload and try again"))?;
+
+    tracing::info!(tender_id = %tender_id, user_id = %auth.user_id, "Tender published");
+
+    notify_tender_published(&state, tender_id, &tender.name, &tender.trade_category, auth.user_id).await;
+    invalidate_tender_caches(&state, project_id, auth.user_id).await;
+
+    let response: TenderResponse = tender.into();
+    Ok(Json(DataResponse::new(response)))
+}
+
+/// POST /api/projects/:project_id/tenders/:tender_id/close
+///
+/// Stop accepting bids on an open tender and notify its bidders.
+pub async fn close_tender(
+    auth: RequireAuth,
+    State(state): State<Arc<AppState>>,
+    Path((project_id, tender_id)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse, ApiError> {
+    verify_project_ownership(&state, project_id, auth.user_id).await?;
+
+    let current = fetch_tender_state(&state, project_id, tender_id).await?;
+    if current.status != "open" {
+        return Err(ApiError::conflict(format!(
+            "Only open tenders can be closed; this tender is {}",
+            current.status
+        )));
+    }
+
+    let tender = sqlx::query_as::<_, TenderRow>(
+        r#"
+        UPDATE tenders SET status = 'closed', updated_at = NOW()
+        WHERE id = $1 AND project_id = $2 AND status = 'open'
+        RETURNING id, project_id, name, description, trade_category, scope_of_work, status, bid_due_date, estimated_value, awarded_to, priority, created_at, updated_at,
+                  (SELECT COUNT(*) FROM bids WHERE tender_id = id) as bids_received
+        "#,
+    )
+    .bind(tender_id)
+    .bind(project_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("close_tender"))?
+    .ok_or_else(|| ApiError::conflict("Tender status changed; reload and try again"))?;
+
+    notify_bidders(
+        &state,
+        tender_id,
+        auth.user_id,
+        "Tender closed",
+        &format!("'{}' is no longer accepting bids.", current.name),
+        NotificationData::TenderClosed {
+            tender_id,
+            tender_title: current.name.clone(),
+        },
+    )
+    .await;
+    invalidate_tender_caches(&state, project_id, auth.user_id).await;
+
+    let response: TenderResponse = tender.into();
+    Ok(Json(DataResponse::new(response)))
+}
+
+/// Notify invited subcontractors and, for public tenders, subcontractors
+/// working in the tender's trade. Failures are logged, not returned.
+async fn notify_tender_published(
+    state: &AppState,
+    tender_id: Uuid,
+    tender_name: &str,
+    trade_category: &str,
+    gc_user_id: Uuid,
+) {
+    let recipients: Vec<Uuid> = match sqlx::query_scalar(
+        r#"
+        SELECT DISTINCT s.profile_id
+        FROM subcontractors s
+        JOIN tenders t ON t.id = $1
+        WHERE s.profile_id IS NOT NULL AND s.profile_id <> $2
+        AND (s.id = ANY(COALESCE(t.invited_subcontractors, '{}'))
+             OR (COALESCE(t.visibility, 'public') = 'public'
+                 AND COALESCE(s.availability_status, 'available') != 'not_taking_work'
+                 AND (s.trade ILIKE '%' || $3 || '%'
+                      OR EXISTS (SELECT 1 FROM jsonb_array_elements_text(s.secondary_trades) st
+                                 WHERE st ILIKE '%' || $3 || '%'))))
+        LIMIT $4
+        "#,
+    )
+    .bind(tender_id)
+    .bind(gc_user_id)
+    .bind(trade_category.replace('_', " "))
+    .bind(PUBLISH_NOTIFY_LIMIT)
+    .fetch_all(&state.db)
+    .await
+    {
+        Ok(ids) => ids,
+        Err(e) => {
+            tracing::warn!(error = %e, tender_id = %tender_id, "Failed to find subcontractors to notify of published tender");
+            return;
+        }
+    };
+
+    // Users whose saved tender searches match, beyond those notified above
+    let saved_search_matches: Vec<(Uuid, String)> = match sqlx::query_as(
+        r#"
+        SELECT DISTINCT ON (ss.user_id) ss.user_id, ss.name
+        FROM saved_searches ss
+        JOIN tenders t ON t.id = $1
+        WHERE ss.search_type = 'tenders'
+        AND ss.notify_new_matches = true
+        AND ss.user_id <> $2
+        AND NOT (ss.user_id = ANY($3))
+        AND COALESCE(t.visibility, 'public') = 'public'
+        AND (NULLIF(ss.filters->>'trade', '') IS NULL
+             OR t.trade_category ILIKE '%' || (ss.filters->>'trade') || '%')
+        AND (NULLIF(ss.filters->>'search', '') IS NULL
+             OR t.name ILIKE '%' || (ss.filters->>'search') || '%'
+             OR t.description ILIKE '%' || (ss.filters->>'search') || '%')
+        ORDER BY ss.user_id, ss.created_at
+        LIMIT $4
+        "#,
+    )
+    .bind(tender_id)
+    .bind(gc_user_id)
+    .bind(&recipients)
+    .bind(PUBLISH_NOTIFY_LIMIT)
+    .fetch_all(&state.db)
+    .await
+    {
+        Ok(rows) => rows,
+        Err(e) => {
+            tracing::warn!(error = %e, tender_id = %tender_id, "Failed to match saved searches for published tender");
+            Vec::new()
+        }
+    };
+
+    let data = NotificationData::TenderPublished {
+        tender_id,
+        tender_title: tender_name.to_string(),
+    };
+    let message = format!("'{}' is now accepting bids", tender_name);
+    let batch: Vec<NewNotification> = recipients
+        .iter()
+        .map(|user_id| {
+            NewNotification::new(*user_id, "New tender open for bids", Some(message.clone()), data.clone())
+        })
+        .chain(saved_search_matches.into_iter().map(|(user_id, search_name)| {
+            NewNotification::new(
+                user_id,
+                format!("New tender matches \"{}\"", search_name),
+                Some(message.clone()),
+                data.clone(),
+            )
+        }))
+        .collect();
+
+    if let Err(e) = notifications::notify_many(&state.db, &batch).await {
+        tracing::warn!(error = %e, tender_id = %tender_id, "Failed to send tender published notifications");
+    }
+}
+
+/// Tell the winning bidder they were awarded, every other live bidder that
+/// their bid was not selected, and the tender's watchers that it was awarded,
+/// in one batch. Failures are logged, not returned.
+async fn notify_tender_awarded(
+    state: &AppState,
+    tender_id: Uuid,
+    tender_name: &str,
+    project_id: Uuid,
+    winner: Option<Uuid>,
+    actor: Uuid,
+) {
+    let bidders: Vec<Uuid> = match sqlx::query_scalar(
+        r#"
+        SELECT DISTINCT COALESCE(b.bidder_id, s.profile_id) FROM bids b
+        LEFT JOIN subcontractors s ON b.subcontractor_id = s.id
+        WHERE b.tender_id = $1
+        AND COALESCE(b.bidder_id, s.profile_id) IS NOT NULL
+        AND b.status NOT IN ('draft', 'withdrawn')
+        "#,
+    )
+    .bind(tender_id)
+    .fetch_all(&state.db)
+    .await
+    {
+        Ok(ids) => ids,
+        Err(e) => {
+            tracing::warn!(error = %e, tender_id = %tender_id, "Failed to load bidders to notify of award");
+            return;
+        }
+    };
+
+    let project_name: String = sqlx::query_scalar("SELECT name FROM projects WHERE id = $1")
+        .bind(project_id)
+        .fetch_optional(&state.db)
+        .await
+        .ok()
+        .flatten()
+        .unwrap_or_default();
+
+    let mut batch: Vec<NewNotification> = Vec::with_capacity(bidders.len() + 1);
+    if let Some(winner) = winner {
+        batch.push(NewNotification::new(
+            winner,
+            "Your bid was accepted!",
+            Some(format!(
+                "Congratulations! Your bid for '{}' on project '{}' has been selected.",
+                tender_name, project_name
+            )),
+            NotificationData::BidAwarded {
+                tender_id,
+                tender_title: tender_name.to_string(),
+                project_name: project_name.clone(),
+            },
+        ));
+    }
+    let mut exclude = bidders.clone();
+    exclude.extend(winner);
+    exclude.push(actor);
+    let tender_watchers = match watchers::recipients(&state.db, WatchedResource::Tender, tender_id, &[], &exclude).await {
+        Ok(ids) => ids,
+        Err(e) => {
+            tracing::warn!(error = %e, tender_id = %tender_id, "Failed to load tender watchers");
+            Vec::new()
+        }
+    };
+
+    batch.extend(bidders.into_iter().filter(|id| Some(*id) != winner).map(|user_id| {
+        NewNotification::new(
+            user_id,
+            "Bid not selected",
+            Some(format!(
+                "Your bid for '{}' was not selected. Keep bidding on other opportunities!",
+                tender_name
+            )),
+            NotificationData::BidRejected {
+                tender_id,
+                tender_title: tender_name.to_string(),
+            },
+        )
+    }));
+
+    let awarded = NotificationData::TenderStatusChanged {
+        tender_id,
+        tender_title: tender_name.to_string(),
+        status: "awarded".to_string(),
+    };
+    batch.extend(tender_watchers.into_iter().map(|user_id| {
+        NewNotification::new(
+            user_id,
+            "Tender awarded",
+            Some(format!("'{}' has been awarded.", tender_name)),
+            awarded.clone(),
+        )
+    }));
+
+    if let Err(e) = notifications::notify_many(&state.db, &batch).await {
+        tracing::warn!(error = %e, tender_id = %tender_id, "Failed to send tender award notifications");
+    }
+}
+
+/// Notify everyone with a live bid on a tender, and its watchers, except
+/// `actor`. Failures are logged, not returned.
+async fn notify_bidders(
+    state: &AppState,
+    tender_id: Uuid,
+    actor: Uuid,
+    title: &str,
+    message: &str,
+    data: NotificationData,
+) {
+    let bidders: Vec<Uuid> = match sqlx::query_scalar(
+        r#"
+        SELECT DISTINCT bidder_id FROM bids
+        WHERE tender_id = $1 AND bidder_id IS NOT NULL
+        AND status NOT IN ('draft', 'withdrawn')
+        "#,
+    )
+    .bind(tender_id)
+    .fetch_all(&state.db)
+    .await
+    {
+        Ok(ids) => ids,
+        Err(e) => {
+            tracing::warn!(error = %e, tender_id = %tender_id, "Failed to load bidders to notify");
+            return;
+        }
+    };
+
+    let mut exclude = bidders.clone();
+    exclude.push(actor);
+    let mut recipients = bidders;
+    match watchers::recipients(&state.db, WatchedResource::Tender, tender_id, &[], &exclude).await {
+        Ok(tender_watchers) => recipients.extend(tender_watchers),
+        Err(e) => tracing::warn!(error = %e, tender_id = %tender_id, "Failed to load tender watchers"),
+    }
+
+    if recipients.is_empty() {
+        return;
+    }
+
+    if let Err(e) =
+        notifications::create_notifications_batch(&state.db, &recipients, title, Some(message), data).await
+    {
+        tracing::warn!(error = %e, tender_id = %tender_id, "Failed to notify bidders");
+    }
 }
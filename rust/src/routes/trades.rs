@@ -0,0 +1,19 @@
+//! Trade catalog routes
+//!
+//! Public reference data: canonical trades and their CSI divisions.
+
+use axum::{http::header, response::IntoResponse, Json};
+
+use crate::api::response::DataResponse;
+use crate::domain::trades::TRADES;
+
+/// GET /api/trades
+///
+/// List the canonical trade catalog with display names and CSI divisions.
+/// The catalog is compiled in, so clients and proxies may cache it for a day.
+pub async fn list_trades() -> impl IntoResponse {
+    (
+        [(header::CACHE_CONTROL, "public, max-age=86400")],
+        Json(DataResponse::new(TRADES)),
+    )
+}
@@ -0,0 +1,311 @@
+//! Verification document routes
+//!
+//! Subcontractors attach evidence (insurance certificates, licenses) to their
+//! verification request instead of relying on self-reported profile fields.
+//! Files are kept outside the public media root and are only reachable
+//! through time-limited signed links issued to the uploader and to admins.
+
+use axum::{
+    body::Body,
+    extract::{Multipart, Path, Query, Request, State},
+    http::{header, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{DateTime, NaiveDate, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::fs;
+use tower::ServiceExt;
+use tower_http::services::ServeFile;
+use uuid::Uuid;
+
+use crate::api::response::{Created, DataResponse};
+use crate::app::AppState;
+use crate::auth::RequireSub;
+use crate::domain::marketplace::{VerificationDocumentResponse, VerificationDocumentType};
+use crate::domain::tenders::SignedDownloadQuery;
+use crate::error::{map_db_err, ApiError};
+
+/// Largest verification document accepted
+pub const MAX_DOCUMENT_BYTES: usize = 20 * 1024 * 1024;
+
+/// Content types accepted for verification documents
+const ALLOWED_MIME_TYPES: &[&str] = &["application/pdf", "image/jpeg", "image/png", "image/webp"];
+
+#[derive(Debug, sqlx::FromRow)]
+struct VerificationDocumentRow {
+    id: Uuid,
+    subcontractor_id: Uuid,
+    document_type: String,
+    file_name: String,
+    file_size: i64,
+    mime_type: Option<String>,
+    expiry_date: Option<NaiveDate>,
+    created_at: DateTime<Utc>,
+}
+
+fn view_path(document_id: Uuid) -> String {
+    format!("/verification-documents/{}/view", document_id)
+}
+
+fn to_response(state: &AppState, viewer_id: Uuid, row: VerificationDocumentRow) -> VerificationDocumentResponse {
+    let signed = state.url_signer.sign(&view_path(row.id), viewer_id);
+    let expired = row.expiry_date.is_some_and(|d| d < Utc::now().date_naive());
+
+    VerificationDocumentResponse {
+        id: row.id,
+        document_type: row.document_type,
+        file_name: row.file_name,
+        file_size: row.file_size,
+        mime_type: row.mime_type,
+        expiry_date: row.expiry_date,
+        expired,
+        view_url: signed.url,
+        view_expires_at: signed.expires_at,
+        created_at: row.created_at,
+        warning: None,
+    }
+}
+
+async fn fetch_own_subcontractor_id(state: &AppState, user_id: Uuid) -> Result<Uuid, ApiError> {
+    sqlx::query_scalar("SELECT id FROM subcontractors WHERE profile_id = $1")
+        .bind(user_id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(map_db_err("fetch_own_subcontractor_id"))?
+        .ok_or_else(|| ApiError::not_found("No subcontractor profile found. Create one first."))
+}
+
+/// Verification documents for each of `sub_ids`, with view links for
+/// `viewer_id`. Subcontractors without documents are absent from the map.
+pub(crate) async fn documents_by_subcontractor(
+    state: &AppState,
+    sub_ids: &[Uuid],
+    viewer_id: Uuid,
+) -> Result<HashMap<Uuid, Vec<VerificationDocumentResponse>>, ApiError> {
+    let rows = sqlx::query_as::<_, VerificationDocumentRow>(
+        r#"
+        SELECT id, subcontractor_id, document_type, file_name, file_size, mime_type,
+               expiry_date, created_at
+        FROM verification_documents
+        WHERE subcontractor_id = ANY($1)
+        ORDER BY created_at DESC
+        "#,
+    )
+    .bind(sub_ids)
+    .fetch_all(&state.db)
+    .await
+    .map_err(map_db_err("documents_by_subcontractor"))?;
+
+    let mut documents: HashMap<Uuid, Vec<VerificationDocumentResponse>> = HashMap::new();
+    for row in rows {
+        documents
+            .entry(row.subcontractor_id)
+            .or_default()
+            .push(to_response(state, viewer_id, row));
+    }
+    Ok(documents)
+}
+
+/// GET /api/marketplace/profile/verification-documents
+///
+/// The caller's verification documents, newest first.
+pub async fn list_my_verification_documents(
+    State(state): State<Arc<AppState>>,
+    auth: RequireSub,
+) -> Result<impl IntoResponse, ApiError> {
+    let sub_id = fetch_own_subcontractor_id(&state, auth.user_id).await?;
+
+    let documents = documents_by_subcontractor(&state, &[sub_id], auth.user_id)
+        .await?
+        .remove(&sub_id)
+        .unwrap_or_default();
+
+    Ok(Json(DataResponse::new(documents)))
+}
+
+/// POST /api/marketplace/profile/verification-documents
+///
+/// Multipart upload with `file`, `document_type` (`insurance`, `license` or
+/// `other`) and an optional `expiry_date` (YYYY-MM-DD). An insurance document
+/// that has already expired is stored but flagged with a warning.
+pub async fn upload_verification_document(
+    State(state): State<Arc<AppState>>,
+    auth: RequireSub,
+    mut multipart: Multipart,
+) -> Result<impl IntoResponse, ApiError> {
+    let sub_id = fetch_own_subcontractor_id(&state, auth.user_id).await?;
+
+    let mut file_data: Option<Vec<u8>> = None;
+    let mut file_name: Option<String> = None;
+    let mut content_type: Option<String> = None;
+    let mut document_type: Option<VerificationDocumentType> = None;
+    let mut expiry_date: Option<NaiveDate> = None;
+
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| ApiError::bad_request(format!("Failed to read multipart: {}", e)))?
+    {
+        match field.name().unwrap_or_default() {
+            "file" => {
+                file_name = field.file_name().map(|s| s.to_string());
+                content_type = field.content_type().map(|s| s.to_string());
+                file_data = Some(
+                    field
+                        .bytes()
+                        .await
+                        .map_err(|e| ApiError::bad_request(format!("Failed to read file: {}", e)))?
+                        .to_vec(),
+                );
+            }
+            "document_type" => {
+                let value = field
+                    .text()
+                    .await
+                    .map_err(|e| ApiError::bad_request(format!("Failed to read field: {}", e)))?;
+                document_type = Some(VerificationDocumentType::parse(value.trim()).ok_or_else(|| {
+                    ApiError::bad_request("document_type must be insurance, license or other")
+                })?);
+            }
+            "expiry_date" => {
+                let value = field
+                    .text()
+                    .await
+                    .map_err(|e| ApiError::bad_request(format!("Failed to read field: {}", e)))?;
+                if !value.trim().is_empty() {
+                    expiry_date = Some(
+                        NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d")
+                            .map_err(|_| ApiError::bad_request("expiry_date must be YYYY-MM-DD"))?,
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let file_data = file_data.ok_or_else(|| ApiError::bad_request("No file provided in upload"))?;
+    let file_name = file_name.ok_or_else(|| ApiError::bad_request("No filename provided in upload"))?;
+    let document_type = document_type.ok_or_else(|| ApiError::bad_request("document_type is required"))?;
+
+    let mime = content_type.unwrap_or_else(|| "application/octet-stream".to_string());
+    if !ALLOWED_MIME_TYPES.contains(&mime.as_str()) {
+        return Err(ApiError::bad_request("Verification documents must be PDF, JPEG, PNG or WebP"));
+    }
+    if file_data.len() > MAX_DOCUMENT_BYTES {
+        return Err(ApiError::bad_request(format!(
+            "Document exceeds the {} MB upload limit",
+            MAX_DOCUMENT_BYTES / (1024 * 1024)
+        )));
+    }
+
+    let upload_dir = format!("./uploads/verification/{}", sub_id);
+    fs::create_dir_all(&upload_dir)
+        .await
+        .map_err(|e| ApiError::internal(format!("Failed to create upload directory: {}", e)))?;
+
+    let safe_filename: String = file_name
+        .chars()
+        .filter(|c| c.is_alphanumeric() || matches!(c, '.' | '-' | '_'))
+        .collect();
+    let file_path = format!("{}/{}_{}", upload_dir, Uuid::new_v4(), safe_filename);
+    fs::write(&file_path, &file_data)
+        .await
+        .map_err(|e| ApiError::internal(format!("Failed to write file: {}", e)))?;
+
+    let row = sqlx::query_as::<_, VerificationDocumentRow>(
+        r#"
+        INSERT INTO verification_documents (
+            subcontractor_id, document_type, file_name, file_path, file_size, mime_type,
+            expiry_date, uploaded_by
+        ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        RETURNING id, subcontractor_id, document_type, file_name, file_size, mime_type,
+                  expiry_date, created_at
+        "#,
+    )
+    .bind(sub_id)
+    .bind(document_type.to_string())
+    .bind(&file_name)
+    .bind(&file_path)
+    .bind(file_data.len() as i64)
+    .bind(&mime)
+    .bind(expiry_date)
+    .bind(auth.user_id)
+    .fetch_one(&state.db)
+    .await;
+
+    let row = match row {
+        Ok(row) => row,
+        Err(e) => {
+            let _ = fs::remove_file(&file_path).await;
+            return Err(map_db_err("upload_verification_document")(e));
+        }
+    };
+
+    let mut response = to_response(&state, auth.user_id, row);
+    if document_type == VerificationDocumentType::Insurance && response.expired {
+        response.warning = Some("This insurance document has already expired".to_string());
+    }
+
+    Ok(Created(response))
+}
+
+/// GET /api/verification-documents/:document_id/view
+///
+/// Follow a signed view link. Authenticated by the link's signature; the
+/// link's user must still be the document's subcontractor or an admin.
+pub async fn view_verification_document(
+    State(state): State<Arc<AppState>>,
+    Path(document_id): Path<Uuid>,
+    Query(link): Query<SignedDownloadQuery>,
+    request: Request,
+) -> Result<Response, ApiError> {
+    state
+        .url_signer
+        .verify(&view_path(document_id), link.user, link.expires, &link.signature)?;
+
+    let document: Option<(String, String, Option<String>, bool)> = sqlx::query_as(
+        r#"
+        SELECT vd.file_name, vd.file_path, vd.mime_type,
+               (s.profile_id = $2 OR COALESCE(p.is_admin, false)) as allowed
+        FROM verification_documents vd
+        JOIN subcontractors s ON vd.subcontractor_id = s.id
+        LEFT JOIN profiles p ON p.id = $2
+        WHERE vd.id = $1
+        "#,
+    )
+    .bind(document_id)
+    .bind(link.user)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(map_db_err("view_verification_document"))?;
+
+    let (name, file_path, mime_type) = match document {
+        Some((name, file_path, mime_type, true)) => (name, file_path, mime_type),
+        _ => return Err(ApiError::not_found("Document not found")),
+    };
+
+    let mut response = ServeFile::new(&file_path)
+        .oneshot(request)
+        .await
+        .map_err(|e| ApiError::internal(format!("Failed to read document: {}", e)))?
+        .map(Body::new);
+
+    if response.status() == StatusCode::NOT_FOUND {
+        return Err(ApiError::not_found("Document file is missing"));
+    }
+
+    if let Some(mime) = mime_type.and_then(|m| HeaderValue::from_str(&m).ok()) {
+        response.headers_mut().insert(header::CONTENT_TYPE, mime);
+    }
+    let safe_name: String = name
+        .chars()
+        .filter(|c| c.is_alphanumeric() || matches!(c, '.' | '-' | '_' | ' '))
+        .collect();
+    if let Ok(disposition) = HeaderValue::from_str(&format!("inline; filename=\"{}\"", safe_name)) {
+        response.headers_mut().insert(header::CONTENT_DISPOSITION, disposition);
+    }
+
+    Ok(response)
+}
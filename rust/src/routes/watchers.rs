@@ -0,0 +1,186 @@
+//! Watch routes
+//!
+//! Follow an RFI or tender without owning it. Watchers are notified of new
+//! responses, answers, bids and status changes alongside the owner; see
+//! `services::watchers`.
+
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+    Json,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::api::response::DataResponse;
+use crate::app::AppState;
+use crate::auth::RequireAuth;
+use crate::domain::notifications::WatchStatus;
+use crate::error::{map_db_err, ApiError};
+use crate::routes::tender_documents::verify_tender_access;
+use crate::services::watchers::{self, WatchedResource};
+
+async fn verify_rfi_exists(state: &AppState, project_id: Uuid, rfi_id: Uuid) -> Result<(), ApiError> {
+    let exists: bool = sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM rfis WHERE id = $1 AND project_id = $2)")
+        .bind(rfi_id)
+        .bind(project_id)
+        .fetch_one(&state.db)
+        .await
+        .map_err(map_db_err("verify_rfi_exists"))?;
+
+    if !exists {
+        return Err(ApiError::not_found("RFI not found"));
+    }
+    Ok(())
+}
+
+/// Verify the user can follow the RFI: the project's owner, the RFI's
+/// requester or assignee, or a sub on the project team. Others get a 404 so
+/// RFI ids don't leak.
+async fn verify_rfi_access(state: &AppState, project_id: Uuid, rfi_id: Uuid, user_id: Uuid) -> Result<(), ApiError> {
+    let visible: bool = sqlx::query_scalar(
+        r#"
+        SELECT EXISTS(
+            SELECT 1 FROM rfis r
+            JOIN projects p ON p.id = r.project_id
+            WHERE r.id = $1 AND r.project_id = $2
+            AND (p.owner_id = $3 OR r.requester_id = $3 OR r.assignee_id = $3
+                 OR EXISTS (
+                     SELECT 1 FROM project_team pt
+                     JOIN subcontractors s ON s.id = pt.subcontractor_id
+                     WHERE pt.project_id = r.project_id AND s.profile_id = $3
+                 ))
+        )
+        "#,
+    )
+    .bind(rfi_id)
+    .bind(project_id)
+    .bind(user_id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(map_db_err("verify_rfi_access"))?;
+
+    if !visible {
+        return Err(ApiError::not_found("RFI not found"));
+    }
+    Ok(())
+}
+
+async fn set_watching(
+    state: &AppState,
+    resource: WatchedResource,
+    resource_id: Uuid,
+    user_id: Uuid,
+    watching: bool,
+) -> Result<WatchStatus, ApiError> {
+    let result = if watching {
+        watchers::watch(&state.db, resource, resource_id, user_id).await
+    } else {
+        watchers::unwatch(&state.db, resource, resource_id, user_id).await.map(|_| ())
+    };
+    result.map_err(map_db_err("set_watching"))?;
+
+    let count = watchers::watcher_count(&state.db, resource, resource_id)
+        .await
+        .map_err(map_db_err("set_watching"))?;
+
+    Ok(WatchStatus {
+        watching,
+        watchers: count,
+    })
+}
+
+/// POST /api/projects/:project_id/rfis/:rfi_id/watch
+///
+/// Follow an RFI's responses and status changes on a project the caller
+/// takes part in.
+pub async fn watch_rfi(
+    State(state): State<Arc<AppState>>,
+    Path((project_id, rfi_id)): Path<(Uuid, Uuid)>,
+    auth: RequireAuth,
+) -> Result<impl IntoResponse, ApiError> {
+    verify_rfi_access(&state, project_id, rfi_id, auth.user_id).await?;
+    let status = set_watching(&state, WatchedResource::Rfi, rfi_id, auth.user_id, true).await?;
+    Ok(Json(DataResponse::new(status)))
+}
+
+/// DELETE /api/projects/:project_id/rfis/:rfi_id/watch
+///
+/// Stop following an RFI. Works even if the caller has left the project.
+pub async fn unwatch_rfi(
+    State(state): State<Arc<AppState>>,
+    Path((project_id, rfi_id)): Path<(Uuid, Uuid)>,
+    auth: RequireAuth,
+) -> Result<impl IntoResponse, ApiError> {
+    verify_rfi_exists(&state, project_id, rfi_id).await?;
+    let status = set_watching(&state, WatchedResource::Rfi, rfi_id, auth.user_id, false).await?;
+    Ok(Json(DataResponse::new(status)))
+}
+
+/// POST /api/tenders/:tender_id/watch
+///
+/// Follow a tender the caller can see: new bids (without amounts or bidders),
+/// Q&A answers and status changes.
+pub async fn watch_tender(
+    State(state): State<Arc<AppState>>,
+    Path(tender_id): Path<Uuid>,
+    auth: RequireAuth,
+) -> Result<impl IntoResponse, ApiError> {
+    verify_tender_access(&state, tender_id, auth.user_id).await?;
+    let status = set_watching(&state, WatchedResource::Tender, tender_id, auth.user_id, true).await?;
+    Ok(Json(DataResponse::new(status)))
+}
+
+/// DELETE /api/tenders/:tender_id/watch
+///
+/// Stop following a tender. Works even if it is no longer visible.
+pub async fn unwatch_tender(
+    State(state): State<Arc<AppState>>,
+    Path(tender_id): Path<Uuid>,
+    auth: RequireAuth,
+) -> Result<impl IntoResponse, ApiError> {
+    let status = set_watching(&state, WatchedResource::Tender, tender_id, auth.user_id, false).await?;
+    Ok(Json(DataResponse::new(status)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::test_support;
+
+    async fn rfi(db: &sqlx::PgPool, project_id: Uuid) -> Uuid {
+        sqlx::query_scalar(
+            r#"
+            INSERT INTO rfis (project_id, title, description, requester, assignee)
+            VALUES ($1, 'Test RFI', 'Which conduit?', 'Requester', 'Assignee')
+            RETURNING id
+            "#,
+        )
+        .bind(project_id)
+        .fetch_one(db)
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn only_project_participants_can_watch_an_rfi() {
+        let Some(state) = test_support::state().await else { return };
+        let owner_id = test_support::user(&state.db, "gc").await;
+        let project_id = test_support::project(&state.db, owner_id).await;
+        let rfi_id = rfi(&state.db, project_id).await;
+        let outsider_id = test_support::user(&state.db, "sub").await;
+
+        let err = watch_rfi(State(state.clone()), Path((project_id, rfi_id)), test_support::auth(outsider_id))
+            .await
+            .err()
+            .expect("outsider should not watch");
+        assert!(matches!(&err, ApiError::NotFound(_)), "{:?}", err);
+        assert_eq!(watchers::watcher_count(&state.db, WatchedResource::Rfi, rfi_id).await.unwrap(), 0);
+
+        watch_rfi(State(state.clone()), Path((project_id, rfi_id)), test_support::auth(owner_id))
+            .await
+            .unwrap();
+        assert_eq!(watchers::watcher_count(&state.db, WatchedResource::Rfi, rfi_id).await.unwrap(), 1);
+    }
+}
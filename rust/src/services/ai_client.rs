@@ -10,14 +10,17 @@
 use anyhow::{Context, Result};
 use reqwest::{Client, StatusCode};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::future::Future;
 use std::time::Duration;
 use tracing::{debug, error, instrument};
 use uuid::Uuid;
 
+use crate::config::{AiModels, AiTimeouts, CircuitBreakerSettings};
 use crate::domain::ai::{
-    PlanSummary, QnAResponse, TenderScopeDoc, TradeScopesOutput,
+    AiOperation, AiTokenUsage, PlanSummary, QnAResponse, TenderScopeDoc, TradeScopesOutput,
 };
 use crate::error::ApiError;
+use crate::services::circuit_breaker::{CircuitBreaker, CircuitSnapshot};
 
 /// Client for the AI service.
 #[derive(Clone)]
@@ -25,6 +28,19 @@ pub struct AiClient {
     client: Client,
     base_url: String,
     token: String,
+    timeouts: AiTimeouts,
+    models: AiModels,
+    breaker: CircuitBreaker,
+}
+
+/// An AI service result and the model that produced it
+#[derive(Debug, Clone)]
+pub struct AiOutput<T> {
+    pub output: T,
+    /// Model reported by the AI service, else the one requested
+    pub model: Option<String>,
+    /// Token counts, when the AI service reports them
+    pub usage: AiTokenUsage,
 }
 
 /// Error response from AI service.
@@ -39,9 +55,20 @@ struct AiErrorResponse {
 
 impl AiClient {
     /// Create a new AI service client.
-    pub fn new(base_url: &str, token: &str, timeout_seconds: u64) -> Result<Self> {
+    ///
+    /// Each operation applies its own timeout from `timeouts` and asks for the
+    /// model configured in `models`; the client-wide timeout is the default for
+    /// anything without an override. Calls go through a circuit breaker
+    /// configured by `circuit`.
+    pub fn new(
+        base_url: &str,
+        token: &str,
+        timeouts: AiTimeouts,
+        models: AiModels,
+        circuit: CircuitBreakerSettings,
+    ) -> Result<Self> {
         let client = Client::builder()
-            .timeout(Duration::from_secs(timeout_seconds))
+            .timeout(timeouts.default)
             // Connection timeout (time to establish TCP connection)
             .connect_timeout(Duration::from_secs(5))
             // Optimize for local service communication
@@ -53,20 +80,59 @@ impl AiClient {
             .build()
             .context("Failed to create HTTP client")?;
 
-        tracing::info!(base_url = base_url, timeout_seconds = timeout_seconds, "AI client initialized");
+        tracing::info!(base_url = base_url, timeouts = ?timeouts, models = ?models, circuit = ?circuit, "AI client initialized");
 
         Ok(Self {
             client,
             base_url: base_url.trim_end_matches('/').to_string(),
             token: token.to_string(),
+            timeouts,
+            models,
+            breaker: CircuitBreaker::new("AI service", circuit),
         })
     }
 
-    /// Make a POST request to the AI service.
+    /// Model requested for `operation`, if one is configured
+    pub fn model(&self, operation: AiOperation) -> Option<&str> {
+        self.models.for_operation(operation)
+    }
+
+    /// Run a call through the circuit breaker. Connection errors, timeouts
+    /// and server errors count as failures; a 4xx answer means the service is
+    /// up.
+    async fn guarded<R>(&self, call: impl Future<Output = Result<R, ApiError>>) -> Result<R, ApiError> {
+        let permit = self.breaker.acquire()?;
+        let result = call.await;
+        match &result {
+            Err(ApiError::GatewayTimeout(_) | ApiError::ServiceUnavailable(_) | ApiError::Internal(_)) => {
+                permit.failure()
+            }
+            _ => permit.success(),
+        }
+        result
+    }
+
+    /// Circuit breaker state, for the health endpoint
+    pub fn circuit(&self) -> CircuitSnapshot {
+        self.breaker.snapshot()
+    }
+
+    /// Make a POST request to the AI service with the given timeout.
     async fn post<T: Serialize, R: DeserializeOwned>(
         &self,
         path: &str,
         body: &T,
+        timeout: Duration,
+        request_id: Option<&str>,
+    ) -> Result<R, ApiError> {
+        self.guarded(self.send_post(path, body, timeout, request_id)).await
+    }
+
+    async fn send_post<T: Serialize, R: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &T,
+        timeout: Duration,
         request_id: Option<&str>,
     ) -> Result<R, ApiError> {
         let url = format!("{}{}", self.base_url, path);
@@ -74,6 +140,7 @@ impl AiClient {
         let mut req = self
             .client
             .post(&url)
+            .timeout(timeout)
             .header("X-Internal-Token", &self.token)
             .header("Content-Type", "application/json");
 
@@ -88,14 +155,20 @@ impl AiClient {
             .send()
             .await
             .map_err(|e| {
+                if e.is_timeout() {
+                    return timeout_error(path, timeout);
+                }
                 error!(error = %e, "AI service request failed");
-                ApiError::Internal(anyhow::anyhow!("AI service unavailable: {}", e))
+                ApiError::service_unavailable("AI service is unavailable")
             })?;
 
         let status = response.status();
 
         if status.is_success() {
             response.json::<R>().await.map_err(|e| {
+                if e.is_timeout() {
+                    return timeout_error(path, timeout);
+                }
                 error!(error = %e, "Failed to parse AI service response");
                 ApiError::Internal(anyhow::anyhow!("Invalid AI service response: {}", e))
             })
@@ -153,20 +226,27 @@ impl AiClient {
         document_text: &str,
         instructions: Option<&str>,
         request_id: Option<&str>,
-    ) -> Result<PlanSummary, ApiError> {
+    ) -> Result<AiOutput<PlanSummary>, ApiError> {
         #[derive(Serialize)]
         struct Request<'a> {
             project_id: String,
             document_text: &'a str,
             #[serde(skip_serializing_if = "Option::is_none")]
             instructions: Option<&'a str>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            model: Option<&'a str>,
         }
 
         #[derive(Deserialize)]
         struct Response {
             summary: PlanSummary,
+            #[serde(default)]
+            model: Option<String>,
+            #[serde(default)]
+            usage: AiTokenUsage,
         }
 
+        let model = self.model(AiOperation::Summary);
         let response: Response = self
             .post(
                 "/v1/plan/summary",
@@ -174,12 +254,18 @@ impl AiClient {
                     project_id: project_id.to_string(),
                     document_text,
                     instructions,
+                    model,
                 },
+                self.timeouts.summary,
                 request_id,
             )
             .await?;
 
-        Ok(response.summary)
+        Ok(AiOutput {
+            output: response.summary,
+            model: response.model.or_else(|| model.map(str::to_string)),
+            usage: response.usage,
+        })
     }
 
     /// Extract trade scopes from document text.
@@ -190,20 +276,27 @@ impl AiClient {
         document_text: &str,
         trades: Option<Vec<String>>,
         request_id: Option<&str>,
-    ) -> Result<TradeScopesOutput, ApiError> {
+    ) -> Result<AiOutput<TradeScopesOutput>, ApiError> {
         #[derive(Serialize)]
         struct Request<'a> {
             project_id: String,
             document_text: &'a str,
             #[serde(skip_serializing_if = "Option::is_none")]
             trades: Option<Vec<String>>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            model: Option<&'a str>,
         }
 
         #[derive(Deserialize)]
         struct Response {
             scopes: TradeScopesOutput,
+            #[serde(default)]
+            model: Option<String>,
+            #[serde(default)]
+            usage: AiTokenUsage,
         }
 
+        let model = self.model(AiOperation::TradeScopes);
         let response: Response = self
             .post(
                 "/v1/plan/trade-scopes",
@@ -211,38 +304,48 @@ impl AiClient {
                     project_id: project_id.to_string(),
                     document_text,
                     trades,
+                    model,
                 },
+                self.timeouts.trade_scopes,
                 request_id,
             )
             .await?;
 
-        Ok(response.scopes)
+        Ok(AiOutput {
+            output: response.scopes,
+            model: response.model.or_else(|| model.map(str::to_string)),
+            usage: response.usage,
+        })
     }
 
     /// Get list of standard trades.
     pub async fn get_standard_trades(&self, request_id: Option<&str>) -> Result<Vec<String>, ApiError> {
-        let url = format!("{}/v1/plan/trades", self.base_url);
+        self.guarded(async {
+            let url = format!("{}/v1/plan/trades", self.base_url);
 
-        let mut req = self
-            .client
-            .get(&url)
-            .header("X-Internal-Token", &self.token);
+            let mut req = self
+                .client
+                .get(&url)
+                .header("X-Internal-Token", &self.token);
 
-        if let Some(rid) = request_id {
-            req = req.header("x-request-id", rid);
-        }
+            if let Some(rid) = request_id {
+                req = req.header("x-request-id", rid);
+            }
 
-        let response = req.send().await.map_err(|e| {
-            ApiError::Internal(anyhow::anyhow!("AI service unavailable: {}", e))
-        })?;
+            let response = req.send().await.map_err(|e| {
+                error!(error = %e, "AI service request failed");
+                ApiError::service_unavailable("AI service is unavailable")
+            })?;
 
-        if response.status().is_success() {
-            response.json().await.map_err(|e| {
-                ApiError::Internal(anyhow::anyhow!("Invalid response: {}", e))
-            })
-        } else {
-            Err(ApiError::Internal(anyhow::anyhow!("Failed to get trades")))
-        }
+            if response.status().is_success() {
+                response.json().await.map_err(|e| {
+                    ApiError::Internal(anyhow::anyhow!("Invalid response: {}", e))
+                })
+            } else {
+                Err(ApiError::Internal(anyhow::anyhow!("Failed to get trades")))
+            }
+        })
+        .await
     }
 
     // =========================================================================
@@ -259,7 +362,7 @@ impl AiClient {
         project_context: Option<&str>,
         bid_due_date: Option<&str>,
         request_id: Option<&str>,
-    ) -> Result<TenderScopeDoc, ApiError> {
+    ) -> Result<AiOutput<TenderScopeDoc>, ApiError> {
         #[derive(Serialize)]
         struct Request<'a> {
             project_id: String,
@@ -269,13 +372,20 @@ impl AiClient {
             project_context: Option<&'a str>,
             #[serde(skip_serializing_if = "Option::is_none")]
             bid_due_date: Option<&'a str>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            model: Option<&'a str>,
         }
 
         #[derive(Deserialize)]
         struct Response {
             document: TenderScopeDoc,
+            #[serde(default)]
+            model: Option<String>,
+            #[serde(default)]
+            usage: AiTokenUsage,
         }
 
+        let model = self.model(AiOperation::TenderScopeDoc);
         let response: Response = self
             .post(
                 "/v1/tenders/scope-doc",
@@ -285,12 +395,18 @@ impl AiClient {
                     scope_data,
                     project_context,
                     bid_due_date,
+                    model,
                 },
+                self.timeouts.tender_scope_doc,
                 request_id,
             )
             .await?;
 
-        Ok(response.document)
+        Ok(AiOutput {
+            output: response.document,
+            model: response.model.or_else(|| model.map(str::to_string)),
+            usage: response.usage,
+        })
     }
 
     // =========================================================================
@@ -306,7 +422,7 @@ impl AiClient {
         document_id: Option<Uuid>,
         document_text: Option<&str>,
         request_id: Option<&str>,
-    ) -> Result<QnAResponse, ApiError> {
+    ) -> Result<AiOutput<QnAResponse>, ApiError> {
         #[derive(Serialize)]
         struct Request<'a> {
             project_id: String,
@@ -315,9 +431,20 @@ impl AiClient {
             document_id: Option<String>,
             #[serde(skip_serializing_if = "Option::is_none")]
             document_text: Option<&'a str>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            model: Option<&'a str>,
+        }
+
+        #[derive(Deserialize)]
+        struct Response {
+            #[serde(flatten)]
+            answer: QnAResponse,
+            #[serde(default)]
+            usage: AiTokenUsage,
         }
 
-        let response: QnAResponse = self
+        let model = self.model(AiOperation::Qna);
+        let mut response: Response = self
             .post(
                 "/v1/qna",
                 &Request {
@@ -325,12 +452,21 @@ impl AiClient {
                     question,
                     document_id: document_id.map(|id| id.to_string()),
                     document_text,
+                    model,
                 },
+                self.timeouts.qna,
                 request_id,
             )
             .await?;
 
-        Ok(response)
+        if response.answer.model.is_none() {
+            response.answer.model = model.map(str::to_string);
+        }
+        Ok(AiOutput {
+            model: response.answer.model.clone(),
+            output: response.answer,
+            usage: response.usage,
+        })
     }
 
     // =========================================================================
@@ -369,6 +505,7 @@ impl AiClient {
                     project_id: project_id.to_string(),
                     document_id: document_id.to_string(),
                 },
+                self.timeouts.default,
                 request_id,
             )
             .await?;
@@ -376,33 +513,95 @@ impl AiClient {
         Ok(response)
     }
 
+    /// Create and start an async trade scope extraction job.
+    ///
+    /// The AI service reports per-step progress and the final scopes to `callback_url`.
+    #[instrument(skip(self, document_text))]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn start_trade_scope_job(
+        &self,
+        job_id: Uuid,
+        project_id: Uuid,
+        document_id: Uuid,
+        document_text: Option<&str>,
+        trades: Option<&[String]>,
+        callback_url: &str,
+        request_id: Option<&str>,
+    ) -> Result<JobResponse, ApiError> {
+        #[derive(Serialize)]
+        struct Request<'a> {
+            r#type: &'static str,
+            input: JobInput<'a>,
+            project_id: String,
+            document_id: String,
+        }
+
+        #[derive(Serialize)]
+        struct JobInput<'a> {
+            job_id: String,
+            callback_url: &'a str,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            document_text: Option<&'a str>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            trades: Option<&'a [String]>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            model: Option<&'a str>,
+        }
+
+        let job: JobResponse = self
+            .post(
+                "/v1/jobs",
+                &Request {
+                    r#type: "trade_scope_extraction",
+                    input: JobInput {
+                        job_id: job_id.to_string(),
+                        callback_url,
+                        document_text,
+                        trades,
+                        model: self.model(AiOperation::TradeScopes),
+                    },
+                    project_id: project_id.to_string(),
+                    document_id: document_id.to_string(),
+                },
+                self.timeouts.default,
+                request_id,
+            )
+            .await?;
+
+        self.run_job_async(&job.job_id, request_id).await
+    }
+
     /// Get job status.
     #[allow(dead_code)]
     pub async fn get_job(&self, job_id: &str, request_id: Option<&str>) -> Result<JobResponse, ApiError> {
-        let url = format!("{}/v1/jobs/{}", self.base_url, job_id);
+        self.guarded(async {
+            let url = format!("{}/v1/jobs/{}", self.base_url, job_id);
 
-        let mut req = self
-            .client
-            .get(&url)
-            .header("X-Internal-Token", &self.token);
+            let mut req = self
+                .client
+                .get(&url)
+                .header("X-Internal-Token", &self.token);
 
-        if let Some(rid) = request_id {
-            req = req.header("x-request-id", rid);
-        }
+            if let Some(rid) = request_id {
+                req = req.header("x-request-id", rid);
+            }
 
-        let response = req.send().await.map_err(|e| {
-            ApiError::Internal(anyhow::anyhow!("AI service unavailable: {}", e))
-        })?;
+            let response = req.send().await.map_err(|e| {
+                error!(error = %e, "AI service request failed");
+                ApiError::service_unavailable("AI service is unavailable")
+            })?;
 
-        if response.status().is_success() {
-            response.json().await.map_err(|e| {
-                ApiError::Internal(anyhow::anyhow!("Invalid response: {}", e))
-            })
-        } else if response.status() == StatusCode::NOT_FOUND {
-            Err(ApiError::NotFound("Job not found".to_string()))
-        } else {
-            Err(ApiError::Internal(anyhow::anyhow!("Failed to get job")))
-        }
+            if response.status().is_success() {
+                response.json().await.map_err(|e| {
+                    ApiError::Internal(anyhow::anyhow!("Invalid response: {}", e))
+                })
+            } else if response.status() == StatusCode::NOT_FOUND {
+                Err(ApiError::NotFound("Job not found".to_string()))
+            } else {
+                Err(ApiError::Internal(anyhow::anyhow!("Failed to get job")))
+            }
+        })
+        .await
     }
 
     /// Run a job (synchronous).
@@ -411,19 +610,39 @@ impl AiClient {
         #[derive(Serialize)]
         struct Empty {}
 
-        self.post(&format!("/v1/jobs/{}/run", job_id), &Empty {}, request_id).await
+        self.post(
+            &format!("/v1/jobs/{}/run", job_id),
+            &Empty {},
+            self.timeouts.default,
+            request_id,
+        )
+        .await
     }
 
     /// Run a job (asynchronous).
-    #[allow(dead_code)]
     pub async fn run_job_async(&self, job_id: &str, request_id: Option<&str>) -> Result<JobResponse, ApiError> {
         #[derive(Serialize)]
         struct Empty {}
 
-        self.post(&format!("/v1/jobs/{}/run-async", job_id), &Empty {}, request_id).await
+        self.post(
+            &format!("/v1/jobs/{}/run-async", job_id),
+            &Empty {},
+            self.timeouts.default,
+            request_id,
+        )
+        .await
     }
 }
 
+/// Map a timed-out AI call to a 504 so clients can tell it apart from a failure.
+fn timeout_error(path: &str, timeout: Duration) -> ApiError {
+    error!(path = path, timeout_seconds = timeout.as_secs(), "AI service request timed out");
+    ApiError::gateway_timeout(format!(
+        "The AI service did not respond within {} seconds. Please try again later.",
+        timeout.as_secs()
+    ))
+}
+
 /// Job response from AI service.
 #[allow(dead_code)]
 #[derive(Debug, Clone, Serialize, Deserialize)]
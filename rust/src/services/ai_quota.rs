@@ -0,0 +1,175 @@
+//! AI call quotas
+//!
+//! Each AI call is checked before it is dispatched against the caller's
+//! daily allowance (a Redis counter that resets at UTC midnight) and the
+//! project's lifetime allowance (successful calls in `ai_usage`). Admins
+//! have their own daily limit and skip project limits; a limit of 0 is
+//! unlimited. If Redis is unreachable the daily limit fails open rather than
+//! taking AI features down with it.
+
+use axum::http::{HeaderMap, HeaderName, HeaderValue};
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use sqlx::PgPool;
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::config::AiQuotaLimits;
+use crate::domain::ai::AiQuotaStatus;
+use crate::error::{map_db_err, ApiError};
+use crate::services::cache::{keys, RedisCache};
+
+/// Response header carrying the calls left today
+pub const REMAINING_HEADER: HeaderName = HeaderName::from_static("x-ai-quota-remaining");
+
+/// One AI call counted against the caller's daily quota
+#[derive(Debug)]
+pub struct AiQuotaReservation {
+    /// Counter holding the reservation; `None` when nothing was counted
+    key: Option<String>,
+    remaining: Option<u32>,
+}
+
+impl AiQuotaReservation {
+    fn unlimited() -> Self {
+        Self {
+            key: None,
+            remaining: None,
+        }
+    }
+
+    /// `x-ai-quota-remaining` for the response, when the caller has a limit
+    pub fn headers(&self) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        if let Some(remaining) = self.remaining {
+            headers.insert(REMAINING_HEADER, HeaderValue::from(remaining));
+        }
+        headers
+    }
+
+    /// Pass the AI call's result through, handing the reservation back if
+    /// the call failed so errors do not use up the quota
+    pub async fn settle<T>(&mut self, cache: &RedisCache, result: Result<T, ApiError>) -> Result<T, ApiError> {
+        if result.is_err() {
+            if let Some(key) = self.key.take() {
+                let _ = cache.decr(&key).await;
+                self.remaining = self.remaining.map(|r| r + 1);
+            }
+        }
+        result
+    }
+}
+
+/// Next UTC midnight
+fn next_reset(now: DateTime<Utc>) -> DateTime<Utc> {
+    (now.date_naive() + ChronoDuration::days(1))
+        .and_hms_opt(0, 0, 0)
+        .map(|midnight| midnight.and_utc())
+        .unwrap_or(now)
+}
+
+fn daily_key(user_id: Uuid, now: DateTime<Utc>) -> String {
+    keys::ai_quota_user_daily(user_id, &now.format("%Y%m%d").to_string())
+}
+
+async fn is_admin(db: &PgPool, user_id: Uuid) -> Result<bool, ApiError> {
+    let is_admin: Option<Option<bool>> = sqlx::query_scalar("SELECT is_admin FROM profiles WHERE id = $1")
+        .bind(user_id)
+        .fetch_optional(db)
+        .await
+        .map_err(map_db_err("is_admin"))?;
+    Ok(is_admin.flatten().unwrap_or(false))
+}
+
+fn daily_limit(limits: &AiQuotaLimits, is_admin: bool) -> u32 {
+    if is_admin {
+        limits.admin_daily
+    } else {
+        limits.user_daily
+    }
+}
+
+/// Count one AI call for `user_id` on `project_id`, or fail with a 429 when
+/// a quota is used up
+pub async fn reserve(
+    db: &PgPool,
+    cache: &RedisCache,
+    limits: &AiQuotaLimits,
+    user_id: Uuid,
+    project_id: Option<Uuid>,
+) -> Result<AiQuotaReservation, ApiError> {
+    let admin = is_admin(db, user_id).await?;
+
+    if let (false, Some(project_id), limit @ 1..) = (admin, project_id, limits.project_total) {
+        let used: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM ai_usage WHERE project_id = $1 AND success")
+                .bind(project_id)
+                .fetch_one(db)
+                .await
+                .map_err(map_db_err("reserve"))?;
+        if used >= limit as i64 {
+            return Err(ApiError::too_many_requests(
+                format!("This project has used its allowance of {} AI requests", limit),
+                None,
+            ));
+        }
+    }
+
+    let limit = daily_limit(limits, admin);
+    if limit == 0 {
+        return Ok(AiQuotaReservation::unlimited());
+    }
+
+    let now = Utc::now();
+    let reset = next_reset(now);
+    let until_reset = (reset - now).num_seconds().max(1) as u64;
+    let key = daily_key(user_id, now);
+
+    // The counter outlives its day by a minute so a late decrement cannot
+    // recreate it without an expiry
+    match cache.incr(&key, Duration::from_secs(until_reset + 60)).await {
+        Ok(count) if count > limit as i64 => {
+            let _ = cache.decr(&key).await;
+            Err(ApiError::too_many_requests(
+                format!(
+                    "Daily limit of {} AI requests reached; it resets at {} UTC",
+                    limit,
+                    reset.format("%Y-%m-%d %H:%M")
+                ),
+                Some(until_reset),
+            ))
+        }
+        Ok(count) => Ok(AiQuotaReservation {
+            key: Some(key),
+            remaining: Some(limit.saturating_sub(count as u32)),
+        }),
+        Err(e) => {
+            tracing::warn!(error = %e, "AI quota counter unavailable; allowing request");
+            Ok(AiQuotaReservation::unlimited())
+        }
+    }
+}
+
+/// The caller's AI allowance for today
+pub async fn status(
+    db: &PgPool,
+    cache: &RedisCache,
+    limits: &AiQuotaLimits,
+    user_id: Uuid,
+) -> Result<AiQuotaStatus, ApiError> {
+    let admin = is_admin(db, user_id).await?;
+    let now = Utc::now();
+    let used_today = cache
+        .get::<i64>(&daily_key(user_id, now))
+        .await
+        .unwrap_or(0)
+        .max(0) as u32;
+    let limit = Some(daily_limit(limits, admin)).filter(|l| *l > 0);
+
+    Ok(AiQuotaStatus {
+        daily_limit: limit,
+        used_today,
+        remaining_today: limit.map(|l| l.saturating_sub(used_today)),
+        resets_at: next_reset(now),
+        is_admin: admin,
+    })
+}
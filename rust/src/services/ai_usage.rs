@@ -0,0 +1,160 @@
+//! AI usage accounting
+//!
+//! Every AI service call made on a user's behalf is recorded in `ai_usage`
+//! with its duration, outcome and whatever token counts the AI service
+//! reports. Recording is best-effort: a failed insert is logged and never
+//! fails the request that made the call. Cache hits are not AI calls and are
+//! not recorded.
+
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use std::time::Instant;
+use uuid::Uuid;
+
+use crate::domain::ai::{AiOperation, AiTokenUsage, AiUsageBreakdown, AiUsageTotals};
+use crate::error::{map_db_err, ApiError};
+use crate::services::ai_client::AiOutput;
+
+/// Aggregate columns selected into `AiUsageTotals`
+const TOTALS_COLUMNS: &str = r#"
+    COUNT(*) as calls,
+    COUNT(*) FILTER (WHERE NOT success) as failed_calls,
+    COALESCE(SUM(prompt_tokens), 0)::bigint as prompt_tokens,
+    COALESCE(SUM(completion_tokens), 0)::bigint as completion_tokens,
+    COALESCE(SUM(total_tokens), 0)::bigint as total_tokens,
+    COALESCE(SUM(duration_ms), 0)::bigint as duration_ms"#;
+
+/// Filter shared by the usage reports: optional project and date range
+const USAGE_FILTER: &str = r#"
+    ($1::uuid IS NULL OR project_id = $1)
+    AND ($2::timestamptz IS NULL OR created_at >= $2)
+    AND ($3::timestamptz IS NULL OR created_at < $3)"#;
+
+/// What a usage report can be grouped by
+#[derive(Debug, Clone, Copy)]
+pub enum UsageGrouping {
+    Operation,
+    Model,
+    User,
+    Project,
+}
+
+impl UsageGrouping {
+    fn column(self) -> &'static str {
+        match self {
+            Self::Operation => "operation",
+            Self::Model => "model",
+            Self::User => "user_id::text",
+            Self::Project => "project_id::text",
+        }
+    }
+}
+
+/// An AI call in flight, timed from `start` until `finish`
+pub struct AiCall {
+    user_id: Uuid,
+    project_id: Option<Uuid>,
+    operation: AiOperation,
+    started: Instant,
+}
+
+impl AiCall {
+    pub fn start(user_id: Uuid, project_id: Option<Uuid>, operation: AiOperation) -> Self {
+        Self {
+            user_id,
+            project_id,
+            operation,
+            started: Instant::now(),
+        }
+    }
+
+    /// Record the call's outcome and hand its result back unchanged
+    pub async fn finish<T>(
+        self,
+        db: &PgPool,
+        result: Result<AiOutput<T>, ApiError>,
+    ) -> Result<AiOutput<T>, ApiError> {
+        let duration_ms = self.started.elapsed().as_millis().min(i32::MAX as u128) as i32;
+        let (model, usage, error) = match &result {
+            Ok(output) => (output.model.as_deref(), output.usage, None),
+            Err(e) => (None, AiTokenUsage::default(), Some(e.to_string())),
+        };
+
+        let insert = sqlx::query(
+            r#"
+            INSERT INTO ai_usage (
+                user_id, project_id, operation, model, prompt_tokens, completion_tokens,
+                total_tokens, duration_ms, success, error_message
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            "#,
+        )
+        .bind(self.user_id)
+        .bind(self.project_id)
+        .bind(self.operation.as_str())
+        .bind(model)
+        .bind(usage.prompt_tokens)
+        .bind(usage.completion_tokens)
+        .bind(usage.total_tokens)
+        .bind(duration_ms)
+        .bind(error.is_none())
+        .bind(&error)
+        .execute(db)
+        .await;
+
+        if let Err(e) = insert {
+            tracing::warn!(error = %e, operation = self.operation.as_str(), "Failed to record AI usage");
+        }
+
+        result
+    }
+}
+
+/// Usage totals, optionally for one project, within `[since, until)`
+pub async fn totals(
+    db: &PgPool,
+    project_id: Option<Uuid>,
+    since: Option<DateTime<Utc>>,
+    until: Option<DateTime<Utc>>,
+) -> Result<AiUsageTotals, ApiError> {
+    sqlx::query_as::<_, AiUsageTotals>(&format!(
+        "SELECT {} FROM ai_usage WHERE {}",
+        TOTALS_COLUMNS, USAGE_FILTER
+    ))
+    .bind(project_id)
+    .bind(since)
+    .bind(until)
+    .fetch_one(db)
+    .await
+    .map_err(map_db_err("totals"))
+}
+
+/// Usage grouped by `grouping`, heaviest first, at most `limit` groups
+pub async fn breakdown(
+    db: &PgPool,
+    grouping: UsageGrouping,
+    project_id: Option<Uuid>,
+    since: Option<DateTime<Utc>>,
+    until: Option<DateTime<Utc>>,
+    limit: i64,
+) -> Result<Vec<AiUsageBreakdown>, ApiError> {
+    sqlx::query_as::<_, AiUsageBreakdown>(&format!(
+        r#"
+        SELECT {col} as key, {totals}
+        FROM ai_usage
+        WHERE {filter}
+        GROUP BY {col}
+        ORDER BY total_tokens DESC, calls DESC, key
+        LIMIT $4
+        "#,
+        col = grouping.column(),
+        totals = TOTALS_COLUMNS,
+        filter = USAGE_FILTER,
+    ))
+    .bind(project_id)
+    .bind(since)
+    .bind(until)
+    .bind(limit)
+    .fetch_all(db)
+    .await
+    .map_err(map_db_err("breakdown"))
+}
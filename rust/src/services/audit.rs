@@ -0,0 +1,54 @@
+//! Activity audit logging
+//!
+//! Shared writer for the `activity_log` table. Used by non-admin routes to record
+//! sensitive actions; admin actions continue to go to `admin_audit_log`.
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::activity::ActivityAction;
+use crate::domain::admin::AuditTargetType;
+
+/// A single activity log entry to be recorded
+#[derive(Debug, Clone)]
+pub struct ActivityEntry {
+    pub actor_id: Uuid,
+    pub project_id: Option<Uuid>,
+    pub action: ActivityAction,
+    pub target_type: AuditTargetType,
+    pub target_id: Option<Uuid>,
+    pub details: serde_json::Value,
+    pub ip_address: Option<String>,
+}
+
+/// Record a sensitive user action in the activity log
+pub async fn log_activity(db: &PgPool, entry: ActivityEntry) -> Result<Uuid, sqlx::Error> {
+    let id = Uuid::new_v4();
+
+    sqlx::query(
+        r#"
+        INSERT INTO activity_log (id, actor_id, project_id, action, target_type, target_id, details, ip_address)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        "#,
+    )
+    .bind(id)
+    .bind(entry.actor_id)
+    .bind(entry.project_id)
+    .bind(entry.action.to_string())
+    .bind(entry.target_type.to_string())
+    .bind(entry.target_id)
+    .bind(&entry.details)
+    .bind(&entry.ip_address)
+    .execute(db)
+    .await?;
+
+    tracing::info!(
+        actor_id = %entry.actor_id,
+        action = %entry.action,
+        target_type = %entry.target_type,
+        target_id = ?entry.target_id,
+        "Activity logged"
+    );
+
+    Ok(id)
+}
@@ -0,0 +1,380 @@
+//! Refresh token rotation and revocation
+//!
+//! Every sign-in starts a session: a refresh token family tracked in
+//! `auth_sessions` by the hash of its current token. Each refresh rotates the
+//! family onto the newly issued token and puts the old one on a revocation
+//! list in Redis. Presenting a rotated-out token again means two parties hold
+//! the family (the token leaked), so the whole family is revoked, and its
+//! Supabase session is ended so the leaked token fails there too. Signing out
+//! or revoking a session puts its current token on the list as well.
+//!
+//! Refresh tokens issued before sessions were tracked are adopted into a new
+//! family the first time they are used.
+//!
+//! Sessions remember the user agent and IP they were last used from, so
+//! users can recognise and sign out their other devices.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use sqlx::PgPool;
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::error::{map_db_err, ApiError};
+use crate::services::cache::{keys, RedisCache};
+use crate::services::SupabaseAuth;
+
+/// Why a session was revoked (`auth_sessions.revoked_reason`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RevokeReason {
+    SignOut,
+    User,
+    ReuseDetected,
+}
+
+impl RevokeReason {
+    fn as_str(self) -> &'static str {
+        match self {
+            RevokeReason::SignOut => "sign_out",
+            RevokeReason::User => "user",
+            RevokeReason::ReuseDetected => "reuse_detected",
+        }
+    }
+}
+
+/// Revocation list entry
+#[derive(Debug, Serialize, Deserialize)]
+struct RevokedToken {
+    session_id: Uuid,
+    /// Rotated out by a refresh, rather than revoked with its session
+    rotated: bool,
+}
+
+/// Where a session is being used from
+#[derive(Debug, Clone, Default)]
+pub struct ClientInfo {
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+/// SHA-256 (hex) of a refresh token; tokens themselves are never stored
+pub fn hash_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
+
+fn revoked() -> ApiError {
+    ApiError::unauthorized("Refresh token has been revoked")
+}
+
+async fn add_to_revocation_list(cache: &RedisCache, ttl: Duration, token_hash: &str, entry: &RevokedToken) {
+    if let Err(e) = cache
+        .set_with_ttl(&keys::revoked_refresh_token(token_hash), entry, ttl)
+        .await
+    {
+        tracing::warn!(error = %e, session_id = %entry.session_id, "Failed to add refresh token to revocation list");
+    }
+}
+
+/// Revoke a family whose refresh token was reused and end its Supabase
+/// session, falling back to `auth_session_id` when none was recorded
+async fn revoke_reused(
+    db: &PgPool,
+    cache: &RedisCache,
+    upstream: &SupabaseAuth,
+    ttl: Duration,
+    session_id: Uuid,
+    auth_session_id: Option<Uuid>,
+) -> Result<(), ApiError> {
+    if !revoke(db, cache, ttl, session_id, RevokeReason::ReuseDetected).await? {
+        return Ok(());
+    }
+
+    let recorded: Option<Uuid> = sqlx::query_scalar("SELECT auth_session_id FROM auth_sessions WHERE id = $1")
+        .bind(session_id)
+        .fetch_optional(db)
+        .await
+        .map_err(map_db_err("revoke_reused"))?
+        .flatten();
+
+    if let Some(auth_session_id) = recorded.or(auth_session_id) {
+        if upstream.end_session(auth_session_id).await.is_err() {
+            tracing::warn!(session_id = %session_id, "Reused session could not be ended at Supabase");
+        }
+    }
+    Ok(())
+}
+
+/// Start a session for a freshly issued refresh token
+pub async fn start(
+    db: &PgPool,
+    user_id: Uuid,
+    refresh_token: &str,
+    auth_session_id: Option<Uuid>,
+    client: &ClientInfo,
+) -> Result<Uuid, ApiError> {
+    sqlx::query_scalar(
+        r#"
+        INSERT INTO auth_sessions (user_id, refresh_token_hash, auth_session_id, user_agent, ip_address)
+        VALUES ($1, $2, $3, $4, $5)
+        ON CONFLICT (refresh_token_hash) DO UPDATE SET auth_session_id = COALESCE(EXCLUDED.auth_session_id, auth_sessions.auth_session_id)
+        RETURNING id
+        "#,
+    )
+    .bind(user_id)
+    .bind(hash_token(refresh_token))
+    .bind(auth_session_id)
+    .bind(&client.user_agent)
+    .bind(&client.ip_address)
+    .fetch_one(db)
+    .await
+    .map_err(map_db_err("start"))
+}
+
+/// Check a refresh token before it is exchanged. Returns its session, or
+/// `None` for a token issued before sessions were tracked. A revoked token
+/// fails with 401, and a rotated-out one also revokes its family.
+pub async fn check_refresh(
+    db: &PgPool,
+    cache: &RedisCache,
+    upstream: &SupabaseAuth,
+    ttl: Duration,
+    refresh_token: &str,
+) -> Result<Option<Uuid>, ApiError> {
+    let token_hash = hash_token(refresh_token);
+
+    if let Some(entry) = cache
+        .get::<RevokedToken>(&keys::revoked_refresh_token(&token_hash))
+        .await
+    {
+        if entry.rotated {
+            tracing::warn!(session_id = %entry.session_id, "Rotated-out refresh token reused; revoking session");
+            revoke_reused(db, cache, upstream, ttl, entry.session_id, None).await?;
+        }
+        return Err(revoked());
+    }
+
+    // The current token of a revoked family stays in the table, so this also
+    // catches revocations the list has lost
+    let session: Option<(Uuid, bool)> = sqlx::query_as(
+        "SELECT id, revoked_at IS NOT NULL FROM auth_sessions WHERE refresh_token_hash = $1",
+    )
+    .bind(&token_hash)
+    .fetch_optional(db)
+    .await
+    .map_err(map_db_err("check_refresh"))?;
+
+    match session {
+        Some((_, true)) => Err(revoked()),
+        Some((id, false)) => Ok(Some(id)),
+        None => Ok(None),
+    }
+}
+
+/// Move a session onto the refresh token just issued for `old_token`, or
+/// start one for an untracked token. If the family moved on meanwhile, the
+/// old token was used twice: the family and the new token are revoked.
+#[allow(clippy::too_many_arguments)]
+pub async fn rotate(
+    db: &PgPool,
+    cache: &RedisCache,
+    upstream: &SupabaseAuth,
+    ttl: Duration,
+    session_id: Option<Uuid>,
+    user_id: Uuid,
+    old_token: &str,
+    new_token: &str,
+    auth_session_id: Option<Uuid>,
+    client: &ClientInfo,
+) -> Result<(), ApiError> {
+    let Some(session_id) = session_id else {
+        start(db, user_id, new_token, auth_session_id, client).await?;
+        return Ok(());
+    };
+
+    let old_hash = hash_token(old_token);
+    let new_hash = hash_token(new_token);
+
+    let result = sqlx::query(
+        r#"
+        UPDATE auth_sessions
+        SET refresh_token_hash = $3,
+            auth_session_id = COALESCE($4, auth_session_id),
+            user_agent = COALESCE($5, user_agent),
+            ip_address = COALESCE($6, ip_address),
+            last_refreshed_at = NOW()
+        WHERE id = $1 AND refresh_token_hash = $2 AND revoked_at IS NULL
+        "#,
+    )
+    .bind(session_id)
+    .bind(&old_hash)
+    .bind(&new_hash)
+    .bind(auth_session_id)
+    .bind(&client.user_agent)
+    .bind(&client.ip_address)
+    .execute(db)
+    .await
+    .map_err(map_db_err("rotate"))?;
+
+    if result.rows_affected() == 0 {
+        tracing::warn!(session_id = %session_id, "Refresh token used concurrently; revoking session");
+        revoke_reused(db, cache, upstream, ttl, session_id, auth_session_id).await?;
+        add_to_revocation_list(cache, ttl, &new_hash, &RevokedToken { session_id, rotated: false }).await;
+        return Err(revoked());
+    }
+
+    add_to_revocation_list(cache, ttl, &old_hash, &RevokedToken { session_id, rotated: true }).await;
+    Ok(())
+}
+
+/// Revoke a session so its refresh token can no longer be used. Returns
+/// whether it was active.
+pub async fn revoke(
+    db: &PgPool,
+    cache: &RedisCache,
+    ttl: Duration,
+    session_id: Uuid,
+    reason: RevokeReason,
+) -> Result<bool, ApiError> {
+    let token_hash: Option<String> = sqlx::query_scalar(
+        r#"
+        UPDATE auth_sessions SET revoked_at = NOW(), revoked_reason = $2
+        WHERE id = $1 AND revoked_at IS NULL
+        RETURNING refresh_token_hash
+        "#,
+    )
+    .bind(session_id)
+    .bind(reason.as_str())
+    .fetch_optional(db)
+    .await
+    .map_err(map_db_err("revoke"))?;
+
+    let Some(token_hash) = token_hash else {
+        return Ok(false);
+    };
+
+    add_to_revocation_list(cache, ttl, &token_hash, &RevokedToken { session_id, rotated: false }).await;
+    tracing::info!(session_id = %session_id, reason = reason.as_str(), "Session revoked");
+    Ok(true)
+}
+
+/// Revoke all of a user's sessions except `keep`. Returns how many were
+/// revoked.
+pub async fn revoke_all(
+    db: &PgPool,
+    cache: &RedisCache,
+    ttl: Duration,
+    user_id: Uuid,
+    keep: Option<Uuid>,
+    reason: RevokeReason,
+) -> Result<u64, ApiError> {
+    let revoked: Vec<(Uuid, String)> = sqlx::query_as(
+        r#"
+        UPDATE auth_sessions SET revoked_at = NOW(), revoked_reason = $3
+        WHERE user_id = $1 AND revoked_at IS NULL AND ($2::uuid IS NULL OR id <> $2)
+        RETURNING id, refresh_token_hash
+        "#,
+    )
+    .bind(user_id)
+    .bind(keep)
+    .bind(reason.as_str())
+    .fetch_all(db)
+    .await
+    .map_err(map_db_err("revoke_all"))?;
+
+    for (session_id, token_hash) in &revoked {
+        add_to_revocation_list(cache, ttl, token_hash, &RevokedToken { session_id: *session_id, rotated: false }).await;
+    }
+
+    tracing::info!(user_id = %user_id, revoked = revoked.len(), reason = reason.as_str(), "Sessions revoked");
+    Ok(revoked.len() as u64)
+}
+
+/// The user's active session matching an access token's `session_id` claim
+/// or, failing that, a refresh token
+pub async fn find_active(
+    db: &PgPool,
+    user_id: Uuid,
+    auth_session_id: Option<Uuid>,
+    refresh_token: Option<&str>,
+) -> Result<Option<Uuid>, ApiError> {
+    if auth_session_id.is_none() && refresh_token.is_none() {
+        return Ok(None);
+    }
+
+    sqlx::query_scalar(
+        r#"
+        SELECT id FROM auth_sessions
+        WHERE user_id = $1 AND revoked_at IS NULL
+        AND (auth_session_id = $2 OR refresh_token_hash = $3)
+        ORDER BY created_at DESC, id
+        LIMIT 1
+        "#,
+    )
+    .bind(user_id)
+    .bind(auth_session_id)
+    .bind(refresh_token.map(hash_token))
+    .fetch_optional(db)
+    .await
+    .map_err(map_db_err("find_active"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{extract::Path, http::StatusCode, routing::delete, Router};
+    use std::sync::{Arc, Mutex};
+
+    use crate::test_support;
+
+    /// Supabase stand-in recording the sessions ended through it
+    async fn recording_auth_service() -> (SupabaseAuth, Arc<Mutex<Vec<Uuid>>>) {
+        let ended = Arc::new(Mutex::new(Vec::new()));
+        let recorder = ended.clone();
+        let router = Router::new().route(
+            "/auth/v1/admin/sessions/:id",
+            delete(move |Path(id): Path<Uuid>| async move {
+                recorder.lock().unwrap().push(id);
+                StatusCode::NO_CONTENT
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+
+        let mut settings = test_support::settings();
+        settings.supabase_url = format!("http://{}", addr);
+        (SupabaseAuth::new(reqwest::Client::new(), &settings), ended)
+    }
+
+    #[tokio::test]
+    async fn reused_token_ends_the_supabase_session() {
+        let Some(db) = test_support::db().await else { return };
+        let Some(cache) = test_support::cache().await else { return };
+        let (upstream, ended) = recording_auth_service().await;
+        let ttl = Duration::from_secs(60);
+
+        let user_id = test_support::user(&db, "gc").await;
+        let auth_session_id = Uuid::new_v4();
+        let first = format!("first-{}", Uuid::new_v4());
+        let second = format!("second-{}", Uuid::new_v4());
+        let client = ClientInfo::default();
+
+        let session_id = start(&db, user_id, &first, Some(auth_session_id), &client).await.unwrap();
+        rotate(&db, &cache, &upstream, ttl, Some(session_id), user_id, &first, &second, None, &client)
+            .await
+            .unwrap();
+        assert!(ended.lock().unwrap().is_empty());
+
+        // The rotated-out token comes back: the family is revoked everywhere
+        let err = check_refresh(&db, &cache, &upstream, ttl, &first).await.unwrap_err();
+        assert!(matches!(err, ApiError::Unauthorized(_)), "{:?}", err);
+        assert_eq!(*ended.lock().unwrap(), vec![auth_session_id]);
+
+        let reason: Option<String> = sqlx::query_scalar("SELECT revoked_reason FROM auth_sessions WHERE id = $1")
+            .bind(session_id)
+            .fetch_one(&db)
+            .await
+            .unwrap();
+        assert_eq!(reason.as_deref(), Some("reuse_detected"));
+    }
+}
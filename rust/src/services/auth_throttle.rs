@@ -0,0 +1,171 @@
+//! Auth endpoint throttling
+//!
+//! Sign-up, sign-in and token refresh are unauthenticated, so they are the
+//! usual target of credential stuffing. Two Redis-backed guards sit in front
+//! of them:
+//!
+//! - a fixed-window counter per client IP and endpoint, and
+//! - a lockout per email after repeated failed sign-ins. Each further lockout
+//!   of the same email within a day doubles its length, up to a maximum.
+//!
+//! Both answer with the same 429 and `Retry-After`, and the lockout is keyed
+//! on the submitted email whether or not an account exists, so neither tells
+//! a caller anything about which emails are registered. Emails are only
+//! stored and logged as SHA-256 hashes. Like the AI quota, the guards fail
+//! open if Redis is unreachable.
+
+use chrono::Utc;
+use sha2::{Digest, Sha256};
+use std::time::Duration;
+
+use crate::config::AuthRateLimits;
+use crate::error::ApiError;
+use crate::services::cache::{keys, RedisCache};
+
+/// How long past lockouts count towards the next one's length
+const LOCKOUT_MEMORY: Duration = Duration::from_secs(24 * 60 * 60);
+
+const TOO_MANY_ATTEMPTS: &str = "Too many attempts; please try again later";
+
+/// A throttled auth endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthEndpoint {
+    SignUp,
+    SignIn,
+    Refresh,
+}
+
+impl AuthEndpoint {
+    fn as_str(self) -> &'static str {
+        match self {
+            AuthEndpoint::SignUp => "signup",
+            AuthEndpoint::SignIn => "signin",
+            AuthEndpoint::Refresh => "refresh",
+        }
+    }
+}
+
+/// SHA-256 of the normalized email, so keys and logs never hold the address
+fn email_hash(email: &str) -> String {
+    hex::encode(Sha256::digest(email.trim().to_lowercase().as_bytes()))
+}
+
+/// Count a request from `ip` to `endpoint`, or fail with a 429 once the
+/// IP has used up the current window
+pub async fn check_ip(
+    cache: &RedisCache,
+    limits: &AuthRateLimits,
+    endpoint: AuthEndpoint,
+    ip: Option<&str>,
+) -> Result<(), ApiError> {
+    let (Some(ip), limit @ 1..) = (ip, limits.ip_per_window) else {
+        return Ok(());
+    };
+
+    let now = Utc::now().timestamp().max(0) as u64;
+    let window = now / limits.window_seconds;
+    let retry_after = (window + 1) * limits.window_seconds - now;
+    let key = keys::auth_ip_window(endpoint.as_str(), ip, window);
+
+    match cache.incr(&key, Duration::from_secs(limits.window_seconds)).await {
+        Ok(count) if count > limit as i64 => {
+            if count == limit as i64 + 1 {
+                tracing::warn!(
+                    endpoint = endpoint.as_str(),
+                    ip = %ip,
+                    limit,
+                    "Auth rate limit reached for IP"
+                );
+            }
+            Err(ApiError::too_many_requests(TOO_MANY_ATTEMPTS, Some(retry_after.max(1))))
+        }
+        Ok(_) => Ok(()),
+        Err(e) => {
+            tracing::warn!(error = %e, "Auth rate limit counter unavailable; allowing request");
+            Ok(())
+        }
+    }
+}
+
+/// Fail with a 429 while sign-ins for `email` are locked
+pub async fn check_lockout(cache: &RedisCache, limits: &AuthRateLimits, email: &str) -> Result<(), ApiError> {
+    if limits.max_failed_sign_ins == 0 {
+        return Ok(());
+    }
+
+    let locked_until: Option<i64> = cache.get(&keys::auth_lock(&email_hash(email))).await;
+    match locked_until {
+        Some(until) => {
+            let retry_after = (until - Utc::now().timestamp()).max(1) as u64;
+            Err(ApiError::too_many_requests(TOO_MANY_ATTEMPTS, Some(retry_after)))
+        }
+        None => Ok(()),
+    }
+}
+
+/// Record a failed sign-in for `email`, locking it once it reaches the limit
+pub async fn record_failed_sign_in(cache: &RedisCache, limits: &AuthRateLimits, email: &str, ip: Option<&str>) {
+    if limits.max_failed_sign_ins == 0 {
+        return;
+    }
+
+    let hash = email_hash(email);
+    let failures_key = keys::auth_failures(&hash);
+    let failures = match cache
+        .incr(&failures_key, Duration::from_secs(limits.failure_window_seconds))
+        .await
+    {
+        Ok(count) => count,
+        Err(e) => {
+            tracing::warn!(error = %e, "Auth failure counter unavailable; not recording failed sign-in");
+            return;
+        }
+    };
+
+    if failures > 1 {
+        tracing::warn!(
+            email_hash = %hash,
+            ip = ip.unwrap_or("unknown"),
+            failures,
+            "Repeated failed sign-in"
+        );
+    }
+
+    if failures < limits.max_failed_sign_ins as i64 {
+        return;
+    }
+
+    let lockouts = cache
+        .incr(&keys::auth_lockouts(&hash), LOCKOUT_MEMORY)
+        .await
+        .unwrap_or(1)
+        .clamp(1, 32);
+    let seconds = limits
+        .lockout_seconds
+        .saturating_mul(1u64 << (lockouts - 1).min(20))
+        .min(limits.max_lockout_seconds);
+    let until = Utc::now().timestamp() + seconds as i64;
+
+    if let Err(e) = cache
+        .set_with_ttl(&keys::auth_lock(&hash), &until, Duration::from_secs(seconds))
+        .await
+    {
+        tracing::warn!(error = %e, "Failed to store sign-in lockout");
+        return;
+    }
+    let _ = cache.delete(&failures_key).await;
+
+    tracing::warn!(
+        email_hash = %hash,
+        ip = ip.unwrap_or("unknown"),
+        failures,
+        lockouts,
+        lockout_seconds = seconds,
+        "Sign-in locked after repeated failures"
+    );
+}
+
+/// Forget earlier failed sign-ins for `email` after it signs in
+pub async fn record_successful_sign_in(cache: &RedisCache, email: &str) {
+    let _ = cache.delete(&keys::auth_failures(&email_hash(email))).await;
+}
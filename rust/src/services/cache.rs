@@ -2,39 +2,57 @@
 //!
 //! Provides a type-safe caching layer with:
 //! - Automatic serialization/deserialization via serde
-//! - Configurable TTL
+//! - Configurable TTL per data category (`CacheNamespace`)
 //! - Cache invalidation patterns
 //! - Connection pooling via ConnectionManager
+//! - Pub/sub for pushing events to connected clients
 
 use anyhow::{Context, Result};
+use futures::Stream;
 use redis::aio::ConnectionManager;
 use redis::AsyncCommands;
 use serde::{de::DeserializeOwned, Serialize};
 use std::time::Duration;
 use tracing::{debug, error, instrument, warn};
+use uuid::Uuid;
+
+use crate::config::CacheTtls;
+
+/// Category of cached data; each has its own TTL from `Settings`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheNamespace {
+    /// AI results: long, they are expensive to recompute
+    Ai,
+    /// Paginated lists and directory pages: short, they change on every write
+    Listing,
+    /// Reference data such as trades and templates: long, rarely changes
+    Reference,
+}
 
 /// Redis cache client with connection pooling.
 #[derive(Clone)]
 pub struct RedisCache {
+    client: redis::Client,
     conn: ConnectionManager,
-    default_ttl: Duration,
+    ttls: CacheTtls,
 }
 
 impl RedisCache {
     /// Create a new Redis cache connection.
-    pub async fn new(redis_url: &str, default_ttl_seconds: u64) -> Result<Self> {
+    pub async fn new(redis_url: &str, ttls: CacheTtls) -> Result<Self> {
         let client = redis::Client::open(redis_url)
             .context("Failed to create Redis client")?;
 
-        let conn = ConnectionManager::new(client)
+        let conn = ConnectionManager::new(client.clone())
             .await
             .context("Failed to connect to Redis")?;
 
         tracing::info!("Redis cache connected");
 
         Ok(Self {
+            client,
             conn,
-            default_ttl: Duration::from_secs(default_ttl_seconds),
+            ttls,
         })
     }
 
@@ -71,10 +89,48 @@ impl RedisCache {
         }
     }
 
+    /// TTL configured for a category of data.
+    pub fn ttl(&self, namespace: CacheNamespace) -> Duration {
+        let seconds = match namespace {
+            CacheNamespace::Ai => self.ttls.ai_seconds,
+            CacheNamespace::Listing => self.ttls.listing_seconds,
+            CacheNamespace::Reference => self.ttls.reference_seconds,
+        };
+        Duration::from_secs(seconds)
+    }
+
     /// Set a value in cache with default TTL.
+    #[allow(dead_code)]
     #[instrument(skip(self, value))]
     pub async fn set<T: Serialize>(&self, key: &str, value: &T) -> Result<()> {
-        self.set_with_ttl(key, value, self.default_ttl).await
+        self.set_with_ttl(key, value, Duration::from_secs(self.ttls.default_seconds)).await
+    }
+
+    /// Set a value in cache with its category's TTL.
+    #[instrument(skip(self, value))]
+    pub async fn set_in<T: Serialize>(
+        &self,
+        namespace: CacheNamespace,
+        key: &str,
+        value: &T,
+    ) -> Result<()> {
+        self.set_with_ttl(key, value, self.ttl(namespace)).await
+    }
+
+    /// Set a value that stays until it is overwritten or deleted.
+    #[instrument(skip(self, value))]
+    pub async fn set_persistent<T: Serialize>(&self, key: &str, value: &T) -> Result<()> {
+        let mut conn = self.conn.clone();
+
+        let data = serde_json::to_string(value)
+            .context("Failed to serialize value for cache")?;
+
+        conn.set::<_, _, ()>(key, data)
+            .await
+            .context("Failed to set cache value")?;
+
+        debug!(key = key, "Stored value without expiry");
+        Ok(())
     }
 
     /// Set a value in cache with custom TTL.
@@ -98,6 +154,113 @@ impl RedisCache {
         Ok(())
     }
 
+    /// Set a raw string only if the key does not exist. Returns whether it was
+    /// set.
+    #[instrument(skip(self, value))]
+    pub async fn set_nx(&self, key: &str, value: &str, ttl: Duration) -> Result<bool> {
+        let mut conn = self.conn.clone();
+
+        let set: Option<String> = redis::cmd("SET")
+            .arg(key)
+            .arg(value)
+            .arg("NX")
+            .arg("PX")
+            .arg(ttl.as_millis() as u64)
+            .query_async(&mut conn)
+            .await
+            .context("Failed to set cache value")?;
+
+        Ok(set.is_some())
+    }
+
+    /// Delete a key only if it still holds `value` (e.g. a lock we own).
+    #[instrument(skip(self, value))]
+    pub async fn delete_if_eq(&self, key: &str, value: &str) -> Result<bool> {
+        let mut conn = self.conn.clone();
+
+        let deleted: i32 = redis::Script::new(
+            r#"
+            if redis.call("GET", KEYS[1]) == ARGV[1] then
+                return redis.call("DEL", KEYS[1])
+            end
+            return 0
+            "#,
+        )
+        .key(key)
+        .arg(value)
+        .invoke_async(&mut conn)
+        .await
+        .context("Failed to delete cache key")?;
+
+        Ok(deleted > 0)
+    }
+
+    /// Whether a key exists.
+    #[instrument(skip(self))]
+    pub async fn exists(&self, key: &str) -> Result<bool> {
+        let mut conn = self.conn.clone();
+        conn.exists(key).await.context("Failed to check cache key")
+    }
+
+    /// Increment a counter, (re)setting its expiry, and return the new value.
+    #[instrument(skip(self))]
+    pub async fn incr(&self, key: &str, ttl: Duration) -> Result<i64> {
+        let mut conn = self.conn.clone();
+
+        let (count,): (i64,) = redis::pipe()
+            .atomic()
+            .incr(key, 1)
+            .expire(key, ttl.as_secs() as i64)
+            .ignore()
+            .query_async(&mut conn)
+            .await
+            .context("Failed to increment counter")?;
+
+        Ok(count)
+    }
+
+    /// Decrement a counter, e.g. to hand back an unused reservation.
+    #[instrument(skip(self))]
+    pub async fn decr(&self, key: &str) -> Result<i64> {
+        let mut conn = self.conn.clone();
+        conn.decr(key, 1).await.context("Failed to decrement counter")
+    }
+
+    /// Publish messages, as `(channel, payload)` pairs, in one round trip.
+    #[instrument(skip(self, messages), fields(count = messages.len()))]
+    pub async fn publish_many(&self, messages: &[(String, String)]) -> Result<()> {
+        if messages.is_empty() {
+            return Ok(());
+        }
+
+        let mut pipe = redis::pipe();
+        for (channel, payload) in messages {
+            pipe.publish(channel, payload).ignore();
+        }
+
+        let mut conn = self.conn.clone();
+        pipe.query_async::<()>(&mut conn)
+            .await
+            .context("Failed to publish messages")
+    }
+
+    /// Subscribe to a channel on a dedicated connection; the subscription
+    /// ends when the returned stream is dropped.
+    pub async fn subscribe(&self, channel: &str) -> Result<impl Stream<Item = redis::Msg>> {
+        let mut pubsub = self
+            .client
+            .get_async_pubsub()
+            .await
+            .context("Failed to open Redis pub/sub connection")?;
+        pubsub
+            .subscribe(channel)
+            .await
+            .context("Failed to subscribe to channel")?;
+
+        debug!(channel = channel, "Subscribed");
+        Ok(pubsub.into_on_message())
+    }
+
     /// Delete a specific key from cache.
     #[allow(dead_code)]
     #[instrument(skip(self))]
@@ -115,17 +278,26 @@ impl RedisCache {
     pub async fn delete_pattern(&self, pattern: &str) -> Result<usize> {
         let mut conn = self.conn.clone();
 
-        // Use SCAN to find keys matching pattern (production-safe)
-        let keys: Vec<String> = redis::cmd("SCAN")
-            .cursor_arg(0)
-            .arg("MATCH")
-            .arg(pattern)
-            .arg("COUNT")
-            .arg(1000)
-            .query_async(&mut conn)
-            .await
-            .map(|(_, keys): (u64, Vec<String>)| keys)
-            .unwrap_or_default();
+        // Use SCAN to find keys matching pattern (production-safe), following
+        // the cursor until the whole keyspace has been visited
+        let mut keys: Vec<String> = Vec::new();
+        let mut cursor: u64 = 0;
+        loop {
+            let (next, batch): (u64, Vec<String>) = redis::cmd("SCAN")
+                .cursor_arg(cursor)
+                .arg("MATCH")
+                .arg(pattern)
+                .arg("COUNT")
+                .arg(1000)
+                .query_async(&mut conn)
+                .await
+                .unwrap_or_default();
+            keys.extend(batch);
+            if next == 0 {
+                break;
+            }
+            cursor = next;
+        }
 
         if keys.is_empty() {
             return Ok(0);
@@ -137,6 +309,13 @@ impl RedisCache {
         Ok(deleted as usize)
     }
 
+    /// Drop a subcontractor's cached marketplace profile and every cached
+    /// directory page. Call after any write that changes what they show.
+    pub async fn invalidate_subcontractor(&self, sub_id: Uuid) {
+        let _ = self.delete(&keys::marketplace_subcontractor(sub_id)).await;
+        let _ = self.delete_pattern(&keys::marketplace_directory_pattern()).await;
+    }
+
     /// Check if Redis is healthy.
     pub async fn health_check(&self) -> Result<()> {
         let mut conn = self.conn.clone();
@@ -329,6 +508,83 @@ pub mod keys {
         format!("ai:*:project:{}*", project_id)
     }
 
+    /// Pattern to invalidate one AI operation's caches for a project
+    /// (`operation` is the key segment, e.g. "qna")
+    pub fn ai_operation_pattern(operation: &str, project_id: Uuid) -> String {
+        format!("ai:{}:project:{}*", operation, project_id)
+    }
+
+    /// Single-flight lock held while one request computes `cache_key`
+    pub fn single_flight_lock(cache_key: &str) -> String {
+        format!("lock:{}", cache_key)
+    }
+
+    // =========================================================================
+    // Marketplace keys
+    // =========================================================================
+
+    /// Marketplace profile of a subcontractor
+    pub fn marketplace_subcontractor(sub_id: Uuid) -> String {
+        format!("marketplace:subcontractor:{}", sub_id)
+    }
+
+    /// Directory page; `query_hash` identifies the filters, sort and page
+    pub fn marketplace_directory(query_hash: &str) -> String {
+        format!("marketplace:directory:{}", query_hash)
+    }
+
+    /// Pattern to invalidate every cached directory page
+    pub fn marketplace_directory_pattern() -> String {
+        "marketplace:directory:*".to_string()
+    }
+
+    // =========================================================================
+    // Quota keys
+    // =========================================================================
+
+    /// AI calls made by a user on one UTC day (`day` as YYYYMMDD)
+    pub fn ai_quota_user_daily(user_id: Uuid, day: &str) -> String {
+        format!("quota:ai:user:{}:{}", user_id, day)
+    }
+
+    // =========================================================================
+    // Auth keys
+    // =========================================================================
+
+    /// Requests to an auth endpoint from one IP in one window
+    pub fn auth_ip_window(endpoint: &str, ip: &str, window: u64) -> String {
+        format!("ratelimit:auth:{}:ip:{}:{}", endpoint, ip, window)
+    }
+
+    /// Recent failed sign-ins for an email (`email_hash` is its SHA-256)
+    pub fn auth_failures(email_hash: &str) -> String {
+        format!("ratelimit:auth:failures:{}", email_hash)
+    }
+
+    /// Lockouts of an email in the last day
+    pub fn auth_lockouts(email_hash: &str) -> String {
+        format!("ratelimit:auth:lockouts:{}", email_hash)
+    }
+
+    /// Active sign-in lockout of an email; holds its end as a unix timestamp
+    pub fn auth_lock(email_hash: &str) -> String {
+        format!("ratelimit:auth:lock:{}", email_hash)
+    }
+
+    /// Revocation list entry for a refresh token (`token_hash` is its SHA-256)
+    pub fn revoked_refresh_token(token_hash: &str) -> String {
+        format!("auth:revoked:{}", token_hash)
+    }
+
+    // =========================================================================
+    // Pub/sub channels
+    // =========================================================================
+
+    /// Live notification events for a user
+    pub fn notification_channel(user_id: Uuid) -> String {
+        format!("notifications:{}", user_id)
+    }
+
     // =========================================================================
     // Dashboard / Stats keys
     // =========================================================================
@@ -342,18 +598,38 @@ pub mod keys {
     pub fn dashboard_pattern(user_id: Uuid) -> String {
         format!("dashboard:user:{}*", user_id)
     }
+
+    // =========================================================================
+    // Reference data keys
+    // =========================================================================
+
+    /// Standard trades list from the AI service
+    pub fn standard_trades() -> String {
+        "ai:standard_trades".to_string()
+    }
+
+    /// Active contract templates
+    pub fn contract_templates() -> String {
+        "reference:contract_templates".to_string()
+    }
+
+    // =========================================================================
+    // Operational switches
+    // =========================================================================
+
+    /// Maintenance mode, as set by an admin
+    pub fn maintenance_mode() -> String {
+        "ops:maintenance_mode".to_string()
+    }
 }
 
-/// Cache TTL constants in seconds
+/// Cache TTL constants for data outside the configurable categories
 pub mod ttl {
     use std::time::Duration;
 
     /// Profile data - 5 minutes (changes infrequently)
     pub const PROFILE: Duration = Duration::from_secs(300);
     
-    /// List data - 2 minutes (balances freshness vs performance)
-    pub const LIST: Duration = Duration::from_secs(120);
-    
     /// Count queries - 1 minute (used for pagination)
     pub const COUNT: Duration = Duration::from_secs(60);
     
@@ -361,8 +637,48 @@ pub mod ttl {
     pub const ENTITY: Duration = Duration::from_secs(300);
     
     /// Dashboard stats - 30 seconds (needs to be relatively fresh)
+    #[allow(dead_code)]
     pub const DASHBOARD: Duration = Duration::from_secs(30);
-    
-    /// AI responses - 1 hour (expensive to compute, rarely changes)
-    pub const AI: Duration = Duration::from_secs(3600);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::test_support;
+
+    const TTLS: CacheTtls = CacheTtls {
+        default_seconds: 30,
+        ai_seconds: 3600,
+        listing_seconds: 1,
+        reference_seconds: 60,
+    };
+
+    #[tokio::test]
+    async fn each_namespace_expires_on_its_own_ttl() {
+        let Some(cache) = test_support::cache_with(TTLS).await else { return };
+        assert_eq!(cache.ttl(CacheNamespace::Ai), Duration::from_secs(3600));
+        assert_eq!(cache.ttl(CacheNamespace::Listing), Duration::from_secs(1));
+        assert_eq!(cache.ttl(CacheNamespace::Reference), Duration::from_secs(60));
+
+        let prefix = format!("test:{}", Uuid::new_v4());
+        let key = |namespace: &str| format!("{}:{}", prefix, namespace);
+        cache.set_in(CacheNamespace::Ai, &key("ai"), &"summary").await.unwrap();
+        cache.set_in(CacheNamespace::Listing, &key("listing"), &"page").await.unwrap();
+        cache.set_in(CacheNamespace::Reference, &key("reference"), &"trades").await.unwrap();
+
+        tokio::time::sleep(Duration::from_millis(1500)).await;
+
+        assert_eq!(cache.get::<String>(&key("listing")).await, None);
+        assert_eq!(cache.get::<String>(&key("reference")).await.as_deref(), Some("trades"));
+        assert_eq!(cache.get::<String>(&key("ai")).await.as_deref(), Some("summary"));
+
+        let mut conn = cache.conn.clone();
+        let reference_ttl: i64 = conn.ttl(key("reference")).await.unwrap();
+        let ai_ttl: i64 = conn.ttl(key("ai")).await.unwrap();
+        assert!((55..=60).contains(&reference_ttl), "reference TTL {}", reference_ttl);
+        assert!((3595..=3600).contains(&ai_ttl), "AI TTL {}", ai_ttl);
+
+        cache.delete_pattern(&format!("{}:*", prefix)).await.unwrap();
+    }
 }
@@ -0,0 +1,215 @@
+//! Circuit breaker for upstream services
+//!
+//! After `failure_threshold` consecutive failures the circuit opens and calls
+//! fail fast with 503 instead of each waiting out a timeout. Once the
+//! cooldown has passed it half-opens: a single probe call goes through, and
+//! its outcome closes the circuit or opens it for another cooldown. State is
+//! per instance. Transitions are logged with the circuit's name, from and to
+//! states, and a running transition count.
+
+use serde::Serialize;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::config::CircuitBreakerSettings;
+use crate::error::ApiError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CircuitState {
+    Closed,
+    Open,
+    HalfOpen,
+}
+
+impl CircuitState {
+    fn as_str(self) -> &'static str {
+        match self {
+            CircuitState::Closed => "closed",
+            CircuitState::Open => "open",
+            CircuitState::HalfOpen => "half_open",
+        }
+    }
+}
+
+/// Point-in-time view of a circuit, for the health endpoint
+#[derive(Debug, Clone, Serialize)]
+pub struct CircuitSnapshot {
+    pub state: CircuitState,
+    pub consecutive_failures: u32,
+    /// Seconds until an open circuit lets a probe through
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_after_seconds: Option<u64>,
+    /// State changes since startup
+    pub transitions: u64,
+}
+
+#[derive(Debug)]
+struct Inner {
+    state: CircuitState,
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+    probe_in_flight: bool,
+    transitions: u64,
+}
+
+#[derive(Clone)]
+pub struct CircuitBreaker {
+    name: &'static str,
+    settings: CircuitBreakerSettings,
+    inner: Arc<Mutex<Inner>>,
+}
+
+/// Permission to make one call. Report its outcome with `success` or
+/// `failure`; dropping it unreported (e.g. the caller went away) frees a
+/// half-open probe slot without counting either way.
+pub struct CircuitPermit {
+    breaker: Option<CircuitBreaker>,
+}
+
+impl CircuitBreaker {
+    pub fn new(name: &'static str, settings: CircuitBreakerSettings) -> Self {
+        Self {
+            name,
+            settings,
+            inner: Arc::new(Mutex::new(Inner {
+                state: CircuitState::Closed,
+                consecutive_failures: 0,
+                opened_at: None,
+                probe_in_flight: false,
+                transitions: 0,
+            })),
+        }
+    }
+
+    fn cooldown(&self) -> Duration {
+        Duration::from_secs(self.settings.cooldown_seconds)
+    }
+
+    /// Ask to make a call; fails with 503 while the circuit is open or its
+    /// half-open probe is already running
+    pub fn acquire(&self) -> Result<CircuitPermit, ApiError> {
+        if self.settings.failure_threshold == 0 {
+            return Ok(CircuitPermit { breaker: None });
+        }
+
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        match inner.state {
+            CircuitState::Closed => {}
+            CircuitState::Open => {
+                let cooled_down = inner
+                    .opened_at
+                    .map_or(true, |at| at.elapsed() >= self.cooldown());
+                if !cooled_down {
+                    return Err(self.unavailable());
+                }
+                self.transition(&mut inner, CircuitState::HalfOpen);
+                inner.probe_in_flight = true;
+            }
+            CircuitState::HalfOpen => {
+                if inner.probe_in_flight {
+                    return Err(self.unavailable());
+                }
+                inner.probe_in_flight = true;
+            }
+        }
+
+        Ok(CircuitPermit { breaker: Some(self.clone()) })
+    }
+
+    pub fn snapshot(&self) -> CircuitSnapshot {
+        let inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        let retry_after_seconds = match (inner.state, inner.opened_at) {
+            (CircuitState::Open, Some(at)) => Some(self.cooldown().saturating_sub(at.elapsed()).as_secs()),
+            _ => None,
+        };
+
+        CircuitSnapshot {
+            state: inner.state,
+            consecutive_failures: inner.consecutive_failures,
+            retry_after_seconds,
+            transitions: inner.transitions,
+        }
+    }
+
+    fn unavailable(&self) -> ApiError {
+        ApiError::service_unavailable(format!(
+            "The {} is temporarily unavailable. Please try again later.",
+            self.name
+        ))
+    }
+
+    fn record(&self, success: bool) {
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        inner.probe_in_flight = false;
+
+        if success {
+            inner.consecutive_failures = 0;
+            if inner.state != CircuitState::Closed {
+                self.transition(&mut inner, CircuitState::Closed);
+                inner.opened_at = None;
+            }
+            return;
+        }
+
+        inner.consecutive_failures = inner.consecutive_failures.saturating_add(1);
+        let trips = match inner.state {
+            CircuitState::HalfOpen => true,
+            CircuitState::Closed => inner.consecutive_failures >= self.settings.failure_threshold,
+            CircuitState::Open => false,
+        };
+        if trips {
+            self.transition(&mut inner, CircuitState::Open);
+            inner.opened_at = Some(Instant::now());
+        }
+    }
+
+    fn transition(&self, inner: &mut Inner, to: CircuitState) {
+        let from = inner.state;
+        inner.state = to;
+        inner.transitions += 1;
+
+        if to == CircuitState::Open {
+            tracing::warn!(
+                circuit = self.name,
+                from = from.as_str(),
+                to = to.as_str(),
+                consecutive_failures = inner.consecutive_failures,
+                cooldown_seconds = self.settings.cooldown_seconds,
+                transitions = inner.transitions,
+                "Circuit opened"
+            );
+        } else {
+            tracing::info!(
+                circuit = self.name,
+                from = from.as_str(),
+                to = to.as_str(),
+                transitions = inner.transitions,
+                "Circuit state changed"
+            );
+        }
+    }
+}
+
+impl CircuitPermit {
+    pub fn success(mut self) {
+        if let Some(breaker) = self.breaker.take() {
+            breaker.record(true);
+        }
+    }
+
+    pub fn failure(mut self) {
+        if let Some(breaker) = self.breaker.take() {
+            breaker.record(false);
+        }
+    }
+}
+
+impl Drop for CircuitPermit {
+    fn drop(&mut self) {
+        if let Some(breaker) = self.breaker.take() {
+            let mut inner = breaker.inner.lock().unwrap_or_else(|e| e.into_inner());
+            inner.probe_in_flight = false;
+        }
+    }
+}
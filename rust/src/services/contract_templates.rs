@@ -0,0 +1,256 @@
+//! Contract template rendering
+//!
+//! Templates reference variables as `{{key}}` placeholders. Values come from
+//! the caller's `variables` object (nested objects are addressed with dotted
+//! keys such as `{{gc.company_name}}`) merged over data derived from the hire
+//! request, project, and parties.
+
+use std::collections::BTreeMap;
+
+use crate::domain::hiring::{ContractSection, TemplateVariable};
+
+/// Resolved variable values keyed by placeholder name
+pub type TemplateValues = BTreeMap<String, String>;
+
+/// Flatten a `variables` JSON object into placeholder values.
+///
+/// Nested objects produce dotted keys, arrays are joined with ", ", and nulls
+/// are treated as not supplied.
+pub fn flatten_variables(variables: &serde_json::Value) -> TemplateValues {
+    let mut values = TemplateValues::new();
+    if let serde_json::Value::Object(map) = variables {
+        for (key, value) in map {
+            flatten_into(&mut values, key, value);
+        }
+    }
+    values
+}
+
+fn flatten_into(values: &mut TemplateValues, key: &str, value: &serde_json::Value) {
+    match value {
+        serde_json::Value::Null => {}
+        serde_json::Value::Object(map) => {
+            for (child, value) in map {
+                flatten_into(values, &format!("{}.{}", key, child), value);
+            }
+        }
+        other => {
+            if let Some(text) = scalar_text(other) {
+                values.insert(key.to_string(), text);
+            }
+        }
+    }
+}
+
+fn scalar_text(value: &serde_json::Value) -> Option<String> {
+    match value {
+        serde_json::Value::Null | serde_json::Value::Object(_) => None,
+        serde_json::Value::String(s) => Some(s.clone()),
+        serde_json::Value::Array(items) => {
+            Some(items.iter().filter_map(scalar_text).collect::<Vec<_>>().join(", "))
+        }
+        other => Some(other.to_string()),
+    }
+}
+
+/// Apply template defaults for variables that have no value yet.
+pub fn apply_defaults(values: &mut TemplateValues, definitions: &[TemplateVariable]) {
+    for def in definitions {
+        if let Some(default) = &def.default {
+            if !has_value(values, &def.key) {
+                values.insert(def.key.clone(), default.clone());
+            }
+        }
+    }
+}
+
+/// Keys of required variables that have no non-blank value.
+pub fn missing_required(values: &TemplateValues, definitions: &[TemplateVariable]) -> Vec<String> {
+    definitions
+        .iter()
+        .filter(|def| def.required.unwrap_or(false) && !has_value(values, &def.key))
+        .map(|def| def.key.clone())
+        .collect()
+}
+
+fn has_value(values: &TemplateValues, key: &str) -> bool {
+    values.get(key).is_some_and(|v| !v.trim().is_empty())
+}
+
+/// Placeholders filled from the hire request, project, and parties when a
+/// contract is drafted, so templates may use them without declaring them.
+pub const DERIVED_KEYS: &[&str] = &[
+    "gc_company_name",
+    "gc_contact_name",
+    "gc_address",
+    "sub_company_name",
+    "sub_contact_name",
+    "sub_address",
+    "project_name",
+    "project_address",
+    "trade",
+    "scope_of_work",
+    "contract_number",
+    "contract_title",
+    "contract_amount",
+    "effective_date",
+    "start_date",
+    "end_date",
+];
+
+/// Distinct placeholder names referenced in `content`, in order of first use.
+pub fn placeholders(content: &str) -> Vec<String> {
+    let mut keys: Vec<String> = Vec::new();
+    let mut rest = content;
+
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            break;
+        };
+        let key = after[..end].trim();
+        if !key.is_empty() && !keys.iter().any(|k| k == key) {
+            keys.push(key.to_string());
+        }
+        rest = &after[end + 2..];
+    }
+
+    keys
+}
+
+/// Placeholders used in a template's content or sections that are neither
+/// declared in `variables` nor derived when the contract is drafted.
+pub fn undeclared_placeholders(
+    content: &str,
+    sections: &[ContractSection],
+    variables: &[TemplateVariable],
+) -> Vec<String> {
+    let mut keys = placeholders(content);
+    for section in sections {
+        for key in placeholders(&section.content) {
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+        }
+    }
+
+    keys.retain(|key| {
+        !DERIVED_KEYS.contains(&key.as_str()) && !variables.iter().any(|v| &v.key == key)
+    });
+    keys
+}
+
+/// Replace `{{key}}` placeholders in `content`.
+///
+/// Placeholders without a value are left as-is so they remain visible in the
+/// draft for the GC to fill in.
+pub fn render(content: &str, values: &TemplateValues) -> String {
+    let mut output = String::with_capacity(content.len());
+    let mut rest = content;
+
+    while let Some(start) = rest.find("{{") {
+        output.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            output.push_str(&rest[start..]);
+            return output;
+        };
+
+        match values.get(after[..end].trim()) {
+            Some(value) => output.push_str(value),
+            None => output.push_str(&rest[start..start + 2 + end + 2]),
+        }
+        rest = &after[end + 2..];
+    }
+
+    output.push_str(rest);
+    output
+}
+
+/// Replace placeholders in every string within a JSON value (e.g. template sections).
+pub fn render_json(value: &mut serde_json::Value, values: &TemplateValues) {
+    match value {
+        serde_json::Value::String(s) => *s = render(s, values),
+        serde_json::Value::Array(items) => items.iter_mut().for_each(|v| render_json(v, values)),
+        serde_json::Value::Object(map) => map.values_mut().for_each(|v| render_json(v, values)),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::json;
+
+    fn variable(key: &str, required: bool, default: Option<&str>) -> TemplateVariable {
+        TemplateVariable {
+            key: key.to_string(),
+            label: key.to_string(),
+            r#type: "text".to_string(),
+            options: None,
+            required: Some(required),
+            default: default.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn nested_variables_flatten_to_dotted_keys() {
+        let values = flatten_variables(&json!({
+            "gc": { "company_name": "Acme Builders", "address": { "city": "Denver" } },
+            "retainage": 5,
+            "exclusions": ["permits", "dumpsters"],
+            "notes": null,
+        }));
+
+        assert_eq!(values["gc.company_name"], "Acme Builders");
+        assert_eq!(values["gc.address.city"], "Denver");
+        assert_eq!(values["retainage"], "5");
+        assert_eq!(values["exclusions"], "permits, dumpsters");
+        assert!(!values.contains_key("notes"));
+        assert!(!values.contains_key("gc"));
+    }
+
+    #[test]
+    fn render_fills_nested_placeholders_and_keeps_unknown_ones() {
+        let values = flatten_variables(&json!({ "gc": { "company_name": "Acme Builders" } }));
+
+        assert_eq!(
+            render("Between {{ gc.company_name }} and {{sub_company_name}}.", &values),
+            "Between Acme Builders and {{sub_company_name}}."
+        );
+        assert_eq!(render("Unclosed {{gc.company_name", &values), "Unclosed {{gc.company_name");
+    }
+
+    #[test]
+    fn missing_required_lists_blank_and_absent_variables() {
+        let definitions = vec![
+            variable("retainage", true, None),
+            variable("gc.company_name", true, None),
+            variable("warranty_years", true, Some("1")),
+            variable("notes", false, None),
+        ];
+        let mut values = flatten_variables(&json!({ "gc": { "company_name": "  " } }));
+        apply_defaults(&mut values, &definitions);
+
+        assert_eq!(missing_required(&values, &definitions), vec!["retainage", "gc.company_name"]);
+    }
+
+    #[test]
+    fn undeclared_placeholders_skip_declared_and_derived_keys() {
+        let sections = vec![ContractSection {
+            key: "payment".to_string(),
+            title: "Payment".to_string(),
+            content: "Retainage of {{retainage}}% paid to {{payee.name}}.".to_string(),
+            editable: true,
+        }];
+
+        let undeclared = undeclared_placeholders(
+            "{{gc_company_name}} engages {{sub_company_name}} for {{site.gate_code}}.",
+            &sections,
+            &[variable("retainage", true, None)],
+        );
+
+        assert_eq!(undeclared, vec!["site.gate_code", "payee.name"]);
+    }
+}
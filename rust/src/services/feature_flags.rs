@@ -0,0 +1,110 @@
+//! Feature flags
+//!
+//! A flag is off unless `feature_flags` turns it on for everyone or
+//! `feature_flag_users` turns it on for a user; a per-user row overrides the
+//! global setting either way. Each instance holds both tables in memory and
+//! reloads them on an interval, and at once after a change it makes itself,
+//! so resolving a request's flags touches neither Postgres nor Redis.
+//! Changes made on another instance show up within one reload interval.
+
+use serde::Serialize;
+use sqlx::PgPool;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use uuid::Uuid;
+
+/// Flags enabled for one user
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(transparent)]
+pub struct FlagSet(Arc<BTreeSet<String>>);
+
+impl FlagSet {
+    pub fn contains(&self, key: &str) -> bool {
+        self.0.contains(key)
+    }
+}
+
+#[derive(Debug, Default)]
+struct Snapshot {
+    global: HashSet<String>,
+    /// user -> flag -> enabled
+    overrides: HashMap<Uuid, HashMap<String, bool>>,
+}
+
+#[derive(Clone)]
+pub struct FeatureFlags {
+    db: PgPool,
+    snapshot: Arc<RwLock<Arc<Snapshot>>>,
+}
+
+impl FeatureFlags {
+    /// Start with every flag off; call `reload` (or `spawn`) to load them
+    pub fn new(db: PgPool) -> Self {
+        Self {
+            db,
+            snapshot: Arc::new(RwLock::new(Arc::new(Snapshot::default()))),
+        }
+    }
+
+    /// Flags enabled for `user_id`
+    pub fn for_user(&self, user_id: Uuid) -> FlagSet {
+        let snapshot = self.snapshot.read().unwrap_or_else(|e| e.into_inner()).clone();
+        let overrides = snapshot.overrides.get(&user_id);
+        if snapshot.global.is_empty() && overrides.is_none() {
+            return FlagSet::default();
+        }
+
+        let mut flags: BTreeSet<String> = snapshot
+            .global
+            .iter()
+            .filter(|key| overrides.and_then(|o| o.get(*key)) != Some(&false))
+            .cloned()
+            .collect();
+        if let Some(overrides) = overrides {
+            flags.extend(overrides.iter().filter(|(_, on)| **on).map(|(key, _)| key.clone()));
+        }
+        FlagSet(Arc::new(flags))
+    }
+
+    /// Re-read both tables
+    pub async fn reload(&self) -> Result<(), sqlx::Error> {
+        let global: Vec<String> =
+            sqlx::query_scalar("SELECT key FROM feature_flags WHERE enabled")
+                .fetch_all(&self.db)
+                .await?;
+        let overrides: Vec<(Uuid, String, bool)> =
+            sqlx::query_as("SELECT user_id, flag_key, enabled FROM feature_flag_users")
+                .fetch_all(&self.db)
+                .await?;
+
+        let mut snapshot = Snapshot {
+            global: global.into_iter().collect(),
+            overrides: HashMap::new(),
+        };
+        for (user_id, key, enabled) in overrides {
+            snapshot.overrides.entry(user_id).or_default().insert(key, enabled);
+        }
+
+        *self.snapshot.write().unwrap_or_else(|e| e.into_inner()) = Arc::new(snapshot);
+        Ok(())
+    }
+
+    /// Reload every `interval`, starting now
+    pub fn spawn(&self, interval: Duration) {
+        let flags = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+            loop {
+                ticker.tick().await;
+                if let Err(e) = flags.reload().await {
+                    tracing::warn!(error = %e, "Failed to reload feature flags");
+                }
+            }
+        });
+
+        tracing::info!(interval_seconds = interval.as_secs(), "Feature flag refresh started");
+    }
+}
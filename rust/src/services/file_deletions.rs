@@ -0,0 +1,146 @@
+//! Uploaded file deletion queue
+//!
+//! Deleting a document enqueues its file in `file_deletions` inside the same
+//! transaction as the row delete, so a crash or failed unlink never leaves an
+//! orphaned file behind. Bid attachments are queued by a delete trigger, which
+//! also covers rows removed by cascade. The deleting request removes the file right away;
+//! this background task retries whatever is still queued. A
+//! transaction-scoped advisory lock ensures only one API instance sweeps per
+//! tick.
+
+use sqlx::{PgPool, Postgres, Transaction};
+use std::time::Duration;
+use tokio::fs;
+use uuid::Uuid;
+
+/// Advisory lock key for the sweeper ("BPXFDEL" in ASCII, fits in an i64)
+const SWEEPER_LOCK_KEY: i64 = 0x0042_5058_4644_454C;
+
+/// Most queued files handled in one pass
+const BATCH_SIZE: i64 = 100;
+
+/// Spawn the sweeper loop
+pub fn spawn(db: PgPool, interval: Duration) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            match sweep(&db).await {
+                Ok(0) => {}
+                Ok(deleted) => tracing::info!(deleted, "Deleted queued files"),
+                Err(e) => tracing::warn!(error = %e, "File deletion sweep failed"),
+            }
+        }
+    });
+
+    tracing::info!(interval_seconds = interval.as_secs(), "File deletion sweeper started");
+}
+
+/// Queue `file_path` for deletion as part of `tx`
+pub async fn enqueue(
+    tx: &mut Transaction<'_, Postgres>,
+    file_path: &str,
+    reason: &str,
+) -> Result<Uuid, sqlx::Error> {
+    sqlx::query_scalar("INSERT INTO file_deletions (file_path, reason) VALUES ($1, $2) RETURNING id")
+        .bind(file_path)
+        .bind(reason)
+        .fetch_one(&mut **tx)
+        .await
+}
+
+/// Delete a queued file now, once the enqueuing transaction has committed.
+/// Failures stay queued for the sweeper.
+pub async fn delete_now(db: &PgPool, id: Uuid, file_path: &str) {
+    if let Err(e) = process(db, id, file_path).await {
+        tracing::warn!(file_path, error = %e, "Failed to delete file; left queued for retry");
+    }
+}
+
+/// Delete files a database trigger queued, once the deleting transaction has
+/// committed. Failures stay queued for the sweeper.
+pub async fn delete_queued_now(db: &PgPool, file_paths: &[String]) {
+    if file_paths.is_empty() {
+        return;
+    }
+
+    let queued: Vec<(Uuid, String)> =
+        match sqlx::query_as("SELECT id, file_path FROM file_deletions WHERE file_path = ANY($1)")
+            .bind(file_paths)
+            .fetch_all(db)
+            .await
+        {
+            Ok(queued) => queued,
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to load queued file deletions; left for the sweeper");
+                return;
+            }
+        };
+
+    for (id, file_path) in queued {
+        delete_now(db, id, &file_path).await;
+    }
+}
+
+/// Retry queued deletions, oldest first. Returns how many files were removed.
+pub async fn sweep(db: &PgPool) -> Result<usize, sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    let locked: bool = sqlx::query_scalar("SELECT pg_try_advisory_xact_lock($1)")
+        .bind(SWEEPER_LOCK_KEY)
+        .fetch_one(&mut *tx)
+        .await?;
+
+    if !locked {
+        return Ok(0);
+    }
+
+    let queued: Vec<(Uuid, String)> = sqlx::query_as(
+        "SELECT id, file_path FROM file_deletions ORDER BY created_at LIMIT $1",
+    )
+    .bind(BATCH_SIZE)
+    .fetch_all(&mut *tx)
+    .await?;
+
+    let mut deleted = 0;
+    for (id, file_path) in queued {
+        match process(db, id, &file_path).await {
+            Ok(()) => deleted += 1,
+            Err(e) => tracing::warn!(file_path = %file_path, error = %e, "Queued file deletion failed"),
+        }
+    }
+
+    tx.commit().await?;
+    Ok(deleted)
+}
+
+/// Unlink the file and drop it from the queue, or record the failure
+async fn process(db: &PgPool, id: Uuid, file_path: &str) -> Result<(), String> {
+    let result = match fs::remove_file(file_path).await {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(e.to_string()),
+    };
+
+    let recorded = match &result {
+        Ok(()) => sqlx::query("DELETE FROM file_deletions WHERE id = $1")
+            .bind(id)
+            .execute(db)
+            .await,
+        Err(e) => sqlx::query(
+            "UPDATE file_deletions SET attempts = attempts + 1, last_error = $2, last_attempt_at = NOW() WHERE id = $1",
+        )
+        .bind(id)
+        .bind(e)
+        .execute(db)
+        .await,
+    };
+
+    if let Err(e) = recorded {
+        tracing::warn!(file_path, error = %e, "Failed to update file deletion queue");
+    }
+
+    result
+}
@@ -0,0 +1,114 @@
+//! Geocoding client for turning free-text locations into coordinates.
+//!
+//! Talks to a Nominatim-compatible `/search` endpoint. Geocoding is
+//! best-effort: failures are logged and callers fall back to text matching.
+
+use reqwest::Client;
+use serde::Deserialize;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Nominatim search result (coordinates are returned as strings)
+#[derive(Debug, Deserialize)]
+struct SearchResult {
+    lat: String,
+    lon: String,
+}
+
+/// Geocoder; disabled when no service URL is configured.
+#[derive(Clone)]
+pub struct Geocoder {
+    client: Client,
+    base_url: Option<String>,
+}
+
+impl Geocoder {
+    pub fn new(client: Client, base_url: &str) -> Self {
+        let base_url = base_url.trim().trim_end_matches('/');
+        Self {
+            client,
+            base_url: (!base_url.is_empty()).then(|| base_url.to_string()),
+        }
+    }
+
+    /// Resolve `query` to `(latitude, longitude)`, or `None` if it cannot be geocoded.
+    pub async fn geocode(&self, query: &str) -> Option<(f64, f64)> {
+        let base_url = self.base_url.as_ref()?;
+        let query = query.trim();
+        if query.is_empty() {
+            return None;
+        }
+
+        let response = self
+            .client
+            .get(format!("{}/search", base_url))
+            .query(&[("q", query), ("format", "json"), ("limit", "1")])
+            .header(reqwest::header::USER_AGENT, "BlueprintX-backend")
+            .send()
+            .await
+            .and_then(|r| r.error_for_status());
+
+        let results: Vec<SearchResult> = match response {
+            Ok(r) => match r.json().await {
+                Ok(results) => results,
+                Err(e) => {
+                    tracing::warn!(error = %e, query = %query, "Invalid geocoding response");
+                    return None;
+                }
+            },
+            Err(e) => {
+                tracing::warn!(error = %e, query = %query, "Geocoding request failed");
+                return None;
+            }
+        };
+
+        let first = results.into_iter().next()?;
+        Some((first.lat.parse().ok()?, first.lon.parse().ok()?))
+    }
+}
+
+/// Tables with `latitude`/`longitude` columns populated by the geocoder
+#[derive(Debug, Clone, Copy)]
+pub enum GeocodedTable {
+    Subcontractors,
+    Tenders,
+}
+
+impl GeocodedTable {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Subcontractors => "subcontractors",
+            Self::Tenders => "tenders",
+        }
+    }
+}
+
+/// Geocode `query` in the background and store the coordinates on the row.
+///
+/// Runs off the request path so slow geocoders never delay writes; rows keep
+/// NULL coordinates (and text-only matching) if geocoding fails.
+pub fn spawn_geocode_row(geocoder: Geocoder, db: PgPool, table: GeocodedTable, id: Uuid, query: String) {
+    if geocoder.base_url.is_none() {
+        return;
+    }
+
+    tokio::spawn(async move {
+        let Some((latitude, longitude)) = geocoder.geocode(&query).await else {
+            return;
+        };
+
+        let sql = format!(
+            "UPDATE {} SET latitude = $2, longitude = $3 WHERE id = $1",
+            table.name()
+        );
+        if let Err(e) = sqlx::query(&sql)
+            .bind(id)
+            .bind(latitude)
+            .bind(longitude)
+            .execute(&db)
+            .await
+        {
+            tracing::warn!(error = %e, id = %id, table = table.name(), "Failed to store geocoded coordinates");
+        }
+    });
+}
@@ -0,0 +1,109 @@
+//! Hire request expirer
+//!
+//! Background task that expires hire requests still awaiting the
+//! subcontractor (`sent`, `viewed`, `interested`) once their response
+//! deadline passes, and tells the GC. Drafts, negotiations, contract stages
+//! and terminal states are left alone. A transaction-scoped advisory lock
+//! ensures only one API instance expires requests per tick.
+
+use sqlx::PgPool;
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::domain::hiring::HireRequestStatus;
+use crate::domain::notifications::NotificationData;
+use crate::services::notifications::{self, NewNotification};
+
+/// Advisory lock key for the expirer ("BPXHREX" in ASCII, fits in an i64)
+const EXPIRER_LOCK_KEY: i64 = 0x0042_5058_4852_4558;
+
+/// A hire request expired by an expirer pass
+#[derive(Debug, sqlx::FromRow)]
+struct ExpiredHireRequest {
+    id: Uuid,
+    gc_id: Uuid,
+    title: String,
+    project_name: Option<String>,
+}
+
+/// Spawn the expirer loop
+pub fn spawn(db: PgPool, interval: Duration) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            match expire_overdue_hire_requests(&db).await {
+                Ok(0) => {}
+                Ok(expired) => tracing::info!(expired, "Expired hire requests past their response deadline"),
+                Err(e) => tracing::warn!(error = %e, "Hire request expirer pass failed"),
+            }
+        }
+    });
+
+    tracing::info!(
+        interval_seconds = interval.as_secs(),
+        "Hire request expirer started"
+    );
+}
+
+/// Expire hire requests whose response deadline has passed and notify their
+/// GCs. Returns the number of requests expired.
+pub async fn expire_overdue_hire_requests(db: &PgPool) -> Result<usize, sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    let locked: bool = sqlx::query_scalar("SELECT pg_try_advisory_xact_lock($1)")
+        .bind(EXPIRER_LOCK_KEY)
+        .fetch_one(&mut *tx)
+        .await?;
+
+    if !locked {
+        return Ok(0);
+    }
+
+    let expirable: Vec<String> = HireRequestStatus::EXPIRABLE
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+
+    let expired = sqlx::query_as::<_, ExpiredHireRequest>(
+        r#"
+        UPDATE hire_requests hr SET status = 'expired', updated_at = NOW()
+        FROM projects p
+        WHERE hr.status = ANY($1)
+        AND hr.response_deadline <= NOW()
+        AND p.id = hr.project_id
+        RETURNING hr.id, hr.gc_id, hr.title, p.name as project_name
+        "#,
+    )
+    .bind(&expirable)
+    .fetch_all(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    let batch: Vec<NewNotification> = expired
+        .iter()
+        .map(|request| {
+            NewNotification::new(
+                request.gc_id,
+                "Hire request expired",
+                Some(format!(
+                    "'{}' passed its response deadline without a reply.",
+                    request.title
+                )),
+                NotificationData::HireRequestExpired {
+                    hire_request_id: request.id,
+                    project_name: request.project_name.clone(),
+                },
+            )
+        })
+        .collect();
+
+    if let Err(e) = notifications::notify_many(db, &batch).await {
+        tracing::warn!(error = %e, "Failed to notify GCs of expired hire requests");
+    }
+
+    Ok(expired.len())
+}
@@ -0,0 +1,161 @@
+//! Image upload validation and thumbnail generation.
+
+use axum::extract::Multipart;
+use image::{imageops::FilterType, ImageFormat, ImageReader};
+use std::io::Cursor;
+use uuid::Uuid;
+
+use super::storage::ObjectStorage;
+use crate::error::ApiError;
+
+/// Content types accepted for image uploads.
+pub const ALLOWED_IMAGE_TYPES: &[&str] = &["image/png", "image/jpeg", "image/webp"];
+
+/// Largest accepted upload size in bytes.
+pub const MAX_IMAGE_BYTES: usize = 10 * 1024 * 1024;
+
+/// Smallest accepted width/height in pixels.
+pub const MIN_IMAGE_DIMENSION: u32 = 64;
+
+/// Largest accepted width/height in pixels.
+pub const MAX_IMAGE_DIMENSION: u32 = 4096;
+
+/// Bounding box for generated thumbnails.
+pub const THUMBNAIL_SIZE: u32 = 256;
+
+/// A validated upload plus its thumbnail variant.
+pub struct ProcessedImage {
+    pub original: Vec<u8>,
+    pub extension: &'static str,
+    pub width: u32,
+    pub height: u32,
+    /// PNG-encoded thumbnail fitting within `THUMBNAIL_SIZE`
+    pub thumbnail: Vec<u8>,
+}
+
+/// Validate an uploaded image and render its thumbnail.
+///
+/// The declared content type must be one of `ALLOWED_IMAGE_TYPES` and agree
+/// with the file's signature. This decodes the image, so call it from
+/// `spawn_blocking`.
+pub fn process_upload(bytes: Vec<u8>, content_type: Option<&str>) -> Result<ProcessedImage, ApiError> {
+    let declared = content_type.unwrap_or_default();
+    if !ALLOWED_IMAGE_TYPES.contains(&declared) {
+        return Err(ApiError::bad_request(format!(
+            "Unsupported image type '{}'; expected one of: {}",
+            declared,
+            ALLOWED_IMAGE_TYPES.join(", ")
+        )));
+    }
+
+    let format = image::guess_format(&bytes)
+        .map_err(|_| ApiError::bad_request("File is not a recognized image"))?;
+    if format.to_mime_type() != declared {
+        return Err(ApiError::bad_request(format!(
+            "File contents do not match declared type '{}'",
+            declared
+        )));
+    }
+
+    let reader = ImageReader::with_format(Cursor::new(bytes.as_slice()), format);
+    let (width, height) = reader
+        .into_dimensions()
+        .map_err(|e| ApiError::bad_request(format!("Invalid image: {}", e)))?;
+    if width.min(height) < MIN_IMAGE_DIMENSION || width.max(height) > MAX_IMAGE_DIMENSION {
+        return Err(ApiError::bad_request(format!(
+            "Image must be between {}x{} and {}x{} pixels (got {}x{})",
+            MIN_IMAGE_DIMENSION, MIN_IMAGE_DIMENSION, MAX_IMAGE_DIMENSION, MAX_IMAGE_DIMENSION,
+            width, height
+        )));
+    }
+
+    let decoded = image::load_from_memory_with_format(&bytes, format)
+        .map_err(|e| ApiError::bad_request(format!("Invalid image: {}", e)))?;
+
+    let mut thumbnail = Vec::new();
+    decoded
+        .resize(THUMBNAIL_SIZE, THUMBNAIL_SIZE, FilterType::Lanczos3)
+        .write_to(&mut Cursor::new(&mut thumbnail), ImageFormat::Png)
+        .map_err(|e| ApiError::internal(format!("Failed to encode thumbnail: {}", e)))?;
+
+    Ok(ProcessedImage {
+        original: bytes,
+        extension: format.extensions_str().first().copied().unwrap_or("img"),
+        width,
+        height,
+        thumbnail,
+    })
+}
+
+/// Object keys of a stored image and its thumbnail.
+#[derive(Debug, Clone)]
+pub struct StoredImage {
+    pub key: String,
+    pub thumb_key: String,
+}
+
+/// Key of the thumbnail stored alongside the image at `key`.
+pub fn thumbnail_key(key: &str) -> String {
+    let stem = key.rsplit_once('.').map_or(key, |(stem, _)| stem);
+    format!("{}_thumb.png", stem)
+}
+
+/// Read the `file` field of a multipart image upload.
+pub async fn read_upload(multipart: &mut Multipart) -> Result<(Vec<u8>, Option<String>), ApiError> {
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| ApiError::bad_request(format!("Failed to read multipart: {}", e)))?
+    {
+        if field.name() == Some("file") {
+            let content_type = field.content_type().map(|s| s.to_string());
+            let bytes = field
+                .bytes()
+                .await
+                .map_err(|e| ApiError::bad_request(format!("Failed to read file: {}", e)))?
+                .to_vec();
+            if bytes.len() > MAX_IMAGE_BYTES {
+                return Err(ApiError::bad_request(format!(
+                    "Image exceeds the {} MB upload limit",
+                    MAX_IMAGE_BYTES / (1024 * 1024)
+                )));
+            }
+            return Ok((bytes, content_type));
+        }
+    }
+
+    Err(ApiError::bad_request("No file provided in upload"))
+}
+
+/// Validate an image, then store it and its thumbnail under `prefix` with fresh keys.
+pub async fn store_upload(
+    storage: &ObjectStorage,
+    prefix: &str,
+    bytes: Vec<u8>,
+    content_type: Option<String>,
+) -> Result<StoredImage, ApiError> {
+    let processed = tokio::task::spawn_blocking(move || process_upload(bytes, content_type.as_deref()))
+        .await
+        .map_err(|e| ApiError::internal(format!("Image processing failed: {}", e)))??;
+
+    let key = format!("{}/{}.{}", prefix, Uuid::new_v4(), processed.extension);
+    let stored = StoredImage {
+        thumb_key: thumbnail_key(&key),
+        key,
+    };
+
+    storage.put(&stored.key, &processed.original).await?;
+    if let Err(e) = storage.put(&stored.thumb_key, &processed.thumbnail).await {
+        storage.delete_best_effort(&[Some(stored.key.as_str())]).await;
+        return Err(e);
+    }
+
+    tracing::debug!(
+        key = %stored.key,
+        width = processed.width,
+        height = processed.height,
+        "Stored image upload"
+    );
+
+    Ok(stored)
+}
@@ -0,0 +1,156 @@
+//! Insurance monitor
+//!
+//! Background task that watches `subcontractors.insurance_expiry`. Subs are
+//! reminded once per expiry date when their insurance comes within
+//! `InsuranceInfo::EXPIRY_WARNING_DAYS` days of expiring. Verified subs whose
+//! insurance has lapsed are sent back to the admin verification queue
+//! (`verification_status = 'pending'`; `verified` is left for the admin to
+//! decide) and told why. A transaction-scoped advisory lock ensures only one
+//! API instance runs a pass per tick.
+
+use chrono::NaiveDate;
+use sqlx::PgPool;
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::domain::marketplace::InsuranceInfo;
+use crate::domain::notifications::NotificationData;
+use crate::services::cache::RedisCache;
+use crate::services::notifications::{self, NewNotification};
+
+/// Advisory lock key for the monitor ("BPXINSM" in ASCII, fits in an i64)
+const MONITOR_LOCK_KEY: i64 = 0x0042_5058_494E_534D;
+
+/// A subcontractor picked up by a monitor pass
+#[derive(Debug, sqlx::FromRow)]
+struct InsuranceAlert {
+    id: Uuid,
+    profile_id: Option<Uuid>,
+    insurance_expiry: NaiveDate,
+    days_remaining: i32,
+}
+
+/// Outcome of one monitor pass
+#[derive(Debug, Default)]
+pub struct InsuranceMonitorPass {
+    pub reminded: usize,
+    pub flagged: usize,
+}
+
+/// Spawn the monitor loop
+pub fn spawn(db: PgPool, cache: RedisCache, interval: Duration) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            match check_insurance_expiry(&db, &cache).await {
+                Ok(InsuranceMonitorPass { reminded: 0, flagged: 0 }) => {}
+                Ok(pass) => tracing::info!(
+                    reminded = pass.reminded,
+                    flagged = pass.flagged,
+                    "Insurance monitor pass complete"
+                ),
+                Err(e) => tracing::warn!(error = %e, "Insurance monitor pass failed"),
+            }
+        }
+    });
+
+    tracing::info!(
+        interval_seconds = interval.as_secs(),
+        "Insurance monitor started"
+    );
+}
+
+/// Remind subs whose insurance expires soon and flag verified subs whose
+/// insurance has lapsed for admin review.
+pub async fn check_insurance_expiry(
+    db: &PgPool,
+    cache: &RedisCache,
+) -> Result<InsuranceMonitorPass, sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    let locked: bool = sqlx::query_scalar("SELECT pg_try_advisory_xact_lock($1)")
+        .bind(MONITOR_LOCK_KEY)
+        .fetch_one(&mut *tx)
+        .await?;
+
+    if !locked {
+        return Ok(InsuranceMonitorPass::default());
+    }
+
+    let expiring = sqlx::query_as::<_, InsuranceAlert>(
+        r#"
+        UPDATE subcontractors SET insurance_reminder_sent_for = insurance_expiry
+        WHERE insurance_expiry BETWEEN CURRENT_DATE AND CURRENT_DATE + $1::int
+        AND insurance_reminder_sent_for IS DISTINCT FROM insurance_expiry
+        RETURNING id, profile_id, insurance_expiry, (insurance_expiry - CURRENT_DATE) as days_remaining
+        "#,
+    )
+    .bind(InsuranceInfo::EXPIRY_WARNING_DAYS as i32)
+    .fetch_all(&mut *tx)
+    .await?;
+
+    let lapsed = sqlx::query_as::<_, InsuranceAlert>(
+        r#"
+        UPDATE subcontractors SET
+            verification_status = 'pending',
+            insurance_lapse_flagged_for = insurance_expiry,
+            updated_at = NOW()
+        WHERE verification_status = 'verified'
+        AND insurance_expiry < CURRENT_DATE
+        AND insurance_lapse_flagged_for IS DISTINCT FROM insurance_expiry
+        RETURNING id, profile_id, insurance_expiry, (insurance_expiry - CURRENT_DATE) as days_remaining
+        "#,
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    // Lapsed subs are no longer listed as verified
+    for alert in &lapsed {
+        cache.invalidate_subcontractor(alert.id).await;
+    }
+
+    let reminders = expiring.iter().filter_map(|alert| {
+        let profile_id = alert.profile_id?;
+        Some(NewNotification::new(
+            profile_id,
+            "Insurance expiring soon",
+            Some(format!(
+                "Your insurance expires on {}. Upload current coverage to stay listed as insured.",
+                alert.insurance_expiry
+            )),
+            NotificationData::InsuranceExpiring {
+                expiry_date: alert.insurance_expiry,
+                days_remaining: alert.days_remaining as i64,
+            },
+        ))
+    });
+    let lapses = lapsed.iter().filter_map(|alert| {
+        let profile_id = alert.profile_id?;
+        Some(NewNotification::new(
+            profile_id,
+            "Insurance lapsed",
+            Some(format!(
+                "Your insurance expired on {}. Your verification is back under admin review until current coverage is on file.",
+                alert.insurance_expiry
+            )),
+            NotificationData::InsuranceLapsed {
+                expiry_date: alert.insurance_expiry,
+            },
+        ))
+    });
+    let batch: Vec<NewNotification> = reminders.chain(lapses).collect();
+
+    if let Err(e) = notifications::notify_many(db, &batch).await {
+        tracing::warn!(error = %e, "Failed to send insurance expiry notifications");
+    }
+
+    Ok(InsuranceMonitorPass {
+        reminded: expiring.len(),
+        flagged: lapsed.len(),
+    })
+}
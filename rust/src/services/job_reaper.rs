@@ -0,0 +1,151 @@
+//! Stalled processing job reaper
+//!
+//! Background task that finds `running` jobs whose worker stopped reporting
+//! progress and either requeues them (claimed jobs under `max_retries`) or marks
+//! them failed. A transaction-scoped advisory lock ensures only one API instance
+//! reaps per tick.
+
+use sqlx::PgPool;
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::services::notifications;
+
+/// Advisory lock key for the reaper ("BPXREAP" in ASCII, fits in an i64)
+const REAPER_LOCK_KEY: i64 = 0x0042_5058_5245_4150;
+
+/// Outcome of a single reaper pass
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ReapOutcome {
+    pub requeued: usize,
+    pub failed: usize,
+}
+
+/// Spawn the reaper loop
+pub fn spawn(db: PgPool, stale_after: Duration, interval: Duration) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            match reap_stalled_jobs(&db, stale_after).await {
+                Ok(outcome) if outcome.requeued + outcome.failed > 0 => {
+                    tracing::warn!(
+                        requeued = outcome.requeued,
+                        failed = outcome.failed,
+                        "Reaped stalled processing jobs"
+                    );
+                }
+                Ok(_) => {}
+                Err(e) => tracing::warn!(error = %e, "Job reaper pass failed"),
+            }
+        }
+    });
+
+    tracing::info!(
+        stale_after_seconds = stale_after.as_secs(),
+        interval_seconds = interval.as_secs(),
+        "Job reaper started"
+    );
+}
+
+/// Requeue or fail running jobs with no progress for longer than `stale_after`
+pub async fn reap_stalled_jobs(db: &PgPool, stale_after: Duration) -> Result<ReapOutcome, sqlx::Error> {
+    let stale_seconds = stale_after.as_secs_f64();
+    let mut tx = db.begin().await?;
+
+    let locked: bool = sqlx::query_scalar("SELECT pg_try_advisory_xact_lock($1)")
+        .bind(REAPER_LOCK_KEY)
+        .fetch_one(&mut *tx)
+        .await?;
+
+    if !locked {
+        return Ok(ReapOutcome::default());
+    }
+
+    // Claimed jobs with retries left go back in the queue for another worker
+    let requeued: Vec<Uuid> = sqlx::query_scalar(
+        r#"
+        UPDATE processing_jobs SET
+            status = 'queued',
+            claimed_by = NULL,
+            claimed_at = NULL,
+            heartbeat_at = NULL,
+            retry_count = retry_count + 1,
+            error_message = 'Requeued after the worker stopped reporting progress',
+            updated_at = NOW()
+        WHERE status = 'running'
+        AND claimed_by IS NOT NULL
+        AND retry_count < max_retries
+        AND COALESCE(last_progress_at, started_at, updated_at) < NOW() - make_interval(secs => $1)
+        RETURNING id
+        "#,
+    )
+    .bind(stale_seconds)
+    .fetch_all(&mut *tx)
+    .await?;
+
+    let failed: Vec<(Uuid, Uuid, Option<Uuid>)> = sqlx::query_as(
+        r#"
+        UPDATE processing_jobs j SET
+            status = 'failed',
+            error_message = 'Job stalled: no progress reported before the timeout',
+            error_step = j.current_step,
+            can_retry = true,
+            completed_at = NOW(),
+            updated_at = NOW()
+        FROM projects p
+        WHERE j.status = 'running'
+        AND p.id = j.project_id
+        AND COALESCE(j.last_progress_at, j.started_at, j.updated_at) < NOW() - make_interval(secs => $1)
+        RETURNING j.id, j.project_id, p.owner_id
+        "#,
+    )
+    .bind(stale_seconds)
+    .fetch_all(&mut *tx)
+    .await?;
+
+    let failed_ids: Vec<Uuid> = failed.iter().map(|(id, _, _)| *id).collect();
+
+    // Steps that were mid-flight restart on requeue and fail with the job otherwise
+    sqlx::query(
+        "UPDATE processing_steps SET status = 'pending', progress = 0 WHERE job_id = ANY($1) AND status = 'running'",
+    )
+    .bind(&requeued)
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query(
+        r#"
+        UPDATE processing_steps SET status = 'failed', error_message = 'Job stalled', completed_at = NOW()
+        WHERE job_id = ANY($1) AND status = 'running'
+        "#,
+    )
+    .bind(&failed_ids)
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    for (job_id, project_id, owner_id) in &failed {
+        if let Some(owner_id) = owner_id {
+            if let Err(e) = notifications::notify_job_failed(
+                db,
+                *owner_id,
+                *job_id,
+                *project_id,
+                "Processing stalled and was stopped. You can retry the job.",
+            )
+            .await
+            {
+                tracing::warn!(error = %e, job_id = %job_id, "Failed to send job failure notification");
+            }
+        }
+    }
+
+    Ok(ReapOutcome {
+        requeued: requeued.len(),
+        failed: failed.len(),
+    })
+}
@@ -0,0 +1,23 @@
+//! Maintenance mode
+//!
+//! While maintenance mode is on, the maintenance middleware refuses mutating
+//! requests with 503 and `Retry-After`; reads, health checks, admins and the
+//! paths in `MAINTENANCE_ALLOWED_PATHS` carry on. The switch lives in Redis so
+//! one admin toggle reaches every instance. If Redis can't be read, requests
+//! are let through rather than refused.
+
+use crate::domain::admin::MaintenanceMode;
+use crate::services::cache::{keys, RedisCache};
+
+/// The current maintenance mode, if it is on
+pub async fn current(cache: &RedisCache) -> Option<MaintenanceMode> {
+    cache
+        .get::<MaintenanceMode>(&keys::maintenance_mode())
+        .await
+        .filter(|mode| mode.enabled)
+}
+
+/// Record `mode`; it stays in effect until changed again
+pub async fn set(cache: &RedisCache, mode: &MaintenanceMode) -> anyhow::Result<()> {
+    cache.set_persistent(&keys::maintenance_mode(), mode).await
+}
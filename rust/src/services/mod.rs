@@ -1,12 +1,45 @@
 //! Service layer modules for external integrations.
 //!
-//! Contains clients for Redis caching, AI service communication, and notification services.
+//! Contains clients for Redis caching, AI service communication and usage accounting and quotas, auth endpoint throttling and session tracking, Supabase session revocation, notification services and live
+//! notification events,
+//! activity audit logging, contract templates, geocoding, media storage, signed
+//! download links, admin-managed settings, feature flags, maintenance mode,
+//! resource watchers, streaming ZIP archives, and background maintenance tasks.
 
 pub mod ai_client;
+pub mod ai_quota;
+pub mod ai_usage;
+pub mod audit;
+pub mod auth_sessions;
+pub mod auth_throttle;
 pub mod cache;
+pub mod circuit_breaker;
+pub mod contract_templates;
+pub mod feature_flags;
+pub mod file_deletions;
+pub mod geocoding;
+pub mod hire_request_expirer;
+pub mod insurance_monitor;
+pub mod images;
+pub mod job_reaper;
+pub mod maintenance;
+pub mod notification_stream;
 pub mod notifications;
+pub mod pdf;
+pub mod signed_urls;
+pub mod single_flight;
+pub mod storage;
+pub mod supabase_auth;
+pub mod system_settings;
+pub mod tender_closer;
+pub mod watchers;
+pub mod zip_stream;
 
 pub use ai_client::AiClient;
 pub use cache::RedisCache;
+pub use geocoding::Geocoder;
+pub use signed_urls::UrlSigner;
+pub use storage::ObjectStorage;
+pub use supabase_auth::SupabaseAuth;
 #[allow(unused_imports)]
 pub use notifications::*;
@@ -0,0 +1,65 @@
+//! Live notification events
+//!
+//! Notification changes are published on a per-user Redis channel
+//! (`notifications:{user_id}`) and relayed to that user's open
+//! `GET /api/notifications/stream` connections, on whichever instance holds
+//! them. Notifications are created from routes and background tasks that only
+//! hold a database pool, so the publisher is installed once at startup rather
+//! than passed to every caller. Publishing is best effort: a failure is
+//! logged, and clients catch up from the list and count endpoints.
+
+use futures::{Stream, StreamExt};
+use std::sync::OnceLock;
+use uuid::Uuid;
+
+use crate::domain::notifications::NotificationStreamEvent;
+use crate::services::cache::{keys, RedisCache};
+
+static PUBLISHER: OnceLock<RedisCache> = OnceLock::new();
+
+/// Use `cache` to publish notification events; later calls are ignored
+pub fn install(cache: RedisCache) {
+    let _ = PUBLISHER.set(cache);
+}
+
+/// Publish events, each to its user's channel
+pub async fn publish(events: &[(Uuid, NotificationStreamEvent)]) {
+    let Some(cache) = PUBLISHER.get() else {
+        return;
+    };
+
+    let messages: Vec<(String, String)> = events
+        .iter()
+        .filter_map(|(user_id, event)| {
+            let payload = serde_json::to_string(event).ok()?;
+            Some((keys::notification_channel(*user_id), payload))
+        })
+        .collect();
+
+    if let Err(e) = cache.publish_many(&messages).await {
+        tracing::warn!(error = %e, count = messages.len(), "Failed to publish notification events");
+    }
+}
+
+/// Publish one event to one user
+pub async fn publish_to(user_id: Uuid, event: NotificationStreamEvent) {
+    publish(&[(user_id, event)]).await;
+}
+
+/// Events published to `user_id`, as `(event name, JSON payload)`
+pub async fn subscribe(
+    cache: &RedisCache,
+    user_id: Uuid,
+) -> anyhow::Result<impl Stream<Item = (String, String)>> {
+    let messages = cache.subscribe(&keys::notification_channel(user_id)).await?;
+
+    Ok(messages.filter_map(|msg| async move {
+        let payload: String = msg.get_payload().ok()?;
+        let name = serde_json::from_str::<serde_json::Value>(&payload)
+            .ok()?
+            .get("event")?
+            .as_str()?
+            .to_string();
+        Some((name, payload))
+    }))
+}
@@ -8,44 +8,128 @@
 use sqlx::PgPool;
 use uuid::Uuid;
 
-use crate::domain::notifications::NotificationType;
+use crate::domain::money::Money;
+use crate::domain::notifications::{
+    Notification, NotificationData, NotificationResponse, NotificationStreamEvent, NotificationType,
+};
+use crate::services::notification_stream;
+use crate::domain::settings::NotificationSettings;
 
-/// Create a notification for a user
-pub async fn create_notification(
-    db: &PgPool,
-    user_id: Uuid,
-    notification_type: NotificationType,
-    title: &str,
-    message: Option<&str>,
-    data: Option<serde_json::Value>,
-) -> Result<Uuid, sqlx::Error> {
-    let id = Uuid::new_v4();
-    let type_str = notification_type.to_string();
-    let data = data.unwrap_or(serde_json::json!({}));
+/// A notification to create with [`notify_many`]
+#[derive(Debug, Clone)]
+pub struct NewNotification {
+    pub user_id: Uuid,
+    pub title: String,
+    pub message: Option<String>,
+    pub data: NotificationData,
+}
+
+impl NewNotification {
+    pub fn new(user_id: Uuid, title: impl Into<String>, message: Option<String>, data: NotificationData) -> Self {
+        Self {
+            user_id,
+            title: title.into(),
+            message,
+            data,
+        }
+    }
+}
+
+/// Create notifications in a single multi-row `INSERT`.
+///
+/// Each notification's type is taken from its `data`, so the stored payload
+/// always matches its type's [`NotificationData`] contract. Returns the new
+/// ids in input order. Each recipient's live stream gets a `created` event.
+pub async fn notify_many(db: &PgPool, notifications: &[NewNotification]) -> Result<Vec<Uuid>, sqlx::Error> {
+    if notifications.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let count = notifications.len();
+    let mut ids = Vec::with_capacity(count);
+    let mut user_ids = Vec::with_capacity(count);
+    let mut types = Vec::with_capacity(count);
+    let mut titles = Vec::with_capacity(count);
+    let mut messages = Vec::with_capacity(count);
+    let mut data = Vec::with_capacity(count);
+    for notification in notifications {
+        ids.push(Uuid::new_v4());
+        user_ids.push(notification.user_id);
+        types.push(notification.data.notification_type().to_string());
+        titles.push(notification.title.clone());
+        messages.push(notification.message.clone());
+        data.push(notification.data.to_stored());
+    }
+
+    let created_at = chrono::Utc::now();
 
     sqlx::query(
         r#"
-        INSERT INTO notifications (id, user_id, type, title, message, data)
-        VALUES ($1, $2, $3, $4, $5, $6)
+        INSERT INTO notifications (id, user_id, type, title, message, data, created_at)
+        SELECT n.*, $7 FROM UNNEST($1::uuid[], $2::uuid[], $3::text[], $4::text[], $5::text[], $6::jsonb[]) n
         "#,
     )
-    .bind(id)
-    .bind(user_id)
-    .bind(&type_str)
-    .bind(title)
-    .bind(message)
+    .bind(&ids)
+    .bind(&user_ids)
+    .bind(&types)
+    .bind(&titles)
+    .bind(&messages)
     .bind(&data)
+    .bind(created_at)
     .execute(db)
     .await?;
 
-    tracing::info!(
-        user_id = %user_id,
-        notification_type = %type_str,
-        notification_id = %id,
-        "Notification created"
-    );
+    if count == 1 {
+        tracing::info!(
+            user_id = %user_ids[0],
+            notification_type = %types[0],
+            notification_id = %ids[0],
+            "Notification created"
+        );
+    } else {
+        tracing::info!(count, "Notifications created");
+    }
+
+    let events: Vec<(Uuid, NotificationStreamEvent)> = (0..count)
+        .map(|i| {
+            let notification = Notification {
+                id: ids[i],
+                user_id: user_ids[i],
+                notification_type: types[i].clone(),
+                title: titles[i].clone(),
+                message: messages[i].clone(),
+                data: sqlx::types::Json(data[i].clone()),
+                is_read: false,
+                read_at: None,
+                created_at,
+            };
+            let event = NotificationStreamEvent::Created {
+                notification: NotificationResponse::from(notification),
+                unread_delta: 1,
+            };
+            (user_ids[i], event)
+        })
+        .collect();
+    notification_stream::publish(&events).await;
 
-    Ok(id)
+    Ok(ids)
+}
+
+/// Create a notification for a user.
+pub async fn create_notification(
+    db: &PgPool,
+    user_id: Uuid,
+    title: &str,
+    message: Option<&str>,
+    data: NotificationData,
+) -> Result<Uuid, sqlx::Error> {
+    let ids = notify_many(
+        db,
+        &[NewNotification::new(user_id, title, message.map(str::to_string), data)],
+    )
+    .await?;
+
+    Ok(ids[0])
 }
 
 /// Create a bid received notification for a GC
@@ -53,25 +137,53 @@ pub async fn notify_bid_received(
     db: &PgPool,
     gc_user_id: Uuid,
     tender_id: Uuid,
+    bid_id: Uuid,
     tender_title: &str,
     subcontractor_name: &str,
-    bid_amount: f64,
+    bid_amount: Money,
 ) -> Result<Uuid, sqlx::Error> {
     create_notification(
         db,
         gc_user_id,
-        NotificationType::BidReceived,
         &format!("New bid on {}", tender_title),
         Some(&format!(
-            "{} submitted a bid of ${:.2}",
+            "{} submitted a bid of ${}",
             subcontractor_name, bid_amount
         )),
-        Some(serde_json::json!({
-            "tender_id": tender_id,
-            "tender_title": tender_title,
-            "subcontractor_name": subcontractor_name,
-            "bid_amount": bid_amount,
-        })),
+        NotificationData::BidReceived {
+            tender_id,
+            bid_id: Some(bid_id),
+            tender_title: tender_title.to_string(),
+            subcontractor_name: subcontractor_name.to_string(),
+            bid_amount,
+        },
+    )
+    .await
+}
+
+/// Create a bid restored notification for a GC
+pub async fn notify_bid_restored(
+    db: &PgPool,
+    gc_user_id: Uuid,
+    tender_id: Uuid,
+    bid_id: Uuid,
+    tender_title: &str,
+    subcontractor_name: &str,
+) -> Result<Uuid, sqlx::Error> {
+    create_notification(
+        db,
+        gc_user_id,
+        &format!("Bid restored on {}", tender_title),
+        Some(&format!(
+            "{} restored their previously withdrawn bid",
+            subcontractor_name
+        )),
+        NotificationData::BidRestored {
+            tender_id,
+            bid_id,
+            tender_title: tender_title.to_string(),
+            subcontractor_name: subcontractor_name.to_string(),
+        },
     )
     .await
 }
@@ -87,17 +199,16 @@ pub async fn notify_bid_awarded(
     create_notification(
         db,
         sub_user_id,
-        NotificationType::BidAwarded,
-        &format!("Your bid was accepted!"),
+        "Your bid was accepted!",
         Some(&format!(
             "Congratulations! Your bid for '{}' on project '{}' has been selected.",
             tender_title, project_name
         )),
-        Some(serde_json::json!({
-            "tender_id": tender_id,
-            "tender_title": tender_title,
-            "project_name": project_name,
-        })),
+        NotificationData::BidAwarded {
+            tender_id,
+            tender_title: tender_title.to_string(),
+            project_name: project_name.to_string(),
+        },
     )
     .await
 }
@@ -112,16 +223,15 @@ pub async fn notify_bid_rejected(
     create_notification(
         db,
         sub_user_id,
-        NotificationType::BidRejected,
-        &format!("Bid not selected"),
+        "Bid not selected",
         Some(&format!(
             "Your bid for '{}' was not selected. Keep bidding on other opportunities!",
             tender_title
         )),
-        Some(serde_json::json!({
-            "tender_id": tender_id,
-            "tender_title": tender_title,
-        })),
+        NotificationData::BidRejected {
+            tender_id,
+            tender_title: tender_title.to_string(),
+        },
     )
     .await
 }
@@ -138,18 +248,17 @@ pub async fn notify_hire_request_received(
     create_notification(
         db,
         sub_user_id,
-        NotificationType::HireRequestReceived,
         &format!("New hire request from {}", gc_company_name),
         Some(&format!(
             "{} would like to hire you for {} work on project '{}'",
             gc_company_name, trade, project_name
         )),
-        Some(serde_json::json!({
-            "hire_request_id": hire_request_id,
-            "gc_company_name": gc_company_name,
-            "project_name": project_name,
-            "trade": trade,
-        })),
+        NotificationData::HireRequestReceived {
+            hire_request_id,
+            gc_company_name: gc_company_name.to_string(),
+            project_name: project_name.to_string(),
+            trade: trade.to_string(),
+        },
     )
     .await
 }
@@ -165,17 +274,16 @@ pub async fn notify_hire_request_accepted(
     create_notification(
         db,
         gc_user_id,
-        NotificationType::HireRequestAccepted,
         &format!("{} accepted your hire request!", subcontractor_name),
         Some(&format!(
             "{} has accepted your hire request for project '{}'",
             subcontractor_name, project_name
         )),
-        Some(serde_json::json!({
-            "hire_request_id": hire_request_id,
-            "subcontractor_name": subcontractor_name,
-            "project_name": project_name,
-        })),
+        NotificationData::HireRequestAccepted {
+            hire_request_id,
+            subcontractor_name: subcontractor_name.to_string(),
+            project_name: project_name.to_string(),
+        },
     )
     .await
 }
@@ -191,17 +299,16 @@ pub async fn notify_hire_request_declined(
     create_notification(
         db,
         gc_user_id,
-        NotificationType::HireRequestDeclined,
         &format!("{} declined your hire request", subcontractor_name),
         Some(&format!(
             "{} has declined your hire request for project '{}'",
             subcontractor_name, project_name
         )),
-        Some(serde_json::json!({
-            "hire_request_id": hire_request_id,
-            "subcontractor_name": subcontractor_name,
-            "project_name": project_name,
-        })),
+        NotificationData::HireRequestDeclined {
+            hire_request_id,
+            subcontractor_name: subcontractor_name.to_string(),
+            project_name: project_name.to_string(),
+        },
     )
     .await
 }
@@ -217,17 +324,16 @@ pub async fn notify_contract_sent(
     create_notification(
         db,
         sub_user_id,
-        NotificationType::ContractSent,
         &format!("Contract received from {}", gc_company_name),
         Some(&format!(
             "{} has sent you a contract for project '{}'. Please review and sign.",
             gc_company_name, project_name
         )),
-        Some(serde_json::json!({
-            "contract_id": contract_id,
-            "gc_company_name": gc_company_name,
-            "project_name": project_name,
-        })),
+        NotificationData::ContractSent {
+            contract_id,
+            gc_company_name: gc_company_name.to_string(),
+            project_name: project_name.to_string(),
+        },
     )
     .await
 }
@@ -241,14 +347,22 @@ pub async fn notify_contract_signed(
     project_name: &str,
     is_fully_signed: bool,
 ) -> Result<Uuid, sqlx::Error> {
-    let notification_type = if is_fully_signed {
-        NotificationType::ContractFullySigned
+    let data = if is_fully_signed {
+        NotificationData::ContractFullySigned {
+            contract_id,
+            signer_name: signer_name.to_string(),
+            project_name: project_name.to_string(),
+        }
     } else {
-        NotificationType::ContractSigned
+        NotificationData::ContractSigned {
+            contract_id,
+            signer_name: signer_name.to_string(),
+            project_name: project_name.to_string(),
+        }
     };
 
     let title = if is_fully_signed {
-        format!("Contract fully signed!")
+        "Contract fully signed!".to_string()
     } else {
         format!("{} signed the contract", signer_name)
     };
@@ -268,15 +382,36 @@ pub async fn notify_contract_signed(
     create_notification(
         db,
         recipient_user_id,
-        notification_type,
         &title,
         Some(&message),
-        Some(serde_json::json!({
-            "contract_id": contract_id,
-            "signer_name": signer_name,
-            "project_name": project_name,
-            "is_fully_signed": is_fully_signed,
-        })),
+        data,
+    )
+    .await
+}
+
+/// Create a payment milestone paid notification for a subcontractor
+pub async fn notify_milestone_paid(
+    db: &PgPool,
+    sub_user_id: Uuid,
+    contract_id: Uuid,
+    milestone_name: &str,
+    amount: Money,
+    outstanding_amount: Money,
+) -> Result<Uuid, sqlx::Error> {
+    create_notification(
+        db,
+        sub_user_id,
+        "Payment milestone paid",
+        Some(&format!(
+            "'{}' (${}) has been marked as paid. Remaining balance: ${}.",
+            milestone_name, amount, outstanding_amount
+        )),
+        NotificationData::PaymentMilestonePaid {
+            contract_id,
+            milestone_name: milestone_name.to_string(),
+            amount,
+            outstanding_amount,
+        },
     )
     .await
 }
@@ -292,17 +427,16 @@ pub async fn notify_review_received(
     create_notification(
         db,
         sub_user_id,
-        NotificationType::ReviewReceived,
         &format!("New review from {}", reviewer_name),
         Some(&format!(
             "{} left you a {:.1}-star review. View and respond to this feedback.",
             reviewer_name, rating
         )),
-        Some(serde_json::json!({
-            "review_id": review_id,
-            "reviewer_name": reviewer_name,
-            "rating": rating,
-        })),
+        NotificationData::ReviewReceived {
+            review_id,
+            reviewer_name: reviewer_name.to_string(),
+            rating,
+        },
     )
     .await
 }
@@ -312,10 +446,37 @@ pub async fn notify_profile_verified(db: &PgPool, sub_user_id: Uuid) -> Result<U
     create_notification(
         db,
         sub_user_id,
-        NotificationType::ProfileVerified,
         "Your profile has been verified!",
         Some("Congratulations! Your profile has been verified. You now have a verification badge visible to GCs."),
-        None,
+        NotificationData::ProfileVerified {},
+    )
+    .await
+}
+
+/// Create a notification when a user's admin privileges are granted or revoked
+pub async fn notify_admin_role_changed(
+    db: &PgPool,
+    user_id: Uuid,
+    granted: bool,
+) -> Result<Uuid, sqlx::Error> {
+    let (title, message) = if granted {
+        (
+            "You have been granted admin access",
+            "An administrator granted you access to the admin panel.",
+        )
+    } else {
+        (
+            "Your admin access has been revoked",
+            "An administrator removed your access to the admin panel.",
+        )
+    };
+
+    create_notification(
+        db,
+        user_id,
+        title,
+        Some(message),
+        NotificationData::AdminRoleChanged { is_admin: granted },
     )
     .await
 }
@@ -329,38 +490,315 @@ pub async fn notify_profile_rejected(
     create_notification(
         db,
         sub_user_id,
-        NotificationType::ProfileRejected,
         "Profile verification not approved",
         Some(&format!(
             "Your profile verification was not approved. Reason: {}. Please update your profile and resubmit.",
             reason
         )),
-        Some(serde_json::json!({
-            "reason": reason,
-        })),
+        NotificationData::ProfileRejected {
+            reason: reason.to_string(),
+        },
     )
     .await
 }
 
-/// Create a new message notification
+/// Window within which new hire messages are coalesced into one unread notification
+pub const MESSAGE_COALESCE_WINDOW_SECS: i64 = 600;
+
+/// Maximum characters of the message body included in the notification
+const MESSAGE_SNIPPET_CHARS: usize = 140;
+
+/// Create (or coalesce into) a new message notification for a hire request.
+///
+/// Skips delivery if the recipient has turned off message alerts. If the recipient
+/// already has an unread message notification for the same hire request created
+/// within [`MESSAGE_COALESCE_WINDOW_SECS`], that notification is updated to
+/// "N new messages" instead of creating another one, and the recipient's live
+/// stream gets an `updated` event.
 pub async fn notify_new_message(
     db: &PgPool,
     recipient_user_id: Uuid,
     hire_request_id: Uuid,
     sender_name: &str,
-) -> Result<Uuid, sqlx::Error> {
+    message: &str,
+) -> Result<Option<Uuid>, sqlx::Error> {
+    let settings: Option<serde_json::Value> =
+        sqlx::query_scalar("SELECT notification_settings FROM user_settings WHERE user_id = $1")
+            .bind(recipient_user_id)
+            .fetch_optional(db)
+            .await?;
+
+    let enabled = settings
+        .and_then(|v| serde_json::from_value::<NotificationSettings>(v).ok())
+        .map(|s| s.message_alerts)
+        .unwrap_or(true);
+
+    if !enabled {
+        return Ok(None);
+    }
+
+    let snippet = if message.chars().count() > MESSAGE_SNIPPET_CHARS {
+        let truncated: String = message.chars().take(MESSAGE_SNIPPET_CHARS).collect();
+        format!("{}…", truncated.trim_end())
+    } else {
+        message.to_string()
+    };
+
+    // Coalesce with a recent unread notification for the same conversation
+    let existing: Option<(Uuid, i64)> = sqlx::query_as(
+        r#"
+        SELECT id, COALESCE((data->>'message_count')::bigint, 1)
+        FROM notifications
+        WHERE user_id = $1
+        AND type = $2
+        AND is_read = false
+        AND data->>'hire_request_id' = $3
+        AND created_at > NOW() - make_interval(secs => $4)
+        ORDER BY created_at DESC
+        LIMIT 1
+        "#,
+    )
+    .bind(recipient_user_id)
+    .bind(NotificationType::NewMessage.to_string())
+    .bind(hire_request_id.to_string())
+    .bind(MESSAGE_COALESCE_WINDOW_SECS as f64)
+    .fetch_optional(db)
+    .await?;
+
+    if let Some((id, count)) = existing {
+        let count = count + 1;
+        let notification: Notification = sqlx::query_as(
+            r#"
+            UPDATE notifications
+            SET title = $2, message = $3, data = $4, created_at = NOW()
+            WHERE id = $1
+            RETURNING id, user_id, type, title, message, data, is_read, read_at, created_at
+            "#,
+        )
+        .bind(id)
+        .bind(format!("{} new messages from {}", count, sender_name))
+        .bind(&snippet)
+        .bind(
+            NotificationData::NewMessage {
+                hire_request_id,
+                sender_name: sender_name.to_string(),
+                snippet: snippet.clone(),
+                message_count: count,
+            }
+            .to_stored(),
+        )
+        .fetch_one(db)
+        .await?;
+
+        // Still the same unread notification, so the count is unchanged
+        notification_stream::publish_to(
+            recipient_user_id,
+            NotificationStreamEvent::Updated {
+                notification: NotificationResponse::from(notification),
+                unread_delta: 0,
+            },
+        )
+        .await;
+
+        return Ok(Some(id));
+    }
+
     create_notification(
         db,
         recipient_user_id,
-        NotificationType::NewMessage,
         &format!("New message from {}", sender_name),
-        Some(&format!("{} sent you a message.", sender_name)),
-        Some(serde_json::json!({
-            "hire_request_id": hire_request_id,
-            "sender_name": sender_name,
-        })),
+        Some(&snippet),
+        NotificationData::NewMessage {
+            hire_request_id,
+            sender_name: sender_name.to_string(),
+            snippet: snippet.clone(),
+            message_count: 1,
+        },
     )
     .await
+    .map(Some)
+}
+
+/// Create a notification inviting a subcontractor to bid on a tender
+pub async fn notify_tender_invitation(
+    db: &PgPool,
+    sub_user_id: Uuid,
+    tender_id: Uuid,
+    tender_title: &str,
+    gc_company_name: Option<&str>,
+) -> Result<Uuid, sqlx::Error> {
+    let inviter = gc_company_name.unwrap_or("A general contractor");
+    create_notification(
+        db,
+        sub_user_id,
+        "You've been invited to bid",
+        Some(&format!("{} invited you to bid on '{}'", inviter, tender_title)),
+        NotificationData::TenderInvitation {
+            tender_id,
+            tender_title: tender_title.to_string(),
+        },
+    )
+    .await
+}
+
+/// Tell a GC a subcontractor asked a question on their tender
+pub async fn notify_tender_question_asked(
+    db: &PgPool,
+    gc_user_id: Uuid,
+    tender_id: Uuid,
+    question_id: Uuid,
+    tender_title: &str,
+) -> Result<Uuid, sqlx::Error> {
+    create_notification(
+        db,
+        gc_user_id,
+        &format!("New question on {}", tender_title),
+        Some("A subcontractor asked a question about this tender. Answers are shared with every bidder."),
+        NotificationData::TenderQuestionAsked {
+            tender_id,
+            question_id,
+            tender_title: tender_title.to_string(),
+        },
+    )
+    .await
+}
+
+/// Tell bidders (and the asker) that a tender question was answered
+pub async fn notify_tender_question_answered(
+    db: &PgPool,
+    recipients: &[Uuid],
+    tender_id: Uuid,
+    question_id: Uuid,
+    tender_title: &str,
+) -> Result<Vec<Uuid>, sqlx::Error> {
+    let data = NotificationData::TenderQuestionAnswered {
+        tender_id,
+        question_id,
+        tender_title: tender_title.to_string(),
+    };
+    let message = format!("The GC posted an answer on '{}'. Review it before bidding.", tender_title);
+    let batch: Vec<NewNotification> = recipients
+        .iter()
+        .map(|user_id| NewNotification::new(*user_id, "Tender question answered", Some(message.clone()), data.clone()))
+        .collect();
+
+    notify_many(db, &batch).await
+}
+
+/// Tell an RFI's participants and watchers someone responded
+pub async fn notify_rfi_response_added(
+    db: &PgPool,
+    recipients: &[Uuid],
+    project_id: Uuid,
+    rfi_id: Uuid,
+    response_id: Uuid,
+    rfi_title: &str,
+    author_name: &str,
+) -> Result<Vec<Uuid>, sqlx::Error> {
+    let data = NotificationData::RfiResponseAdded {
+        project_id,
+        rfi_id,
+        response_id,
+        rfi_title: rfi_title.to_string(),
+        author_name: author_name.to_string(),
+    };
+    let message = format!("{} responded to '{}'.", author_name, rfi_title);
+    let batch: Vec<NewNotification> = recipients
+        .iter()
+        .map(|user_id| NewNotification::new(*user_id, "New RFI response", Some(message.clone()), data.clone()))
+        .collect();
+
+    notify_many(db, &batch).await
+}
+
+/// Tell an RFI's participants and watchers its status changed
+pub async fn notify_rfi_status_changed(
+    db: &PgPool,
+    recipients: &[Uuid],
+    project_id: Uuid,
+    rfi_id: Uuid,
+    rfi_title: &str,
+    status: &str,
+) -> Result<Vec<Uuid>, sqlx::Error> {
+    let data = NotificationData::RfiStatusChanged {
+        project_id,
+        rfi_id,
+        rfi_title: rfi_title.to_string(),
+        status: status.to_string(),
+    };
+    let message = format!("'{}' is now {}.", rfi_title, status);
+    let batch: Vec<NewNotification> = recipients
+        .iter()
+        .map(|user_id| NewNotification::new(*user_id, "RFI status changed", Some(message.clone()), data.clone()))
+        .collect();
+
+    notify_many(db, &batch).await
+}
+
+/// Tell a tender's watchers a bid came in (without its amount or bidder)
+pub async fn notify_tender_bid_placed(
+    db: &PgPool,
+    recipients: &[Uuid],
+    tender_id: Uuid,
+    tender_title: &str,
+) -> Result<Vec<Uuid>, sqlx::Error> {
+    let data = NotificationData::TenderBidPlaced {
+        tender_id,
+        tender_title: tender_title.to_string(),
+    };
+    let message = format!("A new bid was placed on '{}'.", tender_title);
+    let batch: Vec<NewNotification> = recipients
+        .iter()
+        .map(|user_id| NewNotification::new(*user_id, "New bid on a tender you follow", Some(message.clone()), data.clone()))
+        .collect();
+
+    notify_many(db, &batch).await
+}
+
+/// Tell a tender's watchers its status changed
+pub async fn notify_tender_status_changed(
+    db: &PgPool,
+    recipients: &[Uuid],
+    tender_id: Uuid,
+    tender_title: &str,
+    status: &str,
+) -> Result<Vec<Uuid>, sqlx::Error> {
+    let data = NotificationData::TenderStatusChanged {
+        tender_id,
+        tender_title: tender_title.to_string(),
+        status: status.to_string(),
+    };
+    let message = format!("'{}' is now {}.", tender_title, status);
+    let batch: Vec<NewNotification> = recipients
+        .iter()
+        .map(|user_id| NewNotification::new(*user_id, "Tender status changed", Some(message.clone()), data.clone()))
+        .collect();
+
+    notify_many(db, &batch).await
+}
+
+/// Tell an RFI's participants which response resolved it
+pub async fn notify_rfi_response_accepted(
+    db: &PgPool,
+    recipients: &[Uuid],
+    project_id: Uuid,
+    rfi_id: Uuid,
+    response_id: Uuid,
+    rfi_title: &str,
+) -> Result<Vec<Uuid>, sqlx::Error> {
+    let data = NotificationData::RfiResponseAccepted {
+        project_id,
+        rfi_id,
+        response_id,
+        rfi_title: rfi_title.to_string(),
+    };
+    let message = format!("A response was accepted as the answer to '{}'.", rfi_title);
+    let batch: Vec<NewNotification> = recipients
+        .iter()
+        .map(|user_id| NewNotification::new(*user_id, "RFI answered", Some(message.clone()), data.clone()))
+        .collect();
+
+    notify_many(db, &batch).await
 }
 
 /// Create a tender closing soon notification for interested subcontractors
@@ -374,17 +812,34 @@ pub async fn notify_tender_closing_soon(
     create_notification(
         db,
         sub_user_id,
-        NotificationType::TenderClosingSoon,
         &format!("Tender closing in {} hours", hours_remaining),
         Some(&format!(
             "The tender '{}' is closing in {} hours. Submit or update your bid now!",
             tender_title, hours_remaining
         )),
-        Some(serde_json::json!({
-            "tender_id": tender_id,
-            "tender_title": tender_title,
-            "hours_remaining": hours_remaining,
-        })),
+        NotificationData::TenderClosingSoon {
+            tender_id,
+            tender_title: tender_title.to_string(),
+            hours_remaining,
+        },
+    )
+    .await
+}
+
+/// Create a notification when a document processing job fails
+pub async fn notify_job_failed(
+    db: &PgPool,
+    user_id: Uuid,
+    job_id: Uuid,
+    project_id: Uuid,
+    reason: &str,
+) -> Result<Uuid, sqlx::Error> {
+    create_notification(
+        db,
+        user_id,
+        "Document processing failed",
+        Some(reason),
+        NotificationData::JobFailed { job_id, project_id },
     )
     .await
 }
@@ -399,51 +854,109 @@ pub async fn notify_system(
     create_notification(
         db,
         user_id,
-        NotificationType::System,
         title,
         Some(message),
-        None,
+        NotificationData::System {},
     )
     .await
 }
 
-/// Batch create notifications for multiple users
+/// Create the same notification for multiple users
 pub async fn create_notifications_batch(
     db: &PgPool,
     user_ids: &[Uuid],
-    notification_type: NotificationType,
     title: &str,
     message: Option<&str>,
-    data: Option<serde_json::Value>,
+    data: NotificationData,
 ) -> Result<Vec<Uuid>, sqlx::Error> {
-    let type_str = notification_type.to_string();
-    let data = data.unwrap_or(serde_json::json!({}));
-    let mut ids = Vec::with_capacity(user_ids.len());
+    let notifications: Vec<NewNotification> = user_ids
+        .iter()
+        .map(|user_id| NewNotification::new(*user_id, title, message.map(str::to_string), data.clone()))
+        .collect();
+
+    notify_many(db, &notifications).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    for user_id in user_ids {
-        let id = Uuid::new_v4();
-        sqlx::query(
+    use crate::test_support;
+
+    #[tokio::test]
+    async fn batch_is_inserted_in_one_statement() {
+        let Some(db) = test_support::db().await else { return };
+        let tender_id = Uuid::new_v4();
+        let mut recipients = Vec::new();
+        for _ in 0..5 {
+            recipients.push(test_support::user(&db, "sub").await);
+        }
+        let batch: Vec<NewNotification> = recipients
+            .iter()
+            .map(|&user_id| {
+                NewNotification::new(
+                    user_id,
+                    "Bid not selected",
+                    None,
+                    NotificationData::BidRejected { tender_id, tender_title: "Test tender".to_string() },
+                )
+            })
+            .collect();
+
+        let ids = notify_many(&db, &batch).await.unwrap();
+        assert_eq!(ids.len(), 5);
+
+        // Rows written by one statement share a transaction id and command id
+        let (rows, transactions, commands, users): (i64, i64, i64, Vec<Uuid>) = sqlx::query_as(
             r#"
-            INSERT INTO notifications (id, user_id, type, title, message, data)
-            VALUES ($1, $2, $3, $4, $5, $6)
+            SELECT COUNT(*), COUNT(DISTINCT xmin::text), COUNT(DISTINCT cmin::text),
+                   array_agg(user_id ORDER BY array_position($1, id))
+            FROM notifications WHERE id = ANY($1)
             "#,
         )
-        .bind(id)
-        .bind(user_id)
-        .bind(&type_str)
-        .bind(title)
-        .bind(message)
-        .bind(&data)
-        .execute(db)
-        .await?;
-        ids.push(id);
+        .bind(&ids)
+        .fetch_one(&db)
+        .await
+        .unwrap();
+        assert_eq!(rows, 5);
+        assert_eq!(transactions, 1);
+        assert_eq!(commands, 1);
+        assert_eq!(users, recipients);
     }
 
-    tracing::info!(
-        count = user_ids.len(),
-        notification_type = %type_str,
-        "Batch notifications created"
-    );
+    async fn next_event(
+        events: &mut (impl futures::Stream<Item = (String, String)> + Unpin),
+    ) -> (String, serde_json::Value) {
+        use futures::StreamExt;
 
-    Ok(ids)
+        let (name, payload) = tokio::time::timeout(std::time::Duration::from_secs(5), events.next())
+            .await
+            .expect("stream event")
+            .unwrap();
+        (name, serde_json::from_str(&payload).unwrap())
+    }
+
+    #[tokio::test]
+    async fn coalesced_message_is_published_as_an_update() {
+        let Some(db) = test_support::db().await else { return };
+        let Some(cache) = test_support::cache().await else { return };
+        notification_stream::install(cache.clone());
+        let recipient = test_support::user(&db, "gc").await;
+        let hire_request_id = Uuid::new_v4();
+        let mut events = Box::pin(notification_stream::subscribe(&cache, recipient).await.unwrap());
+
+        let first = notify_new_message(&db, recipient, hire_request_id, "Test Electric", "Hello").await.unwrap();
+        let second = notify_new_message(&db, recipient, hire_request_id, "Test Electric", "Any update?").await.unwrap();
+        assert_eq!(first, second);
+
+        let (name, created) = next_event(&mut events).await;
+        assert_eq!(name, "created");
+        assert_eq!(created["unread_delta"], 1);
+
+        let (name, updated) = next_event(&mut events).await;
+        assert_eq!(name, "updated");
+        assert_eq!(updated["unread_delta"], 0);
+        assert_eq!(updated["notification"]["id"], serde_json::json!(first));
+        assert_eq!(updated["notification"]["title"], "2 new messages from Test Electric");
+    }
 }
@@ -0,0 +1,146 @@
+//! PDF sniffing and page counting for document uploads.
+//!
+//! Only as much of the file structure is read as is needed to tell a PDF
+//! apart from other content and to estimate processing work; the AI service
+//! does the real parsing.
+
+use flate2::read::ZlibDecoder;
+use std::io::Read;
+
+/// Signature every PDF starts with (a few leading bytes of junk are tolerated).
+const PDF_MAGIC: &[u8] = b"%PDF-";
+
+/// How far into the file the signature may appear.
+const MAGIC_SEARCH_BYTES: usize = 1024;
+
+/// Most bytes inflated from a single object stream.
+const MAX_INFLATED_BYTES: u64 = 16 * 1024 * 1024;
+
+/// Whether the bytes are a PDF, judged by the file signature.
+pub fn is_pdf(data: &[u8]) -> bool {
+    let head = &data[..data.len().min(MAGIC_SEARCH_BYTES)];
+    find(head, PDF_MAGIC, 0).is_some()
+}
+
+/// Number of pages in a PDF, or `None` when it cannot be determined.
+///
+/// Counts `/Type /Page` objects, including those packed into compressed
+/// object streams (PDF 1.5+). Call it from `spawn_blocking` for large files.
+pub fn page_count(data: &[u8]) -> Option<u32> {
+    let mut pages = count_page_objects(data);
+
+    let mut pos = 0;
+    while let Some(at) = find(data, b"/ObjStm", pos) {
+        pos = at + 1;
+        let Some(stream) = stream_after(data, at) else {
+            continue;
+        };
+        let mut inflated = Vec::new();
+        if ZlibDecoder::new(stream)
+            .take(MAX_INFLATED_BYTES)
+            .read_to_end(&mut inflated)
+            .is_ok()
+        {
+            pages += count_page_objects(&inflated);
+        }
+    }
+
+    (pages > 0).then_some(pages)
+}
+
+/// Count `/Type /Page` entries, skipping `/Type /Pages` tree nodes.
+fn count_page_objects(data: &[u8]) -> u32 {
+    let mut count = 0;
+    let mut pos = 0;
+    while let Some(at) = find(data, b"/Type", pos) {
+        pos = at + 5;
+        let mut i = pos;
+        while data.get(i).is_some_and(|b| b.is_ascii_whitespace()) {
+            i += 1;
+        }
+        if data[i..].starts_with(b"/Page") && !data.get(i + 5).is_some_and(|b| b.is_ascii_alphanumeric()) {
+            count += 1;
+        }
+    }
+    count
+}
+
+/// Raw bytes of the first stream body starting after `from`.
+fn stream_after(data: &[u8], from: usize) -> Option<&[u8]> {
+    let start = find(data, b"stream", from)? + b"stream".len();
+    let start = match data.get(start..start + 2) {
+        Some(b"\r\n") => start + 2,
+        _ if data.get(start) == Some(&b'\n') => start + 1,
+        _ => start,
+    };
+    let end = find(data, b"endstream", start)?;
+    Some(&data[start..end])
+}
+
+fn find(haystack: &[u8], needle: &[u8], from: usize) -> Option<usize> {
+    haystack
+        .get(from..)?
+        .windows(needle.len())
+        .position(|w| w == needle)
+        .map(|p| p + from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use flate2::write::ZlibEncoder;
+    use flate2::Compression;
+    use std::io::Write;
+
+    /// A minimal PDF with `pages` page objects under one page tree
+    fn fake_pdf(pages: usize) -> Vec<u8> {
+        let mut pdf = b"%PDF-1.7\n1 0 obj << /Type /Pages /Count 0 >> endobj\n".to_vec();
+        for i in 0..pages {
+            pdf.extend(format!("{} 0 obj << /Type /Page /Parent 1 0 R >> endobj\n", i + 2).bytes());
+        }
+        pdf.extend(b"%%EOF\n");
+        pdf
+    }
+
+    #[test]
+    fn fake_pdf_is_sniffed_and_counted() {
+        let pdf = fake_pdf(3);
+        assert!(is_pdf(&pdf));
+        assert_eq!(page_count(&pdf), Some(3));
+    }
+
+    #[test]
+    fn pages_in_compressed_object_streams_are_counted() {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(b"<< /Type /Page >> << /Type/Page >> << /Type /Pages >>").unwrap();
+        let stream = encoder.finish().unwrap();
+
+        let mut pdf = fake_pdf(1);
+        pdf.extend(b"9 0 obj << /Type /ObjStm /Filter /FlateDecode >>\nstream\r\n");
+        pdf.extend(&stream);
+        pdf.extend(b"\r\nendstream\nendobj\n");
+
+        assert_eq!(page_count(&pdf), Some(3));
+    }
+
+    #[test]
+    fn signature_after_leading_junk_is_accepted() {
+        let mut pdf = b"\xef\xbb\xbf\r\n".to_vec();
+        pdf.extend(fake_pdf(1));
+        assert!(is_pdf(&pdf));
+    }
+
+    #[test]
+    fn mislabeled_file_is_not_a_pdf() {
+        // A PNG saved as plan.pdf
+        let png = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR\0\0\0\x01\0\0\0\x01\x08\x06\0\0\0";
+        assert!(!is_pdf(png));
+        assert_eq!(page_count(png), None);
+
+        // The signature only counts near the start of the file
+        let mut late = vec![b' '; MAGIC_SEARCH_BYTES];
+        late.extend(fake_pdf(1));
+        assert!(!is_pdf(&late));
+    }
+}
@@ -0,0 +1,173 @@
+//! Single-flight for expensive cache misses
+//!
+//! When a cached AI result expires, every request for it misses at once and
+//! each would pay for the same slow AI call. Instead the first request takes
+//! a Redis lock on the cache key and computes the result; the others poll the
+//! cache until it appears. The lock lives in Redis so requests coalesce
+//! across instances.
+//!
+//! The lock expires on its own if its holder dies. A waiter that sees the
+//! lock released without a cached result (the holder failed), or that gives
+//! up waiting, computes independently, as does everyone if Redis is
+//! unreachable.
+
+use serde::de::DeserializeOwned;
+use std::future::Future;
+use std::time::Duration;
+use tokio::time::{sleep, Instant};
+use uuid::Uuid;
+
+use crate::error::ApiError;
+use crate::services::cache::{keys, RedisCache};
+
+/// How often waiters check the cache
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Slack on top of the computation's own timeout before the lock expires
+const LOCK_MARGIN: Duration = Duration::from_secs(5);
+
+/// Result of [`run`]
+pub enum Flight<T, R> {
+    /// Another request computed and cached the value while this one waited
+    Shared(T),
+    /// This request ran the computation
+    Computed(R),
+}
+
+/// Run `compute` for `cache_key` unless another request is already doing so,
+/// in which case wait (up to `timeout`) for it to cache its result of type
+/// `T`. `compute` is expected to cache its result under `cache_key`; `timeout`
+/// should be how long it can take.
+pub async fn run<T, R, F>(
+    cache: &RedisCache,
+    cache_key: &str,
+    timeout: Duration,
+    compute: F,
+) -> Result<Flight<T, R>, ApiError>
+where
+    T: DeserializeOwned,
+    F: Future<Output = Result<R, ApiError>>,
+{
+    let lock_key = keys::single_flight_lock(cache_key);
+    let token = Uuid::new_v4().to_string();
+
+    match cache.set_nx(&lock_key, &token, timeout + LOCK_MARGIN).await {
+        Ok(true) => {
+            let result = compute.await;
+            if let Err(e) = cache.delete_if_eq(&lock_key, &token).await {
+                tracing::warn!(error = %e, key = cache_key, "Failed to release single-flight lock");
+            }
+            return result.map(Flight::Computed);
+        }
+        Ok(false) => {
+            if let Some(value) = wait(cache, cache_key, &lock_key, timeout).await {
+                return Ok(Flight::Shared(value));
+            }
+        }
+        Err(e) => {
+            tracing::warn!(error = %e, key = cache_key, "Single-flight lock unavailable; computing independently");
+        }
+    }
+
+    compute.await.map(Flight::Computed)
+}
+
+/// Poll for the lock holder's result. `None` if it released the lock without
+/// caching one, or did not finish in time.
+async fn wait<T: DeserializeOwned>(
+    cache: &RedisCache,
+    cache_key: &str,
+    lock_key: &str,
+    timeout: Duration,
+) -> Option<T> {
+    let deadline = Instant::now() + timeout;
+
+    while Instant::now() < deadline {
+        sleep(POLL_INTERVAL).await;
+
+        if let Some(value) = cache.get::<T>(cache_key).await {
+            tracing::debug!(key = cache_key, "Single-flight result shared");
+            return Some(value);
+        }
+        if !cache.exists(lock_key).await.unwrap_or(false) {
+            // Check once more: the holder may have cached and released
+            // between the two reads
+            return cache.get::<T>(cache_key).await;
+        }
+    }
+
+    tracing::warn!(key = cache_key, "Timed out waiting for single-flight result; computing independently");
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use futures::future::join_all;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use crate::test_support;
+
+    const CALLERS: usize = 8;
+
+    /// Run `CALLERS` concurrent flights for one key. Each computation counts
+    /// itself, takes a moment, then caches "result" unless `fail` is set.
+    async fn concurrent_flights(cache: &RedisCache, fail: bool) -> (usize, Vec<Result<Flight<String, String>, ApiError>>) {
+        let cache_key = format!("test:single-flight:{}", Uuid::new_v4());
+        let computations = Arc::new(AtomicUsize::new(0));
+
+        let flights = (0..CALLERS).map(|_| {
+            let cache = cache.clone();
+            let cache_key = cache_key.clone();
+            let computations = computations.clone();
+            async move {
+                let compute = async {
+                    computations.fetch_add(1, Ordering::SeqCst);
+                    sleep(Duration::from_millis(500)).await;
+                    if fail {
+                        return Err(ApiError::service_unavailable("AI service failed"));
+                    }
+                    cache.set_with_ttl(&cache_key, &"result", Duration::from_secs(60)).await.unwrap();
+                    Ok("result".to_string())
+                };
+                run::<String, String, _>(&cache, &cache_key, Duration::from_secs(5), compute).await
+            }
+        });
+        let results = join_all(flights).await;
+
+        let _ = cache.delete(&cache_key).await;
+        (computations.load(Ordering::SeqCst), results)
+    }
+
+    #[tokio::test]
+    async fn concurrent_misses_compute_once() {
+        let Some(cache) = test_support::cache().await else { return };
+
+        let (computations, results) = concurrent_flights(&cache, false).await;
+
+        assert_eq!(computations, 1);
+        let mut computed = 0;
+        for result in results {
+            match result.unwrap() {
+                Flight::Computed(value) => {
+                    computed += 1;
+                    assert_eq!(value, "result");
+                }
+                Flight::Shared(value) => assert_eq!(value, "result"),
+            }
+        }
+        assert_eq!(computed, 1);
+    }
+
+    #[tokio::test]
+    async fn waiters_compute_themselves_when_the_holder_fails() {
+        let Some(cache) = test_support::cache().await else { return };
+
+        let (computations, results) = concurrent_flights(&cache, true).await;
+
+        assert_eq!(computations, CALLERS);
+        assert!(results.iter().all(Result::is_err));
+    }
+}
@@ -0,0 +1,99 @@
+//! Object storage for user-uploaded media (avatars, logos, images).
+//!
+//! Objects are addressed by a slash-separated key and written beneath a root
+//! directory that is served publicly at `public_url`.
+
+use std::path::{Component, Path, PathBuf};
+use tokio::fs;
+
+use crate::error::ApiError;
+
+/// Handle to the media object store.
+#[derive(Clone, Debug)]
+pub struct ObjectStorage {
+    root: PathBuf,
+    public_url: String,
+}
+
+impl ObjectStorage {
+    pub fn new(root: impl Into<PathBuf>, public_url: &str) -> Self {
+        Self {
+            root: root.into(),
+            public_url: public_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Root directory objects are written to.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Store `bytes` under `key`, replacing any existing object.
+    pub async fn put(&self, key: &str, bytes: &[u8]) -> Result<(), ApiError> {
+        let path = self.path_for(key)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .await
+                .map_err(|e| ApiError::internal(format!("Failed to create storage directory: {}", e)))?;
+        }
+        fs::write(&path, bytes)
+            .await
+            .map_err(|e| ApiError::internal(format!("Failed to write object: {}", e)))
+    }
+
+    /// Delete the object at `key`. Missing objects are not an error.
+    pub async fn delete(&self, key: &str) -> Result<(), ApiError> {
+        let path = self.path_for(key)?;
+        match fs::remove_file(&path).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(ApiError::internal(format!("Failed to delete object: {}", e))),
+        }
+    }
+
+    /// Delete several objects, logging rather than returning failures.
+    ///
+    /// Used to clean up replaced or orphaned uploads where the caller has
+    /// already committed to the new state.
+    pub async fn delete_best_effort(&self, keys: &[Option<&str>]) {
+        for key in keys.iter().flatten() {
+            if let Err(e) = self.delete(key).await {
+                tracing::warn!(key = %key, error = %e, "Failed to delete stored object");
+            }
+        }
+    }
+
+    /// Public URL the object at `key` is served from.
+    pub fn url(&self, key: &str) -> String {
+        format!("{}/{}", self.public_url, key)
+    }
+
+    /// Resolve a stored value that is either an object key or an external URL.
+    pub fn resolve_url(&self, key_or_url: &str) -> String {
+        if is_external_url(key_or_url) {
+            key_or_url.to_string()
+        } else {
+            self.url(key_or_url)
+        }
+    }
+
+    /// Object key for a URL previously returned by `url`, if it is one.
+    pub fn key_from_url<'a>(&self, url: &'a str) -> Option<&'a str> {
+        url.strip_prefix(self.public_url.as_str())?
+            .strip_prefix('/')
+            .filter(|key| !key.is_empty())
+    }
+
+    fn path_for(&self, key: &str) -> Result<PathBuf, ApiError> {
+        let relative = Path::new(key);
+        if key.is_empty() || !relative.components().all(|c| matches!(c, Component::Normal(_))) {
+            return Err(ApiError::internal(format!("Invalid object key: {}", key)));
+        }
+        Ok(self.root.join(relative))
+    }
+}
+
+/// Whether a stored value is an external URL rather than an object key.
+pub fn is_external_url(value: &str) -> bool {
+    value.contains("://")
+}
@@ -0,0 +1,145 @@
+//! Supabase Auth session revocation
+//!
+//! Revoking a session here only stops the gateway from refreshing it; the
+//! refresh token would still work sent straight to Supabase with the public
+//! anon key. These calls end the session on Supabase's side as well.
+
+use reqwest::{Client, StatusCode};
+use uuid::Uuid;
+
+use crate::config::Settings;
+use crate::error::ApiError;
+
+/// Client for the Supabase Auth endpoints that end sessions
+#[derive(Clone)]
+pub struct SupabaseAuth {
+    client: Client,
+    base_url: String,
+    anon_key: String,
+    service_role_key: String,
+}
+
+impl SupabaseAuth {
+    pub fn new(client: Client, settings: &Settings) -> Self {
+        Self {
+            client,
+            base_url: settings.supabase_url.trim_end_matches('/').to_string(),
+            anon_key: settings.supabase_anon_key.clone(),
+            service_role_key: settings.supabase_service_role_key.clone(),
+        }
+    }
+
+    /// End every session of the access token's user except the token's own
+    pub async fn sign_out_others(&self, access_token: &str) -> Result<(), ApiError> {
+        let response = self
+            .client
+            .post(format!("{}/auth/v1/logout", self.base_url))
+            .query(&[("scope", "others")])
+            .header("apikey", &self.anon_key)
+            .bearer_auth(access_token)
+            .send()
+            .await
+            .map_err(|e| unavailable("sign out other sessions", e))?;
+
+        check_status(response.status(), "sign out other sessions")
+    }
+
+    /// End the Supabase session `auth_session_id` (the access token's
+    /// `session_id` claim) with the service role. A session Supabase no
+    /// longer has counts as ended.
+    pub async fn end_session(&self, auth_session_id: Uuid) -> Result<(), ApiError> {
+        let response = self
+            .client
+            .delete(format!("{}/auth/v1/admin/sessions/{}", self.base_url, auth_session_id))
+            .header("apikey", &self.service_role_key)
+            .bearer_auth(&self.service_role_key)
+            .send()
+            .await
+            .map_err(|e| unavailable("end session", e))?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(());
+        }
+        check_status(response.status(), "end session")
+    }
+}
+
+fn unavailable(action: &str, error: impl std::fmt::Display) -> ApiError {
+    tracing::warn!(error = %error, "Auth service failed to {}", action);
+    ApiError::service_unavailable("Auth service is unavailable")
+}
+
+fn check_status(status: StatusCode, action: &str) -> Result<(), ApiError> {
+    if status.is_success() {
+        Ok(())
+    } else {
+        Err(unavailable(action, status))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{extract::Path, http::HeaderMap, routing::{delete, post}, Router};
+    use std::collections::HashMap;
+
+    /// Serve `router` on a local port and return a client for it
+    async fn auth_service(router: Router) -> SupabaseAuth {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+
+        let mut settings = crate::test_support::settings();
+        settings.supabase_url = format!("http://{}", addr);
+        settings.supabase_service_role_key = "service-role".to_string();
+        SupabaseAuth::new(Client::new(), &settings)
+    }
+
+    #[tokio::test]
+    async fn sign_out_others_uses_the_callers_token() {
+        let router = Router::new().route(
+            "/auth/v1/logout",
+            post(|axum::extract::Query(query): axum::extract::Query<HashMap<String, String>>, headers: HeaderMap| async move {
+                let authorized = headers.get("authorization").is_some_and(|v| v == "Bearer user-token");
+                if authorized && query.get("scope").map(String::as_str) == Some("others") {
+                    StatusCode::NO_CONTENT
+                } else {
+                    StatusCode::BAD_REQUEST
+                }
+            }),
+        );
+        let auth = auth_service(router).await;
+
+        auth.sign_out_others("user-token").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn end_session_uses_the_service_role() {
+        let ended = Uuid::new_v4();
+        let router = Router::new().route(
+            "/auth/v1/admin/sessions/:id",
+            delete(move |Path(id): Path<Uuid>, headers: HeaderMap| async move {
+                let authorized = headers.get("authorization").is_some_and(|v| v == "Bearer service-role");
+                match (authorized, id == ended) {
+                    (false, _) => StatusCode::UNAUTHORIZED,
+                    (true, true) => StatusCode::NO_CONTENT,
+                    (true, false) => StatusCode::NOT_FOUND,
+                }
+            }),
+        );
+        let auth = auth_service(router).await;
+
+        auth.end_session(ended).await.unwrap();
+        // Already gone at Supabase
+        auth.end_session(Uuid::new_v4()).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn failed_revocation_is_an_error() {
+        let router = Router::new().route("/auth/v1/logout", post(|| async { StatusCode::INTERNAL_SERVER_ERROR }));
+        let auth = auth_service(router).await;
+
+        let err = auth.sign_out_others("user-token").await.unwrap_err();
+        assert!(matches!(err, ApiError::ServiceUnavailable(_)), "{:?}", err);
+    }
+}
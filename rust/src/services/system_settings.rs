@@ -0,0 +1,56 @@
+//! Admin-managed platform settings
+//!
+//! Reader and writer for the `system_settings` key/value table. Values are
+//! JSONB; each key has a typed shape in `domain::admin` and falls back to its
+//! default when the row is missing or unreadable.
+
+use serde::{de::DeserializeOwned, Serialize};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::admin::VerificationRequirements;
+
+/// Fields a subcontractor profile must have before requesting verification
+pub const VERIFICATION_REQUIREMENTS: &str = "verification_requirements";
+
+/// Load a setting, or `T::default()` when it is unset or malformed
+pub async fn get<T: DeserializeOwned + Default>(db: &PgPool, key: &str) -> Result<T, sqlx::Error> {
+    let value: Option<serde_json::Value> =
+        sqlx::query_scalar("SELECT value FROM system_settings WHERE key = $1")
+            .bind(key)
+            .fetch_optional(db)
+            .await?;
+
+    Ok(value
+        .and_then(|v| match serde_json::from_value(v) {
+            Ok(parsed) => Some(parsed),
+            Err(e) => {
+                tracing::warn!(error = %e, key, "Ignoring malformed system setting");
+                None
+            }
+        })
+        .unwrap_or_default())
+}
+
+/// Store a setting, replacing any previous value
+pub async fn set<T: Serialize>(db: &PgPool, key: &str, value: &T, updated_by: Uuid) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO system_settings (key, value, updated_by, updated_at)
+        VALUES ($1, $2, $3, NOW())
+        ON CONFLICT (key) DO UPDATE
+        SET value = EXCLUDED.value, updated_by = EXCLUDED.updated_by, updated_at = NOW()
+        "#,
+    )
+    .bind(key)
+    .bind(serde_json::to_value(value).unwrap_or_default())
+    .bind(updated_by)
+    .execute(db)
+    .await?;
+    Ok(())
+}
+
+/// Current verification requirements
+pub async fn verification_requirements(db: &PgPool) -> Result<VerificationRequirements, sqlx::Error> {
+    get(db, VERIFICATION_REQUIREMENTS).await
+}
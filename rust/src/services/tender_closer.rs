@@ -0,0 +1,141 @@
+//! Expired tender closer
+//!
+//! Background task that closes `open` tenders whose bid due date has passed,
+//! so the stored status agrees with the deadline the marketplace filters and
+//! bid endpoints already enforce. Bidders get the same "tender closed"
+//! notification as a manual close. A transaction-scoped advisory lock ensures
+//! only one API instance closes tenders per tick.
+
+use sqlx::PgPool;
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::domain::notifications::NotificationData;
+use crate::services::cache::{keys as cache_keys, RedisCache};
+use crate::services::notifications;
+use crate::services::watchers::{self, WatchedResource};
+
+/// Advisory lock key for the closer ("BPXTCLS" in ASCII, fits in an i64)
+const CLOSER_LOCK_KEY: i64 = 0x0042_5058_5443_4C53;
+
+/// A tender closed by a closer pass
+#[derive(Debug, sqlx::FromRow)]
+struct ClosedTender {
+    id: Uuid,
+    name: String,
+    project_id: Uuid,
+    owner_id: Uuid,
+}
+
+/// Spawn the closer loop
+pub fn spawn(db: PgPool, cache: RedisCache, interval: Duration) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            match close_expired_tenders(&db, &cache).await {
+                Ok(0) => {}
+                Ok(closed) => tracing::info!(closed, "Closed tenders past their bid due date"),
+                Err(e) => tracing::warn!(error = %e, "Tender closer pass failed"),
+            }
+        }
+    });
+
+    tracing::info!(
+        interval_seconds = interval.as_secs(),
+        "Tender closer started"
+    );
+}
+
+/// Close open tenders whose bid due date has passed and notify their bidders.
+/// Returns the number of tenders closed.
+pub async fn close_expired_tenders(db: &PgPool, cache: &RedisCache) -> Result<usize, sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    let locked: bool = sqlx::query_scalar("SELECT pg_try_advisory_xact_lock($1)")
+        .bind(CLOSER_LOCK_KEY)
+        .fetch_one(&mut *tx)
+        .await?;
+
+    if !locked {
+        return Ok(0);
+    }
+
+    let closed = sqlx::query_as::<_, ClosedTender>(
+        r#"
+        UPDATE tenders t SET status = 'closed', updated_at = NOW()
+        FROM projects p
+        WHERE t.status = 'open'
+        AND t.bid_due_date <= NOW()
+        AND p.id = t.project_id
+        RETURNING t.id, t.name, t.project_id, p.owner_id
+        "#,
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    for tender in &closed {
+        notify_bidders(db, tender).await;
+
+        let _ = cache.delete_pattern(&cache_keys::tender_list_pattern(tender.project_id)).await;
+        let _ = cache.delete_pattern(&cache_keys::tender_user_pattern(tender.owner_id)).await;
+        let _ = cache.delete(&cache_keys::dashboard_stats(tender.owner_id)).await;
+    }
+
+    Ok(closed.len())
+}
+
+/// Notify everyone with an active bid on the tender, and its watchers.
+/// Failures are logged.
+async fn notify_bidders(db: &PgPool, tender: &ClosedTender) {
+    let bidders: Vec<Uuid> = match sqlx::query_scalar(
+        r#"
+        SELECT DISTINCT COALESCE(b.bidder_id, s.profile_id) FROM bids b
+        LEFT JOIN subcontractors s ON b.subcontractor_id = s.id
+        WHERE b.tender_id = $1
+        AND COALESCE(b.bidder_id, s.profile_id) IS NOT NULL
+        AND b.status NOT IN ('draft', 'withdrawn')
+        "#,
+    )
+    .bind(tender.id)
+    .fetch_all(db)
+    .await
+    {
+        Ok(ids) => ids,
+        Err(e) => {
+            tracing::warn!(error = %e, tender_id = %tender.id, "Failed to load bidders to notify");
+            return;
+        }
+    };
+
+    let mut exclude = bidders.clone();
+    exclude.push(tender.owner_id);
+    let mut recipients = bidders;
+    match watchers::recipients(db, WatchedResource::Tender, tender.id, &[], &exclude).await {
+        Ok(tender_watchers) => recipients.extend(tender_watchers),
+        Err(e) => tracing::warn!(error = %e, tender_id = %tender.id, "Failed to load tender watchers"),
+    }
+
+    if recipients.is_empty() {
+        return;
+    }
+
+    if let Err(e) = notifications::create_notifications_batch(
+        db,
+        &recipients,
+        "Tender closed",
+        Some(&format!("'{}' is no longer accepting bids.", tender.name)),
+        NotificationData::TenderClosed {
+            tender_id: tender.id,
+            tender_title: tender.name.clone(),
+        },
+    )
+    .await
+    {
+        tracing::warn!(error = %e, tender_id = %tender.id, "Failed to notify bidders");
+    }
+}
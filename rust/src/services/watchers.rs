@@ -0,0 +1,109 @@
+//! Resource watchers
+//!
+//! Users follow an RFI or tender they don't own and are notified of its
+//! activity alongside the owner. Subscriptions live in the polymorphic
+//! `resource_watchers` table; deleting the RFI or tender removes them.
+//! Delivery honours the recipient's notification settings (`rfi_alerts` for
+//! RFIs, `bid_updates` for tenders).
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Kind of resource a user can watch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatchedResource {
+    Rfi,
+    Tender,
+}
+
+impl WatchedResource {
+    /// `resource_watchers.resource_type` value
+    pub fn as_str(self) -> &'static str {
+        match self {
+            WatchedResource::Rfi => "rfi",
+            WatchedResource::Tender => "tender",
+        }
+    }
+
+    /// `NotificationSettings` key that turns this resource's alerts off
+    fn preference_key(self) -> &'static str {
+        match self {
+            WatchedResource::Rfi => "rfi_alerts",
+            WatchedResource::Tender => "bid_updates",
+        }
+    }
+}
+
+/// Subscribe `user_id`; watching twice is a no-op
+pub async fn watch(db: &PgPool, resource: WatchedResource, resource_id: Uuid, user_id: Uuid) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO resource_watchers (resource_type, resource_id, user_id)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (resource_type, resource_id, user_id) DO NOTHING
+        "#,
+    )
+    .bind(resource.as_str())
+    .bind(resource_id)
+    .bind(user_id)
+    .execute(db)
+    .await?;
+
+    Ok(())
+}
+
+/// Unsubscribe `user_id`; returns whether they were watching
+pub async fn unwatch(db: &PgPool, resource: WatchedResource, resource_id: Uuid, user_id: Uuid) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query(
+        "DELETE FROM resource_watchers WHERE resource_type = $1 AND resource_id = $2 AND user_id = $3",
+    )
+    .bind(resource.as_str())
+    .bind(resource_id)
+    .bind(user_id)
+    .execute(db)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Number of users watching a resource
+pub async fn watcher_count(db: &PgPool, resource: WatchedResource, resource_id: Uuid) -> Result<i64, sqlx::Error> {
+    sqlx::query_scalar("SELECT COUNT(*) FROM resource_watchers WHERE resource_type = $1 AND resource_id = $2")
+        .bind(resource.as_str())
+        .bind(resource_id)
+        .fetch_one(db)
+        .await
+}
+
+/// Who to notify of activity on a resource: `participants` plus its watchers,
+/// without anyone in `exclude` (whoever caused it, or was already told) or
+/// who turned the resource's alerts off. Pass no participants to get just
+/// the watchers.
+pub async fn recipients(
+    db: &PgPool,
+    resource: WatchedResource,
+    resource_id: Uuid,
+    participants: &[Uuid],
+    exclude: &[Uuid],
+) -> Result<Vec<Uuid>, sqlx::Error> {
+    sqlx::query_scalar(
+        r#"
+        SELECT u.user_id FROM (
+            SELECT UNNEST($3::uuid[]) as user_id
+            UNION
+            SELECT user_id FROM resource_watchers WHERE resource_type = $1 AND resource_id = $2
+        ) u
+        LEFT JOIN user_settings us ON us.user_id = u.user_id
+        WHERE u.user_id <> ALL($4::uuid[])
+        AND COALESCE((us.notification_settings->>$5)::boolean, true)
+        ORDER BY u.user_id
+        "#,
+    )
+    .bind(resource.as_str())
+    .bind(resource_id)
+    .bind(participants)
+    .bind(exclude)
+    .bind(resource.preference_key())
+    .fetch_all(db)
+    .await
+}
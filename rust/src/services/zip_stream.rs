@@ -0,0 +1,213 @@
+//! Streaming ZIP writer
+//!
+//! Produces a ZIP archive as a sequence of byte chunks so it can be sent while
+//! it is built. Entries are deflated and written with a trailing data
+//! descriptor (general purpose flag bit 3), so neither sizes nor CRCs need to
+//! be known up front and nothing is seeked back over. Only the central
+//! directory (one small record per entry) is kept until the end.
+//!
+//! ZIP64 is not written: the archive, and every entry in it, must stay under
+//! 4 GiB, with at most 65,535 entries. Exceeding either fails the write
+//! rather than producing a corrupt archive.
+
+use std::io::{self, Write};
+
+use chrono::{DateTime, Datelike, Timelike, Utc};
+use flate2::write::DeflateEncoder;
+use flate2::Compression;
+
+const LOCAL_HEADER_SIGNATURE: u32 = 0x0403_4b50;
+const DATA_DESCRIPTOR_SIGNATURE: u32 = 0x0807_4b50;
+const CENTRAL_HEADER_SIGNATURE: u32 = 0x0201_4b50;
+const END_OF_CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x0605_4b50;
+
+/// PKZIP 2.0: deflate
+const VERSION: u16 = 20;
+/// Sizes and CRC follow the data (bit 3); the name is UTF-8 (bit 11)
+const FLAGS: u16 = 0x0808;
+const METHOD_DEFLATE: u16 = 8;
+
+/// A finished entry, remembered for the central directory
+#[derive(Debug)]
+struct CentralRecord {
+    name: String,
+    dos_time: u16,
+    dos_date: u16,
+    crc: u32,
+    compressed_size: u32,
+    uncompressed_size: u32,
+    header_offset: u32,
+}
+
+/// An archive being written; see the module docs
+#[derive(Debug, Default)]
+pub struct ZipWriter {
+    offset: u64,
+    records: Vec<CentralRecord>,
+}
+
+/// An entry whose data is being written
+pub struct ZipEntry {
+    name: String,
+    dos_time: u16,
+    dos_date: u16,
+    header_offset: u32,
+    crc: crc32fast::Hasher,
+    uncompressed_size: u64,
+    compressed_size: u64,
+    encoder: DeflateEncoder<Vec<u8>>,
+}
+
+fn too_large() -> io::Error {
+    io::Error::other("ZIP archive exceeds 4 GiB or 65535 entries")
+}
+
+/// MS-DOS time and date fields; DOS dates start in 1980
+fn dos_datetime(at: DateTime<Utc>) -> (u16, u16) {
+    if at.year() < 1980 {
+        return (0, (1 << 5) | 1);
+    }
+    let time = (at.hour() << 11) | (at.minute() << 5) | (at.second() / 2);
+    let date = ((at.year().min(2107) as u32 - 1980) << 9) | (at.month() << 5) | at.day();
+    (time as u16, date as u16)
+}
+
+impl ZipWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn advance(&mut self, bytes: &[u8]) -> io::Result<()> {
+        self.offset += bytes.len() as u64;
+        if self.offset > u32::MAX as u64 {
+            return Err(too_large());
+        }
+        Ok(())
+    }
+
+    /// Start an entry named `name` (use `/` between directories). Returns the
+    /// entry and the local header to send.
+    pub fn start_entry(&mut self, name: &str, modified: DateTime<Utc>) -> io::Result<(ZipEntry, Vec<u8>)> {
+        if self.records.len() >= u16::MAX as usize {
+            return Err(too_large());
+        }
+
+        let (dos_time, dos_date) = dos_datetime(modified);
+        let header_offset = self.offset as u32;
+
+        let mut header = Vec::with_capacity(30 + name.len());
+        header.extend_from_slice(&LOCAL_HEADER_SIGNATURE.to_le_bytes());
+        header.extend_from_slice(&VERSION.to_le_bytes());
+        header.extend_from_slice(&FLAGS.to_le_bytes());
+        header.extend_from_slice(&METHOD_DEFLATE.to_le_bytes());
+        header.extend_from_slice(&dos_time.to_le_bytes());
+        header.extend_from_slice(&dos_date.to_le_bytes());
+        // CRC and sizes are in the data descriptor
+        header.extend_from_slice(&[0; 12]);
+        header.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        header.extend_from_slice(&0u16.to_le_bytes());
+        header.extend_from_slice(name.as_bytes());
+        self.advance(&header)?;
+
+        let entry = ZipEntry {
+            name: name.to_string(),
+            dos_time,
+            dos_date,
+            header_offset,
+            crc: crc32fast::Hasher::new(),
+            uncompressed_size: 0,
+            compressed_size: 0,
+            encoder: DeflateEncoder::new(Vec::new(), Compression::default()),
+        };
+        Ok((entry, header))
+    }
+
+    /// Add `data` to `entry`. Returns whatever compressed output is ready to
+    /// send, which may be empty.
+    pub fn write(&mut self, entry: &mut ZipEntry, data: &[u8]) -> io::Result<Vec<u8>> {
+        entry.crc.update(data);
+        entry.uncompressed_size += data.len() as u64;
+        if entry.uncompressed_size > u32::MAX as u64 {
+            return Err(too_large());
+        }
+        entry.encoder.write_all(data)?;
+
+        let out = std::mem::take(entry.encoder.get_mut());
+        entry.compressed_size += out.len() as u64;
+        self.advance(&out)?;
+        Ok(out)
+    }
+
+    /// Finish `entry`. Returns the rest of its compressed data and its data
+    /// descriptor.
+    pub fn finish_entry(&mut self, entry: ZipEntry) -> io::Result<Vec<u8>> {
+        let mut out = entry.encoder.finish()?;
+        let compressed_size = entry.compressed_size + out.len() as u64;
+        if compressed_size > u32::MAX as u64 {
+            return Err(too_large());
+        }
+        let crc = entry.crc.finalize();
+
+        out.extend_from_slice(&DATA_DESCRIPTOR_SIGNATURE.to_le_bytes());
+        out.extend_from_slice(&crc.to_le_bytes());
+        out.extend_from_slice(&(compressed_size as u32).to_le_bytes());
+        out.extend_from_slice(&(entry.uncompressed_size as u32).to_le_bytes());
+        self.advance(&out)?;
+
+        self.records.push(CentralRecord {
+            name: entry.name,
+            dos_time: entry.dos_time,
+            dos_date: entry.dos_date,
+            crc,
+            compressed_size: compressed_size as u32,
+            uncompressed_size: entry.uncompressed_size as u32,
+            header_offset: entry.header_offset,
+        });
+        Ok(out)
+    }
+
+    /// Add a whole entry from memory. Returns everything to send for it.
+    pub fn add(&mut self, name: &str, modified: DateTime<Utc>, data: &[u8]) -> io::Result<Vec<u8>> {
+        let (mut entry, mut out) = self.start_entry(name, modified)?;
+        out.extend(self.write(&mut entry, data)?);
+        out.extend(self.finish_entry(entry)?);
+        Ok(out)
+    }
+
+    /// The central directory and end record that close the archive
+    pub fn finish(self) -> io::Result<Vec<u8>> {
+        let mut out = Vec::new();
+        for record in &self.records {
+            out.extend_from_slice(&CENTRAL_HEADER_SIGNATURE.to_le_bytes());
+            out.extend_from_slice(&VERSION.to_le_bytes()); // made by
+            out.extend_from_slice(&VERSION.to_le_bytes()); // needed
+            out.extend_from_slice(&FLAGS.to_le_bytes());
+            out.extend_from_slice(&METHOD_DEFLATE.to_le_bytes());
+            out.extend_from_slice(&record.dos_time.to_le_bytes());
+            out.extend_from_slice(&record.dos_date.to_le_bytes());
+            out.extend_from_slice(&record.crc.to_le_bytes());
+            out.extend_from_slice(&record.compressed_size.to_le_bytes());
+            out.extend_from_slice(&record.uncompressed_size.to_le_bytes());
+            out.extend_from_slice(&(record.name.len() as u16).to_le_bytes());
+            // Extra field, comment, disk number, internal and external attributes
+            out.extend_from_slice(&[0; 12]);
+            out.extend_from_slice(&record.header_offset.to_le_bytes());
+            out.extend_from_slice(record.name.as_bytes());
+        }
+
+        let directory_size = out.len() as u64;
+        if self.offset + directory_size > u32::MAX as u64 {
+            return Err(too_large());
+        }
+        let entries = self.records.len() as u16;
+
+        out.extend_from_slice(&END_OF_CENTRAL_DIRECTORY_SIGNATURE.to_le_bytes());
+        out.extend_from_slice(&[0; 4]); // this disk, directory disk
+        out.extend_from_slice(&entries.to_le_bytes());
+        out.extend_from_slice(&entries.to_le_bytes());
+        out.extend_from_slice(&(directory_size as u32).to_le_bytes());
+        out.extend_from_slice(&(self.offset as u32).to_le_bytes());
+        out.extend_from_slice(&0u16.to_le_bytes()); // comment length
+        Ok(out)
+    }
+}
@@ -0,0 +1,277 @@
+//! Fixtures for tests that run against Postgres and Redis
+//!
+//! Database tests use `TEST_DATABASE_URL`, a disposable database loaded with
+//! init-db.sql; tests that need the full `AppState` also use
+//! `TEST_REDIS_URL`. When they are unset those tests return early, so
+//! `cargo test` passes without either service. Fixtures insert rows under
+//! fresh ids and leave them behind.
+
+use chrono::{DateTime, Utc};
+use sqlx::{
+    postgres::{PgConnectOptions, PgPoolOptions},
+    PgPool,
+};
+use std::str::FromStr;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use uuid::Uuid;
+
+use crate::app::AppState;
+use crate::auth::{AuthContext, Claims, JwksCache, RequireAuth, RequireSub};
+use crate::config::{CacheTtls, Settings};
+use crate::services::{AiClient, ObjectStorage, RedisCache};
+
+/// Settings from the environment, with placeholders for the variables
+/// `Settings::from_env` requires
+pub fn settings() -> Settings {
+    static SETTINGS: OnceLock<Settings> = OnceLock::new();
+    SETTINGS
+        .get_or_init(|| {
+            for (key, value) in [
+                ("DATABASE_URL", "postgres://localhost/unused"),
+                ("SUPABASE_JWT_JWKS_URL", "http://localhost/jwks"),
+                ("SUPABASE_JWT_ISSUER", "test"),
+                ("AI_SERVICE_TOKEN", "test-internal-token"),
+                ("SUPABASE_URL", "http://localhost"),
+                ("SUPABASE_ANON_KEY", "test"),
+                ("SUPABASE_SERVICE_ROLE_KEY", "test"),
+            ] {
+                if std::env::var(key).is_err() {
+                    std::env::set_var(key, value);
+                }
+            }
+            Settings::from_env().expect("test settings")
+        })
+        .clone()
+}
+
+/// Pool on `TEST_DATABASE_URL`, or `None` to skip the test
+pub async fn db() -> Option<PgPool> {
+    let Ok(url) = std::env::var("TEST_DATABASE_URL") else {
+        eprintln!("TEST_DATABASE_URL not set; skipping");
+        return None;
+    };
+
+    let pool = PgPoolOptions::new()
+        .max_connections(5)
+        .connect(&url)
+        .await
+        .expect("connect to TEST_DATABASE_URL");
+    Some(pool)
+}
+
+/// Pool of `max_connections` on `TEST_DATABASE_URL` through a proxy that holds
+/// every reply back by `latency`, as if the database were across a slow
+/// network, or `None` to skip the test. The connections are opened up front.
+pub async fn slow_db(latency: Duration, max_connections: u32) -> Option<PgPool> {
+    let Ok(url) = std::env::var("TEST_DATABASE_URL") else {
+        eprintln!("TEST_DATABASE_URL not set; skipping");
+        return None;
+    };
+    let options = PgConnectOptions::from_str(&url).expect("parse TEST_DATABASE_URL");
+    let upstream_port = options.get_port();
+    let upstream_socket = options
+        .get_socket()
+        .map(|dir| dir.join(format!(".s.PGSQL.{}", upstream_port)));
+    let upstream_host = options.get_host().to_string();
+
+    // The proxy listens where a Unix socket connection on the same port looks
+    let dir = std::env::temp_dir().join(format!("slow-db-{}", Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).expect("create proxy socket dir");
+    let listener = tokio::net::UnixListener::bind(dir.join(format!(".s.PGSQL.{}", upstream_port)))
+        .expect("bind proxy socket");
+
+    tokio::spawn(async move {
+        while let Ok((client, _)) = listener.accept().await {
+            let upstream_socket = upstream_socket.clone();
+            let upstream_host = upstream_host.clone();
+            tokio::spawn(async move {
+                let (client_read, client_write) = tokio::io::split(client);
+                match upstream_socket {
+                    Some(path) => {
+                        let server = tokio::net::UnixStream::connect(path).await.expect("connect to database");
+                        let (server_read, server_write) = tokio::io::split(server);
+                        relay(client_read, client_write, server_read, server_write, latency).await;
+                    }
+                    None => {
+                        let server = tokio::net::TcpStream::connect((upstream_host.as_str(), upstream_port))
+                            .await
+                            .expect("connect to database");
+                        let (server_read, server_write) = tokio::io::split(server);
+                        relay(client_read, client_write, server_read, server_write, latency).await;
+                    }
+                }
+            });
+        }
+    });
+
+    let pool = PgPoolOptions::new()
+        .max_connections(max_connections)
+        .min_connections(max_connections)
+        .test_before_acquire(false)
+        .connect_with(options.socket(&dir))
+        .await
+        .expect("connect through proxy");
+    let held: Vec<_> = futures::future::join_all((0..max_connections).map(|_| pool.acquire())).await;
+    drop(held);
+    Some(pool)
+}
+
+/// Copy client bytes to the server as they come, and server bytes back
+/// after `latency`
+async fn relay(
+    mut client_read: impl AsyncRead + Unpin,
+    mut client_write: impl AsyncWrite + Unpin,
+    mut server_read: impl AsyncRead + Unpin,
+    mut server_write: impl AsyncWrite + Unpin,
+    latency: Duration,
+) {
+    let requests = tokio::io::copy(&mut client_read, &mut server_write);
+    let replies = async {
+        let mut buf = vec![0u8; 64 * 1024];
+        while let Ok(n @ 1..) = server_read.read(&mut buf).await {
+            tokio::time::sleep(latency).await;
+            if client_write.write_all(&buf[..n]).await.is_err() {
+                break;
+            }
+        }
+    };
+    tokio::select! {
+        _ = requests => {}
+        _ = replies => {}
+    }
+}
+
+/// Cache on `TEST_REDIS_URL`, or `None` to skip the test
+pub async fn cache() -> Option<RedisCache> {
+    cache_with(settings().cache_ttls).await
+}
+
+/// Cache on `TEST_REDIS_URL` with the given TTLs, or `None` to skip the test
+pub async fn cache_with(ttls: CacheTtls) -> Option<RedisCache> {
+    let Ok(url) = std::env::var("TEST_REDIS_URL") else {
+        eprintln!("TEST_REDIS_URL not set; skipping");
+        return None;
+    };
+
+    let cache = RedisCache::new(&url, ttls)
+        .await
+        .expect("connect to TEST_REDIS_URL");
+    Some(cache)
+}
+
+/// Application state on the test database and cache, or `None` to skip the
+/// test. External HTTP services (Supabase, the AI service) are never called.
+pub async fn state() -> Option<Arc<AppState>> {
+    let db = db().await?;
+    let cache = cache().await?;
+    let settings = settings();
+    let http_client = reqwest::Client::new();
+
+    let jwks_cache = JwksCache::new(
+        settings.supabase_jwt_jwks_url.clone(),
+        settings.supabase_jwt_issuer.clone(),
+        settings.supabase_jwt_audience.clone(),
+        settings.jwks_cache_ttl_seconds,
+        http_client.clone(),
+    );
+    let ai_client = AiClient::new(
+        &settings.ai_service_url,
+        &settings.ai_service_token,
+        settings.ai_timeouts,
+        settings.ai_models.clone(),
+        settings.ai_circuit_breaker,
+    )
+    .expect("AI client");
+    let storage = ObjectStorage::new(
+        std::env::temp_dir().join("blueprintx-test-media"),
+        &settings.storage_public_url,
+    );
+
+    Some(AppState::new(db, settings, jwks_cache, cache, ai_client, http_client, storage))
+}
+
+/// Application state like `state()`, but on the database pool `db`
+pub async fn state_on(db: PgPool) -> Option<Arc<AppState>> {
+    let state = state().await?;
+    let mut state = (*state).clone();
+    state.db = db;
+    Some(Arc::new(state))
+}
+
+/// Request authentication for `user_id`, as the JWT extractor would build it
+pub fn auth(user_id: Uuid) -> RequireAuth {
+    let now = Utc::now().timestamp();
+    let claims = Claims {
+        sub: user_id.to_string(),
+        aud: "authenticated".to_string(),
+        iss: "test".to_string(),
+        iat: now,
+        exp: now + 3600,
+        nbf: None,
+        email: None,
+        session_id: None,
+        role: Some("authenticated".to_string()),
+        app_metadata: None,
+        user_metadata: None,
+    };
+    RequireAuth(AuthContext::from_claims_with_token(&claims, "test-token").expect("auth context"))
+}
+
+/// Subcontractor authentication for `user_id`
+pub fn sub(user_id: Uuid) -> RequireSub {
+    RequireSub::assume(auth(user_id))
+}
+
+/// Insert a profile of `user_type` ("gc" or "sub")
+pub async fn user(db: &PgPool, user_type: &str) -> Uuid {
+    let id = Uuid::new_v4();
+    sqlx::query(
+        "INSERT INTO profiles (id, email, user_type, company_name) VALUES ($1, $2, $3, $4)",
+    )
+    .bind(id)
+    .bind(format!("{}@test.example", id))
+    .bind(user_type)
+    .bind(format!("Test {} {}", user_type, &id.to_string()[..8]))
+    .execute(db)
+    .await
+    .expect("insert profile");
+    id
+}
+
+/// Insert a project owned by `owner_id`
+pub async fn project(db: &PgPool, owner_id: Uuid) -> Uuid {
+    sqlx::query_scalar("INSERT INTO projects (owner_id, name) VALUES ($1, 'Test project') RETURNING id")
+        .bind(owner_id)
+        .fetch_one(db)
+        .await
+        .expect("insert project")
+}
+
+/// Insert a directory subcontractor for the profile `profile_id`
+pub async fn subcontractor(db: &PgPool, profile_id: Uuid) -> Uuid {
+    sqlx::query_scalar(
+        "INSERT INTO subcontractors (profile_id, name, trade) VALUES ($1, 'Test Electric', 'electrical') RETURNING id",
+    )
+    .bind(profile_id)
+    .fetch_one(db)
+    .await
+    .expect("insert subcontractor")
+}
+
+/// Insert an open tender on `project_id` due at `bid_due_date`
+pub async fn tender(db: &PgPool, project_id: Uuid, bid_due_date: Option<DateTime<Utc>>) -> Uuid {
+    sqlx::query_scalar(
+        r#"
+        INSERT INTO tenders (project_id, name, trade_category, status, bid_due_date)
+        VALUES ($1, 'Test tender', 'electrical', 'open', $2)
+        RETURNING id
+        "#,
+    )
+    .bind(project_id)
+    .bind(bid_due_date)
+    .fetch_one(db)
+    .await
+    .expect("insert tender")
+}